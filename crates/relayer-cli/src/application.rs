@@ -204,14 +204,17 @@ impl Application for CliApp {
             .as_ref()
             .map_or(false, |cmd| matches!(cmd, CliCmd::Start(_)));
 
+        // Only export the spans of a running relayer, not those of one-off commands
+        let otlp = is_start_cmd.then_some(&config.telemetry.otlp);
+
         if command.json {
             // Enable JSON by using the crate-level `Tracing`
-            let tracing = JsonTracing::new(config.global, &self.debug_sections)?;
+            let tracing = JsonTracing::new(config.global, &self.debug_sections, otlp)?;
             Ok(vec![Box::new(terminal), Box::new(tracing)])
         } else {
             // Use abscissa's tracing, which pretty-prints to the terminal obeying log levels
             let (tracing, reload_handle) =
-                PrettyTracing::new_with_reload_handle(config.global, &self.debug_sections)?;
+                PrettyTracing::new_with_reload_handle(config.global, &self.debug_sections, otlp)?;
 
             if is_start_cmd {
                 spawn_tracing_reload_server(reload_handle, config.tracing_server.clone());
@@ -18,8 +18,11 @@ use ibc_chain_registry::formatter::{SimpleGrpcFormatter, UriFormatter};
 use ibc_chain_registry::paths::IBCPath;
 use ibc_chain_registry::querier::*;
 use ibc_relayer::chain::cosmos::config::CosmosSdkConfig;
+use ibc_relayer::chain::cosmos::wallets::WalletAssignment;
 use ibc_relayer::config::filter::{FilterPattern, PacketFilter};
+use ibc_relayer::config::gas_model::GasModel;
 use ibc_relayer::config::gas_multiplier::GasMultiplier;
+use ibc_relayer::config::tx_pipeline::TxPipeline;
 use ibc_relayer::config::types::{MaxMsgNum, MaxTxSize, Memo, TrustThreshold};
 use ibc_relayer::config::{default, AddressType, ChainConfig, EventSourceMode, GasPrice};
 use ibc_relayer::keyring::Store;
@@ -138,18 +141,26 @@ where
         },
         rpc_timeout: default::rpc_timeout(),
         trusted_node: default::trusted_node(),
+        witnesses: Vec::new(),
         genesis_restart: None,
         account_prefix: chain_data.bech32_prefix,
         key_name: String::new(),
+        extra_key_names: Vec::new(),
+        wallet_assignment: WalletAssignment::default(),
         key_store_type: Store::default(),
         key_store_folder: None,
+        key_store_passphrase: None,
+        remote_signer: None,
         store_prefix: "ibc".to_string(),
         default_gas: Some(100000),
         max_gas: Some(400000),
         gas_adjustment: None,
         gas_multiplier: Some(GasMultiplier::new(1.1).unwrap()),
         dynamic_gas_price,
+        gas_model: GasModel::default(),
+        tx_pipeline: TxPipeline::default(),
         fee_granter: None,
+        fee_grant_expiry_warning: default::fee_grant_expiry_warning(),
         max_msg_num: MaxMsgNum::default(),
         max_tx_size: MaxTxSize::default(),
         max_grpc_decoding_size: default::max_grpc_decoding_size(),
@@ -174,6 +185,7 @@ where
         compat_mode: None,
         clear_interval: None,
         excluded_sequences: BTreeMap::new(),
+        fallback_endpoints: Vec::new(),
     }))
 }
 
@@ -11,18 +11,22 @@ mod keys;
 mod listen;
 mod logs;
 mod misbehaviour;
+mod monitor;
 mod query;
+mod recover;
 mod start;
 mod tx;
 mod update;
 mod upgrade;
 mod version;
+mod worker;
 
 use self::{
     clear::ClearCmds, completions::CompletionsCmd, config::ConfigCmd, create::CreateCmds,
     evidence::EvidenceCmd, fee::FeeCmd, health::HealthCheckCmd, keys::KeysCmd, listen::ListenCmd,
-    logs::LogsCmd, misbehaviour::MisbehaviourCmd, query::QueryCmd, start::StartCmd, tx::TxCmd,
-    update::UpdateCmds, upgrade::UpgradeCmds, version::VersionCmd,
+    logs::LogsCmd, misbehaviour::MisbehaviourCmd, monitor::MonitorCmds, query::QueryCmd,
+    recover::RecoverCmds, start::StartCmd, tx::TxCmd, update::UpdateCmds, upgrade::UpgradeCmds,
+    version::VersionCmd, worker::WorkerCmds,
 };
 
 use core::time::Duration;
@@ -63,6 +67,10 @@ pub enum CliCmd {
     #[clap(subcommand)]
     Upgrade(UpgradeCmds),
 
+    /// Recover objects (clients) which expired or were frozen
+    #[clap(subcommand)]
+    Recover(RecoverCmds),
+
     /// Clear objects, such as outstanding packets on a channel.
     #[clap(subcommand)]
     Clear(ClearCmds),
@@ -94,9 +102,17 @@ pub enum CliCmd {
     #[clap(subcommand)]
     Logs(LogsCmd),
 
+    /// Pause or resume the workers of a running Hermes instance
+    #[clap(subcommand)]
+    Worker(WorkerCmds),
+
     /// Listen to block events and handles evidence
     Evidence(EvidenceCmd),
 
+    /// Monitor chains for misbehaviour, independently of the relayed clients
+    #[clap(subcommand)]
+    Monitor(MonitorCmds),
+
     /// The `version` subcommand, retained for backward compatibility.
     Version(VersionCmd),
 
@@ -149,7 +165,6 @@ impl Configurable<Config> for CliCmd {
         let web = "https://hermes.informal.systems";
         let suffix = format!("{} {} ({})", CliCmd::name(), clap::crate_version!(), web);
         for ccfg in config.chains.iter_mut() {
-            #[allow(irrefutable_let_patterns)]
             if let ChainConfig::CosmosSdk(ref mut cosmos_ccfg) = ccfg {
                 if let Some(memo) = &cosmos_ccfg.memo_overwrite {
                     cosmos_ccfg.memo_prefix = memo.clone();
@@ -163,7 +178,6 @@ impl Configurable<Config> for CliCmd {
         // for a prolonged period of time.
         if !matches!(self, CliCmd::Start(_)) {
             for c in config.chains.iter_mut() {
-                #[allow(irrefutable_let_patterns)]
                 if let ChainConfig::CosmosSdk(ref mut cosmos_ccfg) = c {
                     cosmos_ccfg.rpc_timeout = Duration::from_secs(120);
                 }
@@ -178,6 +178,23 @@ impl Runnable for ClearPacketsCmd {
                 Vec::new()
             };
 
+        let ics20_filter = config
+            .find_chain(&chains.src.id())
+            .map(|chain_config| chain_config.packet_filter().ics20_filter(&self.channel_id))
+            .unwrap_or_default();
+
+        let reverse_ics20_filter = channel
+            .counterparty()
+            .channel_id()
+            .and_then(|counterparty_channel_id| {
+                config.find_chain(&chains.dst.id()).map(|chain_config| {
+                    chain_config
+                        .packet_filter()
+                        .ics20_filter(counterparty_channel_id)
+                })
+            })
+            .unwrap_or_default();
+
         // Construct links in both directions.
         let fwd_opts = LinkParameters {
             src_port_id: self.port_id.clone(),
@@ -185,6 +202,7 @@ impl Runnable for ClearPacketsCmd {
             max_memo_size: config.mode.packets.ics20_max_memo_size,
             max_receiver_size: config.mode.packets.ics20_max_receiver_size,
             exclude_src_sequences,
+            ics20_filter,
         };
 
         // Construct links in both directions.
@@ -194,6 +212,7 @@ impl Runnable for ClearPacketsCmd {
             max_memo_size: config.mode.packets.ics20_max_memo_size,
             max_receiver_size: config.mode.packets.ics20_max_receiver_size,
             exclude_src_sequences: exclude_dst_sequences,
+            ics20_filter: reverse_ics20_filter,
         };
 
         let fwd_link = match Link::new_from_opts(
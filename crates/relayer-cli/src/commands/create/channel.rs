@@ -102,7 +102,7 @@ pub struct CreateChannelCommand {
     #[clap(
         long = "order",
         value_name = "ORDER",
-        help = "The channel ordering, valid options 'unordered' (default) and 'ordered'",
+        help = "The channel ordering, valid options 'unordered' (default), 'ordered' and 'ordered-allow-timeout'",
         default_value_t
     )]
     order: Ordering,
@@ -366,6 +366,36 @@ mod tests {
         )
     }
 
+    #[test]
+    fn test_create_channel_order_allow_timeout() {
+        assert_eq!(
+            CreateChannelCommand {
+                chain_a: ChainId::from_string("chain_a"),
+                chain_b: None,
+                connection_a: Some(ConnectionId::from_str("connection_a").unwrap()),
+                port_a: PortId::from_str("port_id_a").unwrap(),
+                port_b: PortId::from_str("port_id_b").unwrap(),
+                order: Ordering::OrderedAllowTimeout,
+                version: None,
+                new_client_connection: false,
+                yes: false
+            },
+            CreateChannelCommand::parse_from([
+                "test",
+                "--a-chain",
+                "chain_a",
+                "--a-connection",
+                "connection_a",
+                "--a-port",
+                "port_id_a",
+                "--b-port",
+                "port_id_b",
+                "--order",
+                "ordered-allow-timeout"
+            ])
+        )
+    }
+
     #[test]
     fn test_create_channel_a_conn_alias() {
         assert_eq!(
@@ -17,7 +17,9 @@ use tendermint_rpc::{Client, Paging};
 use ibc_relayer::chain::cosmos::CosmosSdkChain;
 use ibc_relayer::chain::endpoint::ChainEndpoint;
 use ibc_relayer::chain::handle::{BaseChainHandle, ChainHandle};
-use ibc_relayer::chain::requests::{IncludeProof, PageRequest, QueryHeight};
+use ibc_relayer::chain::requests::{
+    IncludeProof, PageRequest, QueryClientStateRequest, QueryHeight,
+};
 use ibc_relayer::chain::tracking::TrackedMsgs;
 use ibc_relayer::foreign_client::ForeignClient;
 use ibc_relayer::spawn::spawn_chain_runtime_with_modified_config;
@@ -419,7 +421,7 @@ fn fetch_infraction_block_header(
     })
 }
 
-fn handle_light_client_attack(
+pub(crate) fn handle_light_client_attack(
     rt: Arc<TokioRuntime>,
     config: &Config,
     chain: &CosmosSdkChain,
@@ -595,9 +597,18 @@ fn submit_light_client_attack_evidence(
             counterparty.id(),
         );
 
+        // Wasm clients expect the misbehaviour to be wrapped into a Wasm client message
+        let (client_state, _) = counterparty.query_client_state(
+            QueryClientStateRequest {
+                client_id: counterparty_client_id.clone(),
+                height: QueryHeight::Latest,
+            },
+            IncludeProof::No,
+        )?;
+
         let msg = MsgSubmitMisbehaviour {
             client_id: counterparty_client_id.clone(),
-            misbehaviour: misbehaviour.to_any(),
+            misbehaviour: client_state.wrap_misbehaviour(misbehaviour.into()).into(),
             signer,
         }
         .to_any();
@@ -697,7 +708,7 @@ fn fetch_all_counterparty_clients(
     config: &Config,
     chain: &CosmosSdkChain,
 ) -> eyre::Result<Vec<(ChainId, ClientId)>> {
-    use ibc_relayer::chain::requests::{QueryClientStateRequest, QueryConnectionsRequest};
+    use ibc_relayer::chain::requests::QueryConnectionsRequest;
 
     let connections = chain.query_connections(QueryConnectionsRequest {
         pagination: Some(PageRequest::all()),
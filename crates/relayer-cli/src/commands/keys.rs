@@ -5,6 +5,7 @@ use abscissa_core::{Command, Runnable};
 mod add;
 mod balance;
 mod delete;
+mod grant;
 mod list;
 
 /// `keys` subcommand
@@ -21,4 +22,7 @@ pub enum KeysCmd {
 
     /// Query balance for a key from a configured chain. If no key is given, the key is retrieved from the configuration file.
     Balance(balance::KeyBalanceCmd),
+
+    /// Grant fee allowances from the fee granter of a configured chain to its keys, or renew them
+    Grant(grant::KeysGrantCmd),
 }
@@ -10,10 +10,8 @@ use abscissa_core::{Command, Runnable};
 use eyre::eyre;
 use hdpath::StandardHDPath;
 use ibc_relayer::{
-    config::{ChainConfig, Config},
-    keyring::{
-        AnySigningKeyPair, KeyRing, Secp256k1KeyPair, SigningKeyPair, SigningKeyPairSized, Store,
-    },
+    config::{AddressType, ChainConfig, Config},
+    keyring::{AnySigningKeyPair, KeyRing, Secp256k1KeyPair, SigningKeyPair, SigningKeyPairSized},
 };
 use ibc_relayer_types::core::ics24_host::identifier::ChainId;
 use tracing::warn;
@@ -205,10 +203,12 @@ pub fn add_key(
     let key_pair = match config {
         ChainConfig::CosmosSdk(config) => {
             let mut keyring = KeyRing::new_secp256k1(
-                Store::Test,
+                config.key_store_type,
                 &config.account_prefix,
                 &config.id,
                 &config.key_store_folder,
+                &config.key_store_passphrase,
+                &config.remote_signer,
             )?;
 
             check_key_exists(&keyring, key_name, overwrite);
@@ -220,6 +220,31 @@ pub fn add_key(
             keyring.add_key(key_name, key_pair.clone())?;
             key_pair.into()
         }
+        ChainConfig::SoloMachine(config) => {
+            let mut keyring = KeyRing::new_secp256k1(
+                config.key_store_type,
+                &config.account_prefix,
+                &config.id,
+                &config.key_store_folder,
+                &config.key_store_passphrase,
+                &config.remote_signer,
+            )?;
+
+            check_key_exists(&keyring, key_name, overwrite);
+
+            let key_contents =
+                fs::read_to_string(file).map_err(|_| eyre!("error reading the key file"))?;
+            let key_pair = Secp256k1KeyPair::from_seed_file(&key_contents, hd_path)?;
+
+            keyring.add_key(key_name, key_pair.clone())?;
+            key_pair.into()
+        }
+        ChainConfig::Mock(config) => {
+            return Err(eyre!(
+                "the keys of mock chain '{}' only live in memory",
+                config.id
+            ))
+        }
     };
 
     Ok(key_pair)
@@ -238,10 +263,12 @@ pub fn restore_key(
     let key_pair = match config {
         ChainConfig::CosmosSdk(config) => {
             let mut keyring = KeyRing::new_secp256k1(
-                Store::Test,
+                config.key_store_type,
                 &config.account_prefix,
                 &config.id,
                 &config.key_store_folder,
+                &config.key_store_passphrase,
+                &config.remote_signer,
             )?;
 
             check_key_exists(&keyring, key_name, overwrite);
@@ -256,6 +283,34 @@ pub fn restore_key(
             keyring.add_key(key_name, key_pair.clone())?;
             key_pair.into()
         }
+        ChainConfig::SoloMachine(config) => {
+            let mut keyring = KeyRing::new_secp256k1(
+                config.key_store_type,
+                &config.account_prefix,
+                &config.id,
+                &config.key_store_folder,
+                &config.key_store_passphrase,
+                &config.remote_signer,
+            )?;
+
+            check_key_exists(&keyring, key_name, overwrite);
+
+            let key_pair = Secp256k1KeyPair::from_mnemonic(
+                &mnemonic_content,
+                hdpath,
+                &AddressType::default(),
+                keyring.account_prefix(),
+            )?;
+
+            keyring.add_key(key_name, key_pair.clone())?;
+            key_pair.into()
+        }
+        ChainConfig::Mock(config) => {
+            return Err(eyre!(
+                "the keys of mock chain '{}' only live in memory",
+                config.id
+            ))
+        }
     };
 
     Ok(key_pair)
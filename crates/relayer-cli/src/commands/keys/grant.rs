@@ -0,0 +1,231 @@
+use std::fmt::Write;
+
+use abscissa_core::clap::Parser;
+use abscissa_core::{Command, Runnable};
+
+use ibc_relayer::account::{Balance, FeeAllowance};
+use ibc_relayer::chain::cosmos::feegrant::{grant_allowance_msgs, wrap_in_exec};
+use ibc_relayer::chain::handle::ChainHandle;
+use ibc_relayer::chain::requests::QueryGrantsRequest;
+use ibc_relayer::chain::tracking::TrackedMsgs;
+use ibc_relayer::config::ChainConfig;
+use ibc_relayer_types::applications::transfer::Amount;
+use ibc_relayer_types::core::ics24_host::identifier::ChainId;
+
+use crate::application::app_config;
+use crate::cli_utils::spawn_chain_runtime;
+use crate::conclude::{exit_with_unrecoverable_error, json, Output};
+use crate::error::Error;
+
+/// The data structure that represents the arguments when invoking the `keys grant` CLI command.
+///
+/// `keys grant --chain <chain_id> [--granter-key <KEY_NAME>] [--spend-limit <AMOUNT>] [--expiration <DURATION>]`
+///
+/// Grants a fee allowance from the `fee_granter` of the chain to each of the keys
+/// configured for it, renewing the existing allowances.
+/// The allowances are granted either by the granter itself, if its key is given,
+/// or by the `key_name` key on its behalf, provided it has been authorized to do so.
+#[derive(Clone, Command, Debug, Parser, PartialEq, Eq)]
+pub struct KeysGrantCmd {
+    #[clap(
+        long = "chain",
+        required = true,
+        value_name = "CHAIN_ID",
+        help_heading = "REQUIRED",
+        help = "Identifier of the chain"
+    )]
+    chain_id: ChainId,
+
+    #[clap(
+        long = "granter-key",
+        value_name = "KEY_NAME",
+        help = "(optional) name of the key of the fee granter, to sign the grants with. If omitted, the grants are executed by the `key_name` key through its authz authorization"
+    )]
+    granter_key: Option<String>,
+
+    #[clap(
+        long = "spend-limit",
+        value_name = "AMOUNT",
+        help = "(optional) maximum amount of the gas price denom each key can spend on fees. If omitted, the allowances are unlimited"
+    )]
+    spend_limit: Option<Amount>,
+
+    #[clap(
+        long = "expiration",
+        value_name = "DURATION",
+        help = "(optional) how long the allowances are valid for, e.g. `30days`. If omitted, the allowances do not expire"
+    )]
+    expiration: Option<humantime::Duration>,
+}
+
+impl Runnable for KeysGrantCmd {
+    fn run(&self) {
+        match self.grant() {
+            Ok(grantees) if json() => Output::success(grantees).exit(),
+            Ok(grantees) => {
+                let mut msg = String::from("Granted fee allowances to:");
+                for grantee in grantees {
+                    write!(msg, "\n- {grantee}").unwrap_or_else(exit_with_unrecoverable_error);
+                }
+
+                Output::success_msg(msg).exit()
+            }
+            Err(e) => Output::error(format!("failed to grant the fee allowances: {e}")).exit(),
+        }
+    }
+}
+
+impl KeysGrantCmd {
+    /// Grants the allowances and returns the accounts they were granted to.
+    fn grant(&self) -> Result<Vec<String>, Error> {
+        let mut config = (*app_config()).clone();
+
+        let chain_config = config
+            .find_chain_mut(&self.chain_id)
+            .ok_or_else(|| Error::missing_chain_config(self.chain_id.clone()))?;
+
+        let ChainConfig::CosmosSdk(cosmos_config) = chain_config else {
+            return Err(Error::missing_fee_granter(self.chain_id.clone()));
+        };
+
+        let granter = cosmos_config
+            .fee_granter
+            .clone()
+            .filter(|granter| !granter.is_empty())
+            .ok_or_else(|| Error::missing_fee_granter(self.chain_id.clone()))?;
+
+        let key_names = cosmos_config.key_names();
+        let denom = cosmos_config.gas_price.denom.clone();
+        let keys = chain_config.list_keys().map_err(Error::key_ring)?;
+
+        let grantees: Vec<String> = keys
+            .iter()
+            .filter(|(key_name, _)| key_names.contains(key_name))
+            .map(|(_, key)| key.account())
+            .filter(|account| *account != granter)
+            .collect();
+
+        if let Some(granter_key) = &self.granter_key {
+            let account = keys
+                .iter()
+                .find(|(key_name, _)| key_name == granter_key)
+                .map(|(_, key)| key.account())
+                .unwrap_or_default();
+
+            if account != granter {
+                return Err(Error::granter_key_mismatch(
+                    granter_key.clone(),
+                    account,
+                    granter,
+                ));
+            }
+
+            // The granter signs and pays for the grants itself
+            chain_config.set_key_name(granter_key.clone());
+            if let ChainConfig::CosmosSdk(cosmos_config) = chain_config {
+                cosmos_config.fee_granter = None;
+            }
+        }
+
+        let allowance = FeeAllowance {
+            spend_limit: self
+                .spend_limit
+                .iter()
+                .map(|amount| Balance {
+                    amount: amount.to_string(),
+                    denom: denom.clone(),
+                })
+                .collect(),
+            expiration: self
+                .expiration
+                .map(|expiration| {
+                    tendermint::Time::now()
+                        .checked_add(*expiration)
+                        .ok_or_else(|| Error::cli_arg(format!("invalid expiration '{expiration}'")))
+                })
+                .transpose()?,
+        };
+
+        let chain = spawn_chain_runtime(&config, &self.chain_id)?;
+
+        let mut msgs = Vec::new();
+        for grantee in &grantees {
+            let grants = chain
+                .query_grants(QueryGrantsRequest {
+                    granter: granter.clone(),
+                    grantee: grantee.clone(),
+                })
+                .map_err(Error::relayer)?;
+
+            let renew = grants.fee_allowance.is_some();
+            msgs.extend(
+                grant_allowance_msgs(&granter, grantee, &allowance, renew)
+                    .map_err(Error::relayer)?,
+            );
+        }
+
+        if msgs.is_empty() {
+            return Ok(grantees);
+        }
+
+        if self.granter_key.is_none() {
+            let signer = chain.get_signer().map_err(Error::relayer)?;
+            msgs = vec![wrap_in_exec(signer.as_ref(), msgs).map_err(Error::relayer)?];
+        }
+
+        chain
+            .send_messages_and_wait_commit(TrackedMsgs::new_static(msgs, "keys grant"))
+            .map_err(Error::relayer)?;
+
+        Ok(grantees)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::KeysGrantCmd;
+
+    use abscissa_core::clap::Parser;
+    use ibc_relayer_types::core::ics24_host::identifier::ChainId;
+
+    #[test]
+    fn test_keys_grant() {
+        assert_eq!(
+            KeysGrantCmd {
+                chain_id: ChainId::from_string("chain_id"),
+                granter_key: None,
+                spend_limit: None,
+                expiration: None,
+            },
+            KeysGrantCmd::parse_from(["test", "--chain", "chain_id"])
+        )
+    }
+
+    #[test]
+    fn test_keys_grant_all_options() {
+        assert_eq!(
+            KeysGrantCmd {
+                chain_id: ChainId::from_string("chain_id"),
+                granter_key: Some("cold".to_string()),
+                spend_limit: Some(1000u64.into()),
+                expiration: Some("30days".parse().unwrap()),
+            },
+            KeysGrantCmd::parse_from([
+                "test",
+                "--chain",
+                "chain_id",
+                "--granter-key",
+                "cold",
+                "--spend-limit",
+                "1000",
+                "--expiration",
+                "30days"
+            ])
+        )
+    }
+
+    #[test]
+    fn test_keys_grant_no_chain() {
+        assert!(KeysGrantCmd::try_parse_from(["test"]).is_err())
+    }
+}
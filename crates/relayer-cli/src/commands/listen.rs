@@ -152,6 +152,11 @@ fn subscribe(
                 EventSourceMode::Push { url, batch_delay } => EventSource::websocket(
                     chain_config.id().clone(),
                     url.clone(),
+                    config
+                        .fallback_endpoints
+                        .iter()
+                        .filter_map(|endpoint| endpoint.websocket_addr.clone())
+                        .collect(),
                     compat_mode,
                     *batch_delay,
                     rt,
@@ -159,7 +164,13 @@ fn subscribe(
                 EventSourceMode::Pull { interval } => EventSource::rpc(
                     chain_config.id().clone(),
                     HttpClient::new(config.rpc_addr.clone())?,
+                    config
+                        .fallback_endpoints
+                        .iter()
+                        .map(|endpoint| HttpClient::new(endpoint.rpc_addr.clone()))
+                        .collect::<Result<_, _>>()?,
                     *interval,
+                    None,
                     rt,
                 ),
             }?;
@@ -169,6 +180,9 @@ fn subscribe(
             let subscription = monitor_tx.subscribe()?;
             Ok(subscription)
         }
+        ChainConfig::Mock(_) | ChainConfig::SoloMachine(_) => {
+            Err(in_process_chain_error(chain_config))
+        }
     }
 }
 
@@ -179,6 +193,9 @@ fn detect_compatibility_mode(
     // TODO(erwan): move this to the cosmos sdk endpoint implementation
     let rpc_addr = match config {
         ChainConfig::CosmosSdk(config) => config.rpc_addr.clone(),
+        ChainConfig::Mock(_) | ChainConfig::SoloMachine(_) => {
+            return Err(in_process_chain_error(config))
+        }
     };
     let client = HttpClient::new(rpc_addr)?;
     let status = rt.block_on(client.status())?;
@@ -186,10 +203,21 @@ fn detect_compatibility_mode(
         ChainConfig::CosmosSdk(config) => {
             compat_mode_from_version(&config.compat_mode, status.node_info.version)?.into()
         }
+        ChainConfig::Mock(_) | ChainConfig::SoloMachine(_) => {
+            return Err(in_process_chain_error(config))
+        }
     };
     Ok(compat_mode)
 }
 
+/// The events of mock chains and solo machines are only emitted within the process running them.
+fn in_process_chain_error(config: &ChainConfig) -> eyre::Report {
+    eyre!(
+        "cannot listen to the events of in-process chain '{}'",
+        config.id()
+    )
+}
+
 #[cfg(test)]
 mod tests {
     use super::{EventFilter, ListenCmd};
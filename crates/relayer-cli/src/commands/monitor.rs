@@ -0,0 +1,14 @@
+//! `monitor` subcommand
+
+use abscissa_core::clap::Parser;
+use abscissa_core::{Command, Runnable};
+
+use self::misbehaviour::MonitorMisbehaviourCmd;
+
+pub mod misbehaviour;
+
+#[derive(Command, Debug, Parser, Runnable)]
+pub enum MonitorCmds {
+    /// Cross-check the headers of chains against their witnesses to detect light client attacks
+    Misbehaviour(MonitorMisbehaviourCmd),
+}
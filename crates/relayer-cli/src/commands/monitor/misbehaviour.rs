@@ -0,0 +1,319 @@
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
+
+use abscissa_core::clap::Parser;
+use abscissa_core::{Command, Runnable};
+use tokio::runtime::Runtime as TokioRuntime;
+
+use ibc_relayer::chain::cosmos::CosmosSdkChain;
+use ibc_relayer::chain::endpoint::ChainEndpoint;
+use ibc_relayer::config::{ChainConfig, Config};
+use ibc_relayer::misbehaviour_monitor::{DivergenceRecord, EvidenceArchive, WitnessMonitor};
+use ibc_relayer_types::core::ics24_host::identifier::ChainId;
+
+use crate::commands::evidence::handle_light_client_attack;
+use crate::commands::start::spawn_telemetry_server;
+use crate::conclude::Output;
+use crate::prelude::*;
+
+/// Default directory where the evidence of the divergences detected is archived,
+/// relative to the home directory.
+const DEFAULT_ARCHIVE_DIR: &str = ".hermes/evidence";
+
+/// Cross-check the headers served by the full node of each chain against the
+/// ones served by the `witnesses` configured for that chain, and archive the
+/// evidence of the light client attacks detected.
+#[derive(Clone, Command, Debug, Parser, PartialEq, Eq)]
+pub struct MonitorMisbehaviourCmd {
+    #[clap(
+        long = "chain",
+        value_name = "CHAIN_ID",
+        value_delimiter = ',',
+        help = "Identifiers of the chains to monitor, comma separated \
+                (default: all the chains with witnesses in the config)"
+    )]
+    chain_ids: Vec<ChainId>,
+
+    #[clap(
+        long = "archive-dir",
+        value_name = "PATH",
+        help = "Directory where the evidence of the divergences detected is written as JSON \
+                (default: `~/.hermes/evidence`)"
+    )]
+    archive_dir: Option<PathBuf>,
+
+    #[clap(
+        long = "interval",
+        value_name = "SECONDS",
+        help = "How often to cross-check the latest header of each chain, in seconds (default: 5)",
+        default_value = "5",
+        value_parser = clap::value_parser!(u64).range(1..)
+    )]
+    interval: u64,
+
+    #[clap(
+        long = "submit",
+        help = "Report the evidence to the witness and submit it to every chain hosting a client of the attacked chain"
+    )]
+    submit: bool,
+
+    #[clap(
+        long = "key-name",
+        value_name = "KEY_NAME",
+        help = "Use the given signing key name for submitting the evidence (default: `key_name` config)"
+    )]
+    key_name: Option<String>,
+}
+
+impl MonitorMisbehaviourCmd {
+    /// The configurations of the chains to monitor.
+    fn chain_configs(&self, config: &Config) -> Result<Vec<ChainConfig>, String> {
+        if self.chain_ids.is_empty() {
+            let chains: Vec<_> = config
+                .chains
+                .iter()
+                .filter(
+                    |chain| matches!(chain, ChainConfig::CosmosSdk(c) if !c.witnesses.is_empty()),
+                )
+                .cloned()
+                .collect();
+
+            if chains.is_empty() {
+                return Err("no chain with `witnesses` found in configuration".to_string());
+            }
+
+            return Ok(chains);
+        }
+
+        self.chain_ids
+            .iter()
+            .map(|chain_id| match config.find_chain(chain_id) {
+                Some(ChainConfig::CosmosSdk(c)) if c.witnesses.is_empty() => {
+                    Err(format!("no `witnesses` configured for chain `{chain_id}`"))
+                }
+                Some(chain @ ChainConfig::CosmosSdk(_)) => Ok(chain.clone()),
+                Some(_) => Err(format!("chain `{chain_id}` is not a Cosmos SDK chain")),
+                None => Err(format!("chain `{chain_id}` not found in configuration")),
+            })
+            .collect()
+    }
+
+    fn archive(&self) -> Result<EvidenceArchive, String> {
+        let dir = match &self.archive_dir {
+            Some(dir) => dir.clone(),
+            None => dirs_next::home_dir()
+                .map(|home| home.join(DEFAULT_ARCHIVE_DIR))
+                .ok_or_else(|| {
+                    "cannot find the home directory, use `--archive-dir` instead".to_string()
+                })?,
+        };
+
+        EvidenceArchive::new(&dir).map_err(|e| {
+            format!(
+                "failed to create archive directory `{}`: {e}",
+                dir.display()
+            )
+        })
+    }
+}
+
+impl Runnable for MonitorMisbehaviourCmd {
+    fn run(&self) {
+        let config = (*app_config()).clone();
+
+        let chain_configs = self
+            .chain_configs(&config)
+            .unwrap_or_else(|e| Output::error(e).exit());
+
+        let archive = self.archive().unwrap_or_else(|e| Output::error(e).exit());
+
+        spawn_telemetry_server(&config);
+
+        let rt = Arc::new(TokioRuntime::new().unwrap());
+        let config = Arc::new(config);
+
+        let monitors = chain_configs
+            .into_iter()
+            .map(|mut chain_config| {
+                if let Some(ref key_name) = self.key_name {
+                    chain_config.set_key_name(key_name.to_string());
+                }
+
+                let options = MonitorOptions {
+                    interval: Duration::from_secs(self.interval),
+                    submit: self.submit,
+                    key_name: self.key_name.clone(),
+                };
+
+                let (rt, config, archive) = (rt.clone(), config.clone(), archive.clone());
+
+                thread::spawn(move || {
+                    let chain_id = chain_config.id().clone();
+
+                    let _span = tracing::error_span!("monitor", chain = %chain_id).entered();
+
+                    if let Err(e) = monitor_chain(rt, &config, chain_config, &archive, &options) {
+                        error!("stopped monitoring chain `{chain_id}`: {e}");
+                    }
+                })
+            })
+            .collect::<Vec<_>>();
+
+        for monitor in monitors {
+            let _ = monitor.join();
+        }
+
+        Output::success(()).exit()
+    }
+}
+
+struct MonitorOptions {
+    interval: Duration,
+    submit: bool,
+    key_name: Option<String>,
+}
+
+fn monitor_chain(
+    rt: Arc<TokioRuntime>,
+    config: &Config,
+    chain_config: ChainConfig,
+    archive: &EvidenceArchive,
+    options: &MonitorOptions,
+) -> eyre::Result<()> {
+    let chain = CosmosSdkChain::bootstrap(chain_config, rt.clone())?;
+
+    let mut monitor = WitnessMonitor::new(chain.config(), chain.light_client_options()?)?;
+
+    info!(
+        "cross-checking headers against witnesses every {:?}",
+        options.interval
+    );
+
+    loop {
+        match monitor.check(tendermint::Time::now()) {
+            Ok(divergences) => {
+                for record in divergences {
+                    handle_divergence(
+                        rt.clone(),
+                        config,
+                        &chain,
+                        &monitor,
+                        archive,
+                        options,
+                        &record,
+                    );
+                }
+            }
+            Err(e) => error!("failed to cross-check the latest header: {e}"),
+        }
+
+        thread::sleep(options.interval);
+    }
+}
+
+/// Archive the evidence of the divergence and, if requested, report it to the
+/// witness and submit it to all the counterparty clients of the chain, freezing them.
+fn handle_divergence(
+    rt: Arc<TokioRuntime>,
+    config: &Config,
+    chain: &CosmosSdkChain,
+    monitor: &WitnessMonitor,
+    archive: &EvidenceArchive,
+    options: &MonitorOptions,
+    record: &DivergenceRecord,
+) {
+    match archive.store(record) {
+        Ok(path) => warn!(
+            "evidence of light client attack archived at `{}`",
+            path.display()
+        ),
+        Err(e) => error!("failed to archive evidence of light client attack: {e}"),
+    }
+
+    if !options.submit {
+        return;
+    }
+
+    match monitor.report_to_witness(record) {
+        Ok(Some(hash)) => info!(
+            "evidence reported to witness {} with hash: {hash}",
+            record.witness
+        ),
+        Ok(None) => {}
+        Err(e) => error!(
+            "failed to report evidence to witness {}: {e}",
+            record.witness
+        ),
+    }
+
+    let result = handle_light_client_attack(
+        rt,
+        config,
+        chain,
+        options.key_name.as_ref(),
+        record.light_client_attack(),
+    );
+
+    if let Err(e) = result {
+        error!("failed to submit evidence of light client attack: {e}");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::MonitorMisbehaviourCmd;
+
+    use std::path::PathBuf;
+
+    use abscissa_core::clap::Parser;
+    use ibc_relayer_types::core::ics24_host::identifier::ChainId;
+
+    #[test]
+    fn test_monitor_misbehaviour() {
+        assert_eq!(
+            MonitorMisbehaviourCmd {
+                chain_ids: vec![],
+                archive_dir: None,
+                interval: 5,
+                submit: false,
+                key_name: None,
+            },
+            MonitorMisbehaviourCmd::parse_from(["test"])
+        )
+    }
+
+    #[test]
+    fn test_monitor_misbehaviour_all_options() {
+        assert_eq!(
+            MonitorMisbehaviourCmd {
+                chain_ids: vec![
+                    ChainId::from_string("chain_a"),
+                    ChainId::from_string("chain_b")
+                ],
+                archive_dir: Some(PathBuf::from("/tmp/evidence")),
+                interval: 10,
+                submit: true,
+                key_name: Some("key_name".to_owned()),
+            },
+            MonitorMisbehaviourCmd::parse_from([
+                "test",
+                "--chain",
+                "chain_a,chain_b",
+                "--archive-dir",
+                "/tmp/evidence",
+                "--interval",
+                "10",
+                "--submit",
+                "--key-name",
+                "key_name"
+            ])
+        )
+    }
+
+    #[test]
+    fn test_monitor_misbehaviour_zero_interval() {
+        assert!(MonitorMisbehaviourCmd::try_parse_from(["test", "--interval", "0"]).is_err())
+    }
+}
@@ -16,6 +16,7 @@ mod client;
 mod clients;
 mod connection;
 mod connections;
+mod fee;
 mod packet;
 mod transfer;
 mod tx;
@@ -52,6 +53,10 @@ pub enum QueryCmd {
     #[clap(subcommand)]
     Tx(tx::QueryTxCmd),
 
+    /// Query information about the fees of the relayed packets
+    #[clap(subcommand)]
+    Fee(fee::QueryFeeCmds),
+
     /// Query information about token transfers
     #[clap(subcommand)]
     Transfer(transfer::TransferCmd),
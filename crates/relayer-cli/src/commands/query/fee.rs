@@ -0,0 +1,15 @@
+//! `query fee` subcommand
+
+use abscissa_core::clap::Parser;
+use abscissa_core::{Command, Runnable};
+
+mod profitability;
+
+/// `query fee` subcommand
+#[derive(Command, Debug, Parser, Runnable)]
+pub enum QueryFeeCmds {
+    /// Report the ICS29 fees collected and the transaction fees paid on each channel
+    /// by a running Hermes instance. Requires the REST server of the running instance
+    /// to be enabled.
+    Profitability(profitability::QueryFeeProfitabilityCmd),
+}
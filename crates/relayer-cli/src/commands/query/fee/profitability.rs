@@ -0,0 +1,163 @@
+use core::fmt;
+
+use abscissa_core::clap::Parser;
+use abscissa_core::{Command, Runnable};
+use serde_json::Value;
+
+use ibc_relayer::config::RestConfig;
+use ibc_relayer::profitability::ChannelProfitability;
+use ibc_relayer_types::core::ics24_host::identifier::ChainId;
+
+use crate::application::app_config;
+use crate::conclude::Output;
+
+/// Displays the profitability of each channel in a human readable way.
+struct DisplayReport(Vec<ChannelProfitability>);
+
+impl fmt::Display for DisplayReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.0.is_empty() {
+            return write!(f, "No packets relayed yet");
+        }
+
+        for profitability in &self.0 {
+            let channel = &profitability.channel;
+
+            writeln!(
+                f,
+                "{}/{} on {} to {}: {} packets relayed",
+                channel.port_id,
+                channel.channel_id,
+                channel.chain_id,
+                channel.counterparty_chain_id,
+                profitability.packets_relayed,
+            )?;
+
+            for balance in &profitability.balances {
+                writeln!(
+                    f,
+                    "  {} on {}: collected {}, paid {}, net {}",
+                    balance.denom,
+                    balance.chain_id,
+                    balance.fees_collected,
+                    balance.gas_paid,
+                    balance.net_profit,
+                )?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Report the ICS29 fees collected and the transaction fees paid by a running
+/// Hermes instance for relaying the packets sent on each channel, since it started.
+#[derive(Clone, Command, Debug, Parser, PartialEq, Eq)]
+pub struct QueryFeeProfitabilityCmd {
+    #[clap(
+        long = "chain",
+        value_name = "CHAIN_ID",
+        help = "Only report the channels with an end on the given chain"
+    )]
+    chain_id: Option<ChainId>,
+}
+
+impl Runnable for QueryFeeProfitabilityCmd {
+    fn run(&self) {
+        use crate::conclude::json;
+
+        match fetch_report(&app_config().rest, self.chain_id.as_ref()) {
+            Ok(report) if json() => Output::success(report).exit(),
+            Ok(report) => Output::success_msg(DisplayReport(report).to_string()).exit(),
+            Err(e) => Output::error(e).exit(),
+        }
+    }
+}
+
+/// Fetch the profitability report from the REST server of the running Hermes instance.
+fn fetch_report(
+    rest: &RestConfig,
+    chain_id: Option<&ChainId>,
+) -> Result<Vec<ChannelProfitability>, String> {
+    if !rest.enabled {
+        return Err(
+            "the REST server must be enabled in the configuration to query the profitability"
+                .to_string(),
+        );
+    }
+
+    let url = format!("http://{}:{}/fee/profitability", rest.host, rest.port);
+
+    let mut request = reqwest::blocking::Client::new().get(&url);
+
+    if let Some(chain_id) = chain_id {
+        request = request.query(&[("chain", chain_id.as_str())]);
+    }
+
+    let response: Value = request
+        .send()
+        .and_then(|response| response.json())
+        .map_err(|e| format!("failed to send request to '{url}': {e}"))?;
+
+    match response["status"].as_str() {
+        Some("success") => serde_json::from_value(response["result"].clone())
+            .map_err(|e| format!("unexpected response from '{url}': {e}")),
+        _ => Err(response["result"]["msg"]
+            .as_str()
+            .map(ToString::to_string)
+            .unwrap_or_else(|| format!("unexpected response from '{url}': {response}"))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{DisplayReport, QueryFeeProfitabilityCmd};
+
+    use abscissa_core::clap::Parser;
+    use ibc_relayer::profitability::{ChannelKey, ChannelProfitability, DenomBalance};
+    use ibc_relayer_types::core::ics24_host::identifier::{ChainId, ChannelId, PortId};
+
+    #[test]
+    fn test_query_fee_profitability() {
+        assert_eq!(
+            QueryFeeProfitabilityCmd { chain_id: None },
+            QueryFeeProfitabilityCmd::parse_from(["test"])
+        )
+    }
+
+    #[test]
+    fn test_query_fee_profitability_chain() {
+        assert_eq!(
+            QueryFeeProfitabilityCmd {
+                chain_id: Some(ChainId::from_string("chain_id"))
+            },
+            QueryFeeProfitabilityCmd::parse_from(["test", "--chain", "chain_id"])
+        )
+    }
+
+    #[test]
+    fn test_display_report() {
+        let report = vec![ChannelProfitability {
+            channel: ChannelKey::new(
+                ChainId::from_string("chain_a"),
+                PortId::transfer(),
+                ChannelId::new(0),
+                ChainId::from_string("chain_b"),
+            ),
+            packets_relayed: 2,
+            balances: vec![DenomBalance {
+                chain_id: ChainId::from_string("chain_b"),
+                denom: "stake".to_string(),
+                fees_collected: "0".to_string(),
+                gas_paid: "150".to_string(),
+                net_profit: "-150".to_string(),
+            }],
+        }];
+
+        assert_eq!(
+            DisplayReport(report).to_string(),
+            "transfer/channel-0 on chain_a to chain_b: 2 packets relayed\n  \
+             stake on chain_b: collected 0, paid 150, net -150\n"
+        );
+    }
+}
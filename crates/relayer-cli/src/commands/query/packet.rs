@@ -8,6 +8,7 @@ mod commitments;
 mod pending;
 mod pending_acks;
 mod pending_sends;
+mod trace;
 mod util;
 
 #[derive(Command, Debug, Parser, Runnable)]
@@ -32,4 +33,7 @@ pub enum QueryPacketCmds {
 
     /// Output a summary of pending packets in both directions
     Pending(pending::QueryPendingPacketsCmd),
+
+    /// Trace the lifecycle of a packet on both ends of its channel
+    Trace(trace::QueryPacketTraceCmd),
 }
@@ -0,0 +1,209 @@
+use core::fmt;
+
+use abscissa_core::clap::Parser;
+use abscissa_core::{Command, Runnable};
+
+use ibc_relayer::chain::counterparty::{packet_trace, PacketEventInfo, PacketTrace};
+use ibc_relayer::chain::handle::BaseChainHandle;
+use ibc_relayer_types::core::ics04_channel::packet::Sequence;
+use ibc_relayer_types::core::ics24_host::identifier::{ChainId, ChannelId, PortId};
+
+use crate::cli_utils::spawn_chain_counterparty;
+use crate::conclude::Output;
+use crate::error::Error;
+use crate::prelude::*;
+
+/// Displays the lifecycle of a packet in a human readable way.
+struct DisplayTrace(PacketTrace);
+
+impl fmt::Display for DisplayTrace {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fn event(info: &Option<PacketEventInfo>) -> String {
+            match info {
+                Some(PacketEventInfo {
+                    height,
+                    tx_hash: Some(tx_hash),
+                }) => format!("at height {height} in tx {tx_hash}"),
+                Some(PacketEventInfo { height, .. }) => format!("at height {height}"),
+                None => "not found".to_string(),
+            }
+        }
+
+        let trace = &self.0;
+
+        writeln!(
+            f,
+            "Packet {} sent on {}/{} from {} to {}/{} on {}: {}",
+            trace.sequence,
+            trace.port_id,
+            trace.channel_id,
+            trace.chain_id,
+            trace.counterparty_port_id,
+            trace.counterparty_channel_id,
+            trace.counterparty_chain_id,
+            trace.status,
+        )?;
+
+        writeln!(f, "  Sent:               {}", event(&trace.send))?;
+        writeln!(f, "  Commitment present: {}", trace.commitment_present)?;
+        writeln!(f, "  Received:           {}", trace.received)?;
+        if trace.ack_written {
+            writeln!(f, "  Ack written:        {}", event(&trace.write_ack))?;
+        } else {
+            writeln!(f, "  Ack written:        false")?;
+        }
+        writeln!(f, "  Ack relayed:        {}", trace.ack_relayed)?;
+        writeln!(f, "  Timed out:          {}", trace.timed_out)?;
+
+        Ok(())
+    }
+}
+
+/// Trace the lifecycle of a packet, by querying both ends of its channel for
+/// the event which sent it, its commitment, its receipt and its acknowledgement.
+#[derive(Clone, Command, Debug, Parser, PartialEq, Eq)]
+pub struct QueryPacketTraceCmd {
+    #[clap(
+        long = "chain",
+        required = true,
+        value_name = "CHAIN_ID",
+        help_heading = "REQUIRED",
+        help = "Identifier of the chain which sent the packet"
+    )]
+    chain_id: ChainId,
+
+    #[clap(
+        long = "port",
+        required = true,
+        value_name = "PORT_ID",
+        help_heading = "REQUIRED",
+        help = "Port identifier on the chain given by <CHAIN_ID>"
+    )]
+    port_id: PortId,
+
+    #[clap(
+        long = "channel",
+        visible_alias = "chan",
+        required = true,
+        value_name = "CHANNEL_ID",
+        help_heading = "REQUIRED",
+        help = "Channel identifier on the chain given by <CHAIN_ID>"
+    )]
+    channel_id: ChannelId,
+
+    #[clap(
+        long = "sequence",
+        visible_alias = "seq",
+        required = true,
+        value_name = "SEQUENCE",
+        help_heading = "REQUIRED",
+        help = "Sequence of the packet to trace"
+    )]
+    sequence: Sequence,
+}
+
+impl QueryPacketTraceCmd {
+    fn execute(&self) -> Result<PacketTrace, Error> {
+        let config = app_config();
+
+        let (chains, chan_conn_cli) = spawn_chain_counterparty::<BaseChainHandle>(
+            &config,
+            &self.chain_id,
+            &self.port_id,
+            &self.channel_id,
+        )?;
+
+        packet_trace(
+            &chains.src,
+            &chains.dst,
+            &chan_conn_cli.channel,
+            self.sequence,
+        )
+        .map_err(Error::supervisor)
+    }
+}
+
+impl Runnable for QueryPacketTraceCmd {
+    fn run(&self) {
+        use crate::conclude::json;
+
+        match self.execute() {
+            Ok(trace) if json() => Output::success(trace).exit(),
+            Ok(trace) => Output::success_msg(DisplayTrace(trace).to_string()).exit(),
+            Err(e) => Output::error(e).exit(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::QueryPacketTraceCmd;
+
+    use std::str::FromStr;
+
+    use abscissa_core::clap::Parser;
+    use ibc_relayer_types::core::{
+        ics04_channel::packet::Sequence,
+        ics24_host::identifier::{ChainId, ChannelId, PortId},
+    };
+
+    #[test]
+    fn test_query_packet_trace() {
+        assert_eq!(
+            QueryPacketTraceCmd {
+                chain_id: ChainId::from_string("chain_id"),
+                port_id: PortId::from_str("port_id").unwrap(),
+                channel_id: ChannelId::from_str("channel-07").unwrap(),
+                sequence: Sequence::from(42),
+            },
+            QueryPacketTraceCmd::parse_from([
+                "test",
+                "--chain",
+                "chain_id",
+                "--port",
+                "port_id",
+                "--channel",
+                "channel-07",
+                "--sequence",
+                "42"
+            ])
+        )
+    }
+
+    #[test]
+    fn test_query_packet_trace_aliases() {
+        assert_eq!(
+            QueryPacketTraceCmd {
+                chain_id: ChainId::from_string("chain_id"),
+                port_id: PortId::from_str("port_id").unwrap(),
+                channel_id: ChannelId::from_str("channel-07").unwrap(),
+                sequence: Sequence::from(42),
+            },
+            QueryPacketTraceCmd::parse_from([
+                "test",
+                "--chain",
+                "chain_id",
+                "--port",
+                "port_id",
+                "--chan",
+                "channel-07",
+                "--seq",
+                "42"
+            ])
+        )
+    }
+
+    #[test]
+    fn test_query_packet_trace_no_seq() {
+        assert!(QueryPacketTraceCmd::try_parse_from([
+            "test",
+            "--chain",
+            "chain_id",
+            "--port",
+            "port_id",
+            "--channel",
+            "channel-07"
+        ])
+        .is_err())
+    }
+}
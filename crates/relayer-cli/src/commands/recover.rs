@@ -0,0 +1,14 @@
+//! `recover` subcommand
+
+use abscissa_core::clap::Parser;
+use abscissa_core::{Command, Runnable};
+
+use self::client::RecoverClientCmd;
+
+pub mod client;
+
+#[derive(Command, Debug, Parser, Runnable)]
+pub enum RecoverCmds {
+    /// Submit a governance proposal to recover an expired or frozen IBC client
+    Client(RecoverClientCmd),
+}
@@ -0,0 +1,193 @@
+use abscissa_core::clap::Parser;
+use abscissa_core::{Command, Runnable};
+
+use ibc_relayer::chain::handle::ChainHandle;
+use ibc_relayer::chain::requests::{IncludeProof, QueryClientStateRequest, QueryHeight};
+use ibc_relayer::recover_client::{recover_client, RecoverClientOptions};
+use ibc_relayer::upgrade_chain::requires_legacy_upgrade_proposal;
+use ibc_relayer_types::core::ics24_host::identifier::{ChainId, ClientId};
+
+use crate::cli_utils::spawn_chain_runtime;
+use crate::conclude::{exit_with_unrecoverable_error, Output};
+use crate::error::Error;
+use crate::prelude::*;
+
+/// Submit a governance proposal to recover an expired or frozen client,
+/// by substituting it with a healthy client tracking the same chain.
+///
+/// If no substitute client is given, a new one is created with the same
+/// parameters as the client to recover.
+#[derive(Clone, Command, Debug, Parser, PartialEq, Eq)]
+pub struct RecoverClientCmd {
+    #[clap(
+        long = "chain",
+        required = true,
+        value_name = "CHAIN_ID",
+        help_heading = "REQUIRED",
+        help = "Identifier of the chain that hosts the client"
+    )]
+    chain_id: ChainId,
+
+    #[clap(
+        long = "client",
+        required = true,
+        value_name = "CLIENT_ID",
+        help_heading = "REQUIRED",
+        help = "Identifier of the expired or frozen client to recover"
+    )]
+    client_id: ClientId,
+
+    #[clap(
+        long = "substitute",
+        value_name = "SUBSTITUTE_CLIENT_ID",
+        help = "Identifier of the client to substitute the client to recover with. \
+                If not provided, a new client is created"
+    )]
+    substitute_client_id: Option<ClientId>,
+
+    #[clap(
+        long = "amount",
+        required = true,
+        value_name = "AMOUNT",
+        help_heading = "REQUIRED",
+        help = "Amount of the deposit for the proposal"
+    )]
+    amount: u64,
+
+    #[clap(
+        long = "denom",
+        value_name = "DENOM",
+        help = "Denomination for the deposit (default: 'stake')"
+    )]
+    denom: Option<String>,
+
+    #[clap(
+        long = "gov-account",
+        value_name = "GOV_ACCOUNT",
+        help = "Authority account used to sign the recovery message. Note: This is only used for chains with ibc-go version v8.0.0 or higher"
+    )]
+    gov_account: Option<String>,
+}
+
+impl Runnable for RecoverClientCmd {
+    fn run(&self) {
+        let config = app_config();
+
+        let host_chain = spawn_chain_runtime(&config, &self.chain_id)
+            .unwrap_or_else(exit_with_unrecoverable_error);
+
+        let reference_chain_id = match host_chain.query_client_state(
+            QueryClientStateRequest {
+                client_id: self.client_id.clone(),
+                height: QueryHeight::Latest,
+            },
+            IncludeProof::No,
+        ) {
+            Ok((cs, _)) => cs.chain_id(),
+            Err(e) => Output::error(format!(
+                "Query of client '{}' on chain '{}' failed with error: {}",
+                self.client_id, self.chain_id, e
+            ))
+            .exit(),
+        };
+
+        let reference_chain = spawn_chain_runtime(&config, &reference_chain_id)
+            .unwrap_or_else(exit_with_unrecoverable_error);
+
+        let gov_account = if requires_legacy_upgrade_proposal(host_chain.clone()) {
+            "".to_string()
+        } else if let Some(gov_account) = &self.gov_account {
+            gov_account.clone()
+        } else {
+            Output::error("The chain hosting the client uses an ibc-go version v8.0.0 or higher, which requires the governance module account to be specified using the flag `--gov-account`".to_owned()).exit();
+        };
+
+        let opts = RecoverClientOptions {
+            subject_client_id: self.client_id.clone(),
+            substitute_client_id: self.substitute_client_id.clone(),
+            amount: self.amount,
+            denom: self.denom.as_deref().unwrap_or("stake").into(),
+            gov_account,
+        };
+
+        let res = recover_client(host_chain, reference_chain, &opts).map_err(Error::recover_client);
+
+        match res {
+            Ok(recovered) => Output::success(recovered).exit(),
+            Err(e) => Output::error(e).exit(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RecoverClientCmd;
+
+    use abscissa_core::clap::Parser;
+    use ibc_relayer_types::core::ics24_host::identifier::{ChainId, ClientId};
+    use std::str::FromStr;
+
+    #[test]
+    fn test_recover_client_required_only() {
+        assert_eq!(
+            RecoverClientCmd {
+                chain_id: ChainId::from_string("chain_host"),
+                client_id: ClientId::from_str("07-tendermint-0").unwrap(),
+                substitute_client_id: None,
+                amount: 42,
+                denom: None,
+                gov_account: None,
+            },
+            RecoverClientCmd::parse_from([
+                "test",
+                "--chain",
+                "chain_host",
+                "--client",
+                "07-tendermint-0",
+                "--amount",
+                "42",
+            ])
+        )
+    }
+
+    #[test]
+    fn test_recover_client_all_options() {
+        assert_eq!(
+            RecoverClientCmd {
+                chain_id: ChainId::from_string("chain_host"),
+                client_id: ClientId::from_str("07-tendermint-0").unwrap(),
+                substitute_client_id: Some(ClientId::from_str("07-tendermint-1").unwrap()),
+                amount: 42,
+                denom: Some("my_denom".to_owned()),
+                gov_account: Some("cosmos1gov".to_owned()),
+            },
+            RecoverClientCmd::parse_from([
+                "test",
+                "--chain",
+                "chain_host",
+                "--client",
+                "07-tendermint-0",
+                "--substitute",
+                "07-tendermint-1",
+                "--amount",
+                "42",
+                "--denom",
+                "my_denom",
+                "--gov-account",
+                "cosmos1gov",
+            ])
+        )
+    }
+
+    #[test]
+    fn test_recover_client_no_client() {
+        assert!(RecoverClientCmd::try_parse_from([
+            "test",
+            "--chain",
+            "chain_host",
+            "--amount",
+            "42"
+        ])
+        .is_err())
+    }
+}
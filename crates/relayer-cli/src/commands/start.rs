@@ -1,10 +1,13 @@
 use ibc_relayer::supervisor::SupervisorOptions;
 use ibc_relayer::util::debug_section::DebugSection;
 use std::error::Error;
+use std::fs;
 use std::io;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
 
 use abscissa_core::clap::Parser;
-use abscissa_core::{Command, Runnable};
+use abscissa_core::{Command, Configurable, Runnable};
 use crossbeam_channel::Sender;
 
 use ibc_relayer::chain::handle::{CachingChainHandle, ChainHandle};
@@ -13,10 +16,14 @@ use ibc_relayer::registry::SharedRegistry;
 use ibc_relayer::rest;
 use ibc_relayer::supervisor::{cmd::SupervisorCmd, spawn_supervisor, SupervisorHandle};
 
+use crate::commands::CliCmd;
 use crate::conclude::json;
 use crate::conclude::Output;
 use crate::prelude::*;
 
+/// How often to check whether the configuration file was modified.
+const CONFIG_WATCH_INTERVAL: Duration = Duration::from_secs(5);
+
 #[derive(Clone, Command, Debug, Parser, PartialEq, Eq)]
 pub struct StartCmd {
     #[clap(
@@ -66,10 +73,17 @@ impl Runnable for StartCmd {
             });
 
         match crate::config::config_path() {
-            Some(_) => {
-                register_signals(supervisor_handle.sender.clone()).unwrap_or_else(|e| {
+            Some(config_path) => {
+                register_signals(
+                    self.clone(),
+                    config_path.clone(),
+                    supervisor_handle.sender.clone(),
+                )
+                .unwrap_or_else(|e| {
                     warn!("failed to install signal handler: {}", e);
                 });
+
+                watch_config_file(self.clone(), config_path, supervisor_handle.sender.clone());
             }
             None => {
                 warn!("cannot figure out configuration path, skipping registration of signal handlers");
@@ -83,13 +97,17 @@ impl Runnable for StartCmd {
 }
 
 /// Register the SIGHUP and SIGUSR1 signals, and notify the supervisor.
-/// - [DEPRECATED] SIGHUP: Trigger a reload of the configuration.
+/// - SIGHUP: Trigger a reload of the configuration.
 /// - SIGUSR1: Ask the supervisor to dump its state and print it to the console.
-fn register_signals(tx_cmd: Sender<SupervisorCmd>) -> Result<(), io::Error> {
+fn register_signals(
+    cmd: StartCmd,
+    config_path: PathBuf,
+    tx_cmd: Sender<SupervisorCmd>,
+) -> Result<(), io::Error> {
     use signal_hook::{consts::signal::*, iterator::Signals};
 
     let sigs = vec![
-        SIGHUP,  // Reload of configuration (disabled)
+        SIGHUP,  // Reload of configuration
         SIGUSR1, // Dump state
     ];
 
@@ -98,10 +116,12 @@ fn register_signals(tx_cmd: Sender<SupervisorCmd>) -> Result<(), io::Error> {
     std::thread::spawn(move || {
         for signal in &mut signals {
             match signal {
-                SIGHUP => warn!(
-                    "configuration reloading via SIGHUP has been disabled, \
-                     the signal handler will be removed in the future"
-                ),
+                SIGHUP => {
+                    info!("reloading configuration (triggered by SIGHUP)");
+
+                    reload_config(&cmd, &config_path, &tx_cmd);
+                }
+
                 SIGUSR1 => {
                     info!("dumping state (triggered by SIGUSR1)");
 
@@ -132,6 +152,60 @@ fn register_signals(tx_cmd: Sender<SupervisorCmd>) -> Result<(), io::Error> {
     Ok(())
 }
 
+/// Spawn a thread which reloads the configuration whenever the configuration file is modified.
+fn watch_config_file(cmd: StartCmd, config_path: PathBuf, tx_cmd: Sender<SupervisorCmd>) {
+    fn modified_at(path: &Path) -> Option<std::time::SystemTime> {
+        fs::metadata(path).and_then(|m| m.modified()).ok()
+    }
+
+    let mut last_modified = modified_at(&config_path);
+
+    std::thread::spawn(move || loop {
+        std::thread::sleep(CONFIG_WATCH_INTERVAL);
+
+        let modified = modified_at(&config_path);
+
+        if modified.is_some() && modified != last_modified {
+            last_modified = modified;
+
+            info!(
+                "reloading configuration (file {} was modified)",
+                config_path.display()
+            );
+
+            reload_config(&cmd, &config_path, &tx_cmd);
+        }
+    });
+}
+
+/// Load the configuration file, process it the same way as on startup,
+/// and ask the supervisor to apply the changes.
+fn reload_config(cmd: &StartCmd, config_path: &Path, tx_cmd: &Sender<SupervisorCmd>) {
+    let config = match ibc_relayer::config::load(config_path) {
+        Ok(config) => config,
+        Err(e) => {
+            error!(
+                "failed to reload configuration from {}: {}",
+                config_path.display(),
+                e
+            );
+            return;
+        }
+    };
+
+    let config = match CliCmd::Start(cmd.clone()).process_config(config) {
+        Ok(config) => config,
+        Err(e) => {
+            error!("failed to process the reloaded configuration: {}", e);
+            return;
+        }
+    };
+
+    tx_cmd
+        .send(SupervisorCmd::UpdateConfig(Box::new(config)))
+        .unwrap_or_else(|e| error!("failed to send the reloaded configuration: {}", e));
+}
+
 #[cfg(feature = "rest-server")]
 fn spawn_rest_server(config: &Config) -> Option<rest::Receiver> {
     use ibc_relayer::util::spawn_blocking;
@@ -187,17 +261,12 @@ fn spawn_rest_server(config: &Config) -> Option<rest::Receiver> {
 }
 
 #[cfg(feature = "telemetry")]
-fn spawn_telemetry_server(config: &Config) {
+pub(crate) fn spawn_telemetry_server(config: &Config) {
     use ibc_relayer::util::spawn_blocking;
 
     let _span = tracing::error_span!("telemetry").entered();
 
-    let state = ibc_telemetry::init(
-        config.telemetry.buckets.latency_submitted.range.clone(),
-        config.telemetry.buckets.latency_submitted.buckets,
-        config.telemetry.buckets.latency_confirmed.range.clone(),
-        config.telemetry.buckets.latency_confirmed.buckets,
-    );
+    let state = init_telemetry_state(config);
     let telemetry = config.telemetry.clone();
 
     if !telemetry.enabled {
@@ -205,6 +274,14 @@ fn spawn_telemetry_server(config: &Config) {
         return;
     }
 
+    if config.mode.packets.enabled && !config.mode.packets.tx_confirmation {
+        warn!(
+            "transaction confirmation is disabled, the `tx_latency_confirmed`, `packet_recv_latency` \
+             and `packet_ack_latency` metrics will not be recorded; set `tx_confirmation = true` \
+             in the `[mode.packets]` section of the configuration to record them"
+        );
+    }
+
     spawn_blocking(async move {
         let result = ibc_telemetry::spawn((telemetry.host, telemetry.port), state.clone());
 
@@ -221,14 +298,79 @@ fn spawn_telemetry_server(config: &Config) {
     });
 }
 
+#[cfg(feature = "otlp")]
+fn init_telemetry_state(config: &Config) -> &'static std::sync::Arc<ibc_telemetry::TelemetryState> {
+    let buckets = &config.telemetry.buckets;
+
+    if config.telemetry.otlp.enabled {
+        let settings = crate::components::otlp_settings(&config.telemetry.otlp);
+
+        let result = ibc_telemetry::init_with_otlp(
+            buckets.latency_submitted.range.clone(),
+            buckets.latency_submitted.buckets,
+            buckets.latency_confirmed.range.clone(),
+            buckets.latency_confirmed.buckets,
+            &settings,
+        );
+
+        match result {
+            Ok(state) => {
+                info!(
+                    "exporting metrics to the OpenTelemetry collector at {}",
+                    settings.endpoint
+                );
+
+                return state;
+            }
+            Err(e) => error!(
+                "failed to export metrics to the OpenTelemetry collector at {}: {e}",
+                settings.endpoint
+            ),
+        }
+    }
+
+    ibc_telemetry::init(
+        buckets.latency_submitted.range.clone(),
+        buckets.latency_submitted.buckets,
+        buckets.latency_confirmed.range.clone(),
+        buckets.latency_confirmed.buckets,
+    )
+}
+
+#[cfg(all(feature = "telemetry", not(feature = "otlp")))]
+fn init_telemetry_state(config: &Config) -> &'static std::sync::Arc<ibc_telemetry::TelemetryState> {
+    if config.telemetry.otlp.enabled {
+        warn_otlp_unsupported();
+    }
+
+    ibc_telemetry::init(
+        config.telemetry.buckets.latency_submitted.range.clone(),
+        config.telemetry.buckets.latency_submitted.buckets,
+        config.telemetry.buckets.latency_confirmed.range.clone(),
+        config.telemetry.buckets.latency_confirmed.buckets,
+    )
+}
+
 #[cfg(not(feature = "telemetry"))]
-fn spawn_telemetry_server(config: &Config) {
+pub(crate) fn spawn_telemetry_server(config: &Config) {
     if config.telemetry.enabled {
         warn!(
             "telemetry enabled in the config but Hermes was built without telemetry support, \
              build Hermes with --features=telemetry to enable telemetry support."
         );
     }
+
+    if config.telemetry.otlp.enabled {
+        warn_otlp_unsupported();
+    }
+}
+
+#[cfg(not(feature = "otlp"))]
+fn warn_otlp_unsupported() {
+    warn!(
+        "OTLP export enabled in the config but Hermes was built without OTLP support, \
+         build Hermes with --features=otlp to enable the export of traces and metrics."
+    );
 }
 
 fn make_supervisor<Chain: ChainHandle>(
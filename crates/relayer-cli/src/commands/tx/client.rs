@@ -1,3 +1,5 @@
+#![allow(unused_qualifications)] // Fix for warning in `ValueEnum` generated code
+
 use core::{
     fmt::{Display, Error as FmtError, Formatter},
     time::Duration,
@@ -6,6 +8,7 @@ use std::thread;
 
 use abscissa_core::clap::Parser;
 use abscissa_core::{Command, Runnable};
+use clap::ValueEnum;
 
 use ibc_relayer::config::Config;
 use ibc_relayer::event::IbcEventWithHeight;
@@ -20,6 +23,7 @@ use ibc_relayer::{
 use ibc_relayer_types::core::ics24_host::identifier::{ChainId, ClientId};
 use ibc_relayer_types::events::IbcEvent;
 use ibc_relayer_types::Height;
+use subtle_encoding::hex;
 use tendermint::block::Height as BlockHeight;
 use tendermint_light_client_verifier::types::TrustThreshold;
 use tendermint_rpc::Url;
@@ -75,6 +79,46 @@ pub struct TxCreateClientCmd {
     /// and trusted validator set is sufficient for a commit to be accepted going forward.
     #[clap(long = "trust-threshold", value_name = "TRUST_THRESHOLD", parse(try_from_str = parse_trust_threshold))]
     trust_threshold: Option<TrustThreshold>,
+
+    /// The type of client to create.
+    ///
+    /// A Wasm client wraps a Tendermint client whose verification algorithm
+    /// runs as the Wasm light client contract identified by `--checksum`.
+    #[clap(
+        long = "client-type",
+        value_name = "CLIENT_TYPE",
+        value_enum,
+        default_value = "tendermint"
+    )]
+    client_type: CliClientType,
+
+    /// The hex-encoded checksum of the Wasm light client contract stored on
+    /// the host chain. Required with `--client-type wasm`.
+    #[clap(long = "checksum", value_name = "CHECKSUM")]
+    checksum: Option<String>,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, ValueEnum)]
+pub enum CliClientType {
+    Tendermint,
+    Wasm,
+}
+
+impl TxCreateClientCmd {
+    fn wasm_checksum(&self) -> Result<Option<Vec<u8>>, Error> {
+        match (self.client_type, &self.checksum) {
+            (CliClientType::Tendermint, None) => Ok(None),
+            (CliClientType::Tendermint, Some(_)) => Err(Error::cli_arg(
+                "--checksum can only be used with --client-type wasm".into(),
+            )),
+            (CliClientType::Wasm, None) => Err(Error::cli_arg(
+                "--checksum is required with --client-type wasm".into(),
+            )),
+            (CliClientType::Wasm, Some(checksum)) => hex::decode(checksum.to_lowercase())
+                .map(Some)
+                .map_err(|e| Error::cli_arg(format!("invalid hex-encoded checksum: {e}"))),
+        }
+    }
 }
 
 /// Sample to run this tx:
@@ -87,6 +131,11 @@ impl Runnable for TxCreateClientCmd {
             Output::error("source and destination chains must be different".to_string()).exit()
         }
 
+        let wasm_checksum = match self.wasm_checksum() {
+            Ok(wasm_checksum) => wasm_checksum,
+            Err(e) => Output::error(e).exit(),
+        };
+
         let chains = match ChainHandlePair::spawn(&config, &self.src_chain_id, &self.dst_chain_id) {
             Ok(chains) => chains,
             Err(e) => Output::error(e).exit(),
@@ -98,6 +147,7 @@ impl Runnable for TxCreateClientCmd {
             max_clock_drift: self.clock_drift.map(Into::into),
             trusting_period: self.trusting_period.map(Into::into),
             trust_threshold: self.trust_threshold.map(Into::into),
+            wasm_checksum,
         };
 
         // Trigger client creation via the "build" interface, so that we obtain the resulting event
@@ -209,6 +259,13 @@ impl Runnable for TxUpdateClientCmd {
                     ChainConfig::CosmosSdk(chain_config) => {
                         chain_config.genesis_restart = Some(restart_params)
                     }
+                    ChainConfig::Mock(_) | ChainConfig::SoloMachine(_) => {
+                        Output::error(format!(
+                            "Chain '{}' does not support genesis restarts",
+                            reference_chain_id
+                        ))
+                        .exit();
+                    }
                 },
                 None => {
                     Output::error(format!(
@@ -609,8 +666,8 @@ impl OutputBuffer {
 #[cfg(test)]
 mod tests {
     use super::{
-        parse_trust_threshold, TxCreateClientCmd, TxUpdateClientCmd, TxUpgradeClientCmd,
-        TxUpgradeClientsCmd,
+        parse_trust_threshold, CliClientType, TxCreateClientCmd, TxUpdateClientCmd,
+        TxUpgradeClientCmd, TxUpgradeClientsCmd,
     };
 
     use std::str::FromStr;
@@ -643,7 +700,9 @@ mod tests {
                 src_chain_id: ChainId::from_string("reference_chain"),
                 clock_drift: None,
                 trusting_period: None,
-                trust_threshold: None
+                trust_threshold: None,
+                client_type: CliClientType::Tendermint,
+                checksum: None
             },
             TxCreateClientCmd::parse_from([
                 "test",
@@ -663,7 +722,9 @@ mod tests {
                 src_chain_id: ChainId::from_string("reference_chain"),
                 clock_drift: Some("5s".parse::<Duration>().unwrap()),
                 trusting_period: None,
-                trust_threshold: None
+                trust_threshold: None,
+                client_type: CliClientType::Tendermint,
+                checksum: None
             },
             TxCreateClientCmd::parse_from([
                 "test",
@@ -681,7 +742,9 @@ mod tests {
                 src_chain_id: ChainId::from_string("reference_chain"),
                 clock_drift: Some("3s".parse::<Duration>().unwrap()),
                 trusting_period: None,
-                trust_threshold: None
+                trust_threshold: None,
+                client_type: CliClientType::Tendermint,
+                checksum: None
             },
             TxCreateClientCmd::parse_from([
                 "test",
@@ -703,7 +766,9 @@ mod tests {
                 src_chain_id: ChainId::from_string("reference_chain"),
                 clock_drift: None,
                 trusting_period: Some("5s".parse::<Duration>().unwrap()),
-                trust_threshold: None
+                trust_threshold: None,
+                client_type: CliClientType::Tendermint,
+                checksum: None
             },
             TxCreateClientCmd::parse_from([
                 "test",
@@ -721,7 +786,9 @@ mod tests {
                 src_chain_id: ChainId::from_string("reference_chain"),
                 clock_drift: None,
                 trusting_period: Some("3s".parse::<Duration>().unwrap()),
-                trust_threshold: None
+                trust_threshold: None,
+                client_type: CliClientType::Tendermint,
+                checksum: None
             },
             TxCreateClientCmd::parse_from([
                 "test",
@@ -743,7 +810,9 @@ mod tests {
                 src_chain_id: ChainId::from_string("reference_chain"),
                 clock_drift: None,
                 trusting_period: None,
-                trust_threshold: Some(TrustThreshold::new(1, 2).unwrap())
+                trust_threshold: Some(TrustThreshold::new(1, 2).unwrap()),
+                client_type: CliClientType::Tendermint,
+                checksum: None
             },
             TxCreateClientCmd::parse_from([
                 "test",
@@ -765,7 +834,9 @@ mod tests {
                 src_chain_id: ChainId::from_string("reference_chain"),
                 clock_drift: Some("5s".parse::<Duration>().unwrap()),
                 trusting_period: Some("3s".parse::<Duration>().unwrap()),
-                trust_threshold: Some(TrustThreshold::new(1, 2).unwrap())
+                trust_threshold: Some(TrustThreshold::new(1, 2).unwrap()),
+                client_type: CliClientType::Tendermint,
+                checksum: None
             },
             TxCreateClientCmd::parse_from([
                 "test",
@@ -783,6 +854,49 @@ mod tests {
         )
     }
 
+    #[test]
+    fn test_create_client_wasm() {
+        let cmd = TxCreateClientCmd::parse_from([
+            "test",
+            "--host-chain",
+            "host_chain",
+            "--reference-chain",
+            "reference_chain",
+            "--client-type",
+            "wasm",
+            "--checksum",
+            "0A0b",
+        ]);
+
+        assert_eq!(cmd.client_type, CliClientType::Wasm);
+        assert_eq!(cmd.wasm_checksum().unwrap(), Some(vec![0x0a, 0x0b]));
+    }
+
+    #[test]
+    fn test_create_client_checksum_requires_wasm() {
+        let cmd = TxCreateClientCmd::parse_from([
+            "test",
+            "--host-chain",
+            "host_chain",
+            "--reference-chain",
+            "reference_chain",
+            "--checksum",
+            "0a0b",
+        ]);
+        assert!(cmd.wasm_checksum().is_err());
+
+        let cmd = TxCreateClientCmd::parse_from([
+            "test",
+            "--host-chain",
+            "host_chain",
+            "--reference-chain",
+            "reference_chain",
+            "--client-type",
+            "wasm",
+        ]);
+        assert!(cmd.wasm_checksum().is_err());
+    }
+
     #[test]
     fn test_create_client_no_host_chain() {
         assert!(TxCreateClientCmd::try_parse_from([
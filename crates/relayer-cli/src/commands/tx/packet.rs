@@ -91,6 +91,14 @@ impl Runnable for TxPacketRecvCmd {
 
             // Packets are only excluded when clearing
             exclude_src_sequences: vec![],
+            ics20_filter: config
+                .find_chain(&self.src_chain_id)
+                .map(|chain_config| {
+                    chain_config
+                        .packet_filter()
+                        .ics20_filter(&self.src_channel_id)
+                })
+                .unwrap_or_default(),
         };
 
         let link = match Link::new_from_opts(chains.src, chains.dst, opts, false, false) {
@@ -192,6 +200,14 @@ impl Runnable for TxPacketAckCmd {
 
             // Packets are only excluded when clearing
             exclude_src_sequences: vec![],
+            ics20_filter: config
+                .find_chain(&self.src_chain_id)
+                .map(|chain_config| {
+                    chain_config
+                        .packet_filter()
+                        .ics20_filter(&self.src_channel_id)
+                })
+                .unwrap_or_default(),
         };
 
         let link = match Link::new_from_opts(chains.src, chains.dst, opts, false, false) {
@@ -0,0 +1,281 @@
+#![allow(unused_qualifications)] // Fix for warning in `ValueEnum` generated code
+
+use abscissa_core::clap::Parser;
+use abscissa_core::{Command, Runnable};
+use clap::ValueEnum;
+use serde_json::Value;
+
+use ibc_relayer::config::RestConfig;
+use ibc_relayer::object::{Channel, Client, Connection, Object, Packet};
+use ibc_relayer_types::core::ics24_host::identifier::{
+    ChainId, ChannelId, ClientId, ConnectionId, PortId,
+};
+
+use crate::application::app_config;
+use crate::conclude::Output;
+use crate::error::Error;
+
+/// `worker` subcommands
+#[derive(Command, Debug, Parser, Runnable)]
+pub enum WorkerCmds {
+    /// Pause a worker of a running Hermes instance, until it is resumed.
+    /// Requires the REST server of the running instance to be enabled.
+    Pause(PauseWorkerCmd),
+
+    /// Resume a paused worker of a running Hermes instance.
+    /// Requires the REST server of the running instance to be enabled.
+    Resume(ResumeWorkerCmd),
+}
+
+#[derive(Debug, Parser, Command, PartialEq, Eq)]
+pub struct PauseWorkerCmd {
+    #[clap(flatten)]
+    object: WorkerObjectArgs,
+}
+
+impl Runnable for PauseWorkerCmd {
+    fn run(&self) {
+        let object = match self.object.to_object() {
+            Ok(object) => object,
+            Err(e) => Output::error(e).exit(),
+        };
+
+        match send_request(&app_config().rest, "pause", &object) {
+            Ok(()) => Output::success_msg(format!("paused worker {}", object.short_name())).exit(),
+            Err(e) => Output::error(e).exit(),
+        }
+    }
+}
+
+#[derive(Debug, Parser, Command, PartialEq, Eq)]
+pub struct ResumeWorkerCmd {
+    #[clap(flatten)]
+    object: WorkerObjectArgs,
+}
+
+impl Runnable for ResumeWorkerCmd {
+    fn run(&self) {
+        let object = match self.object.to_object() {
+            Ok(object) => object,
+            Err(e) => Output::error(e).exit(),
+        };
+
+        match send_request(&app_config().rest, "resume", &object) {
+            Ok(()) => Output::success_msg(format!("resumed worker {}", object.short_name())).exit(),
+            Err(e) => Output::error(e).exit(),
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, ValueEnum)]
+pub enum WorkerObjectType {
+    Client,
+    Connection,
+    Channel,
+    Packet,
+}
+
+/// The arguments identifying the object of a worker.
+#[derive(Debug, Parser, PartialEq, Eq)]
+pub struct WorkerObjectArgs {
+    #[clap(
+        long = "type",
+        required = true,
+        value_name = "TYPE",
+        value_enum,
+        help_heading = "REQUIRED",
+        help = "Type of the worker"
+    )]
+    object_type: WorkerObjectType,
+
+    #[clap(
+        long = "src-chain",
+        required = true,
+        value_name = "SRC_CHAIN_ID",
+        help_heading = "REQUIRED",
+        help = "Identifier of the source chain of the worker"
+    )]
+    src_chain_id: ChainId,
+
+    #[clap(
+        long = "dst-chain",
+        required = true,
+        value_name = "DST_CHAIN_ID",
+        help_heading = "REQUIRED",
+        help = "Identifier of the destination chain of the worker"
+    )]
+    dst_chain_id: ChainId,
+
+    #[clap(
+        long = "client",
+        value_name = "CLIENT_ID",
+        help = "Identifier of the client on the destination chain, for client workers"
+    )]
+    client_id: Option<ClientId>,
+
+    #[clap(
+        long = "connection",
+        alias = "conn",
+        value_name = "CONNECTION_ID",
+        help = "Identifier of the connection on the source chain, for connection workers"
+    )]
+    connection_id: Option<ConnectionId>,
+
+    #[clap(
+        long = "port",
+        value_name = "PORT_ID",
+        help = "Identifier of the port on the source chain, for channel and packet workers"
+    )]
+    port_id: Option<PortId>,
+
+    #[clap(
+        long = "channel",
+        alias = "chan",
+        value_name = "CHANNEL_ID",
+        help = "Identifier of the channel on the source chain, for channel and packet workers"
+    )]
+    channel_id: Option<ChannelId>,
+}
+
+impl WorkerObjectArgs {
+    fn to_object(&self) -> Result<Object, Error> {
+        fn required<T: Clone>(value: &Option<T>, flag: &str, tpe: &str) -> Result<T, Error> {
+            value
+                .clone()
+                .ok_or_else(|| Error::cli_arg(format!("{flag} is required for {tpe} workers")))
+        }
+
+        let dst_chain_id = self.dst_chain_id.clone();
+        let src_chain_id = self.src_chain_id.clone();
+
+        let object = match self.object_type {
+            WorkerObjectType::Client => Object::Client(Client {
+                dst_chain_id,
+                dst_client_id: required(&self.client_id, "--client", "client")?,
+                src_chain_id,
+            }),
+            WorkerObjectType::Connection => Object::Connection(Connection {
+                dst_chain_id,
+                src_chain_id,
+                src_connection_id: required(&self.connection_id, "--connection", "connection")?,
+            }),
+            WorkerObjectType::Channel => Object::Channel(Channel {
+                dst_chain_id,
+                src_chain_id,
+                src_channel_id: required(&self.channel_id, "--channel", "channel")?,
+                src_port_id: required(&self.port_id, "--port", "channel")?,
+            }),
+            WorkerObjectType::Packet => Object::Packet(Packet {
+                dst_chain_id,
+                src_chain_id,
+                src_channel_id: required(&self.channel_id, "--channel", "packet")?,
+                src_port_id: required(&self.port_id, "--port", "packet")?,
+            }),
+        };
+
+        Ok(object)
+    }
+}
+
+/// Send a pause or resume request for the given object
+/// to the REST server of the running Hermes instance.
+fn send_request(rest: &RestConfig, action: &str, object: &Object) -> Result<(), String> {
+    if !rest.enabled {
+        return Err(
+            "the REST server must be enabled in the configuration to manage the workers"
+                .to_string(),
+        );
+    }
+
+    let url = format!("http://{}:{}/worker/{action}", rest.host, rest.port);
+
+    let response: Value = reqwest::blocking::Client::new()
+        .post(&url)
+        .json(object)
+        .send()
+        .and_then(|response| response.json())
+        .map_err(|e| format!("failed to send request to '{url}': {e}"))?;
+
+    match response["status"].as_str() {
+        Some("success") => Ok(()),
+        _ => Err(response["result"]["msg"]
+            .as_str()
+            .map(ToString::to_string)
+            .unwrap_or_else(|| format!("unexpected response from '{url}': {response}"))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{WorkerCmds, WorkerObjectType};
+
+    use abscissa_core::clap::Parser;
+    use ibc_relayer::object::{Object, Packet};
+    use ibc_relayer_types::core::ics24_host::identifier::{ChainId, ChannelId, PortId};
+
+    #[test]
+    fn test_pause_packet_worker() {
+        let cmd = WorkerCmds::parse_from([
+            "test",
+            "pause",
+            "--type",
+            "packet",
+            "--src-chain",
+            "chain_a",
+            "--dst-chain",
+            "chain_b",
+            "--port",
+            "transfer",
+            "--channel",
+            "channel-0",
+        ]);
+
+        let WorkerCmds::Pause(cmd) = cmd else {
+            panic!("expected a pause command");
+        };
+
+        assert_eq!(cmd.object.object_type, WorkerObjectType::Packet);
+        assert_eq!(
+            cmd.object.to_object().unwrap(),
+            Object::Packet(Packet {
+                dst_chain_id: ChainId::from_string("chain_b"),
+                src_chain_id: ChainId::from_string("chain_a"),
+                src_channel_id: ChannelId::new(0),
+                src_port_id: PortId::transfer(),
+            })
+        );
+    }
+
+    #[test]
+    fn test_resume_client_worker_missing_client() {
+        let cmd = WorkerCmds::parse_from([
+            "test",
+            "resume",
+            "--type",
+            "client",
+            "--src-chain",
+            "chain_a",
+            "--dst-chain",
+            "chain_b",
+        ]);
+
+        let WorkerCmds::Resume(cmd) = cmd else {
+            panic!("expected a resume command");
+        };
+
+        assert!(cmd.object.to_object().is_err());
+    }
+
+    #[test]
+    fn test_pause_worker_missing_type() {
+        assert!(WorkerCmds::try_parse_from([
+            "test",
+            "pause",
+            "--src-chain",
+            "chain_a",
+            "--dst-chain",
+            "chain_b"
+        ])
+        .is_err())
+    }
+}
@@ -1,10 +1,14 @@
 //! Various components for internal use by the Abscissa subsystem.
 
 use abscissa_core::{Component, FrameworkError, FrameworkErrorKind};
-use tracing_subscriber::{filter::EnvFilter, util::SubscriberInitExt, FmtSubscriber};
+use tracing::Subscriber;
+use tracing_subscriber::{
+    filter::EnvFilter, layer::SubscriberExt, registry::LookupSpan, util::SubscriberInitExt,
+    FmtSubscriber, Layer,
+};
 
 use ibc_relayer::{
-    config::{GlobalConfig, LogLevel},
+    config::{GlobalConfig, LogLevel, OtlpConfig},
     util::debug_section::DebugSection,
 };
 
@@ -25,8 +29,14 @@ const HERMES_LOG_VAR: &str = "RUST_LOG";
 pub struct JsonTracing;
 
 impl JsonTracing {
-    /// Creates a new [`JsonTracing`] component
-    pub fn new(cfg: GlobalConfig, debug_sections: &[DebugSection]) -> Result<Self, FrameworkError> {
+    /// Creates a new [`JsonTracing`] component.
+    ///
+    /// The spans are also exported to an OpenTelemetry collector if `otlp` is enabled.
+    pub fn new(
+        cfg: GlobalConfig,
+        debug_sections: &[DebugSection],
+        otlp: Option<&OtlpConfig>,
+    ) -> Result<Self, FrameworkError> {
         let filter = build_tracing_filter(cfg.log_level, debug_sections)?;
         // Note: JSON formatter is un-affected by ANSI 'color' option. Set to 'false'.
         let use_color = false;
@@ -40,7 +50,7 @@ impl JsonTracing {
             .with_thread_ids(true)
             .json();
 
-        let subscriber = builder.finish();
+        let subscriber = builder.finish().with(otlp_layer(otlp)?);
         subscriber.init();
 
         Ok(Self)
@@ -76,9 +86,13 @@ impl PrettyTracing {
         Ok(Self)
     }
 
+    /// Creates a new [`PrettyTracing`] component, along with a handle to reload its filter.
+    ///
+    /// The spans are also exported to an OpenTelemetry collector if `otlp` is enabled.
     pub fn new_with_reload_handle(
         cfg: GlobalConfig,
         debug_sections: &[DebugSection],
+        otlp: Option<&OtlpConfig>,
     ) -> Result<(Self, ReloadHandle<impl tracing::Subscriber + 'static>), FrameworkError> {
         let filter = build_tracing_filter(cfg.log_level, debug_sections)?;
 
@@ -93,13 +107,60 @@ impl PrettyTracing {
 
         let reload_handle = builder.reload_handle();
 
-        let subscriber = builder.finish();
+        let subscriber = builder.finish().with(otlp_layer(otlp)?);
         subscriber.init();
 
         Ok((Self, reload_handle))
     }
 }
 
+/// A layer of the tracing subscriber, whose type depends on the enabled features.
+type BoxedLayer<S> = Box<dyn Layer<S> + Send + Sync>;
+
+/// Builds the layer exporting the spans to an OpenTelemetry collector over OTLP,
+/// if enabled in the given configuration.
+#[cfg(feature = "otlp")]
+fn otlp_layer<S>(config: Option<&OtlpConfig>) -> Result<Option<BoxedLayer<S>>, FrameworkError>
+where
+    S: Subscriber + for<'span> LookupSpan<'span>,
+{
+    let Some(config) = config.filter(|config| config.enabled) else {
+        return Ok(None);
+    };
+
+    match ibc_telemetry::otlp::layer(&otlp_settings(config)) {
+        Ok(layer) => Ok(Some(Box::new(layer))),
+        Err(e) => {
+            eprintln!(
+                "ERROR: unable to export traces to the OpenTelemetry collector at {}: {e}",
+                config.endpoint
+            );
+
+            Err(FrameworkErrorKind::ComponentError.context(e).into())
+        }
+    }
+}
+
+/// Without the `otlp` feature, the spans are not exported.
+/// A warning is emitted when starting Hermes if OTLP export is enabled.
+#[cfg(not(feature = "otlp"))]
+fn otlp_layer<S>(_config: Option<&OtlpConfig>) -> Result<Option<BoxedLayer<S>>, FrameworkError>
+where
+    S: Subscriber + for<'span> LookupSpan<'span>,
+{
+    Ok(None)
+}
+
+/// The settings of the OTLP exporters, out of the `[telemetry.otlp]` configuration section.
+#[cfg(feature = "otlp")]
+pub fn otlp_settings(config: &OtlpConfig) -> ibc_telemetry::otlp::OtlpSettings {
+    ibc_telemetry::otlp::OtlpSettings {
+        endpoint: config.endpoint.clone(),
+        service_name: config.service_name.clone(),
+        export_interval: config.export_interval,
+    }
+}
+
 /// Check if both stdout and stderr are proper terminal (tty),
 /// so that we know whether or not to enable colored output,
 /// using ANSI escape codes. If either is not, eg. because
@@ -16,6 +16,7 @@ use ibc_relayer::error::Error as RelayerError;
 use ibc_relayer::foreign_client::ForeignClientError;
 use ibc_relayer::keyring::errors::Error as KeyRingError;
 use ibc_relayer::link::error::LinkError;
+use ibc_relayer::recover_client::RecoverClientError;
 use ibc_relayer::spawn::SpawnError;
 use ibc_relayer::supervisor::Error as SupervisorError;
 use ibc_relayer::transfer::TransferError;
@@ -65,6 +66,20 @@ define_error! {
                     e.chain_id)
             },
 
+        MissingFeeGranter
+            { chain_id: ChainId }
+            | e | {
+                format_args!("no `fee_granter` configured for chain '{}'",
+                    e.chain_id)
+            },
+
+        GranterKeyMismatch
+            { key_name: String, account: String, granter: String }
+            | e | {
+                format_args!("the account '{}' of key '{}' is not the fee granter '{}'",
+                    e.account, e.key_name, e.granter)
+            },
+
         MissingCounterpartyChannelId
             { channel_end: IdentifiedChannelEnd }
             | e | {
@@ -112,6 +127,10 @@ define_error! {
             [ UpgradeChainError ]
             |_| { "upgrade chain error" },
 
+        RecoverClient
+            [ RecoverClientError ]
+            |_| { "recover client error" },
+
         Signer
             [ SignerError ]
             |_| { "signer error" },
@@ -6,13 +6,20 @@ use crossbeam_channel as channel;
 
 use ibc_relayer::supervisor::dump_state::SupervisorState;
 use ibc_relayer::{
-    config::ChainConfig,
+    chain::counterparty::PacketTrace,
+    config::{filter::PacketFilter, ChainConfig},
+    event::{bus::BoundedSubscription, relay::RelayEvent},
+    object::Object,
+    profitability::ChannelProfitability,
     rest::{
         request::{reply_channel, ReplySender, Request, VersionInfo},
         RestApiError,
     },
 };
-use ibc_relayer_types::core::ics24_host::identifier::ChainId;
+use ibc_relayer_types::core::{
+    ics04_channel::packet::Sequence,
+    ics24_host::identifier::{ChainId, ChannelId, PortId},
+};
 
 pub const NAME: &str = env!(
     "CARGO_PKG_NAME",
@@ -44,6 +51,13 @@ where
         .map_err(|e| RestApiError::ChannelRecv(e.to_string()))?
 }
 
+/// Subscribe to the events published by the relayer.
+pub fn subscribe_events(
+    sender: &channel::Sender<Request>,
+) -> Result<BoundedSubscription<RelayEvent>, RestApiError> {
+    submit_request(sender, |reply_to| Request::SubscribeEvents { reply_to })
+}
+
 pub fn all_chain_ids(sender: &channel::Sender<Request>) -> Result<Vec<ChainId>, RestApiError> {
     submit_request(sender, |reply_to| Request::GetChains { reply_to })
 }
@@ -76,6 +90,85 @@ pub fn trigger_clear_packets(
     })
 }
 
+/// Submit a request to add the given chain to the relayer, and start relaying on it.
+pub fn add_chain(
+    sender: &channel::Sender<Request>,
+    chain_config: ChainConfig,
+) -> Result<(), RestApiError> {
+    submit_request(sender, |reply_to| Request::AddChain {
+        chain_config: Box::new(chain_config),
+        reply_to,
+    })
+}
+
+/// Submit a request to stop relaying on the chain with the
+/// specified `chain_id`, and remove it from the relayer.
+pub fn remove_chain(sender: &channel::Sender<Request>, chain_id: &str) -> Result<(), RestApiError> {
+    submit_request(sender, |reply_to| Request::RemoveChain {
+        chain_id: ChainId::from_string(chain_id),
+        reply_to,
+    })
+}
+
+/// Submit a request to replace the packet filter of the chain
+/// with the specified `chain_id`.
+pub fn update_packet_filter(
+    sender: &channel::Sender<Request>,
+    chain_id: &str,
+    packet_filter: PacketFilter,
+) -> Result<(), RestApiError> {
+    submit_request(sender, |reply_to| Request::UpdatePacketFilter {
+        chain_id: ChainId::from_string(chain_id),
+        packet_filter,
+        reply_to,
+    })
+}
+
+/// Submit a request to pause the worker for the given object.
+pub fn pause_worker(sender: &channel::Sender<Request>, object: Object) -> Result<(), RestApiError> {
+    submit_request(sender, |reply_to| Request::PauseWorker { object, reply_to })
+}
+
+/// Submit a request to resume the worker for the given object.
+pub fn resume_worker(
+    sender: &channel::Sender<Request>,
+    object: Object,
+) -> Result<(), RestApiError> {
+    submit_request(sender, |reply_to| Request::ResumeWorker {
+        object,
+        reply_to,
+    })
+}
+
+/// Submit a request to trace the lifecycle of the given packet.
+pub fn packet_trace(
+    sender: &channel::Sender<Request>,
+    chain_id: ChainId,
+    port_id: PortId,
+    channel_id: ChannelId,
+    sequence: Sequence,
+) -> Result<PacketTrace, RestApiError> {
+    submit_request(sender, |reply_to| Request::PacketTrace {
+        chain_id,
+        port_id,
+        channel_id,
+        sequence,
+        reply_to,
+    })
+}
+
+/// Submit a request for the profitability of relaying on each channel,
+/// optionally restricted to the channels with an end on the given chain.
+pub fn fee_profitability(
+    sender: &channel::Sender<Request>,
+    chain_id: Option<ChainId>,
+) -> Result<Vec<ChannelProfitability>, RestApiError> {
+    submit_request(sender, |reply_to| Request::FeeProfitability {
+        chain_id,
+        reply_to,
+    })
+}
+
 pub fn assemble_version_info(sender: &channel::Sender<Request>) -> Vec<VersionInfo> {
     // Fetch the relayer library version
     let lib_version = submit_request(sender, |reply_to| Request::Version { reply_to })
@@ -1,27 +1,44 @@
 use std::{
     error::Error,
     net::{SocketAddr, ToSocketAddrs},
+    time::Duration,
 };
 
 use axum::{
     extract::{Path, Query},
-    response::IntoResponse,
-    routing::{get, post},
+    response::{
+        sse::{Event, KeepAlive, Sse},
+        IntoResponse, Response,
+    },
+    routing::{get, post, put},
     Extension, Json, Router, Server,
 };
-use crossbeam_channel as channel;
-use ibc_relayer_types::core::ics24_host::identifier::ChainId;
+use crossbeam_channel::{self as channel, TryRecvError};
+use ibc_relayer_types::core::{
+    ics04_channel::packet::Sequence,
+    ics24_host::identifier::{ChainId, ChannelId, PortId},
+};
 use serde::{Deserialize, Serialize};
-use tokio::task::JoinHandle;
-
-use ibc_relayer::rest::{request::Request, RestApiError};
+use tokio::{sync::mpsc, task::JoinHandle};
+use tokio_stream::{wrappers::ReceiverStream, Stream};
+
+use ibc_relayer::{
+    config::{filter::PacketFilter, ChainConfig},
+    event::{bus::BoundedSubscription, relay::RelayEvent},
+    object::Object,
+    rest::{request::Request, RestApiError},
+};
 
 use crate::handle::{
-    all_chain_ids, assemble_version_info, chain_config, supervisor_state, trigger_clear_packets,
+    self, all_chain_ids, assemble_version_info, chain_config, supervisor_state,
+    trigger_clear_packets,
 };
 
 pub type BoxError = Box<dyn Error + Send + Sync>;
 
+/// How often the forwarding of relay events checks for new events when there are none.
+const EVENTS_POLL_INTERVAL: Duration = Duration::from_millis(100);
+
 pub fn spawn(
     addr: impl ToSocketAddrs,
     sender: channel::Sender<Request>,
@@ -66,6 +83,47 @@ async fn get_chain(
     Json(JsonResult::from(chain))
 }
 
+async fn add_chain(
+    Extension(sender): Extension<Sender>,
+    Json(chain_config): Json<ChainConfig>,
+) -> impl IntoResponse {
+    let result = handle::add_chain(&sender, chain_config);
+    Json(JsonResult::from(result))
+}
+
+async fn remove_chain(
+    Path(id): Path<String>,
+    Extension(sender): Extension<Sender>,
+) -> impl IntoResponse {
+    let result = handle::remove_chain(&sender, &id);
+    Json(JsonResult::from(result))
+}
+
+async fn update_packet_filter(
+    Path(id): Path<String>,
+    Extension(sender): Extension<Sender>,
+    Json(packet_filter): Json<PacketFilter>,
+) -> impl IntoResponse {
+    let result = handle::update_packet_filter(&sender, &id, packet_filter);
+    Json(JsonResult::from(result))
+}
+
+async fn pause_worker(
+    Extension(sender): Extension<Sender>,
+    Json(object): Json<Object>,
+) -> impl IntoResponse {
+    let result = handle::pause_worker(&sender, object);
+    Json(JsonResult::from(result))
+}
+
+async fn resume_worker(
+    Extension(sender): Extension<Sender>,
+    Json(object): Json<Object>,
+) -> impl IntoResponse {
+    let result = handle::resume_worker(&sender, object);
+    Json(JsonResult::from(result))
+}
+
 async fn get_state(Extension(sender): Extension<Sender>) -> impl IntoResponse {
     let state = supervisor_state(&sender);
     Json(JsonResult::from(state))
@@ -84,15 +142,106 @@ async fn clear_packets(
     Json(JsonResult::from(result))
 }
 
+#[derive(Debug, Deserialize)]
+struct PacketTraceParams {
+    chain: ChainId,
+    port: PortId,
+    channel: ChannelId,
+    sequence: Sequence,
+}
+
+async fn packet_trace(
+    Extension(sender): Extension<Sender>,
+    Query(params): Query<PacketTraceParams>,
+) -> impl IntoResponse {
+    let result = handle::packet_trace(
+        &sender,
+        params.chain,
+        params.port,
+        params.channel,
+        params.sequence,
+    );
+    Json(JsonResult::from(result))
+}
+
+#[derive(Debug, Deserialize)]
+struct FeeProfitabilityParams {
+    chain: Option<ChainId>,
+}
+
+async fn fee_profitability(
+    Extension(sender): Extension<Sender>,
+    Query(params): Query<FeeProfitabilityParams>,
+) -> impl IntoResponse {
+    let result = handle::fee_profitability(&sender, params.chain);
+    Json(JsonResult::from(result))
+}
+
+async fn events(Extension(sender): Extension<Sender>) -> Response {
+    match handle::subscribe_events(&sender) {
+        Ok(events) => Sse::new(event_stream(events))
+            .keep_alive(KeepAlive::default())
+            .into_response(),
+        Err(e) => Json(JsonResult::<(), _>::Error(e)).into_response(),
+    }
+}
+
+/// Forward the relay events received from the relayer to the client
+/// as server-sent events, until either of them disconnects.
+///
+/// The relayer drops the events for a client which does not keep up with them.
+/// The client is then sent an `events_dropped` event, whose data is the number
+/// of events it missed, once it has caught up with the events still buffered.
+fn event_stream(
+    events: BoundedSubscription<RelayEvent>,
+) -> impl Stream<Item = Result<Event, axum::Error>> {
+    let (tx, rx) = mpsc::channel(1);
+
+    tokio::spawn(async move {
+        loop {
+            let event = match events.try_recv() {
+                Ok(event) => Event::default().json_data(event).map_err(axum::Error::new),
+                // The events dropped before the relayer disconnects are still reported
+                Err(e) => match events.take_dropped() {
+                    0 => {
+                        if e == TryRecvError::Disconnected || tx.is_closed() {
+                            break;
+                        }
+
+                        tokio::time::sleep(EVENTS_POLL_INTERVAL).await;
+                        continue;
+                    }
+                    dropped => Ok(Event::default()
+                        .event("events_dropped")
+                        .data(dropped.to_string())),
+                },
+            };
+
+            if tx.send(event).await.is_err() {
+                break;
+            }
+        }
+    });
+
+    ReceiverStream::new(rx)
+}
+
 type Sender = channel::Sender<Request>;
 
 async fn run(addr: SocketAddr, sender: Sender) {
     let app = Router::new()
         .route("/version", get(get_version))
         .route("/chains", get(get_chains))
-        .route("/chain/:id", get(get_chain))
+        .route("/chain", post(add_chain))
+        .route("/chain/:id", get(get_chain).delete(remove_chain))
+        .route("/chain/:id/packet_filter", put(update_packet_filter))
         .route("/state", get(get_state))
+        .route("/worker/pause", post(pause_worker))
+        .route("/worker/resume", post(resume_worker))
         .route("/clear_packets", post(clear_packets))
+        .route("/packet/trace", get(packet_trace))
+        .route("/fee/profitability", get(fee_profitability))
+        .route("/events", get(events))
         .layer(Extension(sender));
 
     Server::bind(&addr)
@@ -1,13 +1,24 @@
 use std::{fmt::Debug, str::FromStr, time::Duration};
 
+use reqwest::Method;
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
 
 use ibc_relayer::{
-    config::ChainConfig,
+    chain::counterparty::{PacketEventInfo, PacketStatus, PacketTrace},
+    config::{filter::PacketFilter, ChainConfig},
+    event::{bus::EventBus, relay::RelayEvent},
+    object::{Object, Packet},
+    profitability::{ChannelKey, ChannelProfitability, DenomBalance},
     rest::request::{Request, VersionInfo},
     supervisor::dump_state::SupervisorState,
 };
-use ibc_relayer_types::core::ics24_host::identifier::ChainId;
+use ibc_relayer_types::{
+    core::{
+        ics04_channel::packet::Sequence,
+        ics24_host::identifier::{ChainId, ChannelId, PortId},
+    },
+    Height,
+};
 
 use ibc_relayer_rest::spawn;
 
@@ -28,6 +39,21 @@ async fn run_test<R, F>(port: u16, path: &str, expected: R, handler: F)
 where
     R: Serialize + DeserializeOwned + Debug + PartialEq,
     F: FnOnce(Request) -> TestResult + Send + 'static,
+{
+    run_request_test(port, Method::GET, path, None::<()>, expected, handler).await
+}
+
+async fn run_request_test<B, R, F>(
+    port: u16,
+    method: Method,
+    path: &str,
+    body: Option<B>,
+    expected: R,
+    handler: F,
+) where
+    B: Serialize,
+    R: Serialize + DeserializeOwned + Debug + PartialEq,
+    F: FnOnce(Request) -> TestResult + Send + 'static,
 {
     let (tx, rx) = crossbeam_channel::unbounded();
 
@@ -43,12 +69,14 @@ where
 
     tokio::time::sleep(Duration::from_millis(200)).await;
 
-    let response = reqwest::get(&format!("http://127.0.0.1:{port}{path}"))
-        .await
-        .unwrap()
-        .json::<R>()
-        .await
-        .unwrap();
+    let mut request =
+        reqwest::Client::new().request(method, format!("http://127.0.0.1:{port}{path}"));
+
+    if let Some(body) = body {
+        request = request.json(&body);
+    }
+
+    let response = request.send().await.unwrap().json::<R>().await.unwrap();
 
     assert_eq!(response, expected);
 
@@ -143,3 +171,311 @@ async fn state() {
     })
     .await;
 }
+
+#[tokio::test]
+async fn add_chain() {
+    let config: ChainConfig = toml::de::from_str(MOCK_CHAIN_CONFIG).unwrap();
+    let result: JsonResult<_, ()> = JsonResult::Success(());
+
+    let body = config.clone();
+    run_request_test(
+        19105,
+        Method::POST,
+        "/chain",
+        Some(body),
+        result,
+        move |req| match req {
+            Request::AddChain {
+                chain_config,
+                reply_to,
+            } if *chain_config == config => {
+                reply_to.send(Ok(())).unwrap();
+                TestResult::Success
+            }
+            req => TestResult::WrongRequest(req),
+        },
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn remove_chain() {
+    let result: JsonResult<_, ()> = JsonResult::Success(());
+
+    run_request_test(
+        19106,
+        Method::DELETE,
+        "/chain/mock-0",
+        None::<()>,
+        result,
+        |req| match req {
+            Request::RemoveChain { chain_id, reply_to }
+                if chain_id.to_string().as_str() == "mock-0" =>
+            {
+                reply_to.send(Ok(())).unwrap();
+                TestResult::Success
+            }
+            req => TestResult::WrongRequest(req),
+        },
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn update_packet_filter() {
+    let packet_filter: PacketFilter =
+        toml::de::from_str("policy = 'allow'\nlist = [['transfer', 'channel-0']]").unwrap();
+    let result: JsonResult<_, ()> = JsonResult::Success(());
+
+    let body = packet_filter.clone();
+    run_request_test(
+        19107,
+        Method::PUT,
+        "/chain/mock-0/packet_filter",
+        Some(body),
+        result,
+        move |req| match req {
+            Request::UpdatePacketFilter {
+                chain_id,
+                packet_filter: filter,
+                reply_to,
+            } if chain_id.to_string().as_str() == "mock-0" && filter == packet_filter => {
+                reply_to.send(Ok(())).unwrap();
+                TestResult::Success
+            }
+            req => TestResult::WrongRequest(req),
+        },
+    )
+    .await;
+}
+
+fn packet_object() -> Object {
+    Object::Packet(Packet {
+        dst_chain_id: ChainId::from_string("mock-1"),
+        src_chain_id: ChainId::from_string("mock-0"),
+        src_channel_id: ChannelId::new(0),
+        src_port_id: PortId::transfer(),
+    })
+}
+
+#[tokio::test]
+async fn pause_worker() {
+    let result: JsonResult<_, ()> = JsonResult::Success(());
+
+    run_request_test(
+        19108,
+        Method::POST,
+        "/worker/pause",
+        Some(packet_object()),
+        result,
+        |req| match req {
+            Request::PauseWorker { object, reply_to } if object == packet_object() => {
+                reply_to.send(Ok(())).unwrap();
+                TestResult::Success
+            }
+            req => TestResult::WrongRequest(req),
+        },
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn resume_worker() {
+    let result: JsonResult<_, ()> = JsonResult::Success(());
+
+    run_request_test(
+        19109,
+        Method::POST,
+        "/worker/resume",
+        Some(packet_object()),
+        result,
+        |req| match req {
+            Request::ResumeWorker { object, reply_to } if object == packet_object() => {
+                reply_to.send(Ok(())).unwrap();
+                TestResult::Success
+            }
+            req => TestResult::WrongRequest(req),
+        },
+    )
+    .await;
+}
+
+fn packet_trace() -> PacketTrace {
+    PacketTrace {
+        chain_id: ChainId::from_string("mock-0"),
+        port_id: PortId::transfer(),
+        channel_id: ChannelId::new(0),
+        sequence: Sequence::from(42),
+        counterparty_chain_id: ChainId::from_string("mock-1"),
+        counterparty_port_id: PortId::transfer(),
+        counterparty_channel_id: ChannelId::new(1),
+        status: PacketStatus::AwaitingReceive,
+        send: Some(PacketEventInfo {
+            height: Height::new(0, 10).unwrap(),
+            tx_hash: None,
+        }),
+        commitment_present: true,
+        received: false,
+        write_ack: None,
+        ack_written: false,
+        ack_relayed: false,
+        timed_out: false,
+        relayer: Some(vec![]),
+    }
+}
+
+#[tokio::test]
+async fn trace_packet() {
+    run_test(
+        19110,
+        "/packet/trace?chain=mock-0&port=transfer&channel=channel-0&sequence=42",
+        JsonResult::<_, ()>::Success(packet_trace()),
+        |req| match req {
+            Request::PacketTrace {
+                chain_id,
+                port_id,
+                channel_id,
+                sequence,
+                reply_to,
+            } if chain_id.as_str() == "mock-0"
+                && port_id == PortId::transfer()
+                && channel_id == ChannelId::new(0)
+                && sequence == Sequence::from(42) =>
+            {
+                reply_to.send(Ok(packet_trace())).unwrap();
+                TestResult::Success
+            }
+            req => TestResult::WrongRequest(req),
+        },
+    )
+    .await;
+}
+
+fn fee_profitability() -> Vec<ChannelProfitability> {
+    vec![ChannelProfitability {
+        channel: ChannelKey::new(
+            ChainId::from_string("mock-0"),
+            PortId::transfer(),
+            ChannelId::new(0),
+            ChainId::from_string("mock-1"),
+        ),
+        packets_relayed: 3,
+        balances: vec![DenomBalance {
+            chain_id: ChainId::from_string("mock-0"),
+            denom: "stake".to_string(),
+            fees_collected: "300".to_string(),
+            gas_paid: "450".to_string(),
+            net_profit: "-150".to_string(),
+        }],
+    }]
+}
+
+#[tokio::test]
+async fn profitability() {
+    run_test(
+        19112,
+        "/fee/profitability?chain=mock-0",
+        JsonResult::<_, ()>::Success(fee_profitability()),
+        |req| match req {
+            Request::FeeProfitability {
+                chain_id: Some(chain_id),
+                reply_to,
+            } if chain_id.as_str() == "mock-0" => {
+                reply_to.send(Ok(fee_profitability())).unwrap();
+                TestResult::Success
+            }
+            req => TestResult::WrongRequest(req),
+        },
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn events() {
+    let (tx, rx) = crossbeam_channel::unbounded();
+    let handle = spawn(("127.0.0.1", 19111), tx).unwrap();
+
+    std::thread::spawn(move || match rx.recv() {
+        Ok(Request::SubscribeEvents { reply_to }) => {
+            let mut bus = EventBus::new();
+            let events = bus.subscribe_bounded(1);
+
+            bus.broadcast(RelayEvent::WorkerError {
+                worker: "packet worker".to_string(),
+                error: "oops".to_string(),
+            });
+
+            // The event stream ends once the bus is dropped
+            reply_to.send(Ok(events)).unwrap();
+        }
+        Ok(req) => panic!("got the wrong request: {req:?}"),
+        Err(e) => panic!("got an error: {e}"),
+    });
+
+    tokio::time::sleep(Duration::from_millis(200)).await;
+
+    let response = reqwest::get("http://127.0.0.1:19111/events")
+        .await
+        .unwrap()
+        .text()
+        .await
+        .unwrap();
+
+    let data = response.trim().strip_prefix("data:").map(str::trim);
+
+    assert_eq!(
+        data,
+        Some(r#"{"type":"worker_error","worker":"packet worker","error":"oops"}"#)
+    );
+
+    drop(handle);
+}
+
+#[tokio::test]
+async fn events_dropped() {
+    let (tx, rx) = crossbeam_channel::unbounded();
+    let handle = spawn(("127.0.0.1", 19113), tx).unwrap();
+
+    std::thread::spawn(move || match rx.recv() {
+        Ok(Request::SubscribeEvents { reply_to }) => {
+            let mut bus = EventBus::new();
+            let events = bus.subscribe_bounded(1);
+
+            for worker in ["first", "second", "third"] {
+                bus.broadcast(RelayEvent::WorkerError {
+                    worker: worker.to_string(),
+                    error: "oops".to_string(),
+                });
+            }
+
+            reply_to.send(Ok(events)).unwrap();
+        }
+        Ok(req) => panic!("got the wrong request: {req:?}"),
+        Err(e) => panic!("got an error: {e}"),
+    });
+
+    tokio::time::sleep(Duration::from_millis(200)).await;
+
+    let response = reqwest::get("http://127.0.0.1:19113/events")
+        .await
+        .unwrap()
+        .text()
+        .await
+        .unwrap();
+
+    let lines = response
+        .lines()
+        .filter(|line| !line.is_empty())
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        lines,
+        [
+            r#"data:{"type":"worker_error","worker":"first","error":"oops"}"#,
+            "event:events_dropped",
+            "data:2",
+        ]
+    );
+
+    drop(handle);
+}
@@ -0,0 +1,176 @@
+use std::time::Duration;
+
+use ibc_proto::google::protobuf::Any;
+use ibc_proto::ibc::lightclients::solomachine::v3::ClientState as RawClientState;
+use ibc_proto::Protobuf;
+use prost::Message;
+use serde::{Deserialize, Serialize};
+
+use crate::clients::ics06_solomachine::consensus_state::ConsensusState;
+use crate::clients::ics06_solomachine::error::Error;
+use crate::core::ics02_client::client_state::ClientState as Ics2ClientState;
+use crate::core::ics02_client::client_type::ClientType;
+use crate::core::ics02_client::error::Error as Ics02Error;
+use crate::core::ics24_host::identifier::ChainId;
+use crate::Height;
+
+pub const SOLOMACHINE_CLIENT_STATE_TYPE_URL: &str = "/ibc.lightclients.solomachine.v3.ClientState";
+
+/// The state of a solo machine client.
+///
+/// A solo machine has no blocks: its height is `0-{sequence}`, where the
+/// sequence is incremented by the client each time it verifies a signature.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ClientState {
+    pub sequence: u64,
+    pub is_frozen: bool,
+    pub consensus_state: ConsensusState,
+}
+
+impl ClientState {
+    pub fn new(sequence: u64, consensus_state: ConsensusState) -> Result<Self, Error> {
+        if sequence == 0 {
+            return Err(Error::invalid_sequence());
+        }
+
+        Ok(Self {
+            sequence,
+            is_frozen: false,
+            consensus_state,
+        })
+    }
+}
+
+impl Ics2ClientState for ClientState {
+    type UpgradeOptions = ();
+
+    /// Solo machines are identified by their diversifier.
+    fn chain_id(&self) -> ChainId {
+        ChainId::from_string(&self.consensus_state.diversifier)
+    }
+
+    fn client_type(&self) -> ClientType {
+        ClientType::Solomachine
+    }
+
+    fn latest_height(&self) -> Height {
+        Height::new(0, self.sequence).expect("the sequence of a solo machine client is non-zero")
+    }
+
+    fn frozen_height(&self) -> Option<Height> {
+        // Solo machine clients do not keep track of the height at which they were frozen
+        self.is_frozen.then(|| self.latest_height())
+    }
+
+    fn expired(&self, _elapsed: Duration) -> bool {
+        false
+    }
+
+    fn upgrade(&mut self, _upgrade_height: Height, _upgrade_options: (), _chain_id: ChainId) {
+        // Solo machines cannot be upgraded
+    }
+}
+
+impl Protobuf<RawClientState> for ClientState {}
+
+impl TryFrom<RawClientState> for ClientState {
+    type Error = Error;
+
+    fn try_from(raw: RawClientState) -> Result<Self, Self::Error> {
+        let consensus_state = raw
+            .consensus_state
+            .ok_or_else(|| Error::invalid_raw_client_state("missing consensus state".into()))?
+            .try_into()?;
+
+        Ok(Self {
+            is_frozen: raw.is_frozen,
+            ..Self::new(raw.sequence, consensus_state)?
+        })
+    }
+}
+
+impl From<ClientState> for RawClientState {
+    fn from(value: ClientState) -> Self {
+        RawClientState {
+            sequence: value.sequence,
+            is_frozen: value.is_frozen,
+            consensus_state: Some(value.consensus_state.into()),
+        }
+    }
+}
+
+impl Protobuf<Any> for ClientState {}
+
+impl TryFrom<Any> for ClientState {
+    type Error = Ics02Error;
+
+    fn try_from(raw: Any) -> Result<Self, Self::Error> {
+        use bytes::Buf;
+        use core::ops::Deref;
+
+        fn decode_client_state<B: Buf>(buf: B) -> Result<ClientState, Error> {
+            RawClientState::decode(buf)
+                .map_err(Error::decode)?
+                .try_into()
+        }
+
+        match raw.type_url.as_str() {
+            SOLOMACHINE_CLIENT_STATE_TYPE_URL => {
+                decode_client_state(raw.value.deref()).map_err(Into::into)
+            }
+            _ => Err(Ics02Error::unexpected_client_state_type(
+                SOLOMACHINE_CLIENT_STATE_TYPE_URL.to_string(),
+                raw.type_url,
+            )),
+        }
+    }
+}
+
+impl From<ClientState> for Any {
+    fn from(client_state: ClientState) -> Self {
+        Any {
+            type_url: SOLOMACHINE_CLIENT_STATE_TYPE_URL.to_string(),
+            value: Protobuf::<RawClientState>::encode_vec(client_state),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use test_log::test;
+
+    use super::*;
+    use crate::clients::ics06_solomachine::consensus_state::PublicKey;
+    use crate::timestamp::Timestamp;
+
+    fn consensus_state() -> ConsensusState {
+        let public_key = PublicKey::from_bytes(&[2; 33]).unwrap();
+        ConsensusState::new(public_key, "solo-0".to_string(), Timestamp::now())
+    }
+
+    #[test]
+    fn client_state_roundtrip() {
+        let client_state = ClientState::new(7, consensus_state()).unwrap();
+
+        let any = Any::from(client_state.clone());
+        let decoded = ClientState::try_from(any).unwrap();
+
+        assert_eq!(decoded, client_state);
+        assert_eq!(decoded.latest_height(), Height::new(0, 7).unwrap());
+        assert_eq!(decoded.chain_id(), ChainId::from_string("solo-0"));
+    }
+
+    #[test]
+    fn zero_sequence_is_rejected() {
+        assert!(ClientState::new(0, consensus_state()).is_err());
+    }
+
+    #[test]
+    fn public_key_roundtrip() {
+        let public_key = PublicKey::from_bytes(&[3; 33]).unwrap();
+        let any = Any::from(public_key.clone());
+
+        assert_eq!(PublicKey::try_from(any).unwrap(), public_key);
+        assert!(PublicKey::from_bytes(&[3; 32]).is_err());
+    }
+}
@@ -0,0 +1,49 @@
+use flex_error::{define_error, TraceError};
+
+use crate::core::ics02_client::error::Error as Ics02Error;
+
+define_error! {
+    #[derive(Debug, PartialEq, Eq)]
+    Error {
+        InvalidRawClientState
+            { reason: String }
+            |e| { format_args!("invalid raw client state: {}", e.reason) },
+
+        InvalidRawConsensusState
+            { reason: String }
+            |e| { format_args!("invalid raw consensus state: {}", e.reason) },
+
+        InvalidRawHeader
+            { reason: String }
+            |e| { format_args!("invalid raw header: {}", e.reason) },
+
+        InvalidRawMisbehaviour
+            { reason: String }
+            |e| { format_args!("invalid raw misbehaviour: {}", e.reason) },
+
+        InvalidPublicKey
+            { reason: String }
+            |e| { format_args!("invalid public key: {}", e.reason) },
+
+        InvalidSignature
+            { reason: String }
+            |e| { format_args!("invalid signature: {}", e.reason) },
+
+        InvalidSequence
+            |_| { "the sequence of a solo machine must be strictly positive" },
+
+        InvalidTimestamp
+            { timestamp: u64 }
+            |e| { format_args!("invalid timestamp: {}", e.timestamp) },
+
+        Decode
+            [ TraceError<prost::DecodeError> ]
+            | _ | { "decode error" },
+    }
+}
+
+impl From<Error> for Ics02Error {
+    fn from(e: Error) -> Self {
+        Self::client_specific(e.to_string())
+    }
+}
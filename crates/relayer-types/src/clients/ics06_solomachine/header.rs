@@ -0,0 +1,127 @@
+use bytes::Buf;
+use ibc_proto::google::protobuf::Any;
+use ibc_proto::ibc::lightclients::solomachine::v3::Header as RawHeader;
+use ibc_proto::Protobuf;
+use prost::Message;
+use serde::{Deserialize, Serialize};
+
+use crate::clients::ics06_solomachine::consensus_state::PublicKey;
+use crate::clients::ics06_solomachine::error::Error;
+use crate::core::ics02_client::client_type::ClientType;
+use crate::core::ics02_client::error::Error as Ics02Error;
+use crate::timestamp::Timestamp;
+use crate::Height;
+
+pub const SOLOMACHINE_HEADER_TYPE_URL: &str = "/ibc.lightclients.solomachine.v3.Header";
+
+/// A solo machine header, used to rotate the public key and diversifier of
+/// the client, signed by the current key at the current sequence.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Header {
+    /// The sequence of the client this header is signed for.
+    ///
+    /// The sequence is not part of the encoded header, since the client
+    /// verifies the signature against its own sequence. It is therefore zero
+    /// for headers decoded from their protobuf representation, and has to be
+    /// set with [`Header::with_sequence`] before using their height, eg. from
+    /// the sequence of the client or the consensus height of the update.
+    pub sequence: u64,
+    pub timestamp: Timestamp,
+    /// The signature, encoded with [`encode_signature`](super::signing::encode_signature).
+    #[serde(serialize_with = "crate::serializers::ser_hex_upper")]
+    pub signature: Vec<u8>,
+    pub new_public_key: PublicKey,
+    pub new_diversifier: String,
+}
+
+impl Header {
+    /// Sets the sequence of the client this header is signed for.
+    pub fn with_sequence(self, sequence: u64) -> Self {
+        Self { sequence, ..self }
+    }
+}
+
+impl core::fmt::Display for Header {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> Result<(), core::fmt::Error> {
+        write!(f, "Header {{ sequence: {} }}", self.sequence)
+    }
+}
+
+impl crate::core::ics02_client::header::Header for Header {
+    fn client_type(&self) -> ClientType {
+        ClientType::Solomachine
+    }
+
+    /// The height of the client once this header has been applied,
+    /// ie. its sequence incremented by one.
+    fn height(&self) -> Height {
+        Height::new(0, self.sequence + 1).expect("the incremented sequence is non-zero")
+    }
+
+    fn timestamp(&self) -> Timestamp {
+        self.timestamp
+    }
+}
+
+impl Protobuf<RawHeader> for Header {}
+
+impl TryFrom<RawHeader> for Header {
+    type Error = Error;
+
+    fn try_from(raw: RawHeader) -> Result<Self, Self::Error> {
+        let new_public_key = raw
+            .new_public_key
+            .ok_or_else(|| Error::invalid_raw_header("missing new public key".into()))?
+            .try_into()?;
+
+        let timestamp = Timestamp::from_nanoseconds(raw.timestamp)
+            .map_err(|_| Error::invalid_timestamp(raw.timestamp))?;
+
+        Ok(Self {
+            sequence: 0,
+            timestamp,
+            signature: raw.signature,
+            new_public_key,
+            new_diversifier: raw.new_diversifier,
+        })
+    }
+}
+
+impl From<Header> for RawHeader {
+    fn from(value: Header) -> Self {
+        RawHeader {
+            timestamp: value.timestamp.nanoseconds(),
+            signature: value.signature,
+            new_public_key: Some(value.new_public_key.into()),
+            new_diversifier: value.new_diversifier,
+        }
+    }
+}
+
+impl Protobuf<Any> for Header {}
+
+impl TryFrom<Any> for Header {
+    type Error = Ics02Error;
+
+    fn try_from(raw: Any) -> Result<Self, Ics02Error> {
+        use core::ops::Deref;
+
+        match raw.type_url.as_str() {
+            SOLOMACHINE_HEADER_TYPE_URL => decode_header(raw.value.deref()).map_err(Into::into),
+            _ => Err(Ics02Error::unknown_header_type(raw.type_url)),
+        }
+    }
+}
+
+impl From<Header> for Any {
+    fn from(header: Header) -> Self {
+        Any {
+            type_url: SOLOMACHINE_HEADER_TYPE_URL.to_string(),
+            value: Protobuf::<RawHeader>::encode_vec(header),
+        }
+    }
+}
+
+pub fn decode_header<B: Buf>(buf: B) -> Result<Header, Error> {
+    RawHeader::decode(buf).map_err(Error::decode)?.try_into()
+}
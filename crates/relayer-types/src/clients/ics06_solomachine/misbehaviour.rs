@@ -0,0 +1,121 @@
+use ibc_proto::ibc::lightclients::solomachine::v3::{
+    Misbehaviour as RawMisbehaviour, SignatureAndData as RawSignatureAndData,
+};
+use ibc_proto::Protobuf;
+use serde::{Deserialize, Serialize};
+
+use crate::clients::ics06_solomachine::error::Error;
+use crate::core::ics24_host::identifier::ClientId;
+use crate::timestamp::Timestamp;
+use crate::tx_msg::Msg;
+use crate::Height;
+
+pub const SOLOMACHINE_MISBEHAVIOUR_TYPE_URL: &str = "/ibc.lightclients.solomachine.v3.Misbehaviour";
+
+/// A signature over some data stored at some path, as submitted in a misbehaviour.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SignatureAndData {
+    #[serde(serialize_with = "crate::serializers::ser_hex_upper")]
+    pub signature: Vec<u8>,
+    #[serde(serialize_with = "crate::serializers::ser_hex_upper")]
+    pub path: Vec<u8>,
+    #[serde(serialize_with = "crate::serializers::ser_hex_upper")]
+    pub data: Vec<u8>,
+    pub timestamp: Timestamp,
+}
+
+impl TryFrom<RawSignatureAndData> for SignatureAndData {
+    type Error = Error;
+
+    fn try_from(raw: RawSignatureAndData) -> Result<Self, Self::Error> {
+        let timestamp = Timestamp::from_nanoseconds(raw.timestamp)
+            .map_err(|_| Error::invalid_timestamp(raw.timestamp))?;
+
+        Ok(Self {
+            signature: raw.signature,
+            path: raw.path,
+            data: raw.data,
+            timestamp,
+        })
+    }
+}
+
+impl From<SignatureAndData> for RawSignatureAndData {
+    fn from(value: SignatureAndData) -> Self {
+        RawSignatureAndData {
+            signature: value.signature,
+            path: value.path,
+            data: value.data,
+            timestamp: value.timestamp.nanoseconds(),
+        }
+    }
+}
+
+/// Two different signatures produced by a solo machine for the same sequence.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Misbehaviour {
+    pub client_id: ClientId,
+    pub sequence: u64,
+    pub signature_one: SignatureAndData,
+    pub signature_two: SignatureAndData,
+}
+
+impl crate::core::ics02_client::misbehaviour::Misbehaviour for Misbehaviour {
+    fn client_id(&self) -> &ClientId {
+        &self.client_id
+    }
+
+    fn height(&self) -> Height {
+        Height::new(0, self.sequence.max(1)).expect("the sequence is non-zero")
+    }
+}
+
+impl Msg for Misbehaviour {
+    type ValidationError = Error;
+    type Raw = RawMisbehaviour;
+
+    fn route(&self) -> String {
+        crate::keys::ROUTER_KEY.to_string()
+    }
+
+    fn type_url(&self) -> String {
+        SOLOMACHINE_MISBEHAVIOUR_TYPE_URL.to_string()
+    }
+}
+
+impl Protobuf<RawMisbehaviour> for Misbehaviour {}
+
+impl TryFrom<RawMisbehaviour> for Misbehaviour {
+    type Error = Error;
+
+    fn try_from(raw: RawMisbehaviour) -> Result<Self, Self::Error> {
+        Ok(Self {
+            client_id: Default::default(),
+            sequence: raw.sequence,
+            signature_one: raw
+                .signature_one
+                .ok_or_else(|| Error::invalid_raw_misbehaviour("missing signature one".into()))?
+                .try_into()?,
+            signature_two: raw
+                .signature_two
+                .ok_or_else(|| Error::invalid_raw_misbehaviour("missing signature two".into()))?
+                .try_into()?,
+        })
+    }
+}
+
+impl From<Misbehaviour> for RawMisbehaviour {
+    fn from(value: Misbehaviour) -> Self {
+        RawMisbehaviour {
+            sequence: value.sequence,
+            signature_one: Some(value.signature_one.into()),
+            signature_two: Some(value.signature_two.into()),
+        }
+    }
+}
+
+impl core::fmt::Display for Misbehaviour {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> Result<(), core::fmt::Error> {
+        write!(f, "{} sequence: {}", self.client_id, self.sequence)
+    }
+}
@@ -0,0 +1,9 @@
+//! ICS 06: Solo Machine Client implements a client verification algorithm for standalone
+//! processes, such as off-chain signers, whose state is attested by a single public key.
+
+pub mod client_state;
+pub mod consensus_state;
+pub mod error;
+pub mod header;
+pub mod misbehaviour;
+pub mod signing;
@@ -0,0 +1,159 @@
+//! The bytes signed by a solo machine, and the encoding of its signatures
+//! into the proofs verified by its counterparty clients.
+
+use ibc_proto::cosmos::tx::signing::v1beta1::signature_descriptor::data::{Single, Sum};
+use ibc_proto::cosmos::tx::signing::v1beta1::signature_descriptor::Data as SignatureData;
+use ibc_proto::ibc::lightclients::solomachine::v3::{
+    HeaderData as RawHeaderData, SignBytes as RawSignBytes,
+    TimestampedSignatureData as RawTimestampedSignatureData,
+};
+use prost::Message;
+
+use crate::clients::ics06_solomachine::consensus_state::PublicKey;
+use crate::clients::ics06_solomachine::error::Error;
+use crate::core::ics23_commitment::commitment::CommitmentPrefix;
+use crate::timestamp::Timestamp;
+
+/// The bytes a solo machine signs to attest that `data` is stored under `path`.
+///
+/// The `path` must be formatted with [`merkle_path`].
+pub fn sign_bytes(
+    sequence: u64,
+    timestamp: Timestamp,
+    diversifier: &str,
+    path: &str,
+    data: Vec<u8>,
+) -> Vec<u8> {
+    RawSignBytes {
+        sequence,
+        timestamp: timestamp.nanoseconds(),
+        diversifier: diversifier.to_string(),
+        path: path.as_bytes().to_vec(),
+        data,
+    }
+    .encode_to_vec()
+}
+
+/// The bytes a solo machine signs to rotate its public key and diversifier.
+///
+/// As for any other signature, they are wrapped into [`sign_bytes`], with
+/// the [`HEADER_PATH`] sentinel path.
+pub fn header_data(new_public_key: &PublicKey, new_diversifier: &str) -> Vec<u8> {
+    RawHeaderData {
+        new_pub_key: Some(new_public_key.clone().into()),
+        new_diversifier: new_diversifier.to_string(),
+    }
+    .encode_to_vec()
+}
+
+/// The placeholder path of the data signed by solo machine headers.
+pub const HEADER_PATH: &str = "solomachine:header";
+
+/// Formats the given commitment path the way `ibc-go` formats a merkle path
+/// made of the commitment prefix and that path: each key is URL-escaped and
+/// prefixed with a `/`.
+pub fn merkle_path(prefix: &CommitmentPrefix, path: &str) -> String {
+    let prefix = String::from_utf8_lossy(prefix.as_bytes());
+
+    format!(
+        "/{}/{}",
+        escape_path_segment(&prefix),
+        escape_path_segment(path)
+    )
+}
+
+/// Escapes a path segment like Go's `url.PathEscape` does.
+fn escape_path_segment(segment: &str) -> String {
+    segment
+        .bytes()
+        .map(|b| match b {
+            b'A'..=b'Z'
+            | b'a'..=b'z'
+            | b'0'..=b'9'
+            | b'-'
+            | b'_'
+            | b'.'
+            | b'~'
+            | b'$'
+            | b'&'
+            | b'+'
+            | b'='
+            | b':'
+            | b'@' => (b as char).to_string(),
+            _ => format!("%{b:02X}"),
+        })
+        .collect()
+}
+
+/// Encodes a signature over some [`sign_bytes`] into the proof expected by
+/// solo machine clients.
+pub fn signature_proof(signature: Vec<u8>, timestamp: Timestamp) -> Vec<u8> {
+    RawTimestampedSignatureData {
+        signature_data: encode_signature(signature),
+        timestamp: timestamp.nanoseconds(),
+    }
+    .encode_to_vec()
+}
+
+/// Decodes a proof produced by [`signature_proof`] into the signature and its timestamp.
+pub fn decode_signature_proof(proof: &[u8]) -> Result<(Vec<u8>, Timestamp), Error> {
+    let raw = RawTimestampedSignatureData::decode(proof).map_err(Error::decode)?;
+
+    let timestamp = Timestamp::from_nanoseconds(raw.timestamp)
+        .map_err(|_| Error::invalid_timestamp(raw.timestamp))?;
+
+    Ok((decode_signature(&raw.signature_data)?, timestamp))
+}
+
+/// Encodes a signature as a single-signer `cosmos.tx.signing.v1beta1.SignatureDescriptor.Data`.
+pub fn encode_signature(signature: Vec<u8>) -> Vec<u8> {
+    SignatureData {
+        sum: Some(Sum::Single(Single { mode: 0, signature })),
+    }
+    .encode_to_vec()
+}
+
+/// Decodes a signature encoded with [`encode_signature`].
+pub fn decode_signature(signature_data: &[u8]) -> Result<Vec<u8>, Error> {
+    match SignatureData::decode(signature_data)
+        .map_err(Error::decode)?
+        .sum
+    {
+        Some(Sum::Single(single)) => Ok(single.signature),
+        _ => Err(Error::invalid_signature(
+            "expected a single signature".to_string(),
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use test_log::test;
+
+    use super::*;
+
+    #[test]
+    fn merkle_path_escapes_slashes() {
+        let prefix = CommitmentPrefix::try_from(b"ibc".to_vec()).unwrap();
+
+        assert_eq!(
+            merkle_path(&prefix, "connections/connection-0"),
+            "/ibc/connections%2Fconnection-0"
+        );
+        assert_eq!(
+            merkle_path(&prefix, "clients/07-tendermint-0/consensusStates/1-10"),
+            "/ibc/clients%2F07-tendermint-0%2FconsensusStates%2F1-10"
+        );
+    }
+
+    #[test]
+    fn signature_proof_roundtrip() {
+        let timestamp = Timestamp::from_nanoseconds(42).unwrap();
+        let proof = signature_proof(vec![1, 2, 3], timestamp);
+
+        assert_eq!(
+            decode_signature_proof(&proof).unwrap(),
+            (vec![1, 2, 3], timestamp)
+        );
+    }
+}
@@ -0,0 +1,236 @@
+use bytes::Buf;
+use ibc_proto::google::protobuf::Any;
+use ibc_proto::ibc::lightclients::tendermint::v1::Misbehaviour as RawTmMisbehaviour;
+use ibc_proto::Protobuf;
+use prost::Message;
+use serde::{Deserialize, Serialize};
+
+use crate::clients::ics07_tendermint::header::Header as TmHeader;
+use crate::clients::ics07_tendermint::misbehaviour::{
+    Misbehaviour as TmMisbehaviour, TENDERMINT_MISBEHAVIOR_TYPE_URL,
+};
+use crate::clients::ics08_wasm::error::Error;
+use crate::clients::ics08_wasm::raw::ClientMessage as RawClientMessage;
+use crate::core::ics02_client::client_type::ClientType;
+use crate::core::ics02_client::error::Error as Ics02Error;
+use crate::core::ics02_client::header::Header as Ics02Header;
+use crate::core::ics02_client::misbehaviour::Misbehaviour as Ics02Misbehaviour;
+use crate::core::ics24_host::identifier::ClientId;
+use crate::timestamp::Timestamp;
+use crate::Height;
+
+pub const WASM_CLIENT_MESSAGE_TYPE_URL: &str = "/ibc.lightclients.wasm.v1.ClientMessage";
+
+/// The message updating a Wasm client wrapping a Tendermint client, whose
+/// header is encoded as an `Any` into the opaque `data` of the message.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Header {
+    pub inner: TmHeader,
+}
+
+impl core::fmt::Display for Header {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> Result<(), core::fmt::Error> {
+        write!(f, "Wasm({})", self.inner)
+    }
+}
+
+impl Ics02Header for Header {
+    fn client_type(&self) -> ClientType {
+        ClientType::Wasm
+    }
+
+    fn height(&self) -> Height {
+        self.inner.height()
+    }
+
+    fn timestamp(&self) -> Timestamp {
+        self.inner.timestamp()
+    }
+}
+
+impl From<TmHeader> for Header {
+    fn from(inner: TmHeader) -> Self {
+        Self { inner }
+    }
+}
+
+impl Protobuf<RawClientMessage> for Header {}
+
+impl TryFrom<RawClientMessage> for Header {
+    type Error = Error;
+
+    fn try_from(raw: RawClientMessage) -> Result<Self, Self::Error> {
+        let data = Any::decode(raw.data.as_slice()).map_err(Error::decode)?;
+        let inner = TmHeader::try_from(data).map_err(Error::invalid_payload)?;
+
+        Ok(Self { inner })
+    }
+}
+
+impl From<Header> for RawClientMessage {
+    fn from(value: Header) -> Self {
+        RawClientMessage {
+            data: Any::from(value.inner).encode_to_vec(),
+        }
+    }
+}
+
+impl Protobuf<Any> for Header {}
+
+impl TryFrom<Any> for Header {
+    type Error = Ics02Error;
+
+    fn try_from(raw: Any) -> Result<Self, Ics02Error> {
+        use core::ops::Deref;
+
+        match raw.type_url.as_str() {
+            WASM_CLIENT_MESSAGE_TYPE_URL => decode_header(raw.value.deref()).map_err(Into::into),
+            _ => Err(Ics02Error::unknown_header_type(raw.type_url)),
+        }
+    }
+}
+
+impl From<Header> for Any {
+    fn from(header: Header) -> Self {
+        Any {
+            type_url: WASM_CLIENT_MESSAGE_TYPE_URL.to_string(),
+            value: Protobuf::<RawClientMessage>::encode_vec(header),
+        }
+    }
+}
+
+pub fn decode_header<B: Buf>(buf: B) -> Result<Header, Error> {
+    RawClientMessage::decode(buf)
+        .map_err(Error::decode)?
+        .try_into()
+}
+
+/// The message submitting the misbehaviour of the chain targeted by a Wasm client
+/// wrapping a Tendermint client, whose Tendermint misbehaviour is encoded as an
+/// `Any` into the opaque `data` of the message.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Misbehaviour {
+    pub inner: TmMisbehaviour,
+}
+
+impl core::fmt::Display for Misbehaviour {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> Result<(), core::fmt::Error> {
+        write!(f, "Wasm({})", self.inner)
+    }
+}
+
+impl Ics02Misbehaviour for Misbehaviour {
+    fn client_id(&self) -> &ClientId {
+        self.inner.client_id()
+    }
+
+    fn height(&self) -> Height {
+        self.inner.height()
+    }
+}
+
+impl From<TmMisbehaviour> for Misbehaviour {
+    fn from(inner: TmMisbehaviour) -> Self {
+        Self { inner }
+    }
+}
+
+impl Protobuf<RawClientMessage> for Misbehaviour {}
+
+impl TryFrom<RawClientMessage> for Misbehaviour {
+    type Error = Error;
+
+    fn try_from(raw: RawClientMessage) -> Result<Self, Self::Error> {
+        let data = Any::decode(raw.data.as_slice()).map_err(Error::decode)?;
+
+        if data.type_url != TENDERMINT_MISBEHAVIOR_TYPE_URL {
+            return Err(Error::invalid_payload(
+                Ics02Error::unknown_misbehaviour_type(data.type_url),
+            ));
+        }
+
+        let inner = Protobuf::<RawTmMisbehaviour>::decode_vec(&data.value)
+            .map_err(|e| Error::invalid_payload(Ics02Error::decode_raw_misbehaviour(e)))?;
+
+        Ok(Self { inner })
+    }
+}
+
+impl From<Misbehaviour> for RawClientMessage {
+    fn from(value: Misbehaviour) -> Self {
+        let data = Any {
+            type_url: TENDERMINT_MISBEHAVIOR_TYPE_URL.to_string(),
+            value: Protobuf::<RawTmMisbehaviour>::encode_vec(value.inner),
+        };
+
+        RawClientMessage {
+            data: data.encode_to_vec(),
+        }
+    }
+}
+
+impl Protobuf<Any> for Misbehaviour {}
+
+impl TryFrom<Any> for Misbehaviour {
+    type Error = Ics02Error;
+
+    fn try_from(raw: Any) -> Result<Self, Ics02Error> {
+        use core::ops::Deref;
+
+        match raw.type_url.as_str() {
+            WASM_CLIENT_MESSAGE_TYPE_URL => {
+                decode_misbehaviour(raw.value.deref()).map_err(Into::into)
+            }
+            _ => Err(Ics02Error::unknown_misbehaviour_type(raw.type_url)),
+        }
+    }
+}
+
+impl From<Misbehaviour> for Any {
+    fn from(misbehaviour: Misbehaviour) -> Self {
+        Any {
+            type_url: WASM_CLIENT_MESSAGE_TYPE_URL.to_string(),
+            value: Protobuf::<RawClientMessage>::encode_vec(misbehaviour),
+        }
+    }
+}
+
+pub fn decode_misbehaviour<B: Buf>(buf: B) -> Result<Misbehaviour, Error> {
+    RawClientMessage::decode(buf)
+        .map_err(Error::decode)?
+        .try_into()
+}
+
+#[cfg(test)]
+mod tests {
+    use test_log::test;
+
+    use super::*;
+    use crate::clients::ics07_tendermint::header::test_util::get_dummy_ics07_header;
+
+    #[test]
+    fn header_roundtrip() {
+        let header = Header::from(get_dummy_ics07_header());
+
+        let any = Any::from(header.clone());
+        assert_eq!(any.type_url, WASM_CLIENT_MESSAGE_TYPE_URL);
+        assert_eq!(Header::try_from(any).unwrap(), header);
+    }
+
+    #[test]
+    fn misbehaviour_roundtrip() {
+        let misbehaviour = Misbehaviour::from(TmMisbehaviour {
+            client_id: ClientId::default(),
+            header1: get_dummy_ics07_header(),
+            header2: get_dummy_ics07_header(),
+        });
+
+        let any = Any::from(misbehaviour.clone());
+        assert_eq!(any.type_url, WASM_CLIENT_MESSAGE_TYPE_URL);
+        assert_eq!(Misbehaviour::try_from(any.clone()).unwrap(), misbehaviour);
+
+        // A client message wrapping a misbehaviour is not a header, and vice versa
+        assert!(Header::try_from(any).is_err());
+        assert!(Misbehaviour::try_from(Any::from(Header::from(get_dummy_ics07_header()))).is_err());
+    }
+}
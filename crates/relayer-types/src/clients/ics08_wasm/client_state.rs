@@ -0,0 +1,187 @@
+use std::time::Duration;
+
+use ibc_proto::google::protobuf::Any;
+use ibc_proto::Protobuf;
+use prost::Message;
+use serde::{Deserialize, Serialize};
+
+use crate::clients::ics07_tendermint::client_state::{
+    ClientState as TmClientState, UpgradeOptions as TmUpgradeOptions,
+};
+use crate::clients::ics08_wasm::error::Error;
+use crate::clients::ics08_wasm::raw::ClientState as RawClientState;
+use crate::core::ics02_client::client_state::ClientState as Ics2ClientState;
+use crate::core::ics02_client::client_type::ClientType;
+use crate::core::ics02_client::error::Error as Ics02Error;
+use crate::core::ics24_host::identifier::ChainId;
+use crate::Height;
+
+pub const WASM_CLIENT_STATE_TYPE_URL: &str = "/ibc.lightclients.wasm.v1.ClientState";
+
+/// The length of the SHA-256 checksum identifying a Wasm light client contract.
+pub const CHECKSUM_LENGTH: usize = 32;
+
+/// The state of a Wasm client wrapping a Tendermint client.
+///
+/// The wrapped client state is encoded as an `Any` into the opaque `data`
+/// of the Wasm client state, and its latest height is mirrored by the Wasm
+/// client state itself.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ClientState {
+    #[serde(serialize_with = "crate::serializers::ser_hex_upper")]
+    pub checksum: Vec<u8>,
+    pub inner: TmClientState,
+}
+
+impl ClientState {
+    pub fn new(checksum: Vec<u8>, inner: TmClientState) -> Result<Self, Error> {
+        if checksum.len() != CHECKSUM_LENGTH {
+            return Err(Error::invalid_checksum(format!(
+                "expected {CHECKSUM_LENGTH} bytes, got {}",
+                checksum.len()
+            )));
+        }
+
+        Ok(Self { checksum, inner })
+    }
+}
+
+impl Ics2ClientState for ClientState {
+    type UpgradeOptions = TmUpgradeOptions;
+
+    fn chain_id(&self) -> ChainId {
+        self.inner.chain_id()
+    }
+
+    fn client_type(&self) -> ClientType {
+        ClientType::Wasm
+    }
+
+    fn latest_height(&self) -> Height {
+        self.inner.latest_height()
+    }
+
+    fn frozen_height(&self) -> Option<Height> {
+        self.inner.frozen_height()
+    }
+
+    fn expired(&self, elapsed: Duration) -> bool {
+        self.inner.expired(elapsed)
+    }
+
+    fn upgrade(
+        &mut self,
+        upgrade_height: Height,
+        upgrade_options: TmUpgradeOptions,
+        chain_id: ChainId,
+    ) {
+        self.inner
+            .upgrade(upgrade_height, upgrade_options, chain_id)
+    }
+}
+
+impl Protobuf<RawClientState> for ClientState {}
+
+impl TryFrom<RawClientState> for ClientState {
+    type Error = Error;
+
+    fn try_from(raw: RawClientState) -> Result<Self, Self::Error> {
+        let data = Any::decode(raw.data.as_slice()).map_err(Error::decode)?;
+        let inner = TmClientState::try_from(data).map_err(Error::invalid_payload)?;
+
+        let latest_height = raw
+            .latest_height
+            .ok_or_else(|| Error::invalid_raw_client_state("missing latest height".into()))?;
+
+        if Height::try_from(latest_height).ok() != Some(inner.latest_height()) {
+            return Err(Error::invalid_raw_client_state(
+                "latest height does not match the one of the wrapped client state".into(),
+            ));
+        }
+
+        Self::new(raw.checksum, inner)
+    }
+}
+
+impl From<ClientState> for RawClientState {
+    fn from(value: ClientState) -> Self {
+        RawClientState {
+            latest_height: Some(value.inner.latest_height().into()),
+            data: Any::from(value.inner).encode_to_vec(),
+            checksum: value.checksum,
+        }
+    }
+}
+
+impl Protobuf<Any> for ClientState {}
+
+impl TryFrom<Any> for ClientState {
+    type Error = Ics02Error;
+
+    fn try_from(raw: Any) -> Result<Self, Self::Error> {
+        use bytes::Buf;
+        use core::ops::Deref;
+
+        fn decode_client_state<B: Buf>(buf: B) -> Result<ClientState, Error> {
+            RawClientState::decode(buf)
+                .map_err(Error::decode)?
+                .try_into()
+        }
+
+        match raw.type_url.as_str() {
+            WASM_CLIENT_STATE_TYPE_URL => {
+                decode_client_state(raw.value.deref()).map_err(Into::into)
+            }
+            _ => Err(Ics02Error::unexpected_client_state_type(
+                WASM_CLIENT_STATE_TYPE_URL.to_string(),
+                raw.type_url,
+            )),
+        }
+    }
+}
+
+impl From<ClientState> for Any {
+    fn from(client_state: ClientState) -> Self {
+        Any {
+            type_url: WASM_CLIENT_STATE_TYPE_URL.to_string(),
+            value: Protobuf::<RawClientState>::encode_vec(client_state),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use test_log::test;
+
+    use super::*;
+    use crate::clients::ics07_tendermint::client_state::test_util::get_dummy_tendermint_client_state;
+    use crate::clients::ics07_tendermint::header::test_util::get_dummy_tendermint_header;
+
+    #[test]
+    fn any_roundtrip() {
+        let inner = get_dummy_tendermint_client_state(get_dummy_tendermint_header());
+        let client_state = ClientState::new(vec![7; CHECKSUM_LENGTH], inner).unwrap();
+
+        let any = Any::from(client_state.clone());
+        assert_eq!(any.type_url, WASM_CLIENT_STATE_TYPE_URL);
+        assert_eq!(ClientState::try_from(any).unwrap(), client_state);
+    }
+
+    #[test]
+    fn reject_invalid_checksum() {
+        let inner = get_dummy_tendermint_client_state(get_dummy_tendermint_header());
+
+        assert!(ClientState::new(vec![7; 3], inner).is_err());
+    }
+
+    #[test]
+    fn reject_mismatched_latest_height() {
+        let inner = get_dummy_tendermint_client_state(get_dummy_tendermint_header());
+        let client_state = ClientState::new(vec![7; CHECKSUM_LENGTH], inner).unwrap();
+
+        let mut raw = RawClientState::from(client_state);
+        raw.latest_height = Some(Height::new(0, 1).unwrap().into());
+
+        assert!(ClientState::try_from(raw).is_err());
+    }
+}
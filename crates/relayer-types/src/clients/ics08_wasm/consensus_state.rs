@@ -0,0 +1,96 @@
+use ibc_proto::google::protobuf::Any;
+use ibc_proto::Protobuf;
+use prost::Message;
+use serde::{Deserialize, Serialize};
+
+use crate::clients::ics07_tendermint::consensus_state::ConsensusState as TmConsensusState;
+use crate::clients::ics08_wasm::error::Error;
+use crate::clients::ics08_wasm::raw::ConsensusState as RawConsensusState;
+use crate::core::ics02_client::client_type::ClientType;
+use crate::core::ics02_client::consensus_state::ConsensusState as Ics02ConsensusState;
+use crate::core::ics02_client::error::Error as Ics02Error;
+use crate::core::ics23_commitment::commitment::CommitmentRoot;
+use crate::timestamp::Timestamp;
+
+pub const WASM_CONSENSUS_STATE_TYPE_URL: &str = "/ibc.lightclients.wasm.v1.ConsensusState";
+
+/// The consensus state of a Wasm client wrapping a Tendermint client, which
+/// is encoded as an `Any` into the opaque `data` of the Wasm consensus state.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ConsensusState {
+    pub inner: TmConsensusState,
+}
+
+impl ConsensusState {
+    pub fn new(inner: TmConsensusState) -> Self {
+        Self { inner }
+    }
+}
+
+impl Ics02ConsensusState for ConsensusState {
+    fn client_type(&self) -> ClientType {
+        ClientType::Wasm
+    }
+
+    fn root(&self) -> &CommitmentRoot {
+        self.inner.root()
+    }
+
+    fn timestamp(&self) -> Timestamp {
+        self.inner.timestamp()
+    }
+}
+
+impl Protobuf<RawConsensusState> for ConsensusState {}
+
+impl TryFrom<RawConsensusState> for ConsensusState {
+    type Error = Error;
+
+    fn try_from(raw: RawConsensusState) -> Result<Self, Self::Error> {
+        let data = Any::decode(raw.data.as_slice()).map_err(Error::decode)?;
+        let inner = TmConsensusState::try_from(data).map_err(Error::invalid_payload)?;
+
+        Ok(Self::new(inner))
+    }
+}
+
+impl From<ConsensusState> for RawConsensusState {
+    fn from(value: ConsensusState) -> Self {
+        RawConsensusState {
+            data: Any::from(value.inner).encode_to_vec(),
+        }
+    }
+}
+
+impl Protobuf<Any> for ConsensusState {}
+
+impl TryFrom<Any> for ConsensusState {
+    type Error = Ics02Error;
+
+    fn try_from(raw: Any) -> Result<Self, Self::Error> {
+        use bytes::Buf;
+        use core::ops::Deref;
+
+        fn decode_consensus_state<B: Buf>(buf: B) -> Result<ConsensusState, Error> {
+            RawConsensusState::decode(buf)
+                .map_err(Error::decode)?
+                .try_into()
+        }
+
+        match raw.type_url.as_str() {
+            WASM_CONSENSUS_STATE_TYPE_URL => {
+                decode_consensus_state(raw.value.deref()).map_err(Into::into)
+            }
+            _ => Err(Ics02Error::unknown_consensus_state_type(raw.type_url)),
+        }
+    }
+}
+
+impl From<ConsensusState> for Any {
+    fn from(consensus_state: ConsensusState) -> Self {
+        Any {
+            type_url: WASM_CONSENSUS_STATE_TYPE_URL.to_string(),
+            value: Protobuf::<RawConsensusState>::encode_vec(consensus_state),
+        }
+    }
+}
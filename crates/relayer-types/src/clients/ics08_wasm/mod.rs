@@ -0,0 +1,10 @@
+//! ICS 08: Wasm Client wraps the state of another light client, whose
+//! verification algorithm runs as a Wasm contract on the host chain.
+//!
+//! Only Wasm clients wrapping a Tendermint light client are supported.
+
+pub mod client_message;
+pub mod client_state;
+pub mod consensus_state;
+pub mod error;
+pub mod raw;
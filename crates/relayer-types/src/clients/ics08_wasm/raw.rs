@@ -0,0 +1,36 @@
+//! The protobuf messages of `ibc.lightclients.wasm.v1`, which are not shipped by
+//! the version of `ibc-proto` in use.
+//!
+//! Only the messages wrapping the state and the messages of a client are defined,
+//! see [ibc-go](https://github.com/cosmos/ibc-go/blob/modules/light-clients/08-wasm/v0.1.0/proto/ibc/lightclients/wasm/v1/wasm.proto).
+
+use ibc_proto::ibc::core::client::v1::Height as RawHeight;
+
+/// Wasm light client's Client state
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ClientState {
+    /// bytes encoding the client state of the underlying light client
+    /// implemented as a Wasm contract.
+    #[prost(bytes = "vec", tag = "1")]
+    pub data: Vec<u8>,
+    #[prost(bytes = "vec", tag = "2")]
+    pub checksum: Vec<u8>,
+    #[prost(message, optional, tag = "3")]
+    pub latest_height: Option<RawHeight>,
+}
+
+/// Wasm light client's ConsensusState
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ConsensusState {
+    /// bytes encoding the consensus state of the underlying light client
+    /// implemented as a Wasm contract.
+    #[prost(bytes = "vec", tag = "1")]
+    pub data: Vec<u8>,
+}
+
+/// Wasm light client message (either header(s) or misbehaviour)
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ClientMessage {
+    #[prost(bytes = "vec", tag = "1")]
+    pub data: Vec<u8>,
+}
@@ -5,11 +5,19 @@ use serde::{Deserialize, Serialize};
 use ibc_proto::google::protobuf::Any;
 use ibc_proto::Protobuf;
 
+use crate::clients::ics06_solomachine::header::{
+    decode_header as sm_decode_header, Header as SolomachineHeader, SOLOMACHINE_HEADER_TYPE_URL,
+};
 use crate::clients::ics07_tendermint::header::{
     decode_header as tm_decode_header, Header as TendermintHeader, TENDERMINT_HEADER_TYPE_URL,
 };
+use crate::clients::ics08_wasm::client_message::{
+    decode_header as wasm_decode_header, Header as WasmHeader, WASM_CLIENT_MESSAGE_TYPE_URL,
+};
 use crate::core::ics02_client::client_type::ClientType;
 use crate::core::ics02_client::error::Error;
+#[cfg(any(test, feature = "mocks"))]
+use crate::mock::header::{MockHeader, MOCK_HEADER_TYPE_URL};
 use crate::timestamp::Timestamp;
 use crate::Height;
 
@@ -26,39 +34,53 @@ pub trait Header: Debug + Send + Sync // Any: From<Self>,
     fn timestamp(&self) -> Timestamp;
 }
 
-/// Decodes an encoded header into a known `Header` type,
+/// Decodes an encoded header into a known `Header` type, based on its type URL.
 pub fn decode_header(header_bytes: &[u8]) -> Result<AnyHeader, Error> {
-    // For now, we only have tendermint; however when there is more than one, we
-    // can try decoding into all the known types, and return an error only if
-    // none work
-    let header: TendermintHeader =
-        Protobuf::<Any>::decode(header_bytes).map_err(Error::invalid_raw_header)?;
-
-    Ok(AnyHeader::Tendermint(header))
+    Protobuf::<Any>::decode(header_bytes).map_err(Error::invalid_raw_header)
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
 #[allow(clippy::large_enum_variant)]
 pub enum AnyHeader {
     Tendermint(TendermintHeader),
+    Solomachine(SolomachineHeader),
+    Wasm(WasmHeader),
+
+    #[cfg(any(test, feature = "mocks"))]
+    Mock(MockHeader),
 }
 
 impl Header for AnyHeader {
     fn client_type(&self) -> ClientType {
         match self {
             Self::Tendermint(header) => header.client_type(),
+            Self::Solomachine(header) => header.client_type(),
+            Self::Wasm(header) => header.client_type(),
+
+            #[cfg(any(test, feature = "mocks"))]
+            Self::Mock(header) => header.client_type(),
         }
     }
 
     fn height(&self) -> Height {
         match self {
             Self::Tendermint(header) => header.height(),
+            Self::Solomachine(header) => header.height(),
+            Self::Wasm(header) => header.height(),
+
+            #[cfg(any(test, feature = "mocks"))]
+            Self::Mock(header) => header.height(),
         }
     }
 
     fn timestamp(&self) -> Timestamp {
         match self {
             Self::Tendermint(header) => header.timestamp(),
+            Self::Solomachine(header) => header.timestamp(),
+            Self::Wasm(header) => header.timestamp(),
+
+            #[cfg(any(test, feature = "mocks"))]
+            Self::Mock(header) => header.timestamp(),
         }
     }
 }
@@ -75,6 +97,19 @@ impl TryFrom<Any> for AnyHeader {
                 Ok(AnyHeader::Tendermint(val))
             }
 
+            SOLOMACHINE_HEADER_TYPE_URL => {
+                let val = sm_decode_header(raw.value.as_slice())?;
+                Ok(AnyHeader::Solomachine(val))
+            }
+
+            WASM_CLIENT_MESSAGE_TYPE_URL => {
+                let val = wasm_decode_header(raw.value.as_slice())?;
+                Ok(AnyHeader::Wasm(val))
+            }
+
+            #[cfg(any(test, feature = "mocks"))]
+            MOCK_HEADER_TYPE_URL => Ok(AnyHeader::Mock(MockHeader::try_from(raw)?)),
+
             _ => Err(Error::unknown_header_type(raw.type_url)),
         }
     }
@@ -89,6 +124,12 @@ impl From<AnyHeader> for Any {
                 type_url: TENDERMINT_HEADER_TYPE_URL.to_string(),
                 value: Protobuf::<RawHeader>::encode_vec(header),
             },
+
+            AnyHeader::Solomachine(header) => header.into(),
+            AnyHeader::Wasm(header) => header.into(),
+
+            #[cfg(any(test, feature = "mocks"))]
+            AnyHeader::Mock(header) => header.into(),
         }
     }
 }
@@ -98,3 +139,22 @@ impl From<TendermintHeader> for AnyHeader {
         Self::Tendermint(header)
     }
 }
+
+impl From<SolomachineHeader> for AnyHeader {
+    fn from(header: SolomachineHeader) -> Self {
+        Self::Solomachine(header)
+    }
+}
+
+impl From<WasmHeader> for AnyHeader {
+    fn from(header: WasmHeader) -> Self {
+        Self::Wasm(header)
+    }
+}
+
+#[cfg(any(test, feature = "mocks"))]
+impl From<MockHeader> for AnyHeader {
+    fn from(header: MockHeader) -> Self {
+        Self::Mock(header)
+    }
+}
@@ -336,6 +336,9 @@ pub enum Ordering {
     #[default]
     Unordered = 1,
     Ordered = 2,
+    /// Packets are delivered in order, but timing out a packet does not
+    /// close the channel.
+    OrderedAllowTimeout = 3,
 }
 
 impl Display for Ordering {
@@ -351,15 +354,28 @@ impl Ordering {
             Self::Uninitialized => "UNINITIALIZED",
             Self::Unordered => "ORDER_UNORDERED",
             Self::Ordered => "ORDER_ORDERED",
+            Self::OrderedAllowTimeout => "ORDER_ORDERED_ALLOW_TIMEOUT",
         }
     }
 
+    /// Whether packets are delivered in order on channels with this ordering,
+    /// ie. whether their receipt is tracked by the next sequence to receive.
+    pub fn is_ordered(&self) -> bool {
+        matches!(self, Self::Ordered | Self::OrderedAllowTimeout)
+    }
+
+    /// Whether timing out a packet closes channels with this ordering.
+    pub fn closes_on_timeout(&self) -> bool {
+        matches!(self, Self::Ordered)
+    }
+
     // Parses the Order out from a i32.
     pub fn from_i32(nr: i32) -> Result<Self, Error> {
         match nr {
             0 => Ok(Self::Uninitialized),
             1 => Ok(Self::Unordered),
             2 => Ok(Self::Ordered),
+            3 => Ok(Self::OrderedAllowTimeout),
 
             _ => Err(Error::unknown_order_type(nr.to_string())),
         }
@@ -370,10 +386,15 @@ impl FromStr for Ordering {
     type Err = Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        match s.to_lowercase().trim_start_matches("order_") {
+        match s
+            .to_lowercase()
+            .replace('-', "_")
+            .trim_start_matches("order_")
+        {
             "uninitialized" => Ok(Self::Uninitialized),
             "unordered" => Ok(Self::Unordered),
             "ordered" => Ok(Self::Ordered),
+            "ordered_allow_timeout" => Ok(Self::OrderedAllowTimeout),
             _ => Err(Error::unknown_order_type(s.to_string())),
         }
     }
@@ -386,6 +407,8 @@ pub enum State {
     TryOpen = 2,
     Open = 3,
     Closed = 4,
+    Flushing = 5,
+    FlushComplete = 6,
 }
 
 impl State {
@@ -397,6 +420,8 @@ impl State {
             Self::TryOpen => "TRYOPEN",
             Self::Open => "OPEN",
             Self::Closed => "CLOSED",
+            Self::Flushing => "FLUSHING",
+            Self::FlushComplete => "FLUSHCOMPLETE",
         }
     }
 
@@ -408,6 +433,8 @@ impl State {
             2 => Ok(Self::TryOpen),
             3 => Ok(Self::Open),
             4 => Ok(Self::Closed),
+            5 => Ok(Self::Flushing),
+            6 => Ok(Self::FlushComplete),
             _ => Err(Error::unknown_state(s)),
         }
     }
@@ -422,6 +449,27 @@ impl State {
         self == State::Closed
     }
 
+    /// Returns whether or not this channel is in the middle of an upgrade,
+    /// ie. it is either `Flushing` or `FlushComplete`.
+    pub fn is_upgrading(self) -> bool {
+        matches!(self, State::Flushing | State::FlushComplete)
+    }
+
+    /// The progress of a channel with this state along its lifecycle. An open channel
+    /// goes through the `Flushing` and `FlushComplete` states when it is upgraded, after
+    /// which it is open again, and is `Closed` for good, whatever its state before.
+    fn progress(self) -> u32 {
+        match self {
+            Self::Uninitialized => 0,
+            Self::Init => 1,
+            Self::TryOpen => 2,
+            Self::Open => 3,
+            Self::Flushing => 4,
+            Self::FlushComplete => 5,
+            Self::Closed => 6,
+        }
+    }
+
     /// Returns whether or not the channel with this state
     /// has progressed less or the same than the argument.
     ///
@@ -430,9 +478,10 @@ impl State {
     /// assert!(State::Init.less_or_equal_progress(State::Open));
     /// assert!(State::TryOpen.less_or_equal_progress(State::TryOpen));
     /// assert!(!State::Closed.less_or_equal_progress(State::Open));
+    /// assert!(State::FlushComplete.less_or_equal_progress(State::Closed));
     /// ```
     pub fn less_or_equal_progress(self, other: Self) -> bool {
-        self as u32 <= other as u32
+        self.progress() <= other.progress()
     }
 }
 
@@ -593,6 +642,14 @@ mod tests {
                 ordering: "ORDERED",
                 want_res: Some(Ordering::Ordered),
             },
+            Test {
+                ordering: "ORDER_ORDERED_ALLOW_TIMEOUT",
+                want_res: Some(Ordering::OrderedAllowTimeout),
+            },
+            Test {
+                ordering: "ordered-allow-timeout",
+                want_res: Some(Ordering::OrderedAllowTimeout),
+            },
             Test {
                 ordering: "UNKNOWN_ORDER",
                 want_res: None,
@@ -608,4 +665,20 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn channel_state_progress() {
+        use super::State;
+
+        assert!(State::Init.less_or_equal_progress(State::Open));
+        assert!(State::TryOpen.less_or_equal_progress(State::TryOpen));
+        assert!(!State::Closed.less_or_equal_progress(State::Open));
+
+        // An upgrading channel is open, but not closed
+        assert!(State::Open.less_or_equal_progress(State::Flushing));
+        assert!(State::Flushing.less_or_equal_progress(State::FlushComplete));
+        assert!(State::FlushComplete.less_or_equal_progress(State::Closed));
+        assert!(!State::Closed.less_or_equal_progress(State::Flushing));
+        assert!(!State::Flushing.less_or_equal_progress(State::TryOpen));
+    }
 }
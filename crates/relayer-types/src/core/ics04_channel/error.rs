@@ -349,6 +349,19 @@ define_error! {
                     e.description)
             },
 
+        MissingUpgradeTimeout
+            | _ | { "missing upgrade timeout, either a height or a timestamp must be set" },
+
+        MissingUpgradeFields
+            | _ | { "missing upgrade fields" },
+
+        MissingUpgrade
+            | _ | { "missing upgrade" },
+
+        InvalidTimeoutTimestamp
+            { timestamp: u64 }
+            | e | { format_args!("invalid timeout timestamp: {}", e.timestamp) },
+
         AbciConversionFailed
             { abci_event: String }
             | e | { format_args!("Failed to convert abci event to IbcEvent: {}", e.abci_event)}
@@ -20,6 +20,9 @@ pub const PORT_ID_ATTRIBUTE_KEY: &str = "port_id";
 pub const COUNTERPARTY_CHANNEL_ID_ATTRIBUTE_KEY: &str = "counterparty_channel_id";
 pub const COUNTERPARTY_PORT_ID_ATTRIBUTE_KEY: &str = "counterparty_port_id";
 
+/// Channel upgrade event attribute keys
+pub const UPGRADE_SEQUENCE_ATTRIBUTE_KEY: &str = "upgrade_sequence";
+
 /// Packet event attribute keys
 pub const PKT_SEQ_ATTRIBUTE_KEY: &str = "packet_sequence";
 pub const PKT_DATA_ATTRIBUTE_KEY: &str = "packet_data_hex";
@@ -475,6 +478,199 @@ impl_from_ibc_to_abci_event!(
     CloseConfirm
 );
 
+/// Attributes common to all the channel upgrade handshake events.
+#[derive(Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, Serialize)]
+pub struct UpgradeAttributes {
+    pub port_id: PortId,
+    pub channel_id: ChannelId,
+    pub counterparty_port_id: PortId,
+    pub counterparty_channel_id: Option<ChannelId>,
+    pub upgrade_sequence: u64,
+}
+
+impl UpgradeAttributes {
+    pub fn port_id(&self) -> &PortId {
+        &self.port_id
+    }
+
+    pub fn channel_id(&self) -> &ChannelId {
+        &self.channel_id
+    }
+}
+
+impl Display for UpgradeAttributes {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), FmtError> {
+        match &self.counterparty_channel_id {
+            Some(counterparty_channel_id) => write!(f, "UpgradeAttributes {{ port_id: {}, channel_id: {}, counterparty_port_id: {}, counterparty_channel_id: {}, upgrade_sequence: {} }}", self.port_id, self.channel_id, self.counterparty_port_id, counterparty_channel_id, self.upgrade_sequence),
+            None => write!(f, "UpgradeAttributes {{ port_id: {}, channel_id: {}, counterparty_port_id: {}, counterparty_channel_id: None, upgrade_sequence: {} }}", self.port_id, self.channel_id, self.counterparty_port_id, self.upgrade_sequence),
+        }
+    }
+}
+
+/// Convert upgrade attributes to Tendermint ABCI tags
+impl From<UpgradeAttributes> for Vec<abci::EventAttribute> {
+    fn from(a: UpgradeAttributes) -> Self {
+        let mut attributes = vec![];
+        let port_id = (PORT_ID_ATTRIBUTE_KEY, a.port_id.as_str()).into();
+        attributes.push(port_id);
+        let channel_id = (CHANNEL_ID_ATTRIBUTE_KEY, a.channel_id.as_str()).into();
+        attributes.push(channel_id);
+        let counterparty_port_id = (
+            COUNTERPARTY_PORT_ID_ATTRIBUTE_KEY,
+            a.counterparty_port_id.as_str(),
+        )
+            .into();
+        attributes.push(counterparty_port_id);
+        if let Some(channel_id) = a.counterparty_channel_id {
+            let channel_id = (COUNTERPARTY_CHANNEL_ID_ATTRIBUTE_KEY, channel_id.as_str()).into();
+            attributes.push(channel_id);
+        }
+        let upgrade_sequence = (
+            UPGRADE_SEQUENCE_ATTRIBUTE_KEY,
+            a.upgrade_sequence.to_string(),
+        )
+            .into();
+        attributes.push(upgrade_sequence);
+        attributes
+    }
+}
+
+macro_rules! impl_upgrade_event {
+    ($($event:ident => $variant:ident),+) => {
+        $(
+            impl $event {
+                pub fn port_id(&self) -> &PortId {
+                    &self.port_id
+                }
+
+                pub fn channel_id(&self) -> &ChannelId {
+                    &self.channel_id
+                }
+
+                pub fn counterparty_port_id(&self) -> &PortId {
+                    &self.counterparty_port_id
+                }
+
+                pub fn counterparty_channel_id(&self) -> Option<&ChannelId> {
+                    self.counterparty_channel_id.as_ref()
+                }
+            }
+
+            impl Display for $event {
+                fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), FmtError> {
+                    write!(
+                        f,
+                        "{} {{ {} }}",
+                        stringify!($event),
+                        UpgradeAttributes::from(self.clone())
+                    )
+                }
+            }
+
+            impl From<$event> for UpgradeAttributes {
+                fn from(ev: $event) -> Self {
+                    Self {
+                        port_id: ev.port_id,
+                        channel_id: ev.channel_id,
+                        counterparty_port_id: ev.counterparty_port_id,
+                        counterparty_channel_id: ev.counterparty_channel_id,
+                        upgrade_sequence: ev.upgrade_sequence,
+                    }
+                }
+            }
+
+            impl TryFrom<UpgradeAttributes> for $event {
+                type Error = EventError;
+
+                fn try_from(attrs: UpgradeAttributes) -> Result<Self, Self::Error> {
+                    Ok(Self {
+                        port_id: attrs.port_id,
+                        channel_id: attrs.channel_id,
+                        counterparty_port_id: attrs.counterparty_port_id,
+                        counterparty_channel_id: attrs.counterparty_channel_id,
+                        upgrade_sequence: attrs.upgrade_sequence,
+                    })
+                }
+            }
+
+            impl From<$event> for IbcEvent {
+                fn from(v: $event) -> Self {
+                    IbcEvent::$variant(v)
+                }
+            }
+
+            impl EventType for $event {
+                fn event_type() -> IbcEventType {
+                    IbcEventType::$variant
+                }
+            }
+
+            impl From<$event> for abci::Event {
+                fn from(v: $event) -> Self {
+                    let kind = <$event>::event_type().as_str().to_owned();
+                    Self {
+                        kind,
+                        attributes: UpgradeAttributes::from(v).into(),
+                    }
+                }
+            }
+        )+
+    };
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct UpgradeInit {
+    pub port_id: PortId,
+    pub channel_id: ChannelId,
+    pub counterparty_port_id: PortId,
+    pub counterparty_channel_id: Option<ChannelId>,
+    pub upgrade_sequence: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct UpgradeTry {
+    pub port_id: PortId,
+    pub channel_id: ChannelId,
+    pub counterparty_port_id: PortId,
+    pub counterparty_channel_id: Option<ChannelId>,
+    pub upgrade_sequence: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct UpgradeAck {
+    pub port_id: PortId,
+    pub channel_id: ChannelId,
+    pub counterparty_port_id: PortId,
+    pub counterparty_channel_id: Option<ChannelId>,
+    pub upgrade_sequence: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct UpgradeConfirm {
+    pub port_id: PortId,
+    pub channel_id: ChannelId,
+    pub counterparty_port_id: PortId,
+    pub counterparty_channel_id: Option<ChannelId>,
+    pub upgrade_sequence: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct UpgradeOpen {
+    pub port_id: PortId,
+    pub channel_id: ChannelId,
+    pub counterparty_port_id: PortId,
+    pub counterparty_channel_id: Option<ChannelId>,
+    pub upgrade_sequence: u64,
+}
+
+impl_upgrade_event!(
+    UpgradeInit => UpgradeInitChannel,
+    UpgradeTry => UpgradeTryChannel,
+    UpgradeAck => UpgradeAckChannel,
+    UpgradeConfirm => UpgradeConfirmChannel,
+    UpgradeOpen => UpgradeOpenChannel
+);
+
 #[derive(Clone, Debug, PartialEq, Eq, Serialize)]
 pub struct SendPacket {
     pub packet: Packet,
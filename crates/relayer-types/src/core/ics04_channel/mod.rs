@@ -9,4 +9,6 @@ pub mod msgs;
 pub mod packet;
 pub mod packet_id;
 pub mod timeout;
+pub mod upgrade;
+pub mod upgrade_fields;
 pub mod version;
@@ -2,9 +2,13 @@ use std::fmt::{Display, Error as FmtError, Formatter};
 
 use serde::{Deserialize, Serialize};
 
+use ibc_proto::ibc::core::channel::v1::Timeout as RawUpgradeTimeout;
 use ibc_proto::ibc::core::client::v1::Height as RawHeight;
+use ibc_proto::Protobuf;
 
 use crate::core::ics02_client::{error::Error as ICS2Error, height::Height};
+use crate::core::ics04_channel::error::Error as ChannelError;
+use crate::timestamp::Timestamp;
 
 /// Indicates a consensus height on the destination chain after which the packet
 /// will no longer be processed, and will instead count as having timed-out.
@@ -180,3 +184,153 @@ impl<'de> Deserialize<'de> for TimeoutHeight {
         })
     }
 }
+
+/// A composite of timeout height and timeout timestamp types, useful for when
+/// performing a channel upgrade handshake, as there are cases when only timeout
+/// height is set, only timeout timestamp is set, or both are set.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum UpgradeTimeout {
+    /// Timeout height indicates the height at which the counterparty
+    /// must no longer proceed with the upgrade handshake.
+    /// The chains will then preserve their original channel and the upgrade handshake is aborted
+    Height(Height),
+
+    /// Timeout timestamp indicates the time on the counterparty at which
+    /// the counterparty must no longer proceed with the upgrade handshake.
+    /// The chains will then preserve their original channel and the upgrade handshake is aborted.
+    Timestamp(Timestamp),
+
+    /// Both timeouts are set.
+    Both(Height, Timestamp),
+}
+
+impl UpgradeTimeout {
+    pub fn new(height: Option<Height>, timestamp: Option<Timestamp>) -> Result<Self, ChannelError> {
+        match (height, timestamp) {
+            (Some(height), None) => Ok(UpgradeTimeout::Height(height)),
+            (None, Some(timestamp)) => Ok(UpgradeTimeout::Timestamp(timestamp)),
+            (Some(height), Some(timestamp)) => Ok(UpgradeTimeout::Both(height, timestamp)),
+            (None, None) => Err(ChannelError::missing_upgrade_timeout()),
+        }
+    }
+
+    pub fn into_tuple(self) -> (Option<Height>, Option<Timestamp>) {
+        match self {
+            UpgradeTimeout::Height(height) => (Some(height), None),
+            UpgradeTimeout::Timestamp(timestamp) => (None, Some(timestamp)),
+            UpgradeTimeout::Both(height, timestamp) => (Some(height), Some(timestamp)),
+        }
+    }
+}
+
+impl Protobuf<RawUpgradeTimeout> for UpgradeTimeout {}
+
+impl TryFrom<RawUpgradeTimeout> for UpgradeTimeout {
+    type Error = ChannelError;
+
+    fn try_from(value: RawUpgradeTimeout) -> Result<Self, Self::Error> {
+        let raw_timeout_height = value.height.map(Height::try_from).transpose();
+
+        let raw_timeout_timestamp = Timestamp::from_nanoseconds(value.timestamp)
+            .map_err(|_| ChannelError::invalid_timeout_timestamp(value.timestamp))
+            .map(|timestamp| {
+                // Note: if the timestamp is 0, then the timeout timestamp is not set
+                if timestamp.nanoseconds() == 0 {
+                    None
+                } else {
+                    Some(timestamp)
+                }
+            });
+
+        let (timeout_height, timeout_timestamp) = match (raw_timeout_height, raw_timeout_timestamp)
+        {
+            (Ok(timeout_height), Ok(timeout_timestamp)) => (timeout_height, timeout_timestamp),
+            (Err(_), Ok(Some(timeout_timestamp))) => (None, Some(timeout_timestamp)),
+            (Ok(Some(timeout_height)), Err(_)) => (Some(timeout_height), None),
+            _ => return Err(ChannelError::missing_upgrade_timeout()),
+        };
+
+        Self::new(timeout_height, timeout_timestamp)
+    }
+}
+
+impl From<UpgradeTimeout> for RawUpgradeTimeout {
+    fn from(value: UpgradeTimeout) -> Self {
+        match value {
+            UpgradeTimeout::Height(height) => Self {
+                height: Some(RawHeight::from(height)),
+                timestamp: 0,
+            },
+            UpgradeTimeout::Timestamp(timestamp) => Self {
+                height: None,
+                timestamp: timestamp.nanoseconds(),
+            },
+            UpgradeTimeout::Both(height, timestamp) => Self {
+                height: Some(RawHeight::from(height)),
+                timestamp: timestamp.nanoseconds(),
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ibc_proto::ibc::core::channel::v1::Timeout as RawUpgradeTimeout;
+    use ibc_proto::ibc::core::client::v1::Height as RawHeight;
+
+    use crate::core::ics04_channel::timeout::UpgradeTimeout;
+
+    #[test]
+    fn upgrade_timeout_try_from_raw() {
+        let height = Some(RawHeight {
+            revision_number: 1,
+            revision_height: 10,
+        });
+
+        let only_height = RawUpgradeTimeout {
+            height: height.clone(),
+            timestamp: 0,
+        };
+        assert!(matches!(
+            UpgradeTimeout::try_from(only_height),
+            Ok(UpgradeTimeout::Height(_))
+        ));
+
+        let only_timestamp = RawUpgradeTimeout {
+            height: None,
+            timestamp: 1_000_000,
+        };
+        assert!(matches!(
+            UpgradeTimeout::try_from(only_timestamp),
+            Ok(UpgradeTimeout::Timestamp(_))
+        ));
+
+        let both = RawUpgradeTimeout {
+            height,
+            timestamp: 1_000_000,
+        };
+        assert!(matches!(
+            UpgradeTimeout::try_from(both),
+            Ok(UpgradeTimeout::Both(_, _))
+        ));
+
+        let neither = RawUpgradeTimeout {
+            height: None,
+            timestamp: 0,
+        };
+        assert!(UpgradeTimeout::try_from(neither).is_err());
+    }
+
+    #[test]
+    fn upgrade_timeout_to_and_from() {
+        let raw = RawUpgradeTimeout {
+            height: Some(RawHeight {
+                revision_number: 0,
+                revision_height: 42,
+            }),
+            timestamp: 5_000,
+        };
+        let timeout = UpgradeTimeout::try_from(raw.clone()).unwrap();
+        assert_eq!(raw, RawUpgradeTimeout::from(timeout));
+    }
+}
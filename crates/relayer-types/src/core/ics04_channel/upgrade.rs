@@ -0,0 +1,123 @@
+use ibc_proto::ibc::core::channel::v1::Upgrade as RawUpgrade;
+use ibc_proto::Protobuf;
+use serde::{Deserialize, Serialize};
+
+use crate::core::ics04_channel::error::Error as ChannelError;
+use crate::core::ics04_channel::packet::Sequence;
+use crate::core::ics04_channel::timeout::UpgradeTimeout;
+use crate::core::ics04_channel::upgrade_fields::UpgradeFields;
+
+/// An upgrade proposed for a channel end, as stored by ibc-go
+/// under the `channelUpgrades/upgrades` path.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Upgrade {
+    pub fields: UpgradeFields,
+    // timeout can be zero, see `TryFrom<RawUpgrade>` implementation
+    pub timeout: Option<UpgradeTimeout>,
+    pub next_sequence_send: Sequence,
+}
+
+impl Protobuf<RawUpgrade> for Upgrade {}
+
+impl TryFrom<RawUpgrade> for Upgrade {
+    type Error = ChannelError;
+
+    fn try_from(value: RawUpgrade) -> Result<Self, Self::Error> {
+        let fields = value
+            .fields
+            .ok_or_else(ChannelError::missing_upgrade_fields)?
+            .try_into()?;
+
+        // The upgrade stored on the chain which initiated the upgrade handshake
+        // does not have a timeout set, only the one stored after `ChanUpgradeTry`
+        // on the counterparty does.
+        let timeout = value
+            .timeout
+            .and_then(|tm| UpgradeTimeout::try_from(tm).ok());
+
+        let next_sequence_send = value.next_sequence_send.into();
+
+        Ok(Self {
+            fields,
+            timeout,
+            next_sequence_send,
+        })
+    }
+}
+
+impl From<Upgrade> for RawUpgrade {
+    fn from(value: Upgrade) -> Self {
+        let timeout = value.timeout.map(|tm| tm.into());
+
+        Self {
+            fields: Some(value.fields.into()),
+            timeout,
+            next_sequence_send: value.next_sequence_send.into(),
+        }
+    }
+}
+
+#[cfg(test)]
+pub mod test_util {
+    use ibc_proto::ibc::core::channel::v1::Timeout as RawTimeout;
+    use ibc_proto::ibc::core::channel::v1::Upgrade as RawUpgrade;
+    use ibc_proto::ibc::core::client::v1::Height as RawHeight;
+
+    use crate::core::ics04_channel::upgrade_fields::test_util::get_dummy_upgrade_fields;
+
+    /// Returns a dummy `RawUpgrade`, for testing only!
+    pub fn get_dummy_upgrade() -> RawUpgrade {
+        RawUpgrade {
+            fields: Some(get_dummy_upgrade_fields()),
+            timeout: Some(RawTimeout {
+                height: Some(RawHeight {
+                    revision_number: 1,
+                    revision_height: 1,
+                }),
+                timestamp: 0,
+            }),
+            next_sequence_send: 1,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ibc_proto::ibc::core::channel::v1::Upgrade as RawUpgrade;
+    use test_log::test;
+
+    use crate::core::ics04_channel::upgrade::test_util::get_dummy_upgrade;
+    use crate::core::ics04_channel::upgrade::Upgrade;
+
+    #[test]
+    fn upgrade_try_from_raw() {
+        let raw = get_dummy_upgrade();
+
+        assert!(Upgrade::try_from(raw.clone()).is_ok());
+
+        let without_timeout = RawUpgrade {
+            timeout: None,
+            ..raw.clone()
+        };
+        assert!(Upgrade::try_from(without_timeout)
+            .unwrap()
+            .timeout
+            .is_none());
+
+        let without_fields = RawUpgrade {
+            fields: None,
+            ..raw
+        };
+        assert!(Upgrade::try_from(without_fields).is_err());
+    }
+
+    #[test]
+    fn to_and_from() {
+        let raw = get_dummy_upgrade();
+        let upgrade = Upgrade::try_from(raw.clone()).unwrap();
+        let raw_back = RawUpgrade::from(upgrade.clone());
+        let upgrade_back = Upgrade::try_from(raw_back.clone()).unwrap();
+        assert_eq!(raw, raw_back);
+        assert_eq!(upgrade, upgrade_back);
+    }
+}
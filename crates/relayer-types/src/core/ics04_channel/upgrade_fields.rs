@@ -0,0 +1,82 @@
+use std::str::FromStr;
+
+use ibc_proto::ibc::core::channel::v1::UpgradeFields as RawUpgradeFields;
+use ibc_proto::Protobuf;
+use serde::{Deserialize, Serialize};
+
+use crate::core::ics04_channel::channel::Ordering;
+use crate::core::ics04_channel::error::Error as ChannelError;
+use crate::core::ics04_channel::version::Version;
+use crate::core::ics24_host::identifier::ConnectionId;
+
+/// The fields of a channel end which may be changed during a channel upgrade.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UpgradeFields {
+    pub ordering: Ordering,
+    pub connection_hops: Vec<ConnectionId>,
+    pub version: Version,
+}
+
+impl UpgradeFields {
+    pub fn new(ordering: Ordering, connection_hops: Vec<ConnectionId>, version: Version) -> Self {
+        Self {
+            ordering,
+            connection_hops,
+            version,
+        }
+    }
+}
+
+impl Protobuf<RawUpgradeFields> for UpgradeFields {}
+
+impl TryFrom<RawUpgradeFields> for UpgradeFields {
+    type Error = ChannelError;
+
+    fn try_from(value: RawUpgradeFields) -> Result<Self, Self::Error> {
+        let ordering = Ordering::from_i32(value.ordering)?;
+
+        // Parse each item in connection_hops into a ConnectionId.
+        let connection_hops = value
+            .connection_hops
+            .into_iter()
+            .map(|conn_id| ConnectionId::from_str(conn_id.as_str()))
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(ChannelError::identifier)?;
+
+        let version = Version::from(value.version);
+
+        Ok(Self::new(ordering, connection_hops, version))
+    }
+}
+
+impl From<UpgradeFields> for RawUpgradeFields {
+    fn from(value: UpgradeFields) -> Self {
+        let raw_connection_hops = value
+            .connection_hops
+            .iter()
+            .map(|id| id.as_str().to_string())
+            .collect();
+
+        Self {
+            ordering: value.ordering as i32,
+            connection_hops: raw_connection_hops,
+            version: value.version.to_string(),
+        }
+    }
+}
+
+#[cfg(test)]
+pub mod test_util {
+    use ibc_proto::ibc::core::channel::v1::UpgradeFields as RawUpgradeFields;
+
+    use crate::core::ics24_host::identifier::ConnectionId;
+
+    /// Returns a dummy `RawUpgradeFields`, for testing only!
+    pub fn get_dummy_upgrade_fields() -> RawUpgradeFields {
+        RawUpgradeFields {
+            ordering: 1,
+            connection_hops: vec![ConnectionId::default().to_string()],
+            version: "ics20".to_string(),
+        }
+    }
+}
@@ -42,6 +42,7 @@ pub enum Path {
     Acks(AcksPath),
     Receipts(ReceiptsPath),
     Upgrade(ClientUpgradePath),
+    ChannelUpgrade(ChannelUpgradePath),
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Display)]
@@ -112,6 +113,13 @@ pub struct ReceiptsPath {
     pub sequence: Sequence,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Display)]
+#[display(fmt = "channelUpgrades/upgrades/ports/{port_id}/channels/{channel_id}")]
+pub struct ChannelUpgradePath {
+    pub port_id: PortId,
+    pub channel_id: ChannelId,
+}
+
 /// Paths that are specific for client upgrades.
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Display)]
 pub enum ClientUpgradePath {
@@ -166,6 +174,7 @@ impl FromStr for Path {
             .or_else(|| parse_acks(&components))
             .or_else(|| parse_receipts(&components))
             .or_else(|| parse_upgrades(&components))
+            .or_else(|| parse_channel_upgrades(&components))
             .ok_or_else(|| PathError::parse_failure(s.to_string()))
     }
 }
@@ -372,6 +381,39 @@ fn parse_channel_ends(components: &[&str]) -> Option<Path> {
     Some(ChannelEndsPath(port_id, channel_id).into())
 }
 
+fn parse_channel_upgrades(components: &[&str]) -> Option<Path> {
+    if components.len() != 6 {
+        return None;
+    }
+
+    if components[0] != "channelUpgrades" || components[1] != "upgrades" {
+        return None;
+    }
+
+    let port = parse_ports(&components[2..=3]);
+    let channel = parse_channels(&components[4..=5]);
+
+    let port_id = if let Some(Path::Ports(PortsPath(port_id))) = port {
+        port_id
+    } else {
+        return None;
+    };
+
+    let channel_id = if let Some(SubPath::Channels(channel_id)) = channel {
+        channel_id
+    } else {
+        return None;
+    };
+
+    Some(
+        ChannelUpgradePath {
+            port_id,
+            channel_id,
+        }
+        .into(),
+    )
+}
+
 fn parse_seqs(components: &[&str]) -> Option<Path> {
     if components.len() != 5 {
         return None;
@@ -778,6 +820,35 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_parse_channel_upgrades_fn() {
+        let path = "channelUpgrades/upgrades/ports/defaultPort/channels/channel-0";
+        let components: Vec<&str> = path.split('/').collect();
+
+        assert_eq!(
+            parse_channel_upgrades(&components),
+            Some(Path::ChannelUpgrade(ChannelUpgradePath {
+                port_id: PortId::default(),
+                channel_id: ChannelId::default(),
+            })),
+        );
+    }
+
+    #[test]
+    fn channel_upgrade_path_parses() {
+        let path = "channelUpgrades/upgrades/ports/defaultPort/channels/channel-0";
+        let path = Path::from_str(path);
+
+        assert!(path.is_ok());
+        assert_eq!(
+            path.unwrap(),
+            Path::ChannelUpgrade(ChannelUpgradePath {
+                port_id: PortId::default(),
+                channel_id: ChannelId::default(),
+            }),
+        );
+    }
+
     #[test]
     fn test_parse_seqs_fn() {
         let path = "nextSequenceSend/ports/defaultPort/channels/channel-0";
@@ -3,7 +3,7 @@ use std::time::Duration;
 use serde::{Deserialize, Serialize};
 
 use ibc_proto::google::protobuf::Any;
-use ibc_proto::ibc::mock::ClientState as RawMockClientState;
+use ibc_proto::ibc::mock::Header as RawMockHeader;
 use ibc_proto::Protobuf;
 
 use crate::core::ics02_client::client_state::ClientState;
@@ -19,10 +19,28 @@ use crate::Height;
 
 pub const MOCK_CLIENT_STATE_TYPE_URL: &str = "/ibc.mock.ClientState";
 
+/// Raw representation of a [`MockClientState`].
+///
+/// This is `ibc.mock.ClientState` extended with the identifier of the chain
+/// tracked by the client, which the relayer needs in order to find the
+/// counterparty of a client hosted on a mock chain.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RawMockClientState {
+    #[prost(message, optional, tag = "1")]
+    pub header: Option<RawMockHeader>,
+    #[prost(bool, tag = "2")]
+    pub frozen: bool,
+    #[prost(uint64, tag = "3")]
+    pub trusting_period: u64,
+    #[prost(string, tag = "4")]
+    pub chain_id: String,
+}
+
 /// A mock of a client state. For an example of a real structure that this mocks, you can see
 /// `ClientState` of ics07_tendermint/client_state.rs.
-#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub struct MockClientState {
+    pub chain_id: ChainId,
     pub header: MockHeader,
     pub frozen_height: Option<Height>,
 }
@@ -30,11 +48,16 @@ pub struct MockClientState {
 impl MockClientState {
     pub fn new(header: MockHeader) -> Self {
         Self {
+            chain_id: ChainId::default(),
             header,
             frozen_height: None,
         }
     }
 
+    pub fn with_chain_id(self, chain_id: ChainId) -> Self {
+        Self { chain_id, ..self }
+    }
+
     pub fn latest_height(&self) -> Height {
         self.header.height()
     }
@@ -46,19 +69,22 @@ impl TryFrom<RawMockClientState> for MockClientState {
     type Error = Error;
 
     fn try_from(raw: RawMockClientState) -> Result<Self, Self::Error> {
-        Ok(Self::new(raw.header.unwrap().try_into()?))
+        let header = raw
+            .header
+            .ok_or_else(Error::missing_raw_header)?
+            .try_into()?;
+
+        Ok(Self::new(header).with_chain_id(ChainId::from_string(&raw.chain_id)))
     }
 }
 
 impl From<MockClientState> for RawMockClientState {
     fn from(value: MockClientState) -> Self {
         RawMockClientState {
-            header: Some(ibc_proto::ibc::mock::Header {
-                height: Some(value.header.height().into()),
-                timestamp: value.header.timestamp.nanoseconds(),
-            }),
+            header: Some(value.header.into()),
             frozen: false,
             trusting_period: 14 * 24 * 60 * 60,
+            chain_id: value.chain_id.to_string(),
         }
     }
 }
@@ -104,7 +130,7 @@ impl ClientState for MockClientState {
     type UpgradeOptions = ();
 
     fn chain_id(&self) -> ChainId {
-        unimplemented!()
+        self.chain_id.clone()
     }
 
     fn client_type(&self) -> ClientType {
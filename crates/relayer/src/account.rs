@@ -3,10 +3,37 @@
 use serde::{Deserialize, Serialize};
 
 /// The balance for a specific denom
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Balance {
     /// The amount of coins in the account, as a string to allow for large amounts
     pub amount: String,
     /// The denomination for that coin
     pub denom: String,
 }
+
+/// A fee allowance granted to an account, as found by the `feegrant` module
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FeeAllowance {
+    /// The maximum amount of coins that can be spent on fees, unlimited if empty
+    pub spend_limit: Vec<Balance>,
+    /// The time at which the allowance expires, if any
+    pub expiration: Option<tendermint::Time>,
+}
+
+/// An authorization granted to an account, as found by the `authz` module
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Authorization {
+    /// The type URL of the message the grantee is allowed to execute
+    pub msg_type_url: String,
+    /// The time at which the authorization expires, if any
+    pub expiration: Option<tendermint::Time>,
+}
+
+/// The grants given by a granter to a grantee
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Grants {
+    /// The fee allowance of the grantee, if any
+    pub fee_allowance: Option<FeeAllowance>,
+    /// The authorization for the grantee to renew fee allowances on behalf of the granter, if any
+    pub authorization: Option<Authorization>,
+}
@@ -3,6 +3,8 @@ pub mod cosmos;
 pub mod counterparty;
 pub mod endpoint;
 pub mod handle;
+pub mod mock;
 pub mod requests;
 pub mod runtime;
+pub mod solomachine;
 pub mod tracking;
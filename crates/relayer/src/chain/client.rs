@@ -10,6 +10,8 @@ use crate::foreign_client::CreateOptions;
 #[derive(Clone, Debug)]
 pub enum ClientSettings {
     Tendermint(cosmos::client::Settings),
+    Mock,
+    SoloMachine,
 }
 
 impl ClientSettings {
@@ -21,13 +23,12 @@ impl ClientSettings {
         src_chain_config: &ChainConfig,
         dst_chain_config: &ChainConfig,
     ) -> Self {
-        // Currently, only Tendermint chain pairs are supported by
-        // ForeignClient::build_create_client_and_send. Support for
-        // heterogeneous chains is left for future revisions.
+        // The type of the client is given by the source chain, while
+        // the destination chain only hosts it.
         //
         // TODO: extract Tendermint-related configs into a separate substructure
         // that can be used both by CosmosSdkConfig and configs for nonSDK chains.
-        use ChainConfig::CosmosSdk as Csdk;
+        use ChainConfig::{CosmosSdk as Csdk, Mock, SoloMachine};
         match (src_chain_config, dst_chain_config) {
             (Csdk(src_chain_config), Csdk(dst_chain_config)) => {
                 ClientSettings::Tendermint(cosmos::client::Settings::for_create_command(
@@ -36,6 +37,15 @@ impl ClientSettings {
                     dst_chain_config,
                 ))
             }
+            (Csdk(src_chain_config), Mock(_) | SoloMachine(_)) => {
+                ClientSettings::Tendermint(cosmos::client::Settings::for_in_process_host(
+                    options,
+                    src_chain_config,
+                    dst_chain_config.max_block_time(),
+                ))
+            }
+            (Mock(_), _) => ClientSettings::Mock,
+            (SoloMachine(_), _) => ClientSettings::SoloMachine,
         }
     }
 }
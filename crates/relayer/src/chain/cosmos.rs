@@ -1,6 +1,7 @@
 use alloc::sync::Arc;
 use bytes::{Buf, Bytes};
 use core::{
+    cell::RefCell,
     convert::{TryFrom, TryInto},
     future::Future,
     str::FromStr,
@@ -8,7 +9,7 @@ use core::{
 };
 use futures::future::join_all;
 use num_bigint::BigInt;
-use std::{cmp::Ordering, thread};
+use std::{cmp::Ordering, collections::BTreeMap, thread};
 
 use tokio::runtime::Runtime as TokioRuntime;
 use tonic::codegen::http::Uri;
@@ -25,6 +26,7 @@ use ibc_proto::ibc::apps::fee::v1::{
 use ibc_proto::interchain_security::ccv::v1::ConsumerParams as CcvConsumerParams;
 use ibc_proto::Protobuf;
 use ibc_relayer_types::applications::ics31_icq::response::CrossChainQueryResponse;
+use ibc_relayer_types::applications::transfer::RawCoin;
 use ibc_relayer_types::clients::ics07_tendermint::client_state::{
     AllowUpdate, ClientState as TmClientState,
 };
@@ -36,16 +38,19 @@ use ibc_relayer_types::core::ics02_client::events::UpdateClient;
 use ibc_relayer_types::core::ics03_connection::connection::{
     ConnectionEnd, IdentifiedConnectionEnd,
 };
-use ibc_relayer_types::core::ics04_channel::channel::{ChannelEnd, IdentifiedChannelEnd};
+use ibc_relayer_types::core::ics04_channel::channel::{
+    ChannelEnd, IdentifiedChannelEnd, Ordering as ChannelOrdering,
+};
 use ibc_relayer_types::core::ics04_channel::packet::Sequence;
+use ibc_relayer_types::core::ics04_channel::upgrade::Upgrade;
 use ibc_relayer_types::core::ics23_commitment::commitment::CommitmentPrefix;
 use ibc_relayer_types::core::ics23_commitment::merkle::MerkleProof;
 use ibc_relayer_types::core::ics24_host::identifier::{
     ChainId, ChannelId, ClientId, ConnectionId, PortId,
 };
 use ibc_relayer_types::core::ics24_host::path::{
-    AcksPath, ChannelEndsPath, ClientConsensusStatePath, ClientStatePath, CommitmentsPath,
-    ConnectionsPath, ReceiptsPath, SeqRecvsPath,
+    AcksPath, ChannelEndsPath, ChannelUpgradePath, ClientConsensusStatePath, ClientStatePath,
+    CommitmentsPath, ConnectionsPath, ReceiptsPath, SeqRecvsPath,
 };
 use ibc_relayer_types::core::ics24_host::{
     ClientUpgradePath, Path, IBC_QUERY_PATH, SDK_UPGRADE_QUERY_PATH,
@@ -56,30 +61,40 @@ use ibc_relayer_types::Height as ICSHeight;
 use tendermint::block::Height as TmHeight;
 use tendermint::node::{self, info::TxIndexStatus};
 use tendermint::time::Time as TmTime;
+use tendermint::Hash as TxHash;
+use tendermint_light_client::light_client::Options as TmLightClientOptions;
 use tendermint_light_client::verifier::types::LightBlock as TmLightBlock;
 use tendermint_rpc::client::CompatMode;
 use tendermint_rpc::endpoint::broadcast::tx_sync::Response;
 use tendermint_rpc::endpoint::status;
-use tendermint_rpc::{Client, HttpClient, Order};
+use tendermint_rpc::{Client, HttpClient, Order, Url};
 
-use crate::account::Balance;
+use crate::account::{Balance, Grants};
 use crate::chain::client::ClientSettings;
 use crate::chain::cosmos::batch::{
-    send_batched_messages_and_wait_check_tx, send_batched_messages_and_wait_commit,
-    sequential_send_batched_messages_and_wait_commit,
+    pipelined_send_batched_messages_and_wait_check_tx,
+    pipelined_send_batched_messages_and_wait_commit, send_batched_messages_and_wait_check_tx,
+    send_batched_messages_and_wait_commit, sequential_send_batched_messages_and_wait_commit,
 };
 use crate::chain::cosmos::encode::key_pair_to_signer;
+use crate::chain::cosmos::endpoints::{Endpoint, Endpoints, ProbeOutcome};
+use crate::chain::cosmos::estimate::estimate_tx_fee;
 use crate::chain::cosmos::fee::maybe_register_counterparty_payee;
+use crate::chain::cosmos::feegrant::MSG_GRANT_ALLOWANCE_TYPE_URL;
 use crate::chain::cosmos::gas::{calculate_fee, mul_ceil};
+use crate::chain::cosmos::gas_model::GasModelState;
+use crate::chain::cosmos::pipeline::InFlightTxs;
 use crate::chain::cosmos::query::account::get_or_fetch_account;
 use crate::chain::cosmos::query::balance::{query_all_balances, query_balance};
 use crate::chain::cosmos::query::consensus_state::query_consensus_state_heights;
 use crate::chain::cosmos::query::custom::cross_chain_query_via_rpc;
 use crate::chain::cosmos::query::denom_trace::query_denom_trace;
 use crate::chain::cosmos::query::fee::query_incentivized_packet;
+use crate::chain::cosmos::query::feegrant::{query_authorization, query_fee_allowance};
 use crate::chain::cosmos::query::status::query_status;
 use crate::chain::cosmos::query::tx::{
-    filter_matching_event, query_packets_from_block, query_packets_from_txs, query_txs,
+    all_ibc_events_from_tx_search_response, filter_matching_event, query_packets_from_block,
+    query_packets_from_txs, query_tx_response, query_txs,
 };
 use crate::chain::cosmos::query::{abci_query, fetch_version_specs, packet_query, QueryResponse};
 use crate::chain::cosmos::types::account::Account;
@@ -87,6 +102,7 @@ use crate::chain::cosmos::types::config::TxConfig;
 use crate::chain::cosmos::types::gas::{
     default_gas_from_config, gas_multiplier_from_config, max_gas_from_config,
 };
+use crate::chain::cosmos::wallets::Wallets;
 use crate::chain::endpoint::{ChainEndpoint, ChainStatus, HealthCheck};
 use crate::chain::handle::Subscription;
 use crate::chain::requests::*;
@@ -120,9 +136,13 @@ pub mod compatibility;
 pub mod config;
 pub mod eip_base_fee;
 pub mod encode;
+pub mod endpoints;
 pub mod estimate;
 pub mod fee;
+pub mod feegrant;
 pub mod gas;
+pub mod gas_model;
+pub mod pipeline;
 pub mod query;
 pub mod retry;
 pub mod simulate;
@@ -130,6 +150,7 @@ pub mod tx;
 pub mod types;
 pub mod version;
 pub mod wait;
+pub mod wallets;
 
 /// Defines an upper limit on how large any transaction can be.
 /// This upper limit is defined as a fraction relative to the block's
@@ -151,13 +172,31 @@ pub struct CosmosSdkChain {
     tx_config: TxConfig,
     pub rpc_client: HttpClient,
     compat_mode: CompatMode,
+    /// The RPC and gRPC addresses of the endpoint in use, which differ from the
+    /// configured `rpc_addr` and `grpc_addr` after failing over to a fallback endpoint
+    rpc_addr: Url,
     grpc_addr: Uri,
     light_client: TmLightClient,
     rt: Arc<TokioRuntime>,
     keybase: KeyRing<Secp256k1KeyPair>,
+    wallets: Wallets,
+
+    /// The full nodes serving the chain, and the health of each of them
+    endpoints: Endpoints,
+
+    /// The outcomes of the probes of the endpoints, when the chain has `fallback_endpoints`
+    endpoint_probes: Option<crossbeam_channel::Receiver<Vec<ProbeOutcome>>>,
+
+    /// A cached copy of the account information of each wallet, keyed by address
+    accounts: BTreeMap<String, Option<Account>>,
 
-    /// A cached copy of the account information
-    account: Option<Account>,
+    /// The Txs in flight of each wallet, keyed by address, when `tx_pipeline` is enabled
+    in_flight_txs: BTreeMap<String, InFlightTxs>,
+
+    /// The model of the gas used by the messages sent to the chain, when `gas_model` is enabled.
+    /// It is behind a `RefCell` since the Txs which are not waited for are confirmed by
+    /// `query_txs`, which only borrows the chain.
+    gas_model: RefCell<GasModelState>,
 
     tx_monitor_cmd: Option<TxEventSourceCmd>,
 }
@@ -179,6 +218,36 @@ impl CosmosSdkChain {
             .map_err(Error::key_base)
     }
 
+    fn wallet_key(&self, key_name: &str) -> Result<Secp256k1KeyPair, Error> {
+        self.keybase()
+            .get_key(key_name)
+            .map_err(|e| Error::key_not_found(key_name.to_string(), e))
+    }
+
+    /// The key of the wallet to sign the given messages with, ie. the wallet whose
+    /// signer they were built with, or the key set as `key_name` by default.
+    fn signing_key(&self, tracked_msgs: &TrackedMsgs) -> Result<Secp256k1KeyPair, Error> {
+        let key_pair = self.key()?;
+
+        let Some(signer) = tracked_msgs.signer() else {
+            return Ok(key_pair);
+        };
+
+        if signer.as_ref() == key_pair.account() {
+            return Ok(key_pair);
+        }
+
+        let (key_name, key_pair) = self
+            .get_keys()?
+            .into_iter()
+            .find(|(_, key_pair)| signer.as_ref() == key_pair.account())
+            .ok_or_else(|| Error::wallet_not_found(signer.to_string()))?;
+
+        debug!(wallet = %key_name, account = %signer, "signing with wallet");
+
+        Ok(key_pair)
+    }
+
     /// Fetches the trusting period as a `Duration` from the chain config.
     /// If no trusting period exists in the config, the trusting period is calculated
     /// as two-thirds of the `unbonding_period`.
@@ -188,6 +257,18 @@ impl CosmosSdkChain {
             .unwrap_or(2 * unbonding_period / 3)
     }
 
+    /// The options with which a light client verifies the headers of this chain,
+    /// independently of the state of any IBC client tracking it.
+    pub fn light_client_options(&self) -> Result<TmLightClientOptions, Error> {
+        let unbonding_period = self.unbonding_period()?;
+
+        Ok(TmLightClientOptions {
+            trust_threshold: self.config.trust_threshold.into(),
+            trusting_period: self.trusting_period(unbonding_period),
+            clock_drift: self.config.clock_drift + self.config.max_block_time,
+        })
+    }
+
     /// Performs validation of the relayer's configuration
     /// for a specific chain against the parameters of that chain.
     ///
@@ -240,16 +321,17 @@ impl CosmosSdkChain {
         let latest_height = self.query_chain_latest_height()?;
 
         // Check on the configured max_tx_size against the consensus parameters at latest height
-        let result = self
-            .block_on(self.rpc_client.consensus_params(latest_height))
-            .map_err(|e| {
-                Error::config_validation_json_rpc(
-                    self.id().clone(),
-                    self.config.rpc_addr.to_string(),
-                    "/consensus_params".to_string(),
-                    e,
-                )
-            })?;
+        let result = self.endpoints.track(
+            self.block_on(self.rpc_client.consensus_params(latest_height))
+                .map_err(|e| {
+                    Error::config_validation_json_rpc(
+                        self.id().clone(),
+                        self.rpc_addr.to_string(),
+                        "/consensus_params".to_string(),
+                        e,
+                    )
+                }),
+        )?;
 
         let max_bound = result.consensus_params.block.max_bytes;
         let max_allowed = mul_ceil(max_bound, BLOCK_MAX_BYTES_MAX_FRACTION);
@@ -295,7 +377,12 @@ impl CosmosSdkChain {
         // Query /genesis RPC endpoint to retrieve the `max_expected_time_per_block` value
         // to use as `max_block_time`.
         // If it is not found, keep the configured `max_block_time`.
-        match self.block_on(self.rpc_client.genesis::<GenesisAppState>()) {
+        let genesis = self.endpoints.track(
+            self.block_on(self.rpc_client.genesis::<GenesisAppState>())
+                .map_err(|e| Error::rpc(self.rpc_addr.clone(), e)),
+        );
+
+        match genesis {
             Ok(genesis_reponse) => {
                 let old_max_block_time = self.config.max_block_time;
                 self.config.max_block_time =
@@ -317,6 +404,22 @@ impl CosmosSdkChain {
         Ok(())
     }
 
+    /// RPC clients for the `fallback_endpoints` of the chain.
+    fn fallback_rpc_clients(&self) -> Result<Vec<HttpClient>, Error> {
+        self.config
+            .fallback_endpoints
+            .iter()
+            .map(|endpoint| {
+                let mut rpc_client = HttpClient::new(endpoint.rpc_addr.clone())
+                    .map_err(|e| Error::rpc(endpoint.rpc_addr.clone(), e))?;
+
+                rpc_client.set_compat_mode(self.compat_mode);
+
+                Ok(rpc_client)
+            })
+            .collect()
+    }
+
     fn init_event_source(&mut self) -> Result<TxEventSourceCmd, Error> {
         crate::time!(
             "init_event_source",
@@ -331,6 +434,11 @@ impl CosmosSdkChain {
             Mode::Push { url, batch_delay } => EventSource::websocket(
                 self.config.id.clone(),
                 url.clone(),
+                self.config
+                    .fallback_endpoints
+                    .iter()
+                    .filter_map(|endpoint| endpoint.websocket_addr.clone())
+                    .collect(),
                 self.compat_mode,
                 *batch_delay,
                 self.rt.clone(),
@@ -338,7 +446,12 @@ impl CosmosSdkChain {
             Mode::Pull { interval } => EventSource::rpc(
                 self.config.id.clone(),
                 self.rpc_client.clone(),
+                self.fallback_rpc_clients()?,
                 *interval,
+                // Replay the blocks following the last processed height, if the chain is resumed
+                crate::store::global()
+                    .and_then(|store| store.resume_height(&self.config.id))
+                    .and_then(|height| TmHeight::try_from(height.revision_height()).ok()),
                 self.rt.clone(),
             ),
         }
@@ -349,6 +462,86 @@ impl CosmosSdkChain {
         Ok(monitor_tx)
     }
 
+    /// Record the health of every endpoint of the chain, as observed by the
+    /// latest round of probes, if any, and by the requests sent since the last check.
+    fn record_endpoints_health(&mut self) {
+        self.endpoints.record_requests();
+
+        let Some(outcomes) = self
+            .endpoint_probes
+            .as_ref()
+            .and_then(|probes| probes.try_iter().last())
+        else {
+            return;
+        };
+
+        for (index, outcome) in outcomes.iter().enumerate() {
+            let rpc_addr = &self.endpoints.all()[index].rpc_addr;
+
+            match outcome {
+                ProbeOutcome::Success { height, latency } => {
+                    trace!(%rpc_addr, %height, ?latency, "endpoint probed");
+                }
+                ProbeOutcome::Failure(e) => {
+                    warn!(%rpc_addr, "failed to probe endpoint: {e}");
+                }
+                ProbeOutcome::TimedOut(timeout) => {
+                    warn!(%rpc_addr, "probing endpoint timed out after {timeout:?}");
+                }
+            }
+
+            self.endpoints.health_mut(index).record_probe(outcome);
+        }
+    }
+
+    /// Point the RPC and gRPC clients, the light client and the
+    /// transaction configuration of the chain to the given endpoint.
+    ///
+    /// The event source is left untouched, as it fails over on its own.
+    fn switch_endpoint(&mut self, endpoint: &Endpoint) -> Result<(), Error> {
+        let mut rpc_client = HttpClient::new(endpoint.rpc_addr.clone())
+            .map_err(|e| Error::rpc(endpoint.rpc_addr.clone(), e))?;
+
+        rpc_client.set_compat_mode(self.compat_mode);
+
+        let grpc_addr = endpoint.grpc_uri()?;
+
+        // The configuration of the chain is left untouched, so that it keeps reporting
+        // the configured endpoint, and only the light client is built from a copy of it.
+        let mut light_client_config = self.config.clone();
+        light_client_config.rpc_addr = endpoint.rpc_addr.clone();
+
+        let node_info = self.block_on(fetch_node_info(&rpc_client, &light_client_config))?;
+        let light_client =
+            TmLightClient::from_cosmos_sdk_config(&light_client_config, node_info.id)?;
+
+        self.tx_config.rpc_address = endpoint.rpc_addr.clone();
+        self.tx_config.grpc_address = grpc_addr.clone();
+
+        self.rpc_client = rpc_client;
+        self.rpc_addr = endpoint.rpc_addr.clone();
+        self.grpc_addr = grpc_addr;
+        self.light_client = light_client;
+
+        Ok(())
+    }
+
+    /// Report which RPC and gRPC endpoints of the chain are in use.
+    fn report_active_endpoints(&self) {
+        crate::telemetry!({
+            let telemetry = ibc_telemetry::global();
+
+            for (index, endpoint) in self.endpoints.all().iter().enumerate() {
+                let active = self.endpoints.is_active(index);
+                let rpc_addr = endpoint.rpc_addr.to_string();
+                let grpc_addr = endpoint.grpc_addr.to_string();
+
+                telemetry.active_endpoint(self.id(), "rpc", &rpc_addr, active);
+                telemetry.active_endpoint(self.id(), "grpc", &grpc_addr, active);
+            }
+        });
+    }
+
     /// Performs a gRPC query to fetch CCV Consumer chain staking parameters.
     pub fn query_ccv_consumer_chain_params(&self) -> Result<CcvConsumerParams, Error> {
         crate::time!(
@@ -359,13 +552,14 @@ impl CosmosSdkChain {
         );
         crate::telemetry!(query, self.id(), "query_ccv_consumer_chain_params");
 
-        let mut client = self
-            .block_on(
+        let mut client = self.endpoints.track(
+            self.block_on(
                 ibc_proto::interchain_security::ccv::consumer::v1::query_client::QueryClient::connect(
                     self.grpc_addr.clone()
                 ),
             )
-            .map_err(Error::grpc_transport)?;
+            .map_err(Error::grpc_transport),
+        )?;
 
         client = client
             .max_decoding_message_size(self.config().max_grpc_decoding_size.get_bytes() as usize);
@@ -375,8 +569,10 @@ impl CosmosSdkChain {
         );
 
         let response = self
-            .block_on(client.query_params(request))
-            .map_err(|e| Error::grpc_status(e, "query_ccv_consumer_chain_params".to_owned()))?;
+            .endpoints
+            .track(self.block_on(client.query_params(request)).map_err(|e| {
+                Error::grpc_status(e, "query_ccv_consumer_chain_params".to_owned())
+            }))?;
 
         let params = response
             .into_inner()
@@ -396,13 +592,14 @@ impl CosmosSdkChain {
         );
         crate::telemetry!(query, self.id(), "query_staking_params");
 
-        let mut client = self
-            .block_on(
+        let mut client = self.endpoints.track(
+            self.block_on(
                 ibc_proto::cosmos::staking::v1beta1::query_client::QueryClient::connect(
                     self.grpc_addr.clone(),
                 ),
             )
-            .map_err(Error::grpc_transport)?;
+            .map_err(Error::grpc_transport),
+        )?;
 
         client = client
             .max_decoding_message_size(self.config().max_grpc_decoding_size.get_bytes() as usize);
@@ -410,9 +607,10 @@ impl CosmosSdkChain {
         let request =
             tonic::Request::new(ibc_proto::cosmos::staking::v1beta1::QueryParamsRequest {});
 
-        let response = self
-            .block_on(client.params(request))
-            .map_err(|e| Error::grpc_status(e, "query_staking_params".to_owned()))?;
+        let response = self.endpoints.track(
+            self.block_on(client.params(request))
+                .map_err(|e| Error::grpc_status(e, "query_staking_params".to_owned())),
+        )?;
 
         let params = response
             .into_inner()
@@ -451,20 +649,21 @@ impl CosmosSdkChain {
                 .contains("unknown service cosmos.base.node.v1beta1.Service")
         }
 
-        let mut client = self
-            .block_on(
+        let mut client = self.endpoints.track(
+            self.block_on(
                 ibc_proto::cosmos::base::node::v1beta1::service_client::ServiceClient::connect(
                     self.grpc_addr.clone(),
                 ),
             )
-            .map_err(Error::grpc_transport)?;
+            .map_err(Error::grpc_transport),
+        )?;
 
         client = client
             .max_decoding_message_size(self.config().max_grpc_decoding_size.get_bytes() as usize);
 
         let request = tonic::Request::new(ibc_proto::cosmos::base::node::v1beta1::ConfigRequest {});
 
-        match self.block_on(client.config(request)) {
+        let result = match self.block_on(client.config(request)) {
             Ok(response) => {
                 let params = response.into_inner();
 
@@ -477,7 +676,9 @@ impl CosmosSdkChain {
                     Err(Error::grpc_status(e, "query_config_params".to_owned()))
                 }
             }
-        }
+        };
+
+        self.endpoints.track(result)
     }
 
     /// The minimum gas price that this node accepts
@@ -503,7 +704,7 @@ impl CosmosSdkChain {
         self.rt.block_on(dynamic_gas_price(
             &gas_config,
             &self.config.id,
-            &self.config.rpc_addr,
+            &self.rpc_addr,
         ))
     }
 
@@ -576,14 +777,14 @@ impl CosmosSdkChain {
             return Err(Error::private_store());
         }
 
-        let response = self.block_on(abci_query(
+        let response = self.endpoints.track(self.block_on(abci_query(
             &self.rpc_client,
-            &self.config.rpc_addr,
+            &self.rpc_addr,
             IBC_QUERY_PATH.to_string(),
             data.to_string(),
             height_query.into(),
             prove,
-        ))?;
+        )))?;
 
         // TODO: Verify response proof, if requested.
 
@@ -614,14 +815,14 @@ impl CosmosSdkChain {
     ) -> Result<(Vec<u8>, MerkleProof), Error> {
         let path = SDK_UPGRADE_QUERY_PATH.into();
 
-        let response: QueryResponse = self.block_on(abci_query(
+        let response: QueryResponse = self.endpoints.track(self.block_on(abci_query(
             &self.rpc_client,
-            &self.config.rpc_addr,
+            &self.rpc_addr,
             path,
             Path::Upgrade(query_data).to_string(),
             query_height.into(),
             true,
-        ))?;
+        )))?;
 
         let proof = response.proof.ok_or_else(Error::empty_response_proof)?;
 
@@ -641,13 +842,14 @@ impl CosmosSdkChain {
         );
         crate::telemetry!(query, self.id(), "rpc_status");
 
-        let status = self
-            .block_on(self.rpc_client.status())
-            .map_err(|e| Error::rpc(self.config.rpc_addr.clone(), e))?;
+        let status = self.endpoints.track(
+            self.block_on(self.rpc_client.status())
+                .map_err(|e| Error::rpc(self.rpc_addr.clone(), e)),
+        )?;
 
         if status.sync_info.catching_up {
             Err(Error::chain_not_caught_up(
-                self.config.rpc_addr.to_string(),
+                self.rpc_addr.to_string(),
                 self.config().id.clone(),
             ))
         } else {
@@ -671,15 +873,19 @@ impl CosmosSdkChain {
         let grpc_addr = self.grpc_addr.clone();
         let grpc_addr_string = grpc_addr.to_string();
 
-        let mut client = self
-            .block_on(ServiceClient::connect(grpc_addr.clone()))
-            .map_err(Error::grpc_transport)?;
+        let mut client = self.endpoints.track(
+            self.block_on(ServiceClient::connect(grpc_addr.clone()))
+                .map_err(Error::grpc_transport),
+        )?;
 
         let request = tonic::Request::new(GetSyncingRequest {});
 
         let sync_info = self
-            .block_on(client.get_syncing(request))
-            .map_err(|e| Error::grpc_status(e, "get_syncing".to_string()))?
+            .endpoints
+            .track(
+                self.block_on(client.get_syncing(request))
+                    .map_err(|e| Error::grpc_status(e, "get_syncing".to_string())),
+            )?
             .into_inner();
 
         if sync_info.syncing {
@@ -708,7 +914,7 @@ impl CosmosSdkChain {
 
         if rpc_status.sync_info.catching_up {
             return Err(Error::chain_not_caught_up(
-                self.config.rpc_addr.to_string(),
+                self.rpc_addr.to_string(),
                 self.config().id.clone(),
             ));
         }
@@ -717,7 +923,7 @@ impl CosmosSdkChain {
 
         if grpc_status.syncing {
             return Err(Error::chain_not_caught_up(
-                self.config.grpc_addr.to_string(),
+                self.grpc_addr.to_string(),
                 self.config().id.clone(),
             ));
         }
@@ -735,11 +941,11 @@ impl CosmosSdkChain {
         );
         crate::telemetry!(query, self.id(), "query_latest_height");
 
-        let status = self.rt.block_on(query_status(
+        let status = self.endpoints.track(self.rt.block_on(query_status(
             self.id(),
             &self.rpc_client,
-            &self.config.rpc_addr,
-        ))?;
+            &self.rpc_addr,
+        )))?;
 
         Ok(status.height)
     }
@@ -764,20 +970,36 @@ impl CosmosSdkChain {
             }
         );
 
+        let key_pair = self.signing_key(&tracked_msgs)?;
         let proto_msgs = tracked_msgs.msgs;
-
-        let key_pair = self.key()?;
         let key_account = key_pair.account();
 
-        let account =
-            get_or_fetch_account(&self.grpc_addr, &key_account, &mut self.account).await?;
+        let account = get_or_fetch_account(
+            &self.grpc_addr,
+            &key_account,
+            self.accounts.entry(key_account.clone()).or_default(),
+        )
+        .await?;
 
-        if self.config.sequential_batch_tx {
+        if self.config.tx_pipeline.enabled {
+            pipelined_send_batched_messages_and_wait_commit(
+                &self.rpc_client,
+                &self.tx_config,
+                &key_pair,
+                account,
+                self.in_flight_txs.entry(key_account.clone()).or_default(),
+                self.gas_model.get_mut(),
+                &self.config.memo_prefix,
+                proto_msgs,
+            )
+            .await
+        } else if self.config.sequential_batch_tx {
             sequential_send_batched_messages_and_wait_commit(
                 &self.rpc_client,
                 &self.tx_config,
                 &key_pair,
                 account,
+                self.gas_model.get_mut(),
                 &self.config.memo_prefix,
                 proto_msgs,
             )
@@ -788,6 +1010,7 @@ impl CosmosSdkChain {
                 &self.tx_config,
                 &key_pair,
                 account,
+                self.gas_model.get_mut(),
                 &self.config.memo_prefix,
                 proto_msgs,
             )
@@ -815,23 +1038,41 @@ impl CosmosSdkChain {
             }
         );
 
+        let key_pair = self.signing_key(&tracked_msgs)?;
         let proto_msgs = tracked_msgs.msgs;
-
-        let key_pair = self.key()?;
         let key_account = key_pair.account();
 
-        let account =
-            get_or_fetch_account(&self.grpc_addr, &key_account, &mut self.account).await?;
-
-        send_batched_messages_and_wait_check_tx(
-            &self.rpc_client,
-            &self.tx_config,
-            &key_pair,
-            account,
-            &self.config.memo_prefix,
-            proto_msgs,
+        let account = get_or_fetch_account(
+            &self.grpc_addr,
+            &key_account,
+            self.accounts.entry(key_account.clone()).or_default(),
         )
-        .await
+        .await?;
+
+        if self.config.tx_pipeline.enabled {
+            pipelined_send_batched_messages_and_wait_check_tx(
+                &self.rpc_client,
+                &self.tx_config,
+                &key_pair,
+                account,
+                self.in_flight_txs.entry(key_account.clone()).or_default(),
+                self.gas_model.get_mut(),
+                &self.config.memo_prefix,
+                proto_msgs,
+            )
+            .await
+        } else {
+            send_batched_messages_and_wait_check_tx(
+                &self.rpc_client,
+                &self.tx_config,
+                &key_pair,
+                account,
+                self.gas_model.get_mut(),
+                &self.config.memo_prefix,
+                proto_msgs,
+            )
+            .await
+        }
     }
 
     fn query_packet_from_block(
@@ -851,9 +1092,10 @@ impl CosmosSdkChain {
         let tm_height =
             tendermint::block::Height::try_from(block_height.revision_height()).unwrap();
 
-        let response = self
-            .block_on(self.rpc_client.block_results(tm_height))
-            .map_err(|e| Error::rpc(self.config.rpc_addr.clone(), e))?;
+        let response = self.endpoints.track(
+            self.block_on(self.rpc_client.block_results(tm_height))
+                .map_err(|e| Error::rpc(self.rpc_addr.clone(), e)),
+        )?;
 
         let response_height = ICSHeight::new(self.id().version(), u64::from(response.height))
             .map_err(|_| Error::invalid_height_no_source())?;
@@ -905,8 +1147,8 @@ impl CosmosSdkChain {
         let mut end_block_events = vec![];
 
         for seq in request.sequences.iter().copied() {
-            let response = self
-                .block_on(self.rpc_client.block_search(
+            let response = self.endpoints.track(
+                self.block_on(self.rpc_client.block_search(
                     packet_query(request, seq),
                     // We only need the first page
                     1,
@@ -924,7 +1166,8 @@ impl CosmosSdkChain {
                     // blocks first.
                     Order::Descending,
                 ))
-                .map_err(|e| Error::rpc(self.config.rpc_addr.clone(), e))?;
+                .map_err(|e| Error::rpc(self.rpc_addr.clone(), e)),
+            )?;
 
             for block in response.blocks.into_iter().map(|response| response.block) {
                 let response_height =
@@ -964,9 +1207,7 @@ impl ChainEndpoint for CosmosSdkChain {
     }
 
     fn bootstrap(config: ChainConfig, rt: Arc<TokioRuntime>) -> Result<Self, Error> {
-        #[allow(irrefutable_let_patterns)]
-        let ChainConfig::CosmosSdk(config) = config
-        else {
+        let ChainConfig::CosmosSdk(config) = config else {
             return Err(Error::config(ConfigError::wrong_type()));
         };
 
@@ -986,6 +1227,8 @@ impl ChainEndpoint for CosmosSdkChain {
             &config.account_prefix,
             &config.id,
             &config.key_store_folder,
+            &config.key_store_passphrase,
+            &config.remote_signer,
         )
         .map_err(Error::key_base)?;
 
@@ -994,18 +1237,40 @@ impl ChainEndpoint for CosmosSdkChain {
 
         let tx_config = TxConfig::try_from(&config)?;
 
+        let wallets = Wallets::new(config.key_names(), config.wallet_assignment);
+
+        let endpoints = Endpoints::new(&config);
+
+        let endpoint_probes = endpoints.has_fallbacks().then(|| {
+            endpoints::spawn_probes(
+                &rt,
+                config.id.clone(),
+                endpoints.all().to_vec(),
+                compat_mode,
+                config.rpc_timeout,
+            )
+        });
+
         // Retrieve the version specification of this chain
 
+        let rpc_addr = config.rpc_addr.clone();
+
         let chain = Self {
             config,
             rpc_client,
             compat_mode,
+            rpc_addr,
             grpc_addr,
             light_client,
             rt,
             keybase,
+            wallets,
+            endpoints,
+            endpoint_probes,
             tx_config,
-            account: None,
+            accounts: BTreeMap::new(),
+            in_flight_txs: BTreeMap::new(),
+            gas_model: RefCell::default(),
             tx_monitor_cmd: None,
         };
 
@@ -1038,6 +1303,27 @@ impl ChainEndpoint for CosmosSdkChain {
         Ok(key_pair)
     }
 
+    fn get_keys(&self) -> Result<Vec<(String, Self::SigningKeyPair)>, Error> {
+        self.wallets
+            .key_names()
+            .iter()
+            .map(|key_name| Ok((key_name.clone(), self.wallet_key(key_name)?)))
+            .collect()
+    }
+
+    fn select_signer(
+        &mut self,
+        channel: &(PortId, ChannelId),
+        ordering: ChannelOrdering,
+    ) -> Result<Signer, Error> {
+        let key_name = self.wallets.select(Some(channel), ordering).to_string();
+        let key_pair = self.wallet_key(&key_name)?;
+
+        debug!(wallet = %key_name, account = %key_pair.account(), "selected wallet");
+
+        key_pair_to_signer(&key_pair)
+    }
+
     fn subscribe(&mut self) -> Result<Subscription, Error> {
         let tx_monitor_cmd = match &self.tx_monitor_cmd {
             Some(tx_monitor_cmd) => tx_monitor_cmd,
@@ -1083,6 +1369,34 @@ impl ChainEndpoint for CosmosSdkChain {
         Ok(HealthCheck::Healthy)
     }
 
+    /// Checks the health of each of the `rpc_addr`/`grpc_addr` and `fallback_endpoints`
+    /// of the chain, as probed in the background and observed by the requests sent to them,
+    /// and fails over to the healthiest of them if the one in use is unhealthy,
+    /// ie. unreachable, lagging behind or failing too often.
+    fn check_endpoints(&mut self) -> Result<(), Error> {
+        if self.endpoints.has_fallbacks() {
+            self.record_endpoints_health();
+
+            if let Some(index) = self.endpoints.select() {
+                let endpoint = self.endpoints.all()[index].clone();
+
+                warn!(
+                    "failing over from endpoint {} to endpoint {} for chain '{}'",
+                    self.rpc_addr,
+                    endpoint.rpc_addr,
+                    self.id()
+                );
+
+                self.switch_endpoint(&endpoint)?;
+                self.endpoints.set_active(index);
+            }
+        }
+
+        self.report_active_endpoints();
+
+        Ok(())
+    }
+
     /// Fetch a header from the chain at the given height and verify it.
     fn verify_header(
         &mut self,
@@ -1139,7 +1453,9 @@ impl ChainEndpoint for CosmosSdkChain {
     ) -> Result<Vec<IbcEventWithHeight>, Error> {
         let runtime = self.rt.clone();
 
-        runtime.block_on(self.do_send_messages_and_wait_commit(tracked_msgs))
+        let result = runtime.block_on(self.do_send_messages_and_wait_commit(tracked_msgs));
+
+        self.endpoints.track(result)
     }
 
     fn send_messages_and_wait_check_tx(
@@ -1148,7 +1464,9 @@ impl ChainEndpoint for CosmosSdkChain {
     ) -> Result<Vec<Response>, Error> {
         let runtime = self.rt.clone();
 
-        runtime.block_on(self.do_send_messages_and_wait_check_tx(tracked_msgs))
+        let result = runtime.block_on(self.do_send_messages_and_wait_check_tx(tracked_msgs));
+
+        self.endpoints.track(result)
     }
 
     /// Get the account for the signer
@@ -1166,8 +1484,35 @@ impl ChainEndpoint for CosmosSdkChain {
         ChainConfig::CosmosSdk(self.config.clone())
     }
 
+    fn update_gas_settings(&mut self, config: ChainConfig) -> Result<(), Error> {
+        let ChainConfig::CosmosSdk(config) = config else {
+            return Err(Error::config(ConfigError::wrong_type()));
+        };
+
+        self.config.set_gas_settings(&config);
+        self.tx_config.gas_config = GasConfig::from(&self.config);
+
+        Ok(())
+    }
+
+    fn estimate_tx_fee(&self, msg_type: &str) -> Result<Option<RawCoin>, Error> {
+        let fee = self.rt.block_on(estimate_tx_fee(
+            &self.tx_config,
+            &self.gas_model.borrow(),
+            msg_type,
+        ));
+
+        Ok(fee
+            .amount
+            .into_iter()
+            .next()
+            .and_then(|coin| RawCoin::try_from(coin).ok()))
+    }
+
     fn version_specs(&self) -> Result<Specs, Error> {
-        let version_specs = self.block_on(fetch_version_specs(self.id(), &self.grpc_addr))?;
+        let version_specs = self
+            .endpoints
+            .track(self.block_on(fetch_version_specs(self.id(), &self.grpc_addr)))?;
         Ok(version_specs)
     }
 
@@ -1181,7 +1526,9 @@ impl ChainEndpoint for CosmosSdkChain {
         let account = key.account();
 
         let denom = denom.unwrap_or(&self.config.gas_price.denom);
-        let balance = self.block_on(query_balance(&self.grpc_addr, &account, denom))?;
+        let balance =
+            self.endpoints
+                .track(self.block_on(query_balance(&self.grpc_addr, &account, denom)))?;
 
         Ok(balance)
     }
@@ -1195,13 +1542,17 @@ impl ChainEndpoint for CosmosSdkChain {
         };
         let account = key.account();
 
-        let balance = self.block_on(query_all_balances(&self.grpc_addr, &account))?;
+        let balance = self
+            .endpoints
+            .track(self.block_on(query_all_balances(&self.grpc_addr, &account)))?;
 
         Ok(balance)
     }
 
     fn query_denom_trace(&self, hash: String) -> Result<DenomTrace, Error> {
-        let denom_trace = self.block_on(query_denom_trace(&self.grpc_addr, &hash))?;
+        let denom_trace = self
+            .endpoints
+            .track(self.block_on(query_denom_trace(&self.grpc_addr, &hash)))?;
 
         Ok(denom_trace)
     }
@@ -1233,14 +1584,16 @@ impl ChainEndpoint for CosmosSdkChain {
         // We cannot rely on `/status` endpoint to provide details about the latest block.
         // Instead, we need to pull block height via `/abci_info` and then fetch block
         // metadata at the given height via `/blockchain` endpoint.
-        let abci_info = self
-            .block_on(self.rpc_client.abci_info())
-            .map_err(|e| Error::rpc(self.config.rpc_addr.clone(), e))?;
+        let abci_info = self.endpoints.track(
+            self.block_on(self.rpc_client.abci_info())
+                .map_err(|e| Error::rpc(self.rpc_addr.clone(), e)),
+        )?;
 
         // Query `/header` endpoint to pull the latest block that the application committed.
-        let response = self
-            .block_on(self.rpc_client.header(abci_info.last_block_height))
-            .map_err(|e| Error::rpc(self.config.rpc_addr.clone(), e))?;
+        let response = self.endpoints.track(
+            self.block_on(self.rpc_client.header(abci_info.last_block_height))
+                .map_err(|e| Error::rpc(self.rpc_addr.clone(), e)),
+        )?;
 
         let height = ICSHeight::new(
             ChainId::chain_version(response.header.chain_id.as_str()),
@@ -1266,21 +1619,25 @@ impl ChainEndpoint for CosmosSdkChain {
         );
         crate::telemetry!(query, self.id(), "query_clients");
 
-        let mut client = self
-            .block_on(
+        let mut client = self.endpoints.track(
+            self.block_on(
                 ibc_proto::ibc::core::client::v1::query_client::QueryClient::connect(
                     self.grpc_addr.clone(),
                 ),
             )
-            .map_err(Error::grpc_transport)?;
+            .map_err(Error::grpc_transport),
+        )?;
 
         client = client
             .max_decoding_message_size(self.config().max_grpc_decoding_size.get_bytes() as usize);
 
         let request = tonic::Request::new(request.into());
         let response = self
-            .block_on(client.client_states(request))
-            .map_err(|e| Error::grpc_status(e, "query_clients".to_owned()))?
+            .endpoints
+            .track(
+                self.block_on(client.client_states(request))
+                    .map_err(|e| Error::grpc_status(e, "query_clients".to_owned())),
+            )?
             .into_inner();
 
         // Deserialize into domain type
@@ -1395,11 +1752,12 @@ impl ChainEndpoint for CosmosSdkChain {
         &self,
         request: QueryConsensusStateHeightsRequest,
     ) -> Result<Vec<ICSHeight>, Error> {
-        self.block_on(query_consensus_state_heights(
-            self.id(),
-            &self.grpc_addr,
-            request,
-        ))
+        self.endpoints
+            .track(self.block_on(query_consensus_state_heights(
+                self.id(),
+                &self.grpc_addr,
+                request,
+            )))
     }
 
     fn query_consensus_state(
@@ -1427,7 +1785,10 @@ impl ChainEndpoint for CosmosSdkChain {
 
         let consensus_state = AnyConsensusState::decode_vec(&res.value).map_err(Error::decode)?;
 
-        if !matches!(consensus_state, AnyConsensusState::Tendermint(_)) {
+        if !matches!(
+            consensus_state,
+            AnyConsensusState::Tendermint(_) | AnyConsensusState::Wasm(_)
+        ) {
             return Err(Error::consensus_state_type_mismatch(
                 ClientType::Tendermint,
                 consensus_state.client_type(),
@@ -1457,25 +1818,28 @@ impl ChainEndpoint for CosmosSdkChain {
         );
         crate::telemetry!(query, self.id(), "query_client_connections");
 
-        let mut client = self
-            .block_on(
+        let mut client = self.endpoints.track(
+            self.block_on(
                 ibc_proto::ibc::core::connection::v1::query_client::QueryClient::connect(
                     self.grpc_addr.clone(),
                 ),
             )
-            .map_err(Error::grpc_transport)?;
+            .map_err(Error::grpc_transport),
+        )?;
 
         client = client
             .max_decoding_message_size(self.config().max_grpc_decoding_size.get_bytes() as usize);
 
         let request = tonic::Request::new(request.into());
 
-        let response = match self.block_on(client.client_connections(request)) {
-            Ok(res) => res.into_inner(),
+        let result = match self.block_on(client.client_connections(request)) {
+            Ok(res) => Ok(res.into_inner()),
             Err(e) if e.code() == tonic::Code::NotFound => return Ok(vec![]),
-            Err(e) => return Err(Error::grpc_status(e, "query_client_connections".to_owned())),
+            Err(e) => Err(Error::grpc_status(e, "query_client_connections".to_owned())),
         };
 
+        let response = self.endpoints.track(result)?;
+
         let ids = response
             .connection_paths
             .iter()
@@ -1503,13 +1867,14 @@ impl ChainEndpoint for CosmosSdkChain {
         );
         crate::telemetry!(query, self.id(), "query_connections");
 
-        let mut client = self
-            .block_on(
+        let mut client = self.endpoints.track(
+            self.block_on(
                 ibc_proto::ibc::core::connection::v1::query_client::QueryClient::connect(
                     self.grpc_addr.clone(),
                 ),
             )
-            .map_err(Error::grpc_transport)?;
+            .map_err(Error::grpc_transport),
+        )?;
 
         client = client
             .max_decoding_message_size(self.config().max_grpc_decoding_size.get_bytes() as usize);
@@ -1517,8 +1882,11 @@ impl ChainEndpoint for CosmosSdkChain {
         let request = tonic::Request::new(request.into());
 
         let response = self
-            .block_on(client.connections(request))
-            .map_err(|e| Error::grpc_status(e, "query_connections".to_owned()))?
+            .endpoints
+            .track(
+                self.block_on(client.connections(request))
+                    .map_err(|e| Error::grpc_status(e, "query_connections".to_owned())),
+            )?
             .into_inner();
 
         let connections = response
@@ -1621,9 +1989,10 @@ impl ChainEndpoint for CosmosSdkChain {
                 ))
             }
             IncludeProof::No => self
-                .block_on(async {
+                .endpoints
+                .track(self.block_on(async {
                     do_query_connection(self, &request.connection_id, request.height).await
-                })
+                }))
                 .map(|conn_end| (conn_end, None)),
         }
     }
@@ -1642,13 +2011,14 @@ impl ChainEndpoint for CosmosSdkChain {
         );
         crate::telemetry!(query, self.id(), "query_connection_channels");
 
-        let mut client = self
-            .block_on(
+        let mut client = self.endpoints.track(
+            self.block_on(
                 ibc_proto::ibc::core::channel::v1::query_client::QueryClient::connect(
                     self.grpc_addr.clone(),
                 ),
             )
-            .map_err(Error::grpc_transport)?;
+            .map_err(Error::grpc_transport),
+        )?;
 
         client = client
             .max_decoding_message_size(self.config().max_grpc_decoding_size.get_bytes() as usize);
@@ -1656,8 +2026,11 @@ impl ChainEndpoint for CosmosSdkChain {
         let request = tonic::Request::new(request.into());
 
         let response = self
-            .block_on(client.connection_channels(request))
-            .map_err(|e| Error::grpc_status(e, "query_connection_channels".to_owned()))?
+            .endpoints
+            .track(
+                self.block_on(client.connection_channels(request))
+                    .map_err(|e| Error::grpc_status(e, "query_connection_channels".to_owned())),
+            )?
             .into_inner();
 
         let channels = response
@@ -1692,13 +2065,14 @@ impl ChainEndpoint for CosmosSdkChain {
         );
         crate::telemetry!(query, self.id(), "query_channels");
 
-        let mut client = self
-            .block_on(
+        let mut client = self.endpoints.track(
+            self.block_on(
                 ibc_proto::ibc::core::channel::v1::query_client::QueryClient::connect(
                     self.grpc_addr.clone(),
                 ),
             )
-            .map_err(Error::grpc_transport)?;
+            .map_err(Error::grpc_transport),
+        )?;
 
         client = client
             .max_decoding_message_size(self.config().max_grpc_decoding_size.get_bytes() as usize);
@@ -1706,8 +2080,11 @@ impl ChainEndpoint for CosmosSdkChain {
         let request = tonic::Request::new(request.into());
 
         let response = self
-            .block_on(client.channels(request))
-            .map_err(|e| Error::grpc_status(e, "query_channels".to_owned()))?
+            .endpoints
+            .track(
+                self.block_on(client.channels(request))
+                    .map_err(|e| Error::grpc_status(e, "query_channels".to_owned())),
+            )?
             .into_inner();
 
         let channels = response
@@ -1759,6 +2136,39 @@ impl ChainEndpoint for CosmosSdkChain {
         }
     }
 
+    fn query_upgrade(
+        &self,
+        request: QueryUpgradeRequest,
+        include_proof: IncludeProof,
+    ) -> Result<(Upgrade, Option<MerkleProof>), Error> {
+        crate::time!(
+            "query_upgrade",
+            {
+                "src_chain": self.config().id.to_string(),
+            }
+        );
+        crate::telemetry!(query, self.id(), "query_upgrade");
+
+        let res = self.query(
+            ChannelUpgradePath {
+                port_id: request.port_id,
+                channel_id: request.channel_id,
+            },
+            request.height,
+            matches!(include_proof, IncludeProof::Yes),
+        )?;
+
+        let upgrade = Upgrade::decode_vec(&res.value).map_err(Error::decode)?;
+
+        match include_proof {
+            IncludeProof::Yes => {
+                let proof = res.proof.ok_or_else(Error::empty_response_proof)?;
+                Ok((upgrade, Some(proof)))
+            }
+            IncludeProof::No => Ok((upgrade, None)),
+        }
+    }
+
     /// Performs a `QueryChannelClientStateRequest` gRPC query in order to fetch the client state
     /// associated with a given channel, if it exists.
     fn query_channel_client_state(
@@ -1773,13 +2183,14 @@ impl ChainEndpoint for CosmosSdkChain {
         );
         crate::telemetry!(query, self.id(), "query_channel_client_state");
 
-        let mut client = self
-            .block_on(
+        let mut client = self.endpoints.track(
+            self.block_on(
                 ibc_proto::ibc::core::channel::v1::query_client::QueryClient::connect(
                     self.grpc_addr.clone(),
                 ),
             )
-            .map_err(Error::grpc_transport)?;
+            .map_err(Error::grpc_transport),
+        )?;
 
         client = client
             .max_decoding_message_size(self.config().max_grpc_decoding_size.get_bytes() as usize);
@@ -1787,8 +2198,11 @@ impl ChainEndpoint for CosmosSdkChain {
         let request = tonic::Request::new(request.into());
 
         let response = self
-            .block_on(client.channel_client_state(request))
-            .map_err(|e| Error::grpc_status(e, "query_channel_client_state".to_owned()))?
+            .endpoints
+            .track(
+                self.block_on(client.channel_client_state(request))
+                    .map_err(|e| Error::grpc_status(e, "query_channel_client_state".to_owned())),
+            )?
             .into_inner();
 
         let client_state: Option<IdentifiedAnyClientState> = response
@@ -1837,13 +2251,14 @@ impl ChainEndpoint for CosmosSdkChain {
         );
         crate::telemetry!(query, self.id(), "query_packet_commitments");
 
-        let mut client = self
-            .block_on(
+        let mut client = self.endpoints.track(
+            self.block_on(
                 ibc_proto::ibc::core::channel::v1::query_client::QueryClient::connect(
                     self.grpc_addr.clone(),
                 ),
             )
-            .map_err(Error::grpc_transport)?;
+            .map_err(Error::grpc_transport),
+        )?;
 
         client = client
             .max_decoding_message_size(self.config().max_grpc_decoding_size.get_bytes() as usize);
@@ -1851,8 +2266,11 @@ impl ChainEndpoint for CosmosSdkChain {
         let request = tonic::Request::new(request.into());
 
         let response = self
-            .block_on(client.packet_commitments(request))
-            .map_err(|e| Error::grpc_status(e, "query_packet_commitments".to_owned()))?
+            .endpoints
+            .track(
+                self.block_on(client.packet_commitments(request))
+                    .map_err(|e| Error::grpc_status(e, "query_packet_commitments".to_owned())),
+            )?
             .into_inner();
 
         let mut commitment_sequences: Vec<Sequence> = response
@@ -1909,13 +2327,14 @@ impl ChainEndpoint for CosmosSdkChain {
         );
         crate::telemetry!(query, self.id(), "query_unreceived_packets");
 
-        let mut client = self
-            .block_on(
+        let mut client = self.endpoints.track(
+            self.block_on(
                 ibc_proto::ibc::core::channel::v1::query_client::QueryClient::connect(
                     self.grpc_addr.clone(),
                 ),
             )
-            .map_err(Error::grpc_transport)?;
+            .map_err(Error::grpc_transport),
+        )?;
 
         client = client
             .max_decoding_message_size(self.config().max_grpc_decoding_size.get_bytes() as usize);
@@ -1923,8 +2342,11 @@ impl ChainEndpoint for CosmosSdkChain {
         let request = tonic::Request::new(request.into());
 
         let mut response = self
-            .block_on(client.unreceived_packets(request))
-            .map_err(|e| Error::grpc_status(e, "query_unreceived_packets".to_owned()))?
+            .endpoints
+            .track(
+                self.block_on(client.unreceived_packets(request))
+                    .map_err(|e| Error::grpc_status(e, "query_unreceived_packets".to_owned())),
+            )?
             .into_inner();
 
         response.sequences.sort_unstable();
@@ -1978,13 +2400,14 @@ impl ChainEndpoint for CosmosSdkChain {
             return Ok((Vec::new(), self.query_chain_latest_height()?));
         }
 
-        let mut client = self
-            .block_on(
+        let mut client = self.endpoints.track(
+            self.block_on(
                 ibc_proto::ibc::core::channel::v1::query_client::QueryClient::connect(
                     self.grpc_addr.clone(),
                 ),
             )
-            .map_err(Error::grpc_transport)?;
+            .map_err(Error::grpc_transport),
+        )?;
 
         client = client
             .max_decoding_message_size(self.config().max_grpc_decoding_size.get_bytes() as usize);
@@ -1992,8 +2415,11 @@ impl ChainEndpoint for CosmosSdkChain {
         let request = tonic::Request::new(request.into());
 
         let response = self
-            .block_on(client.packet_acknowledgements(request))
-            .map_err(|e| Error::grpc_status(e, "query_packet_acknowledgements".to_owned()))?
+            .endpoints
+            .track(
+                self.block_on(client.packet_acknowledgements(request))
+                    .map_err(|e| Error::grpc_status(e, "query_packet_acknowledgements".to_owned())),
+            )?
             .into_inner();
 
         let acks_sequences = response
@@ -2024,13 +2450,14 @@ impl ChainEndpoint for CosmosSdkChain {
         );
         crate::telemetry!(query, self.id(), "query_unreceived_acknowledgements");
 
-        let mut client = self
-            .block_on(
+        let mut client = self.endpoints.track(
+            self.block_on(
                 ibc_proto::ibc::core::channel::v1::query_client::QueryClient::connect(
                     self.grpc_addr.clone(),
                 ),
             )
-            .map_err(Error::grpc_transport)?;
+            .map_err(Error::grpc_transport),
+        )?;
 
         client = client
             .max_decoding_message_size(self.config().max_grpc_decoding_size.get_bytes() as usize);
@@ -2038,8 +2465,10 @@ impl ChainEndpoint for CosmosSdkChain {
         let request = tonic::Request::new(request.into());
 
         let mut response = self
-            .block_on(client.unreceived_acks(request))
-            .map_err(|e| Error::grpc_status(e, "query_unreceived_acknowledgements".to_owned()))?
+            .endpoints
+            .track(self.block_on(client.unreceived_acks(request)).map_err(|e| {
+                Error::grpc_status(e, "query_unreceived_acknowledgements".to_owned())
+            }))?
             .into_inner();
 
         response.sequences.sort_unstable();
@@ -2103,12 +2532,32 @@ impl ChainEndpoint for CosmosSdkChain {
         });
         crate::telemetry!(query, self.id(), "query_txs");
 
-        self.block_on(query_txs(
+        // This is how the Txs sent without waiting for their commit are confirmed,
+        // so learn from the gas used by the ones whose estimated gas was recorded.
+        if let QueryTxRequest::Transaction(tx) = &request {
+            let response = self.endpoints.track(self.block_on(query_tx_response(
+                &self.rpc_client,
+                &self.rpc_addr,
+                &tx.0,
+            )))?;
+
+            let Some(response) = response else {
+                return Ok(vec![]);
+            };
+
+            self.gas_model
+                .borrow_mut()
+                .record_tx_result(self.id(), &response);
+
+            return Ok(all_ibc_events_from_tx_search_response(self.id(), response));
+        }
+
+        self.endpoints.track(self.block_on(query_txs(
             self.id(),
             &self.rpc_client,
-            &self.config.rpc_addr,
+            &self.rpc_addr,
             request,
-        ))
+        )))
     }
 
     /// This function queries transactions for packet events matching certain criteria.
@@ -2136,19 +2585,19 @@ impl ChainEndpoint for CosmosSdkChain {
             // Usage note: `Qualified::Equal` is currently only used in the call hierarchy involving
             // the CLI methods, namely the CLI for `tx packet-recv` and `tx packet-ack` when the
             // user passes the flag `packet-data-query-height`.
-            Qualified::Equal(_) => self.block_on(query_packets_from_block(
+            Qualified::Equal(_) => self.endpoints.track(self.block_on(query_packets_from_block(
                 self.id(),
                 &self.rpc_client,
-                &self.config.rpc_addr,
+                &self.rpc_addr,
                 &request,
-            )),
+            ))),
             Qualified::SmallerEqual(_) => {
-                let tx_events = self.block_on(query_packets_from_txs(
+                let tx_events = self.endpoints.track(self.block_on(query_packets_from_txs(
                     self.id(),
                     &self.rpc_client,
-                    &self.config.rpc_addr,
+                    &self.rpc_addr,
                     &request,
-                ))?;
+                )))?;
 
                 let recvd_sequences: Vec<_> = tx_events
                     .iter()
@@ -2217,7 +2666,9 @@ impl ChainEndpoint for CosmosSdkChain {
             })
         };
 
-        let header = header.map_err(|e| Error::rpc(self.config.rpc_addr.clone(), e))?;
+        let header = self
+            .endpoints
+            .track(header.map_err(|e| Error::rpc(self.rpc_addr.clone(), e)))?;
         Ok(header.into())
     }
 
@@ -2226,7 +2677,12 @@ impl ChainEndpoint for CosmosSdkChain {
         height: ICSHeight,
         settings: ClientSettings,
     ) -> Result<Self::ClientState, Error> {
-        let ClientSettings::Tendermint(settings) = settings;
+        let ClientSettings::Tendermint(settings) = settings else {
+            return Err(Error::client_type_mismatch(
+                ClientType::Tendermint,
+                ClientType::Mock,
+            ));
+        };
         let unbonding_period = self.unbonding_period()?;
         let trusting_period = settings
             .trusting_period
@@ -2298,20 +2754,27 @@ impl ChainEndpoint for CosmosSdkChain {
         port_id: &PortId,
         counterparty_payee: &Signer,
     ) -> Result<(), Error> {
-        let address = self.get_signer()?;
-        let key_pair = self.key()?;
+        // Every wallet may relay packets on the channel, and thus needs a counterparty payee
+        for key_name in self.wallets.key_names() {
+            let key_pair = self.wallet_key(key_name)?;
+            let address = key_pair_to_signer(&key_pair)?;
 
-        self.rt.block_on(maybe_register_counterparty_payee(
-            &self.rpc_client,
-            &self.tx_config,
-            &key_pair,
-            &mut self.account,
-            &self.config.memo_prefix,
-            channel_id,
-            port_id,
-            &address,
-            counterparty_payee,
-        ))
+            self.endpoints
+                .track(self.rt.block_on(maybe_register_counterparty_payee(
+                    &self.rpc_client,
+                    &self.tx_config,
+                    &key_pair,
+                    self.accounts.entry(key_pair.account()).or_default(),
+                    self.gas_model.get_mut(),
+                    &self.config.memo_prefix,
+                    channel_id,
+                    port_id,
+                    &address,
+                    counterparty_payee,
+                )))?;
+        }
+
+        Ok(())
     }
 
     fn cross_chain_query(
@@ -2327,7 +2790,7 @@ impl ChainEndpoint for CosmosSdkChain {
         let results: Vec<Result<CrossChainQueryResponse, _>> = self.rt.block_on(joined_tasks);
         let responses = results
             .into_iter()
-            .filter_map(|req| req.ok())
+            .filter_map(|req| self.endpoints.track(req).ok())
             .collect::<Vec<CrossChainQueryResponse>>();
 
         Ok(responses)
@@ -2337,8 +2800,9 @@ impl ChainEndpoint for CosmosSdkChain {
         &self,
         request: QueryIncentivizedPacketRequest,
     ) -> Result<QueryIncentivizedPacketResponse, Error> {
-        let incentivized_response =
-            self.block_on(query_incentivized_packet(&self.grpc_addr, request))?;
+        let incentivized_response = self
+            .endpoints
+            .track(self.block_on(query_incentivized_packet(&self.grpc_addr, request)))?;
         Ok(incentivized_response)
     }
 
@@ -2351,20 +2815,25 @@ impl ChainEndpoint for CosmosSdkChain {
         );
         crate::telemetry!(query, self.id(), "query_consumer_chains");
 
-        let mut client = self.block_on(
-            ibc_proto::interchain_security::ccv::provider::v1::query_client::QueryClient::connect(
-                self.grpc_addr.clone(),
-            ),
-        )
-        .map_err(Error::grpc_transport)?;
+        let mut client = self.endpoints.track(
+            self.block_on(
+                ibc_proto::interchain_security::ccv::provider::v1::query_client::QueryClient::connect(
+                    self.grpc_addr.clone(),
+                ),
+            )
+            .map_err(Error::grpc_transport),
+        )?;
 
         let request = tonic::Request::new(
             ibc_proto::interchain_security::ccv::provider::v1::QueryConsumerChainsRequest {},
         );
 
         let response = self
-            .block_on(client.query_consumer_chains(request))
-            .map_err(|e| Error::grpc_status(e, "query_consumer_chains".to_owned()))?
+            .endpoints
+            .track(
+                self.block_on(client.query_consumer_chains(request))
+                    .map_err(|e| Error::grpc_status(e, "query_consumer_chains".to_owned())),
+            )?
             .into_inner();
 
         let result = response
@@ -2375,6 +2844,44 @@ impl ChainEndpoint for CosmosSdkChain {
 
         Ok(result)
     }
+
+    fn query_grants(&self, request: QueryGrantsRequest) -> Result<Grants, Error> {
+        crate::time!(
+            "query_grants",
+            {
+                "src_chain": self.config().id.to_string(),
+            }
+        );
+        crate::telemetry!(query, self.id(), "query_grants");
+
+        let fee_allowance = self.endpoints.track(self.block_on(query_fee_allowance(
+            &self.grpc_addr,
+            &request.granter,
+            &request.grantee,
+        )))?;
+
+        let authorization = self.endpoints.track(self.block_on(query_authorization(
+            &self.grpc_addr,
+            &request.granter,
+            &request.grantee,
+            MSG_GRANT_ALLOWANCE_TYPE_URL,
+        )))?;
+
+        Ok(Grants {
+            fee_allowance,
+            authorization,
+        })
+    }
+
+    fn query_dropped_txs(&self, tx_hashes: &[TxHash]) -> Result<Vec<TxHash>, Error> {
+        let dropped = self
+            .in_flight_txs
+            .values()
+            .flat_map(|in_flight| in_flight.dropped(tx_hashes))
+            .collect();
+
+        Ok(dropped)
+    }
 }
 
 fn sort_events_by_sequence(events: &mut [IbcEventWithHeight]) {
@@ -2429,7 +2936,7 @@ fn client_id_suffix(client_id: &ClientId) -> Option<u64> {
 fn do_health_check(chain: &CosmosSdkChain) -> Result<(), Error> {
     let chain_id = chain.id();
     let grpc_address = chain.grpc_addr.to_string();
-    let rpc_address = chain.config.rpc_addr.to_string();
+    let rpc_address = chain.rpc_addr.to_string();
 
     if !chain.config.excluded_sequences.is_empty() {
         for (channel_id, seqs) in chain.config.excluded_sequences.iter() {
@@ -2442,14 +2949,16 @@ fn do_health_check(chain: &CosmosSdkChain) -> Result<(), Error> {
         }
     }
 
-    chain.block_on(chain.rpc_client.health()).map_err(|e| {
-        Error::health_check_json_rpc(
-            chain_id.clone(),
-            rpc_address.clone(),
-            "/health".to_string(),
-            e,
-        )
-    })?;
+    chain
+        .endpoints
+        .track(chain.block_on(chain.rpc_client.health()).map_err(|e| {
+            Error::health_check_json_rpc(
+                chain_id.clone(),
+                rpc_address.clone(),
+                "/health".to_string(),
+                e,
+            )
+        }))?;
 
     let status = chain.chain_status()?;
 
@@ -2501,7 +3010,9 @@ fn do_health_check(chain: &CosmosSdkChain) -> Result<(), Error> {
         );
     }
 
-    let version_specs = chain.block_on(fetch_version_specs(&chain.config.id, &chain.grpc_addr))?;
+    let version_specs = chain
+        .endpoints
+        .track(chain.block_on(fetch_version_specs(&chain.config.id, &chain.grpc_addr)))?;
 
     if let Err(diagnostic) = compatibility::run_diagnostic(&version_specs) {
         return Err(Error::compat_check_failed(
@@ -11,6 +11,8 @@ use tracing::debug;
 
 use crate::chain::cosmos::encode::encoded_tx_metrics;
 use crate::chain::cosmos::gas::gas_amount_to_fee;
+use crate::chain::cosmos::gas_model::GasModelState;
+use crate::chain::cosmos::pipeline::{send_tx_pipelined, InFlightTxs};
 use crate::chain::cosmos::retry::send_tx_with_account_sequence_retry;
 use crate::chain::cosmos::types::account::Account;
 use crate::chain::cosmos::types::config::TxConfig;
@@ -33,6 +35,7 @@ pub async fn send_batched_messages_and_wait_commit(
     config: &TxConfig,
     key_pair: &Secp256k1KeyPair,
     account: &mut Account,
+    gas_model: &mut GasModelState,
     tx_memo: &Memo,
     messages: Vec<Any>,
 ) -> Result<Vec<IbcEventWithHeight>, Error> {
@@ -40,14 +43,17 @@ pub async fn send_batched_messages_and_wait_commit(
         return Ok(Vec::new());
     }
 
-    let mut tx_sync_results =
-        send_messages_as_batches(rpc_client, config, key_pair, account, tx_memo, messages).await?;
+    let mut tx_sync_results = send_messages_as_batches(
+        rpc_client, config, key_pair, account, gas_model, tx_memo, messages,
+    )
+    .await?;
 
     wait_for_block_commits(
         &config.chain_id,
         rpc_client,
         &config.rpc_address,
         &config.rpc_timeout,
+        gas_model,
         &mut tx_sync_results,
     )
     .await?;
@@ -70,6 +76,7 @@ pub async fn sequential_send_batched_messages_and_wait_commit(
     config: &TxConfig,
     key_pair: &Secp256k1KeyPair,
     account: &mut Account,
+    gas_model: &mut GasModelState,
     tx_memo: &Memo,
     messages: Vec<Any>,
 ) -> Result<Vec<IbcEventWithHeight>, Error> {
@@ -78,7 +85,7 @@ pub async fn sequential_send_batched_messages_and_wait_commit(
     }
 
     let tx_sync_results = sequential_send_messages_as_batches(
-        rpc_client, config, key_pair, account, tx_memo, messages,
+        rpc_client, config, key_pair, account, gas_model, tx_memo, messages,
     )
     .await?;
 
@@ -95,6 +102,7 @@ pub async fn send_batched_messages_and_wait_check_tx(
     config: &TxConfig,
     key_pair: &Secp256k1KeyPair,
     account: &mut Account,
+    gas_model: &mut GasModelState,
     tx_memo: &Memo,
     messages: Vec<Any>,
 ) -> Result<Vec<Response>, Error> {
@@ -108,7 +116,84 @@ pub async fn send_batched_messages_and_wait_check_tx(
 
     for batch in batches {
         let response = send_tx_with_account_sequence_retry(
-            rpc_client, config, key_pair, account, tx_memo, &batch,
+            rpc_client, config, key_pair, account, gas_model, tx_memo, &batch,
+        )
+        .await?;
+
+        responses.push(response);
+    }
+
+    Ok(responses)
+}
+
+/**
+   Broadcast messages as multiple batched transactions, pipelined after the
+   transactions of the same account which are still in flight, and then wait
+   for all transactions to be committed.
+*/
+pub async fn pipelined_send_batched_messages_and_wait_commit(
+    rpc_client: &HttpClient,
+    config: &TxConfig,
+    key_pair: &Secp256k1KeyPair,
+    account: &mut Account,
+    in_flight: &mut InFlightTxs,
+    gas_model: &mut GasModelState,
+    tx_memo: &Memo,
+    messages: Vec<Any>,
+) -> Result<Vec<IbcEventWithHeight>, Error> {
+    if messages.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let mut tx_sync_results = pipelined_send_messages_as_batches(
+        rpc_client, config, key_pair, account, in_flight, gas_model, tx_memo, messages,
+    )
+    .await?;
+
+    wait_for_block_commits(
+        &config.chain_id,
+        rpc_client,
+        &config.rpc_address,
+        &config.rpc_timeout,
+        gas_model,
+        &mut tx_sync_results,
+    )
+    .await?;
+
+    let events = tx_sync_results
+        .into_iter()
+        .flat_map(|el| el.events)
+        .collect();
+
+    Ok(events)
+}
+
+/**
+   Broadcast messages as multiple batched transactions, pipelined after the
+   transactions of the same account which are still in flight, only waiting
+   for their check tx.
+*/
+pub async fn pipelined_send_batched_messages_and_wait_check_tx(
+    rpc_client: &HttpClient,
+    config: &TxConfig,
+    key_pair: &Secp256k1KeyPair,
+    account: &mut Account,
+    in_flight: &mut InFlightTxs,
+    gas_model: &mut GasModelState,
+    tx_memo: &Memo,
+    messages: Vec<Any>,
+) -> Result<Vec<Response>, Error> {
+    if messages.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let batches = batch_messages(config, key_pair, account, tx_memo, messages).await?;
+
+    let mut responses = Vec::new();
+
+    for batch in batches {
+        let response = send_tx_pipelined(
+            rpc_client, config, key_pair, account, in_flight, gas_model, tx_memo, &batch,
         )
         .await?;
 
@@ -123,6 +208,7 @@ async fn send_messages_as_batches(
     config: &TxConfig,
     key_pair: &Secp256k1KeyPair,
     account: &mut Account,
+    gas_model: &mut GasModelState,
     tx_memo: &Memo,
     messages: Vec<Any>,
 ) -> Result<Vec<TxSyncResult>, Error> {
@@ -147,7 +233,7 @@ async fn send_messages_as_batches(
         let message_count = batch.len();
 
         let response = send_tx_with_account_sequence_retry(
-            rpc_client, config, key_pair, account, tx_memo, &batch,
+            rpc_client, config, key_pair, account, gas_model, tx_memo, &batch,
         )
         .await?;
 
@@ -164,6 +250,7 @@ async fn sequential_send_messages_as_batches(
     config: &TxConfig,
     key_pair: &Secp256k1KeyPair,
     account: &mut Account,
+    gas_model: &mut GasModelState,
     tx_memo: &Memo,
     messages: Vec<Any>,
 ) -> Result<Vec<TxSyncResult>, Error> {
@@ -188,7 +275,7 @@ async fn sequential_send_messages_as_batches(
         let message_count = batch.len();
 
         let response = send_tx_with_account_sequence_retry(
-            rpc_client, config, key_pair, account, tx_memo, &batch,
+            rpc_client, config, key_pair, account, gas_model, tx_memo, &batch,
         )
         .await?;
 
@@ -201,6 +288,7 @@ async fn sequential_send_messages_as_batches(
             rpc_client,
             &config.rpc_address,
             &config.rpc_timeout,
+            gas_model,
             &mut tx_sync_results,
         )
         .await?;
@@ -209,6 +297,46 @@ async fn sequential_send_messages_as_batches(
     Ok(tx_sync_results)
 }
 
+async fn pipelined_send_messages_as_batches(
+    rpc_client: &HttpClient,
+    config: &TxConfig,
+    key_pair: &Secp256k1KeyPair,
+    account: &mut Account,
+    in_flight: &mut InFlightTxs,
+    gas_model: &mut GasModelState,
+    tx_memo: &Memo,
+    messages: Vec<Any>,
+) -> Result<Vec<TxSyncResult>, Error> {
+    let message_count = messages.len();
+
+    let batches = batch_messages(config, key_pair, account, tx_memo, messages).await?;
+
+    debug!(
+        "sending {} messages as {} batches to chain {} pipelined after {} txs in flight",
+        message_count,
+        batches.len(),
+        config.chain_id,
+        in_flight.len()
+    );
+
+    let mut tx_sync_results = Vec::new();
+
+    for batch in batches {
+        let message_count = batch.len();
+
+        let response = send_tx_pipelined(
+            rpc_client, config, key_pair, account, in_flight, gas_model, tx_memo, &batch,
+        )
+        .await?;
+
+        let tx_sync_result = response_to_tx_sync_result(&config.chain_id, message_count, response);
+
+        tx_sync_results.push(tx_sync_result);
+    }
+
+    Ok(tx_sync_results)
+}
+
 fn response_to_tx_sync_result(
     chain_id: &ChainId,
     message_count: usize,
@@ -340,7 +468,6 @@ mod tests {
         let config = config::load(path).expect("could not parse config");
         let chain_id = ChainId::from_string("chain_A");
 
-        #[allow(irrefutable_let_patterns)]
         let config::ChainConfig::CosmosSdk(chain_config) = config.find_chain(&chain_id).unwrap() else {
             panic!("should be a cosmos sdk chain config");
         };
@@ -357,6 +484,8 @@ mod tests {
             "cosmos",
             &chain_id,
             &chain_config.key_store_folder,
+            &chain_config.key_store_passphrase,
+            &chain_config.remote_signer,
         )
         .unwrap();
         let hd_path = COSMOS_HD_PATH.parse().unwrap();
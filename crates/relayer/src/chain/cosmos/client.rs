@@ -50,6 +50,29 @@ impl Settings {
             trust_threshold,
         }
     }
+
+    /// Settings for a client of a Cosmos SDK chain hosted in-process, ie. on
+    /// a mock chain or a solo machine, which has no clock drift of its own
+    /// and commits a block every `dst_block_time`.
+    pub fn for_in_process_host(
+        options: CreateOptions,
+        src_chain_config: &CosmosSdkConfig,
+        dst_block_time: Duration,
+    ) -> Self {
+        let max_clock_drift = options
+            .max_clock_drift
+            .unwrap_or(src_chain_config.clock_drift + dst_block_time);
+
+        let trust_threshold = options
+            .trust_threshold
+            .unwrap_or(src_chain_config.trust_threshold);
+
+        Settings {
+            max_clock_drift,
+            trusting_period: options.trusting_period,
+            trust_threshold,
+        }
+    }
 }
 
 /// The client state clock drift must account for destination
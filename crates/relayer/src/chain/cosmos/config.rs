@@ -5,21 +5,24 @@ use std::path::PathBuf;
 use byte_unit::Byte;
 use ibc_relayer_types::core::ics04_channel::packet::Sequence;
 use serde_derive::{Deserialize, Serialize};
-use tendermint_rpc::Url;
+use tendermint_rpc::{Url, WebSocketClientUrl};
 
 use ibc_relayer_types::core::ics23_commitment::specs::ProofSpecs;
 use ibc_relayer_types::core::ics24_host::identifier::{ChainId, ChannelId};
 
 use crate::chain::cosmos::config::error::Error as ConfigError;
+use crate::chain::cosmos::wallets::WalletAssignment;
 use crate::config::compat_mode::CompatMode;
 use crate::config::dynamic_gas::DynamicGasPrice;
+use crate::config::gas_model::GasModel;
 use crate::config::gas_multiplier::GasMultiplier;
+use crate::config::tx_pipeline::TxPipeline;
 use crate::config::types::{MaxMsgNum, MaxTxSize, Memo, TrustThreshold};
 use crate::config::{
     self, AddressType, EventSourceMode, ExtensionOption, GasPrice, GenesisRestart, PacketFilter,
 };
 use crate::config::{default, RefreshRate};
-use crate::keyring::Store;
+use crate::keyring::{KeyStorePassphrase, RemoteSignerConfig, Store};
 
 pub mod error;
 
@@ -46,11 +49,26 @@ pub struct CosmosSdkConfig {
     #[serde(default = "default::trusted_node")]
     pub trusted_node: bool,
 
+    /// The RPC URLs of full nodes against which `hermes monitor misbehaviour`
+    /// cross-checks the headers served by the node at `rpc_addr`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub witnesses: Vec<Url>,
+
     pub account_prefix: String,
     pub key_name: String,
+    /// Additional keys to sign the transactions relaying packets with
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub extra_key_names: Vec<String>,
+    /// How the transactions relaying packets are spread across the keys
+    #[serde(default)]
+    pub wallet_assignment: WalletAssignment,
     #[serde(default)]
     pub key_store_type: Store,
     pub key_store_folder: Option<PathBuf>,
+    /// Where to read the passphrase of the `File` key store from
+    pub key_store_passphrase: Option<KeyStorePassphrase>,
+    /// The signer holding the keys of the `Remote` key store
+    pub remote_signer: Option<RemoteSignerConfig>,
     pub store_prefix: String,
     pub default_gas: Option<u64>,
     pub max_gas: Option<u64>,
@@ -66,6 +84,13 @@ pub struct CosmosSdkConfig {
 
     pub fee_granter: Option<String>,
 
+    /// How long before the fee grants given to the wallets expire to start warning about it
+    #[serde(
+        default = "default::fee_grant_expiry_warning",
+        with = "humantime_serde"
+    )]
+    pub fee_grant_expiry_warning: Duration,
+
     #[serde(default)]
     pub max_msg_num: MaxMsgNum,
 
@@ -142,6 +167,14 @@ pub struct CosmosSdkConfig {
     #[serde(default)]
     pub dynamic_gas_price: DynamicGasPrice,
 
+    /// Estimate the gas of transactions from the gas used by past ones instead of simulating them
+    #[serde(default)]
+    pub gas_model: GasModel,
+
+    /// Broadcast several transactions per block by tracking the account sequence locally
+    #[serde(default)]
+    pub tx_pipeline: TxPipeline,
+
     #[serde(default)]
     pub address_type: AddressType,
     #[serde(default = "Vec::new", skip_serializing_if = "Vec::is_empty")]
@@ -150,12 +183,55 @@ pub struct CosmosSdkConfig {
     pub clear_interval: Option<u64>,
     #[serde(default)]
     pub excluded_sequences: BTreeMap<ChannelId, Vec<Sequence>>,
+    /// Additional full nodes to fail over to when the one at `rpc_addr` and `grpc_addr` is unhealthy
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub fallback_endpoints: Vec<FallbackEndpoint>,
+}
+
+/// A full node of the chain to fail over to.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct FallbackEndpoint {
+    /// The RPC URL of the node
+    pub rpc_addr: Url,
+
+    /// The gRPC URL of the node
+    pub grpc_addr: Url,
+
+    /// The WebSocket URL to collect events from, when the event source is in `push` mode
+    pub websocket_addr: Option<WebSocketClientUrl>,
 }
 
 impl CosmosSdkConfig {
+    /// The names of all the keys used to sign transactions, starting with `key_name`.
+    pub fn key_names(&self) -> Vec<String> {
+        let mut key_names = vec![self.key_name.clone()];
+
+        for key_name in &self.extra_key_names {
+            if !key_names.contains(key_name) {
+                key_names.push(key_name.clone());
+            }
+        }
+
+        key_names
+    }
+
+    /// Replace the gas settings with the ones of the given configuration.
+    ///
+    /// These are the settings which can be updated without restarting the chain runtime.
+    pub fn set_gas_settings(&mut self, other: &CosmosSdkConfig) {
+        self.default_gas = other.default_gas;
+        self.max_gas = other.max_gas;
+        self.gas_multiplier = other.gas_multiplier;
+        self.gas_price = other.gas_price.clone();
+        self.dynamic_gas_price = other.dynamic_gas_price;
+    }
+
     pub fn validate(&self) -> Result<(), Diagnostic<ConfigError>> {
         validate_trust_threshold(&self.id, self.trust_threshold)?;
         validate_gas_settings(&self.id, self.gas_adjustment)?;
+        validate_tx_pipeline(&self.id, self.tx_pipeline, self.sequential_batch_tx)?;
+        validate_gas_model(&self.id, self.gas_model)?;
         Ok(())
     }
 }
@@ -213,6 +289,63 @@ fn validate_gas_settings(
 
     Ok(())
 }
+
+/// Check that the transaction pipeline, if enabled, allows at least one
+/// transaction in flight and is not combined with `sequential_batch_tx`,
+/// which waits for each transaction to be committed before sending the next.
+fn validate_tx_pipeline(
+    id: &ChainId,
+    tx_pipeline: TxPipeline,
+    sequential_batch_tx: bool,
+) -> Result<(), Diagnostic<ConfigError>> {
+    if !tx_pipeline.enabled {
+        return Ok(());
+    }
+
+    if tx_pipeline.max_in_flight == 0 {
+        return Err(Diagnostic::Error(ConfigError::invalid_tx_pipeline(
+            id.clone(),
+            "`max_in_flight` must be greater than zero".to_string(),
+        )));
+    }
+
+    if sequential_batch_tx {
+        return Err(Diagnostic::Error(ConfigError::invalid_tx_pipeline(
+            id.clone(),
+            "it cannot be enabled together with `sequential_batch_tx`".to_string(),
+        )));
+    }
+
+    Ok(())
+}
+
+/// Check that the gas model, if enabled, requires at least one sample per type
+/// of message, and that its maximum drift is within (0, 1].
+fn validate_gas_model(id: &ChainId, gas_model: GasModel) -> Result<(), Diagnostic<ConfigError>> {
+    if !gas_model.enabled {
+        return Ok(());
+    }
+
+    if gas_model.min_samples == 0 {
+        return Err(Diagnostic::Error(ConfigError::invalid_gas_model(
+            id.clone(),
+            "`min_samples` must be greater than zero".to_string(),
+        )));
+    }
+
+    if !(gas_model.max_drift > 0.0 && gas_model.max_drift <= 1.0) {
+        return Err(Diagnostic::Error(ConfigError::invalid_gas_model(
+            id.clone(),
+            format!(
+                "`max_drift` must be greater than 0 and at most 1, found {}",
+                gas_model.max_drift
+            ),
+        )));
+    }
+
+    Ok(())
+}
+
 #[derive(Clone, Debug)]
 pub enum Diagnostic<E> {
     Warning(E),
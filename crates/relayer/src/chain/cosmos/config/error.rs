@@ -28,5 +28,25 @@ define_error! {
                 e.chain_id, e.gas_adjustment, e.gas_multiplier
             )
         },
+
+        InvalidTxPipeline
+        {
+            chain_id: ChainId,
+            reason: String,
+        }
+        |e| {
+            format!("config file specifies an invalid `tx_pipeline` for the chain '{0}': {1}",
+                e.chain_id, e.reason)
+        },
+
+        InvalidGasModel
+        {
+            chain_id: ChainId,
+            reason: String,
+        }
+        |e| {
+            format!("config file specifies an invalid `gas_model` for the chain '{0}': {1}",
+                e.chain_id, e.reason)
+        },
     }
 }
@@ -0,0 +1,520 @@
+//! Health tracking of the full nodes serving a chain, ie. the one at its `rpc_addr`
+//! and `grpc_addr` and its `fallback_endpoints`, in order to fail over to the
+//! healthiest of them when the one in use becomes unhealthy.
+//!
+//! The endpoints are probed by a background task, so that the chain runtime keeps
+//! serving requests in the meantime, and the health of the endpoint in use also
+//! accounts for the errors of the actual queries and transactions sent to it.
+
+use core::sync::atomic::{AtomicU64, Ordering};
+use core::time::Duration;
+use std::collections::VecDeque;
+use std::str::FromStr;
+use std::time::Instant;
+
+use crossbeam_channel as channel;
+use futures::future::join_all;
+use tokio::runtime::Runtime as TokioRuntime;
+use tonic::codegen::http::Uri;
+
+use ibc_proto::cosmos::base::tendermint::v1beta1::service_client::ServiceClient;
+use ibc_proto::cosmos::base::tendermint::v1beta1::GetSyncingRequest;
+use ibc_relayer_types::core::ics24_host::identifier::ChainId;
+use tendermint_rpc::client::CompatMode;
+use tendermint_rpc::{HttpClient, Url, WebSocketClientUrl};
+
+use crate::chain::cosmos::config::CosmosSdkConfig;
+use crate::chain::cosmos::query::status::query_status;
+use crate::config::EventSourceMode;
+use crate::error::Error;
+
+/// How many blocks an endpoint can lag behind the most advanced endpoint
+/// of the chain before being considered unhealthy.
+pub const MAX_HEIGHT_LAG: u64 = 5;
+
+/// The fraction of the recent probes of an endpoint which can fail
+/// before the endpoint is considered unhealthy.
+pub const MAX_ERROR_RATE: f64 = 0.5;
+
+/// How many of the most recent probes of an endpoint are used to compute its error rate.
+const PROBES_WINDOW: usize = 10;
+
+/// How often the endpoints are probed.
+const PROBES_INTERVAL: Duration = Duration::from_secs(30);
+
+/// A full node of the chain.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Endpoint {
+    pub rpc_addr: Url,
+    pub grpc_addr: Url,
+    /// The WebSocket URL to collect events from, if known
+    pub websocket_addr: Option<WebSocketClientUrl>,
+}
+
+impl Endpoint {
+    pub fn grpc_uri(&self) -> Result<Uri, Error> {
+        Uri::from_str(&self.grpc_addr.to_string())
+            .map_err(|e| Error::invalid_uri(self.grpc_addr.to_string(), e))
+    }
+}
+
+/// The outcome of probing an endpoint.
+#[derive(Debug)]
+pub enum ProbeOutcome {
+    /// The endpoint reported the given height, and the probe took `latency`
+    Success {
+        height: u64,
+        latency: Duration,
+    },
+    Failure(Box<Error>),
+    TimedOut(Duration),
+}
+
+/// The health of an endpoint, as observed by the latest probes
+/// and by the requests sent to it since it was last checked.
+#[derive(Clone, Debug, Default)]
+pub struct EndpointHealth {
+    /// The outcome of the most recent probes, `true` for a success
+    probes: VecDeque<bool>,
+    /// The latest height reported by the endpoint
+    latest_height: Option<u64>,
+    /// How long the latest successful probe took
+    latency: Option<Duration>,
+    /// How many requests succeeded and failed because of the endpoint
+    requests: (u64, u64),
+}
+
+impl EndpointHealth {
+    fn record(&mut self, success: bool) {
+        if self.probes.len() == PROBES_WINDOW {
+            self.probes.pop_front();
+        }
+
+        self.probes.push_back(success);
+    }
+
+    pub fn record_success(&mut self, height: u64, latency: Duration) {
+        self.record(true);
+        self.latest_height = Some(height);
+        self.latency = Some(latency);
+    }
+
+    pub fn record_error(&mut self) {
+        self.record(false);
+    }
+
+    pub fn record_probe(&mut self, outcome: &ProbeOutcome) {
+        match outcome {
+            ProbeOutcome::Success { height, latency } => self.record_success(*height, *latency),
+            ProbeOutcome::Failure(_) | ProbeOutcome::TimedOut(_) => self.record_error(),
+        }
+    }
+
+    /// Whether the latest probe of the endpoint succeeded.
+    pub fn is_reachable(&self) -> bool {
+        self.probes.back().copied().unwrap_or(false)
+    }
+
+    /// The fraction of the recent probes and requests which failed.
+    pub fn error_rate(&self) -> f64 {
+        let (succeeded, failed) = self.requests;
+
+        let total = self.probes.len() as u64 + succeeded + failed;
+
+        if total == 0 {
+            return 0.0;
+        }
+
+        let errors = self.probes.iter().filter(|success| !**success).count() as u64 + failed;
+
+        errors as f64 / total as f64
+    }
+}
+
+/// The number of requests sent to an endpoint which succeeded and failed.
+#[derive(Debug, Default)]
+struct RequestCounts {
+    succeeded: AtomicU64,
+    failed: AtomicU64,
+}
+
+impl RequestCounts {
+    fn take(&self) -> (u64, u64) {
+        (
+            self.succeeded.swap(0, Ordering::Relaxed),
+            self.failed.swap(0, Ordering::Relaxed),
+        )
+    }
+}
+
+/// The full nodes serving a chain, the first one being the one configured
+/// with `rpc_addr` and `grpc_addr`, followed by the `fallback_endpoints`.
+#[derive(Debug)]
+pub struct Endpoints {
+    endpoints: Vec<Endpoint>,
+    health: Vec<EndpointHealth>,
+    active: usize,
+    /// The requests sent to the active endpoint since it was last checked
+    requests: RequestCounts,
+}
+
+impl Endpoints {
+    pub fn new(config: &CosmosSdkConfig) -> Self {
+        let websocket_addr = match &config.event_source {
+            EventSourceMode::Push { url, .. } => Some(url.clone()),
+            EventSourceMode::Pull { .. } => None,
+        };
+
+        let primary = Endpoint {
+            rpc_addr: config.rpc_addr.clone(),
+            grpc_addr: config.grpc_addr.clone(),
+            websocket_addr,
+        };
+
+        let fallbacks = config.fallback_endpoints.iter().map(|fallback| Endpoint {
+            rpc_addr: fallback.rpc_addr.clone(),
+            grpc_addr: fallback.grpc_addr.clone(),
+            websocket_addr: fallback.websocket_addr.clone(),
+        });
+
+        let endpoints: Vec<_> = core::iter::once(primary).chain(fallbacks).collect();
+        let health = vec![EndpointHealth::default(); endpoints.len()];
+
+        Self {
+            endpoints,
+            health,
+            active: 0,
+            requests: RequestCounts::default(),
+        }
+    }
+
+    pub fn all(&self) -> &[Endpoint] {
+        &self.endpoints
+    }
+
+    /// The endpoint in use.
+    pub fn active(&self) -> &Endpoint {
+        &self.endpoints[self.active]
+    }
+
+    pub fn is_active(&self, index: usize) -> bool {
+        self.active == index
+    }
+
+    pub fn has_fallbacks(&self) -> bool {
+        self.endpoints.len() > 1
+    }
+
+    pub fn health_mut(&mut self, index: usize) -> &mut EndpointHealth {
+        &mut self.health[index]
+    }
+
+    /// Record the outcome of a request sent to the active endpoint, and return it.
+    ///
+    /// Only the errors caused by the endpoint itself, eg. because it is unreachable,
+    /// count as failures, and not the ones caused by the request.
+    pub fn track<T>(&self, result: Result<T, Error>) -> Result<T, Error> {
+        match &result {
+            Ok(_) => {
+                self.requests.succeeded.fetch_add(1, Ordering::Relaxed);
+            }
+            Err(e) if e.is_endpoint_error() => {
+                self.requests.failed.fetch_add(1, Ordering::Relaxed);
+            }
+            Err(_) => {}
+        }
+
+        result
+    }
+
+    /// Account for the requests sent since the last check in the health of the active
+    /// endpoint, and forget the ones sent to the endpoints which are no longer in use.
+    pub fn record_requests(&mut self) {
+        let requests = self.requests.take();
+
+        for (index, health) in self.health.iter_mut().enumerate() {
+            health.requests = if index == self.active {
+                requests
+            } else {
+                (0, 0)
+            };
+        }
+    }
+
+    /// How many blocks the given endpoint lags behind the most advanced endpoint,
+    /// or `None` if its height is unknown.
+    pub fn height_lag(&self, index: usize) -> Option<u64> {
+        let height = self.health[index].latest_height?;
+
+        let max_height = self
+            .health
+            .iter()
+            .filter_map(|health| health.latest_height)
+            .max()
+            .unwrap_or(height);
+
+        Some(max_height.saturating_sub(height))
+    }
+
+    /// An endpoint is healthy if it is reachable, does not lag more than
+    /// [`MAX_HEIGHT_LAG`] blocks behind, and its error rate is at most [`MAX_ERROR_RATE`].
+    pub fn is_healthy(&self, index: usize) -> bool {
+        let health = &self.health[index];
+
+        health.is_reachable()
+            && self.height_lag(index).unwrap_or(u64::MAX) <= MAX_HEIGHT_LAG
+            && health.error_rate() <= MAX_ERROR_RATE
+    }
+
+    /// The score of the given endpoint, lower is better, or `None` if it is unreachable.
+    ///
+    /// Each block of lag and each 10% of errors weighs as much as a second of latency.
+    pub fn score(&self, index: usize) -> Option<f64> {
+        let health = &self.health[index];
+
+        if !health.is_reachable() {
+            return None;
+        }
+
+        let lag = self.height_lag(index)? as f64;
+        let latency = health.latency.unwrap_or_default().as_secs_f64();
+
+        Some(lag + health.error_rate() * 10.0 + latency)
+    }
+
+    /// The healthiest endpoint to switch to if the active one is unhealthy, if any.
+    ///
+    /// The active endpoint is kept as long as it is healthy, even if
+    /// another endpoint has a better score, to avoid flapping between them.
+    pub fn select(&self) -> Option<usize> {
+        if self.is_healthy(self.active) {
+            return None;
+        }
+
+        let active_score = self.score(self.active).unwrap_or(f64::INFINITY);
+
+        (0..self.endpoints.len())
+            .filter(|&index| index != self.active)
+            .filter_map(|index| self.score(index).map(|score| (index, score)))
+            .filter(|&(_, score)| score < active_score)
+            .min_by(|(_, a), (_, b)| a.total_cmp(b))
+            .map(|(index, _)| index)
+    }
+
+    /// Mark the given endpoint as the one in use.
+    pub fn set_active(&mut self, index: usize) {
+        self.active = index;
+    }
+}
+
+/// Spawn a task which probes all the given endpoints concurrently every [`PROBES_INTERVAL`],
+/// and sends the outcomes of each round of probes, in the order of the endpoints,
+/// to the returned channel. The task stops once the channel is dropped.
+///
+/// A round of probes is dropped if the outcomes of the previous one were not received yet.
+pub fn spawn_probes(
+    rt: &TokioRuntime,
+    chain_id: ChainId,
+    endpoints: Vec<Endpoint>,
+    compat_mode: CompatMode,
+    timeout: Duration,
+) -> channel::Receiver<Vec<ProbeOutcome>> {
+    let (tx, rx) = channel::bounded(1);
+
+    rt.spawn(async move {
+        loop {
+            let probes = endpoints.iter().map(|endpoint| {
+                let chain_id = &chain_id;
+
+                async move {
+                    let start = Instant::now();
+
+                    match tokio::time::timeout(timeout, probe(chain_id, endpoint, compat_mode))
+                        .await
+                    {
+                        Ok(Ok(height)) => ProbeOutcome::Success {
+                            height,
+                            latency: start.elapsed(),
+                        },
+                        Ok(Err(e)) => ProbeOutcome::Failure(Box::new(e)),
+                        Err(_) => ProbeOutcome::TimedOut(timeout),
+                    }
+                }
+            });
+
+            let outcomes = join_all(probes).await;
+
+            if let Err(channel::TrySendError::Disconnected(_)) = tx.try_send(outcomes) {
+                break;
+            }
+
+            tokio::time::sleep(PROBES_INTERVAL).await;
+        }
+    });
+
+    rx
+}
+
+/// Probe the RPC and gRPC servers of the given endpoint,
+/// returning the latest height reported by the RPC server.
+///
+/// Returns an error if any of them is unreachable or still syncing.
+pub async fn probe(
+    chain_id: &ChainId,
+    endpoint: &Endpoint,
+    compat_mode: CompatMode,
+) -> Result<u64, Error> {
+    let mut rpc_client = HttpClient::new(endpoint.rpc_addr.clone())
+        .map_err(|e| Error::rpc(endpoint.rpc_addr.clone(), e))?;
+
+    rpc_client.set_compat_mode(compat_mode);
+
+    let status = query_status(chain_id, &rpc_client, &endpoint.rpc_addr).await?;
+
+    let mut grpc_client = ServiceClient::connect(endpoint.grpc_uri()?)
+        .await
+        .map_err(Error::grpc_transport)?;
+
+    let sync_info = grpc_client
+        .get_syncing(tonic::Request::new(GetSyncingRequest {}))
+        .await
+        .map_err(|e| Error::grpc_status(e, "get_syncing".to_string()))?
+        .into_inner();
+
+    if sync_info.syncing {
+        return Err(Error::chain_not_caught_up(
+            endpoint.grpc_addr.to_string(),
+            chain_id.clone(),
+        ));
+    }
+
+    Ok(status.height.revision_height())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use test_log::test;
+
+    fn endpoints(count: usize) -> Endpoints {
+        let endpoints: Vec<_> = (0..count)
+            .map(|i| Endpoint {
+                rpc_addr: format!("http://node-{i}:26657").parse().unwrap(),
+                grpc_addr: format!("http://node-{i}:9090").parse().unwrap(),
+                websocket_addr: None,
+            })
+            .collect();
+
+        Endpoints {
+            health: vec![EndpointHealth::default(); endpoints.len()],
+            endpoints,
+            active: 0,
+            requests: RequestCounts::default(),
+        }
+    }
+
+    const LATENCY: Duration = Duration::from_millis(100);
+
+    #[test]
+    fn error_rate_is_computed_over_recent_probes() {
+        let mut health = EndpointHealth::default();
+        assert_eq!(health.error_rate(), 0.0);
+
+        health.record_error();
+        health.record_success(1, LATENCY);
+        assert_eq!(health.error_rate(), 0.5);
+
+        for _ in 0..PROBES_WINDOW {
+            health.record_success(1, LATENCY);
+        }
+
+        assert_eq!(health.error_rate(), 0.0);
+        assert!(health.is_reachable());
+    }
+
+    #[test]
+    fn keeps_healthy_active_endpoint() {
+        let mut endpoints = endpoints(2);
+
+        endpoints
+            .health_mut(0)
+            .record_success(100, Duration::from_secs(1));
+        endpoints.health_mut(1).record_success(100, LATENCY);
+
+        assert_eq!(endpoints.select(), None);
+    }
+
+    #[test]
+    fn fails_over_from_unreachable_endpoint() {
+        let mut endpoints = endpoints(3);
+
+        endpoints.health_mut(0).record_error();
+        endpoints
+            .health_mut(1)
+            .record_success(100, Duration::from_secs(1));
+        endpoints.health_mut(2).record_success(100, LATENCY);
+
+        assert_eq!(endpoints.select(), Some(2));
+    }
+
+    #[test]
+    fn fails_over_from_lagging_endpoint() {
+        let mut endpoints = endpoints(2);
+
+        endpoints.health_mut(0).record_success(100, LATENCY);
+        endpoints
+            .health_mut(1)
+            .record_success(100 + MAX_HEIGHT_LAG + 1, LATENCY);
+
+        assert_eq!(endpoints.height_lag(0), Some(MAX_HEIGHT_LAG + 1));
+        assert!(!endpoints.is_healthy(0));
+
+        assert_eq!(endpoints.select(), Some(1));
+    }
+
+    #[test]
+    fn stays_on_unhealthy_endpoint_without_better_one() {
+        let mut endpoints = endpoints(2);
+
+        endpoints.health_mut(0).record_error();
+        endpoints.health_mut(1).record_error();
+
+        assert_eq!(endpoints.select(), None);
+    }
+
+    #[test]
+    fn fails_over_on_request_errors() {
+        let mut endpoints = endpoints(2);
+
+        endpoints.health_mut(0).record_success(100, LATENCY);
+        endpoints.health_mut(1).record_success(100, LATENCY);
+
+        let unreachable = || {
+            Err::<(), _>(Error::rpc(
+                endpoints.active().rpc_addr.clone(),
+                tendermint_rpc::Error::timeout(LATENCY),
+            ))
+        };
+
+        for _ in 0..3 {
+            let _ = endpoints.track(unreachable());
+        }
+        let _ = endpoints.track(Ok(()));
+
+        // Errors caused by the request itself do not count
+        let _ = endpoints.track(Err::<(), _>(Error::private_store()));
+
+        endpoints.record_requests();
+
+        // 3 failures out of 1 probe and 4 requests
+        assert_eq!(endpoints.health_mut(0).error_rate(), 0.6);
+        assert_eq!(endpoints.select(), Some(1));
+
+        endpoints.set_active(1);
+        endpoints.record_requests();
+
+        // The requests sent to the endpoint no longer in use are forgotten
+        assert_eq!(endpoints.health_mut(0).error_rate(), 0.0);
+    }
+}
@@ -7,6 +7,7 @@ use tracing::{debug, error, span, warn, Level};
 
 use crate::chain::cosmos::encode::sign_tx;
 use crate::chain::cosmos::gas::gas_amount_to_fee;
+use crate::chain::cosmos::gas_model::{self, GasModelState};
 use crate::chain::cosmos::simulate::send_tx_simulate;
 use crate::chain::cosmos::types::account::Account;
 use crate::chain::cosmos::types::config::TxConfig;
@@ -20,12 +21,27 @@ use crate::util::pretty::PrettyFee;
 pub enum EstimatedGas {
     Simulated(u64),
     Default(u64),
+    /// Estimated from the gas used by past transactions with the same types of messages
+    Modelled(u64),
 }
 
 impl EstimatedGas {
+    pub const SIMULATION: &'static str = "simulation";
+    pub const DEFAULT: &'static str = "default";
+    pub const MODEL: &'static str = "model";
+
     pub fn get_amount(&self) -> u64 {
         match self {
-            Self::Simulated(amount) | Self::Default(amount) => *amount,
+            Self::Simulated(amount) | Self::Default(amount) | Self::Modelled(amount) => *amount,
+        }
+    }
+
+    /// Where the estimate comes from, ie. `simulation`, `default` or `model`.
+    pub fn source(&self) -> &'static str {
+        match self {
+            Self::Simulated(_) => Self::SIMULATION,
+            Self::Default(_) => Self::DEFAULT,
+            Self::Modelled(_) => Self::MODEL,
         }
     }
 }
@@ -34,6 +50,7 @@ pub async fn estimate_tx_fees(
     config: &TxConfig,
     key_pair: &Secp256k1KeyPair,
     account: &Account,
+    gas_model: &mut GasModelState,
     tx_memo: &Memo,
     messages: &[Any],
 ) -> Result<(Fee, EstimatedGas), Error> {
@@ -66,12 +83,33 @@ pub async fn estimate_tx_fees(
         &config.chain_id,
         tx,
         account,
+        gas_model,
     )
     .await?;
 
     Ok(estimated_fee_and_gas)
 }
 
+/// Estimate the fee of a transaction with a single message of the given type, which is
+/// not built yet and whose gas can therefore not be simulated.
+///
+/// The gas is estimated from the gas used by past transactions with the same type of message
+/// if the `gas_model` is enabled and has enough samples of it, and is the `default_gas` otherwise.
+pub async fn estimate_tx_fee(config: &TxConfig, gas_model: &GasModelState, msg_type: &str) -> Fee {
+    let gas_config = &config.gas_config;
+    let settings = &gas_config.gas_model;
+
+    let msg_types = gas_model::MsgTypes::from([(msg_type.to_string(), 1)]);
+
+    let gas = settings
+        .enabled
+        .then(|| gas_model.estimate(settings, &msg_types))
+        .flatten()
+        .unwrap_or(gas_config.default_gas);
+
+    gas_amount_to_fee(gas_config, gas, &config.chain_id, &config.rpc_address).await
+}
+
 async fn estimate_fee_with_tx(
     gas_config: &GasConfig,
     grpc_address: &Uri,
@@ -79,6 +117,7 @@ async fn estimate_fee_with_tx(
     chain_id: &ChainId,
     tx: Tx,
     account: &Account,
+    gas_model: &mut GasModelState,
 ) -> Result<(Fee, EstimatedGas), Error> {
     let estimated_gas = {
         crate::time!(
@@ -88,9 +127,11 @@ async fn estimate_fee_with_tx(
             }
 
         );
-        estimate_gas_with_tx(gas_config, grpc_address, tx, account).await
+        estimate_gas_with_tx(gas_config, grpc_address, tx, account, gas_model).await
     }?;
 
+    telemetry!(gas_estimates, chain_id, estimated_gas.source());
+
     let estimated_gas_amount = estimated_gas.get_amount();
 
     if estimated_gas_amount > gas_config.max_gas {
@@ -127,12 +168,31 @@ async fn estimate_fee_with_tx(
 ///
 /// If the batch is split in two TX-es, the second one will fail the simulation in `deliverTx` check.
 /// In this case we use the `default_gas` param.
+///
+/// If the `gas_model` is enabled, the simulation is skipped when the gas used by enough
+/// past transactions with the same types of messages was observed, and the gas is instead
+/// estimated from the model learned from them. Otherwise the gas used by the simulation
+/// is learned by the model.
 async fn estimate_gas_with_tx(
     gas_config: &GasConfig,
     grpc_address: &Uri,
     tx: Tx,
     account: &Account,
+    gas_model: &mut GasModelState,
 ) -> Result<EstimatedGas, Error> {
+    let settings = &gas_config.gas_model;
+
+    let msg_types = match &tx.body {
+        Some(body) if settings.enabled => gas_model::msg_types(&body.messages),
+        _ => Default::default(),
+    };
+
+    if let Some(gas) = gas_model.estimate(settings, &msg_types) {
+        debug!("estimated gas amount from the gas used by past txs: {gas}");
+
+        return Ok(EstimatedGas::Modelled(gas));
+    }
+
     let simulated_gas = send_tx_simulate(grpc_address, tx)
         .await
         .map(|sr| sr.gas_info);
@@ -146,6 +206,10 @@ async fn estimate_gas_with_tx(
                 gas_info.gas_used
             );
 
+            if settings.enabled {
+                gas_model.observe(&msg_types, gas_info.gas_used);
+            }
+
             Ok(EstimatedGas::Simulated(gas_info.gas_used))
         }
 
@@ -3,6 +3,7 @@ use ibc_relayer_types::core::ics24_host::identifier::{ChannelId, PortId};
 use ibc_relayer_types::signer::Signer;
 use tendermint_rpc::HttpClient;
 
+use crate::chain::cosmos::gas_model::GasModelState;
 use crate::chain::cosmos::query::account::get_or_fetch_account;
 use crate::chain::cosmos::query::fee::query_counterparty_payee;
 use crate::chain::cosmos::retry::send_tx_with_account_sequence_retry;
@@ -19,6 +20,7 @@ pub async fn maybe_register_counterparty_payee(
     tx_config: &TxConfig,
     key_pair: &Secp256k1KeyPair,
     m_account: &mut Option<Account>,
+    gas_model: &mut GasModelState,
     tx_memo: &Memo,
     channel_id: &ChannelId,
     port_id: &PortId,
@@ -51,12 +53,13 @@ pub async fn maybe_register_counterparty_payee(
                 tx_config,
                 key_pair,
                 account,
+                gas_model,
                 tx_memo,
                 &[message],
             )
             .await?;
 
-            wait_tx_succeed(
+            let response = wait_tx_succeed(
                 rpc_client,
                 &tx_config.rpc_address,
                 &tx_config.rpc_timeout,
@@ -64,6 +67,8 @@ pub async fn maybe_register_counterparty_payee(
             )
             .await?;
 
+            gas_model.record_tx_result(&tx_config.chain_id, &response);
+
             Ok(())
         }
     }
@@ -0,0 +1,351 @@
+//! Messages of the `feegrant` and `authz` modules, used to let a cold funding
+//! account, the fee granter, pay for the transactions of the relayer wallets.
+//!
+//! These messages are not part of `ibc-proto`, so the subset needed by
+//! Hermes is defined here.
+
+use ibc_proto::cosmos::base::query::v1beta1::{PageRequest, PageResponse};
+use ibc_proto::cosmos::base::v1beta1::Coin;
+use ibc_proto::google::protobuf::{Any, Duration, Timestamp};
+use prost::Message;
+
+use crate::account::{Authorization, Balance, FeeAllowance};
+use crate::error::Error;
+
+pub const BASIC_ALLOWANCE_TYPE_URL: &str = "/cosmos.feegrant.v1beta1.BasicAllowance";
+pub const PERIODIC_ALLOWANCE_TYPE_URL: &str = "/cosmos.feegrant.v1beta1.PeriodicAllowance";
+pub const ALLOWED_MSG_ALLOWANCE_TYPE_URL: &str = "/cosmos.feegrant.v1beta1.AllowedMsgAllowance";
+pub const MSG_GRANT_ALLOWANCE_TYPE_URL: &str = "/cosmos.feegrant.v1beta1.MsgGrantAllowance";
+pub const MSG_REVOKE_ALLOWANCE_TYPE_URL: &str = "/cosmos.feegrant.v1beta1.MsgRevokeAllowance";
+pub const MSG_EXEC_TYPE_URL: &str = "/cosmos.authz.v1beta1.MsgExec";
+
+// protobuf messages: https://github.com/cosmos/cosmos-sdk/blob/main/proto/cosmos/feegrant/v1beta1/feegrant.proto
+#[derive(Clone, PartialEq, Message)]
+pub struct BasicAllowance {
+    #[prost(message, repeated, tag = "1")]
+    pub spend_limit: Vec<Coin>,
+    #[prost(message, optional, tag = "2")]
+    pub expiration: Option<Timestamp>,
+}
+
+#[derive(Clone, PartialEq, Message)]
+pub struct PeriodicAllowance {
+    #[prost(message, optional, tag = "1")]
+    pub basic: Option<BasicAllowance>,
+    #[prost(message, optional, tag = "2")]
+    pub period: Option<Duration>,
+    #[prost(message, repeated, tag = "3")]
+    pub period_spend_limit: Vec<Coin>,
+    #[prost(message, repeated, tag = "4")]
+    pub period_can_spend: Vec<Coin>,
+    #[prost(message, optional, tag = "5")]
+    pub period_reset: Option<Timestamp>,
+}
+
+#[derive(Clone, PartialEq, Message)]
+pub struct AllowedMsgAllowance {
+    #[prost(message, optional, tag = "1")]
+    pub allowance: Option<Any>,
+    #[prost(string, repeated, tag = "2")]
+    pub allowed_messages: Vec<String>,
+}
+
+#[derive(Clone, PartialEq, Message)]
+pub struct FeeGrant {
+    #[prost(string, tag = "1")]
+    pub granter: String,
+    #[prost(string, tag = "2")]
+    pub grantee: String,
+    #[prost(message, optional, tag = "3")]
+    pub allowance: Option<Any>,
+}
+
+// protobuf messages: https://github.com/cosmos/cosmos-sdk/blob/main/proto/cosmos/feegrant/v1beta1/tx.proto
+#[derive(Clone, PartialEq, Message)]
+pub struct MsgGrantAllowance {
+    #[prost(string, tag = "1")]
+    pub granter: String,
+    #[prost(string, tag = "2")]
+    pub grantee: String,
+    #[prost(message, optional, tag = "3")]
+    pub allowance: Option<Any>,
+}
+
+#[derive(Clone, PartialEq, Message)]
+pub struct MsgRevokeAllowance {
+    #[prost(string, tag = "1")]
+    pub granter: String,
+    #[prost(string, tag = "2")]
+    pub grantee: String,
+}
+
+// protobuf messages: https://github.com/cosmos/cosmos-sdk/blob/main/proto/cosmos/feegrant/v1beta1/query.proto
+#[derive(Clone, PartialEq, Message)]
+pub struct QueryAllowanceRequest {
+    #[prost(string, tag = "1")]
+    pub granter: String,
+    #[prost(string, tag = "2")]
+    pub grantee: String,
+}
+
+#[derive(Clone, PartialEq, Message)]
+pub struct QueryAllowanceResponse {
+    #[prost(message, optional, tag = "1")]
+    pub allowance: Option<FeeGrant>,
+}
+
+// protobuf messages: https://github.com/cosmos/cosmos-sdk/blob/main/proto/cosmos/authz/v1beta1/authz.proto
+#[derive(Clone, PartialEq, Message)]
+pub struct AuthzGrant {
+    #[prost(message, optional, tag = "1")]
+    pub authorization: Option<Any>,
+    #[prost(message, optional, tag = "2")]
+    pub expiration: Option<Timestamp>,
+}
+
+// protobuf messages: https://github.com/cosmos/cosmos-sdk/blob/main/proto/cosmos/authz/v1beta1/tx.proto
+#[derive(Clone, PartialEq, Message)]
+pub struct MsgExec {
+    #[prost(string, tag = "1")]
+    pub grantee: String,
+    #[prost(message, repeated, tag = "2")]
+    pub msgs: Vec<Any>,
+}
+
+// protobuf messages: https://github.com/cosmos/cosmos-sdk/blob/main/proto/cosmos/authz/v1beta1/query.proto
+#[derive(Clone, PartialEq, Message)]
+pub struct QueryGrantsRequest {
+    #[prost(string, tag = "1")]
+    pub granter: String,
+    #[prost(string, tag = "2")]
+    pub grantee: String,
+    #[prost(string, tag = "3")]
+    pub msg_type_url: String,
+    #[prost(message, optional, tag = "4")]
+    pub pagination: Option<PageRequest>,
+}
+
+#[derive(Clone, PartialEq, Message)]
+pub struct QueryGrantsResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub grants: Vec<AuthzGrant>,
+    #[prost(message, optional, tag = "2")]
+    pub pagination: Option<PageResponse>,
+}
+
+/// Decodes a fee allowance, keeping only the overall spend limit and expiration
+/// of periodic and message-restricted allowances.
+pub fn decode_allowance(allowance: &Any) -> Result<FeeAllowance, Error> {
+    let decode_error = |e| Error::protobuf_decode(allowance.type_url.clone(), e);
+
+    match allowance.type_url.as_str() {
+        BASIC_ALLOWANCE_TYPE_URL => {
+            let basic = BasicAllowance::decode(allowance.value.as_slice()).map_err(decode_error)?;
+            basic_allowance(basic)
+        }
+        PERIODIC_ALLOWANCE_TYPE_URL => {
+            let periodic =
+                PeriodicAllowance::decode(allowance.value.as_slice()).map_err(decode_error)?;
+            basic_allowance(periodic.basic.unwrap_or_default())
+        }
+        ALLOWED_MSG_ALLOWANCE_TYPE_URL => {
+            let allowed =
+                AllowedMsgAllowance::decode(allowance.value.as_slice()).map_err(decode_error)?;
+            let inner = allowed
+                .allowance
+                .ok_or_else(|| Error::unknown_fee_allowance_type(allowance.type_url.clone()))?;
+            decode_allowance(&inner)
+        }
+        type_url => Err(Error::unknown_fee_allowance_type(type_url.to_string())),
+    }
+}
+
+fn basic_allowance(basic: BasicAllowance) -> Result<FeeAllowance, Error> {
+    Ok(FeeAllowance {
+        spend_limit: basic
+            .spend_limit
+            .into_iter()
+            .map(|coin| Balance {
+                amount: coin.amount,
+                denom: coin.denom,
+            })
+            .collect(),
+        expiration: basic.expiration.map(to_time).transpose()?,
+    })
+}
+
+/// Converts an authz grant for the given message type into an [`Authorization`].
+pub fn decode_authorization(grant: AuthzGrant, msg_type_url: &str) -> Result<Authorization, Error> {
+    Ok(Authorization {
+        msg_type_url: msg_type_url.to_string(),
+        expiration: grant.expiration.map(to_time).transpose()?,
+    })
+}
+
+/// Builds the messages granting the given allowance to the grantee.
+///
+/// An allowance cannot be granted over an existing one, which is
+/// therefore revoked first when `renew` is set.
+pub fn grant_allowance_msgs(
+    granter: &str,
+    grantee: &str,
+    allowance: &FeeAllowance,
+    renew: bool,
+) -> Result<Vec<Any>, Error> {
+    let basic = BasicAllowance {
+        spend_limit: allowance
+            .spend_limit
+            .iter()
+            .map(|balance| Coin {
+                denom: balance.denom.clone(),
+                amount: balance.amount.clone(),
+            })
+            .collect(),
+        expiration: allowance.expiration.map(to_timestamp),
+    };
+
+    let grant = MsgGrantAllowance {
+        granter: granter.to_string(),
+        grantee: grantee.to_string(),
+        allowance: Some(to_any(BASIC_ALLOWANCE_TYPE_URL, &basic)?),
+    };
+
+    let mut msgs = Vec::with_capacity(2);
+
+    if renew {
+        let revoke = MsgRevokeAllowance {
+            granter: granter.to_string(),
+            grantee: grantee.to_string(),
+        };
+
+        msgs.push(to_any(MSG_REVOKE_ALLOWANCE_TYPE_URL, &revoke)?);
+    }
+
+    msgs.push(to_any(MSG_GRANT_ALLOWANCE_TYPE_URL, &grant)?);
+
+    Ok(msgs)
+}
+
+/// Wraps the given messages in a `MsgExec`, so that they are executed by
+/// the grantee on behalf of the granter who authorized them.
+pub fn wrap_in_exec(grantee: &str, msgs: Vec<Any>) -> Result<Any, Error> {
+    let exec = MsgExec {
+        grantee: grantee.to_string(),
+        msgs,
+    };
+
+    to_any(MSG_EXEC_TYPE_URL, &exec)
+}
+
+fn to_any<M: Message>(type_url: &str, msg: &M) -> Result<Any, Error> {
+    let mut value = Vec::new();
+    msg.encode(&mut value)
+        .map_err(|e| Error::protobuf_encode(type_url.to_string(), e))?;
+
+    Ok(Any {
+        type_url: type_url.to_string(),
+        value,
+    })
+}
+
+fn to_time(timestamp: Timestamp) -> Result<tendermint::Time, Error> {
+    tendermint::Time::from_unix_timestamp(timestamp.seconds, timestamp.nanos as u32)
+        .map_err(Error::invalid_grant_expiration)
+}
+
+fn to_timestamp(time: tendermint::Time) -> Timestamp {
+    Timestamp {
+        seconds: time.unix_timestamp(),
+        nanos: (time.unix_timestamp_nanos() % 1_000_000_000) as i32,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn coin(amount: &str) -> Coin {
+        Coin {
+            denom: "stake".to_string(),
+            amount: amount.to_string(),
+        }
+    }
+
+    #[test]
+    fn decode_nested_allowance() {
+        let basic = BasicAllowance {
+            spend_limit: vec![coin("1000")],
+            expiration: Some(Timestamp {
+                seconds: 1_700_000_000,
+                nanos: 0,
+            }),
+        };
+
+        let periodic = PeriodicAllowance {
+            basic: Some(basic),
+            period: Some(Duration {
+                seconds: 3600,
+                nanos: 0,
+            }),
+            period_spend_limit: vec![coin("10")],
+            period_can_spend: vec![coin("5")],
+            period_reset: None,
+        };
+
+        let allowed = AllowedMsgAllowance {
+            allowance: Some(to_any(PERIODIC_ALLOWANCE_TYPE_URL, &periodic).unwrap()),
+            allowed_messages: vec!["/ibc.core.channel.v1.MsgRecvPacket".to_string()],
+        };
+
+        let allowance =
+            decode_allowance(&to_any(ALLOWED_MSG_ALLOWANCE_TYPE_URL, &allowed).unwrap()).unwrap();
+
+        assert_eq!(
+            allowance,
+            FeeAllowance {
+                spend_limit: vec![Balance {
+                    amount: "1000".to_string(),
+                    denom: "stake".to_string(),
+                }],
+                expiration: Some(tendermint::Time::from_unix_timestamp(1_700_000_000, 0).unwrap()),
+            }
+        );
+    }
+
+    #[test]
+    fn decode_unknown_allowance_fails() {
+        let any = Any {
+            type_url: "/cosmos.feegrant.v1beta1.UnknownAllowance".to_string(),
+            value: vec![],
+        };
+
+        assert!(decode_allowance(&any).is_err());
+    }
+
+    #[test]
+    fn build_renewal_in_exec() {
+        let allowance = FeeAllowance {
+            spend_limit: vec![],
+            expiration: Some(tendermint::Time::from_unix_timestamp(1_700_000_000, 5).unwrap()),
+        };
+
+        let msgs =
+            grant_allowance_msgs("cosmos1granter", "cosmos1grantee", &allowance, true).unwrap();
+        let types: Vec<_> = msgs.iter().map(|msg| msg.type_url.as_str()).collect();
+        assert_eq!(
+            types,
+            [MSG_REVOKE_ALLOWANCE_TYPE_URL, MSG_GRANT_ALLOWANCE_TYPE_URL]
+        );
+
+        let exec = wrap_in_exec("cosmos1primary", msgs).unwrap();
+        let exec = MsgExec::decode(exec.value.as_slice()).unwrap();
+        assert_eq!(exec.grantee, "cosmos1primary");
+
+        let grant = MsgGrantAllowance::decode(exec.msgs[1].value.as_slice()).unwrap();
+        assert_eq!(grant.granter, "cosmos1granter");
+        assert_eq!(
+            decode_allowance(&grant.allowance.unwrap()).unwrap(),
+            allowance
+        );
+    }
+}
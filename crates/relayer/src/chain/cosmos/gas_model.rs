@@ -0,0 +1,563 @@
+//! Estimation of the gas of transactions from a model of the gas used by each type of
+//! message, learned from the simulations of past transactions and from the gas they
+//! actually used once committed, so that most transactions need not be simulated.
+//!
+//! Each chain has its own model, and remembers the gas estimated for the transactions
+//! it broadcast until they are committed, in order to measure the accuracy of the
+//! estimates and to fall back on simulating the transactions whose estimates drift.
+
+use std::collections::{BTreeMap, VecDeque};
+
+use tendermint::Hash as TxHash;
+use tendermint_rpc::endpoint::broadcast::tx_sync::Response;
+use tendermint_rpc::endpoint::tx::Response as TxResponse;
+use tracing::{debug, warn};
+
+use ibc_proto::google::protobuf::Any;
+use ibc_relayer_types::core::ics24_host::identifier::ChainId;
+
+use crate::chain::cosmos::estimate::EstimatedGas;
+use crate::config::gas_model::GasModel;
+use crate::telemetry;
+
+/// How fast the model adapts to the gas used by new transactions, between 0 and 1.
+const LEARNING_RATE: f64 = 0.5;
+
+/// How many of the transactions awaiting confirmation have their estimated gas remembered.
+const MAX_TX_ESTIMATES: usize = 1024;
+
+/// The "out of gas" error is defined as the error code 11 in cosmos-sdk:
+/// https://github.com/cosmos/cosmos-sdk/blob/v0.44.0/types/errors/errors.go#L72-L73
+const OUT_OF_GAS_ERR: u32 = 11;
+
+/// The message of the "out of gas" error of cosmos-sdk, which ends the log of the
+/// transactions failing with it.
+const OUT_OF_GAS_LOG: &str = "out of gas";
+
+/// The number of messages of each type included in a transaction, by type URL.
+pub type MsgTypes = BTreeMap<String, u64>;
+
+pub fn msg_types(messages: &[Any]) -> MsgTypes {
+    let mut msg_types = MsgTypes::new();
+
+    for message in messages {
+        *msg_types.entry(message.type_url.clone()).or_default() += 1;
+    }
+
+    msg_types
+}
+
+/// The gas used by a type of message.
+#[derive(Clone, Debug, Default)]
+struct MsgGas {
+    gas: f64,
+    /// How many transactions including this type of message were observed
+    samples: u64,
+}
+
+/// The gas used by a transaction is modelled as a base gas, eg. for verifying its
+/// signature, plus the gas used by each of its messages, depending on their type.
+#[derive(Clone, Debug, Default)]
+pub struct MsgGasModel {
+    base: f64,
+    msgs: BTreeMap<String, MsgGas>,
+}
+
+impl MsgGasModel {
+    /// The gas estimated for a transaction with the given messages, or `None` if any
+    /// of their types was observed in fewer than `min_samples` transactions.
+    pub fn estimate(&self, msg_types: &MsgTypes, min_samples: u64) -> Option<u64> {
+        if msg_types.is_empty() {
+            return None;
+        }
+
+        let all_sampled = msg_types.keys().all(|type_url| {
+            self.msgs
+                .get(type_url)
+                .map_or(false, |msg| msg.samples >= min_samples)
+        });
+
+        if !all_sampled {
+            return None;
+        }
+
+        Some(self.predict(msg_types).max(0.0).round() as u64)
+    }
+
+    fn predict(&self, msg_types: &MsgTypes) -> f64 {
+        let msgs_gas: f64 = msg_types
+            .iter()
+            .filter_map(|(type_url, count)| {
+                self.msgs.get(type_url).map(|msg| msg.gas * *count as f64)
+            })
+            .sum();
+
+        self.base + msgs_gas
+    }
+
+    /// Learn from the gas used by a transaction with the given messages.
+    ///
+    /// The gas not accounted for by the types of messages already known is first
+    /// split evenly among the messages of new types, and the model is then updated
+    /// with a normalized least mean squares step.
+    pub fn observe(&mut self, msg_types: &MsgTypes, gas_used: u64) {
+        if msg_types.is_empty() {
+            return;
+        }
+
+        let gas_used = gas_used as f64;
+
+        let new_msgs: u64 = msg_types
+            .iter()
+            .filter(|(type_url, _)| !self.msgs.contains_key(*type_url))
+            .map(|(_, count)| count)
+            .sum();
+
+        if new_msgs > 0 {
+            let gas = ((gas_used - self.predict(msg_types)) / new_msgs as f64).max(0.0);
+
+            for type_url in msg_types.keys() {
+                self.msgs
+                    .entry(type_url.clone())
+                    .or_insert(MsgGas { gas, samples: 0 });
+            }
+        }
+
+        let error = gas_used - self.predict(msg_types);
+        let norm = 1.0 + msg_types.values().map(|c| (c * c) as f64).sum::<f64>();
+        let step = LEARNING_RATE * error / norm;
+
+        self.base += step;
+
+        for (type_url, count) in msg_types {
+            if let Some(msg) = self.msgs.get_mut(type_url) {
+                msg.gas += step * *count as f64;
+                msg.samples += 1;
+            }
+        }
+    }
+
+    /// Discard the samples of the given types of messages, so that the transactions
+    /// including them are simulated again until enough new samples are observed.
+    pub fn invalidate(&mut self, msg_types: &MsgTypes) {
+        for type_url in msg_types.keys() {
+            if let Some(msg) = self.msgs.get_mut(type_url) {
+                msg.samples = 0;
+            }
+        }
+    }
+}
+
+/// The gas estimated for a transaction which was broadcast.
+#[derive(Clone, Debug)]
+struct TxEstimate {
+    msg_types: MsgTypes,
+    gas: u64,
+    source: &'static str,
+    max_drift: f64,
+}
+
+/// The model of the gas used by the messages of a chain, and the gas estimated
+/// for the transactions broadcast to it which are awaiting confirmation.
+#[derive(Clone, Debug, Default)]
+pub struct GasModelState {
+    model: MsgGasModel,
+    tx_estimates: BTreeMap<TxHash, TxEstimate>,
+    /// The hashes of the transactions in `tx_estimates`, from the oldest to the newest
+    tx_hashes: VecDeque<TxHash>,
+}
+
+impl GasModelState {
+    /// Estimate the gas of a transaction with the given messages, if enough
+    /// transactions including the same types of messages were observed.
+    pub fn estimate(&self, settings: &GasModel, msg_types: &MsgTypes) -> Option<u64> {
+        self.model.estimate(msg_types, settings.min_samples)
+    }
+
+    /// Learn from the gas used by a transaction with the given messages.
+    pub fn observe(&mut self, msg_types: &MsgTypes, gas_used: u64) {
+        self.model.observe(msg_types, gas_used);
+    }
+
+    /// Remember the gas estimated for a transaction which passed CheckTx, to compare it
+    /// with the gas the transaction uses once committed, or fall back on simulating the
+    /// transactions with the same types of messages if it ran out of gas in CheckTx.
+    pub fn record_broadcast(
+        &mut self,
+        chain_id: &ChainId,
+        settings: &GasModel,
+        response: &Response,
+        messages: &[Any],
+        estimated_gas: &EstimatedGas,
+    ) {
+        let msg_types = msg_types(messages);
+
+        // The response to a broadcast has no codespace, so the error is
+        // told apart from the ones of other modules with the same code by its log.
+        if response.code.is_err() {
+            if response.code.value() == OUT_OF_GAS_ERR
+                && response.log.ends_with(OUT_OF_GAS_LOG)
+                && estimated_gas.source() == EstimatedGas::MODEL
+            {
+                warn!(
+                    chain = %chain_id,
+                    estimated = estimated_gas.get_amount(),
+                    "tx ran out of gas in CheckTx, simulating txs with the same types of messages again"
+                );
+
+                self.model.invalidate(&msg_types);
+            }
+
+            return;
+        }
+
+        let estimate = TxEstimate {
+            msg_types,
+            gas: estimated_gas.get_amount(),
+            source: estimated_gas.source(),
+            max_drift: settings.max_drift,
+        };
+
+        // A transaction broadcast again keeps its hash, so only its estimate is replaced
+        if self.tx_estimates.insert(response.hash, estimate).is_some() {
+            return;
+        }
+
+        if self.tx_hashes.len() == MAX_TX_ESTIMATES {
+            if let Some(oldest) = self.tx_hashes.pop_front() {
+                self.tx_estimates.remove(&oldest);
+            }
+        }
+
+        self.tx_hashes.push_back(response.hash);
+    }
+
+    /// Learn from the gas used by a committed transaction whose estimated gas was recorded,
+    /// and fall back on simulating the transactions including the same types of messages
+    /// if it was estimated from the model and drifted too much, or ran out of gas.
+    pub fn record_tx_result(&mut self, chain_id: &ChainId, response: &TxResponse) {
+        let Some(estimate) = self.tx_estimates.remove(&response.hash) else {
+            return;
+        };
+
+        self.tx_hashes.retain(|hash| *hash != response.hash);
+
+        let result = &response.tx_result;
+
+        // The gas used by failed transactions is not representative,
+        // as their execution stopped at the failure.
+        if result.code.is_err() {
+            if result.codespace == "sdk" && result.code.value() == OUT_OF_GAS_ERR {
+                warn!(
+                    chain = %chain_id,
+                    tx_hash = %response.hash,
+                    estimated = estimate.gas,
+                    "tx ran out of gas, simulating txs with the same types of messages again"
+                );
+
+                self.model.invalidate(&estimate.msg_types);
+            }
+
+            return;
+        }
+
+        let gas_used = u64::try_from(result.gas_used).unwrap_or_default();
+        let error = relative_error(estimate.gas, gas_used);
+
+        telemetry!(
+            gas_estimate_error,
+            chain_id,
+            estimate.source,
+            (error * 100.0).round() as u64
+        );
+
+        if estimate.source == EstimatedGas::MODEL && error > estimate.max_drift {
+            warn!(
+                chain = %chain_id,
+                tx_hash = %response.hash,
+                estimated = estimate.gas,
+                used = gas_used,
+                "gas estimated from the model drifted from the gas used, \
+                simulating txs with the same types of messages again"
+            );
+
+            self.model.invalidate(&estimate.msg_types);
+        } else {
+            debug!(
+                chain = %chain_id,
+                tx_hash = %response.hash,
+                estimated = estimate.gas,
+                used = gas_used,
+                "learning from the gas used by tx"
+            );
+        }
+
+        self.model.observe(&estimate.msg_types, gas_used);
+    }
+}
+
+/// The relative difference between the estimated and the actual gas.
+fn relative_error(estimated: u64, used: u64) -> f64 {
+    if used == 0 {
+        return 0.0;
+    }
+
+    (estimated as f64 - used as f64).abs() / used as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use tendermint::abci::types::ExecTxResult;
+    use tendermint::abci::Code;
+    use test_log::test;
+
+    const UPDATE_CLIENT: &str = "/ibc.core.client.v1.MsgUpdateClient";
+    const RECV_PACKET: &str = "/ibc.core.channel.v1.MsgRecvPacket";
+
+    fn msgs(counts: &[(&str, u64)]) -> MsgTypes {
+        counts
+            .iter()
+            .map(|(type_url, count)| (type_url.to_string(), *count))
+            .collect()
+    }
+
+    /// The gas used by a tx: a base of 60k, 100k per client update and 50k per packet.
+    fn gas_used(msg_types: &MsgTypes) -> u64 {
+        60_000
+            + msg_types.get(UPDATE_CLIENT).unwrap_or(&0) * 100_000
+            + msg_types.get(RECV_PACKET).unwrap_or(&0) * 50_000
+    }
+
+    #[test]
+    fn counts_msg_types() {
+        let messages = [RECV_PACKET, UPDATE_CLIENT, RECV_PACKET].map(|type_url| Any {
+            type_url: type_url.to_string(),
+            value: vec![],
+        });
+
+        assert_eq!(
+            msg_types(&messages),
+            msgs(&[(UPDATE_CLIENT, 1), (RECV_PACKET, 2)])
+        );
+    }
+
+    #[test]
+    fn requires_min_samples() {
+        let mut model = MsgGasModel::default();
+        let batch = msgs(&[(UPDATE_CLIENT, 1), (RECV_PACKET, 2)]);
+
+        model.observe(&batch, gas_used(&batch));
+        model.observe(&batch, gas_used(&batch));
+
+        assert_eq!(model.estimate(&batch, 3), None);
+        assert_eq!(model.estimate(&batch, 2), Some(gas_used(&batch)));
+
+        // Unknown types of messages are never estimated
+        let other = msgs(&[(UPDATE_CLIENT, 1), ("/ibc.core.channel.v1.MsgTimeout", 1)]);
+        assert_eq!(model.estimate(&other, 1), None);
+    }
+
+    #[test]
+    fn learns_gas_per_msg_type() {
+        let mut model = MsgGasModel::default();
+
+        let batches = [
+            msgs(&[(UPDATE_CLIENT, 1), (RECV_PACKET, 1)]),
+            msgs(&[(UPDATE_CLIENT, 1), (RECV_PACKET, 5)]),
+            msgs(&[(RECV_PACKET, 3)]),
+            msgs(&[(UPDATE_CLIENT, 1), (RECV_PACKET, 10)]),
+        ];
+
+        for _ in 0..200 {
+            for batch in &batches {
+                model.observe(batch, gas_used(batch));
+            }
+        }
+
+        let batch = msgs(&[(UPDATE_CLIENT, 1), (RECV_PACKET, 7)]);
+        let estimated = model.estimate(&batch, 10).unwrap();
+
+        assert!(relative_error(estimated, gas_used(&batch)) < 0.01);
+    }
+
+    #[test]
+    fn invalidate_discards_samples() {
+        let mut model = MsgGasModel::default();
+        let batch = msgs(&[(UPDATE_CLIENT, 1), (RECV_PACKET, 2)]);
+
+        model.observe(&batch, gas_used(&batch));
+        assert!(model.estimate(&batch, 1).is_some());
+
+        model.invalidate(&msgs(&[(RECV_PACKET, 1)]));
+        assert_eq!(model.estimate(&batch, 1), None);
+        assert!(model.estimate(&msgs(&[(UPDATE_CLIENT, 1)]), 1).is_some());
+
+        model.observe(&batch, gas_used(&batch));
+        assert!(model.estimate(&batch, 1).is_some());
+    }
+
+    fn tx_hash(i: u64) -> TxHash {
+        let mut bytes = [0; 32];
+        bytes[..8].copy_from_slice(&i.to_be_bytes());
+
+        TxHash::Sha256(bytes)
+    }
+
+    fn broadcast(hash: TxHash) -> Response {
+        Response {
+            code: Code::Ok,
+            data: Default::default(),
+            log: String::new(),
+            hash,
+        }
+    }
+
+    fn rejected(code: u32, log: &str) -> Response {
+        Response {
+            code: Code::Err(code.try_into().unwrap()),
+            log: log.to_string(),
+            ..broadcast(tx_hash(0))
+        }
+    }
+
+    fn committed(hash: TxHash, gas_used: u64) -> TxResponse {
+        TxResponse {
+            hash,
+            height: 1_u32.into(),
+            index: 0,
+            tx_result: ExecTxResult {
+                gas_used: gas_used as i64,
+                ..Default::default()
+            },
+            tx: vec![],
+            proof: None,
+        }
+    }
+
+    fn settings() -> GasModel {
+        GasModel {
+            enabled: true,
+            min_samples: 2,
+            max_drift: 0.1,
+        }
+    }
+
+    #[test]
+    fn drifting_estimate_falls_back_on_simulation() {
+        let chain_id = ChainId::from_string("chain-0");
+        let mut state = GasModelState::default();
+
+        let messages = [UPDATE_CLIENT, RECV_PACKET, RECV_PACKET].map(|type_url| Any {
+            type_url: type_url.to_string(),
+            value: vec![],
+        });
+        let batch = msg_types(&messages);
+
+        state.observe(&batch, gas_used(&batch));
+        state.observe(&batch, gas_used(&batch));
+        let estimated = state.estimate(&settings(), &batch).unwrap();
+
+        state.record_broadcast(
+            &chain_id,
+            &settings(),
+            &broadcast(tx_hash(1)),
+            &messages,
+            &EstimatedGas::Modelled(estimated),
+        );
+
+        // The result of a tx whose estimate was not recorded is ignored
+        state.record_tx_result(&chain_id, &committed(tx_hash(2), estimated * 2));
+        assert!(state.estimate(&settings(), &batch).is_some());
+
+        state.record_tx_result(&chain_id, &committed(tx_hash(1), estimated * 2));
+        assert_eq!(state.estimate(&settings(), &batch), None);
+        assert!(state.tx_estimates.is_empty());
+        assert!(state.tx_hashes.is_empty());
+    }
+
+    #[test]
+    fn tx_estimates_are_bounded() {
+        let chain_id = ChainId::from_string("chain-0");
+        let mut state = GasModelState::default();
+
+        for i in 0..=MAX_TX_ESTIMATES as u64 {
+            state.record_broadcast(
+                &chain_id,
+                &settings(),
+                &broadcast(tx_hash(i)),
+                &[],
+                &EstimatedGas::Simulated(100_000),
+            );
+        }
+
+        assert_eq!(state.tx_estimates.len(), MAX_TX_ESTIMATES);
+        assert!(!state.tx_estimates.contains_key(&tx_hash(0)));
+        assert!(state
+            .tx_estimates
+            .contains_key(&tx_hash(MAX_TX_ESTIMATES as u64)));
+    }
+
+    #[test]
+    fn rebroadcast_tx_is_recorded_once() {
+        let chain_id = ChainId::from_string("chain-0");
+        let mut state = GasModelState::default();
+
+        for gas in [100_000, 120_000] {
+            state.record_broadcast(
+                &chain_id,
+                &settings(),
+                &broadcast(tx_hash(1)),
+                &[],
+                &EstimatedGas::Simulated(gas),
+            );
+        }
+
+        assert_eq!(state.tx_hashes.len(), 1);
+        assert_eq!(state.tx_estimates[&tx_hash(1)].gas, 120_000);
+
+        state.record_tx_result(&chain_id, &committed(tx_hash(1), 120_000));
+        assert!(state.tx_estimates.is_empty());
+        assert!(state.tx_hashes.is_empty());
+    }
+
+    #[test]
+    fn out_of_gas_in_check_tx_falls_back_on_simulation() {
+        let chain_id = ChainId::from_string("chain-0");
+        let mut state = GasModelState::default();
+
+        let messages = [UPDATE_CLIENT, RECV_PACKET].map(|type_url| Any {
+            type_url: type_url.to_string(),
+            value: vec![],
+        });
+        let batch = msg_types(&messages);
+
+        state.observe(&batch, gas_used(&batch));
+        state.observe(&batch, gas_used(&batch));
+        let estimated = state.estimate(&settings(), &batch).unwrap();
+
+        // An error with the same code from another codespace is not an out of gas error
+        state.record_broadcast(
+            &chain_id,
+            &settings(),
+            &rejected(OUT_OF_GAS_ERR, "invalid packet: packet already received"),
+            &messages,
+            &EstimatedGas::Modelled(estimated),
+        );
+        assert!(state.estimate(&settings(), &batch).is_some());
+
+        state.record_broadcast(
+            &chain_id,
+            &settings(),
+            &rejected(
+                OUT_OF_GAS_ERR,
+                "out of gas in location: WriteFlat; gasWanted: 100, gasUsed: 200: out of gas",
+            ),
+            &messages,
+            &EstimatedGas::Modelled(estimated),
+        );
+        assert_eq!(state.estimate(&settings(), &batch), None);
+        assert!(state.tx_hashes.is_empty());
+    }
+}
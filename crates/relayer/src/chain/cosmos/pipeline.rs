@@ -0,0 +1,378 @@
+//! Pipelined submission of transactions, where the sequence of the account is
+//! tracked locally so that several transactions can be broadcast within the same
+//! block, without waiting for the previous ones to be committed.
+
+use core::mem;
+use core::time::Duration;
+use std::collections::VecDeque;
+use std::time::Instant;
+
+use ibc_proto::google::protobuf::Any;
+use tendermint::abci::Code;
+use tendermint::Hash as TxHash;
+use tendermint_rpc::endpoint::broadcast::tx_sync::Response;
+use tendermint_rpc::HttpClient;
+use tokio::time::sleep;
+use tracing::{debug, error, instrument, warn};
+
+use crate::chain::cosmos::estimate::EstimatedGas;
+use crate::chain::cosmos::gas_model::GasModelState;
+use crate::chain::cosmos::query::account::{query_account, refresh_account};
+use crate::chain::cosmos::retry::INCORRECT_ACCOUNT_SEQUENCE_ERR;
+use crate::chain::cosmos::tx::estimate_fee_and_send_tx;
+use crate::chain::cosmos::types::account::{Account, AccountSequence};
+use crate::chain::cosmos::types::config::TxConfig;
+use crate::config::types::Memo;
+use crate::error::{parse_sequences_in_mismatch_error_message, Error, ErrorDetail};
+use crate::keyring::{Secp256k1KeyPair, SigningKeyPair};
+use crate::sdk_error::sdk_error_from_tx_sync_error_code;
+use crate::telemetry;
+
+/// How many of the hashes of the Txs dropped from the mempool are remembered.
+const MAX_DROPPED_TXS: usize = 1024;
+
+/// Delay before querying the sequence committed for the account again,
+/// while waiting for the Txs in flight to be committed.
+const IN_FLIGHT_POLL_DELAY: Duration = Duration::from_millis(300);
+
+/// A Tx which passed CheckTx but is not known to be committed yet.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct InFlightTx {
+    pub hash: TxHash,
+    pub sequence: AccountSequence,
+}
+
+/// The Txs broadcast by an account which are in flight,
+/// and the ones recently dropped from the mempool.
+#[derive(Clone, Debug, Default)]
+pub struct InFlightTxs {
+    in_flight: VecDeque<InFlightTx>,
+    dropped: VecDeque<TxHash>,
+}
+
+impl InFlightTxs {
+    pub fn len(&self) -> usize {
+        self.in_flight.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.in_flight.is_empty()
+    }
+
+    pub fn record_broadcast(&mut self, hash: TxHash, sequence: AccountSequence) {
+        self.in_flight.push_back(InFlightTx { hash, sequence });
+    }
+
+    /// Forget the Txs which were committed, given the next sequence of the account.
+    pub fn prune(&mut self, committed: AccountSequence) {
+        self.in_flight.retain(|tx| tx.sequence >= committed);
+    }
+
+    /// Record the Txs in flight with the given sequence or a higher one
+    /// as dropped from the mempool, and return their hashes.
+    pub fn drop_from(&mut self, sequence: AccountSequence) -> Vec<TxHash> {
+        let (dropped, in_flight): (VecDeque<_>, VecDeque<_>) = mem::take(&mut self.in_flight)
+            .into_iter()
+            .partition(|tx| tx.sequence >= sequence);
+
+        self.in_flight = in_flight;
+
+        let hashes: Vec<_> = dropped.into_iter().map(|tx| tx.hash).collect();
+
+        for hash in &hashes {
+            if self.dropped.len() == MAX_DROPPED_TXS {
+                self.dropped.pop_front();
+            }
+
+            self.dropped.push_back(*hash);
+        }
+
+        hashes
+    }
+
+    /// The given Txs which were dropped from the mempool.
+    pub fn dropped(&self, tx_hashes: &[TxHash]) -> Vec<TxHash> {
+        tx_hashes
+            .iter()
+            .filter(|hash| self.dropped.contains(hash))
+            .copied()
+            .collect()
+    }
+}
+
+/// Broadcast the given messages as a Tx with the next sequence of the account,
+/// without waiting for the Txs in flight to be committed, unless there are already
+/// `max_in_flight` of them.
+///
+/// On an account sequence mismatch, the local sequence is reset to the one expected
+/// by the full node and the Tx is broadcast again once. If the full node expects a
+/// lower sequence than the local one, the Txs in flight from that sequence on were
+/// evicted from the mempool or invalidated by a failed CheckTx, and are recorded as
+/// dropped so that their messages can be resubmitted.
+#[instrument(
+    name = "send_tx_pipelined",
+    level = "error",
+    skip_all,
+    fields(
+        chain = %config.chain_id,
+        account.sequence = %account.sequence,
+        in_flight = %in_flight.len(),
+    ),
+)]
+pub async fn send_tx_pipelined(
+    rpc_client: &HttpClient,
+    config: &TxConfig,
+    key_pair: &Secp256k1KeyPair,
+    account: &mut Account,
+    in_flight: &mut InFlightTxs,
+    gas_model: &mut GasModelState,
+    tx_memo: &Memo,
+    messages: &[Any],
+) -> Result<Response, Error> {
+    wait_for_in_flight_txs(config, key_pair, in_flight).await?;
+
+    let mut result = estimate_fee_and_send_tx(
+        rpc_client, config, key_pair, account, gas_model, tx_memo, messages,
+    )
+    .await;
+
+    if let Some(message) = account_sequence_mismatch(&result) {
+        warn!(
+            %message,
+            "failed to send tx because of a mismatched account sequence number, \
+            resetting account sequence number and retrying once"
+        );
+
+        telemetry!(
+            broadcast_errors,
+            &account.address.to_string(),
+            INCORRECT_ACCOUNT_SEQUENCE_ERR,
+            &message,
+        );
+
+        reset_account_sequence(config, key_pair, account, in_flight, &message).await?;
+
+        result = estimate_fee_and_send_tx(
+            rpc_client, config, key_pair, account, gas_model, tx_memo, messages,
+        )
+        .await;
+    }
+
+    let (response, estimated_gas) = result?;
+
+    match response.code {
+        Code::Ok => {
+            in_flight.record_broadcast(response.hash, account.sequence);
+            account.sequence.increment_mut();
+
+            debug!(
+                ?response,
+                account.sequence.new = %account.sequence,
+                "tx was successfully broadcasted, \
+                increasing account sequence number"
+            );
+
+            telemetry!(messages_submitted, &config.chain_id, messages.len() as u64);
+        }
+
+        // The sequence is not consumed since CheckTx failed,
+        // so the Txs in flight are not affected.
+        Code::Err(code) => {
+            error!(
+                ?response,
+                diagnostic = ?sdk_error_from_tx_sync_error_code(code.into(), estimated_gas),
+                "failed to broadcast tx with unrecoverable error"
+            );
+
+            telemetry!(
+                broadcast_errors,
+                &account.address.to_string(),
+                code.into(),
+                &response.log
+            );
+        }
+    }
+
+    Ok(response)
+}
+
+/// Wait for the number of Txs in flight to get below `max_in_flight`, by querying
+/// the sequence committed for the account.
+///
+/// The Txs in flight are not considered as dropped when they take long to be committed,
+/// since they may still be in the mempool. Instead, Hermes keeps waiting as long as some
+/// of them get committed, and if none is within `rpc_timeout`, it stops waiting without
+/// resetting the local sequence. The next Tx is then broadcast on top of the Txs in
+/// flight, and if they are gone from the mempool, CheckTx fails with an account sequence
+/// mismatch which records them as dropped.
+async fn wait_for_in_flight_txs(
+    config: &TxConfig,
+    key_pair: &Secp256k1KeyPair,
+    in_flight: &mut InFlightTxs,
+) -> Result<(), Error> {
+    let max_in_flight = config.tx_pipeline.max_in_flight;
+
+    if in_flight.len() < max_in_flight {
+        return Ok(());
+    }
+
+    let mut start = Instant::now();
+    let mut pending = in_flight.len();
+
+    loop {
+        let committed = query_account(&config.grpc_address, &key_pair.account()).await?;
+        let committed = AccountSequence::new(committed.sequence);
+
+        in_flight.prune(committed);
+
+        if in_flight.len() < max_in_flight {
+            return Ok(());
+        }
+
+        if in_flight.len() < pending {
+            pending = in_flight.len();
+            start = Instant::now();
+        } else if start.elapsed() > config.rpc_timeout {
+            warn!(
+                in_flight = in_flight.len(),
+                account.sequence.committed = %committed,
+                "txs in flight were not committed in time, \
+                broadcasting the next tx to check whether they are still in the mempool"
+            );
+
+            return Ok(());
+        }
+
+        sleep(IN_FLIGHT_POLL_DELAY).await;
+    }
+}
+
+/// Reset the local sequence of the account to the one expected by the full node,
+/// as reported in the given account sequence mismatch error message.
+async fn reset_account_sequence(
+    config: &TxConfig,
+    key_pair: &Secp256k1KeyPair,
+    account: &mut Account,
+    in_flight: &mut InFlightTxs,
+    message: &str,
+) -> Result<(), Error> {
+    match parse_sequences_in_mismatch_error_message(message) {
+        // The full node is behind the local sequence, so the Txs in flight
+        // from the expected sequence on are not in the mempool anymore.
+        Some((expected, got)) if expected < got => {
+            let expected = AccountSequence::new(expected);
+            let dropped = in_flight.drop_from(expected);
+
+            warn!(
+                dropped = dropped.len(),
+                "txs in flight were dropped from the mempool, \
+                resubmitting from account sequence number {expected}"
+            );
+
+            telemetry!(
+                dropped_txs,
+                &config.chain_id,
+                &account.address.to_string(),
+                dropped.len() as u64
+            );
+
+            account.sequence = expected;
+        }
+
+        // The full node is ahead of the local sequence,
+        // eg. because the account is used by another agent.
+        Some((expected, _)) => {
+            account.sequence = AccountSequence::new(expected);
+        }
+
+        None => {
+            refresh_account(&config.grpc_address, &key_pair.account(), account).await?;
+            in_flight.prune(account.sequence);
+        }
+    }
+
+    Ok(())
+}
+
+/// The error message, if the Tx was rejected because of an account sequence mismatch,
+/// either when simulating it or by CheckTx.
+fn account_sequence_mismatch(result: &Result<(Response, EstimatedGas), Error>) -> Option<String> {
+    match result {
+        Ok((response, _)) if response.code == Code::from(INCORRECT_ACCOUNT_SEQUENCE_ERR) => {
+            Some(response.log.to_string())
+        }
+        Err(e) => match e.detail() {
+            ErrorDetail::GrpcStatus(detail)
+                if detail.is_account_sequence_mismatch_that_requires_refresh() =>
+            {
+                Some(detail.status.message().to_string())
+            }
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use test_log::test;
+
+    fn tx_hash(i: u64) -> TxHash {
+        let mut bytes = [0; 32];
+        bytes[..8].copy_from_slice(&i.to_be_bytes());
+
+        TxHash::Sha256(bytes)
+    }
+
+    fn in_flight_txs(sequences: core::ops::Range<u64>) -> InFlightTxs {
+        let mut in_flight = InFlightTxs::default();
+
+        for sequence in sequences {
+            in_flight.record_broadcast(tx_hash(sequence), AccountSequence::new(sequence));
+        }
+
+        in_flight
+    }
+
+    #[test]
+    fn prune_committed_txs() {
+        let mut in_flight = in_flight_txs(10..15);
+
+        in_flight.prune(AccountSequence::new(12));
+        assert_eq!(in_flight.len(), 3);
+
+        in_flight.prune(AccountSequence::new(15));
+        assert!(in_flight.is_empty());
+        assert!(in_flight.dropped(&[tx_hash(10), tx_hash(14)]).is_empty());
+    }
+
+    #[test]
+    fn drop_evicted_txs() {
+        let mut in_flight = in_flight_txs(10..15);
+
+        let dropped = in_flight.drop_from(AccountSequence::new(12));
+        assert_eq!(dropped, vec![tx_hash(12), tx_hash(13), tx_hash(14)]);
+        assert_eq!(in_flight.len(), 2);
+
+        // The local sequence is reset to 12, so the resubmitted Txs reuse the same sequences
+        in_flight.record_broadcast(tx_hash(20), AccountSequence::new(12));
+
+        assert_eq!(
+            in_flight.dropped(&[tx_hash(11), tx_hash(13), tx_hash(20)]),
+            vec![tx_hash(13)]
+        );
+    }
+
+    #[test]
+    fn dropped_txs_are_bounded() {
+        let mut in_flight = in_flight_txs(0..MAX_DROPPED_TXS as u64 + 1);
+
+        let dropped = in_flight.drop_from(AccountSequence::new(0));
+        assert_eq!(dropped.len(), MAX_DROPPED_TXS + 1);
+
+        assert!(in_flight.dropped(&dropped[..1]).is_empty());
+        assert_eq!(in_flight.dropped(&dropped[1..]).len(), MAX_DROPPED_TXS);
+    }
+}
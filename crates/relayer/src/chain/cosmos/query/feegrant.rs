@@ -0,0 +1,108 @@
+use http::uri::{PathAndQuery, Uri};
+use tonic::codec::ProstCodec;
+use tonic::transport::Endpoint;
+use tonic::Code;
+
+use crate::account::{Authorization, FeeAllowance};
+use crate::chain::cosmos::feegrant::{
+    decode_allowance, decode_authorization, QueryAllowanceRequest, QueryAllowanceResponse,
+    QueryGrantsRequest, QueryGrantsResponse,
+};
+use crate::config::default::max_grpc_decoding_size;
+use crate::error::Error;
+
+/// Uses the GRPC client to retrieve the fee allowance given by `granter` to `grantee`,
+/// if any.
+pub async fn query_fee_allowance(
+    grpc_address: &Uri,
+    granter: &str,
+    grantee: &str,
+) -> Result<Option<FeeAllowance>, Error> {
+    let request = QueryAllowanceRequest {
+        granter: granter.to_string(),
+        grantee: grantee.to_string(),
+    };
+
+    let response: Option<QueryAllowanceResponse> = unary(
+        grpc_address,
+        "/cosmos.feegrant.v1beta1.Query/Allowance",
+        request,
+        "query_fee_allowance",
+    )
+    .await?;
+
+    match response
+        .and_then(|response| response.allowance)
+        .and_then(|grant| grant.allowance)
+    {
+        Some(allowance) => decode_allowance(&allowance).map(Some),
+        None => Ok(None),
+    }
+}
+
+/// Uses the GRPC client to retrieve the authorization given by `granter` to `grantee`
+/// to execute messages of the given type, if any.
+pub async fn query_authorization(
+    grpc_address: &Uri,
+    granter: &str,
+    grantee: &str,
+    msg_type_url: &str,
+) -> Result<Option<Authorization>, Error> {
+    let request = QueryGrantsRequest {
+        granter: granter.to_string(),
+        grantee: grantee.to_string(),
+        msg_type_url: msg_type_url.to_string(),
+        pagination: None,
+    };
+
+    let response: Option<QueryGrantsResponse> = unary(
+        grpc_address,
+        "/cosmos.authz.v1beta1.Query/Grants",
+        request,
+        "query_authorization",
+    )
+    .await?;
+
+    response
+        .and_then(|response| response.grants.into_iter().next())
+        .map(|grant| decode_authorization(grant, msg_type_url))
+        .transpose()
+}
+
+/// Performs a unary gRPC call for a service without generated client in `ibc-proto`.
+///
+/// Returns `None` if the queried item is not found.
+async fn unary<Req, Res>(
+    grpc_address: &Uri,
+    path: &'static str,
+    request: Req,
+    query: &str,
+) -> Result<Option<Res>, Error>
+where
+    Req: prost::Message + Send + Sync + 'static,
+    Res: prost::Message + Default + Send + Sync + 'static,
+{
+    let channel = Endpoint::from(grpc_address.clone())
+        .connect()
+        .await
+        .map_err(Error::grpc_transport)?;
+
+    let mut client = tonic::client::Grpc::new(channel)
+        .max_decoding_message_size(max_grpc_decoding_size().get_bytes() as usize);
+
+    client.ready().await.map_err(Error::grpc_transport)?;
+
+    let result = client
+        .unary(
+            tonic::Request::new(request),
+            PathAndQuery::from_static(path),
+            ProstCodec::default(),
+        )
+        .await;
+
+    match result {
+        Ok(response) => Ok(Some(response.into_inner())),
+        Err(e) if e.code() == Code::NotFound => Ok(None),
+        Err(e) => Err(Error::grpc_status(e, query.to_owned())),
+    }
+}
@@ -307,7 +307,7 @@ fn packet_from_tx_search_response(
         .events
         .iter()
         .find_map(|ev| filter_matching_event(ev, request, &[seq]))
-        .map(|ibc_event| IbcEventWithHeight::new(ibc_event, height)))
+        .map(|ibc_event| IbcEventWithHeight::new(ibc_event, height).with_tx_hash(response.hash)))
 }
 
 /// Returns the given event wrapped in `Some` if the event data
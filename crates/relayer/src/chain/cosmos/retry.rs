@@ -8,6 +8,7 @@ use tendermint::abci::Code;
 use tendermint_rpc::endpoint::broadcast::tx_sync::Response;
 use tendermint_rpc::HttpClient;
 
+use crate::chain::cosmos::gas_model::GasModelState;
 use crate::chain::cosmos::query::account::refresh_account;
 use crate::chain::cosmos::tx::estimate_fee_and_send_tx;
 use crate::chain::cosmos::types::account::Account;
@@ -23,7 +24,7 @@ const ACCOUNT_SEQUENCE_RETRY_DELAY: u64 = 300;
 
 // The error "incorrect account sequence" is defined as the unique error code 32 in cosmos-sdk:
 // https://github.com/cosmos/cosmos-sdk/blob/v0.44.0/types/errors/errors.go#L115-L117
-const INCORRECT_ACCOUNT_SEQUENCE_ERR: u32 = 32;
+pub(crate) const INCORRECT_ACCOUNT_SEQUENCE_ERR: u32 = 32;
 
 /// Try to `send_tx` and retry on account sequence error with re-cached account s.n.
 /// An account sequence error can occur if the account sequence that
@@ -50,6 +51,7 @@ pub async fn send_tx_with_account_sequence_retry(
     config: &TxConfig,
     key_pair: &Secp256k1KeyPair,
     account: &mut Account,
+    gas_model: &mut GasModelState,
     tx_memo: &Memo,
     messages: &[Any],
 ) -> Result<Response, Error> {
@@ -63,7 +65,7 @@ pub async fn send_tx_with_account_sequence_retry(
     let _message_count = messages.len() as u64;
 
     let response = do_send_tx_with_account_sequence_retry(
-        rpc_client, config, key_pair, account, tx_memo, messages,
+        rpc_client, config, key_pair, account, gas_model, tx_memo, messages,
     )
     .await;
 
@@ -79,10 +81,15 @@ async fn do_send_tx_with_account_sequence_retry(
     config: &TxConfig,
     key_pair: &Secp256k1KeyPair,
     account: &mut Account,
+    gas_model: &mut GasModelState,
     tx_memo: &Memo,
     messages: &[Any],
 ) -> Result<Response, Error> {
-    match estimate_fee_and_send_tx(rpc_client, config, key_pair, account, tx_memo, messages).await {
+    match estimate_fee_and_send_tx(
+        rpc_client, config, key_pair, account, gas_model, tx_memo, messages,
+    )
+    .await
+    {
         // Gas estimation failed with account sequence mismatch during gas estimation.
         // It indicates that the account sequence cached by hermes is stale (got < expected).
         // This can happen when the same account is used by another agent.
@@ -94,7 +101,7 @@ async fn do_send_tx_with_account_sequence_retry(
             );
 
             refresh_account_and_retry_send_tx_with_account_sequence(
-                rpc_client, config, key_pair, account, tx_memo, messages,
+                rpc_client, config, key_pair, account, gas_model, tx_memo, messages,
             )
             .await
         }
@@ -118,7 +125,7 @@ async fn do_send_tx_with_account_sequence_retry(
             );
 
             refresh_account_and_retry_send_tx_with_account_sequence(
-                rpc_client, config, key_pair, account, tx_memo, messages,
+                rpc_client, config, key_pair, account, gas_model, tx_memo, messages,
             )
             .await
         }
@@ -185,6 +192,7 @@ async fn refresh_account_and_retry_send_tx_with_account_sequence(
     config: &TxConfig,
     key_pair: &Secp256k1KeyPair,
     account: &mut Account,
+    gas_model: &mut GasModelState,
     tx_memo: &Memo,
     messages: &[Any],
 ) -> Result<Response, Error> {
@@ -195,8 +203,10 @@ async fn refresh_account_and_retry_send_tx_with_account_sequence(
     // Retry after delay
     thread::sleep(Duration::from_millis(ACCOUNT_SEQUENCE_RETRY_DELAY));
 
-    let (estimate_result, _) =
-        estimate_fee_and_send_tx(rpc_client, config, key_pair, account, tx_memo, messages).await?;
+    let (estimate_result, _) = estimate_fee_and_send_tx(
+        rpc_client, config, key_pair, account, gas_model, tx_memo, messages,
+    )
+    .await?;
 
     Ok(estimate_result)
 }
@@ -6,6 +6,7 @@ use tendermint_rpc::{Client, HttpClient, Url};
 
 use crate::chain::cosmos::encode::sign_and_encode_tx;
 use crate::chain::cosmos::estimate::estimate_tx_fees;
+use crate::chain::cosmos::gas_model::GasModelState;
 use crate::chain::cosmos::query::account::query_account;
 use crate::chain::cosmos::query::tx::all_ibc_events_from_tx_search_response;
 use crate::chain::cosmos::types::account::Account;
@@ -15,6 +16,7 @@ use crate::config::types::Memo;
 use crate::error::Error;
 use crate::event::IbcEventWithHeight;
 use crate::keyring::{Secp256k1KeyPair, SigningKeyPair};
+use crate::profitability;
 
 use super::batch::send_batched_messages_and_wait_commit;
 use super::estimate::EstimatedGas;
@@ -24,17 +26,30 @@ pub async fn estimate_fee_and_send_tx(
     config: &TxConfig,
     key_pair: &Secp256k1KeyPair,
     account: &Account,
+    gas_model: &mut GasModelState,
     tx_memo: &Memo,
     messages: &[Any],
 ) -> Result<(Response, EstimatedGas), Error> {
     let (fee, estimated_gas) =
-        estimate_tx_fees(config, key_pair, account, tx_memo, messages).await?;
+        estimate_tx_fees(config, key_pair, account, gas_model, tx_memo, messages).await?;
 
     let tx_result = send_tx_with_fee(
         rpc_client, config, key_pair, account, tx_memo, messages, &fee,
     )
     .await?;
 
+    let settings = &config.gas_config.gas_model;
+
+    if settings.enabled {
+        gas_model.record_broadcast(
+            &config.chain_id,
+            settings,
+            &tx_result,
+            messages,
+            &estimated_gas,
+        );
+    }
+
     Ok((tx_result, estimated_gas))
 }
 
@@ -51,6 +66,10 @@ async fn send_tx_with_fee(
 
     let response = broadcast_tx_sync(rpc_client, &config.rpc_address, tx_bytes).await?;
 
+    if response.code.is_ok() {
+        profitability::record_tx_fee(response.hash, &fee.amount);
+    }
+
     Ok(response)
 }
 
@@ -96,6 +115,7 @@ pub async fn simple_send_tx(
         config,
         key_pair,
         &account,
+        &mut GasModelState::default(),
         &Memo::default(),
         &messages,
     )
@@ -134,6 +154,7 @@ pub async fn batched_send_tx(
         config,
         key_pair,
         &mut account,
+        &mut GasModelState::default(),
         &Memo::default(),
         messages,
     )
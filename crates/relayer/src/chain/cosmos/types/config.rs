@@ -7,6 +7,7 @@ use tendermint_rpc::Url;
 
 use crate::chain::cosmos::config::CosmosSdkConfig;
 use crate::chain::cosmos::types::gas::GasConfig;
+use crate::config::tx_pipeline::TxPipeline;
 use crate::config::types::{MaxMsgNum, MaxTxSize};
 use crate::config::AddressType;
 use crate::error::Error;
@@ -22,6 +23,7 @@ pub struct TxConfig {
     pub max_msg_num: MaxMsgNum,
     pub max_tx_size: MaxTxSize,
     pub extension_options: Vec<Any>,
+    pub tx_pipeline: TxPipeline,
 }
 
 impl<'a> TryFrom<&'a CosmosSdkConfig> for TxConfig {
@@ -49,6 +51,7 @@ impl<'a> TryFrom<&'a CosmosSdkConfig> for TxConfig {
             max_msg_num: config.max_msg_num,
             max_tx_size: config.max_tx_size,
             extension_options,
+            tx_pipeline: config.tx_pipeline,
         })
     }
 }
@@ -3,6 +3,7 @@ use ibc_proto::cosmos::tx::v1beta1::Fee;
 use crate::chain::cosmos::calculate_fee;
 use crate::chain::cosmos::config::CosmosSdkConfig;
 use crate::config::dynamic_gas::DynamicGasPrice;
+use crate::config::gas_model::GasModel;
 use crate::config::GasPrice;
 
 /// Default gas limit when submitting a transaction.
@@ -19,6 +20,7 @@ pub struct GasConfig {
     pub max_fee: Fee,
     pub fee_granter: String,
     pub dynamic_gas_price: DynamicGasPrice,
+    pub gas_model: GasModel,
 }
 
 impl<'a> From<&'a CosmosSdkConfig> for GasConfig {
@@ -31,6 +33,7 @@ impl<'a> From<&'a CosmosSdkConfig> for GasConfig {
             max_fee: max_fee_from_config(config),
             fee_granter: fee_granter_from_config(config),
             dynamic_gas_price: config.dynamic_gas_price,
+            gas_model: config.gas_model,
         }
     }
 }
@@ -11,6 +11,7 @@ use tendermint_rpc::{HttpClient, Url};
 use tokio::time::sleep;
 use tracing::{debug, debug_span, trace};
 
+use crate::chain::cosmos::gas_model::GasModelState;
 use crate::chain::cosmos::query::tx::query_tx_response;
 use crate::chain::cosmos::types::events::from_tx_response_event;
 use crate::chain::cosmos::types::tx::{TxStatus, TxSyncResult};
@@ -27,6 +28,7 @@ pub async fn wait_for_block_commits(
     rpc_client: &HttpClient,
     rpc_address: &Url,
     rpc_timeout: &Duration,
+    gas_model: &mut GasModelState,
     tx_sync_results: &mut [TxSyncResult],
 ) -> Result<(), Error> {
     if all_tx_results_found(tx_sync_results) {
@@ -68,8 +70,14 @@ pub async fn wait_for_block_commits(
             thread::sleep(WAIT_BACKOFF);
 
             for tx_sync_result in tx_sync_results.iter_mut() {
-                let res =
-                    update_tx_sync_result(chain_id, rpc_client, rpc_address, tx_sync_result).await;
+                let res = update_tx_sync_result(
+                    chain_id,
+                    rpc_client,
+                    rpc_address,
+                    gas_model,
+                    tx_sync_result,
+                )
+                .await;
                 if let Err(e) = res {
                     debug!("update_tx_sync_result failed: {e}");
                 }
@@ -82,6 +90,7 @@ async fn update_tx_sync_result(
     chain_id: &ChainId,
     rpc_client: &HttpClient,
     rpc_address: &Url,
+    gas_model: &mut GasModelState,
     tx_sync_result: &mut TxSyncResult,
 ) -> Result<(), Error> {
     if let TxStatus::Pending { message_count } = tx_sync_result.status {
@@ -91,6 +100,8 @@ async fn update_tx_sync_result(
         if let Some(response) = response {
             tx_sync_result.status = TxStatus::ReceivedResponse;
 
+            gas_model.record_tx_result(chain_id, &response);
+
             let height = Height::new(chain_id.version(), u64::from(response.height)).unwrap();
             if response.tx_result.code.is_err() {
                 tx_sync_result.events = vec![
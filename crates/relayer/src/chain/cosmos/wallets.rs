@@ -0,0 +1,157 @@
+//! Selection of the wallet used to sign a transaction, for chains
+//! configured with more than one key.
+
+use ibc_relayer_types::core::ics04_channel::channel::Ordering;
+use ibc_relayer_types::core::ics24_host::identifier::{ChannelId, PortId};
+use serde_derive::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+/// How the transactions relaying packets are spread across the wallets of a chain.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WalletAssignment {
+    /// Each transaction is signed with the next wallet in turn,
+    /// except on ordered channels which are assigned a single wallet.
+    #[default]
+    RoundRobin,
+
+    /// All the transactions for a given channel are signed with the same wallet.
+    PerChannel,
+}
+
+/// The names of the keys used to sign transactions on a chain,
+/// the first one being the key set as `key_name` in the configuration.
+#[derive(Clone, Debug)]
+pub struct Wallets {
+    key_names: Vec<String>,
+    assignment: WalletAssignment,
+    next: usize,
+}
+
+impl Wallets {
+    pub fn new(key_names: Vec<String>, assignment: WalletAssignment) -> Self {
+        assert!(!key_names.is_empty(), "a chain needs at least one key");
+
+        Self {
+            key_names,
+            assignment,
+            next: 0,
+        }
+    }
+
+    pub fn key_names(&self) -> &[String] {
+        &self.key_names
+    }
+
+    pub fn primary(&self) -> &str {
+        &self.key_names[0]
+    }
+
+    /// Selects the key to sign the messages relaying packets on the given channel with.
+    ///
+    /// Messages which are not tied to a channel, eg. client updates or
+    /// messages submitted from the CLI, are always signed with the primary key.
+    ///
+    /// The messages relaying packets on an ordered channel are always signed with the
+    /// same wallet, whatever the assignment, since the transactions signed with different
+    /// wallets may be committed out of order and fail to be delivered.
+    pub fn select(&mut self, channel: Option<&(PortId, ChannelId)>, ordering: Ordering) -> &str {
+        let Some((port_id, channel_id)) = channel else {
+            return self.primary();
+        };
+
+        let index = match self.assignment {
+            WalletAssignment::RoundRobin if !ordering.is_ordered() => {
+                let index = self.next % self.key_names.len();
+                self.next = index + 1;
+                index
+            }
+            WalletAssignment::RoundRobin | WalletAssignment::PerChannel => {
+                channel_index(port_id, channel_id) % self.key_names.len()
+            }
+        };
+
+        &self.key_names[index]
+    }
+}
+
+/// A stable index derived from the channel, so that a channel is
+/// assigned the same wallet across restarts.
+fn channel_index(port_id: &PortId, channel_id: &ChannelId) -> usize {
+    let digest = Sha256::digest(format!("{port_id}/{channel_id}"));
+    let prefix: [u8; 8] = digest[..8].try_into().expect("digest is 32 bytes long");
+
+    u64::from_be_bytes(prefix) as usize
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn channel(channel_id: u64) -> (PortId, ChannelId) {
+        (PortId::transfer(), ChannelId::new(channel_id))
+    }
+
+    #[test]
+    fn select_round_robin() {
+        let mut wallets = Wallets::new(
+            vec!["a".to_string(), "b".to_string(), "c".to_string()],
+            WalletAssignment::RoundRobin,
+        );
+
+        let selected: Vec<_> = (0..4)
+            .map(|i| {
+                wallets
+                    .select(Some(&channel(i % 2)), Ordering::Unordered)
+                    .to_string()
+            })
+            .collect();
+        assert_eq!(selected, ["a", "b", "c", "a"]);
+
+        assert_eq!(wallets.select(None, Ordering::Unordered), "a");
+    }
+
+    #[test]
+    fn select_per_channel() {
+        let mut wallets = Wallets::new(
+            vec!["a".to_string(), "b".to_string(), "c".to_string()],
+            WalletAssignment::PerChannel,
+        );
+
+        for i in 0..10 {
+            let first = wallets
+                .select(Some(&channel(i)), Ordering::Unordered)
+                .to_string();
+            let second = wallets
+                .select(Some(&channel(i)), Ordering::Unordered)
+                .to_string();
+            assert_eq!(first, second);
+        }
+
+        let used: std::collections::BTreeSet<_> = (0..30)
+            .map(|i| {
+                wallets
+                    .select(Some(&channel(i)), Ordering::Unordered)
+                    .to_string()
+            })
+            .collect();
+        assert!(used.len() > 1);
+    }
+
+    #[test]
+    fn select_ordered_channel() {
+        for assignment in [WalletAssignment::RoundRobin, WalletAssignment::PerChannel] {
+            let mut wallets = Wallets::new(
+                vec!["a".to_string(), "b".to_string(), "c".to_string()],
+                assignment,
+            );
+
+            for ordering in [Ordering::Ordered, Ordering::OrderedAllowTimeout] {
+                let selected: std::collections::BTreeSet<_> = (0..10)
+                    .map(|_| wallets.select(Some(&channel(0)), ordering).to_string())
+                    .collect();
+                assert_eq!(selected.len(), 1);
+            }
+        }
+    }
+}
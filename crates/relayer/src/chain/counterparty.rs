@@ -1,3 +1,4 @@
+use core::fmt::{Display, Error as FmtError, Formatter};
 use std::collections::HashSet;
 
 use ibc_relayer_types::{
@@ -13,14 +14,16 @@ use ibc_relayer_types::{
             ChainId, ChannelId, ClientId, ConnectionId, PortChannelId, PortId,
         },
     },
+    events::{IbcEvent, WithBlockDataType},
     Height,
 };
 use serde::{Deserialize, Serialize};
 use tracing::{error, trace};
 
 use super::requests::{
-    IncludeProof, PageRequest, QueryChannelRequest, QueryClientConnectionsRequest,
-    QueryClientStateRequest, QueryConnectionRequest, QueryPacketAcknowledgementsRequest,
+    IncludeProof, PageRequest, Qualified, QueryChannelRequest, QueryClientConnectionsRequest,
+    QueryClientStateRequest, QueryConnectionRequest, QueryPacketAcknowledgementRequest,
+    QueryPacketAcknowledgementsRequest, QueryPacketCommitmentRequest, QueryPacketEventDataRequest,
     QueryUnreceivedAcksRequest, QueryUnreceivedPacketsRequest,
 };
 use super::{
@@ -30,6 +33,8 @@ use super::{
 use crate::chain::requests::QueryHeight;
 use crate::channel::ChannelError;
 use crate::client_state::IdentifiedAnyClientState;
+use crate::event::IbcEventWithHeight;
+use crate::link::inspect::RelayerPacketStatus;
 use crate::path::PathIdentifiers;
 use crate::supervisor::Error;
 use crate::telemetry;
@@ -661,3 +666,214 @@ pub fn pending_packet_summary(
         unreceived_acks: pending_acks,
     })
 }
+
+/// Where a packet stands in its lifecycle.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PacketStatus {
+    /// Neither a commitment, a receipt nor a `SendPacket` event
+    /// could be found for the packet.
+    NotFound,
+    /// The packet was sent, and is waiting to be received on the destination chain.
+    AwaitingReceive,
+    /// The packet timed out before being received on the destination chain,
+    /// and the timeout is waiting to be relayed back to the source chain.
+    AwaitingTimeout,
+    /// The packet was received on the destination chain,
+    /// which has not written its acknowledgement yet.
+    AwaitingAckWrite,
+    /// The acknowledgement of the packet was written on the destination chain,
+    /// and is waiting to be relayed back to the source chain.
+    AwaitingAck,
+    /// The acknowledgement of the packet was relayed back to the source chain.
+    Acknowledged,
+    /// The timeout of the packet was relayed back to the source chain.
+    TimedOut,
+}
+
+impl Display for PacketStatus {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), FmtError> {
+        match self {
+            Self::NotFound => write!(f, "not found"),
+            Self::AwaitingReceive => write!(f, "awaiting receive on the destination chain"),
+            Self::AwaitingTimeout => write!(f, "timed out, awaiting timeout on the source chain"),
+            Self::AwaitingAckWrite => {
+                write!(
+                    f,
+                    "received, awaiting acknowledgement on the destination chain"
+                )
+            }
+            Self::AwaitingAck => write!(f, "awaiting acknowledgement on the source chain"),
+            Self::Acknowledged => write!(f, "acknowledged"),
+            Self::TimedOut => write!(f, "timed out"),
+        }
+    }
+}
+
+/// The height and transaction at which a packet event was emitted.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PacketEventInfo {
+    pub height: Height,
+    pub tx_hash: Option<String>,
+}
+
+impl From<&IbcEventWithHeight> for PacketEventInfo {
+    fn from(event: &IbcEventWithHeight) -> Self {
+        Self {
+            height: event.height,
+            tx_hash: event.tx_hash.map(|hash| hash.to_string()),
+        }
+    }
+}
+
+/// The lifecycle of a packet, as observed on both ends of its channel.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PacketTrace {
+    pub chain_id: ChainId,
+    pub port_id: PortId,
+    pub channel_id: ChannelId,
+    pub sequence: Sequence,
+    pub counterparty_chain_id: ChainId,
+    pub counterparty_port_id: PortId,
+    pub counterparty_channel_id: ChannelId,
+    pub status: PacketStatus,
+    /// The `SendPacket` event on the source chain, if it could be found.
+    pub send: Option<PacketEventInfo>,
+    /// Whether the packet commitment is still present on the source chain.
+    pub commitment_present: bool,
+    /// Whether the packet was received on the destination chain.
+    pub received: bool,
+    /// The `WriteAcknowledgement` event on the destination chain, if it could be found.
+    pub write_ack: Option<PacketEventInfo>,
+    /// Whether the acknowledgement was written on the destination chain.
+    pub ack_written: bool,
+    /// Whether the acknowledgement was relayed back to the source chain.
+    pub ack_relayed: bool,
+    /// Whether the packet timed out, whether the timeout was relayed back or not.
+    pub timed_out: bool,
+    /// What the running relayer knows of the packet, if the trace was requested from it.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub relayer: Option<Vec<RelayerPacketStatus>>,
+}
+
+/// Trace the lifecycle of the packet with the given sequence, sent on the
+/// given channel of `chain` to `counterparty_chain`.
+pub fn packet_trace(
+    chain: &impl ChainHandle,
+    counterparty_chain: &impl ChainHandle,
+    channel: &IdentifiedChannelEnd,
+    sequence: Sequence,
+) -> Result<PacketTrace, Error> {
+    let counterparty = channel.channel_end.counterparty();
+    let counterparty_port_id = &counterparty.port_id;
+    let counterparty_channel_id = counterparty
+        .channel_id
+        .as_ref()
+        .ok_or_else(Error::missing_counterparty_channel_id)?;
+
+    let events_request = |event_id| QueryPacketEventDataRequest {
+        event_id,
+        source_channel_id: channel.channel_id.clone(),
+        source_port_id: channel.port_id.clone(),
+        destination_channel_id: counterparty_channel_id.clone(),
+        destination_port_id: counterparty_port_id.clone(),
+        sequences: vec![sequence],
+        height: Qualified::SmallerEqual(QueryHeight::Latest),
+    };
+
+    let send_event = chain
+        .query_packet_events(events_request(WithBlockDataType::SendPacket))
+        .map_err(Error::relayer)?
+        .into_iter()
+        .find(|event| matches!(event.event, IbcEvent::SendPacket(_)));
+
+    let (commitment, _) = chain
+        .query_packet_commitment(
+            QueryPacketCommitmentRequest {
+                port_id: channel.port_id.clone(),
+                channel_id: channel.channel_id.clone(),
+                sequence,
+                height: QueryHeight::Latest,
+            },
+            IncludeProof::No,
+        )
+        .map_err(Error::relayer)?;
+
+    let commitment_present = !commitment.is_empty();
+
+    let received = unreceived_packets_sequences(
+        counterparty_chain,
+        counterparty_port_id,
+        counterparty_channel_id,
+        vec![sequence],
+    )?
+    .is_empty();
+
+    let (ack, _) = counterparty_chain
+        .query_packet_acknowledgement(
+            QueryPacketAcknowledgementRequest {
+                port_id: counterparty_port_id.clone(),
+                channel_id: counterparty_channel_id.clone(),
+                sequence,
+                height: QueryHeight::Latest,
+            },
+            IncludeProof::No,
+        )
+        .map_err(Error::relayer)?;
+
+    let ack_written = !ack.is_empty();
+
+    let write_ack_event = if ack_written {
+        counterparty_chain
+            .query_packet_events(events_request(WithBlockDataType::WriteAck))
+            .map_err(Error::relayer)?
+            .into_iter()
+            .find(|event| matches!(event.event, IbcEvent::WriteAcknowledgement(_)))
+    } else {
+        None
+    };
+
+    // Only check the timeout of packets still waiting to be received
+    let timeout_elapsed = match send_event.as_ref().and_then(|e| e.event.packet()) {
+        Some(packet) if commitment_present && !received => {
+            let status = counterparty_chain
+                .query_application_status()
+                .map_err(Error::relayer)?;
+
+            packet.timed_out(&status.timestamp, status.height)
+        }
+        _ => false,
+    };
+
+    let status = match (commitment_present, received) {
+        (true, true) if ack_written => PacketStatus::AwaitingAck,
+        (true, true) => PacketStatus::AwaitingAckWrite,
+        (true, false) if timeout_elapsed => PacketStatus::AwaitingTimeout,
+        (true, false) => PacketStatus::AwaitingReceive,
+        (false, true) => PacketStatus::Acknowledged,
+        (false, false) if send_event.is_some() => PacketStatus::TimedOut,
+        (false, false) => PacketStatus::NotFound,
+    };
+
+    Ok(PacketTrace {
+        chain_id: chain.id(),
+        port_id: channel.port_id.clone(),
+        channel_id: channel.channel_id.clone(),
+        sequence,
+        counterparty_chain_id: counterparty_chain.id(),
+        counterparty_port_id: counterparty_port_id.clone(),
+        counterparty_channel_id: counterparty_channel_id.clone(),
+        status,
+        send: send_event.as_ref().map(PacketEventInfo::from),
+        commitment_present,
+        received,
+        write_ack: write_ack_event.as_ref().map(PacketEventInfo::from),
+        ack_written,
+        ack_relayed: status == PacketStatus::Acknowledged,
+        timed_out: matches!(
+            status,
+            PacketStatus::AwaitingTimeout | PacketStatus::TimedOut
+        ),
+        relayer: None,
+    })
+}
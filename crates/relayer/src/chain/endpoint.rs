@@ -7,6 +7,7 @@ use ibc_proto::ibc::apps::fee::v1::{
     QueryIncentivizedPacketRequest, QueryIncentivizedPacketResponse,
 };
 use ibc_relayer_types::applications::ics31_icq::response::CrossChainQueryResponse;
+use ibc_relayer_types::applications::transfer::RawCoin;
 use ibc_relayer_types::core::ics02_client::client_state::ClientState;
 use ibc_relayer_types::core::ics02_client::consensus_state::ConsensusState;
 use ibc_relayer_types::core::ics02_client::events::UpdateClient;
@@ -15,8 +16,9 @@ use ibc_relayer_types::core::ics03_connection::connection::{
     ConnectionEnd, IdentifiedConnectionEnd, State,
 };
 use ibc_relayer_types::core::ics03_connection::version::{get_compatible_versions, Version};
-use ibc_relayer_types::core::ics04_channel::channel::{ChannelEnd, IdentifiedChannelEnd};
+use ibc_relayer_types::core::ics04_channel::channel::{ChannelEnd, IdentifiedChannelEnd, Ordering};
 use ibc_relayer_types::core::ics04_channel::packet::{PacketMsgType, Sequence};
+use ibc_relayer_types::core::ics04_channel::upgrade::Upgrade;
 use ibc_relayer_types::core::ics23_commitment::commitment::{
     CommitmentPrefix, CommitmentProofBytes,
 };
@@ -31,7 +33,7 @@ use ibc_relayer_types::Height as ICSHeight;
 
 use tendermint_rpc::endpoint::broadcast::tx_sync::Response as TxResponse;
 
-use crate::account::Balance;
+use crate::account::{Balance, Grants};
 use crate::chain::client::ClientSettings;
 use crate::chain::cosmos::version::Specs;
 use crate::chain::handle::Subscription;
@@ -98,6 +100,14 @@ pub trait ChainEndpoint: Sized {
     /// Perform a health check
     fn health_check(&mut self) -> Result<HealthCheck, Error>;
 
+    /// Probe the full nodes serving the chain, and fail over to the healthiest
+    /// of them if the one in use is unhealthy. Called periodically by the chain runtime.
+    ///
+    /// Chains served by a single node can rely on the default implementation, which does nothing.
+    fn check_endpoints(&mut self) -> Result<(), Error> {
+        Ok(())
+    }
+
     // Events
     fn subscribe(&mut self) -> Result<Subscription, Error>;
 
@@ -114,6 +124,21 @@ pub trait ChainEndpoint: Sized {
     /// Get the signing key pair
     fn get_key(&self) -> Result<Self::SigningKeyPair, Error>;
 
+    /// Get the name and signing key pair of every wallet used to sign transactions
+    fn get_keys(&self) -> Result<Vec<(String, Self::SigningKeyPair)>, Error> {
+        Ok(vec![(self.config().key_name().clone(), self.get_key()?)])
+    }
+
+    /// Select the wallet which signs the messages relaying packets on the given channel,
+    /// and return its signer. Chains with a single wallet always return their signer.
+    fn select_signer(
+        &mut self,
+        _channel: &(PortId, ChannelId),
+        _ordering: Ordering,
+    ) -> Result<Signer, Error> {
+        self.get_signer()
+    }
+
     fn add_key(&mut self, key_name: &str, key_pair: Self::SigningKeyPair) -> Result<(), Error> {
         self.keybase_mut()
             .add_key(key_name, key_pair)
@@ -122,6 +147,23 @@ pub trait ChainEndpoint: Sized {
         Ok(())
     }
 
+    /// Swap the gas settings of the chain with the ones of the given configuration,
+    /// leaving the rest of the configuration untouched.
+    ///
+    /// Chains without any gas settings can rely on the default implementation, which does nothing.
+    fn update_gas_settings(&mut self, _config: ChainConfig) -> Result<(), Error> {
+        Ok(())
+    }
+
+    /// Estimate the fee of a transaction with a single message of the given type URL,
+    /// without simulating it.
+    ///
+    /// Chains without any gas settings can rely on the default implementation,
+    /// which does not provide any estimate.
+    fn estimate_tx_fee(&self, _msg_type: &str) -> Result<Option<RawCoin>, Error> {
+        Ok(None)
+    }
+
     // Versioning
 
     /// Return the version of the IBC protocol that this chain is running, if known.
@@ -261,6 +303,14 @@ pub trait ChainEndpoint: Sized {
         include_proof: IncludeProof,
     ) -> Result<(ChannelEnd, Option<MerkleProof>), Error>;
 
+    /// Performs a query to retrieve the upgrade proposed for the channel with
+    /// the given identifier. A proof can optionally be returned along with the result.
+    fn query_upgrade(
+        &self,
+        request: QueryUpgradeRequest,
+        include_proof: IncludeProof,
+    ) -> Result<(Upgrade, Option<MerkleProof>), Error>;
+
     /// Performs a query to retrieve the client state for the channel associated
     /// with a given channel identifier.
     fn query_channel_client_state(
@@ -687,4 +737,19 @@ pub trait ChainEndpoint: Sized {
     ) -> Result<QueryIncentivizedPacketResponse, Error>;
 
     fn query_consumer_chains(&self) -> Result<Vec<(ChainId, ClientId)>, Error>;
+
+    /// Query the fee allowance and the authorization to renew it,
+    /// given by the granter to the grantee.
+    fn query_grants(&self, request: QueryGrantsRequest) -> Result<Grants, Error>;
+
+    /// Return the given Txs which were dropped from the mempool before being committed.
+    ///
+    /// Only chains which track the Txs they have in flight can tell,
+    /// the others never report any Tx as dropped.
+    fn query_dropped_txs(
+        &self,
+        _tx_hashes: &[tendermint::Hash],
+    ) -> Result<Vec<tendermint::Hash>, Error> {
+        Ok(Vec::new())
+    }
 }
@@ -8,7 +8,7 @@ use ibc_proto::ibc::apps::fee::v1::{
     QueryIncentivizedPacketRequest, QueryIncentivizedPacketResponse,
 };
 use ibc_relayer_types::{
-    applications::ics31_icq::response::CrossChainQueryResponse,
+    applications::{ics31_icq::response::CrossChainQueryResponse, transfer::RawCoin},
     core::{
         ics02_client::{events::UpdateClient, header::AnyHeader},
         ics03_connection::{
@@ -16,8 +16,9 @@ use ibc_relayer_types::{
             version::Version,
         },
         ics04_channel::{
-            channel::{ChannelEnd, IdentifiedChannelEnd},
+            channel::{ChannelEnd, IdentifiedChannelEnd, Ordering},
             packet::{PacketMsgType, Sequence},
+            upgrade::Upgrade,
         },
         ics23_commitment::{commitment::CommitmentPrefix, merkle::MerkleProof},
         ics24_host::identifier::{ChainId, ChannelId, ClientId, ConnectionId, PortId},
@@ -28,7 +29,7 @@ use ibc_relayer_types::{
 };
 
 use crate::{
-    account::Balance,
+    account::{Balance, Grants},
     client_state::{AnyClientState, IdentifiedAnyClientState},
     config::ChainConfig,
     connection::ConnectionMsgType,
@@ -98,7 +99,11 @@ pub fn reply_channel<T>() -> (ReplyTo<T>, Reply<T>) {
 }
 
 /// Requests that a `ChainHandle` may send to a `ChainRuntime`.
-#[derive(Clone, Debug)]
+///
+/// The name of a request, eg. `query_client_state`, is given by its
+/// [`IntoStaticStr`](strum::IntoStaticStr) implementation.
+#[derive(Clone, Debug, strum::IntoStaticStr)]
+#[strum(serialize_all = "snake_case")]
 #[allow(clippy::large_enum_variant)]
 pub enum ChainRequest {
     Shutdown {
@@ -135,12 +140,32 @@ pub enum ChainRequest {
         reply_to: ReplyTo<AnySigningKeyPair>,
     },
 
+    GetKeys {
+        reply_to: ReplyTo<Vec<(String, AnySigningKeyPair)>>,
+    },
+
+    SelectSigner {
+        channel: (PortId, ChannelId),
+        ordering: Ordering,
+        reply_to: ReplyTo<Signer>,
+    },
+
     AddKey {
         key_name: String,
         key: AnySigningKeyPair,
         reply_to: ReplyTo<()>,
     },
 
+    UpdateGasSettings {
+        config: ChainConfig,
+        reply_to: ReplyTo<()>,
+    },
+
+    EstimateTxFee {
+        msg_type: String,
+        reply_to: ReplyTo<Option<RawCoin>>,
+    },
+
     VersionSpecs {
         reply_to: ReplyTo<Specs>,
     },
@@ -271,6 +296,12 @@ pub enum ChainRequest {
         reply_to: ReplyTo<(ChannelEnd, Option<MerkleProof>)>,
     },
 
+    QueryUpgrade {
+        request: QueryUpgradeRequest,
+        include_proof: IncludeProof,
+        reply_to: ReplyTo<(Upgrade, Option<MerkleProof>)>,
+    },
+
     QueryChannelClientState {
         request: QueryChannelClientStateRequest,
         reply_to: ReplyTo<Option<IdentifiedAnyClientState>>,
@@ -371,6 +402,16 @@ pub enum ChainRequest {
     QueryConsumerChains {
         reply_to: ReplyTo<Vec<(ChainId, ClientId)>>,
     },
+
+    QueryGrants {
+        request: QueryGrantsRequest,
+        reply_to: ReplyTo<Grants>,
+    },
+
+    QueryDroppedTxs {
+        tx_hashes: Vec<tendermint::Hash>,
+        reply_to: ReplyTo<Vec<tendermint::Hash>>,
+    },
 }
 
 pub trait ChainHandle: Clone + Display + Send + Sync + Debug + 'static {
@@ -410,8 +451,27 @@ pub trait ChainHandle: Clone + Display + Send + Sync + Debug + 'static {
 
     fn get_key(&self) -> Result<AnySigningKeyPair, Error>;
 
+    /// Get the name and key of every wallet used to sign transactions on this chain.
+    fn get_keys(&self) -> Result<Vec<(String, AnySigningKeyPair)>, Error>;
+
+    /// Select the wallet which signs the messages relaying packets on the given channel,
+    /// and return its signer, with which all the messages of the transaction must be built.
+    fn select_signer(
+        &self,
+        channel: (PortId, ChannelId),
+        ordering: Ordering,
+    ) -> Result<Signer, Error>;
+
     fn add_key(&self, key_name: String, key: AnySigningKeyPair) -> Result<(), Error>;
 
+    /// Swap the gas settings of the chain with the ones of the given configuration,
+    /// without restarting the chain runtime.
+    fn update_gas_settings(&self, config: ChainConfig) -> Result<(), Error>;
+
+    /// Estimate the fee of a transaction with a single message of the given type URL,
+    /// at the current gas price of the chain, if it has any.
+    fn estimate_tx_fee(&self, msg_type: String) -> Result<Option<RawCoin>, Error>;
+
     /// Return the version of the IBC protocol that this chain is running, if known.
     fn version_specs(&self) -> Result<Specs, Error>;
 
@@ -529,6 +589,14 @@ pub trait ChainHandle: Clone + Display + Send + Sync + Debug + 'static {
         include_proof: IncludeProof,
     ) -> Result<(ChannelEnd, Option<MerkleProof>), Error>;
 
+    /// Performs a query to retrieve the upgrade proposed for the channel with
+    /// the given identifier. A proof can optionally be returned along with the result.
+    fn query_upgrade(
+        &self,
+        request: QueryUpgradeRequest,
+        include_proof: IncludeProof,
+    ) -> Result<(Upgrade, Option<MerkleProof>), Error>;
+
     /// Performs a query to retrieve the client state for the channel associated
     /// with a given channel identifier.
     fn query_channel_client_state(
@@ -684,4 +752,15 @@ pub trait ChainHandle: Clone + Display + Send + Sync + Debug + 'static {
     ) -> Result<QueryIncentivizedPacketResponse, Error>;
 
     fn query_consumer_chains(&self) -> Result<Vec<(ChainId, ClientId)>, Error>;
+
+    /// Query the fee allowance and the authorization to renew it,
+    /// given by the granter to the grantee.
+    fn query_grants(&self, request: QueryGrantsRequest) -> Result<Grants, Error>;
+
+    /// Return the given Txs which were dropped from the mempool before being
+    /// committed, and whose messages must therefore be resubmitted.
+    fn query_dropped_txs(
+        &self,
+        tx_hashes: Vec<tendermint::Hash>,
+    ) -> Result<Vec<tendermint::Hash>, Error>;
 }
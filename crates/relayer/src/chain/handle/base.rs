@@ -7,13 +7,14 @@ use ibc_proto::ibc::apps::fee::v1::{
     QueryIncentivizedPacketRequest, QueryIncentivizedPacketResponse,
 };
 use ibc_relayer_types::{
-    applications::ics31_icq::response::CrossChainQueryResponse,
+    applications::{ics31_icq::response::CrossChainQueryResponse, transfer::RawCoin},
     core::{
         ics02_client::{events::UpdateClient, header::AnyHeader},
         ics03_connection::connection::{ConnectionEnd, IdentifiedConnectionEnd},
         ics03_connection::version::Version,
-        ics04_channel::channel::{ChannelEnd, IdentifiedChannelEnd},
+        ics04_channel::channel::{ChannelEnd, IdentifiedChannelEnd, Ordering},
         ics04_channel::packet::{PacketMsgType, Sequence},
+        ics04_channel::upgrade::Upgrade,
         ics23_commitment::{commitment::CommitmentPrefix, merkle::MerkleProof},
         ics24_host::identifier::ChainId,
         ics24_host::identifier::ChannelId,
@@ -25,7 +26,7 @@ use ibc_relayer_types::{
 };
 
 use crate::{
-    account::Balance,
+    account::{Balance, Grants},
     chain::{
         client::ClientSettings, cosmos::version::Specs, endpoint::ChainStatus, requests::*,
         tracking::TrackedMsgs,
@@ -139,6 +140,22 @@ impl ChainHandle for BaseChainHandle {
         self.send(|reply_to| ChainRequest::GetKey { reply_to })
     }
 
+    fn get_keys(&self) -> Result<Vec<(String, AnySigningKeyPair)>, Error> {
+        self.send(|reply_to| ChainRequest::GetKeys { reply_to })
+    }
+
+    fn select_signer(
+        &self,
+        channel: (PortId, ChannelId),
+        ordering: Ordering,
+    ) -> Result<Signer, Error> {
+        self.send(|reply_to| ChainRequest::SelectSigner {
+            channel,
+            ordering,
+            reply_to,
+        })
+    }
+
     fn add_key(&self, key_name: String, key: AnySigningKeyPair) -> Result<(), Error> {
         self.send(|reply_to| ChainRequest::AddKey {
             key_name,
@@ -147,6 +164,14 @@ impl ChainHandle for BaseChainHandle {
         })
     }
 
+    fn update_gas_settings(&self, config: ChainConfig) -> Result<(), Error> {
+        self.send(|reply_to| ChainRequest::UpdateGasSettings { config, reply_to })
+    }
+
+    fn estimate_tx_fee(&self, msg_type: String) -> Result<Option<RawCoin>, Error> {
+        self.send(|reply_to| ChainRequest::EstimateTxFee { msg_type, reply_to })
+    }
+
     fn version_specs(&self) -> Result<Specs, Error> {
         self.send(|reply_to| ChainRequest::VersionSpecs { reply_to })
     }
@@ -299,6 +324,18 @@ impl ChainHandle for BaseChainHandle {
         })
     }
 
+    fn query_upgrade(
+        &self,
+        request: QueryUpgradeRequest,
+        include_proof: IncludeProof,
+    ) -> Result<(Upgrade, Option<MerkleProof>), Error> {
+        self.send(|reply_to| ChainRequest::QueryUpgrade {
+            request,
+            include_proof,
+            reply_to,
+        })
+    }
+
     fn query_channel_client_state(
         &self,
         request: QueryChannelClientStateRequest,
@@ -521,4 +558,18 @@ impl ChainHandle for BaseChainHandle {
     fn query_consumer_chains(&self) -> Result<Vec<(ChainId, ClientId)>, Error> {
         self.send(|reply_to| ChainRequest::QueryConsumerChains { reply_to })
     }
+
+    fn query_grants(&self, request: QueryGrantsRequest) -> Result<Grants, Error> {
+        self.send(|reply_to| ChainRequest::QueryGrants { request, reply_to })
+    }
+
+    fn query_dropped_txs(
+        &self,
+        tx_hashes: Vec<tendermint::Hash>,
+    ) -> Result<Vec<tendermint::Hash>, Error> {
+        self.send(|reply_to| ChainRequest::QueryDroppedTxs {
+            tx_hashes,
+            reply_to,
+        })
+    }
 }
@@ -6,13 +6,15 @@ use tracing::Span;
 use ibc_proto::ibc::apps::fee::v1::QueryIncentivizedPacketRequest;
 use ibc_proto::ibc::apps::fee::v1::QueryIncentivizedPacketResponse;
 use ibc_relayer_types::applications::ics31_icq::response::CrossChainQueryResponse;
+use ibc_relayer_types::applications::transfer::RawCoin;
 use ibc_relayer_types::core::ics02_client::events::UpdateClient;
 use ibc_relayer_types::core::ics03_connection::connection::ConnectionEnd;
 use ibc_relayer_types::core::ics03_connection::connection::IdentifiedConnectionEnd;
 use ibc_relayer_types::core::ics03_connection::version::Version;
 use ibc_relayer_types::core::ics04_channel::channel::ChannelEnd;
-use ibc_relayer_types::core::ics04_channel::channel::IdentifiedChannelEnd;
+use ibc_relayer_types::core::ics04_channel::channel::{IdentifiedChannelEnd, Ordering};
 use ibc_relayer_types::core::ics04_channel::packet::{PacketMsgType, Sequence};
+use ibc_relayer_types::core::ics04_channel::upgrade::Upgrade;
 use ibc_relayer_types::core::ics23_commitment::commitment::CommitmentPrefix;
 use ibc_relayer_types::core::ics23_commitment::merkle::MerkleProof;
 use ibc_relayer_types::core::ics24_host::identifier::{
@@ -22,7 +24,7 @@ use ibc_relayer_types::proofs::Proofs;
 use ibc_relayer_types::signer::Signer;
 use ibc_relayer_types::Height;
 
-use crate::account::Balance;
+use crate::account::{Balance, Grants};
 use crate::cache::{Cache, CacheStatus};
 use crate::chain::client::ClientSettings;
 use crate::chain::cosmos::version::Specs;
@@ -119,10 +121,30 @@ impl<Handle: ChainHandle> ChainHandle for CachingChainHandle<Handle> {
         self.inner().get_key()
     }
 
+    fn get_keys(&self) -> Result<Vec<(String, AnySigningKeyPair)>, Error> {
+        self.inner().get_keys()
+    }
+
+    fn select_signer(
+        &self,
+        channel: (PortId, ChannelId),
+        ordering: Ordering,
+    ) -> Result<Signer, Error> {
+        self.inner().select_signer(channel, ordering)
+    }
+
     fn add_key(&self, key_name: String, key: AnySigningKeyPair) -> Result<(), Error> {
         self.inner().add_key(key_name, key)
     }
 
+    fn update_gas_settings(&self, config: ChainConfig) -> Result<(), Error> {
+        self.inner().update_gas_settings(config)
+    }
+
+    fn estimate_tx_fee(&self, msg_type: String) -> Result<Option<RawCoin>, Error> {
+        self.inner().estimate_tx_fee(msg_type)
+    }
+
     fn version_specs(&self) -> Result<Specs, Error> {
         self.inner().version_specs()
     }
@@ -335,6 +357,14 @@ impl<Handle: ChainHandle> ChainHandle for CachingChainHandle<Handle> {
         }
     }
 
+    fn query_upgrade(
+        &self,
+        request: QueryUpgradeRequest,
+        include_proof: IncludeProof,
+    ) -> Result<(Upgrade, Option<MerkleProof>), Error> {
+        self.inner().query_upgrade(request, include_proof)
+    }
+
     fn query_channel_client_state(
         &self,
         request: QueryChannelClientStateRequest,
@@ -515,4 +545,15 @@ impl<Handle: ChainHandle> ChainHandle for CachingChainHandle<Handle> {
     fn query_consumer_chains(&self) -> Result<Vec<(ChainId, ClientId)>, Error> {
         self.inner.query_consumer_chains()
     }
+
+    fn query_grants(&self, request: QueryGrantsRequest) -> Result<Grants, Error> {
+        self.inner.query_grants(request)
+    }
+
+    fn query_dropped_txs(
+        &self,
+        tx_hashes: Vec<tendermint::Hash>,
+    ) -> Result<Vec<tendermint::Hash>, Error> {
+        self.inner.query_dropped_txs(tx_hashes)
+    }
 }
@@ -9,14 +9,16 @@ use ibc_proto::ibc::apps::fee::v1::{
     QueryIncentivizedPacketRequest, QueryIncentivizedPacketResponse,
 };
 use ibc_relayer_types::applications::ics31_icq::response::CrossChainQueryResponse;
+use ibc_relayer_types::applications::transfer::RawCoin;
 use ibc_relayer_types::core::ics02_client::events::UpdateClient;
 use ibc_relayer_types::core::ics02_client::header::AnyHeader;
 use ibc_relayer_types::core::ics03_connection::connection::ConnectionEnd;
 use ibc_relayer_types::core::ics03_connection::connection::IdentifiedConnectionEnd;
 use ibc_relayer_types::core::ics03_connection::version::Version;
 use ibc_relayer_types::core::ics04_channel::channel::ChannelEnd;
-use ibc_relayer_types::core::ics04_channel::channel::IdentifiedChannelEnd;
+use ibc_relayer_types::core::ics04_channel::channel::{IdentifiedChannelEnd, Ordering};
 use ibc_relayer_types::core::ics04_channel::packet::{PacketMsgType, Sequence};
+use ibc_relayer_types::core::ics04_channel::upgrade::Upgrade;
 use ibc_relayer_types::core::ics23_commitment::commitment::CommitmentPrefix;
 use ibc_relayer_types::core::ics23_commitment::merkle::MerkleProof;
 use ibc_relayer_types::core::ics24_host::identifier::{
@@ -26,7 +28,7 @@ use ibc_relayer_types::proofs::Proofs;
 use ibc_relayer_types::signer::Signer;
 use ibc_relayer_types::Height;
 
-use crate::account::Balance;
+use crate::account::{Balance, Grants};
 use crate::chain::client::ClientSettings;
 use crate::chain::cosmos::version::Specs;
 use crate::chain::endpoint::{ChainStatus, HealthCheck};
@@ -146,11 +148,35 @@ impl<Handle: ChainHandle> ChainHandle for CountingChainHandle<Handle> {
         self.inner().get_key()
     }
 
+    fn get_keys(&self) -> Result<Vec<(String, AnySigningKeyPair)>, Error> {
+        self.inc_metric("get_keys");
+        self.inner().get_keys()
+    }
+
+    fn select_signer(
+        &self,
+        channel: (PortId, ChannelId),
+        ordering: Ordering,
+    ) -> Result<Signer, Error> {
+        self.inc_metric("select_signer");
+        self.inner().select_signer(channel, ordering)
+    }
+
     fn add_key(&self, key_name: String, key: AnySigningKeyPair) -> Result<(), Error> {
         self.inc_metric("add_key");
         self.inner().add_key(key_name, key)
     }
 
+    fn update_gas_settings(&self, config: ChainConfig) -> Result<(), Error> {
+        self.inc_metric("update_gas_settings");
+        self.inner().update_gas_settings(config)
+    }
+
+    fn estimate_tx_fee(&self, msg_type: String) -> Result<Option<RawCoin>, Error> {
+        self.inc_metric("estimate_tx_fee");
+        self.inner().estimate_tx_fee(msg_type)
+    }
+
     fn version_specs(&self) -> Result<Specs, Error> {
         self.inc_metric("ibc_version");
         self.inner().version_specs()
@@ -307,6 +333,15 @@ impl<Handle: ChainHandle> ChainHandle for CountingChainHandle<Handle> {
         self.inner().query_channel(request, include_proof)
     }
 
+    fn query_upgrade(
+        &self,
+        request: QueryUpgradeRequest,
+        include_proof: IncludeProof,
+    ) -> Result<(Upgrade, Option<MerkleProof>), Error> {
+        self.inc_metric("query_upgrade");
+        self.inner().query_upgrade(request, include_proof)
+    }
+
     fn query_channel_client_state(
         &self,
         request: QueryChannelClientStateRequest,
@@ -509,4 +544,17 @@ impl<Handle: ChainHandle> ChainHandle for CountingChainHandle<Handle> {
         self.inc_metric("query_consumer_chains");
         self.inner.query_consumer_chains()
     }
+
+    fn query_grants(&self, request: QueryGrantsRequest) -> Result<Grants, Error> {
+        self.inc_metric("query_grants");
+        self.inner.query_grants(request)
+    }
+
+    fn query_dropped_txs(
+        &self,
+        tx_hashes: Vec<tendermint::Hash>,
+    ) -> Result<Vec<tendermint::Hash>, Error> {
+        self.inc_metric("query_dropped_txs");
+        self.inner.query_dropped_txs(tx_hashes)
+    }
 }
@@ -0,0 +1,29 @@
+//! An in-process chain with an in-memory IBC store.
+//!
+//! A [`MockHost`] hosts clients, connections, channels and packets the
+//! same way a Cosmos SDK chain running ibc-go does, but without any full node:
+//! messages are applied directly to the store, which commits a new block for
+//! every transaction and an empty block every `block_time`.
+//!
+//! A [`MockChain`] is a chain backed by such a host. Its own light client is
+//! the mock client from `ibc_relayer_types::mock`, whose headers need no
+//! verification, and the proofs it returns are placeholders which it does
+//! not verify either. This makes it possible to exercise the relaying logic
+//! (handshakes, packet relaying, the supervisor) in unit tests which need no
+//! chain binary. Since a mock client must never be accepted outside of such
+//! tests, the mock chain is only available with the `mock` feature.
+
+pub mod config;
+pub mod host;
+mod store;
+
+#[cfg(any(test, feature = "mock"))]
+mod endpoint;
+
+#[cfg(any(test, feature = "mock"))]
+pub use self::endpoint::MockChain;
+
+#[cfg(test)]
+pub(crate) use self::endpoint::generate_key;
+
+pub use self::host::MockHost;
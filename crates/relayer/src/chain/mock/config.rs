@@ -0,0 +1,73 @@
+use core::time::Duration;
+
+use serde_derive::{Deserialize, Serialize};
+
+use ibc_relayer_types::core::ics24_host::identifier::ChainId;
+
+use crate::config::{default, PacketFilter};
+
+/// Configuration of an in-process mock chain.
+///
+/// A mock chain keeps its whole IBC store in memory and produces a new
+/// block every `block_time`, which makes it possible to exercise the
+/// relaying logic without running any full node.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct MockChainConfig {
+    /// The chain's network identifier
+    pub id: ChainId,
+
+    #[serde(default = "default_account_prefix")]
+    pub account_prefix: String,
+
+    #[serde(default = "default_key_name")]
+    pub key_name: String,
+
+    #[serde(default = "default_store_prefix")]
+    pub store_prefix: String,
+
+    /// The interval at which the chain produces new blocks
+    #[serde(default = "default_block_time", with = "humantime_serde")]
+    pub block_time: Duration,
+
+    /// How many packets to fetch at once from the chain when clearing packets
+    #[serde(default = "default::query_packets_chunk_size")]
+    pub query_packets_chunk_size: usize,
+
+    pub clear_interval: Option<u64>,
+
+    #[serde(default)]
+    pub packet_filter: PacketFilter,
+}
+
+impl MockChainConfig {
+    /// A configuration with the default settings for the chain with the given identifier.
+    pub fn new(id: ChainId) -> Self {
+        Self {
+            id,
+            account_prefix: default_account_prefix(),
+            key_name: default_key_name(),
+            store_prefix: default_store_prefix(),
+            block_time: default_block_time(),
+            query_packets_chunk_size: default::query_packets_chunk_size(),
+            clear_interval: None,
+            packet_filter: PacketFilter::default(),
+        }
+    }
+}
+
+fn default_account_prefix() -> String {
+    "mock".to_string()
+}
+
+fn default_key_name() -> String {
+    "relayer".to_string()
+}
+
+fn default_store_prefix() -> String {
+    "ibc".to_string()
+}
+
+fn default_block_time() -> Duration {
+    Duration::from_millis(100)
+}
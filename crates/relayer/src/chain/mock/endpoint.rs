@@ -0,0 +1,861 @@
+//! The [`MockChain`] endpoint.
+
+use alloc::sync::Arc;
+use core::str::FromStr;
+
+use bip39::{Language, Mnemonic, MnemonicType};
+use hdpath::StandardHDPath;
+use tendermint_rpc::endpoint::broadcast::tx_sync::Response as TxResponse;
+use tokio::runtime::Runtime as TokioRuntime;
+
+use ibc_proto::ibc::apps::fee::v1::{
+    QueryIncentivizedPacketRequest, QueryIncentivizedPacketResponse,
+};
+use ibc_relayer_types::applications::ics31_icq::response::CrossChainQueryResponse;
+use ibc_relayer_types::core::ics02_client::error::Error as ClientError;
+use ibc_relayer_types::core::ics02_client::events::UpdateClient;
+use ibc_relayer_types::core::ics03_connection::connection::{
+    ConnectionEnd, IdentifiedConnectionEnd,
+};
+use ibc_relayer_types::core::ics04_channel::channel::{ChannelEnd, IdentifiedChannelEnd};
+use ibc_relayer_types::core::ics04_channel::packet::Sequence;
+use ibc_relayer_types::core::ics04_channel::upgrade::Upgrade;
+use ibc_relayer_types::core::ics23_commitment::commitment::CommitmentPrefix;
+use ibc_relayer_types::core::ics23_commitment::merkle::MerkleProof;
+use ibc_relayer_types::core::ics24_host::identifier::{
+    ChainId, ChannelId, ClientId, ConnectionId, PortId,
+};
+use ibc_relayer_types::mock::client_state::MockClientState;
+use ibc_relayer_types::mock::consensus_state::MockConsensusState;
+use ibc_relayer_types::mock::header::MockHeader;
+use ibc_relayer_types::signer::Signer;
+use ibc_relayer_types::timestamp::Timestamp;
+use ibc_relayer_types::Height as ICSHeight;
+
+use crate::account::{Balance, Grants};
+use crate::chain::client::ClientSettings;
+use crate::chain::cosmos::version::Specs;
+use crate::chain::endpoint::{ChainEndpoint, ChainStatus, HealthCheck};
+use crate::chain::handle::Subscription;
+use crate::chain::requests::*;
+use crate::chain::tracking::TrackedMsgs;
+use crate::client_state::{AnyClientState, IdentifiedAnyClientState};
+use crate::config::{AddressType, ChainConfig, Error as ConfigError};
+use crate::consensus_state::AnyConsensusState;
+use crate::denom::DenomTrace;
+use crate::error::Error;
+use crate::event::IbcEventWithHeight;
+use crate::keyring::{KeyRing, Secp256k1KeyPair, SigningKeyPair, Store};
+use crate::misbehaviour::MisbehaviourEvidence;
+
+use super::config::MockChainConfig;
+use super::host::MockHost;
+
+pub struct MockChain {
+    config: MockChainConfig,
+    keybase: KeyRing<Secp256k1KeyPair>,
+    host: MockHost,
+}
+
+/// Generates a fresh key for the relayer to sign the messages it submits with.
+pub(crate) fn generate_key(account_prefix: &str) -> Result<Secp256k1KeyPair, Error> {
+    let mnemonic = Mnemonic::new(MnemonicType::Words24, Language::English);
+    let hd_path = StandardHDPath::from_str("m/44'/118'/0'/0/0")
+        .map_err(|_| Error::mock_chain("invalid HD path".to_string()))?;
+
+    Secp256k1KeyPair::from_mnemonic(
+        mnemonic.phrase(),
+        &hd_path,
+        &AddressType::Cosmos,
+        account_prefix,
+    )
+    .map_err(Error::key_base)
+}
+
+impl ChainEndpoint for MockChain {
+    type LightBlock = MockHeader;
+    type Header = MockHeader;
+    type ConsensusState = MockConsensusState;
+    type ClientState = MockClientState;
+    type Time = Timestamp;
+    type SigningKeyPair = Secp256k1KeyPair;
+
+    fn id(&self) -> &ChainId {
+        &self.config.id
+    }
+
+    fn config(&self) -> ChainConfig {
+        ChainConfig::Mock(self.config.clone())
+    }
+
+    fn bootstrap(config: ChainConfig, _rt: Arc<TokioRuntime>) -> Result<Self, Error> {
+        let ChainConfig::Mock(config) = config else {
+            return Err(Error::config(ConfigError::wrong_type()));
+        };
+
+        let mut keybase = KeyRing::new_secp256k1(
+            Store::Memory,
+            &config.account_prefix,
+            &config.id,
+            &None,
+            &None,
+            &None,
+        )
+        .map_err(Error::key_base)?;
+
+        keybase
+            .add_key(&config.key_name, generate_key(&config.account_prefix)?)
+            .map_err(Error::key_base)?;
+
+        let host = MockHost::new(&config.id, config.store_prefix.clone(), config.block_time)?;
+
+        Ok(Self {
+            config,
+            keybase,
+            host,
+        })
+    }
+
+    fn shutdown(self) -> Result<(), Error> {
+        self.host.shutdown();
+        Ok(())
+    }
+
+    fn health_check(&mut self) -> Result<HealthCheck, Error> {
+        Ok(HealthCheck::Healthy)
+    }
+
+    fn subscribe(&mut self) -> Result<Subscription, Error> {
+        Ok(self.host.subscribe())
+    }
+
+    fn keybase(&self) -> &KeyRing<Self::SigningKeyPair> {
+        &self.keybase
+    }
+
+    fn keybase_mut(&mut self) -> &mut KeyRing<Self::SigningKeyPair> {
+        &mut self.keybase
+    }
+
+    fn get_signer(&self) -> Result<Signer, Error> {
+        self.get_key()?
+            .account()
+            .parse()
+            .map_err(|e| Error::ics02(ClientError::signer(e)))
+    }
+
+    fn get_key(&self) -> Result<Self::SigningKeyPair, Error> {
+        self.keybase
+            .get_key(&self.config.key_name)
+            .map_err(|e| Error::key_not_found(self.config.key_name.clone(), e))
+    }
+
+    fn version_specs(&self) -> Result<Specs, Error> {
+        Ok(Specs {
+            cosmos_sdk: None,
+            ibc_go: None,
+            tendermint: None,
+            comet: None,
+        })
+    }
+
+    fn send_messages_and_wait_commit(
+        &mut self,
+        tracked_msgs: TrackedMsgs,
+    ) -> Result<Vec<IbcEventWithHeight>, Error> {
+        let (_, events) = self.host.submit(tracked_msgs);
+        Ok(events)
+    }
+
+    fn send_messages_and_wait_check_tx(
+        &mut self,
+        tracked_msgs: TrackedMsgs,
+    ) -> Result<Vec<TxResponse>, Error> {
+        let (response, _) = self.host.submit(tracked_msgs);
+        Ok(vec![response])
+    }
+
+    fn verify_header(
+        &mut self,
+        _trusted: ICSHeight,
+        target: ICSHeight,
+        _client_state: &AnyClientState,
+    ) -> Result<Self::LightBlock, Error> {
+        self.host.with_store(|store| store.header(target))
+    }
+
+    fn check_misbehaviour(
+        &mut self,
+        _update: &UpdateClient,
+        _client_state: &AnyClientState,
+    ) -> Result<Option<MisbehaviourEvidence>, Error> {
+        // Headers of a mock chain cannot conflict, as they are all produced by the same store
+        Ok(None)
+    }
+
+    fn query_balance(
+        &self,
+        _key_name: Option<&str>,
+        _denom: Option<&str>,
+    ) -> Result<Balance, Error> {
+        Err(Error::mock_chain(
+            "balances are not supported by the mock chain".to_string(),
+        ))
+    }
+
+    fn query_all_balances(&self, _key_name: Option<&str>) -> Result<Vec<Balance>, Error> {
+        Err(Error::mock_chain(
+            "balances are not supported by the mock chain".to_string(),
+        ))
+    }
+
+    fn query_denom_trace(&self, _hash: String) -> Result<DenomTrace, Error> {
+        Err(Error::mock_chain(
+            "denomination traces are not supported by the mock chain".to_string(),
+        ))
+    }
+
+    fn query_commitment_prefix(&self) -> Result<CommitmentPrefix, Error> {
+        self.host.query_commitment_prefix()
+    }
+
+    fn query_application_status(&self) -> Result<ChainStatus, Error> {
+        let header = self.host.latest_header();
+
+        Ok(ChainStatus {
+            height: header.height,
+            timestamp: header.timestamp,
+        })
+    }
+
+    fn query_clients(
+        &self,
+        request: QueryClientStatesRequest,
+    ) -> Result<Vec<IdentifiedAnyClientState>, Error> {
+        self.host.query_clients(request)
+    }
+
+    fn query_client_state(
+        &self,
+        request: QueryClientStateRequest,
+        include_proof: IncludeProof,
+    ) -> Result<(AnyClientState, Option<MerkleProof>), Error> {
+        self.host.query_client_state(request, include_proof)
+    }
+
+    fn query_consensus_state(
+        &self,
+        request: QueryConsensusStateRequest,
+        include_proof: IncludeProof,
+    ) -> Result<(AnyConsensusState, Option<MerkleProof>), Error> {
+        self.host.query_consensus_state(request, include_proof)
+    }
+
+    fn query_consensus_state_heights(
+        &self,
+        request: QueryConsensusStateHeightsRequest,
+    ) -> Result<Vec<ICSHeight>, Error> {
+        self.host.query_consensus_state_heights(request)
+    }
+
+    fn query_upgraded_client_state(
+        &self,
+        _request: QueryUpgradedClientStateRequest,
+    ) -> Result<(AnyClientState, MerkleProof), Error> {
+        Err(Error::mock_chain(
+            "upgrades are not supported by the mock chain".to_string(),
+        ))
+    }
+
+    fn query_upgraded_consensus_state(
+        &self,
+        _request: QueryUpgradedConsensusStateRequest,
+    ) -> Result<(AnyConsensusState, MerkleProof), Error> {
+        Err(Error::mock_chain(
+            "upgrades are not supported by the mock chain".to_string(),
+        ))
+    }
+
+    fn query_connections(
+        &self,
+        request: QueryConnectionsRequest,
+    ) -> Result<Vec<IdentifiedConnectionEnd>, Error> {
+        self.host.query_connections(request)
+    }
+
+    fn query_client_connections(
+        &self,
+        request: QueryClientConnectionsRequest,
+    ) -> Result<Vec<ConnectionId>, Error> {
+        self.host.query_client_connections(request)
+    }
+
+    fn query_connection(
+        &self,
+        request: QueryConnectionRequest,
+        include_proof: IncludeProof,
+    ) -> Result<(ConnectionEnd, Option<MerkleProof>), Error> {
+        self.host.query_connection(request, include_proof)
+    }
+
+    fn query_connection_channels(
+        &self,
+        request: QueryConnectionChannelsRequest,
+    ) -> Result<Vec<IdentifiedChannelEnd>, Error> {
+        self.host.query_connection_channels(request)
+    }
+
+    fn query_channels(
+        &self,
+        request: QueryChannelsRequest,
+    ) -> Result<Vec<IdentifiedChannelEnd>, Error> {
+        self.host.query_channels(request)
+    }
+
+    fn query_channel(
+        &self,
+        request: QueryChannelRequest,
+        include_proof: IncludeProof,
+    ) -> Result<(ChannelEnd, Option<MerkleProof>), Error> {
+        self.host.query_channel(request, include_proof)
+    }
+
+    fn query_upgrade(
+        &self,
+        _request: QueryUpgradeRequest,
+        _include_proof: IncludeProof,
+    ) -> Result<(Upgrade, Option<MerkleProof>), Error> {
+        Err(Error::mock_chain(
+            "channel upgrades are not supported by the mock chain".to_string(),
+        ))
+    }
+
+    fn query_channel_client_state(
+        &self,
+        request: QueryChannelClientStateRequest,
+    ) -> Result<Option<IdentifiedAnyClientState>, Error> {
+        self.host.query_channel_client_state(request)
+    }
+
+    fn query_packet_commitment(
+        &self,
+        request: QueryPacketCommitmentRequest,
+        include_proof: IncludeProof,
+    ) -> Result<(Vec<u8>, Option<MerkleProof>), Error> {
+        self.host.query_packet_commitment(request, include_proof)
+    }
+
+    fn query_packet_commitments(
+        &self,
+        request: QueryPacketCommitmentsRequest,
+    ) -> Result<(Vec<Sequence>, ICSHeight), Error> {
+        self.host.query_packet_commitments(request)
+    }
+
+    fn query_packet_receipt(
+        &self,
+        request: QueryPacketReceiptRequest,
+        include_proof: IncludeProof,
+    ) -> Result<(Vec<u8>, Option<MerkleProof>), Error> {
+        self.host.query_packet_receipt(request, include_proof)
+    }
+
+    fn query_unreceived_packets(
+        &self,
+        request: QueryUnreceivedPacketsRequest,
+    ) -> Result<Vec<Sequence>, Error> {
+        self.host.query_unreceived_packets(request)
+    }
+
+    fn query_packet_acknowledgement(
+        &self,
+        request: QueryPacketAcknowledgementRequest,
+        include_proof: IncludeProof,
+    ) -> Result<(Vec<u8>, Option<MerkleProof>), Error> {
+        self.host
+            .query_packet_acknowledgement(request, include_proof)
+    }
+
+    fn query_packet_acknowledgements(
+        &self,
+        request: QueryPacketAcknowledgementsRequest,
+    ) -> Result<(Vec<Sequence>, ICSHeight), Error> {
+        self.host.query_packet_acknowledgements(request)
+    }
+
+    fn query_unreceived_acknowledgements(
+        &self,
+        request: QueryUnreceivedAcksRequest,
+    ) -> Result<Vec<Sequence>, Error> {
+        self.host.query_unreceived_acknowledgements(request)
+    }
+
+    fn query_next_sequence_receive(
+        &self,
+        request: QueryNextSequenceReceiveRequest,
+        include_proof: IncludeProof,
+    ) -> Result<(Sequence, Option<MerkleProof>), Error> {
+        self.host
+            .query_next_sequence_receive(request, include_proof)
+    }
+
+    fn query_txs(&self, request: QueryTxRequest) -> Result<Vec<IbcEventWithHeight>, Error> {
+        self.host.query_txs(request)
+    }
+
+    fn query_packet_events(
+        &self,
+        request: QueryPacketEventDataRequest,
+    ) -> Result<Vec<IbcEventWithHeight>, Error> {
+        self.host.query_packet_events(request)
+    }
+
+    fn query_host_consensus_state(
+        &self,
+        request: QueryHostConsensusStateRequest,
+    ) -> Result<Self::ConsensusState, Error> {
+        self.host.with_store(|store| {
+            let height = store.query_height(request.height)?;
+            Ok(MockConsensusState::new(store.header(height)?))
+        })
+    }
+
+    fn build_client_state(
+        &self,
+        height: ICSHeight,
+        _settings: ClientSettings,
+    ) -> Result<Self::ClientState, Error> {
+        let header = self.host.with_store(|store| store.header(height))?;
+
+        Ok(MockClientState::new(header).with_chain_id(self.id().clone()))
+    }
+
+    fn build_consensus_state(
+        &self,
+        light_block: Self::LightBlock,
+    ) -> Result<Self::ConsensusState, Error> {
+        Ok(MockConsensusState::new(light_block))
+    }
+
+    fn build_header(
+        &mut self,
+        _trusted_height: ICSHeight,
+        target_height: ICSHeight,
+        _client_state: &AnyClientState,
+    ) -> Result<(Self::Header, Vec<Self::Header>), Error> {
+        // Mock headers need no intermediate headers to be verified
+        let header = self.host.with_store(|store| store.header(target_height))?;
+
+        Ok((header, Vec::new()))
+    }
+
+    fn maybe_register_counterparty_payee(
+        &mut self,
+        _channel_id: &ChannelId,
+        _port_id: &PortId,
+        _counterparty_payee: &Signer,
+    ) -> Result<(), Error> {
+        // The mock chain does not support fees, so there is no payee to register
+        Ok(())
+    }
+
+    fn cross_chain_query(
+        &self,
+        _requests: Vec<CrossChainQueryRequest>,
+    ) -> Result<Vec<CrossChainQueryResponse>, Error> {
+        Err(Error::mock_chain(
+            "cross-chain queries are not supported by the mock chain".to_string(),
+        ))
+    }
+
+    fn query_incentivized_packet(
+        &self,
+        _request: QueryIncentivizedPacketRequest,
+    ) -> Result<QueryIncentivizedPacketResponse, Error> {
+        Err(Error::mock_chain(
+            "fees are not supported by the mock chain".to_string(),
+        ))
+    }
+
+    fn query_consumer_chains(&self) -> Result<Vec<(ChainId, ClientId)>, Error> {
+        Ok(Vec::new())
+    }
+
+    fn query_grants(&self, _request: QueryGrantsRequest) -> Result<Grants, Error> {
+        Err(Error::mock_chain(
+            "fee grants are not supported by the mock chain".to_string(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use core::time::Duration;
+
+    use ibc_proto::cosmos::base::v1beta1::Coin;
+    use ibc_relayer_types::applications::transfer::msgs::transfer::MsgTransfer;
+    use ibc_relayer_types::core::ics02_client::msgs::create_client::MsgCreateClient;
+    use ibc_relayer_types::core::ics02_client::msgs::update_client::MsgUpdateClient;
+    use ibc_relayer_types::core::ics03_connection::connection::State as ConnectionState;
+    use ibc_relayer_types::core::ics04_channel::channel::{Ordering, State as ChannelState};
+    use ibc_relayer_types::core::ics04_channel::timeout::TimeoutHeight;
+    use ibc_relayer_types::events::IbcEvent;
+    use ibc_relayer_types::tx_msg::Msg;
+    use test_log::test;
+
+    use crate::chain::handle::{BaseChainHandle, ChainHandle};
+    use crate::chain::runtime::ChainRuntime;
+    use crate::channel::Channel;
+    use crate::config::{default, Config};
+    use crate::connection::Connection;
+    use crate::foreign_client::ForeignClient;
+    use crate::link::{Link, LinkParameters};
+    use crate::registry::SharedRegistry;
+    use crate::supervisor::{spawn_supervisor, SupervisorOptions};
+
+    fn mock_config(id: &str) -> ChainConfig {
+        ChainConfig::Mock(MockChainConfig {
+            block_time: Duration::from_millis(10),
+            ..MockChainConfig::new(ChainId::from_string(id))
+        })
+    }
+
+    fn spawn(id: &str, rt: Arc<TokioRuntime>) -> BaseChainHandle {
+        ChainRuntime::<MockChain>::spawn(mock_config(id), rt).unwrap()
+    }
+
+    /// Opens a connection and then an unordered transfer channel between the two chains.
+    fn open_channel(
+        chain_a: &BaseChainHandle,
+        chain_b: &BaseChainHandle,
+    ) -> Channel<BaseChainHandle, BaseChainHandle> {
+        let client_b_on_a = ForeignClient::new(chain_a.clone(), chain_b.clone()).unwrap();
+        let client_a_on_b = ForeignClient::new(chain_b.clone(), chain_a.clone()).unwrap();
+
+        let connection = Connection::new(client_b_on_a, client_a_on_b, Duration::ZERO).unwrap();
+
+        Channel::new(
+            connection,
+            Ordering::Unordered,
+            PortId::transfer(),
+            PortId::transfer(),
+            None,
+        )
+        .unwrap()
+    }
+
+    fn query_channel_end(chain: &BaseChainHandle, channel_id: &ChannelId) -> ChannelEnd {
+        let (channel, _) = chain
+            .query_channel(
+                QueryChannelRequest {
+                    port_id: PortId::transfer(),
+                    channel_id: channel_id.clone(),
+                    height: QueryHeight::Latest,
+                },
+                IncludeProof::No,
+            )
+            .unwrap();
+
+        channel
+    }
+
+    fn transfer_link(
+        src: &BaseChainHandle,
+        dst: &BaseChainHandle,
+        src_channel_id: &ChannelId,
+    ) -> Link<BaseChainHandle, BaseChainHandle> {
+        let opts = LinkParameters {
+            src_port_id: PortId::transfer(),
+            src_channel_id: src_channel_id.clone(),
+            max_memo_size: default::ics20_max_memo_size(),
+            max_receiver_size: default::ics20_max_receiver_size(),
+            exclude_src_sequences: vec![],
+            ics20_filter: Default::default(),
+        };
+
+        Link::new_from_opts(src.clone(), dst.clone(), opts, false, false).unwrap()
+    }
+
+    fn send_transfer(src: &BaseChainHandle, dst: &BaseChainHandle, src_channel_id: &ChannelId) {
+        let msg = MsgTransfer {
+            source_port: PortId::transfer(),
+            source_channel: src_channel_id.clone(),
+            token: Coin {
+                denom: "samoleans".to_string(),
+                amount: "1000".to_string(),
+            },
+            sender: src.get_signer().unwrap(),
+            receiver: dst.get_signer().unwrap(),
+            timeout_height: TimeoutHeight::no_timeout(),
+            timeout_timestamp: (Timestamp::now() + Duration::from_secs(600)).unwrap(),
+            memo: None,
+        };
+
+        let events = src
+            .send_messages_and_wait_commit(TrackedMsgs::new_single(msg.to_any(), "transfer"))
+            .unwrap();
+        assert!(matches!(events[0].event, IbcEvent::SendPacket(_)));
+    }
+
+    fn pending_packets(chain: &BaseChainHandle, channel_id: &ChannelId) -> Vec<Sequence> {
+        let (sequences, _) = chain
+            .query_packet_commitments(QueryPacketCommitmentsRequest {
+                port_id: PortId::transfer(),
+                channel_id: channel_id.clone(),
+                pagination: None,
+            })
+            .unwrap();
+
+        sequences
+    }
+
+    fn bootstrap(id: &str) -> MockChain {
+        let rt = Arc::new(TokioRuntime::new().unwrap());
+        MockChain::bootstrap(mock_config(id), rt).unwrap()
+    }
+
+    /// Creates a client of `src` on `dst`, returning its identifier.
+    fn create_client(dst: &mut MockChain, src: &MockChain) -> ClientId {
+        let height = src.query_application_status().unwrap().height;
+        let client_state = src
+            .build_client_state(height, ClientSettings::Mock)
+            .unwrap();
+        let header = src.host.with_store(|store| store.header(height)).unwrap();
+        let consensus_state = src.build_consensus_state(header).unwrap();
+
+        let msg = MsgCreateClient::new(
+            client_state.into(),
+            consensus_state.into(),
+            dst.get_signer().unwrap(),
+        )
+        .unwrap();
+
+        let events = dst
+            .send_messages_and_wait_commit(TrackedMsgs::new_single(msg.to_any(), "create_client"))
+            .unwrap();
+
+        match &events[0].event {
+            IbcEvent::CreateClient(event) => event.client_id().clone(),
+            event => panic!("expected a CreateClient event, got {event:?}"),
+        }
+    }
+
+    #[test]
+    fn create_and_update_client() {
+        let mut chain_a = bootstrap("mock-a-0");
+        let mut chain_b = bootstrap("mock-b-0");
+
+        let client_id = create_client(&mut chain_a, &chain_b);
+        let create_height = chain_b.query_application_status().unwrap().height;
+
+        let (client_state, _) = chain_a
+            .query_client_state(
+                QueryClientStateRequest {
+                    client_id: client_id.clone(),
+                    height: QueryHeight::Latest,
+                },
+                IncludeProof::No,
+            )
+            .unwrap();
+        assert_eq!(client_state.chain_id(), chain_b.id().clone());
+
+        std::thread::sleep(Duration::from_millis(50));
+
+        let target_height = chain_b.query_application_status().unwrap().height;
+        let (header, _) = chain_b
+            .build_header(create_height, target_height, &client_state)
+            .unwrap();
+
+        let msg = MsgUpdateClient {
+            client_id: client_id.clone(),
+            header: header.into(),
+            signer: chain_a.get_signer().unwrap(),
+        };
+
+        let events = chain_a
+            .send_messages_and_wait_commit(TrackedMsgs::new_single(msg.to_any(), "update_client"))
+            .unwrap();
+        assert!(matches!(events[0].event, IbcEvent::UpdateClient(_)));
+
+        let heights = chain_a
+            .query_consensus_state_heights(QueryConsensusStateHeightsRequest {
+                client_id,
+                pagination: None,
+            })
+            .unwrap();
+        assert!(heights.contains(&target_height));
+
+        chain_a.shutdown().unwrap();
+        chain_b.shutdown().unwrap();
+    }
+
+    #[test]
+    fn failed_transaction_yields_chain_error() {
+        let mut chain_a = bootstrap("mock-a-0");
+        let chain_b = bootstrap("mock-b-0");
+
+        // The client to update does not exist on chain A
+        let header = chain_b.host.latest_header();
+        let msg = MsgUpdateClient {
+            client_id: ClientId::default(),
+            header: header.into(),
+            signer: chain_a.get_signer().unwrap(),
+        };
+
+        let events = chain_a
+            .send_messages_and_wait_commit(TrackedMsgs::new_single(msg.to_any(), "update_client"))
+            .unwrap();
+
+        assert!(matches!(
+            events[..],
+            [IbcEventWithHeight {
+                event: IbcEvent::ChainError(_),
+                ..
+            }]
+        ));
+    }
+
+    #[test]
+    fn connection_handshake() {
+        let rt = Arc::new(TokioRuntime::new().unwrap());
+
+        let chain_a: BaseChainHandle =
+            ChainRuntime::<MockChain>::spawn(mock_config("mock-a-0"), rt.clone()).unwrap();
+        let chain_b: BaseChainHandle =
+            ChainRuntime::<MockChain>::spawn(mock_config("mock-b-0"), rt).unwrap();
+
+        let client_b_on_a = ForeignClient::new(chain_a.clone(), chain_b.clone()).unwrap();
+        let client_a_on_b = ForeignClient::new(chain_b.clone(), chain_a.clone()).unwrap();
+
+        let connection = Connection::new(client_b_on_a, client_a_on_b, Duration::ZERO).unwrap();
+
+        let (end_a, _) = chain_a
+            .query_connection(
+                QueryConnectionRequest {
+                    connection_id: connection.src_connection_id().unwrap().clone(),
+                    height: QueryHeight::Latest,
+                },
+                IncludeProof::No,
+            )
+            .unwrap();
+
+        let (end_b, _) = chain_b
+            .query_connection(
+                QueryConnectionRequest {
+                    connection_id: connection.dst_connection_id().unwrap().clone(),
+                    height: QueryHeight::Latest,
+                },
+                IncludeProof::No,
+            )
+            .unwrap();
+
+        assert!(end_a.state_matches(&ConnectionState::Open));
+        assert!(end_b.state_matches(&ConnectionState::Open));
+
+        chain_a.shutdown().unwrap();
+        chain_b.shutdown().unwrap();
+    }
+
+    #[test]
+    fn channel_handshake() {
+        let rt = Arc::new(TokioRuntime::new().unwrap());
+
+        let chain_a = spawn("mock-a-0", rt.clone());
+        let chain_b = spawn("mock-b-0", rt);
+
+        let channel = open_channel(&chain_a, &chain_b);
+        let channel_a = channel.src_channel_id().unwrap().clone();
+        let channel_b = channel.dst_channel_id().unwrap().clone();
+
+        let end_a = query_channel_end(&chain_a, &channel_a);
+        let end_b = query_channel_end(&chain_b, &channel_b);
+
+        assert!(end_a.state_matches(&ChannelState::Open));
+        assert!(end_b.state_matches(&ChannelState::Open));
+        assert_eq!(end_a.counterparty().channel_id(), Some(&channel_b));
+        assert_eq!(end_b.counterparty().channel_id(), Some(&channel_a));
+
+        chain_a.shutdown().unwrap();
+        chain_b.shutdown().unwrap();
+    }
+
+    #[test]
+    fn packet_relay() {
+        let rt = Arc::new(TokioRuntime::new().unwrap());
+
+        let chain_a = spawn("mock-a-0", rt.clone());
+        let chain_b = spawn("mock-b-0", rt);
+
+        let channel = open_channel(&chain_a, &chain_b);
+        let channel_a = channel.src_channel_id().unwrap().clone();
+        let channel_b = channel.dst_channel_id().unwrap().clone();
+
+        send_transfer(&chain_a, &chain_b, &channel_a);
+
+        // Relay the packet from chain A to chain B, which acknowledges it
+        let events = transfer_link(&chain_a, &chain_b, &channel_a)
+            .relay_recv_packet_and_timeout_messages(vec![])
+            .unwrap();
+        assert!(events
+            .iter()
+            .any(|event| matches!(event, IbcEvent::WriteAcknowledgement(_))));
+
+        // Relay the acknowledgement back from chain B to chain A
+        let events = transfer_link(&chain_b, &chain_a, &channel_b)
+            .relay_ack_packet_messages(vec![])
+            .unwrap();
+        assert!(events
+            .iter()
+            .any(|event| matches!(event, IbcEvent::AcknowledgePacket(_))));
+
+        // The acknowledgement deletes the packet commitment on chain A
+        assert!(pending_packets(&chain_a, &channel_a).is_empty());
+
+        chain_a.shutdown().unwrap();
+        chain_b.shutdown().unwrap();
+    }
+
+    #[test]
+    fn supervisor_relays_packets() {
+        let config = Config {
+            chains: vec![mock_config("mock-a-0"), mock_config("mock-b-0")],
+            ..Config::default()
+        };
+
+        let registry = SharedRegistry::<BaseChainHandle>::new(config.clone());
+        let chain_a = registry
+            .get_or_spawn(&ChainId::from_string("mock-a-0"))
+            .unwrap();
+        let chain_b = registry
+            .get_or_spawn(&ChainId::from_string("mock-b-0"))
+            .unwrap();
+
+        let channel = open_channel(&chain_a, &chain_b);
+        let channel_a = channel.src_channel_id().unwrap().clone();
+
+        send_transfer(&chain_a, &chain_b, &channel_a);
+
+        let supervisor = spawn_supervisor(
+            config,
+            registry,
+            None,
+            SupervisorOptions {
+                health_check: false,
+                force_full_scan: false,
+            },
+        )
+        .unwrap();
+
+        // The packet is cleared on start, and its acknowledgement relayed back to chain A
+        let relayed = (0..100).any(|_| {
+            std::thread::sleep(Duration::from_millis(100));
+            pending_packets(&chain_a, &channel_a).is_empty()
+        });
+
+        supervisor.shutdown();
+
+        assert!(relayed, "the supervisor did not relay the packet");
+    }
+}
@@ -0,0 +1,579 @@
+//! The in-memory IBC host shared by the [`MockChain`](super::MockChain)
+//! and the [`SoloMachine`](crate::chain::solomachine::SoloMachine).
+
+use alloc::sync::Arc;
+use core::time::Duration;
+use std::sync::RwLock;
+
+use tendermint::abci::Code;
+use tendermint_rpc::endpoint::broadcast::tx_sync::Response as TxResponse;
+use tracing::error_span;
+
+use ibc_relayer_types::core::ics02_client::error::Error as ClientError;
+use ibc_relayer_types::core::ics03_connection::connection::{
+    ConnectionEnd, IdentifiedConnectionEnd,
+};
+use ibc_relayer_types::core::ics04_channel::channel::{ChannelEnd, IdentifiedChannelEnd};
+use ibc_relayer_types::core::ics04_channel::packet::Sequence;
+use ibc_relayer_types::core::ics23_commitment::commitment::CommitmentPrefix;
+use ibc_relayer_types::core::ics23_commitment::merkle::MerkleProof;
+use ibc_relayer_types::core::ics24_host::identifier::{ChainId, ConnectionId};
+use ibc_relayer_types::events::{IbcEvent, WithBlockDataType};
+use ibc_relayer_types::mock::header::MockHeader;
+use ibc_relayer_types::Height as ICSHeight;
+
+use crate::chain::handle::Subscription;
+use crate::chain::requests::*;
+use crate::chain::tracking::TrackedMsgs;
+use crate::client_state::{AnyClientState, IdentifiedAnyClientState};
+use crate::consensus_state::AnyConsensusState;
+use crate::error::Error;
+use crate::event::IbcEventWithHeight;
+use crate::util::lock::LockExt;
+use crate::util::task::{spawn_background_task, Next, TaskError, TaskHandle};
+
+use super::store::MockStore;
+
+/// Hosts clients, connections, channels and packets in an in-memory store,
+/// which commits a new block for every transaction and an empty block
+/// every `block_time`.
+pub struct MockHost {
+    store_prefix: String,
+    store: Arc<RwLock<MockStore>>,
+    block_producer: TaskHandle,
+}
+
+impl MockHost {
+    pub fn new(
+        chain_id: &ChainId,
+        store_prefix: String,
+        block_time: Duration,
+    ) -> Result<Self, Error> {
+        let store = Arc::new(RwLock::new(MockStore::new(chain_id.clone())?));
+
+        let block_producer = {
+            let store = store.clone();
+
+            spawn_background_task(
+                error_span!("mock.block_producer", chain = %chain_id),
+                Some(block_time),
+                move || -> Result<Next, TaskError<Error>> {
+                    store.acquire_write().produce_block();
+                    Ok(Next::Continue)
+                },
+            )
+        };
+
+        Ok(Self {
+            store_prefix,
+            store,
+            block_producer,
+        })
+    }
+
+    pub fn shutdown(self) {
+        self.block_producer.shutdown_and_wait();
+    }
+
+    pub fn subscribe(&self) -> Subscription {
+        self.store.acquire_write().subscribe()
+    }
+
+    /// Runs the given closure against the IBC store of the host.
+    pub(super) fn with_store<R>(
+        &self,
+        f: impl FnOnce(&MockStore) -> Result<R, Error>,
+    ) -> Result<R, Error> {
+        f(&self.store.acquire_read())
+    }
+
+    /// The header of the latest block committed by the host.
+    pub fn latest_header(&self) -> MockHeader {
+        self.store.acquire_read().latest_header()
+    }
+
+    /// Submits the given messages as a single transaction.
+    ///
+    /// As on a Cosmos chain, a failed transaction yields a `ChainError` event
+    /// and a response with a non-zero code rather than an error.
+    pub fn submit(&self, tracked_msgs: TrackedMsgs) -> (TxResponse, Vec<IbcEventWithHeight>) {
+        let mut store = self.store.acquire_write();
+
+        match store.submit(tracked_msgs.msgs, tracked_msgs.tracking_id) {
+            Ok((hash, events)) => {
+                let response = TxResponse {
+                    code: Code::Ok,
+                    data: Default::default(),
+                    log: String::new(),
+                    hash,
+                };
+
+                (response, events)
+            }
+            Err(e) => {
+                let response = TxResponse {
+                    code: Code::from(1),
+                    data: Default::default(),
+                    log: e.to_string(),
+                    hash: Default::default(),
+                };
+
+                let event = IbcEventWithHeight::new(
+                    IbcEvent::ChainError(e.to_string()),
+                    store.latest_height(),
+                );
+
+                (response, vec![event])
+            }
+        }
+    }
+
+    pub fn query_commitment_prefix(&self) -> Result<CommitmentPrefix, Error> {
+        CommitmentPrefix::try_from(self.store_prefix.as_bytes().to_vec())
+            .map_err(|_| Error::ics02(ClientError::empty_prefix()))
+    }
+
+    pub fn query_clients(
+        &self,
+        _request: QueryClientStatesRequest,
+    ) -> Result<Vec<IdentifiedAnyClientState>, Error> {
+        self.with_store(|store| {
+            let clients = store
+                .state(QueryHeight::Latest)?
+                .clients
+                .iter()
+                .map(|(client_id, record)| {
+                    IdentifiedAnyClientState::new(client_id.clone(), record.client_state.clone())
+                })
+                .collect();
+
+            Ok(clients)
+        })
+    }
+
+    pub fn query_client_state(
+        &self,
+        request: QueryClientStateRequest,
+        include_proof: IncludeProof,
+    ) -> Result<(AnyClientState, Option<MerkleProof>), Error> {
+        self.with_store(|store| {
+            let client_state = store
+                .state(request.height)?
+                .client(&request.client_id)?
+                .client_state
+                .clone();
+
+            Ok((client_state, mock_proof(include_proof)))
+        })
+    }
+
+    pub fn query_consensus_state(
+        &self,
+        request: QueryConsensusStateRequest,
+        include_proof: IncludeProof,
+    ) -> Result<(AnyConsensusState, Option<MerkleProof>), Error> {
+        self.with_store(|store| {
+            let consensus_state = store
+                .state(request.query_height)?
+                .client(&request.client_id)?
+                .consensus_states
+                .get(&request.consensus_height)
+                .cloned()
+                .ok_or_else(|| {
+                    Error::mock_chain(format!(
+                        "client '{}' has no consensus state at height {}",
+                        request.client_id, request.consensus_height
+                    ))
+                })?;
+
+            Ok((consensus_state, mock_proof(include_proof)))
+        })
+    }
+
+    pub fn query_consensus_state_heights(
+        &self,
+        request: QueryConsensusStateHeightsRequest,
+    ) -> Result<Vec<ICSHeight>, Error> {
+        self.with_store(|store| {
+            let heights = store
+                .state(QueryHeight::Latest)?
+                .client(&request.client_id)?
+                .consensus_states
+                .keys()
+                .copied()
+                .collect();
+
+            Ok(heights)
+        })
+    }
+
+    pub fn query_connections(
+        &self,
+        _request: QueryConnectionsRequest,
+    ) -> Result<Vec<IdentifiedConnectionEnd>, Error> {
+        self.with_store(|store| {
+            let connections = store
+                .state(QueryHeight::Latest)?
+                .connections
+                .iter()
+                .map(|(connection_id, connection)| {
+                    IdentifiedConnectionEnd::new(connection_id.clone(), connection.clone())
+                })
+                .collect();
+
+            Ok(connections)
+        })
+    }
+
+    pub fn query_client_connections(
+        &self,
+        request: QueryClientConnectionsRequest,
+    ) -> Result<Vec<ConnectionId>, Error> {
+        self.with_store(|store| {
+            let connection_ids = store
+                .state(QueryHeight::Latest)?
+                .connections
+                .iter()
+                .filter(|(_, connection)| connection.client_id() == &request.client_id)
+                .map(|(connection_id, _)| connection_id.clone())
+                .collect();
+
+            Ok(connection_ids)
+        })
+    }
+
+    pub fn query_connection(
+        &self,
+        request: QueryConnectionRequest,
+        include_proof: IncludeProof,
+    ) -> Result<(ConnectionEnd, Option<MerkleProof>), Error> {
+        self.with_store(|store| {
+            // As on ibc-go, querying a connection which does not exist yields an empty end
+            let connection = store
+                .state(request.height)?
+                .connections
+                .get(&request.connection_id)
+                .cloned()
+                .unwrap_or_default();
+
+            Ok((connection, mock_proof(include_proof)))
+        })
+    }
+
+    pub fn query_connection_channels(
+        &self,
+        request: QueryConnectionChannelsRequest,
+    ) -> Result<Vec<IdentifiedChannelEnd>, Error> {
+        self.with_store(|store| {
+            let channels = store
+                .state(QueryHeight::Latest)?
+                .channels
+                .iter()
+                .filter(|(_, channel)| channel.connection_hops.contains(&request.connection_id))
+                .map(|((port_id, channel_id), channel)| {
+                    IdentifiedChannelEnd::new(port_id.clone(), channel_id.clone(), channel.clone())
+                })
+                .collect();
+
+            Ok(channels)
+        })
+    }
+
+    pub fn query_channels(
+        &self,
+        _request: QueryChannelsRequest,
+    ) -> Result<Vec<IdentifiedChannelEnd>, Error> {
+        self.with_store(|store| {
+            let channels = store
+                .state(QueryHeight::Latest)?
+                .channels
+                .iter()
+                .map(|((port_id, channel_id), channel)| {
+                    IdentifiedChannelEnd::new(port_id.clone(), channel_id.clone(), channel.clone())
+                })
+                .collect();
+
+            Ok(channels)
+        })
+    }
+
+    pub fn query_channel(
+        &self,
+        request: QueryChannelRequest,
+        include_proof: IncludeProof,
+    ) -> Result<(ChannelEnd, Option<MerkleProof>), Error> {
+        self.with_store(|store| {
+            // As on ibc-go, querying a channel which does not exist yields an empty end
+            let channel = store
+                .state(request.height)?
+                .channels
+                .get(&(request.port_id, request.channel_id))
+                .cloned()
+                .unwrap_or_default();
+
+            Ok((channel, mock_proof(include_proof)))
+        })
+    }
+
+    pub fn query_channel_client_state(
+        &self,
+        request: QueryChannelClientStateRequest,
+    ) -> Result<Option<IdentifiedAnyClientState>, Error> {
+        self.with_store(|store| {
+            let state = store.state(QueryHeight::Latest)?;
+
+            let client_state = state
+                .channels
+                .get(&(request.port_id, request.channel_id))
+                .and_then(|channel| channel.connection_hops.first())
+                .and_then(|connection_id| state.connections.get(connection_id))
+                .and_then(|connection| {
+                    let client_id = connection.client_id();
+
+                    state.clients.get(client_id).map(|record| {
+                        IdentifiedAnyClientState::new(
+                            client_id.clone(),
+                            record.client_state.clone(),
+                        )
+                    })
+                });
+
+            Ok(client_state)
+        })
+    }
+
+    pub fn query_packet_commitment(
+        &self,
+        request: QueryPacketCommitmentRequest,
+        include_proof: IncludeProof,
+    ) -> Result<(Vec<u8>, Option<MerkleProof>), Error> {
+        self.with_store(|store| {
+            let commitment = store
+                .state(request.height)?
+                .packet_commitments
+                .get(&(request.port_id, request.channel_id, request.sequence))
+                .cloned()
+                .unwrap_or_default();
+
+            Ok((commitment, mock_proof(include_proof)))
+        })
+    }
+
+    pub fn query_packet_commitments(
+        &self,
+        request: QueryPacketCommitmentsRequest,
+    ) -> Result<(Vec<Sequence>, ICSHeight), Error> {
+        self.with_store(|store| {
+            let sequences = store
+                .state(QueryHeight::Latest)?
+                .packet_commitment_sequences(&request.port_id, &request.channel_id);
+
+            Ok((sequences, store.latest_height()))
+        })
+    }
+
+    pub fn query_packet_receipt(
+        &self,
+        request: QueryPacketReceiptRequest,
+        include_proof: IncludeProof,
+    ) -> Result<(Vec<u8>, Option<MerkleProof>), Error> {
+        self.with_store(|store| {
+            let received = store.state(request.height)?.has_received(
+                &request.port_id,
+                &request.channel_id,
+                request.sequence,
+            );
+
+            let receipt = if received { vec![1] } else { Vec::new() };
+
+            Ok((receipt, mock_proof(include_proof)))
+        })
+    }
+
+    pub fn query_unreceived_packets(
+        &self,
+        request: QueryUnreceivedPacketsRequest,
+    ) -> Result<Vec<Sequence>, Error> {
+        self.with_store(|store| {
+            let state = store.state(QueryHeight::Latest)?;
+
+            let sequences = request
+                .packet_commitment_sequences
+                .into_iter()
+                .filter(|sequence| {
+                    !state.has_received(&request.port_id, &request.channel_id, *sequence)
+                })
+                .collect();
+
+            Ok(sequences)
+        })
+    }
+
+    pub fn query_packet_acknowledgement(
+        &self,
+        request: QueryPacketAcknowledgementRequest,
+        include_proof: IncludeProof,
+    ) -> Result<(Vec<u8>, Option<MerkleProof>), Error> {
+        self.with_store(|store| {
+            let ack = store
+                .state(request.height)?
+                .packet_acknowledgements
+                .get(&(request.port_id, request.channel_id, request.sequence))
+                .cloned()
+                .unwrap_or_default();
+
+            Ok((ack, mock_proof(include_proof)))
+        })
+    }
+
+    pub fn query_packet_acknowledgements(
+        &self,
+        request: QueryPacketAcknowledgementsRequest,
+    ) -> Result<(Vec<Sequence>, ICSHeight), Error> {
+        self.with_store(|store| {
+            if request.packet_commitment_sequences.is_empty() {
+                return Ok((Vec::new(), store.latest_height()));
+            }
+
+            let sequences = store
+                .state(QueryHeight::Latest)?
+                .packet_acknowledgement_sequences(&request.port_id, &request.channel_id)
+                .into_iter()
+                .filter(|sequence| request.packet_commitment_sequences.contains(sequence))
+                .collect();
+
+            Ok((sequences, store.latest_height()))
+        })
+    }
+
+    pub fn query_unreceived_acknowledgements(
+        &self,
+        request: QueryUnreceivedAcksRequest,
+    ) -> Result<Vec<Sequence>, Error> {
+        self.with_store(|store| {
+            let state = store.state(QueryHeight::Latest)?;
+
+            let mut sequences: Vec<_> = request
+                .packet_ack_sequences
+                .into_iter()
+                .filter(|sequence| {
+                    state.packet_commitments.contains_key(&(
+                        request.port_id.clone(),
+                        request.channel_id.clone(),
+                        *sequence,
+                    ))
+                })
+                .collect();
+
+            sequences.sort_unstable();
+            Ok(sequences)
+        })
+    }
+
+    pub fn query_next_sequence_receive(
+        &self,
+        request: QueryNextSequenceReceiveRequest,
+        include_proof: IncludeProof,
+    ) -> Result<(Sequence, Option<MerkleProof>), Error> {
+        self.with_store(|store| {
+            let state = store.state(request.height)?;
+            let channel = state.channel(&request.port_id, &request.channel_id)?;
+
+            if !channel.ordering.is_ordered() {
+                return Err(Error::mock_chain(format!(
+                    "channel '{}/{}' is not ordered",
+                    request.channel_id, request.port_id
+                )));
+            }
+
+            let sequence = state
+                .next_sequence_recv
+                .get(&(request.port_id, request.channel_id))
+                .copied()
+                .unwrap_or_else(|| Sequence::from(1));
+
+            Ok((sequence, mock_proof(include_proof)))
+        })
+    }
+
+    pub fn query_txs(&self, request: QueryTxRequest) -> Result<Vec<IbcEventWithHeight>, Error> {
+        self.with_store(|store| match request {
+            QueryTxRequest::Client(request) => {
+                let max_height = store.query_height(request.query_height)?;
+
+                // Only the first event matching the request is of interest, see `query_txs` for Cosmos chains
+                let event = store
+                    .events()
+                    .iter()
+                    .filter(|event| event.height <= max_height)
+                    .find(|event| match (&event.event, &request.event_id) {
+                        (IbcEvent::CreateClient(e), WithBlockDataType::CreateClient) => {
+                            e.client_id() == &request.client_id
+                                && e.0.consensus_height == request.consensus_height
+                        }
+                        (IbcEvent::UpdateClient(e), WithBlockDataType::UpdateClient) => {
+                            e.client_id() == &request.client_id
+                                && e.consensus_height() == request.consensus_height
+                        }
+                        _ => false,
+                    })
+                    .cloned();
+
+                Ok(event.into_iter().collect())
+            }
+            QueryTxRequest::Transaction(tx) => Ok(store.tx_events(&tx.0)),
+        })
+    }
+
+    pub fn query_packet_events(
+        &self,
+        request: QueryPacketEventDataRequest,
+    ) -> Result<Vec<IbcEventWithHeight>, Error> {
+        self.with_store(|store| {
+            let (max_height, exact) = match request.height {
+                Qualified::SmallerEqual(height) => (store.query_height(height)?, false),
+                Qualified::Equal(height) => (store.query_height(height)?, true),
+            };
+
+            let events = store
+                .events()
+                .iter()
+                .filter(|event| {
+                    if exact {
+                        event.height == max_height
+                    } else {
+                        event.height <= max_height
+                    }
+                })
+                .filter(|event| {
+                    let packet = match (&event.event, &request.event_id) {
+                        (IbcEvent::SendPacket(e), WithBlockDataType::SendPacket) => &e.packet,
+                        (IbcEvent::WriteAcknowledgement(e), WithBlockDataType::WriteAck) => {
+                            &e.packet
+                        }
+                        _ => return false,
+                    };
+
+                    packet.source_port == request.source_port_id
+                        && packet.source_channel == request.source_channel_id
+                        && packet.destination_port == request.destination_port_id
+                        && packet.destination_channel == request.destination_channel_id
+                        && request.sequences.contains(&packet.sequence)
+                })
+                .cloned()
+                .collect();
+
+            Ok(events)
+        })
+    }
+}
+
+/// The placeholder proof returned by the queries of a mock host.
+fn mock_proof(include_proof: IncludeProof) -> Option<MerkleProof> {
+    match include_proof {
+        IncludeProof::Yes => Some(MerkleProof {
+            proofs: vec![Default::default()],
+        }),
+        IncludeProof::No => None,
+    }
+}
@@ -0,0 +1,1137 @@
+//! The in-memory IBC store of a [`MockHost`](super::MockHost).
+//!
+//! Every block committed by the store records the IBC state resulting from
+//! the messages it contains, so that queries can be served at any past height.
+
+use alloc::collections::{BTreeMap, BTreeSet};
+use alloc::sync::Arc;
+
+use ibc_proto::google::protobuf::Any;
+use ibc_proto::ibc::applications::transfer::v2::FungibleTokenPacketData as RawPacketData;
+use secp256k1::ecdsa::Signature;
+use secp256k1::{Message, PublicKey, Secp256k1};
+use sha2::{Digest, Sha256};
+use tendermint::Hash as TxHash;
+
+use ibc_relayer_types::applications::transfer::acknowledgement::Acknowledgement as TransferAck;
+use ibc_relayer_types::applications::transfer::msgs::transfer::{self, MsgTransfer};
+use ibc_relayer_types::clients::ics06_solomachine::client_state::ClientState as SmClientState;
+use ibc_relayer_types::clients::ics06_solomachine::consensus_state::ConsensusState as SmConsensusState;
+use ibc_relayer_types::clients::ics06_solomachine::header::Header as SmHeader;
+use ibc_relayer_types::clients::ics06_solomachine::signing::{
+    decode_signature, header_data, sign_bytes, HEADER_PATH,
+};
+use ibc_relayer_types::clients::ics07_tendermint::consensus_state::ConsensusState as TmConsensusState;
+use ibc_relayer_types::core::ics02_client::events::{
+    Attributes as ClientAttributes, CreateClient, NewBlock, UpdateClient,
+};
+use ibc_relayer_types::core::ics02_client::header::{AnyHeader, Header};
+use ibc_relayer_types::core::ics02_client::msgs::create_client::MsgCreateClient;
+use ibc_relayer_types::core::ics02_client::msgs::update_client::MsgUpdateClient;
+use ibc_relayer_types::core::ics02_client::msgs::ClientMsg;
+use ibc_relayer_types::core::ics03_connection::connection::{
+    ConnectionEnd, Counterparty as ConnectionCounterparty, State as ConnectionState,
+};
+use ibc_relayer_types::core::ics03_connection::events as connection_events;
+use ibc_relayer_types::core::ics03_connection::msgs::ConnectionMsg;
+use ibc_relayer_types::core::ics03_connection::version::get_compatible_versions;
+use ibc_relayer_types::core::ics04_channel::channel::{ChannelEnd, State as ChannelState};
+use ibc_relayer_types::core::ics04_channel::events as channel_events;
+use ibc_relayer_types::core::ics04_channel::msgs::{ChannelMsg, PacketMsg};
+use ibc_relayer_types::core::ics04_channel::packet::{Packet, Sequence};
+use ibc_relayer_types::core::ics24_host::identifier::{
+    ChainId, ChannelId, ClientId, ConnectionId, PortId,
+};
+use ibc_relayer_types::core::ics26_routing::msgs::Ics26Envelope;
+use ibc_relayer_types::events::IbcEvent;
+#[cfg(any(test, feature = "mock"))]
+use ibc_relayer_types::mock::client_state::MockClientState;
+#[cfg(any(test, feature = "mock"))]
+use ibc_relayer_types::mock::consensus_state::MockConsensusState;
+use ibc_relayer_types::mock::header::MockHeader;
+use ibc_relayer_types::proofs::Proofs;
+use ibc_relayer_types::timestamp::Timestamp;
+use ibc_relayer_types::Height;
+
+use crate::chain::requests::QueryHeight;
+use crate::chain::tracking::TrackingId;
+use crate::client_state::AnyClientState;
+use crate::consensus_state::AnyConsensusState;
+use crate::error::Error;
+use crate::event::bus::EventBus;
+use crate::event::source::{EventBatch, Result as EventResult};
+use crate::event::IbcEventWithHeight;
+
+/// A packet is identified by the port and channel it was sent or received on,
+/// together with its sequence number.
+pub type PacketKey = (PortId, ChannelId, Sequence);
+
+/// The state of a client hosted by the mock chain.
+#[derive(Clone, Debug)]
+pub struct ClientRecord {
+    pub client_state: AnyClientState,
+    pub consensus_states: BTreeMap<Height, AnyConsensusState>,
+}
+
+/// The IBC state of the mock chain at a given height.
+#[derive(Clone, Debug, Default)]
+pub struct IbcState {
+    pub clients: BTreeMap<ClientId, ClientRecord>,
+    pub connections: BTreeMap<ConnectionId, ConnectionEnd>,
+    pub channels: BTreeMap<(PortId, ChannelId), ChannelEnd>,
+    pub next_sequence_send: BTreeMap<(PortId, ChannelId), Sequence>,
+    pub next_sequence_recv: BTreeMap<(PortId, ChannelId), Sequence>,
+    pub next_sequence_ack: BTreeMap<(PortId, ChannelId), Sequence>,
+    pub packet_commitments: BTreeMap<PacketKey, Vec<u8>>,
+    pub packet_receipts: BTreeSet<PacketKey>,
+    pub packet_acknowledgements: BTreeMap<PacketKey, Vec<u8>>,
+    client_counter: u64,
+    connection_counter: u64,
+    channel_counter: u64,
+}
+
+impl IbcState {
+    pub fn client(&self, client_id: &ClientId) -> Result<&ClientRecord, Error> {
+        self.clients
+            .get(client_id)
+            .ok_or_else(|| Error::mock_chain(format!("client '{client_id}' not found")))
+    }
+
+    pub fn connection(&self, connection_id: &ConnectionId) -> Result<&ConnectionEnd, Error> {
+        self.connections
+            .get(connection_id)
+            .ok_or_else(|| Error::mock_chain(format!("connection '{connection_id}' not found")))
+    }
+
+    pub fn channel(&self, port_id: &PortId, channel_id: &ChannelId) -> Result<&ChannelEnd, Error> {
+        self.channels
+            .get(&(port_id.clone(), channel_id.clone()))
+            .ok_or_else(|| Error::mock_chain(format!("channel '{channel_id}/{port_id}' not found")))
+    }
+
+    /// The sequences of the packets sent on the given channel
+    /// whose commitment has not been cleared yet.
+    pub fn packet_commitment_sequences(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Vec<Sequence> {
+        self.packet_commitments
+            .keys()
+            .filter(|(port, channel, _)| port == port_id && channel == channel_id)
+            .map(|(_, _, sequence)| *sequence)
+            .collect()
+    }
+
+    /// The sequences of the packets received on the given channel
+    /// whose acknowledgement has been written.
+    pub fn packet_acknowledgement_sequences(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Vec<Sequence> {
+        self.packet_acknowledgements
+            .keys()
+            .filter(|(port, channel, _)| port == port_id && channel == channel_id)
+            .map(|(_, _, sequence)| *sequence)
+            .collect()
+    }
+
+    /// Whether the packet with the given sequence has already been received on the channel.
+    pub fn has_received(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        sequence: Sequence,
+    ) -> bool {
+        let key = (port_id.clone(), channel_id.clone());
+
+        match self.channels.get(&key).map(|channel| channel.ordering) {
+            Some(ordering) if ordering.is_ordered() => self
+                .next_sequence_recv
+                .get(&key)
+                .map_or(false, |next| sequence < *next),
+            _ => self.packet_receipts.contains(&(key.0, key.1, sequence)),
+        }
+    }
+
+    fn deliver(&mut self, host: &MockHeader, msg: Any) -> Result<Vec<IbcEvent>, Error> {
+        if msg.type_url == transfer::TYPE_URL {
+            let msg = MsgTransfer::try_from(msg)
+                .map_err(|e| Error::mock_chain(format!("invalid transfer message: {e}")))?;
+
+            return self.send_transfer(msg);
+        }
+
+        let envelope = Ics26Envelope::try_from(msg)
+            .map_err(|e| Error::mock_chain(format!("unsupported message: {e}")))?;
+
+        match envelope {
+            Ics26Envelope::Ics2Msg(msg) => self.deliver_client_msg(msg),
+            Ics26Envelope::Ics3Msg(msg) => self.deliver_connection_msg(msg),
+            Ics26Envelope::Ics4ChannelMsg(msg) => self.deliver_channel_msg(msg),
+            Ics26Envelope::Ics4PacketMsg(msg) => self.deliver_packet_msg(host, msg),
+        }
+    }
+
+    fn deliver_client_msg(&mut self, msg: ClientMsg) -> Result<Vec<IbcEvent>, Error> {
+        match msg {
+            ClientMsg::CreateClient(msg) => self.create_client(msg),
+            ClientMsg::UpdateClient(msg) => self.update_client(msg),
+            ClientMsg::Misbehaviour(_) => Err(Error::mock_chain(
+                "misbehaviour is not supported by the mock chain".to_string(),
+            )),
+            ClientMsg::UpgradeClient(_) => Err(Error::mock_chain(
+                "client upgrades are not supported by the mock chain".to_string(),
+            )),
+        }
+    }
+
+    fn create_client(&mut self, msg: MsgCreateClient) -> Result<Vec<IbcEvent>, Error> {
+        let client_state = AnyClientState::try_from(msg.client_state).map_err(Error::ics02)?;
+        let consensus_state =
+            AnyConsensusState::try_from(msg.consensus_state).map_err(Error::ics02)?;
+
+        let client_type = client_state.client_type();
+        let consensus_height = client_state.latest_height();
+
+        let client_id = ClientId::new(client_type, self.client_counter)
+            .map_err(|e| Error::mock_chain(e.to_string()))?;
+        self.client_counter += 1;
+
+        self.clients.insert(
+            client_id.clone(),
+            ClientRecord {
+                client_state,
+                consensus_states: BTreeMap::from([(consensus_height, consensus_state)]),
+            },
+        );
+
+        let event = CreateClient(ClientAttributes {
+            client_id,
+            client_type,
+            consensus_height,
+        });
+
+        Ok(vec![event.into()])
+    }
+
+    fn update_client(&mut self, msg: MsgUpdateClient) -> Result<Vec<IbcEvent>, Error> {
+        let mut header = AnyHeader::try_from(msg.header).map_err(Error::ics02)?;
+
+        let record = self
+            .clients
+            .get_mut(&msg.client_id)
+            .ok_or_else(|| Error::mock_chain(format!("client '{}' not found", msg.client_id)))?;
+
+        let client_type = record.client_state.client_type();
+        let header_type = header.client_type();
+
+        let (client_state, consensus_state) = match (&record.client_state, &mut header) {
+            #[cfg(any(test, feature = "mock"))]
+            (AnyClientState::Mock(client_state), AnyHeader::Mock(header)) => (
+                AnyClientState::Mock(MockClientState {
+                    header: *header,
+                    ..client_state.clone()
+                }),
+                AnyConsensusState::Mock(MockConsensusState::new(*header)),
+            ),
+            (AnyClientState::Tendermint(client_state), AnyHeader::Tendermint(header)) => (
+                AnyClientState::Tendermint(
+                    client_state
+                        .clone()
+                        .with_header(header.clone())
+                        .map_err(Error::ics07)?,
+                ),
+                AnyConsensusState::Tendermint(TmConsensusState::from(header.clone())),
+            ),
+            (AnyClientState::Solomachine(client_state), AnyHeader::Solomachine(header)) => {
+                // The sequence a header is signed for is not part of its encoding
+                header.sequence = client_state.sequence;
+                verify_solomachine_header(client_state, header)?;
+
+                let consensus_state = SmConsensusState::new(
+                    header.new_public_key.clone(),
+                    header.new_diversifier.clone(),
+                    header.timestamp,
+                );
+
+                (
+                    AnyClientState::Solomachine(SmClientState {
+                        sequence: client_state.sequence + 1,
+                        consensus_state: consensus_state.clone(),
+                        ..client_state.clone()
+                    }),
+                    AnyConsensusState::Solomachine(consensus_state),
+                )
+            }
+            _ => return Err(Error::client_type_mismatch(client_type, header_type)),
+        };
+
+        let header_height = header.height();
+        let is_newer = header_height > record.client_state.latest_height();
+
+        if is_newer {
+            record.client_state = client_state;
+        }
+
+        record
+            .consensus_states
+            .insert(header_height, consensus_state);
+
+        let event = UpdateClient {
+            common: ClientAttributes {
+                client_id: msg.client_id,
+                client_type,
+                consensus_height: header_height,
+            },
+            header: Some(header),
+        };
+
+        Ok(vec![event.into()])
+    }
+
+    /// Checks that the given client can verify the proofs of a counterparty message.
+    ///
+    /// The proofs themselves are not verified. Instead, the client must have a
+    /// consensus state at the proof height, except for solo machine clients:
+    /// those must be at the sequence of the first signature, and advance their
+    /// sequence by one for every signature they verify, as in ibc-go.
+    fn verify_proofs(&mut self, client_id: &ClientId, proofs: &Proofs) -> Result<(), Error> {
+        let height = proofs.height();
+
+        let record = self
+            .clients
+            .get_mut(client_id)
+            .ok_or_else(|| Error::mock_chain(format!("client '{client_id}' not found")))?;
+
+        if let AnyClientState::Solomachine(client_state) = &mut record.client_state {
+            if height.revision_height() != client_state.sequence {
+                return Err(Error::mock_chain(format!(
+                    "solo machine client '{client_id}' is at sequence {} but the proofs are signed at sequence {}",
+                    client_state.sequence,
+                    height.revision_height()
+                )));
+            }
+
+            let signatures = 1
+                + u64::from(proofs.client_proof().is_some())
+                + u64::from(proofs.consensus_proof().is_some())
+                + u64::from(proofs.other_proof().is_some());
+
+            client_state.sequence += signatures;
+
+            let consensus_state =
+                AnyConsensusState::Solomachine(client_state.consensus_state.clone());
+            let latest_height = Height::new(0, client_state.sequence).map_err(Error::ics02)?;
+
+            record
+                .consensus_states
+                .insert(height, consensus_state.clone());
+            record
+                .consensus_states
+                .insert(latest_height, consensus_state);
+
+            return Ok(());
+        }
+
+        if record.consensus_states.contains_key(&height) {
+            Ok(())
+        } else {
+            Err(Error::mock_chain(format!(
+                "client '{client_id}' has no consensus state at proof height {height}"
+            )))
+        }
+    }
+
+    /// The client underlying the single connection hop of the given channel.
+    fn channel_client_id(&self, channel: &ChannelEnd) -> Result<ClientId, Error> {
+        let connection_id = channel
+            .connection_hops
+            .first()
+            .ok_or_else(|| Error::mock_chain("channel has no connection hop".to_string()))?;
+
+        Ok(self.connection(connection_id)?.client_id().clone())
+    }
+
+    fn deliver_connection_msg(&mut self, msg: ConnectionMsg) -> Result<Vec<IbcEvent>, Error> {
+        match msg {
+            ConnectionMsg::ConnectionOpenInit(msg) => {
+                self.client(&msg.client_id)?;
+
+                let connection_id = ConnectionId::new(self.connection_counter);
+                self.connection_counter += 1;
+
+                let versions = msg
+                    .version
+                    .map(|version| vec![version])
+                    .unwrap_or_else(get_compatible_versions);
+
+                let attributes = connection_events::Attributes {
+                    connection_id: Some(connection_id.clone()),
+                    client_id: msg.client_id.clone(),
+                    counterparty_connection_id: msg.counterparty.connection_id().cloned(),
+                    counterparty_client_id: msg.counterparty.client_id().clone(),
+                };
+
+                self.connections.insert(
+                    connection_id,
+                    ConnectionEnd::new(
+                        ConnectionState::Init,
+                        msg.client_id,
+                        msg.counterparty,
+                        versions,
+                        msg.delay_period,
+                    ),
+                );
+
+                Ok(vec![connection_events::OpenInit::from(attributes).into()])
+            }
+            ConnectionMsg::ConnectionOpenTry(msg) => {
+                let msg = *msg;
+                self.verify_proofs(&msg.client_id, &msg.proofs)?;
+
+                let connection_id = ConnectionId::new(self.connection_counter);
+                self.connection_counter += 1;
+
+                let version = msg
+                    .counterparty_versions
+                    .into_iter()
+                    .find(|version| get_compatible_versions().contains(version))
+                    .ok_or_else(|| {
+                        Error::mock_chain("no compatible connection version".to_string())
+                    })?;
+
+                let attributes = connection_events::Attributes {
+                    connection_id: Some(connection_id.clone()),
+                    client_id: msg.client_id.clone(),
+                    counterparty_connection_id: msg.counterparty.connection_id().cloned(),
+                    counterparty_client_id: msg.counterparty.client_id().clone(),
+                };
+
+                self.connections.insert(
+                    connection_id,
+                    ConnectionEnd::new(
+                        ConnectionState::TryOpen,
+                        msg.client_id,
+                        msg.counterparty,
+                        vec![version],
+                        msg.delay_period,
+                    ),
+                );
+
+                Ok(vec![connection_events::OpenTry::from(attributes).into()])
+            }
+            ConnectionMsg::ConnectionOpenAck(msg) => {
+                let msg = *msg;
+                let mut connection = self.connection(&msg.connection_id)?.clone();
+
+                if !connection.state_matches(&ConnectionState::Init) {
+                    return Err(Error::mock_chain(format!(
+                        "connection '{}' is not in INIT state",
+                        msg.connection_id
+                    )));
+                }
+
+                self.verify_proofs(connection.client_id(), &msg.proofs)?;
+
+                let counterparty = connection.counterparty().clone();
+                connection.set_state(ConnectionState::Open);
+                connection.set_version(msg.version);
+                connection.set_counterparty(ConnectionCounterparty::new(
+                    counterparty.client_id().clone(),
+                    Some(msg.counterparty_connection_id.clone()),
+                    counterparty.prefix().clone(),
+                ));
+
+                let attributes = connection_events::Attributes {
+                    connection_id: Some(msg.connection_id.clone()),
+                    client_id: connection.client_id().clone(),
+                    counterparty_connection_id: Some(msg.counterparty_connection_id),
+                    counterparty_client_id: counterparty.client_id().clone(),
+                };
+
+                self.connections.insert(msg.connection_id, connection);
+
+                Ok(vec![connection_events::OpenAck::from(attributes).into()])
+            }
+            ConnectionMsg::ConnectionOpenConfirm(msg) => {
+                let mut connection = self.connection(&msg.connection_id)?.clone();
+
+                if !connection.state_matches(&ConnectionState::TryOpen) {
+                    return Err(Error::mock_chain(format!(
+                        "connection '{}' is not in TRYOPEN state",
+                        msg.connection_id
+                    )));
+                }
+
+                self.verify_proofs(connection.client_id(), &msg.proofs)?;
+
+                connection.set_state(ConnectionState::Open);
+
+                let attributes = connection_events::Attributes {
+                    connection_id: Some(msg.connection_id.clone()),
+                    client_id: connection.client_id().clone(),
+                    counterparty_connection_id: connection.counterparty().connection_id().cloned(),
+                    counterparty_client_id: connection.counterparty().client_id().clone(),
+                };
+
+                self.connections.insert(msg.connection_id, connection);
+
+                Ok(vec![connection_events::OpenConfirm::from(attributes).into()])
+            }
+        }
+    }
+
+    fn deliver_channel_msg(&mut self, msg: ChannelMsg) -> Result<Vec<IbcEvent>, Error> {
+        match msg {
+            ChannelMsg::ChannelOpenInit(msg) => {
+                let mut channel = msg.channel;
+                let connection_id = self.channel_connection_id(&channel)?;
+
+                let channel_id = ChannelId::new(self.channel_counter);
+                self.channel_counter += 1;
+
+                channel.set_state(ChannelState::Init);
+
+                let event = channel_events::OpenInit {
+                    port_id: msg.port_id.clone(),
+                    channel_id: Some(channel_id.clone()),
+                    connection_id,
+                    counterparty_port_id: channel.remote.port_id.clone(),
+                    counterparty_channel_id: None,
+                };
+
+                self.open_channel(msg.port_id, channel_id, channel);
+
+                Ok(vec![event.into()])
+            }
+            ChannelMsg::ChannelOpenTry(msg) => {
+                let mut channel = msg.channel;
+                let connection_id = self.channel_connection_id(&channel)?;
+                let client_id = self.channel_client_id(&channel)?;
+                self.verify_proofs(&client_id, &msg.proofs)?;
+
+                let channel_id = ChannelId::new(self.channel_counter);
+                self.channel_counter += 1;
+
+                channel.set_state(ChannelState::TryOpen);
+                channel.set_version(msg.counterparty_version);
+
+                let event = channel_events::OpenTry {
+                    port_id: msg.port_id.clone(),
+                    channel_id: Some(channel_id.clone()),
+                    connection_id,
+                    counterparty_port_id: channel.remote.port_id.clone(),
+                    counterparty_channel_id: channel.remote.channel_id.clone(),
+                };
+
+                self.open_channel(msg.port_id, channel_id, channel);
+
+                Ok(vec![event.into()])
+            }
+            ChannelMsg::ChannelOpenAck(msg) => {
+                let mut channel = self.channel(&msg.port_id, &msg.channel_id)?.clone();
+
+                if !channel.state_matches(&ChannelState::Init) {
+                    return Err(Error::mock_chain(format!(
+                        "channel '{}/{}' is not in INIT state",
+                        msg.channel_id, msg.port_id
+                    )));
+                }
+
+                let client_id = self.channel_client_id(&channel)?;
+                self.verify_proofs(&client_id, &msg.proofs)?;
+
+                channel.set_state(ChannelState::Open);
+                channel.set_version(msg.counterparty_version);
+                channel.set_counterparty_channel_id(msg.counterparty_channel_id.clone());
+
+                let event = channel_events::OpenAck {
+                    port_id: msg.port_id.clone(),
+                    channel_id: Some(msg.channel_id.clone()),
+                    connection_id: self.channel_connection_id(&channel)?,
+                    counterparty_port_id: channel.remote.port_id.clone(),
+                    counterparty_channel_id: Some(msg.counterparty_channel_id),
+                };
+
+                self.channels.insert((msg.port_id, msg.channel_id), channel);
+
+                Ok(vec![event.into()])
+            }
+            ChannelMsg::ChannelOpenConfirm(msg) => {
+                let mut channel = self.channel(&msg.port_id, &msg.channel_id)?.clone();
+
+                if !channel.state_matches(&ChannelState::TryOpen) {
+                    return Err(Error::mock_chain(format!(
+                        "channel '{}/{}' is not in TRYOPEN state",
+                        msg.channel_id, msg.port_id
+                    )));
+                }
+
+                let client_id = self.channel_client_id(&channel)?;
+                self.verify_proofs(&client_id, &msg.proofs)?;
+
+                channel.set_state(ChannelState::Open);
+
+                let event = channel_events::OpenConfirm {
+                    port_id: msg.port_id.clone(),
+                    channel_id: Some(msg.channel_id.clone()),
+                    connection_id: self.channel_connection_id(&channel)?,
+                    counterparty_port_id: channel.remote.port_id.clone(),
+                    counterparty_channel_id: channel.remote.channel_id.clone(),
+                };
+
+                self.channels.insert((msg.port_id, msg.channel_id), channel);
+
+                Ok(vec![event.into()])
+            }
+            ChannelMsg::ChannelCloseInit(msg) => {
+                let mut channel = self.channel(&msg.port_id, &msg.channel_id)?.clone();
+
+                if channel.state_matches(&ChannelState::Closed) {
+                    return Err(Error::mock_chain(format!(
+                        "channel '{}/{}' is already closed",
+                        msg.channel_id, msg.port_id
+                    )));
+                }
+
+                channel.set_state(ChannelState::Closed);
+
+                let event = channel_events::CloseInit {
+                    port_id: msg.port_id.clone(),
+                    channel_id: msg.channel_id.clone(),
+                    connection_id: self.channel_connection_id(&channel)?,
+                    counterparty_port_id: channel.remote.port_id.clone(),
+                    counterparty_channel_id: channel.remote.channel_id.clone(),
+                };
+
+                self.channels.insert((msg.port_id, msg.channel_id), channel);
+
+                Ok(vec![event.into()])
+            }
+            ChannelMsg::ChannelCloseConfirm(msg) => {
+                let mut channel = self.channel(&msg.port_id, &msg.channel_id)?.clone();
+
+                if channel.state_matches(&ChannelState::Closed) {
+                    return Err(Error::mock_chain(format!(
+                        "channel '{}/{}' is already closed",
+                        msg.channel_id, msg.port_id
+                    )));
+                }
+
+                let client_id = self.channel_client_id(&channel)?;
+                self.verify_proofs(&client_id, &msg.proofs)?;
+
+                channel.set_state(ChannelState::Closed);
+
+                let event = channel_events::CloseConfirm {
+                    channel_id: Some(msg.channel_id.clone()),
+                    port_id: msg.port_id.clone(),
+                    connection_id: self.channel_connection_id(&channel)?,
+                    counterparty_port_id: channel.remote.port_id.clone(),
+                    counterparty_channel_id: channel.remote.channel_id.clone(),
+                };
+
+                self.channels.insert((msg.port_id, msg.channel_id), channel);
+
+                Ok(vec![event.into()])
+            }
+            _ => Err(Error::mock_chain(
+                "channel upgrades are not supported by the mock chain".to_string(),
+            )),
+        }
+    }
+
+    /// The single connection hop of the given channel, which must exist on this chain.
+    fn channel_connection_id(&self, channel: &ChannelEnd) -> Result<ConnectionId, Error> {
+        let connection_id = channel
+            .connection_hops
+            .first()
+            .ok_or_else(|| Error::mock_chain("channel has no connection hop".to_string()))?;
+
+        self.connection(connection_id)?;
+
+        Ok(connection_id.clone())
+    }
+
+    fn open_channel(&mut self, port_id: PortId, channel_id: ChannelId, channel: ChannelEnd) {
+        let key = (port_id, channel_id);
+
+        self.next_sequence_send
+            .insert(key.clone(), Sequence::from(1));
+        self.next_sequence_recv
+            .insert(key.clone(), Sequence::from(1));
+        self.next_sequence_ack
+            .insert(key.clone(), Sequence::from(1));
+        self.channels.insert(key, channel);
+    }
+
+    fn send_transfer(&mut self, msg: MsgTransfer) -> Result<Vec<IbcEvent>, Error> {
+        let channel = self.channel(&msg.source_port, &msg.source_channel)?;
+
+        if !channel.is_open() {
+            return Err(Error::mock_chain(format!(
+                "channel '{}/{}' is not open",
+                msg.source_channel, msg.source_port
+            )));
+        }
+
+        let destination_port = channel.remote.port_id.clone();
+        let destination_channel = channel.remote.channel_id.clone().ok_or_else(|| {
+            Error::mock_chain("channel has no counterparty channel identifier".to_string())
+        })?;
+
+        let data = RawPacketData {
+            denom: msg.token.denom,
+            amount: msg.token.amount,
+            sender: msg.sender.to_string(),
+            receiver: msg.receiver.to_string(),
+            memo: msg.memo.unwrap_or_default(),
+        };
+
+        let key = (msg.source_port.clone(), msg.source_channel.clone());
+        let sequence = self
+            .next_sequence_send
+            .get(&key)
+            .copied()
+            .unwrap_or_else(|| Sequence::from(1));
+
+        let packet = Packet {
+            sequence,
+            source_port: msg.source_port,
+            source_channel: msg.source_channel,
+            destination_port,
+            destination_channel,
+            data: serde_json::to_vec(&data).map_err(|e| Error::mock_chain(e.to_string()))?,
+            timeout_height: msg.timeout_height,
+            timeout_timestamp: msg.timeout_timestamp,
+        };
+
+        self.next_sequence_send.insert(key, sequence.increment());
+        self.packet_commitments.insert(
+            packet_key(&packet, Side::Source),
+            packet_commitment(&packet),
+        );
+
+        Ok(vec![channel_events::SendPacket { packet }.into()])
+    }
+
+    fn deliver_packet_msg(
+        &mut self,
+        host: &MockHeader,
+        msg: PacketMsg,
+    ) -> Result<Vec<IbcEvent>, Error> {
+        match msg {
+            PacketMsg::RecvPacket(msg) => {
+                let packet = msg.packet;
+                let channel =
+                    self.channel(&packet.destination_port, &packet.destination_channel)?;
+
+                if !channel.is_open() {
+                    return Err(Error::mock_chain(format!(
+                        "channel '{}/{}' is not open",
+                        packet.destination_channel, packet.destination_port
+                    )));
+                }
+
+                let ordering = channel.ordering;
+                let client_id = self.channel_client_id(channel)?;
+                self.verify_proofs(&client_id, &msg.proofs)?;
+
+                if packet.timed_out(&host.timestamp, host.height) {
+                    return Err(Error::mock_chain(format!("packet {packet} has timed out")));
+                }
+
+                // Relaying a packet twice is a no-op, as on a real chain
+                if self.has_received(
+                    &packet.destination_port,
+                    &packet.destination_channel,
+                    packet.sequence,
+                ) {
+                    return Ok(vec![]);
+                }
+
+                let key = packet_key(&packet, Side::Destination);
+
+                if ordering.is_ordered() {
+                    let channel_key = (key.0.clone(), key.1.clone());
+                    let next_recv = self
+                        .next_sequence_recv
+                        .get(&channel_key)
+                        .copied()
+                        .unwrap_or_else(|| Sequence::from(1));
+
+                    if packet.sequence != next_recv {
+                        return Err(Error::mock_chain(format!(
+                            "packet sequence {} does not match the next sequence to receive {}",
+                            packet.sequence, next_recv
+                        )));
+                    }
+
+                    self.next_sequence_recv
+                        .insert(channel_key, next_recv.increment());
+                } else {
+                    self.packet_receipts.insert(key.clone());
+                }
+
+                let ack = serde_json::to_vec(&TransferAck::success())
+                    .map_err(|e| Error::mock_chain(e.to_string()))?;
+
+                self.packet_acknowledgements
+                    .insert(key, Sha256::digest(&ack).to_vec());
+
+                Ok(vec![
+                    channel_events::ReceivePacket {
+                        packet: packet.clone(),
+                    }
+                    .into(),
+                    channel_events::WriteAcknowledgement { packet, ack }.into(),
+                ])
+            }
+            PacketMsg::AckPacket(msg) => {
+                let packet = msg.packet;
+                let channel = self.channel(&packet.source_port, &packet.source_channel)?;
+                let ordering = channel.ordering;
+                let client_id = self.channel_client_id(channel)?;
+                self.verify_proofs(&client_id, &msg.proofs)?;
+
+                let key = packet_key(&packet, Side::Source);
+
+                // The packet was already acknowledged, which is a no-op
+                if self.packet_commitments.remove(&key).is_none() {
+                    return Ok(vec![]);
+                }
+
+                if ordering.is_ordered() {
+                    self.next_sequence_ack
+                        .insert((key.0, key.1), packet.sequence.increment());
+                }
+
+                Ok(vec![channel_events::AcknowledgePacket { packet }.into()])
+            }
+            PacketMsg::ToPacket(msg) => {
+                let packet = msg.packet;
+                let proof_height = msg.proofs.height();
+                let channel = self.channel(&packet.source_port, &packet.source_channel)?;
+                let ordering = channel.ordering;
+                let client_id = self.channel_client_id(channel)?;
+                self.verify_proofs(&client_id, &msg.proofs)?;
+
+                let counterparty_timestamp =
+                    self.client(&client_id)?.consensus_states[&proof_height].timestamp();
+
+                if !packet.timed_out(&counterparty_timestamp, proof_height) {
+                    return Err(Error::mock_chain(format!(
+                        "packet {packet} has not timed out at height {proof_height}"
+                    )));
+                }
+
+                let key = packet_key(&packet, Side::Source);
+
+                if self.packet_commitments.remove(&key).is_none() {
+                    return Ok(vec![]);
+                }
+
+                if ordering.closes_on_timeout() {
+                    self.close_channel(&key.0, &key.1);
+                } else if ordering.is_ordered() {
+                    self.next_sequence_ack
+                        .insert((key.0, key.1), packet.sequence.increment());
+                }
+
+                Ok(vec![channel_events::TimeoutPacket { packet }.into()])
+            }
+            PacketMsg::ToClosePacket(msg) => {
+                let packet = msg.packet;
+                let channel = self.channel(&packet.source_port, &packet.source_channel)?;
+                let ordering = channel.ordering;
+                let client_id = self.channel_client_id(channel)?;
+                self.verify_proofs(&client_id, &msg.proofs)?;
+
+                let key = packet_key(&packet, Side::Source);
+
+                if self.packet_commitments.remove(&key).is_none() {
+                    return Ok(vec![]);
+                }
+
+                if ordering.closes_on_timeout() {
+                    self.close_channel(&key.0, &key.1);
+                }
+
+                Ok(vec![channel_events::TimeoutOnClosePacket { packet }.into()])
+            }
+        }
+    }
+
+    fn close_channel(&mut self, port_id: &PortId, channel_id: &ChannelId) {
+        if let Some(channel) = self
+            .channels
+            .get_mut(&(port_id.clone(), channel_id.clone()))
+        {
+            channel.set_state(ChannelState::Closed);
+        }
+    }
+}
+
+/// Which end of a packet's channel a store key refers to.
+enum Side {
+    Source,
+    Destination,
+}
+
+/// Verifies that a solo machine header is signed by the current key of the
+/// client, for its current sequence.
+fn verify_solomachine_header(client_state: &SmClientState, header: &SmHeader) -> Result<(), Error> {
+    let consensus_state = &client_state.consensus_state;
+
+    if consensus_state.timestamp.after(&header.timestamp) {
+        return Err(Error::mock_chain(format!(
+            "solo machine header timestamp {} is older than the consensus state timestamp {}",
+            header.timestamp, consensus_state.timestamp
+        )));
+    }
+
+    let invalid_signature = || {
+        Error::mock_chain(format!(
+            "invalid solo machine header signature for sequence {}",
+            client_state.sequence
+        ))
+    };
+
+    let bytes = sign_bytes(
+        client_state.sequence,
+        header.timestamp,
+        &consensus_state.diversifier,
+        HEADER_PATH,
+        header_data(&header.new_public_key, &header.new_diversifier),
+    );
+
+    let message =
+        Message::from_digest_slice(&Sha256::digest(bytes)).map_err(|_| invalid_signature())?;
+    let signature = decode_signature(&header.signature)
+        .ok()
+        .and_then(|signature| Signature::from_compact(&signature).ok())
+        .ok_or_else(invalid_signature)?;
+    let public_key = PublicKey::from_slice(consensus_state.public_key.as_bytes())
+        .map_err(|_| invalid_signature())?;
+
+    Secp256k1::verification_only()
+        .verify_ecdsa(&message, &signature, &public_key)
+        .map_err(|_| invalid_signature())
+}
+
+fn packet_key(packet: &Packet, side: Side) -> PacketKey {
+    match side {
+        Side::Source => (
+            packet.source_port.clone(),
+            packet.source_channel.clone(),
+            packet.sequence,
+        ),
+        Side::Destination => (
+            packet.destination_port.clone(),
+            packet.destination_channel.clone(),
+            packet.sequence,
+        ),
+    }
+}
+
+/// Computes the packet commitment the same way as ibc-go does.
+fn packet_commitment(packet: &Packet) -> Vec<u8> {
+    let mut hash_input = packet
+        .timeout_timestamp
+        .nanoseconds()
+        .to_be_bytes()
+        .to_vec();
+
+    hash_input.extend(
+        packet
+            .timeout_height
+            .commitment_revision_number()
+            .to_be_bytes(),
+    );
+    hash_input.extend(
+        packet
+            .timeout_height
+            .commitment_revision_height()
+            .to_be_bytes(),
+    );
+    hash_input.extend(Sha256::digest(&packet.data));
+
+    Sha256::digest(hash_input).to_vec()
+}
+
+/// The blocks of a mock chain, together with the IBC state at each height
+/// and the events emitted so far.
+pub struct MockStore {
+    chain_id: ChainId,
+    headers: BTreeMap<Height, MockHeader>,
+    /// The IBC state is only recorded at the heights where it changed
+    states: BTreeMap<Height, IbcState>,
+    events: Vec<IbcEventWithHeight>,
+    txs: Vec<(TxHash, Vec<IbcEventWithHeight>)>,
+    event_bus: EventBus<Arc<EventResult<EventBatch>>>,
+}
+
+impl MockStore {
+    /// Creates the store with a genesis block at height 1 and an empty IBC state.
+    pub fn new(chain_id: ChainId) -> Result<Self, Error> {
+        let genesis_height = Height::new(chain_id.version(), 1).map_err(Error::ics02)?;
+        let genesis = MockHeader::new(genesis_height).with_timestamp(Timestamp::now());
+
+        Ok(Self {
+            chain_id,
+            headers: BTreeMap::from([(genesis_height, genesis)]),
+            states: BTreeMap::from([(genesis_height, IbcState::default())]),
+            events: Vec::new(),
+            txs: Vec::new(),
+            event_bus: EventBus::new(),
+        })
+    }
+
+    pub fn latest_header(&self) -> MockHeader {
+        self.headers
+            .values()
+            .next_back()
+            .copied()
+            .expect("the store always contains the genesis block")
+    }
+
+    pub fn latest_height(&self) -> Height {
+        self.latest_header().height
+    }
+
+    pub fn header(&self, height: Height) -> Result<MockHeader, Error> {
+        self.headers.get(&height).copied().ok_or_else(|| {
+            Error::mock_chain(format!(
+                "no block at height {height} on chain '{}'",
+                self.chain_id
+            ))
+        })
+    }
+
+    /// Resolves the given query height to the height of an existing block.
+    pub fn query_height(&self, height: QueryHeight) -> Result<Height, Error> {
+        match height {
+            QueryHeight::Latest => Ok(self.latest_height()),
+            QueryHeight::Specific(height) => self.header(height).map(|header| header.height),
+        }
+    }
+
+    /// The IBC state at the given height.
+    pub fn state(&self, height: QueryHeight) -> Result<&IbcState, Error> {
+        let height = self.query_height(height)?;
+
+        let (_, state) = self
+            .states
+            .range(..=height)
+            .next_back()
+            .expect("the store always contains the genesis state");
+
+        Ok(state)
+    }
+
+    /// The events emitted since genesis, in the order they were emitted.
+    pub fn events(&self) -> &[IbcEventWithHeight] {
+        &self.events
+    }
+
+    /// The events emitted by the transaction with the given hash.
+    pub fn tx_events(&self, hash: &TxHash) -> Vec<IbcEventWithHeight> {
+        self.txs
+            .iter()
+            .find(|(tx_hash, _)| tx_hash == hash)
+            .map(|(_, events)| events.clone())
+            .unwrap_or_default()
+    }
+
+    pub fn subscribe(&mut self) -> crossbeam_channel::Receiver<Arc<EventResult<EventBatch>>> {
+        self.event_bus.subscribe()
+    }
+
+    /// Commits a block without any message.
+    pub fn produce_block(&mut self) {
+        let header = self.next_header();
+        self.commit_block(header, None, Vec::new(), TrackingId::new_uuid());
+    }
+
+    /// Commits a block with a single transaction made of the given messages,
+    /// which are applied atomically: if any of them fails, the block is not
+    /// committed and the error is returned.
+    pub fn submit(
+        &mut self,
+        msgs: Vec<Any>,
+        tracking_id: TrackingId,
+    ) -> Result<(TxHash, Vec<IbcEventWithHeight>), Error> {
+        let header = self.next_header();
+        let mut state = self.state(QueryHeight::Latest)?.clone();
+        let mut events = Vec::new();
+        let mut hasher = Sha256::new();
+
+        hasher.update(header.height.to_string());
+
+        for msg in msgs {
+            hasher.update(&msg.value);
+            events.extend(state.deliver(&header, msg)?);
+        }
+
+        let hash = TxHash::Sha256(hasher.finalize().into());
+        let events = self.commit_block(header, Some(state), events, tracking_id);
+
+        self.txs.push((hash, events.clone()));
+
+        Ok((hash, events))
+    }
+
+    fn next_header(&self) -> MockHeader {
+        let latest = self.latest_header();
+
+        // Ensure that block timestamps are strictly increasing
+        let now = Timestamp::now();
+        let timestamp = if now.after(&latest.timestamp) {
+            now
+        } else {
+            (latest.timestamp + core::time::Duration::from_nanos(1)).unwrap_or(now)
+        };
+
+        MockHeader::new(latest.height.increment()).with_timestamp(timestamp)
+    }
+
+    fn commit_block(
+        &mut self,
+        header: MockHeader,
+        state: Option<IbcState>,
+        events: Vec<IbcEvent>,
+        tracking_id: TrackingId,
+    ) -> Vec<IbcEventWithHeight> {
+        let height = header.height;
+
+        self.headers.insert(height, header);
+
+        if let Some(state) = state {
+            self.states.insert(height, state);
+        }
+
+        let events: Vec<_> = events
+            .into_iter()
+            .map(|event| IbcEventWithHeight::new(event, height))
+            .collect();
+
+        self.events.extend(events.iter().cloned());
+
+        let mut batch_events = vec![IbcEventWithHeight::new(
+            NewBlock::new(height).into(),
+            height,
+        )];
+        batch_events.extend(events.iter().cloned());
+
+        self.event_bus.broadcast(Arc::new(Ok(EventBatch {
+            chain_id: self.chain_id.clone(),
+            tracking_id,
+            height,
+            events: batch_events,
+        })));
+
+        events
+    }
+}
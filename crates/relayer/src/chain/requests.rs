@@ -274,6 +274,14 @@ pub struct QueryChannelRequest {
     pub height: QueryHeight,
 }
 
+/// Request to fetch the upgrade proposed for a specified channel, if any.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct QueryUpgradeRequest {
+    pub port_id: PortId,
+    pub channel_id: ChannelId,
+    pub height: QueryHeight,
+}
+
 /// gRPC request to fetch the client state associated with a specified channel.
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct QueryChannelClientStateRequest {
@@ -511,3 +519,10 @@ pub struct CrossChainQueryRequest {
     pub request: String,
     pub height: TMBlockHeight,
 }
+
+/// Query request for the fee allowance and authorization given by `granter` to `grantee`.
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct QueryGrantsRequest {
+    pub granter: String,
+    pub grantee: String,
+}
@@ -1,15 +1,16 @@
 use alloc::sync::Arc;
+use core::time::Duration;
 use std::thread;
 
 use crossbeam_channel as channel;
 use tokio::runtime::Runtime as TokioRuntime;
-use tracing::{error, Span};
+use tracing::{error, error_span, Span};
 
 use ibc_proto::ibc::apps::fee::v1::{
     QueryIncentivizedPacketRequest, QueryIncentivizedPacketResponse,
 };
 use ibc_relayer_types::{
-    applications::ics31_icq::response::CrossChainQueryResponse,
+    applications::{ics31_icq::response::CrossChainQueryResponse, transfer::RawCoin},
     core::{
         ics02_client::events::UpdateClient,
         ics02_client::header::AnyHeader,
@@ -18,8 +19,9 @@ use ibc_relayer_types::{
             version::Version,
         },
         ics04_channel::{
-            channel::{ChannelEnd, IdentifiedChannelEnd},
+            channel::{ChannelEnd, IdentifiedChannelEnd, Ordering},
             packet::{PacketMsgType, Sequence},
+            upgrade::Upgrade,
         },
         ics23_commitment::{commitment::CommitmentPrefix, merkle::MerkleProof},
         ics24_host::identifier::{ChainId, ChannelId, ClientId, ConnectionId, PortId},
@@ -30,7 +32,7 @@ use ibc_relayer_types::{
 };
 
 use crate::{
-    account::Balance,
+    account::{Balance, Grants},
     chain::requests::QueryPacketEventDataRequest,
     client_state::{AnyClientState, IdentifiedAnyClientState},
     config::ChainConfig,
@@ -52,6 +54,10 @@ use super::{
     tracking::TrackedMsgs,
 };
 
+/// How often the runtime probes the full nodes serving the chain,
+/// to fail over to another one if the node in use is unhealthy.
+const CHECK_ENDPOINTS_INTERVAL: Duration = Duration::from_secs(30);
+
 pub struct Threads {
     pub chain_runtime: thread::JoinHandle<()>,
     pub event_source: Option<thread::JoinHandle<()>>,
@@ -132,8 +138,16 @@ where
     }
 
     fn run(mut self) -> Result<(), Error> {
+        let check_endpoints = channel::tick(CHECK_ENDPOINTS_INTERVAL);
+
         loop {
             channel::select! {
+                recv(check_endpoints) -> _ => {
+                    if let Err(e) = self.chain.check_endpoints() {
+                        error!("failed to check the endpoints of the chain: {}", e);
+                    }
+                },
+
                 recv(self.request_receiver) -> event => {
                     let (span, event) = match event {
                         Ok((span, event)) => (span, event),
@@ -145,6 +159,13 @@ where
 
                     let _span = span.entered();
 
+                    // Trace the queries, eg. to export their duration over OTLP
+                    let request: &'static str = (&event).into();
+                    let _query_span = request.starts_with("query_").then(|| {
+                        let chain = ChainEndpoint::id(&self.chain);
+                        error_span!("query", %chain, otel.name = request).entered()
+                    });
+
                     match event {
                         ChainRequest::Shutdown { reply_to } => {
                             let res = self.chain.shutdown();
@@ -183,10 +204,26 @@ where
                             self.get_key(reply_to)?
                         },
 
+                        ChainRequest::GetKeys { reply_to } => {
+                            self.get_keys(reply_to)?
+                        },
+
+                        ChainRequest::SelectSigner { channel, ordering, reply_to } => {
+                            self.select_signer(channel, ordering, reply_to)?
+                        },
+
                         ChainRequest::AddKey { key_name, key, reply_to } => {
                             self.add_key(key_name, key, reply_to)?
                         },
 
+                        ChainRequest::UpdateGasSettings { config, reply_to } => {
+                            self.update_gas_settings(config, reply_to)?
+                        },
+
+                        ChainRequest::EstimateTxFee { msg_type, reply_to } => {
+                            self.estimate_tx_fee(msg_type, reply_to)?
+                        },
+
                         ChainRequest::VersionSpecs { reply_to } => {
                             self.version_specs(reply_to)?
                         },
@@ -287,6 +324,10 @@ where
                             self.query_channel(request, include_proof, reply_to)?
                         },
 
+                        ChainRequest::QueryUpgrade { request, include_proof, reply_to } => {
+                            self.query_upgrade(request, include_proof, reply_to)?
+                        },
+
                         ChainRequest::QueryChannelClientState { request, reply_to } => {
                             self.query_channel_client_state(request, reply_to)?
                         },
@@ -354,6 +395,14 @@ where
                         ChainRequest::QueryConsumerChains { reply_to } => {
                             self.query_consumer_chains(reply_to)?
                         },
+
+                        ChainRequest::QueryGrants { request, reply_to } => {
+                            self.query_grants(request, reply_to)?
+                        },
+
+                        ChainRequest::QueryDroppedTxs { tx_hashes, reply_to } => {
+                            self.query_dropped_txs(&tx_hashes, reply_to)?
+                        },
                     }
                 },
             }
@@ -437,6 +486,28 @@ where
         reply_to.send(result).map_err(Error::send)
     }
 
+    fn get_keys(
+        &mut self,
+        reply_to: ReplyTo<Vec<(String, AnySigningKeyPair)>>,
+    ) -> Result<(), Error> {
+        let result = self.chain.get_keys().map(|keys| {
+            keys.into_iter()
+                .map(|(key_name, key)| (key_name, key.into()))
+                .collect()
+        });
+        reply_to.send(result).map_err(Error::send)
+    }
+
+    fn select_signer(
+        &mut self,
+        channel: (PortId, ChannelId),
+        ordering: Ordering,
+        reply_to: ReplyTo<Signer>,
+    ) -> Result<(), Error> {
+        let result = self.chain.select_signer(&channel, ordering);
+        reply_to.send(result).map_err(Error::send)
+    }
+
     fn add_key(
         &mut self,
         key_name: String,
@@ -450,6 +521,24 @@ where
         reply_to.send(result).map_err(Error::send)
     }
 
+    fn update_gas_settings(
+        &mut self,
+        config: ChainConfig,
+        reply_to: ReplyTo<()>,
+    ) -> Result<(), Error> {
+        let result = self.chain.update_gas_settings(config);
+        reply_to.send(result).map_err(Error::send)
+    }
+
+    fn estimate_tx_fee(
+        &mut self,
+        msg_type: String,
+        reply_to: ReplyTo<Option<RawCoin>>,
+    ) -> Result<(), Error> {
+        let result = self.chain.estimate_tx_fee(&msg_type);
+        reply_to.send(result).map_err(Error::send)
+    }
+
     fn version_specs(&mut self, reply_to: ReplyTo<Specs>) -> Result<(), Error> {
         let result = self.chain.version_specs();
         reply_to.send(result).map_err(Error::send)
@@ -663,6 +752,16 @@ where
         reply_to.send(result).map_err(Error::send)
     }
 
+    fn query_upgrade(
+        &self,
+        request: QueryUpgradeRequest,
+        include_proof: IncludeProof,
+        reply_to: ReplyTo<(Upgrade, Option<MerkleProof>)>,
+    ) -> Result<(), Error> {
+        let result = self.chain.query_upgrade(request, include_proof);
+        reply_to.send(result).map_err(Error::send)
+    }
+
     fn query_channel_client_state(
         &self,
         request: QueryChannelClientStateRequest,
@@ -865,4 +964,26 @@ where
 
         Ok(())
     }
+
+    fn query_grants(
+        &self,
+        request: QueryGrantsRequest,
+        reply_to: ReplyTo<Grants>,
+    ) -> Result<(), Error> {
+        let result = self.chain.query_grants(request);
+        reply_to.send(result).map_err(Error::send)?;
+
+        Ok(())
+    }
+
+    fn query_dropped_txs(
+        &self,
+        tx_hashes: &[tendermint::Hash],
+        reply_to: ReplyTo<Vec<tendermint::Hash>>,
+    ) -> Result<(), Error> {
+        let result = self.chain.query_dropped_txs(tx_hashes);
+        reply_to.send(result).map_err(Error::send)?;
+
+        Ok(())
+    }
 }
@@ -0,0 +1,1055 @@
+//! A solo machine, ie. a standalone process whose state is attested by the
+//! signatures of a single key rather than by a consensus.
+//!
+//! A [`SoloMachine`] hosts the clients, connections and channels it opens
+//! with its counterparties in an in-memory store, the same as the one of a
+//! [`MockChain`](super::mock::MockChain). Its counterparties track it with
+//! an ICS 06 solo machine client, which verifies the signatures the solo
+//! machine produces over its state in place of membership proofs.
+//!
+//! The height of a solo machine is `0-{sequence}`, where the sequence is
+//! incremented with every signature. Since the counterparty clients do the
+//! same with every signature they verify, the sequence of a solo machine
+//! only remains in sync with a single counterparty client.
+
+pub mod config;
+
+use alloc::sync::Arc;
+use core::fmt::Display;
+use core::sync::atomic::{AtomicU64, Ordering as AtomicOrdering};
+
+use tendermint_rpc::endpoint::broadcast::tx_sync::Response as TxResponse;
+use tokio::runtime::Runtime as TokioRuntime;
+
+use ibc_proto::google::protobuf::Any;
+use ibc_proto::ibc::apps::fee::v1::{
+    QueryIncentivizedPacketRequest, QueryIncentivizedPacketResponse,
+};
+use ibc_proto::Protobuf;
+use ibc_relayer_types::applications::ics31_icq::response::CrossChainQueryResponse;
+use ibc_relayer_types::clients::ics06_solomachine::client_state::ClientState as SmClientState;
+use ibc_relayer_types::clients::ics06_solomachine::consensus_state::{
+    ConsensusState as SmConsensusState, PublicKey,
+};
+use ibc_relayer_types::clients::ics06_solomachine::header::Header as SmHeader;
+use ibc_relayer_types::clients::ics06_solomachine::signing::{
+    encode_signature, header_data, merkle_path, sign_bytes, signature_proof, HEADER_PATH,
+};
+use ibc_relayer_types::core::ics02_client::client_type::ClientType;
+use ibc_relayer_types::core::ics02_client::error::Error as ClientError;
+use ibc_relayer_types::core::ics02_client::events::UpdateClient;
+use ibc_relayer_types::core::ics03_connection::connection::{
+    ConnectionEnd, IdentifiedConnectionEnd, State as ConnectionState,
+};
+use ibc_relayer_types::core::ics04_channel::channel::{ChannelEnd, IdentifiedChannelEnd};
+use ibc_relayer_types::core::ics04_channel::packet::{PacketMsgType, Sequence};
+use ibc_relayer_types::core::ics04_channel::upgrade::Upgrade;
+use ibc_relayer_types::core::ics23_commitment::commitment::{
+    CommitmentPrefix, CommitmentProofBytes,
+};
+use ibc_relayer_types::core::ics23_commitment::merkle::MerkleProof;
+use ibc_relayer_types::core::ics24_host::identifier::{
+    ChainId, ChannelId, ClientId, ConnectionId, PortId,
+};
+use ibc_relayer_types::core::ics24_host::path::{
+    AcksPath, ChannelEndsPath, ClientConsensusStatePath, ClientStatePath, CommitmentsPath,
+    ConnectionsPath, ReceiptsPath, SeqRecvsPath,
+};
+use ibc_relayer_types::proofs::{ConsensusProof, Proofs};
+use ibc_relayer_types::signer::Signer;
+use ibc_relayer_types::timestamp::Timestamp;
+use ibc_relayer_types::Height as ICSHeight;
+
+use crate::account::{Balance, Grants};
+use crate::chain::client::ClientSettings;
+use crate::chain::cosmos::version::Specs;
+use crate::chain::endpoint::{ChainEndpoint, ChainStatus, HealthCheck};
+use crate::chain::handle::Subscription;
+use crate::chain::mock::MockHost;
+use crate::chain::requests::*;
+use crate::chain::tracking::TrackedMsgs;
+use crate::client_state::{AnyClientState, IdentifiedAnyClientState};
+use crate::config::{ChainConfig, Error as ConfigError};
+use crate::connection::ConnectionMsgType;
+use crate::consensus_state::AnyConsensusState;
+use crate::denom::DenomTrace;
+use crate::error::Error;
+use crate::event::IbcEventWithHeight;
+use crate::keyring::{KeyRing, Secp256k1KeyPair, SigningKeyPair};
+use crate::misbehaviour::MisbehaviourEvidence;
+
+use self::config::{SoloMachineConfig, BLOCK_TIME};
+
+pub struct SoloMachine {
+    config: SoloMachineConfig,
+    keybase: KeyRing<Secp256k1KeyPair>,
+    /// The store of the IBC state hosted by the solo machine
+    host: MockHost,
+    /// The sequence of the next signature
+    sequence: AtomicU64,
+}
+
+impl SoloMachine {
+    fn diversifier(&self) -> String {
+        self.config.id.to_string()
+    }
+
+    fn latest_height(&self) -> Result<ICSHeight, Error> {
+        ICSHeight::new(0, self.sequence.load(AtomicOrdering::SeqCst)).map_err(Error::ics02)
+    }
+
+    fn public_key(&self) -> Result<PublicKey, Error> {
+        let key = self.get_key()?;
+
+        PublicKey::from_bytes(&key.public_key.serialize()).map_err(Error::ics06)
+    }
+
+    /// The consensus state attesting the current key and diversifier of the solo machine.
+    fn consensus_state(&self) -> Result<SmConsensusState, Error> {
+        Ok(SmConsensusState::new(
+            self.public_key()?,
+            self.diversifier(),
+            Timestamp::now(),
+        ))
+    }
+
+    fn sign(
+        &self,
+        sequence: u64,
+        timestamp: Timestamp,
+        path: &str,
+        data: Vec<u8>,
+    ) -> Result<Vec<u8>, Error> {
+        let bytes = sign_bytes(sequence, timestamp, &self.diversifier(), path, data);
+
+        self.get_key()?.sign(&bytes).map_err(Error::key_base)
+    }
+
+    /// Signs that the given data is stored under the given path, which
+    /// consumes the next sequence, and returns that sequence along with
+    /// the resulting proof.
+    fn prove(
+        &self,
+        path: impl Display,
+        data: Vec<u8>,
+    ) -> Result<(u64, CommitmentProofBytes), Error> {
+        let prefix = self.query_commitment_prefix()?;
+        let path = merkle_path(&prefix, &path.to_string());
+
+        let sequence = self.sequence.fetch_add(1, AtomicOrdering::SeqCst);
+        let timestamp = Timestamp::now();
+        let signature = self.sign(sequence, timestamp, &path, data)?;
+
+        let proof = CommitmentProofBytes::try_from(signature_proof(signature, timestamp))
+            .map_err(Error::malformed_proof)?;
+
+        Ok((sequence, proof))
+    }
+
+    fn proof_height(sequence: u64) -> Result<ICSHeight, Error> {
+        ICSHeight::new(0, sequence).map_err(Error::ics02)
+    }
+
+    fn prove_channel(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<(u64, CommitmentProofBytes), Error> {
+        let (channel, _) = self.query_channel(
+            QueryChannelRequest {
+                port_id: port_id.clone(),
+                channel_id: channel_id.clone(),
+                height: QueryHeight::Latest,
+            },
+            IncludeProof::No,
+        )?;
+
+        self.prove(
+            ChannelEndsPath(port_id.clone(), channel_id.clone()),
+            channel.encode_vec(),
+        )
+    }
+}
+
+impl ChainEndpoint for SoloMachine {
+    type LightBlock = SmConsensusState;
+    type Header = SmHeader;
+    type ConsensusState = SmConsensusState;
+    type ClientState = SmClientState;
+    type Time = Timestamp;
+    type SigningKeyPair = Secp256k1KeyPair;
+
+    fn id(&self) -> &ChainId {
+        &self.config.id
+    }
+
+    fn config(&self) -> ChainConfig {
+        ChainConfig::SoloMachine(self.config.clone())
+    }
+
+    fn bootstrap(config: ChainConfig, _rt: Arc<TokioRuntime>) -> Result<Self, Error> {
+        let ChainConfig::SoloMachine(config) = config else {
+            return Err(Error::config(ConfigError::wrong_type()));
+        };
+
+        if config.id.version() != 0 {
+            return Err(Error::solo_machine(format!(
+                "the identifier of solo machine '{}' must have revision number 0",
+                config.id
+            )));
+        }
+
+        let keybase = KeyRing::new_secp256k1(
+            config.key_store_type,
+            &config.account_prefix,
+            &config.id,
+            &config.key_store_folder,
+            &config.key_store_passphrase,
+            &config.remote_signer,
+        )
+        .map_err(Error::key_base)?;
+
+        let host = MockHost::new(&config.id, config.store_prefix.clone(), BLOCK_TIME)?;
+
+        let sequence = AtomicU64::new(config.sequence.max(1));
+
+        Ok(Self {
+            config,
+            keybase,
+            host,
+            sequence,
+        })
+    }
+
+    fn shutdown(self) -> Result<(), Error> {
+        self.host.shutdown();
+        Ok(())
+    }
+
+    fn health_check(&mut self) -> Result<HealthCheck, Error> {
+        Ok(HealthCheck::Healthy)
+    }
+
+    fn subscribe(&mut self) -> Result<Subscription, Error> {
+        Ok(self.host.subscribe())
+    }
+
+    fn keybase(&self) -> &KeyRing<Self::SigningKeyPair> {
+        &self.keybase
+    }
+
+    fn keybase_mut(&mut self) -> &mut KeyRing<Self::SigningKeyPair> {
+        &mut self.keybase
+    }
+
+    fn get_signer(&self) -> Result<Signer, Error> {
+        self.get_key()?
+            .account()
+            .parse()
+            .map_err(|e| Error::ics02(ClientError::signer(e)))
+    }
+
+    fn get_key(&self) -> Result<Self::SigningKeyPair, Error> {
+        self.keybase
+            .get_key(&self.config.key_name)
+            .map_err(|e| Error::key_not_found(self.config.key_name.clone(), e))
+    }
+
+    fn version_specs(&self) -> Result<Specs, Error> {
+        Ok(Specs {
+            cosmos_sdk: None,
+            ibc_go: None,
+            tendermint: None,
+            comet: None,
+        })
+    }
+
+    fn send_messages_and_wait_commit(
+        &mut self,
+        tracked_msgs: TrackedMsgs,
+    ) -> Result<Vec<IbcEventWithHeight>, Error> {
+        let (_, events) = self.host.submit(tracked_msgs);
+        Ok(events)
+    }
+
+    fn send_messages_and_wait_check_tx(
+        &mut self,
+        tracked_msgs: TrackedMsgs,
+    ) -> Result<Vec<TxResponse>, Error> {
+        let (response, _) = self.host.submit(tracked_msgs);
+        Ok(vec![response])
+    }
+
+    fn verify_header(
+        &mut self,
+        _trusted: ICSHeight,
+        _target: ICSHeight,
+        _client_state: &AnyClientState,
+    ) -> Result<Self::LightBlock, Error> {
+        self.consensus_state()
+    }
+
+    fn check_misbehaviour(
+        &mut self,
+        _update: &UpdateClient,
+        _client_state: &AnyClientState,
+    ) -> Result<Option<MisbehaviourEvidence>, Error> {
+        // A solo machine never signs twice with the same sequence
+        Ok(None)
+    }
+
+    fn query_balance(
+        &self,
+        _key_name: Option<&str>,
+        _denom: Option<&str>,
+    ) -> Result<Balance, Error> {
+        Err(Error::solo_machine(
+            "a solo machine holds no balance".to_string(),
+        ))
+    }
+
+    fn query_all_balances(&self, _key_name: Option<&str>) -> Result<Vec<Balance>, Error> {
+        Err(Error::solo_machine(
+            "a solo machine holds no balance".to_string(),
+        ))
+    }
+
+    fn query_denom_trace(&self, _hash: String) -> Result<DenomTrace, Error> {
+        Err(Error::solo_machine(
+            "denomination traces are not supported by solo machines".to_string(),
+        ))
+    }
+
+    fn query_commitment_prefix(&self) -> Result<CommitmentPrefix, Error> {
+        self.host.query_commitment_prefix()
+    }
+
+    fn query_application_status(&self) -> Result<ChainStatus, Error> {
+        Ok(ChainStatus {
+            height: self.latest_height()?,
+            timestamp: Timestamp::now(),
+        })
+    }
+
+    // The state of a solo machine is only ever attested at its latest
+    // sequence, so the queries below all target the latest state of the
+    // store and return no proof.
+
+    fn query_clients(
+        &self,
+        request: QueryClientStatesRequest,
+    ) -> Result<Vec<IdentifiedAnyClientState>, Error> {
+        self.host.query_clients(request)
+    }
+
+    fn query_client_state(
+        &self,
+        request: QueryClientStateRequest,
+        _include_proof: IncludeProof,
+    ) -> Result<(AnyClientState, Option<MerkleProof>), Error> {
+        self.host.query_client_state(
+            QueryClientStateRequest {
+                height: QueryHeight::Latest,
+                ..request
+            },
+            IncludeProof::No,
+        )
+    }
+
+    fn query_consensus_state(
+        &self,
+        request: QueryConsensusStateRequest,
+        _include_proof: IncludeProof,
+    ) -> Result<(AnyConsensusState, Option<MerkleProof>), Error> {
+        self.host.query_consensus_state(
+            QueryConsensusStateRequest {
+                query_height: QueryHeight::Latest,
+                ..request
+            },
+            IncludeProof::No,
+        )
+    }
+
+    fn query_consensus_state_heights(
+        &self,
+        request: QueryConsensusStateHeightsRequest,
+    ) -> Result<Vec<ICSHeight>, Error> {
+        self.host.query_consensus_state_heights(request)
+    }
+
+    fn query_upgraded_client_state(
+        &self,
+        _request: QueryUpgradedClientStateRequest,
+    ) -> Result<(AnyClientState, MerkleProof), Error> {
+        Err(Error::solo_machine(
+            "upgrades are not supported by solo machines".to_string(),
+        ))
+    }
+
+    fn query_upgraded_consensus_state(
+        &self,
+        _request: QueryUpgradedConsensusStateRequest,
+    ) -> Result<(AnyConsensusState, MerkleProof), Error> {
+        Err(Error::solo_machine(
+            "upgrades are not supported by solo machines".to_string(),
+        ))
+    }
+
+    fn query_connections(
+        &self,
+        request: QueryConnectionsRequest,
+    ) -> Result<Vec<IdentifiedConnectionEnd>, Error> {
+        self.host.query_connections(request)
+    }
+
+    fn query_client_connections(
+        &self,
+        request: QueryClientConnectionsRequest,
+    ) -> Result<Vec<ConnectionId>, Error> {
+        self.host.query_client_connections(request)
+    }
+
+    fn query_connection(
+        &self,
+        request: QueryConnectionRequest,
+        _include_proof: IncludeProof,
+    ) -> Result<(ConnectionEnd, Option<MerkleProof>), Error> {
+        self.host.query_connection(
+            QueryConnectionRequest {
+                height: QueryHeight::Latest,
+                ..request
+            },
+            IncludeProof::No,
+        )
+    }
+
+    fn query_connection_channels(
+        &self,
+        request: QueryConnectionChannelsRequest,
+    ) -> Result<Vec<IdentifiedChannelEnd>, Error> {
+        self.host.query_connection_channels(request)
+    }
+
+    fn query_channels(
+        &self,
+        request: QueryChannelsRequest,
+    ) -> Result<Vec<IdentifiedChannelEnd>, Error> {
+        self.host.query_channels(request)
+    }
+
+    fn query_channel(
+        &self,
+        request: QueryChannelRequest,
+        _include_proof: IncludeProof,
+    ) -> Result<(ChannelEnd, Option<MerkleProof>), Error> {
+        self.host.query_channel(
+            QueryChannelRequest {
+                height: QueryHeight::Latest,
+                ..request
+            },
+            IncludeProof::No,
+        )
+    }
+
+    fn query_upgrade(
+        &self,
+        _request: QueryUpgradeRequest,
+        _include_proof: IncludeProof,
+    ) -> Result<(Upgrade, Option<MerkleProof>), Error> {
+        Err(Error::solo_machine(
+            "channel upgrades are not supported by solo machines".to_string(),
+        ))
+    }
+
+    fn query_channel_client_state(
+        &self,
+        request: QueryChannelClientStateRequest,
+    ) -> Result<Option<IdentifiedAnyClientState>, Error> {
+        self.host.query_channel_client_state(request)
+    }
+
+    fn query_packet_commitment(
+        &self,
+        request: QueryPacketCommitmentRequest,
+        _include_proof: IncludeProof,
+    ) -> Result<(Vec<u8>, Option<MerkleProof>), Error> {
+        self.host.query_packet_commitment(
+            QueryPacketCommitmentRequest {
+                height: QueryHeight::Latest,
+                ..request
+            },
+            IncludeProof::No,
+        )
+    }
+
+    fn query_packet_commitments(
+        &self,
+        request: QueryPacketCommitmentsRequest,
+    ) -> Result<(Vec<Sequence>, ICSHeight), Error> {
+        let (sequences, _) = self.host.query_packet_commitments(request)?;
+        Ok((sequences, self.latest_height()?))
+    }
+
+    fn query_packet_receipt(
+        &self,
+        request: QueryPacketReceiptRequest,
+        _include_proof: IncludeProof,
+    ) -> Result<(Vec<u8>, Option<MerkleProof>), Error> {
+        self.host.query_packet_receipt(
+            QueryPacketReceiptRequest {
+                height: QueryHeight::Latest,
+                ..request
+            },
+            IncludeProof::No,
+        )
+    }
+
+    fn query_unreceived_packets(
+        &self,
+        request: QueryUnreceivedPacketsRequest,
+    ) -> Result<Vec<Sequence>, Error> {
+        self.host.query_unreceived_packets(request)
+    }
+
+    fn query_packet_acknowledgement(
+        &self,
+        request: QueryPacketAcknowledgementRequest,
+        _include_proof: IncludeProof,
+    ) -> Result<(Vec<u8>, Option<MerkleProof>), Error> {
+        self.host.query_packet_acknowledgement(
+            QueryPacketAcknowledgementRequest {
+                height: QueryHeight::Latest,
+                ..request
+            },
+            IncludeProof::No,
+        )
+    }
+
+    fn query_packet_acknowledgements(
+        &self,
+        request: QueryPacketAcknowledgementsRequest,
+    ) -> Result<(Vec<Sequence>, ICSHeight), Error> {
+        let (sequences, _) = self.host.query_packet_acknowledgements(request)?;
+        Ok((sequences, self.latest_height()?))
+    }
+
+    fn query_unreceived_acknowledgements(
+        &self,
+        request: QueryUnreceivedAcksRequest,
+    ) -> Result<Vec<Sequence>, Error> {
+        self.host.query_unreceived_acknowledgements(request)
+    }
+
+    fn query_next_sequence_receive(
+        &self,
+        request: QueryNextSequenceReceiveRequest,
+        _include_proof: IncludeProof,
+    ) -> Result<(Sequence, Option<MerkleProof>), Error> {
+        self.host.query_next_sequence_receive(
+            QueryNextSequenceReceiveRequest {
+                height: QueryHeight::Latest,
+                ..request
+            },
+            IncludeProof::No,
+        )
+    }
+
+    fn query_txs(&self, request: QueryTxRequest) -> Result<Vec<IbcEventWithHeight>, Error> {
+        let request = match request {
+            QueryTxRequest::Client(request) => QueryTxRequest::Client(QueryClientEventRequest {
+                query_height: QueryHeight::Latest,
+                ..request
+            }),
+            request => request,
+        };
+
+        self.host.query_txs(request)
+    }
+
+    fn query_packet_events(
+        &self,
+        request: QueryPacketEventDataRequest,
+    ) -> Result<Vec<IbcEventWithHeight>, Error> {
+        self.host.query_packet_events(QueryPacketEventDataRequest {
+            height: Qualified::SmallerEqual(QueryHeight::Latest),
+            ..request
+        })
+    }
+
+    fn query_host_consensus_state(
+        &self,
+        _request: QueryHostConsensusStateRequest,
+    ) -> Result<Self::ConsensusState, Error> {
+        self.consensus_state()
+    }
+
+    fn build_client_state(
+        &self,
+        height: ICSHeight,
+        _settings: ClientSettings,
+    ) -> Result<Self::ClientState, Error> {
+        SmClientState::new(height.revision_height(), self.consensus_state()?).map_err(Error::ics06)
+    }
+
+    fn build_consensus_state(
+        &self,
+        light_block: Self::LightBlock,
+    ) -> Result<Self::ConsensusState, Error> {
+        Ok(light_block)
+    }
+
+    /// Builds a header signed at the sequence of the given client, which
+    /// brings the sequence of the solo machine back in sync with that client.
+    fn build_header(
+        &mut self,
+        _trusted_height: ICSHeight,
+        _target_height: ICSHeight,
+        client_state: &AnyClientState,
+    ) -> Result<(Self::Header, Vec<Self::Header>), Error> {
+        let AnyClientState::Solomachine(client_state) = client_state else {
+            return Err(Error::client_type_mismatch(
+                ClientType::Solomachine,
+                client_state.client_type(),
+            ));
+        };
+
+        let sequence = client_state.sequence;
+        let new_public_key = self.public_key()?;
+        let new_diversifier = self.diversifier();
+
+        // The timestamp of a header cannot go backwards
+        let now = Timestamp::now();
+        let timestamp = if now.after(&client_state.consensus_state.timestamp) {
+            now
+        } else {
+            client_state.consensus_state.timestamp
+        };
+
+        let signature = encode_signature(self.sign(
+            sequence,
+            timestamp,
+            HEADER_PATH,
+            header_data(&new_public_key, &new_diversifier),
+        )?);
+
+        self.sequence.store(sequence + 1, AtomicOrdering::SeqCst);
+
+        let header = SmHeader {
+            sequence,
+            timestamp,
+            signature,
+            new_public_key,
+            new_diversifier,
+        };
+
+        Ok((header, Vec::new()))
+    }
+
+    /// Signs the connection, and for `OpenTry` and `OpenAck` the client and
+    /// consensus states of the counterparty, in that order, which is the
+    /// order in which the counterparty client verifies them.
+    fn build_connection_proofs_and_client_state(
+        &self,
+        message_type: ConnectionMsgType,
+        connection_id: &ConnectionId,
+        client_id: &ClientId,
+        _height: ICSHeight,
+    ) -> Result<(Option<AnyClientState>, Proofs), Error> {
+        let (connection_end, _) = self.query_connection(
+            QueryConnectionRequest {
+                connection_id: connection_id.clone(),
+                height: QueryHeight::Latest,
+            },
+            IncludeProof::No,
+        )?;
+
+        let expected_states: &[ConnectionState] = match message_type {
+            ConnectionMsgType::OpenTry => &[ConnectionState::Init, ConnectionState::TryOpen],
+            ConnectionMsgType::OpenAck => &[ConnectionState::TryOpen, ConnectionState::Open],
+            ConnectionMsgType::OpenConfirm => &[ConnectionState::Open],
+        };
+
+        if !expected_states
+            .iter()
+            .any(|state| connection_end.state_matches(state))
+        {
+            return Err(Error::bad_connection_state());
+        }
+
+        let (sequence, connection_proof) = self.prove(
+            ConnectionsPath(connection_id.clone()),
+            connection_end.encode_vec(),
+        )?;
+
+        let mut client_state = None;
+        let mut client_proof = None;
+        let mut consensus_proof = None;
+
+        if matches!(
+            message_type,
+            ConnectionMsgType::OpenTry | ConnectionMsgType::OpenAck
+        ) {
+            let (client_state_value, _) = self.query_client_state(
+                QueryClientStateRequest {
+                    client_id: client_id.clone(),
+                    height: QueryHeight::Latest,
+                },
+                IncludeProof::No,
+            )?;
+
+            let consensus_height = client_state_value.latest_height();
+
+            let (consensus_state, _) = self.query_consensus_state(
+                QueryConsensusStateRequest {
+                    client_id: client_id.clone(),
+                    consensus_height,
+                    query_height: QueryHeight::Latest,
+                },
+                IncludeProof::No,
+            )?;
+
+            let (_, proof) = self.prove(
+                ClientStatePath(client_id.clone()),
+                Protobuf::<Any>::encode_vec(client_state_value.clone()),
+            )?;
+            client_proof = Some(proof);
+
+            let (_, proof) = self.prove(
+                ClientConsensusStatePath {
+                    client_id: client_id.clone(),
+                    epoch: consensus_height.revision_number(),
+                    height: consensus_height.revision_height(),
+                },
+                Protobuf::<Any>::encode_vec(consensus_state),
+            )?;
+            consensus_proof =
+                Some(ConsensusProof::new(proof, consensus_height).map_err(Error::consensus_proof)?);
+
+            client_state = Some(client_state_value);
+        }
+
+        Ok((
+            client_state,
+            Proofs::new(
+                connection_proof,
+                client_proof,
+                consensus_proof,
+                None,
+                None,
+                Self::proof_height(sequence)?,
+            )
+            .map_err(Error::malformed_proof)?,
+        ))
+    }
+
+    fn build_channel_proofs(
+        &self,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        _height: ICSHeight,
+    ) -> Result<Proofs, Error> {
+        let (sequence, channel_proof) = self.prove_channel(port_id, channel_id)?;
+
+        Proofs::new(
+            channel_proof,
+            None,
+            None,
+            None,
+            None,
+            Self::proof_height(sequence)?,
+        )
+        .map_err(Error::malformed_proof)
+    }
+
+    fn build_packet_proofs(
+        &self,
+        packet_type: PacketMsgType,
+        port_id: PortId,
+        channel_id: ChannelId,
+        sequence: Sequence,
+        _height: ICSHeight,
+    ) -> Result<Proofs, Error> {
+        // On channel closure, the channel is verified before the packet
+        let channel_proof = match packet_type {
+            PacketMsgType::TimeoutOnCloseUnordered | PacketMsgType::TimeoutOnCloseOrdered => {
+                Some(self.prove_channel(&port_id, &channel_id)?)
+            }
+            _ => None,
+        };
+
+        let (proof_sequence, packet_proof) = match packet_type {
+            PacketMsgType::Recv => {
+                let (commitment, _) = self.query_packet_commitment(
+                    QueryPacketCommitmentRequest {
+                        port_id: port_id.clone(),
+                        channel_id: channel_id.clone(),
+                        sequence,
+                        height: QueryHeight::Latest,
+                    },
+                    IncludeProof::No,
+                )?;
+
+                self.prove(
+                    CommitmentsPath {
+                        port_id,
+                        channel_id,
+                        sequence,
+                    },
+                    commitment,
+                )?
+            }
+            PacketMsgType::Ack => {
+                let (ack, _) = self.query_packet_acknowledgement(
+                    QueryPacketAcknowledgementRequest {
+                        port_id: port_id.clone(),
+                        channel_id: channel_id.clone(),
+                        sequence,
+                        height: QueryHeight::Latest,
+                    },
+                    IncludeProof::No,
+                )?;
+
+                self.prove(
+                    AcksPath {
+                        port_id,
+                        channel_id,
+                        sequence,
+                    },
+                    ack,
+                )?
+            }
+            PacketMsgType::TimeoutUnordered | PacketMsgType::TimeoutOnCloseUnordered => {
+                // The absence of a receipt is attested by signing empty data
+                self.prove(
+                    ReceiptsPath {
+                        port_id,
+                        channel_id,
+                        sequence,
+                    },
+                    Vec::new(),
+                )?
+            }
+            PacketMsgType::TimeoutOrdered | PacketMsgType::TimeoutOnCloseOrdered => {
+                let (next_sequence, _) = self.query_next_sequence_receive(
+                    QueryNextSequenceReceiveRequest {
+                        port_id: port_id.clone(),
+                        channel_id: channel_id.clone(),
+                        height: QueryHeight::Latest,
+                    },
+                    IncludeProof::No,
+                )?;
+
+                self.prove(
+                    SeqRecvsPath(port_id, channel_id),
+                    u64::from(next_sequence).to_be_bytes().to_vec(),
+                )?
+            }
+        };
+
+        let (proof_sequence, channel_proof) = match channel_proof {
+            Some((channel_sequence, channel_proof)) => (channel_sequence, Some(channel_proof)),
+            None => (proof_sequence, None),
+        };
+
+        Proofs::new(
+            packet_proof,
+            None,
+            None,
+            None,
+            channel_proof,
+            Self::proof_height(proof_sequence)?,
+        )
+        .map_err(Error::malformed_proof)
+    }
+
+    fn maybe_register_counterparty_payee(
+        &mut self,
+        _channel_id: &ChannelId,
+        _port_id: &PortId,
+        _counterparty_payee: &Signer,
+    ) -> Result<(), Error> {
+        // Solo machines do not support fees, so there is no payee to register
+        Ok(())
+    }
+
+    fn cross_chain_query(
+        &self,
+        _requests: Vec<CrossChainQueryRequest>,
+    ) -> Result<Vec<CrossChainQueryResponse>, Error> {
+        Err(Error::solo_machine(
+            "cross-chain queries are not supported by solo machines".to_string(),
+        ))
+    }
+
+    fn query_incentivized_packet(
+        &self,
+        _request: QueryIncentivizedPacketRequest,
+    ) -> Result<QueryIncentivizedPacketResponse, Error> {
+        Err(Error::solo_machine(
+            "fees are not supported by solo machines".to_string(),
+        ))
+    }
+
+    fn query_consumer_chains(&self) -> Result<Vec<(ChainId, ClientId)>, Error> {
+        Ok(Vec::new())
+    }
+
+    fn query_grants(&self, _request: QueryGrantsRequest) -> Result<Grants, Error> {
+        Err(Error::solo_machine(
+            "fee grants are not supported by solo machines".to_string(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use ibc_relayer_types::core::ics02_client::msgs::create_client::MsgCreateClient;
+    use ibc_relayer_types::core::ics02_client::msgs::update_client::MsgUpdateClient;
+    use ibc_relayer_types::events::IbcEvent;
+    use ibc_relayer_types::tx_msg::Msg;
+    use test_log::test;
+
+    use crate::chain::mock::config::MockChainConfig;
+    use crate::chain::mock::{generate_key, MockChain};
+    use crate::config::{default, PacketFilter};
+    use crate::keyring::Store;
+
+    fn bootstrap(id: &str) -> SoloMachine {
+        let config = SoloMachineConfig {
+            id: ChainId::from_string(id),
+            key_name: "solo".to_string(),
+            account_prefix: "cosmos".to_string(),
+            key_store_type: Store::Memory,
+            key_store_folder: None,
+            key_store_passphrase: None,
+            remote_signer: None,
+            store_prefix: "ibc".to_string(),
+            sequence: 1,
+            query_packets_chunk_size: default::query_packets_chunk_size(),
+            clear_interval: None,
+            packet_filter: PacketFilter::default(),
+        };
+
+        let rt = Arc::new(TokioRuntime::new().unwrap());
+        let mut solo = SoloMachine::bootstrap(ChainConfig::SoloMachine(config), rt).unwrap();
+
+        solo.keybase_mut()
+            .add_key("solo", generate_key("cosmos").unwrap())
+            .unwrap();
+
+        solo
+    }
+
+    fn bootstrap_mock(id: &str) -> MockChain {
+        let rt = Arc::new(TokioRuntime::new().unwrap());
+        MockChain::bootstrap(
+            ChainConfig::Mock(MockChainConfig::new(ChainId::from_string(id))),
+            rt,
+        )
+        .unwrap()
+    }
+
+    /// Creates a client of `solo` on `chain`, returning its identifier.
+    fn create_client(chain: &mut MockChain, solo: &SoloMachine) -> ClientId {
+        let height = solo.query_application_status().unwrap().height;
+        let client_state = solo
+            .build_client_state(height, ClientSettings::SoloMachine)
+            .unwrap();
+        let consensus_state = solo
+            .query_host_consensus_state(QueryHostConsensusStateRequest {
+                height: QueryHeight::Latest,
+            })
+            .unwrap();
+
+        let msg = MsgCreateClient::new(
+            client_state.into(),
+            consensus_state.into(),
+            chain.get_signer().unwrap(),
+        )
+        .unwrap();
+
+        let events = chain
+            .send_messages_and_wait_commit(TrackedMsgs::new_single(msg.to_any(), "create_client"))
+            .unwrap();
+
+        match &events[0].event {
+            IbcEvent::CreateClient(event) => event.client_id().clone(),
+            event => panic!("expected a CreateClient event, got {event:?}"),
+        }
+    }
+
+    fn update_client(
+        chain: &mut MockChain,
+        solo: &mut SoloMachine,
+        client_id: &ClientId,
+    ) -> Vec<IbcEventWithHeight> {
+        let (client_state, _) = chain
+            .query_client_state(
+                QueryClientStateRequest {
+                    client_id: client_id.clone(),
+                    height: QueryHeight::Latest,
+                },
+                IncludeProof::No,
+            )
+            .unwrap();
+
+        let height = client_state.latest_height();
+        let (header, _) = solo.build_header(height, height, &client_state).unwrap();
+
+        let msg = MsgUpdateClient {
+            client_id: client_id.clone(),
+            header: header.into(),
+            signer: chain.get_signer().unwrap(),
+        };
+
+        chain
+            .send_messages_and_wait_commit(TrackedMsgs::new_single(msg.to_any(), "update_client"))
+            .unwrap()
+    }
+
+    #[test]
+    fn create_and_update_client() {
+        let mut chain = bootstrap_mock("mock-0");
+        let mut solo = bootstrap("solo-0");
+
+        let client_id = create_client(&mut chain, &solo);
+
+        let events = update_client(&mut chain, &mut solo, &client_id);
+        assert!(matches!(events[0].event, IbcEvent::UpdateClient(_)));
+
+        let (client_state, _) = chain
+            .query_client_state(
+                QueryClientStateRequest {
+                    client_id,
+                    height: QueryHeight::Latest,
+                },
+                IncludeProof::No,
+            )
+            .unwrap();
+
+        // The header consumed the first sequence, which the solo machine follows
+        assert_eq!(client_state.latest_height(), ICSHeight::new(0, 2).unwrap());
+        assert_eq!(solo.latest_height().unwrap(), ICSHeight::new(0, 2).unwrap());
+
+        chain.shutdown().unwrap();
+        solo.shutdown().unwrap();
+    }
+
+    #[test]
+    fn header_signed_by_another_key_is_rejected() {
+        let mut chain = bootstrap_mock("mock-0");
+        let solo = bootstrap("solo-0");
+        let mut impostor = bootstrap("solo-0");
+
+        let client_id = create_client(&mut chain, &solo);
+
+        let events = update_client(&mut chain, &mut impostor, &client_id);
+        assert!(matches!(events[0].event, IbcEvent::ChainError(_)));
+
+        chain.shutdown().unwrap();
+        solo.shutdown().unwrap();
+        impostor.shutdown().unwrap();
+    }
+}
@@ -0,0 +1,67 @@
+use core::time::Duration;
+use std::path::PathBuf;
+
+use serde_derive::{Deserialize, Serialize};
+
+use ibc_relayer_types::core::ics24_host::identifier::ChainId;
+
+use crate::config::{default, PacketFilter};
+use crate::keyring::{KeyStorePassphrase, RemoteSignerConfig, Store};
+
+/// The interval at which the in-memory store of a solo machine commits its state.
+pub const BLOCK_TIME: Duration = Duration::from_millis(100);
+
+/// Configuration of a solo machine, ie. a process whose state is attested
+/// by the signatures of a single key rather than by a consensus.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct SoloMachineConfig {
+    /// The identifier of the solo machine, which is also the diversifier
+    /// included in everything it signs
+    pub id: ChainId,
+
+    /// The key attesting the state of the solo machine
+    pub key_name: String,
+
+    #[serde(default = "default_account_prefix")]
+    pub account_prefix: String,
+
+    #[serde(default)]
+    pub key_store_type: Store,
+    pub key_store_folder: Option<PathBuf>,
+    /// Where to read the passphrase of the `File` key store from
+    pub key_store_passphrase: Option<KeyStorePassphrase>,
+    /// The signer holding the keys of the `Remote` key store
+    pub remote_signer: Option<RemoteSignerConfig>,
+
+    #[serde(default = "default_store_prefix")]
+    pub store_prefix: String,
+
+    /// The sequence of the first signature produced by the solo machine.
+    ///
+    /// When restarting a solo machine, this must be set to the sequence of
+    /// its clients on the counterparty chains.
+    #[serde(default = "default_sequence")]
+    pub sequence: u64,
+
+    /// How many packets to fetch at once from the chain when clearing packets
+    #[serde(default = "default::query_packets_chunk_size")]
+    pub query_packets_chunk_size: usize,
+
+    pub clear_interval: Option<u64>,
+
+    #[serde(default)]
+    pub packet_filter: PacketFilter,
+}
+
+fn default_account_prefix() -> String {
+    "cosmos".to_string()
+}
+
+fn default_store_prefix() -> String {
+    "ibc".to_string()
+}
+
+fn default_sequence() -> u64 {
+    1
+}
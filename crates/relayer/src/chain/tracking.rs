@@ -1,6 +1,7 @@
 use core::fmt::{Display, Error as FmtError, Formatter};
 
 use ibc_proto::google::protobuf::Any;
+use ibc_relayer_types::signer::Signer;
 use uuid::Uuid;
 
 /// Identifier used to track an `EventBatch` along
@@ -70,17 +71,25 @@ impl Display for TrackingId {
 pub struct TrackedMsgs {
     pub msgs: Vec<Any>,
     pub tracking_id: TrackingId,
+    /// The signer these messages were built with, if they were built with the
+    /// signer of a wallet selected to relay packets rather than the signer of the chain.
+    pub signer: Option<Signer>,
 }
 
 impl TrackedMsgs {
     pub fn new(msgs: Vec<Any>, tracking_id: TrackingId) -> Self {
-        Self { msgs, tracking_id }
+        Self {
+            msgs,
+            tracking_id,
+            signer: None,
+        }
     }
 
     pub fn new_static(msgs: Vec<Any>, tracking_id: &'static str) -> Self {
         Self {
             msgs,
             tracking_id: TrackingId::Static(tracking_id),
+            signer: None,
         }
     }
 
@@ -88,6 +97,7 @@ impl TrackedMsgs {
         Self {
             msgs,
             tracking_id: TrackingId::Uuid(tracking_id),
+            signer: None,
         }
     }
 
@@ -95,6 +105,7 @@ impl TrackedMsgs {
         Self {
             msgs: vec![msg],
             tracking_id: TrackingId::Static(tracking_id),
+            signer: None,
         }
     }
 
@@ -102,6 +113,7 @@ impl TrackedMsgs {
         Self {
             msgs: vec![msg],
             tracking_id: TrackingId::Uuid(tracking_id),
+            signer: None,
         }
     }
 
@@ -112,4 +124,15 @@ impl TrackedMsgs {
     pub fn tracking_id(&self) -> TrackingId {
         self.tracking_id
     }
+
+    /// Marks these messages as built with the given signer, in which case
+    /// they are signed with the key of the corresponding wallet.
+    pub fn with_signer(mut self, signer: Signer) -> Self {
+        self.signer = Some(signer);
+        self
+    }
+
+    pub fn signer(&self) -> Option<&Signer> {
+        self.signer.as_ref()
+    }
 }
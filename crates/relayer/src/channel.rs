@@ -15,10 +15,19 @@ use ibc_relayer_types::core::ics04_channel::msgs::chan_open_ack::MsgChannelOpenA
 use ibc_relayer_types::core::ics04_channel::msgs::chan_open_confirm::MsgChannelOpenConfirm;
 use ibc_relayer_types::core::ics04_channel::msgs::chan_open_init::MsgChannelOpenInit;
 use ibc_relayer_types::core::ics04_channel::msgs::chan_open_try::MsgChannelOpenTry;
+use ibc_relayer_types::core::ics04_channel::msgs::chan_upgrade_ack::MsgChannelUpgradeAck;
+use ibc_relayer_types::core::ics04_channel::msgs::chan_upgrade_confirm::MsgChannelUpgradeConfirm;
+use ibc_relayer_types::core::ics04_channel::msgs::chan_upgrade_init::MsgChannelUpgradeInit;
+use ibc_relayer_types::core::ics04_channel::msgs::chan_upgrade_open::MsgChannelUpgradeOpen;
+use ibc_relayer_types::core::ics04_channel::msgs::chan_upgrade_try::MsgChannelUpgradeTry;
+use ibc_relayer_types::core::ics04_channel::upgrade::Upgrade;
+use ibc_relayer_types::core::ics04_channel::upgrade_fields::UpgradeFields;
+use ibc_relayer_types::core::ics23_commitment::commitment::CommitmentProofBytes;
 use ibc_relayer_types::core::ics24_host::identifier::{
     ChainId, ChannelId, ClientId, ConnectionId, PortId,
 };
 use ibc_relayer_types::events::IbcEvent;
+use ibc_relayer_types::signer::Signer;
 use ibc_relayer_types::tx_msg::Msg;
 use ibc_relayer_types::Height;
 
@@ -26,10 +35,11 @@ use crate::chain::counterparty::{channel_connection_client, channel_state_on_des
 use crate::chain::handle::ChainHandle;
 use crate::chain::requests::{
     IncludeProof, PageRequest, QueryChannelRequest, QueryConnectionChannelsRequest,
-    QueryConnectionRequest, QueryHeight,
+    QueryConnectionRequest, QueryHeight, QueryUpgradeRequest,
 };
 use crate::chain::tracking::TrackedMsgs;
 use crate::connection::Connection;
+use crate::error::Error as RelayerError;
 use crate::foreign_client::{ForeignClient, HasExpiredOrFrozenError};
 use crate::object::Channel as WorkerChannelObject;
 use crate::supervisor::error::Error as SupervisorError;
@@ -757,7 +767,15 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> Channel<ChainA, ChainB> {
             (State::TryOpen, State::Init) => Some(self.build_chan_open_ack_and_send()?),
             (State::TryOpen, State::TryOpen) => Some(self.build_chan_open_ack_and_send()?),
             (State::Open, State::TryOpen) => Some(self.build_chan_open_confirm_and_send()?),
-            (State::Open, State::Open) => return Ok((None, Next::Abort)),
+            (State::Open, State::Open) => {
+                // The source end may have initiated an upgrade that the
+                // destination end has not picked up yet.
+                if self.has_pending_upgrade_on_src()? {
+                    Some(self.build_chan_upgrade_try_and_send()?)
+                } else {
+                    return Ok((None, Next::Abort));
+                }
+            }
 
             // If the counterparty state is already Open but current state is TryOpen,
             // return anyway as the final step is to be done by the counterparty worker.
@@ -767,21 +785,82 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> Channel<ChainA, ChainB> {
             (State::Closed, State::Closed) => return Ok((None, Next::Abort)),
             (State::Closed, _) => Some(self.build_chan_close_confirm_and_send()?),
 
+            // Upgrade handshake steps
+            (State::Flushing, State::Open) => Some(self.build_chan_upgrade_ack_and_send()?),
+            (State::Open | State::FlushComplete, State::FlushComplete) => {
+                Some(self.build_chan_upgrade_open_and_send()?)
+            }
+
             _ => None,
         };
 
-        // Abort if the channel is at OpenAck, OpenConfirm or CloseConfirm stage, as there is
-        // nothing more for the worker to do
+        // Abort if the channel is at OpenAck, OpenConfirm, CloseConfirm or UpgradeOpen stage,
+        // as there is nothing more for the worker to do
         match event {
             Some(IbcEvent::OpenConfirmChannel(_))
             | Some(IbcEvent::OpenAckChannel(_))
-            | Some(IbcEvent::CloseConfirmChannel(_)) => Ok((event, Next::Abort)),
+            | Some(IbcEvent::CloseConfirmChannel(_))
+            | Some(IbcEvent::UpgradeOpenChannel(_)) => Ok((event, Next::Abort)),
+            _ => Ok((event, Next::Continue)),
+        }
+    }
+
+    /// Performs the step following an `UpgradeAck` on the source chain.
+    ///
+    /// Whether a `ChanUpgradeConfirm` is due cannot be decided from the channel
+    /// end states alone, since both ends are in `FLUSHING` state after the
+    /// `ChanUpgradeTry` on one end and the `ChanUpgradeAck` on the other.
+    fn upgrade_ack_step(&mut self) -> Result<(Option<IbcEvent>, Next), ChannelError> {
+        let event = match self.counterparty_state()? {
+            State::Flushing => Some(self.build_chan_upgrade_confirm_and_send()?),
+            _ => None,
+        };
+
+        match event {
+            Some(IbcEvent::UpgradeOpenChannel(_)) => Ok((event, Next::Abort)),
             _ => Ok((event, Next::Continue)),
         }
     }
 
+    /// Returns `true` if the source channel end has an upgrade in progress
+    /// with a higher upgrade sequence than the destination channel end.
+    fn has_pending_upgrade_on_src(&self) -> Result<bool, ChannelError> {
+        let src_channel = self.a_channel(self.a_channel_id())?;
+        let dst_channel = self.b_channel(self.b_channel_id())?;
+
+        if src_channel.upgrade_sequence <= dst_channel.upgrade_sequence {
+            return Ok(false);
+        }
+
+        let src_channel_id = self
+            .src_channel_id()
+            .ok_or_else(ChannelError::missing_local_channel_id)?;
+
+        // The upgrade is removed from the store once it is cancelled, while
+        // the upgrade sequence is kept.
+        let upgrade = self.src_chain().query_upgrade(
+            QueryUpgradeRequest {
+                port_id: self.src_port_id().clone(),
+                channel_id: src_channel_id.clone(),
+                height: QueryHeight::Latest,
+            },
+            IncludeProof::No,
+        );
+
+        Ok(upgrade.is_ok())
+    }
+
     pub fn step_state(&mut self, state: State, index: u64) -> RetryResult<Next, u64> {
-        match self.handshake_step(state) {
+        let result = self.handshake_step(state);
+        Self::step_result(result, state, index)
+    }
+
+    fn step_result(
+        result: Result<(Option<IbcEvent>, Next), ChannelError>,
+        state: State,
+        index: u64,
+    ) -> RetryResult<Next, u64> {
+        match result {
             Err(e) => {
                 if e.is_expired_or_frozen_error() {
                     error!(
@@ -809,6 +888,22 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> Channel<ChainA, ChainB> {
             IbcEvent::OpenAckChannel(_) => State::Open,
             IbcEvent::OpenConfirmChannel(_) => State::Open,
             IbcEvent::CloseInitChannel(_) => State::Closed,
+            IbcEvent::UpgradeAckChannel(_) => {
+                let result = self.upgrade_ack_step();
+                return Self::step_result(result, State::Flushing, index);
+            }
+            // The state reached by an upgrade step depends on the packets
+            // still in flight, hence it is queried from the source chain.
+            IbcEvent::UpgradeInitChannel(_)
+            | IbcEvent::UpgradeTryChannel(_)
+            | IbcEvent::UpgradeConfirmChannel(_)
+            | IbcEvent::UpgradeOpenChannel(_) => match self.a_channel(self.a_channel_id()) {
+                Ok(channel_end) => *channel_end.state(),
+                Err(e) => {
+                    error!("failed to query channel end state: {}", e);
+                    return RetryResult::Retry(index);
+                }
+            },
             _ => State::Uninitialized,
         };
 
@@ -1469,6 +1564,383 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> Channel<ChainA, ChainB> {
         }
     }
 
+    /// Queries the source channel end at the given height, together with
+    /// its membership proof.
+    fn query_src_channel_with_proof(
+        &self,
+        height: Height,
+    ) -> Result<(ChannelEnd, CommitmentProofBytes), ChannelError> {
+        let src_channel_id = self
+            .src_channel_id()
+            .ok_or_else(ChannelError::missing_local_channel_id)?;
+
+        let (channel_end, maybe_proof) = self
+            .src_chain()
+            .query_channel(
+                QueryChannelRequest {
+                    port_id: self.src_port_id().clone(),
+                    channel_id: src_channel_id.clone(),
+                    height: QueryHeight::Specific(height),
+                },
+                IncludeProof::Yes,
+            )
+            .map_err(|e| ChannelError::query(self.src_chain().id(), e))?;
+
+        let proof = maybe_proof
+            .ok_or_else(|| ChannelError::channel_proof(RelayerError::queried_proof_not_found()))?;
+
+        let proof_bytes = CommitmentProofBytes::try_from(proof)
+            .map_err(|e| ChannelError::channel_proof(RelayerError::malformed_proof(e)))?;
+
+        Ok((channel_end, proof_bytes))
+    }
+
+    /// Queries the pending upgrade of the source channel end at the given
+    /// height, together with its membership proof.
+    fn query_src_upgrade_with_proof(
+        &self,
+        height: Height,
+    ) -> Result<(Upgrade, CommitmentProofBytes), ChannelError> {
+        let src_channel_id = self
+            .src_channel_id()
+            .ok_or_else(ChannelError::missing_local_channel_id)?;
+
+        let (upgrade, maybe_proof) = self
+            .src_chain()
+            .query_upgrade(
+                QueryUpgradeRequest {
+                    port_id: self.src_port_id().clone(),
+                    channel_id: src_channel_id.clone(),
+                    height: QueryHeight::Specific(height),
+                },
+                IncludeProof::Yes,
+            )
+            .map_err(|e| ChannelError::query(self.src_chain().id(), e))?;
+
+        let proof = maybe_proof
+            .ok_or_else(|| ChannelError::channel_proof(RelayerError::queried_proof_not_found()))?;
+
+        let proof_bytes = CommitmentProofBytes::try_from(proof)
+            .map_err(|e| ChannelError::channel_proof(RelayerError::malformed_proof(e)))?;
+
+        Ok((upgrade, proof_bytes))
+    }
+
+    fn src_latest_height(&self) -> Result<Height, ChannelError> {
+        self.src_chain()
+            .query_latest_height()
+            .map_err(|e| ChannelError::query(self.src_chain().id(), e))
+    }
+
+    fn dst_signer(&self) -> Result<Signer, ChannelError> {
+        self.dst_chain()
+            .get_signer()
+            .map_err(|e| ChannelError::fetch_signer(self.dst_chain().id(), e))
+    }
+
+    /// Submits the given upgrade handshake messages to the destination chain
+    /// and returns the first event accepted by `is_expected`.
+    fn send_chan_upgrade_msgs(
+        &self,
+        msgs: Vec<Any>,
+        tracking_id: &'static str,
+        is_expected: fn(&IbcEvent) -> bool,
+    ) -> Result<IbcEvent, ChannelError> {
+        let tm = TrackedMsgs::new_static(msgs, tracking_id);
+
+        let events = self
+            .dst_chain()
+            .send_messages_and_wait_commit(tm)
+            .map_err(|e| ChannelError::submit(self.dst_chain().id(), e))?;
+
+        let result = events
+            .into_iter()
+            .find(|event_with_height| {
+                is_expected(&event_with_height.event)
+                    || matches!(event_with_height.event, IbcEvent::ChainError(_))
+            })
+            .ok_or_else(|| {
+                ChannelError::missing_event(format!("no {tracking_id} event was in the response"))
+            })?;
+
+        match &result.event {
+            IbcEvent::ChainError(e) => Err(ChannelError::tx_response(e.clone())),
+            event if is_expected(event) => {
+                info!("🎊  {} => {}", self.dst_chain().id(), result);
+                Ok(result.event)
+            }
+            _ => Err(ChannelError::invalid_event(result.event)),
+        }
+    }
+
+    /// Builds a `MsgChannelUpgradeInit` for the destination channel end.
+    ///
+    /// Any of the upgrade fields left unspecified is taken from the current
+    /// channel end. Note that chains usually require the signer of this
+    /// message to be the channel upgrade authority.
+    pub fn build_chan_upgrade_init(
+        &self,
+        new_version: Option<Version>,
+        new_ordering: Option<Ordering>,
+        new_connection_hops: Option<Vec<ConnectionId>>,
+    ) -> Result<Vec<Any>, ChannelError> {
+        // Destination channel ID must be specified
+        let dst_channel_id = self
+            .dst_channel_id()
+            .ok_or_else(ChannelError::missing_counterparty_channel_id)?;
+
+        if new_version.is_none() && new_ordering.is_none() && new_connection_hops.is_none() {
+            return Err(ChannelError::missing_upgrade_fields());
+        }
+
+        // Channel must exist and be open on destination
+        let (channel_end, _) = self
+            .dst_chain()
+            .query_channel(
+                QueryChannelRequest {
+                    port_id: self.dst_port_id().clone(),
+                    channel_id: dst_channel_id.clone(),
+                    height: QueryHeight::Latest,
+                },
+                IncludeProof::No,
+            )
+            .map_err(|e| ChannelError::query(self.dst_chain().id(), e))?;
+
+        if !channel_end.is_open() {
+            return Err(ChannelError::invalid_upgrade_state(
+                dst_channel_id.clone(),
+                *channel_end.state(),
+            ));
+        }
+
+        let fields = UpgradeFields::new(
+            new_ordering.unwrap_or(*channel_end.ordering()),
+            new_connection_hops.unwrap_or_else(|| channel_end.connection_hops().clone()),
+            new_version.unwrap_or_else(|| channel_end.version().clone()),
+        );
+
+        let signer = self.dst_signer()?;
+
+        // Build the domain type message
+        let new_msg = MsgChannelUpgradeInit::new(
+            self.dst_port_id().clone(),
+            dst_channel_id.clone(),
+            fields,
+            signer,
+        );
+
+        Ok(vec![new_msg.to_any()])
+    }
+
+    pub fn build_chan_upgrade_init_and_send(
+        &self,
+        new_version: Option<Version>,
+        new_ordering: Option<Ordering>,
+        new_connection_hops: Option<Vec<ConnectionId>>,
+    ) -> Result<IbcEvent, ChannelError> {
+        let dst_msgs =
+            self.build_chan_upgrade_init(new_version, new_ordering, new_connection_hops)?;
+
+        self.send_chan_upgrade_msgs(dst_msgs, "ChannelUpgradeInit", |event| {
+            matches!(event, IbcEvent::UpgradeInitChannel(_))
+        })
+        .map_err(|e| {
+            error!("failed ChanUpgradeInit {}: {}", self.b_side, e);
+            e
+        })
+    }
+
+    pub fn build_chan_upgrade_try(&self) -> Result<Vec<Any>, ChannelError> {
+        // Destination channel ID must be specified
+        let dst_channel_id = self
+            .dst_channel_id()
+            .ok_or_else(ChannelError::missing_counterparty_channel_id)?;
+
+        // Channel must exist on destination
+        let (dst_channel, _) = self
+            .dst_chain()
+            .query_channel(
+                QueryChannelRequest {
+                    port_id: self.dst_port_id().clone(),
+                    channel_id: dst_channel_id.clone(),
+                    height: QueryHeight::Latest,
+                },
+                IncludeProof::No,
+            )
+            .map_err(|e| ChannelError::query(self.dst_chain().id(), e))?;
+
+        let query_height = self.src_latest_height()?;
+        let (src_channel, proof_channel) = self.query_src_channel_with_proof(query_height)?;
+        let (upgrade, proof_upgrade) = self.query_src_upgrade_with_proof(query_height)?;
+        let proof_height = query_height.increment();
+
+        // Build message(s) to update client on destination
+        let mut msgs = self.build_update_client_on_dst(proof_height)?;
+
+        let signer = self.dst_signer()?;
+
+        // Build the domain type message
+        let new_msg = MsgChannelUpgradeTry::new(
+            self.dst_port_id().clone(),
+            dst_channel_id.clone(),
+            dst_channel.connection_hops().clone(),
+            upgrade.fields,
+            src_channel.upgrade_sequence,
+            proof_channel,
+            proof_upgrade,
+            proof_height,
+            signer,
+        );
+
+        msgs.push(new_msg.to_any());
+        Ok(msgs)
+    }
+
+    pub fn build_chan_upgrade_try_and_send(&self) -> Result<IbcEvent, ChannelError> {
+        let dst_msgs = self.build_chan_upgrade_try()?;
+
+        self.send_chan_upgrade_msgs(dst_msgs, "ChannelUpgradeTry", |event| {
+            matches!(event, IbcEvent::UpgradeTryChannel(_))
+        })
+        .map_err(|e| {
+            error!("failed ChanUpgradeTry {}: {}", self.b_side, e);
+            e
+        })
+    }
+
+    pub fn build_chan_upgrade_ack(&self) -> Result<Vec<Any>, ChannelError> {
+        // Destination channel ID must be specified
+        let dst_channel_id = self
+            .dst_channel_id()
+            .ok_or_else(ChannelError::missing_counterparty_channel_id)?;
+
+        let query_height = self.src_latest_height()?;
+        let (_, proof_channel) = self.query_src_channel_with_proof(query_height)?;
+        let (upgrade, proof_upgrade) = self.query_src_upgrade_with_proof(query_height)?;
+        let proof_height = query_height.increment();
+
+        // Build message(s) to update client on destination
+        let mut msgs = self.build_update_client_on_dst(proof_height)?;
+
+        let signer = self.dst_signer()?;
+
+        // Build the domain type message
+        let new_msg = MsgChannelUpgradeAck::new(
+            self.dst_port_id().clone(),
+            dst_channel_id.clone(),
+            upgrade,
+            proof_channel,
+            proof_upgrade,
+            proof_height,
+            signer,
+        );
+
+        msgs.push(new_msg.to_any());
+        Ok(msgs)
+    }
+
+    pub fn build_chan_upgrade_ack_and_send(&self) -> Result<IbcEvent, ChannelError> {
+        let dst_msgs = self.build_chan_upgrade_ack()?;
+
+        self.send_chan_upgrade_msgs(dst_msgs, "ChannelUpgradeAck", |event| {
+            matches!(event, IbcEvent::UpgradeAckChannel(_))
+        })
+        .map_err(|e| {
+            error!("failed ChanUpgradeAck {}: {}", self.b_side, e);
+            e
+        })
+    }
+
+    pub fn build_chan_upgrade_confirm(&self) -> Result<Vec<Any>, ChannelError> {
+        // Destination channel ID must be specified
+        let dst_channel_id = self
+            .dst_channel_id()
+            .ok_or_else(ChannelError::missing_counterparty_channel_id)?;
+
+        let query_height = self.src_latest_height()?;
+        let (src_channel, proof_channel) = self.query_src_channel_with_proof(query_height)?;
+        let (upgrade, proof_upgrade) = self.query_src_upgrade_with_proof(query_height)?;
+        let proof_height = query_height.increment();
+
+        // Build message(s) to update client on destination
+        let mut msgs = self.build_update_client_on_dst(proof_height)?;
+
+        let signer = self.dst_signer()?;
+
+        // Build the domain type message
+        let new_msg = MsgChannelUpgradeConfirm::new(
+            self.dst_port_id().clone(),
+            dst_channel_id.clone(),
+            *src_channel.state(),
+            upgrade,
+            proof_channel,
+            proof_upgrade,
+            proof_height,
+            signer,
+        );
+
+        msgs.push(new_msg.to_any());
+        Ok(msgs)
+    }
+
+    pub fn build_chan_upgrade_confirm_and_send(&self) -> Result<IbcEvent, ChannelError> {
+        let dst_msgs = self.build_chan_upgrade_confirm()?;
+
+        // Confirm may complete the upgrade right away if both ends are done flushing
+        self.send_chan_upgrade_msgs(dst_msgs, "ChannelUpgradeConfirm", |event| {
+            matches!(
+                event,
+                IbcEvent::UpgradeConfirmChannel(_) | IbcEvent::UpgradeOpenChannel(_)
+            )
+        })
+        .map_err(|e| {
+            error!("failed ChanUpgradeConfirm {}: {}", self.b_side, e);
+            e
+        })
+    }
+
+    pub fn build_chan_upgrade_open(&self) -> Result<Vec<Any>, ChannelError> {
+        // Destination channel ID must be specified
+        let dst_channel_id = self
+            .dst_channel_id()
+            .ok_or_else(ChannelError::missing_counterparty_channel_id)?;
+
+        let query_height = self.src_latest_height()?;
+        let (src_channel, proof_channel) = self.query_src_channel_with_proof(query_height)?;
+        let proof_height = query_height.increment();
+
+        // Build message(s) to update client on destination
+        let mut msgs = self.build_update_client_on_dst(proof_height)?;
+
+        let signer = self.dst_signer()?;
+
+        // Build the domain type message
+        let new_msg = MsgChannelUpgradeOpen::new(
+            self.dst_port_id().clone(),
+            dst_channel_id.clone(),
+            *src_channel.state(),
+            src_channel.upgrade_sequence,
+            proof_channel,
+            proof_height,
+            signer,
+        );
+
+        msgs.push(new_msg.to_any());
+        Ok(msgs)
+    }
+
+    pub fn build_chan_upgrade_open_and_send(&self) -> Result<IbcEvent, ChannelError> {
+        let dst_msgs = self.build_chan_upgrade_open()?;
+
+        self.send_chan_upgrade_msgs(dst_msgs, "ChannelUpgradeOpen", |event| {
+            matches!(event, IbcEvent::UpgradeOpenChannel(_))
+        })
+        .map_err(|e| {
+            error!("failed ChanUpgradeOpen {}: {}", self.b_side, e);
+            e
+        })
+    }
+
     pub fn map_chain<ChainC: ChainHandle, ChainD: ChainHandle>(
         self,
         mapper_a: impl Fn(ChainA) -> ChainC,
@@ -154,6 +154,19 @@ define_error! {
                     e.counterparty_channel_id)
             },
 
+        InvalidUpgradeState
+            {
+                channel_id: ChannelId,
+                state: State,
+            }
+            | e | {
+                format_args!("channel '{0}' cannot be upgraded while in state {1}",
+                    e.channel_id, e.state)
+            },
+
+        MissingUpgradeFields
+            | _ | { "at least one of the version, ordering or connection hops must be specified for a channel upgrade" },
+
         MissingEvent
             { description: String }
             | e | {
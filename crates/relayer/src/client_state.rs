@@ -4,33 +4,45 @@ use serde::{Deserialize, Serialize};
 
 use ibc_proto::google::protobuf::Any;
 use ibc_proto::ibc::core::client::v1::IdentifiedClientState;
+use ibc_proto::ibc::lightclients::solomachine::v3::ClientState as RawSmClientState;
 use ibc_proto::ibc::lightclients::tendermint::v1::ClientState as RawTmClientState;
 use ibc_proto::Protobuf;
+use ibc_relayer_types::clients::ics06_solomachine::client_state::{
+    ClientState as SmClientState, SOLOMACHINE_CLIENT_STATE_TYPE_URL,
+};
 use ibc_relayer_types::clients::ics07_tendermint::client_state::{
     ClientState as TmClientState, UpgradeOptions as TmUpgradeOptions,
     TENDERMINT_CLIENT_STATE_TYPE_URL,
 };
+use ibc_relayer_types::clients::ics08_wasm::client_message::{
+    Header as WasmHeader, Misbehaviour as WasmMisbehaviour,
+};
+use ibc_relayer_types::clients::ics08_wasm::client_state::{
+    ClientState as WasmClientState, WASM_CLIENT_STATE_TYPE_URL,
+};
+use ibc_relayer_types::clients::ics08_wasm::raw::ClientState as RawWasmClientState;
 use ibc_relayer_types::core::ics02_client::client_state::ClientState;
 use ibc_relayer_types::core::ics02_client::client_type::ClientType;
 use ibc_relayer_types::core::ics02_client::error::Error;
+use ibc_relayer_types::core::ics02_client::header::AnyHeader;
 use ibc_relayer_types::core::ics02_client::trust_threshold::TrustThreshold;
 use ibc_relayer_types::core::ics24_host::error::ValidationError;
 use ibc_relayer_types::core::ics24_host::identifier::{ChainId, ClientId};
 use ibc_relayer_types::Height;
 
-#[cfg(test)]
-use ibc_proto::ibc::mock::ClientState as RawMockClientState;
-#[cfg(test)]
-use ibc_relayer_types::mock::client_state::MockClientState;
-#[cfg(test)]
-use ibc_relayer_types::mock::client_state::MOCK_CLIENT_STATE_TYPE_URL;
+use crate::misbehaviour::AnyMisbehaviour;
+
+#[cfg(any(test, feature = "mock"))]
+use ibc_relayer_types::mock::client_state::{
+    MockClientState, RawMockClientState, MOCK_CLIENT_STATE_TYPE_URL,
+};
 
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(tag = "type")]
 pub enum AnyUpgradeOptions {
     Tendermint(TmUpgradeOptions),
 
-    #[cfg(test)]
+    #[cfg(any(test, feature = "mock"))]
     Mock(()),
 }
 
@@ -38,7 +50,7 @@ impl AnyUpgradeOptions {
     fn into_tm_upgrade_options(self) -> Option<TmUpgradeOptions> {
         match self {
             AnyUpgradeOptions::Tendermint(tm) => Some(tm),
-            #[cfg(test)]
+            #[cfg(any(test, feature = "mock"))]
             AnyUpgradeOptions::Mock(_) => None,
         }
     }
@@ -49,7 +61,11 @@ impl AnyUpgradeOptions {
 pub enum AnyClientState {
     Tendermint(TmClientState),
 
-    #[cfg(test)]
+    Solomachine(SmClientState),
+
+    Wasm(WasmClientState),
+
+    #[cfg(any(test, feature = "mock"))]
     Mock(MockClientState),
 }
 
@@ -58,7 +74,11 @@ impl AnyClientState {
         match self {
             AnyClientState::Tendermint(tm_state) => tm_state.chain_id(),
 
-            #[cfg(test)]
+            AnyClientState::Solomachine(sm_state) => sm_state.chain_id(),
+
+            AnyClientState::Wasm(wasm_state) => wasm_state.chain_id(),
+
+            #[cfg(any(test, feature = "mock"))]
             AnyClientState::Mock(mock_state) => mock_state.chain_id(),
         }
     }
@@ -67,7 +87,11 @@ impl AnyClientState {
         match self {
             Self::Tendermint(tm_state) => tm_state.latest_height(),
 
-            #[cfg(test)]
+            Self::Solomachine(sm_state) => sm_state.latest_height(),
+
+            Self::Wasm(wasm_state) => wasm_state.latest_height(),
+
+            #[cfg(any(test, feature = "mock"))]
             Self::Mock(mock_state) => mock_state.latest_height(),
         }
     }
@@ -76,7 +100,11 @@ impl AnyClientState {
         match self {
             Self::Tendermint(tm_state) => tm_state.frozen_height(),
 
-            #[cfg(test)]
+            Self::Solomachine(sm_state) => sm_state.frozen_height(),
+
+            Self::Wasm(wasm_state) => wasm_state.frozen_height(),
+
+            #[cfg(any(test, feature = "mock"))]
             Self::Mock(mock_state) => mock_state.frozen_height(),
         }
     }
@@ -85,8 +113,14 @@ impl AnyClientState {
         match self {
             AnyClientState::Tendermint(state) => Some(state.trust_threshold),
 
-            #[cfg(test)]
-            AnyClientState::Mock(_) => None,
+            AnyClientState::Wasm(state) => Some(state.inner.trust_threshold),
+
+            AnyClientState::Solomachine(_) => None,
+
+            // Mock clients accept any header, but report the default threshold
+            // so that the supervisor does not filter them out
+            #[cfg(any(test, feature = "mock"))]
+            AnyClientState::Mock(_) => Some(TrustThreshold::ONE_THIRD),
         }
     }
 
@@ -94,7 +128,12 @@ impl AnyClientState {
         match self {
             AnyClientState::Tendermint(state) => state.trusting_period,
 
-            #[cfg(test)]
+            AnyClientState::Wasm(state) => state.inner.trusting_period,
+
+            // Solo machine clients never expire
+            AnyClientState::Solomachine(_) => Duration::MAX,
+
+            #[cfg(any(test, feature = "mock"))]
             AnyClientState::Mock(_) => Duration::from_secs(14 * 24 * 60 * 60), // 2 weeks
         }
     }
@@ -103,7 +142,11 @@ impl AnyClientState {
         match self {
             AnyClientState::Tendermint(state) => state.max_clock_drift,
 
-            #[cfg(test)]
+            AnyClientState::Wasm(state) => state.inner.max_clock_drift,
+
+            AnyClientState::Solomachine(_) => Duration::new(0, 0),
+
+            #[cfg(any(test, feature = "mock"))]
             AnyClientState::Mock(_) => Duration::new(0, 0),
         }
     }
@@ -112,10 +155,34 @@ impl AnyClientState {
         match self {
             Self::Tendermint(state) => state.client_type(),
 
-            #[cfg(test)]
+            Self::Solomachine(state) => state.client_type(),
+
+            Self::Wasm(state) => state.client_type(),
+
+            #[cfg(any(test, feature = "mock"))]
             Self::Mock(state) => state.client_type(),
         }
     }
+
+    /// Wraps a header of the chain targeted by this client into the message
+    /// this client expects, ie. into a Wasm client message for Wasm clients.
+    pub fn wrap_header(&self, header: AnyHeader) -> AnyHeader {
+        match (self, header) {
+            (Self::Wasm(_), AnyHeader::Tendermint(header)) => WasmHeader::from(header).into(),
+            (_, header) => header,
+        }
+    }
+
+    /// Wraps a misbehaviour of the chain targeted by this client into the message
+    /// this client expects, ie. into a Wasm client message for Wasm clients.
+    pub fn wrap_misbehaviour(&self, misbehaviour: AnyMisbehaviour) -> AnyMisbehaviour {
+        match (self, misbehaviour) {
+            (Self::Wasm(_), AnyMisbehaviour::Tendermint(misbehaviour)) => {
+                WasmMisbehaviour::from(misbehaviour).into()
+            }
+            (_, misbehaviour) => misbehaviour,
+        }
+    }
 }
 
 impl Protobuf<Any> for AnyClientState {}
@@ -132,7 +199,17 @@ impl TryFrom<Any> for AnyClientState {
                     .map_err(Error::decode_raw_client_state)?,
             )),
 
-            #[cfg(test)]
+            SOLOMACHINE_CLIENT_STATE_TYPE_URL => Ok(AnyClientState::Solomachine(
+                Protobuf::<RawSmClientState>::decode_vec(&raw.value)
+                    .map_err(Error::decode_raw_client_state)?,
+            )),
+
+            WASM_CLIENT_STATE_TYPE_URL => Ok(AnyClientState::Wasm(
+                Protobuf::<RawWasmClientState>::decode_vec(&raw.value)
+                    .map_err(Error::decode_raw_client_state)?,
+            )),
+
+            #[cfg(any(test, feature = "mock"))]
             MOCK_CLIENT_STATE_TYPE_URL => Ok(AnyClientState::Mock(
                 Protobuf::<RawMockClientState>::decode_vec(&raw.value)
                     .map_err(Error::decode_raw_client_state)?,
@@ -150,7 +227,15 @@ impl From<AnyClientState> for Any {
                 type_url: TENDERMINT_CLIENT_STATE_TYPE_URL.to_string(),
                 value: Protobuf::<RawTmClientState>::encode_vec(value),
             },
-            #[cfg(test)]
+            AnyClientState::Solomachine(value) => Any {
+                type_url: SOLOMACHINE_CLIENT_STATE_TYPE_URL.to_string(),
+                value: Protobuf::<RawSmClientState>::encode_vec(value),
+            },
+            AnyClientState::Wasm(value) => Any {
+                type_url: WASM_CLIENT_STATE_TYPE_URL.to_string(),
+                value: Protobuf::<RawWasmClientState>::encode_vec(value),
+            },
+            #[cfg(any(test, feature = "mock"))]
             AnyClientState::Mock(value) => Any {
                 type_url: MOCK_CLIENT_STATE_TYPE_URL.to_string(),
                 value: Protobuf::<RawMockClientState>::encode_vec(value),
@@ -166,7 +251,11 @@ impl ClientState for AnyClientState {
         match self {
             AnyClientState::Tendermint(tm_state) => tm_state.chain_id(),
 
-            #[cfg(test)]
+            AnyClientState::Solomachine(sm_state) => sm_state.chain_id(),
+
+            AnyClientState::Wasm(wasm_state) => wasm_state.chain_id(),
+
+            #[cfg(any(test, feature = "mock"))]
             AnyClientState::Mock(mock_state) => mock_state.chain_id(),
         }
     }
@@ -198,7 +287,17 @@ impl ClientState for AnyClientState {
                 //       not a problem in practice for now but good to have.
             }
 
-            #[cfg(test)]
+            AnyClientState::Solomachine(sm_state) => {
+                sm_state.upgrade(upgrade_height, (), chain_id);
+            }
+
+            AnyClientState::Wasm(wasm_state) => {
+                if let Some(upgrade_options) = upgrade_options.into_tm_upgrade_options() {
+                    wasm_state.upgrade(upgrade_height, upgrade_options, chain_id);
+                }
+            }
+
+            #[cfg(any(test, feature = "mock"))]
             AnyClientState::Mock(mock_state) => {
                 mock_state.upgrade(upgrade_height, (), chain_id);
             }
@@ -209,7 +308,11 @@ impl ClientState for AnyClientState {
         match self {
             AnyClientState::Tendermint(tm_state) => tm_state.expired(elapsed_since_latest),
 
-            #[cfg(test)]
+            AnyClientState::Solomachine(sm_state) => sm_state.expired(elapsed_since_latest),
+
+            AnyClientState::Wasm(wasm_state) => wasm_state.expired(elapsed_since_latest),
+
+            #[cfg(any(test, feature = "mock"))]
             AnyClientState::Mock(mock_state) => mock_state.expired(elapsed_since_latest),
         }
     }
@@ -221,7 +324,19 @@ impl From<TmClientState> for AnyClientState {
     }
 }
 
-#[cfg(test)]
+impl From<SmClientState> for AnyClientState {
+    fn from(cs: SmClientState) -> Self {
+        Self::Solomachine(cs)
+    }
+}
+
+impl From<WasmClientState> for AnyClientState {
+    fn from(cs: WasmClientState) -> Self {
+        Self::Wasm(cs)
+    }
+}
+
+#[cfg(any(test, feature = "mock"))]
 impl From<MockClientState> for AnyClientState {
     fn from(cs: MockClientState) -> Self {
         Self::Mock(cs)
@@ -275,10 +390,15 @@ impl From<IdentifiedAnyClientState> for IdentifiedClientState {
 mod tests {
     use ibc_proto::google::protobuf::Any;
     use ibc_relayer_types::clients::ics07_tendermint::client_state::test_util::get_dummy_tendermint_client_state;
-    use ibc_relayer_types::clients::ics07_tendermint::header::test_util::get_dummy_tendermint_header;
+    use ibc_relayer_types::clients::ics07_tendermint::header::test_util::{
+        get_dummy_ics07_header, get_dummy_tendermint_header,
+    };
+    use ibc_relayer_types::clients::ics07_tendermint::misbehaviour::Misbehaviour as TmMisbehaviour;
+    use ibc_relayer_types::clients::ics08_wasm::client_message::WASM_CLIENT_MESSAGE_TYPE_URL;
+    use ibc_relayer_types::clients::ics08_wasm::client_state::CHECKSUM_LENGTH;
     use test_log::test;
 
-    use super::AnyClientState;
+    use super::{AnyClientState, AnyMisbehaviour, WasmClientState};
 
     #[test]
     fn any_client_state_serialization() {
@@ -289,4 +409,40 @@ mod tests {
         let tm_client_state_back = AnyClientState::try_from(raw).unwrap();
         assert_eq!(tm_client_state, tm_client_state_back);
     }
+
+    #[test]
+    fn wrap_misbehaviour_for_wasm_client() {
+        let tm_client_state = get_dummy_tendermint_client_state(get_dummy_tendermint_header());
+        let wasm_client_state: AnyClientState =
+            WasmClientState::new(vec![7; CHECKSUM_LENGTH], tm_client_state.clone())
+                .unwrap()
+                .into();
+        let tm_client_state: AnyClientState = tm_client_state.into();
+
+        let misbehaviour = AnyMisbehaviour::Tendermint(TmMisbehaviour {
+            client_id: Default::default(),
+            header1: get_dummy_ics07_header(),
+            header2: get_dummy_ics07_header(),
+        });
+
+        // Tendermint clients expect the Tendermint misbehaviour as is
+        assert_eq!(
+            tm_client_state.wrap_misbehaviour(misbehaviour.clone()),
+            misbehaviour
+        );
+
+        // Wasm clients expect it wrapped into a Wasm client message
+        let wrapped = wasm_client_state.wrap_misbehaviour(misbehaviour.clone());
+        let AnyMisbehaviour::Wasm(wasm_misbehaviour) = &wrapped else {
+            panic!("expected a Wasm misbehaviour");
+        };
+        assert_eq!(
+            AnyMisbehaviour::Tendermint(wasm_misbehaviour.inner.clone()),
+            misbehaviour
+        );
+
+        let any = Any::from(wrapped.clone());
+        assert_eq!(any.type_url, WASM_CLIENT_MESSAGE_TYPE_URL);
+        assert_eq!(AnyMisbehaviour::try_from(any).unwrap(), wrapped);
+    }
 }
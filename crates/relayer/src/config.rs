@@ -4,9 +4,11 @@ pub mod compat_mode;
 pub mod dynamic_gas;
 pub mod error;
 pub mod filter;
+pub mod gas_model;
 pub mod gas_multiplier;
 pub mod proof_specs;
 pub mod refresh_rate;
+pub mod tx_pipeline;
 pub mod types;
 
 use alloc::collections::BTreeMap;
@@ -16,7 +18,13 @@ use core::str::FromStr;
 use core::time::Duration;
 use ibc_relayer_types::core::ics04_channel::packet::Sequence;
 use std::borrow::Cow;
-use std::{fs, fs::File, io::Write, ops::Range, path::Path};
+use std::{
+    fs,
+    fs::File,
+    io::Write,
+    ops::Range,
+    path::{Path, PathBuf},
+};
 
 use byte_unit::Byte;
 use serde::{Deserialize, Serialize};
@@ -29,11 +37,14 @@ use ibc_relayer_types::core::ics24_host::identifier::{ChainId, ChannelId, PortId
 use ibc_relayer_types::timestamp::ZERO_DURATION;
 
 use crate::chain::cosmos::config::CosmosSdkConfig;
+use crate::chain::mock::config::MockChainConfig;
+use crate::chain::solomachine;
+use crate::chain::solomachine::config::SoloMachineConfig;
 use crate::config::types::ics20_field_size_limit::Ics20FieldSizeLimit;
 use crate::config::types::TrustThreshold;
 use crate::error::Error as RelayerError;
 use crate::extension_options::ExtensionOptionDynamicFeeTx;
-use crate::keyring::{AnySigningKeyPair, KeyRing, Store};
+use crate::keyring::{AnySigningKeyPair, KeyRing};
 
 use crate::keyring;
 
@@ -170,6 +181,13 @@ pub mod default {
         50
     }
 
+    pub fn store_path() -> PathBuf {
+        dirs_next::home_dir()
+            .unwrap_or_default()
+            .join(".hermes")
+            .join("store")
+    }
+
     pub fn rpc_timeout() -> Duration {
         Duration::from_secs(10)
     }
@@ -190,6 +208,10 @@ pub mod default {
         Duration::from_secs(30)
     }
 
+    pub fn fee_grant_expiry_warning() -> Duration {
+        Duration::from_secs(7 * 24 * 60 * 60)
+    }
+
     pub fn trusted_node() -> bool {
         false
     }
@@ -259,6 +281,8 @@ pub struct Config {
     pub chains: Vec<ChainConfig>,
     #[serde(default)]
     pub tracing_server: TracingServerConfig,
+    #[serde(default)]
+    pub store: StoreConfig,
 }
 
 impl Config {
@@ -315,6 +339,7 @@ impl Config {
                         .validate()
                         .map_err(Into::<Diagnostic<Error>>::into)?;
                 }
+                ChainConfig::Mock(_) | ChainConfig::SoloMachine(_) => {}
             }
         }
 
@@ -325,7 +350,7 @@ impl Config {
     }
 }
 
-#[derive(Copy, Clone, Debug, Deserialize, Serialize)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
 #[serde(deny_unknown_fields)]
 pub struct ModeConfig {
     pub clients: Clients,
@@ -378,7 +403,7 @@ impl Default for ModeConfig {
     }
 }
 
-#[derive(Copy, Clone, Debug, Default, Deserialize, Serialize)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
 #[serde(deny_unknown_fields)]
 pub struct Clients {
     pub enabled: bool,
@@ -388,19 +413,19 @@ pub struct Clients {
     pub misbehaviour: bool,
 }
 
-#[derive(Copy, Clone, Debug, Default, Deserialize, Serialize)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
 #[serde(deny_unknown_fields)]
 pub struct Connections {
     pub enabled: bool,
 }
 
-#[derive(Copy, Clone, Debug, Default, Deserialize, Serialize)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
 #[serde(deny_unknown_fields)]
 pub struct Channels {
     pub enabled: bool,
 }
 
-#[derive(Copy, Clone, Debug, Deserialize, Serialize)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
 #[serde(deny_unknown_fields)]
 pub struct Packets {
     pub enabled: bool,
@@ -467,13 +492,13 @@ impl Display for LogLevel {
     }
 }
 
-#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[derive(Clone, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
 #[serde(default, deny_unknown_fields)]
 pub struct GlobalConfig {
     pub log_level: LogLevel,
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
 #[serde(deny_unknown_fields)]
 pub struct TelemetryConfig {
     pub enabled: bool,
@@ -481,9 +506,11 @@ pub struct TelemetryConfig {
     pub port: u16,
     #[serde(default = "HistogramBuckets::default")]
     pub buckets: HistogramBuckets,
+    #[serde(default)]
+    pub otlp: OtlpConfig,
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
 pub struct HistogramBuckets {
     #[serde(default = "default::latency_submitted")]
     pub latency_submitted: HistogramConfig,
@@ -500,7 +527,7 @@ impl Default for HistogramBuckets {
     }
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
 #[serde(try_from = "HistogramRangeUnchecked")]
 pub struct HistogramConfig {
     #[serde(flatten)]
@@ -545,11 +572,40 @@ impl Default for TelemetryConfig {
             host: "127.0.0.1".to_string(),
             port: 3001,
             buckets: HistogramBuckets::default(),
+            otlp: OtlpConfig::default(),
         }
     }
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize)]
+/// Configuration of the export of traces and metrics to an OpenTelemetry
+/// collector over OTLP (gRPC).
+///
+/// Only available if Hermes was built with the `otlp` feature.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct OtlpConfig {
+    pub enabled: bool,
+    pub endpoint: String,
+    pub service_name: String,
+    #[serde(with = "humantime_serde")]
+    pub export_interval: Duration,
+}
+
+/// Default values for the `telemetry.otlp` configuration section.
+///
+/// # IMPORTANT: Remember to update the Hermes guide & the default config.toml whenever these values change.
+impl Default for OtlpConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            endpoint: "http://127.0.0.1:4317".to_string(),
+            service_name: "hermes".to_string(),
+            export_interval: Duration::from_secs(30),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
 #[serde(deny_unknown_fields)]
 pub struct RestConfig {
     pub enabled: bool,
@@ -633,38 +689,80 @@ pub enum EventSourceMode {
 // below when adding a new chain type.
 #[derive(Clone, Debug, PartialEq, Serialize)]
 #[serde(tag = "type")]
+#[allow(clippy::large_enum_variant)]
 pub enum ChainConfig {
     CosmosSdk(CosmosSdkConfig),
+    Mock(MockChainConfig),
+    SoloMachine(SoloMachineConfig),
 }
 
 impl ChainConfig {
     pub fn id(&self) -> &ChainId {
         match self {
             Self::CosmosSdk(config) => &config.id,
+            Self::Mock(config) => &config.id,
+            Self::SoloMachine(config) => &config.id,
         }
     }
 
     pub fn packet_filter(&self) -> &PacketFilter {
         match self {
             Self::CosmosSdk(config) => &config.packet_filter,
+            Self::Mock(config) => &config.packet_filter,
+            Self::SoloMachine(config) => &config.packet_filter,
+        }
+    }
+
+    pub fn set_packet_filter(&mut self, packet_filter: PacketFilter) {
+        match self {
+            Self::CosmosSdk(config) => config.packet_filter = packet_filter,
+            Self::Mock(config) => config.packet_filter = packet_filter,
+            Self::SoloMachine(config) => config.packet_filter = packet_filter,
+        }
+    }
+
+    /// Replace the gas settings with the ones of the given configuration,
+    /// if both are configurations of the same type of chain.
+    pub fn set_gas_settings(&mut self, other: &ChainConfig) {
+        if let (Self::CosmosSdk(config), Self::CosmosSdk(other)) = (self, other) {
+            config.set_gas_settings(other);
         }
     }
 
     pub fn max_block_time(&self) -> Duration {
         match self {
             Self::CosmosSdk(config) => config.max_block_time,
+            Self::Mock(config) => config.block_time,
+            Self::SoloMachine(_) => solomachine::config::BLOCK_TIME,
         }
     }
 
     pub fn key_name(&self) -> &String {
         match self {
             Self::CosmosSdk(config) => &config.key_name,
+            Self::Mock(config) => &config.key_name,
+            Self::SoloMachine(config) => &config.key_name,
         }
     }
 
+    /// The names of all the keys used to sign transactions, starting with `key_name`.
+    pub fn key_names(&self) -> Vec<String> {
+        match self {
+            Self::CosmosSdk(config) => config.key_names(),
+            Self::Mock(config) => vec![config.key_name.clone()],
+            Self::SoloMachine(config) => vec![config.key_name.clone()],
+        }
+    }
+
+    /// Sets the key used to sign transactions, which is then the only one in use.
     pub fn set_key_name(&mut self, key_name: String) {
         match self {
-            Self::CosmosSdk(config) => config.key_name = key_name,
+            Self::CosmosSdk(config) => {
+                config.key_name = key_name;
+                config.extra_key_names.clear();
+            }
+            Self::Mock(config) => config.key_name = key_name,
+            Self::SoloMachine(config) => config.key_name = key_name,
         }
     }
 
@@ -672,10 +770,27 @@ impl ChainConfig {
         let keys = match self {
             ChainConfig::CosmosSdk(config) => {
                 let keyring = KeyRing::new_secp256k1(
-                    Store::Test,
+                    config.key_store_type,
+                    &config.account_prefix,
+                    &config.id,
+                    &config.key_store_folder,
+                    &config.key_store_passphrase,
+                    &config.remote_signer,
+                )?;
+                keyring
+                    .keys()?
+                    .into_iter()
+                    .map(|(key_name, keys)| (key_name, keys.into()))
+                    .collect()
+            }
+            ChainConfig::SoloMachine(config) => {
+                let keyring = KeyRing::new_secp256k1(
+                    config.key_store_type,
                     &config.account_prefix,
                     &config.id,
                     &config.key_store_folder,
+                    &config.key_store_passphrase,
+                    &config.remote_signer,
                 )?;
                 keyring
                     .keys()?
@@ -683,6 +798,8 @@ impl ChainConfig {
                     .map(|(key_name, keys)| (key_name, keys.into()))
                     .collect()
             }
+            // The keys of a mock chain only live in the memory of its runtime
+            ChainConfig::Mock(_) => Vec::new(),
         };
 
         Ok(keys)
@@ -691,18 +808,24 @@ impl ChainConfig {
     pub fn clear_interval(&self) -> Option<u64> {
         match self {
             Self::CosmosSdk(config) => config.clear_interval,
+            Self::Mock(config) => config.clear_interval,
+            Self::SoloMachine(config) => config.clear_interval,
         }
     }
 
     pub fn query_packets_chunk_size(&self) -> usize {
         match self {
             Self::CosmosSdk(config) => config.query_packets_chunk_size,
+            Self::Mock(config) => config.query_packets_chunk_size,
+            Self::SoloMachine(config) => config.query_packets_chunk_size,
         }
     }
 
     pub fn set_query_packets_chunk_size(&mut self, query_packets_chunk_size: usize) {
         match self {
             Self::CosmosSdk(config) => config.query_packets_chunk_size = query_packets_chunk_size,
+            Self::Mock(config) => config.query_packets_chunk_size = query_packets_chunk_size,
+            Self::SoloMachine(config) => config.query_packets_chunk_size = query_packets_chunk_size,
         }
     }
 
@@ -713,6 +836,7 @@ impl ChainConfig {
                 .get(channel_id)
                 .map(|seqs| Cow::Borrowed(seqs.as_slice()))
                 .unwrap_or_else(|| Cow::Owned(Vec::new())),
+            Self::Mock(_) | Self::SoloMachine(_) => Cow::Owned(Vec::new()),
         }
     }
 }
@@ -742,6 +866,14 @@ impl<'de> Deserialize<'de> for ChainConfig {
                 .map(Self::CosmosSdk)
                 .map_err(|e| serde::de::Error::custom(format!("invalid CosmosSdk config: {e}"))),
 
+            "Mock" => MockChainConfig::deserialize(value)
+                .map(Self::Mock)
+                .map_err(|e| serde::de::Error::custom(format!("invalid Mock config: {e}"))),
+
+            "SoloMachine" => SoloMachineConfig::deserialize(value)
+                .map(Self::SoloMachine)
+                .map_err(|e| serde::de::Error::custom(format!("invalid SoloMachine config: {e}"))),
+
             //
             // <-- Add new chain types here -->
             //
@@ -797,6 +929,30 @@ impl Default for TracingServerConfig {
     }
 }
 
+/// Configuration of the on-disk relayer state store.
+///
+/// Only available if Hermes was built with the `relay-store` feature.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct StoreConfig {
+    pub enabled: bool,
+    pub path: PathBuf,
+    pub max_replay_blocks: u64,
+}
+
+/// Default values for the `store` configuration section.
+///
+/// # IMPORTANT: Remember to update the Hermes guide & the default config.toml whenever these values change.
+impl Default for StoreConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            path: default::store_path(),
+            max_replay_blocks: 1000,
+        }
+    }
+}
+
 #[derive(Clone, Debug)]
 pub enum Diagnostic<E> {
     Warning(E),
@@ -900,6 +1056,7 @@ mod tests {
             super::ChainConfig::CosmosSdk(_) => {
                 // all good
             }
+            _ => panic!("expected a CosmosSdk chain config"),
         }
     }
 
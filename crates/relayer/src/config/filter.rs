@@ -4,21 +4,24 @@ use core::fmt;
 use core::str::FromStr;
 use itertools::Itertools;
 use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::hash::Hash;
 
-use ibc_relayer_types::applications::transfer::RawCoin;
+use ibc_proto::ibc::applications::transfer::v2::FungibleTokenPacketData as RawPacketData;
+use ibc_relayer_types::applications::transfer::{Amount, RawCoin};
 use ibc_relayer_types::bigint::U256;
 use ibc_relayer_types::core::ics24_host::identifier::{ChannelId, PortId};
 use ibc_relayer_types::events::IbcEventType;
 
 /// Represents all the filtering policies for packets.
-#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct PacketFilter {
     #[serde(flatten)]
     pub channel_policy: ChannelPolicy,
     #[serde(default)]
-    pub min_fees: HashMap<ChannelFilterMatch, FeePolicy>,
+    pub min_fees: HashMap<PortChannelFilterMatch, FeePolicy>,
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub ics20: HashMap<ChannelFilterMatch, Ics20Filter>,
 }
 
 impl Default for PacketFilter {
@@ -27,6 +30,7 @@ impl Default for PacketFilter {
         Self {
             channel_policy: ChannelPolicy::default(),
             min_fees: HashMap::new(),
+            ics20: HashMap::new(),
         }
     }
 }
@@ -34,14 +38,33 @@ impl Default for PacketFilter {
 impl PacketFilter {
     pub fn new(
         channel_policy: ChannelPolicy,
-        min_fees: HashMap<ChannelFilterMatch, FeePolicy>,
+        min_fees: HashMap<PortChannelFilterMatch, FeePolicy>,
     ) -> Self {
         Self {
             channel_policy,
             min_fees,
+            ics20: HashMap::new(),
         }
     }
 
+    /// The fee policy of the packets sent on the given channel, if any.
+    pub fn fee_policy(&self, port_id: &PortId, channel_id: &ChannelId) -> Option<&FeePolicy> {
+        self.min_fees
+            .iter()
+            .find(|(channel, _)| channel.matches(port_id, channel_id))
+            .map(|(_, policy)| policy)
+    }
+
+    /// The filter on the data of the ICS-20 packets sent on the given channel.
+    /// By default, allows all packets.
+    pub fn ics20_filter(&self, channel_id: &ChannelId) -> Ics20Filter {
+        self.ics20
+            .iter()
+            .find(|(channel, _)| channel.matches(channel_id))
+            .map(|(_, filter)| filter.clone())
+            .unwrap_or_default()
+    }
+
     pub fn allow(filters: Vec<(PortFilterMatch, ChannelFilterMatch)>) -> PacketFilter {
         PacketFilter::new(
             ChannelPolicy::Allow(ChannelFilters::new(filters)),
@@ -68,27 +91,146 @@ pub enum ChannelPolicy {
 }
 
 /// Represents the policy used to filter incentivized packets.
-/// Currently only filtering on `recv_fee` is authorized.
-#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+///
+/// The `recv`, `ack` and `timeout` rules apply respectively to the `recv_fee`
+/// of the packets to receive, the `ack_fee` of the packets to acknowledge and
+/// the `timeout_fee` of the packets to time out. The fees must satisfy at least
+/// one of the rules: an empty list of rules is never satisfied, while a missing
+/// list does not require any minimum fee.
+///
+/// The `prices` give the value of one unit of each denom in a common unit.
+/// When they are set, the rules without a denom compare the total value
+/// of the fees in all the priced denoms against the minimum amount.
+///
+/// When a `gas_margin` is set, the value of the fees must moreover cover the
+/// estimated fee of the transaction relaying the packet, times the margin.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
 pub struct FeePolicy {
-    recv: Vec<MinFee>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    recv: Option<Vec<MinFee>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    ack: Option<Vec<MinFee>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    timeout: Option<Vec<MinFee>>,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    prices: BTreeMap<String, f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    gas_margin: Option<f64>,
 }
 
 impl FeePolicy {
     pub fn new(recv: Vec<MinFee>) -> Self {
-        Self { recv }
+        Self {
+            recv: Some(recv),
+            ..Default::default()
+        }
+    }
+
+    pub fn with_ack(self, ack: Vec<MinFee>) -> Self {
+        Self {
+            ack: Some(ack),
+            ..self
+        }
+    }
+
+    pub fn with_timeout(self, timeout: Vec<MinFee>) -> Self {
+        Self {
+            timeout: Some(timeout),
+            ..self
+        }
+    }
+
+    pub fn with_prices(self, prices: BTreeMap<String, f64>) -> Self {
+        Self { prices, ..self }
     }
 
+    pub fn with_gas_margin(self, gas_margin: f64) -> Self {
+        Self {
+            gas_margin: Some(gas_margin),
+            ..self
+        }
+    }
+
+    /// Whether the fees must cover the estimated fee of the transaction relaying the packet.
+    pub fn has_gas_margin(&self) -> bool {
+        self.gas_margin.is_some()
+    }
+
+    /// Whether packets may be filtered out on their `recv_fee`, in which case
+    /// the packets which are not incentivized are not relayed.
+    pub fn filters_recvs(&self) -> bool {
+        self.recv.is_some() || self.has_gas_margin()
+    }
+
+    /// Whether the acknowledgements of incentivized packets may be filtered out,
+    /// in which case their `ack_fee` must be queried.
+    pub fn filters_acks(&self) -> bool {
+        self.ack.is_some() || self.has_gas_margin()
+    }
+
+    /// Whether the fees of a packet are enough to relay it with the message
+    /// corresponding to the given event, ie. `SendPacket` for a `MsgRecvPacket`,
+    /// `WriteAcknowledgement` for a `MsgAcknowledgement` and `Timeout` for a `MsgTimeout`.
     pub fn should_relay(&self, event_type: IbcEventType, fees: &[RawCoin]) -> bool {
-        match event_type {
-            IbcEventType::SendPacket => fees
-                .iter()
-                .any(|fee| self.recv.iter().any(|e| e.is_enough(fee))),
-            _ => true,
+        let rules = match event_type {
+            IbcEventType::SendPacket => &self.recv,
+            IbcEventType::WriteAck => &self.ack,
+            IbcEventType::Timeout => &self.timeout,
+            _ => return true,
+        };
+
+        rules.as_ref().map_or(true, |rules| {
+            rules.iter().any(|rule| self.satisfies(rule, fees))
+        })
+    }
+
+    /// Whether the value of the fees covers the estimated fee of the transaction
+    /// relaying the packet, times the gas margin.
+    ///
+    /// If the denom of the transaction fee has no price, only the fees
+    /// in that denom are accounted for.
+    pub fn covers_tx_fee(&self, fees: &[RawCoin], tx_fee: &RawCoin) -> bool {
+        let Some(gas_margin) = self.gas_margin else {
+            return true;
+        };
+
+        let priced = self.prices.contains_key(&tx_fee.denom);
+
+        let price = |denom: &str| match self.prices.get(denom) {
+            Some(price) if priced => *price,
+            _ if denom == tx_fee.denom => 1.0,
+            _ => 0.0,
+        };
+
+        let value: f64 = fees
+            .iter()
+            .map(|fee| amount_to_f64(fee.amount) * price(&fee.denom))
+            .sum();
+
+        value >= amount_to_f64(tx_fee.amount) * price(&tx_fee.denom) * gas_margin
+    }
+
+    fn satisfies(&self, rule: &MinFee, fees: &[RawCoin]) -> bool {
+        if rule.denom.is_some() || self.prices.is_empty() {
+            return fees.iter().any(|fee| rule.is_enough(fee));
         }
+
+        let value: f64 = fees
+            .iter()
+            .filter_map(|fee| {
+                let price = self.prices.get(&fee.denom)?;
+                Some(amount_to_f64(fee.amount) * price)
+            })
+            .sum();
+
+        value >= rule.amount as f64
     }
 }
 
+fn amount_to_f64(amount: Amount) -> f64 {
+    amount.to_string().parse().unwrap_or(f64::INFINITY)
+}
+
 /// Represents the minimum fee authorized when filtering.
 /// If no denom is specified, any denom is allowed.
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
@@ -110,6 +252,90 @@ impl MinFee {
     }
 }
 
+/// Represents the rules used to filter ICS-20 packets on their decoded data.
+///
+/// A packet is relayed if its denom, sender and receiver match one of the
+/// allowed patterns, when any is set, and none of the denied patterns, its
+/// memo matches none of the denied patterns, and its amount is at least the
+/// minimum amount configured for its denom, if any.
+///
+/// The denom of a packet is the full denom, including the trace of the
+/// channels it was sent over, eg. `transfer/channel-0/uatom`.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Ics20Filter {
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    allow_denoms: Vec<Wildcard>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    deny_denoms: Vec<Wildcard>,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    min_amounts: BTreeMap<String, u64>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    allow_senders: Vec<Wildcard>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    deny_senders: Vec<Wildcard>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    allow_receivers: Vec<Wildcard>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    deny_receivers: Vec<Wildcard>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    deny_memos: Vec<Wildcard>,
+}
+
+impl Ics20Filter {
+    /// Whether the filter allows all packets, in which case
+    /// their data does not need to be decoded.
+    pub fn is_empty(&self) -> bool {
+        self == &Self::default()
+    }
+
+    /// Check the data of an ICS-20 packet against the filter.
+    /// Returns the field of the packet data which is rejected, if any.
+    pub fn check(&self, packet_data: &RawPacketData) -> Result<(), &'static str> {
+        if !is_allowed(&self.allow_denoms, &self.deny_denoms, &packet_data.denom) {
+            return Err("denom");
+        }
+
+        if let Some(min_amount) = self.min_amounts.get(&packet_data.denom) {
+            let enough = Amount::from_str(&packet_data.amount)
+                .map_or(false, |amount| amount.0 >= U256::from(*min_amount));
+
+            if !enough {
+                return Err("amount");
+            }
+        }
+
+        if !is_allowed(&self.allow_senders, &self.deny_senders, &packet_data.sender) {
+            return Err("sender");
+        }
+
+        if !is_allowed(
+            &self.allow_receivers,
+            &self.deny_receivers,
+            &packet_data.receiver,
+        ) {
+            return Err("receiver");
+        }
+
+        if self
+            .deny_memos
+            .iter()
+            .any(|p| p.is_match(&packet_data.memo))
+        {
+            return Err("memo");
+        }
+
+        Ok(())
+    }
+}
+
+/// A value is allowed if it matches any of the allowed patterns, or if there
+/// are none, and it matches none of the denied patterns.
+fn is_allowed(allow: &[Wildcard], deny: &[Wildcard], value: &str) -> bool {
+    (allow.is_empty() || allow.iter().any(|p| p.is_match(value)))
+        && !deny.iter().any(|p| p.is_match(value))
+}
+
 impl Default for ChannelPolicy {
     /// By default, allows all channels & ports.
     fn default() -> Self {
@@ -274,6 +500,13 @@ impl Serialize for Wildcard {
     }
 }
 
+impl<'de> Deserialize<'de> for Wildcard {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Wildcard, D::Error> {
+        let pattern = String::deserialize(deserializer)?;
+        Wildcard::new(pattern).map_err(de::Error::custom)
+    }
+}
+
 impl PartialEq for Wildcard {
     fn eq(&self, other: &Self) -> bool {
         self.pattern == other.pattern
@@ -359,6 +592,72 @@ pub type PortFilterMatch = FilterPattern<PortId>;
 /// Type alias for a [`FilterPattern`] containing a [`ChannelId`].
 pub type ChannelFilterMatch = FilterPattern<ChannelId>;
 
+/// Matches channels either by their channel identifier only, eg. `'channel-0'`,
+/// whatever their port, or by their port and channel identifiers separated
+/// by a slash, eg. `'transfer/channel-0'`, both of which may be wildcards.
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+pub struct PortChannelFilterMatch {
+    pub port: Option<PortFilterMatch>,
+    pub channel: ChannelFilterMatch,
+}
+
+impl PortChannelFilterMatch {
+    /// Whether the given channel, bound to the given port, matches this filter.
+    pub fn matches(&self, port_id: &PortId, channel_id: &ChannelId) -> bool {
+        self.port
+            .as_ref()
+            .map_or(true, |port| port.matches(port_id))
+            && self.channel.matches(channel_id)
+    }
+}
+
+impl From<ChannelFilterMatch> for PortChannelFilterMatch {
+    fn from(channel: ChannelFilterMatch) -> Self {
+        Self {
+            port: None,
+            channel,
+        }
+    }
+}
+
+impl fmt::Display for PortChannelFilterMatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.port {
+            Some(port) => write!(f, "{port}/{}", self.channel),
+            None => write!(f, "{}", self.channel),
+        }
+    }
+}
+
+impl Serialize for PortChannelFilterMatch {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for PortChannelFilterMatch {
+    fn deserialize<D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<PortChannelFilterMatch, D::Error> {
+        let value = String::deserialize(deserializer)?;
+
+        let (port, channel) = match value.split_once('/') {
+            Some((port, channel)) => (Some(port), channel),
+            None => (None, value.as_str()),
+        };
+
+        Ok(PortChannelFilterMatch {
+            port: port
+                .map(|port| de::Visitor::visit_str(port::PortFilterMatchVisitor, port))
+                .transpose()?,
+            channel: de::Visitor::visit_str(channel::ChannelFilterMatchVisitor, channel)?,
+        })
+    }
+}
+
 impl<'de> Deserialize<'de> for PortFilterMatch {
     fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<PortFilterMatch, D::Error> {
         deserializer.deserialize_string(port::PortFilterMatchVisitor)
@@ -605,4 +904,241 @@ mod tests {
         let wildcard = "ica*".parse::<Wildcard>().unwrap();
         assert_eq!(wildcard.to_string(), "ica*".to_string());
     }
+
+    fn coin(amount: u64, denom: &str) -> RawCoin {
+        RawCoin::new(denom.to_string(), amount)
+    }
+
+    #[test]
+    fn deserialize_fee_policy() {
+        let toml_content = r#"
+            recv = [ { amount = 20, denom = 'stake' } ]
+            ack = [ { amount = 10 } ]
+            prices = { stake = 1.0, samoleans = 0.5 }
+            gas_margin = 1.5
+            "#;
+
+        let fee_policy: FeePolicy =
+            toml::from_str(toml_content).expect("could not parse fee policy");
+
+        assert_eq!(
+            fee_policy,
+            FeePolicy::new(vec![MinFee::new(20, Some("stake".to_string()))])
+                .with_ack(vec![MinFee::new(10, None)])
+                .with_prices(BTreeMap::from([
+                    ("stake".to_string(), 1.0),
+                    ("samoleans".to_string(), 0.5)
+                ]))
+                .with_gas_margin(1.5)
+        );
+    }
+
+    #[test]
+    fn fee_policy_per_port_and_channel() {
+        let toml_content = r#"
+            policy = 'allow'
+            list = [ ['*', '*'] ]
+
+            [min_fees.'transfer/channel-0']
+            recv = [ { amount = 20 } ]
+
+            [min_fees.'channel-1']
+            recv = [ { amount = 10 } ]
+            "#;
+
+        let filter: PacketFilter =
+            toml::from_str(toml_content).expect("could not parse packet filter");
+
+        let transfer = PortId::transfer();
+        let other = PortId::from_str("other").unwrap();
+
+        let policy = |port_id, channel_id| filter.fee_policy(port_id, &ChannelId::new(channel_id));
+
+        assert_eq!(
+            policy(&transfer, 0),
+            Some(&FeePolicy::new(vec![MinFee::new(20, None)]))
+        );
+        assert_eq!(policy(&other, 0), None);
+        assert_eq!(
+            policy(&transfer, 1),
+            Some(&FeePolicy::new(vec![MinFee::new(10, None)]))
+        );
+        assert_eq!(
+            policy(&other, 1),
+            Some(&FeePolicy::new(vec![MinFee::new(10, None)]))
+        );
+        assert_eq!(policy(&transfer, 2), None);
+
+        let serialized = toml::to_string(&filter).expect("could not serialize packet filter");
+        let deserialized: PacketFilter =
+            toml::from_str(&serialized).expect("could not parse serialized packet filter");
+        assert_eq!(filter, deserialized);
+    }
+
+    #[test]
+    fn fee_policy_per_event_type() {
+        let fee_policy = FeePolicy::new(vec![MinFee::new(20, Some("stake".to_string()))])
+            .with_timeout(vec![MinFee::new(5, Some("stake".to_string()))]);
+
+        assert!(fee_policy.should_relay(IbcEventType::SendPacket, &[coin(20, "stake")]));
+        assert!(!fee_policy.should_relay(IbcEventType::SendPacket, &[coin(10, "stake")]));
+        assert!(!fee_policy.should_relay(IbcEventType::SendPacket, &[coin(20, "samoleans")]));
+
+        assert!(fee_policy.should_relay(IbcEventType::Timeout, &[coin(5, "stake")]));
+        assert!(!fee_policy.should_relay(IbcEventType::Timeout, &[coin(4, "stake")]));
+
+        // Without any rule, no minimum fee is required
+        assert!(fee_policy.should_relay(IbcEventType::WriteAck, &[]));
+
+        // An empty list of rules is never satisfied
+        let fee_policy = FeePolicy::new(vec![]);
+        assert!(!fee_policy.should_relay(IbcEventType::SendPacket, &[coin(20, "stake")]));
+    }
+
+    #[test]
+    fn fee_policy_with_prices() {
+        let fee_policy = FeePolicy::new(vec![MinFee::new(20, None)]).with_prices(BTreeMap::from([
+            ("stake".to_string(), 1.0),
+            ("samoleans".to_string(), 0.5),
+        ]));
+
+        // 10 stake + 20 samoleans are worth 20 stake
+        assert!(fee_policy.should_relay(
+            IbcEventType::SendPacket,
+            &[coin(10, "stake"), coin(20, "samoleans")]
+        ));
+        assert!(!fee_policy.should_relay(
+            IbcEventType::SendPacket,
+            &[coin(10, "stake"), coin(19, "samoleans")]
+        ));
+
+        // Fees in a denom without a price are not accounted for
+        assert!(!fee_policy.should_relay(
+            IbcEventType::SendPacket,
+            &[coin(10, "stake"), coin(100, "apples")]
+        ));
+    }
+
+    #[test]
+    fn fee_policy_with_gas_margin() {
+        let tx_fee = coin(100, "uatom");
+
+        // Without a gas margin, the fee of the transaction does not matter
+        assert!(FeePolicy::default().covers_tx_fee(&[], &tx_fee));
+
+        let fee_policy = FeePolicy::default().with_gas_margin(1.5);
+
+        assert!(fee_policy.covers_tx_fee(&[coin(150, "uatom")], &tx_fee));
+        assert!(!fee_policy.covers_tx_fee(&[coin(149, "uatom")], &tx_fee));
+        assert!(!fee_policy.covers_tx_fee(&[coin(1000, "stake")], &tx_fee));
+
+        let fee_policy = fee_policy.with_prices(BTreeMap::from([
+            ("uatom".to_string(), 2.0),
+            ("stake".to_string(), 1.0),
+        ]));
+
+        // 100 uatom are worth 200 stake
+        assert!(fee_policy.covers_tx_fee(&[coin(300, "stake")], &tx_fee));
+        assert!(!fee_policy.covers_tx_fee(&[coin(299, "stake")], &tx_fee));
+
+        // Without a price for the denom of the transaction fee, only the fees in that denom count
+        let fee_policy = FeePolicy::default()
+            .with_gas_margin(1.5)
+            .with_prices(BTreeMap::from([("stake".to_string(), 1.0)]));
+
+        assert!(fee_policy.covers_tx_fee(&[coin(150, "uatom")], &tx_fee));
+        assert!(!fee_policy.covers_tx_fee(&[coin(100, "uatom"), coin(1000, "stake")], &tx_fee));
+    }
+
+    fn packet_data(denom: &str, amount: &str, sender: &str, memo: &str) -> RawPacketData {
+        RawPacketData {
+            denom: denom.to_string(),
+            amount: amount.to_string(),
+            sender: sender.to_string(),
+            receiver: "cosmos1receiver".to_string(),
+            memo: memo.to_string(),
+        }
+    }
+
+    #[test]
+    fn ics20_filter_allows_all_by_default() {
+        let filter = Ics20Filter::default();
+
+        assert!(filter.is_empty());
+        assert_eq!(
+            filter.check(&packet_data("uatom", "1", "cosmos1sender", "")),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn ics20_filter() {
+        let toml_content = r#"
+            allow_denoms = [ 'uatom', 'transfer/*/stake' ]
+            deny_denoms = [ 'transfer/channel-1/*' ]
+            min_amounts = { uatom = 1000 }
+            deny_senders = [ 'cosmos1spammer*' ]
+            deny_memos = [ '*airdrop*' ]
+            "#;
+
+        let filter: Ics20Filter = toml::from_str(toml_content).expect("could not parse filter");
+
+        assert!(!filter.is_empty());
+
+        let check =
+            |denom, amount, sender, memo| filter.check(&packet_data(denom, amount, sender, memo));
+
+        assert_eq!(check("uatom", "1000", "cosmos1sender", "hello"), Ok(()));
+        assert_eq!(
+            check("transfer/channel-0/stake", "1", "cosmos1sender", ""),
+            Ok(())
+        );
+
+        assert_eq!(
+            check("samoleans", "1000", "cosmos1sender", ""),
+            Err("denom")
+        );
+        assert_eq!(
+            check("transfer/channel-1/stake", "1", "cosmos1sender", ""),
+            Err("denom")
+        );
+        assert_eq!(check("uatom", "999", "cosmos1sender", ""), Err("amount"));
+        assert_eq!(check("uatom", "lots", "cosmos1sender", ""), Err("amount"));
+        assert_eq!(
+            check("uatom", "1000", "cosmos1spammer42", ""),
+            Err("sender")
+        );
+        assert_eq!(
+            check("uatom", "1000", "cosmos1sender", "free airdrop!"),
+            Err("memo")
+        );
+    }
+
+    #[test]
+    fn ics20_filter_per_channel() {
+        let toml_content = r#"
+            policy = 'allowall'
+
+            [ics20.'channel-0']
+            allow_receivers = [ 'cosmos1receiver' ]
+
+            [ics20.'channel-1']
+            deny_receivers = [ 'cosmos1receiver' ]
+            "#;
+
+        let packet_filter: PacketFilter =
+            toml::from_str(toml_content).expect("could not parse packet filter");
+
+        let data = packet_data("uatom", "1", "cosmos1sender", "");
+
+        let check = |channel_id| {
+            packet_filter
+                .ics20_filter(&ChannelId::new(channel_id))
+                .check(&data)
+        };
+
+        assert_eq!(check(0), Ok(()));
+        assert_eq!(check(1), Err("receiver"));
+        assert_eq!(check(2), Ok(()));
+    }
 }
@@ -0,0 +1,77 @@
+use serde_derive::{Deserialize, Serialize};
+
+/// Settings of the estimation of the gas of transactions from a model of the gas
+/// used by each type of message, learned from past simulations and transactions,
+/// instead of simulating every transaction.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct GasModel {
+    pub enabled: bool,
+    /// How many transactions including a type of message must have been observed
+    /// before the gas of the transactions including it is estimated from the model.
+    pub min_samples: u64,
+    /// The relative difference between the gas estimated from the model and the gas
+    /// actually used by a transaction above which the transactions including the same
+    /// types of messages are simulated again.
+    pub max_drift: f64,
+}
+
+impl GasModel {
+    const DEFAULT_MIN_SAMPLES: u64 = 10;
+    const DEFAULT_MAX_DRIFT: f64 = 0.1;
+
+    pub fn disabled() -> Self {
+        Self {
+            enabled: false,
+            min_samples: Self::DEFAULT_MIN_SAMPLES,
+            max_drift: Self::DEFAULT_MAX_DRIFT,
+        }
+    }
+}
+
+impl Default for GasModel {
+    fn default() -> Self {
+        Self::disabled()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use serde::Deserialize;
+    use test_log::test;
+
+    #[derive(Debug, Deserialize)]
+    struct DummyConfig {
+        #[serde(default)]
+        gas_model: GasModel,
+    }
+
+    #[test]
+    fn parse_gas_model() {
+        let config = toml::from_str::<DummyConfig>(
+            "gas_model = { enabled = true, min_samples = 5, max_drift = 0.2 }",
+        )
+        .unwrap();
+
+        assert_eq!(
+            config.gas_model,
+            GasModel {
+                enabled: true,
+                min_samples: 5,
+                max_drift: 0.2,
+            }
+        );
+    }
+
+    #[test]
+    fn default_gas_model() {
+        let config = toml::from_str::<DummyConfig>("").unwrap();
+        assert_eq!(config.gas_model, GasModel::disabled());
+
+        let config = toml::from_str::<DummyConfig>("gas_model = { enabled = true }").unwrap();
+        assert_eq!(config.gas_model.min_samples, 10);
+        assert_eq!(config.gas_model.max_drift, 0.1);
+    }
+}
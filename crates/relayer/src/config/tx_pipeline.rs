@@ -0,0 +1,68 @@
+use serde_derive::{Deserialize, Serialize};
+
+/// Settings of the pipelined submission of transactions, where the account
+/// sequence is tracked locally so that several transactions can be broadcast
+/// within the same block, instead of waiting for each one to be committed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct TxPipeline {
+    pub enabled: bool,
+    /// How many transactions broadcast by the same wallet can be pending
+    /// at once, ie. neither committed nor dropped from the mempool.
+    pub max_in_flight: usize,
+}
+
+impl TxPipeline {
+    const DEFAULT_MAX_IN_FLIGHT: usize = 10;
+
+    pub fn disabled() -> Self {
+        Self {
+            enabled: false,
+            max_in_flight: Self::DEFAULT_MAX_IN_FLIGHT,
+        }
+    }
+}
+
+impl Default for TxPipeline {
+    fn default() -> Self {
+        Self::disabled()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use serde::Deserialize;
+    use test_log::test;
+
+    #[derive(Debug, Deserialize)]
+    struct DummyConfig {
+        #[serde(default)]
+        tx_pipeline: TxPipeline,
+    }
+
+    #[test]
+    fn parse_tx_pipeline() {
+        let config =
+            toml::from_str::<DummyConfig>("tx_pipeline = { enabled = true, max_in_flight = 4 }")
+                .unwrap();
+
+        assert_eq!(
+            config.tx_pipeline,
+            TxPipeline {
+                enabled: true,
+                max_in_flight: 4
+            }
+        );
+    }
+
+    #[test]
+    fn default_tx_pipeline() {
+        let config = toml::from_str::<DummyConfig>("").unwrap();
+        assert_eq!(config.tx_pipeline, TxPipeline::disabled());
+
+        let config = toml::from_str::<DummyConfig>("tx_pipeline = { enabled = true }").unwrap();
+        assert_eq!(config.tx_pipeline.max_in_flight, 10);
+    }
+}
@@ -2,11 +2,19 @@ use serde::{Deserialize, Serialize};
 
 use ibc_proto::google::protobuf::Any;
 use ibc_proto::ibc::core::client::v1::ConsensusStateWithHeight;
+use ibc_proto::ibc::lightclients::solomachine::v3::ConsensusState as RawSmConsensusState;
 use ibc_proto::ibc::lightclients::tendermint::v1::ConsensusState as RawConsensusState;
 use ibc_proto::Protobuf;
+use ibc_relayer_types::clients::ics06_solomachine::consensus_state::{
+    ConsensusState as SmConsensusState, SOLOMACHINE_CONSENSUS_STATE_TYPE_URL,
+};
 use ibc_relayer_types::clients::ics07_tendermint::consensus_state::{
     ConsensusState as TmConsensusState, TENDERMINT_CONSENSUS_STATE_TYPE_URL,
 };
+use ibc_relayer_types::clients::ics08_wasm::consensus_state::{
+    ConsensusState as WasmConsensusState, WASM_CONSENSUS_STATE_TYPE_URL,
+};
+use ibc_relayer_types::clients::ics08_wasm::raw::ConsensusState as RawWasmConsensusState;
 use ibc_relayer_types::core::ics02_client::client_type::ClientType;
 use ibc_relayer_types::core::ics02_client::consensus_state::ConsensusState;
 use ibc_relayer_types::core::ics02_client::error::Error;
@@ -14,11 +22,11 @@ use ibc_relayer_types::core::ics23_commitment::commitment::CommitmentRoot;
 use ibc_relayer_types::timestamp::Timestamp;
 use ibc_relayer_types::Height;
 
-#[cfg(test)]
+#[cfg(any(test, feature = "mock"))]
 use ibc_proto::ibc::mock::ConsensusState as RawMockConsensusState;
-#[cfg(test)]
+#[cfg(any(test, feature = "mock"))]
 use ibc_relayer_types::mock::consensus_state::MockConsensusState;
-#[cfg(test)]
+#[cfg(any(test, feature = "mock"))]
 use ibc_relayer_types::mock::consensus_state::MOCK_CONSENSUS_STATE_TYPE_URL;
 
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
@@ -27,7 +35,11 @@ use ibc_relayer_types::mock::consensus_state::MOCK_CONSENSUS_STATE_TYPE_URL;
 pub enum AnyConsensusState {
     Tendermint(TmConsensusState),
 
-    #[cfg(test)]
+    Solomachine(SmConsensusState),
+
+    Wasm(WasmConsensusState),
+
+    #[cfg(any(test, feature = "mock"))]
     Mock(MockConsensusState),
 }
 
@@ -36,7 +48,11 @@ impl AnyConsensusState {
         match self {
             Self::Tendermint(cs_state) => cs_state.timestamp.into(),
 
-            #[cfg(test)]
+            Self::Solomachine(sm_state) => sm_state.timestamp,
+
+            Self::Wasm(wasm_state) => wasm_state.timestamp(),
+
+            #[cfg(any(test, feature = "mock"))]
             Self::Mock(mock_state) => mock_state.timestamp(),
         }
     }
@@ -45,7 +61,11 @@ impl AnyConsensusState {
         match self {
             AnyConsensusState::Tendermint(_cs) => ClientType::Tendermint,
 
-            #[cfg(test)]
+            AnyConsensusState::Solomachine(_cs) => ClientType::Solomachine,
+
+            AnyConsensusState::Wasm(_cs) => ClientType::Wasm,
+
+            #[cfg(any(test, feature = "mock"))]
             AnyConsensusState::Mock(_cs) => ClientType::Mock,
         }
     }
@@ -65,7 +85,17 @@ impl TryFrom<Any> for AnyConsensusState {
                     .map_err(Error::decode_raw_client_state)?,
             )),
 
-            #[cfg(test)]
+            SOLOMACHINE_CONSENSUS_STATE_TYPE_URL => Ok(AnyConsensusState::Solomachine(
+                Protobuf::<RawSmConsensusState>::decode_vec(&value.value)
+                    .map_err(Error::decode_raw_client_state)?,
+            )),
+
+            WASM_CONSENSUS_STATE_TYPE_URL => Ok(AnyConsensusState::Wasm(
+                Protobuf::<RawWasmConsensusState>::decode_vec(&value.value)
+                    .map_err(Error::decode_raw_client_state)?,
+            )),
+
+            #[cfg(any(test, feature = "mock"))]
             MOCK_CONSENSUS_STATE_TYPE_URL => Ok(AnyConsensusState::Mock(
                 Protobuf::<RawMockConsensusState>::decode_vec(&value.value)
                     .map_err(Error::decode_raw_client_state)?,
@@ -83,7 +113,15 @@ impl From<AnyConsensusState> for Any {
                 type_url: TENDERMINT_CONSENSUS_STATE_TYPE_URL.to_string(),
                 value: Protobuf::<RawConsensusState>::encode_vec(value),
             },
-            #[cfg(test)]
+            AnyConsensusState::Solomachine(value) => Any {
+                type_url: SOLOMACHINE_CONSENSUS_STATE_TYPE_URL.to_string(),
+                value: Protobuf::<RawSmConsensusState>::encode_vec(value),
+            },
+            AnyConsensusState::Wasm(value) => Any {
+                type_url: WASM_CONSENSUS_STATE_TYPE_URL.to_string(),
+                value: Protobuf::<RawWasmConsensusState>::encode_vec(value),
+            },
+            #[cfg(any(test, feature = "mock"))]
             AnyConsensusState::Mock(value) => Any {
                 type_url: MOCK_CONSENSUS_STATE_TYPE_URL.to_string(),
                 value: Protobuf::<RawMockConsensusState>::encode_vec(value),
@@ -92,13 +130,25 @@ impl From<AnyConsensusState> for Any {
     }
 }
 
-#[cfg(test)]
+#[cfg(any(test, feature = "mock"))]
 impl From<MockConsensusState> for AnyConsensusState {
     fn from(cs: MockConsensusState) -> Self {
         Self::Mock(cs)
     }
 }
 
+impl From<SmConsensusState> for AnyConsensusState {
+    fn from(cs: SmConsensusState) -> Self {
+        Self::Solomachine(cs)
+    }
+}
+
+impl From<WasmConsensusState> for AnyConsensusState {
+    fn from(cs: WasmConsensusState) -> Self {
+        Self::Wasm(cs)
+    }
+}
+
 impl From<TmConsensusState> for AnyConsensusState {
     fn from(cs: TmConsensusState) -> Self {
         Self::Tendermint(cs)
@@ -151,7 +201,11 @@ impl ConsensusState for AnyConsensusState {
         match self {
             Self::Tendermint(cs_state) => cs_state.root(),
 
-            #[cfg(test)]
+            Self::Solomachine(sm_state) => sm_state.root(),
+
+            Self::Wasm(wasm_state) => wasm_state.root(),
+
+            #[cfg(any(test, feature = "mock"))]
             Self::Mock(mock_state) => mock_state.root(),
         }
     }
@@ -26,6 +26,7 @@ use tendermint_rpc::Error as TendermintRpcError;
 
 use ibc_relayer_types::applications::ics29_fee::error::Error as FeeError;
 use ibc_relayer_types::applications::ics31_icq::error::Error as CrossChainQueryError;
+use ibc_relayer_types::clients::ics06_solomachine::error as solomachine_error;
 use ibc_relayer_types::clients::ics07_tendermint::error as tendermint_error;
 use ibc_relayer_types::core::ics02_client::{client_type::ClientType, error as client_error};
 use ibc_relayer_types::core::ics03_connection::error as connection_error;
@@ -264,6 +265,10 @@ define_error! {
             [ KeyringError ]
             |e| { format!("signature key not found: {}", e.key_name) },
 
+        WalletNotFound
+            { account: String }
+            |e| { format!("no wallet of the chain has the account of the signer: {}", e.account) },
+
         Ics02
             [ client_error::Error ]
             |e| { format!("ICS 02 error: {}", e.source) },
@@ -272,6 +277,10 @@ define_error! {
             [ connection_error::Error ]
             |_| { "ICS 03 error" },
 
+        Ics06
+            [ solomachine_error::Error ]
+            |_| { "ICS 06 error" },
+
         Ics07
             [ tendermint_error::Error ]
             |_| { "ICS 07 error" },
@@ -513,6 +522,18 @@ define_error! {
                 format!("failed to deserialize account of an unknown protobuf type: {0}", e.type_url)
             },
 
+        UnknownFeeAllowanceType
+            {
+                type_url: String
+            }
+            |e| {
+                format!("failed to deserialize fee allowance of an unknown protobuf type: {0}", e.type_url)
+            },
+
+        InvalidGrantExpiration
+            [ TendermintError ]
+            |_| { "invalid grant expiration time" },
+
         EmptyBaseAccount
             |_| { "empty BaseAccount within EthAccount" },
 
@@ -618,6 +639,14 @@ define_error! {
         Base64Decode
             [ TraceError<subtle_encoding::Error> ]
             |_| { "Error decoding base64-encoded data" },
+
+        MockChain
+            { reason: String }
+            |e| { format!("mock chain error: {}", e.reason) },
+
+        SoloMachine
+            { reason: String }
+            |e| { format!("solo machine error: {}", e.reason) },
     }
 }
 
@@ -635,6 +664,32 @@ impl Error {
             _ => false,
         }
     }
+
+    /// Whether this error was caused by the full node the request was sent to,
+    /// eg. because it is unreachable, overloaded or timed out,
+    /// rather than by the request itself.
+    pub fn is_endpoint_error(&self) -> bool {
+        use tendermint_rpc::error::ErrorDetail as RpcErrorDetail;
+
+        match self.detail() {
+            ErrorDetail::Rpc(e) => matches!(
+                e.source,
+                RpcErrorDetail::Io(_)
+                    | RpcErrorDetail::Http(_)
+                    | RpcErrorDetail::HttpRequestFailed(_)
+                    | RpcErrorDetail::Server(_)
+                    | RpcErrorDetail::Timeout(_)
+                    | RpcErrorDetail::WebSocket(_)
+                    | RpcErrorDetail::WebSocketTimeout(_)
+            ),
+            ErrorDetail::GrpcTransport(_) => true,
+            ErrorDetail::GrpcStatus(e) => matches!(
+                e.status.code(),
+                tonic::Code::Unavailable | tonic::Code::DeadlineExceeded
+            ),
+            _ => false,
+        }
+    }
 }
 
 impl GrpcStatusSubdetail {
@@ -723,7 +778,7 @@ impl GrpcStatusSubdetail {
 /// during simulating or broadcasting a transaction, includes the following pattern:
 /// "account sequence mismatch, expected E, got G".
 /// If a match is found it extracts and returns (E, G).
-fn parse_sequences_in_mismatch_error_message(message: &str) -> Option<(u64, u64)> {
+pub(crate) fn parse_sequences_in_mismatch_error_message(message: &str) -> Option<(u64, u64)> {
     let re = Regex::new(r"account sequence mismatch, expected (?P<expected>\d+), got (?P<got>\d+)")
         .unwrap();
 
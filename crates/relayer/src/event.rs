@@ -2,6 +2,7 @@ use core::fmt::{Display, Error as FmtError, Formatter};
 use serde::Serialize;
 use subtle_encoding::hex;
 use tendermint::abci::Event as AbciEvent;
+use tendermint::Hash as TxHash;
 
 use ibc_relayer_types::{
     applications::ics29_fee::events::{DistributeFeePacket, IncentivizedPacket},
@@ -18,7 +19,7 @@ use ibc_relayer_types::{
     },
     core::ics04_channel::{
         error::Error as ChannelError,
-        events::{self as channel_events, Attributes as ChannelAttributes},
+        events::{self as channel_events, Attributes as ChannelAttributes, UpgradeAttributes},
         packet::Packet,
         timeout::TimeoutHeight,
     },
@@ -28,23 +29,35 @@ use ibc_relayer_types::{
 
 pub mod bus;
 pub mod error;
+pub mod relay;
 pub mod source;
 
 #[derive(Clone, Debug, Serialize)]
 pub struct IbcEventWithHeight {
     pub event: IbcEvent,
     pub height: Height,
+    /// The hash of the transaction which emitted the event, when known.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tx_hash: Option<TxHash>,
 }
 
 impl IbcEventWithHeight {
     pub fn new(event: IbcEvent, height: Height) -> Self {
-        Self { event, height }
+        Self {
+            event,
+            height,
+            tx_hash: None,
+        }
     }
 
     pub fn with_height(self, height: Height) -> Self {
+        Self { height, ..self }
+    }
+
+    pub fn with_tx_hash(self, tx_hash: TxHash) -> Self {
         Self {
-            event: self.event,
-            height,
+            tx_hash: Some(tx_hash),
+            ..self
         }
     }
 }
@@ -109,6 +122,22 @@ pub fn ibc_event_try_from_abci_event(abci_event: &AbciEvent) -> Result<IbcEvent,
             channel_close_confirm_try_from_abci_event(abci_event)
                 .map_err(IbcEventError::channel)?,
         )),
+        Ok(IbcEventType::UpgradeInitChannel) => Ok(IbcEvent::UpgradeInitChannel(
+            channel_upgrade_init_try_from_abci_event(abci_event).map_err(IbcEventError::channel)?,
+        )),
+        Ok(IbcEventType::UpgradeTryChannel) => Ok(IbcEvent::UpgradeTryChannel(
+            channel_upgrade_try_try_from_abci_event(abci_event).map_err(IbcEventError::channel)?,
+        )),
+        Ok(IbcEventType::UpgradeAckChannel) => Ok(IbcEvent::UpgradeAckChannel(
+            channel_upgrade_ack_try_from_abci_event(abci_event).map_err(IbcEventError::channel)?,
+        )),
+        Ok(IbcEventType::UpgradeConfirmChannel) => Ok(IbcEvent::UpgradeConfirmChannel(
+            channel_upgrade_confirm_try_from_abci_event(abci_event)
+                .map_err(IbcEventError::channel)?,
+        )),
+        Ok(IbcEventType::UpgradeOpenChannel) => Ok(IbcEvent::UpgradeOpenChannel(
+            channel_upgrade_open_try_from_abci_event(abci_event).map_err(IbcEventError::channel)?,
+        )),
         Ok(IbcEventType::SendPacket) => Ok(IbcEvent::SendPacket(
             send_packet_try_from_abci_event(abci_event).map_err(IbcEventError::channel)?,
         )),
@@ -148,9 +177,22 @@ pub fn create_client_try_from_abci_event(
 pub fn update_client_try_from_abci_event(
     abci_event: &AbciEvent,
 ) -> Result<client_events::UpdateClient, ClientError> {
-    client_extract_attributes_from_tx(abci_event).map(|attributes| client_events::UpdateClient {
-        common: attributes,
-        header: extract_header_from_tx(abci_event).ok(),
+    client_extract_attributes_from_tx(abci_event).map(|attributes| {
+        let header = extract_header_from_tx(abci_event)
+            .ok()
+            .map(|header| match header {
+                // The sequence a solo machine header is signed for is not part of its encoding,
+                // it is the one preceding the consensus height the header was applied at
+                AnyHeader::Solomachine(header) => AnyHeader::Solomachine(
+                    header.with_sequence(attributes.consensus_height.revision_height() - 1),
+                ),
+                header => header,
+            });
+
+        client_events::UpdateClient {
+            common: attributes,
+            header,
+        }
     })
 }
 
@@ -250,6 +292,56 @@ pub fn channel_close_confirm_try_from_abci_event(
     }
 }
 
+pub fn channel_upgrade_init_try_from_abci_event(
+    abci_event: &AbciEvent,
+) -> Result<channel_events::UpgradeInit, ChannelError> {
+    match channel_upgrade_extract_attributes_from_tx(abci_event) {
+        Ok(attrs) => channel_events::UpgradeInit::try_from(attrs)
+            .map_err(|_| ChannelError::implementation_specific()),
+        Err(e) => Err(e),
+    }
+}
+
+pub fn channel_upgrade_try_try_from_abci_event(
+    abci_event: &AbciEvent,
+) -> Result<channel_events::UpgradeTry, ChannelError> {
+    match channel_upgrade_extract_attributes_from_tx(abci_event) {
+        Ok(attrs) => channel_events::UpgradeTry::try_from(attrs)
+            .map_err(|_| ChannelError::implementation_specific()),
+        Err(e) => Err(e),
+    }
+}
+
+pub fn channel_upgrade_ack_try_from_abci_event(
+    abci_event: &AbciEvent,
+) -> Result<channel_events::UpgradeAck, ChannelError> {
+    match channel_upgrade_extract_attributes_from_tx(abci_event) {
+        Ok(attrs) => channel_events::UpgradeAck::try_from(attrs)
+            .map_err(|_| ChannelError::implementation_specific()),
+        Err(e) => Err(e),
+    }
+}
+
+pub fn channel_upgrade_confirm_try_from_abci_event(
+    abci_event: &AbciEvent,
+) -> Result<channel_events::UpgradeConfirm, ChannelError> {
+    match channel_upgrade_extract_attributes_from_tx(abci_event) {
+        Ok(attrs) => channel_events::UpgradeConfirm::try_from(attrs)
+            .map_err(|_| ChannelError::implementation_specific()),
+        Err(e) => Err(e),
+    }
+}
+
+pub fn channel_upgrade_open_try_from_abci_event(
+    abci_event: &AbciEvent,
+) -> Result<channel_events::UpgradeOpen, ChannelError> {
+    match channel_upgrade_extract_attributes_from_tx(abci_event) {
+        Ok(attrs) => channel_events::UpgradeOpen::try_from(attrs)
+            .map_err(|_| ChannelError::implementation_specific()),
+        Err(e) => Err(e),
+    }
+}
+
 pub fn send_packet_try_from_abci_event(
     abci_event: &AbciEvent,
 ) -> Result<channel_events::SendPacket, ChannelError> {
@@ -398,6 +490,42 @@ fn channel_extract_attributes_from_tx(
     Ok(attr)
 }
 
+fn channel_upgrade_extract_attributes_from_tx(
+    event: &AbciEvent,
+) -> Result<UpgradeAttributes, ChannelError> {
+    let mut attr = UpgradeAttributes::default();
+    let mut channel_id = None;
+
+    for tag in &event.attributes {
+        let key = tag.key.as_str();
+        let value = tag.value.as_str();
+        match key {
+            channel_events::PORT_ID_ATTRIBUTE_KEY => {
+                attr.port_id = value.parse().map_err(ChannelError::identifier)?
+            }
+            channel_events::CHANNEL_ID_ATTRIBUTE_KEY => {
+                channel_id = Some(value.parse().map_err(ChannelError::identifier)?);
+            }
+            channel_events::COUNTERPARTY_PORT_ID_ATTRIBUTE_KEY => {
+                attr.counterparty_port_id = value.parse().map_err(ChannelError::identifier)?;
+            }
+            channel_events::COUNTERPARTY_CHANNEL_ID_ATTRIBUTE_KEY => {
+                attr.counterparty_channel_id = value.parse().ok();
+            }
+            channel_events::UPGRADE_SEQUENCE_ATTRIBUTE_KEY => {
+                attr.upgrade_sequence = value
+                    .parse()
+                    .map_err(|e| ChannelError::invalid_string_as_sequence(value.to_string(), e))?;
+            }
+            _ => {}
+        }
+    }
+
+    attr.channel_id = channel_id.ok_or_else(ChannelError::missing_channel_id)?;
+
+    Ok(attr)
+}
+
 pub fn extract_packet_and_write_ack_from_tx(
     event: &AbciEvent,
 ) -> Result<(Packet, Vec<u8>), ChannelError> {
@@ -477,11 +605,44 @@ mod tests {
         Protobuf::<Any>::encode(header.clone(), &mut header_bytes).unwrap();
 
         let decoded_dyn_header = decode_header(&header_bytes).unwrap();
-        let AnyHeader::Tendermint(decoded_tm_header) = decoded_dyn_header;
+        let AnyHeader::Tendermint(decoded_tm_header) = decoded_dyn_header else {
+            panic!("expected a Tendermint header");
+        };
 
         assert_eq!(header, decoded_tm_header);
     }
 
+    #[test]
+    fn solomachine_update_client_event_height() {
+        use ibc_relayer_types::clients::ics06_solomachine::consensus_state::PublicKey;
+        use ibc_relayer_types::clients::ics06_solomachine::header::Header as SmHeader;
+        use ibc_relayer_types::core::ics02_client::client_type::ClientType;
+        use ibc_relayer_types::core::ics02_client::header::Header;
+        use ibc_relayer_types::timestamp::Timestamp;
+
+        let header = SmHeader {
+            sequence: 5,
+            timestamp: Timestamp::now(),
+            signature: vec![1, 2, 3],
+            new_public_key: PublicKey::from_bytes(&[2; 33]).unwrap(),
+            new_diversifier: "solo-0".to_string(),
+        };
+
+        let update = client_events::UpdateClient {
+            common: client_events::Attributes {
+                client_id: "06-solomachine-0".parse().unwrap(),
+                client_type: ClientType::Solomachine,
+                consensus_height: Height::new(0, 6).unwrap(),
+            },
+            header: Some(AnyHeader::Solomachine(header.clone())),
+        };
+
+        let decoded = update_client_try_from_abci_event(&AbciEvent::from(update)).unwrap();
+
+        assert_eq!(decoded.header, Some(AnyHeader::Solomachine(header)));
+        assert_eq!(decoded.header.unwrap().height(), Height::new(0, 6).unwrap());
+    }
+
     #[test]
     fn connection_event_to_abci_event() {
         let attributes = ConnectionAttributes {
@@ -564,4 +725,50 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn channel_upgrade_event_to_abci_event() {
+        let attributes = UpgradeAttributes {
+            port_id: "test_port".parse().unwrap(),
+            channel_id: "channel-0".parse().unwrap(),
+            counterparty_port_id: "counterparty_test_port".parse().unwrap(),
+            counterparty_channel_id: Some("channel-1".parse().unwrap()),
+            upgrade_sequence: 2,
+        };
+        let mut abci_events = vec![];
+        let upgrade_init = channel_events::UpgradeInit::try_from(attributes.clone()).unwrap();
+        abci_events.push(AbciEvent::from(upgrade_init.clone()));
+        let upgrade_try = channel_events::UpgradeTry::try_from(attributes.clone()).unwrap();
+        abci_events.push(AbciEvent::from(upgrade_try.clone()));
+        let upgrade_ack = channel_events::UpgradeAck::try_from(attributes.clone()).unwrap();
+        abci_events.push(AbciEvent::from(upgrade_ack.clone()));
+        let upgrade_confirm = channel_events::UpgradeConfirm::try_from(attributes.clone()).unwrap();
+        abci_events.push(AbciEvent::from(upgrade_confirm.clone()));
+        let upgrade_open = channel_events::UpgradeOpen::try_from(attributes).unwrap();
+        abci_events.push(AbciEvent::from(upgrade_open.clone()));
+
+        for abci_event in abci_events {
+            match ibc_event_try_from_abci_event(&abci_event).ok() {
+                Some(ibc_event) => match ibc_event {
+                    IbcEvent::UpgradeInitChannel(e) => {
+                        assert_eq!(UpgradeAttributes::from(e), upgrade_init.clone().into())
+                    }
+                    IbcEvent::UpgradeTryChannel(e) => {
+                        assert_eq!(UpgradeAttributes::from(e), upgrade_try.clone().into())
+                    }
+                    IbcEvent::UpgradeAckChannel(e) => {
+                        assert_eq!(UpgradeAttributes::from(e), upgrade_ack.clone().into())
+                    }
+                    IbcEvent::UpgradeConfirmChannel(e) => {
+                        assert_eq!(UpgradeAttributes::from(e), upgrade_confirm.clone().into())
+                    }
+                    IbcEvent::UpgradeOpenChannel(e) => {
+                        assert_eq!(UpgradeAttributes::from(e), upgrade_open.clone().into())
+                    }
+                    _ => panic!("unexpected event type"),
+                },
+                None => panic!("converted event was wrong"),
+            }
+        }
+    }
 }
@@ -1,9 +1,37 @@
 use alloc::collections::VecDeque;
+use alloc::sync::Arc;
+use core::sync::atomic::{AtomicU64, Ordering};
 
 use crossbeam_channel as channel;
 
 pub struct EventBus<T> {
-    txs: VecDeque<channel::Sender<T>>,
+    txs: VecDeque<Subscriber<T>>,
+}
+
+struct Subscriber<T> {
+    tx: channel::Sender<T>,
+    dropped: Arc<AtomicU64>,
+}
+
+/// A subscription to an [`EventBus`] which holds at most a fixed number of values.
+///
+/// The values broadcast while the subscription is full are dropped,
+/// and counted so that the subscriber can find out about the gap.
+#[derive(Clone, Debug)]
+pub struct BoundedSubscription<T> {
+    rx: channel::Receiver<T>,
+    dropped: Arc<AtomicU64>,
+}
+
+impl<T> BoundedSubscription<T> {
+    pub fn try_recv(&self) -> Result<T, channel::TryRecvError> {
+        self.rx.try_recv()
+    }
+
+    /// Return how many values were dropped since the last call.
+    pub fn take_dropped(&self) -> u64 {
+        self.dropped.swap(0, Ordering::Relaxed)
+    }
 }
 
 impl<T> Default for EventBus<T> {
@@ -19,27 +47,57 @@ impl<T> EventBus<T> {
         }
     }
 
+    /// Whether there are no subscribers to the bus.
+    pub fn is_empty(&self) -> bool {
+        self.txs.is_empty()
+    }
+
     pub fn subscribe(&mut self) -> channel::Receiver<T> {
         let (tx, rx) = channel::unbounded();
-        self.txs.push_back(tx);
+        self.push(tx);
         rx
     }
 
+    /// Subscribe to the bus, holding at most `capacity` values
+    /// which have not yet been received.
+    pub fn subscribe_bounded(&mut self, capacity: usize) -> BoundedSubscription<T> {
+        let (tx, rx) = channel::bounded(capacity);
+        let dropped = self.push(tx);
+        BoundedSubscription { rx, dropped }
+    }
+
+    fn push(&mut self, tx: channel::Sender<T>) -> Arc<AtomicU64> {
+        let dropped = Arc::new(AtomicU64::new(0));
+
+        self.txs.push_back(Subscriber {
+            tx,
+            dropped: dropped.clone(),
+        });
+
+        dropped
+    }
+
     pub fn broadcast(&mut self, value: T)
     where
         T: Clone,
     {
         let mut disconnected = Vec::new();
 
-        for (idx, tx) in self.txs.iter().enumerate() {
+        for (idx, subscriber) in self.txs.iter().enumerate() {
             // TODO: Avoid cloning when sending to last subscriber
-            if let Err(channel::SendError(_)) = tx.send(value.clone()) {
-                disconnected.push(idx);
+            match subscriber.tx.try_send(value.clone()) {
+                Ok(()) => {}
+                // Only bounded subscriptions can be full
+                Err(channel::TrySendError::Full(_)) => {
+                    subscriber.dropped.fetch_add(1, Ordering::Relaxed);
+                }
+                Err(channel::TrySendError::Disconnected(_)) => disconnected.push(idx),
             }
         }
 
-        // Remove all disconnected subscribers
-        for idx in disconnected {
+        // Remove all disconnected subscribers, starting from the last one
+        // so that the indices of the remaining ones stay valid
+        for idx in disconnected.into_iter().rev() {
             self.txs.remove(idx);
         }
     }
@@ -117,4 +175,31 @@ mod tests {
 
         assert_eq!(counter(), 20);
     }
+
+    #[test]
+    #[serial]
+    fn bounded_subscriber() {
+        reset_counter();
+
+        let mut bus = EventBus::new();
+        let sub = bus.subscribe_bounded(1);
+        let rx = bus.subscribe();
+
+        bus.broadcast(Value(42));
+        bus.broadcast(Value(113));
+        bus.broadcast(Value(7));
+
+        assert_eq!(sub.try_recv(), Ok(Value(42)));
+        assert!(sub.try_recv().is_err());
+        assert_eq!(sub.take_dropped(), 2);
+        assert_eq!(sub.take_dropped(), 0);
+
+        // The unbounded subscriber is not affected
+        assert_eq!(rx.recv(), Ok(Value(42)));
+        assert_eq!(rx.recv(), Ok(Value(113)));
+        assert_eq!(rx.recv(), Ok(Value(7)));
+
+        bus.broadcast(Value(8));
+        assert_eq!(sub.try_recv(), Ok(Value(8)));
+    }
 }
@@ -0,0 +1,203 @@
+//! Structured events describing the actions taken by the relayer,
+//! eg. submitting a transaction or relaying a packet.
+//!
+//! The events are broadcast on a global [`EventBus`], to which consumers
+//! such as the event stream of the REST server can subscribe.
+//! Nothing is broadcast while there are no subscribers.
+
+use std::sync::Mutex;
+
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+use tendermint::Hash as TxHash;
+
+use ibc_relayer_types::core::ics04_channel::packet::Sequence;
+use ibc_relayer_types::core::ics24_host::identifier::{ChainId, ChannelId, ClientId, PortId};
+use ibc_relayer_types::events::IbcEvent;
+use ibc_relayer_types::Height;
+
+use crate::chain::tracking::TrackingId;
+
+use super::bus::{BoundedSubscription, EventBus};
+
+static BUS: Lazy<Mutex<EventBus<RelayEvent>>> = Lazy::new(|| Mutex::new(EventBus::new()));
+
+/// How many events a subscriber can fall behind by before the newer events are dropped.
+const SUBSCRIPTION_CAPACITY: usize = 1024;
+
+/// An action taken by the relayer.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum RelayEvent {
+    /// Transactions were submitted to a chain, and are waiting for confirmation.
+    TxSubmitted {
+        chain_id: ChainId,
+        tracking_id: String,
+        tx_hashes: Vec<String>,
+    },
+
+    /// Transactions previously submitted to a chain were committed.
+    TxConfirmed {
+        chain_id: ChainId,
+        tracking_id: String,
+        tx_hashes: Vec<String>,
+    },
+
+    /// A packet was received, acknowledged or timed out on a chain.
+    PacketRelayed {
+        /// The chain on which the packet was relayed.
+        chain_id: ChainId,
+        /// The type of the IBC event emitted when relaying the packet,
+        /// eg. `receive_packet` or `acknowledge_packet`.
+        event_type: String,
+        src_port_id: PortId,
+        src_channel_id: ChannelId,
+        dst_port_id: PortId,
+        dst_channel_id: ChannelId,
+        sequence: Sequence,
+    },
+
+    /// A client hosted on a chain was updated.
+    ClientUpdated {
+        chain_id: ChainId,
+        client_id: ClientId,
+        consensus_height: Height,
+    },
+
+    /// Misbehaviour was detected for a client hosted on a chain,
+    /// and evidence of it was submitted.
+    MisbehaviourDetected {
+        chain_id: ChainId,
+        client_id: ClientId,
+    },
+
+    /// A worker encountered an error.
+    WorkerError { worker: String, error: String },
+}
+
+/// Subscribe to the events published by the relayer.
+///
+/// The events published while the subscriber lags behind by more than
+/// [`SUBSCRIPTION_CAPACITY`] events are dropped, and counted by the subscription.
+/// The subscription is dropped once the returned value is dropped.
+pub fn subscribe() -> BoundedSubscription<RelayEvent> {
+    BUS.lock().unwrap().subscribe_bounded(SUBSCRIPTION_CAPACITY)
+}
+
+/// Broadcast the event built by the given closure to all the subscribers.
+///
+/// The closure is only called if there is at least one subscriber.
+pub fn publish(event: impl FnOnce() -> RelayEvent) {
+    let mut bus = BUS.lock().unwrap();
+
+    if !bus.is_empty() {
+        bus.broadcast(event());
+    }
+}
+
+pub fn publish_tx_submitted(chain_id: ChainId, tracking_id: TrackingId, tx_hashes: &[TxHash]) {
+    publish(|| RelayEvent::TxSubmitted {
+        chain_id,
+        tracking_id: tracking_id.to_string(),
+        tx_hashes: tx_hashes.iter().map(|h| h.to_string()).collect(),
+    })
+}
+
+pub fn publish_tx_confirmed(chain_id: ChainId, tracking_id: TrackingId, tx_hashes: &[TxHash]) {
+    publish(|| RelayEvent::TxConfirmed {
+        chain_id,
+        tracking_id: tracking_id.to_string(),
+        tx_hashes: tx_hashes.iter().map(|h| h.to_string()).collect(),
+    })
+}
+
+/// Publish the relayed packets and updated clients
+/// among the events of a committed transaction.
+pub fn publish_tx_events(chain_id: &ChainId, events: &[IbcEvent]) {
+    for event in events {
+        if let Some(event) = tx_event(chain_id, event) {
+            publish(|| event);
+        }
+    }
+}
+
+fn tx_event(chain_id: &ChainId, event: &IbcEvent) -> Option<RelayEvent> {
+    match event {
+        IbcEvent::ReceivePacket(_)
+        | IbcEvent::AcknowledgePacket(_)
+        | IbcEvent::TimeoutPacket(_)
+        | IbcEvent::TimeoutOnClosePacket(_) => {
+            let packet = event.packet()?;
+
+            Some(RelayEvent::PacketRelayed {
+                chain_id: chain_id.clone(),
+                event_type: event.event_type().as_str().to_string(),
+                src_port_id: packet.source_port.clone(),
+                src_channel_id: packet.source_channel.clone(),
+                dst_port_id: packet.destination_port.clone(),
+                dst_channel_id: packet.destination_channel.clone(),
+                sequence: packet.sequence,
+            })
+        }
+
+        IbcEvent::UpdateClient(update) => Some(RelayEvent::ClientUpdated {
+            chain_id: chain_id.clone(),
+            client_id: update.client_id().clone(),
+            consensus_height: update.consensus_height(),
+        }),
+
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use test_log::test;
+
+    use ibc_relayer_types::core::ics04_channel::events::ReceivePacket;
+    use ibc_relayer_types::core::ics04_channel::packet::Packet;
+
+    #[test]
+    fn packet_relayed_event() {
+        let chain_id = ChainId::from_string("chain_b");
+
+        let packet = Packet {
+            sequence: Sequence::from(42),
+            source_port: PortId::transfer(),
+            source_channel: ChannelId::new(0),
+            destination_port: PortId::transfer(),
+            destination_channel: ChannelId::new(1),
+            ..Default::default()
+        };
+
+        let event = IbcEvent::ReceivePacket(ReceivePacket { packet });
+
+        assert_eq!(
+            tx_event(&chain_id, &event),
+            Some(RelayEvent::PacketRelayed {
+                chain_id,
+                event_type: "receive_packet".to_string(),
+                src_port_id: PortId::transfer(),
+                src_channel_id: ChannelId::new(0),
+                dst_port_id: PortId::transfer(),
+                dst_channel_id: ChannelId::new(1),
+                sequence: Sequence::from(42),
+            })
+        );
+    }
+
+    #[test]
+    fn serialize_event() {
+        let event = RelayEvent::WorkerError {
+            worker: "packet::channel-0/transfer:chain_a->chain_b".to_string(),
+            error: "oops".to_string(),
+        };
+
+        assert_eq!(
+            serde_json::to_string(&event).unwrap(),
+            r#"{"type":"worker_error","worker":"packet::channel-0/transfer:chain_a->chain_b","error":"oops"}"#
+        );
+    }
+}
@@ -6,6 +6,7 @@ use std::{sync::Arc, time::Duration};
 use crossbeam_channel as channel;
 
 use futures::Stream;
+use tendermint::block::Height as BlockHeight;
 use tendermint_rpc::{
     client::CompatMode, event::Event as RpcEvent, Error as RpcError, HttpClient, WebSocketClientUrl,
 };
@@ -31,12 +32,19 @@ impl EventSource {
     pub fn websocket(
         chain_id: ChainId,
         ws_url: WebSocketClientUrl,
+        fallback_ws_urls: Vec<WebSocketClientUrl>,
         rpc_compat: CompatMode,
         batch_delay: Duration,
         rt: Arc<TokioRuntime>,
     ) -> Result<(Self, TxEventSourceCmd)> {
-        let (mut source, tx) =
-            websocket::EventSource::new(chain_id, ws_url, rpc_compat, batch_delay, rt)?;
+        let (mut source, tx) = websocket::EventSource::new(
+            chain_id,
+            ws_url,
+            fallback_ws_urls,
+            rpc_compat,
+            batch_delay,
+            rt,
+        )?;
 
         source.init_subscriptions()?;
 
@@ -46,10 +54,19 @@ impl EventSource {
     pub fn rpc(
         chain_id: ChainId,
         rpc_client: HttpClient,
+        fallback_rpc_clients: Vec<HttpClient>,
         poll_interval: Duration,
+        start_height: Option<BlockHeight>,
         rt: Arc<TokioRuntime>,
     ) -> Result<(Self, TxEventSourceCmd)> {
-        let (source, tx) = rpc::EventSource::new(chain_id, rpc_client, poll_interval, rt)?;
+        let (source, tx) = rpc::EventSource::new(
+            chain_id,
+            rpc_client,
+            fallback_rpc_clients,
+            poll_interval,
+            start_height,
+            rt,
+        )?;
         Ok((Self::Rpc(source), tx))
     }
 
@@ -7,7 +7,7 @@ use tokio::{
     runtime::Runtime as TokioRuntime,
     time::{sleep, Duration, Instant},
 };
-use tracing::{debug, error, error_span, trace};
+use tracing::{debug, error, error_span, trace, warn};
 
 use tendermint::abci;
 use tendermint::block::Height as BlockHeight;
@@ -39,8 +39,11 @@ pub struct EventSource {
     /// Chain identifier
     chain_id: ChainId,
 
-    /// RPC client
-    rpc_client: HttpClient,
+    /// RPC clients of the nodes to collect events from, in order of preference
+    rpc_clients: Vec<HttpClient>,
+
+    /// Index in `rpc_clients` of the client in use
+    active_client: usize,
 
     /// Poll interval
     poll_interval: Duration,
@@ -56,13 +59,20 @@ pub struct EventSource {
 
     /// Last fetched block height
     last_fetched_height: BlockHeight,
+
+    /// Height after which to start fetching blocks, if not the latest height
+    start_height: Option<BlockHeight>,
 }
 
 impl EventSource {
+    /// Create an event source polling the node behind the given RPC client,
+    /// failing over to the next fallback RPC client whenever polling fails.
     pub fn new(
         chain_id: ChainId,
         rpc_client: HttpClient,
+        fallback_rpc_clients: Vec<HttpClient>,
         poll_interval: Duration,
+        start_height: Option<BlockHeight>,
         rt: Arc<TokioRuntime>,
     ) -> Result<(Self, TxEventSourceCmd)> {
         let event_bus = EventBus::new();
@@ -71,16 +81,39 @@ impl EventSource {
         let source = Self {
             rt,
             chain_id,
-            rpc_client,
+            rpc_clients: core::iter::once(rpc_client)
+                .chain(fallback_rpc_clients)
+                .collect(),
+            active_client: 0,
             poll_interval,
             event_bus,
             rx_cmd,
             last_fetched_height: BlockHeight::from(0_u32),
+            start_height,
         };
 
         Ok((source, TxEventSourceCmd(tx_cmd)))
     }
 
+    /// The RPC client in use.
+    fn rpc_client(&self) -> &HttpClient {
+        &self.rpc_clients[self.active_client]
+    }
+
+    /// Fail over to the next RPC client, if there are any fallback clients.
+    fn rotate_client(&mut self) {
+        if self.rpc_clients.len() < 2 {
+            return;
+        }
+
+        self.active_client = (self.active_client + 1) % self.rpc_clients.len();
+
+        match self.active_client {
+            0 => warn!("failing back to the primary RPC endpoint"),
+            n => warn!("failing over to fallback RPC endpoint #{n}"),
+        }
+    }
+
     pub fn run(mut self) {
         let _span = error_span!("event_source.rpc", chain.id = %self.chain_id).entered();
 
@@ -91,8 +124,12 @@ impl EventSource {
         rt.block_on(async {
             let mut backoff = poll_backoff(self.poll_interval);
 
-            // Initialize the latest fetched height
-            if let Ok(latest_height) = latest_height(&self.rpc_client).await {
+            // Initialize the latest fetched height, either to the configured
+            // start height so that the following blocks are replayed, or to the latest height
+            if let Some(start_height) = self.start_height {
+                debug!(%start_height, "replaying blocks after start height");
+                self.last_fetched_height = start_height;
+            } else if let Ok(latest_height) = latest_height(self.rpc_client()).await {
                 self.last_fetched_height = latest_height;
             }
 
@@ -121,6 +158,9 @@ impl EventSource {
                     Err(e) => {
                         error!("event source encountered an error: {e}");
 
+                        // Poll another node next time, if any
+                        self.rotate_client();
+
                         // Let's backoff the little bit to give the chain some time to recover.
                         let delay = backoff.next().expect("backoff is an infinite iterator");
 
@@ -140,7 +180,7 @@ impl EventSource {
             return Ok(Next::Abort);
         }
 
-        let latest_height = latest_height(&self.rpc_client).await?;
+        let latest_height = latest_height(self.rpc_client()).await?;
 
         let batches = if latest_height > self.last_fetched_height {
             trace!(
@@ -203,7 +243,7 @@ impl EventSource {
         for height in heights {
             trace!("collecting events at height {height}");
 
-            let result = collect_events(&self.rpc_client, &self.chain_id, height).await;
+            let result = collect_events(self.rpc_client(), &self.chain_id, height).await;
 
             match result {
                 Ok(batch) => {
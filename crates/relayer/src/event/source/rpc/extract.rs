@@ -25,7 +25,7 @@ pub fn extract_events(
                         telemetry!(fees_amount, _chain_id, &dist.receiver, dist.fee.clone());
                     }
                 } else {
-                    events_with_height.push(IbcEventWithHeight { height, event });
+                    events_with_height.push(IbcEventWithHeight::new(event, height));
                 }
             }
 
@@ -86,6 +86,11 @@ fn event_is_type_channel(ev: &IbcEvent) -> bool {
             | IbcEvent::OpenConfirmChannel(_)
             | IbcEvent::CloseInitChannel(_)
             | IbcEvent::CloseConfirmChannel(_)
+            | IbcEvent::UpgradeInitChannel(_)
+            | IbcEvent::UpgradeTryChannel(_)
+            | IbcEvent::UpgradeAckChannel(_)
+            | IbcEvent::UpgradeConfirmChannel(_)
+            | IbcEvent::UpgradeOpenChannel(_)
     )
 }
 
@@ -12,7 +12,7 @@ use futures::{
 };
 use tokio::task::JoinHandle;
 use tokio::{runtime::Runtime as TokioRuntime, sync::mpsc};
-use tracing::{debug, error, info, instrument, trace};
+use tracing::{debug, error, info, instrument, trace, warn};
 
 use tendermint_rpc::{
     client::CompatMode, event::Event as RpcEvent, query::Query, SubscriptionClient,
@@ -76,8 +76,10 @@ pub struct EventSource {
     tx_err: mpsc::UnboundedSender<tendermint_rpc::Error>,
     /// Channel where to receive commands
     rx_cmd: channel::Receiver<EventSourceCmd>,
-    /// Node Address
-    ws_url: WebSocketClientUrl,
+    /// The WebSocket URLs of the nodes to collect events from, in order of preference
+    ws_urls: Vec<WebSocketClientUrl>,
+    /// Index in `ws_urls` of the URL currently connected to
+    active_url: usize,
     /// RPC compatibility mode
     rpc_compat: CompatMode,
     /// Queries
@@ -89,7 +91,10 @@ pub struct EventSource {
 }
 
 impl EventSource {
-    /// Create an event source, and connect to a node
+    /// Create an event source, and connect to a node.
+    ///
+    /// The fallback URLs are tried in order if the node at `ws_url` cannot be reached,
+    /// and are failed over to whenever the connection to the node in use is lost.
     #[instrument(
         name = "event_source.create",
         level = "error",
@@ -99,6 +104,7 @@ impl EventSource {
     pub fn new(
         chain_id: ChainId,
         ws_url: WebSocketClientUrl,
+        fallback_ws_urls: Vec<WebSocketClientUrl>,
         rpc_compat: CompatMode,
         batch_delay: Duration,
         rt: Arc<TokioRuntime>,
@@ -106,11 +112,43 @@ impl EventSource {
         let event_bus = EventBus::new();
         let (tx_cmd, rx_cmd) = channel::unbounded();
 
-        let builder = WebSocketClient::builder(ws_url.clone()).compat_mode(rpc_compat);
+        let ws_urls: Vec<_> = core::iter::once(ws_url).chain(fallback_ws_urls).collect();
+
+        let mut connection = Err(Error::client_creation_failed(
+            chain_id.clone(),
+            ws_urls[0].clone(),
+        ));
 
-        let (client, driver) = rt
-            .block_on(builder.build())
-            .map_err(|_| Error::client_creation_failed(chain_id.clone(), ws_url.clone()))?;
+        for (index, ws_url) in ws_urls.iter().enumerate() {
+            let builder = WebSocketClient::builder(ws_url.clone()).compat_mode(rpc_compat);
+
+            match rt.block_on(builder.build()) {
+                Ok(client) => {
+                    connection = Ok((index, client));
+                    break;
+                }
+                Err(e) => {
+                    error!("failed to connect to WebSocket endpoint {ws_url}: {e}");
+                    connection = Err(Error::client_creation_failed(
+                        chain_id.clone(),
+                        ws_url.clone(),
+                    ));
+                }
+            }
+        }
+
+        let (active_url, (client, driver)) = connection?;
+
+        telemetry!({
+            for (index, url) in ws_urls.iter().enumerate() {
+                ibc_telemetry::global().active_endpoint(
+                    &chain_id,
+                    "websocket",
+                    &url.to_string(),
+                    index == active_url,
+                );
+            }
+        });
 
         let (tx_err, rx_err) = mpsc::unbounded_channel();
         let driver_handle = rt.spawn(run_driver(driver, tx_err.clone()));
@@ -129,7 +167,8 @@ impl EventSource {
             rx_err,
             tx_err,
             rx_cmd,
-            ws_url,
+            ws_urls,
+            active_url,
             rpc_compat,
             subscriptions: Box::new(futures::stream::empty()),
         };
@@ -137,6 +176,41 @@ impl EventSource {
         Ok((source, TxEventSourceCmd(tx_cmd)))
     }
 
+    /// The WebSocket URL of the node currently connected to.
+    pub fn ws_url(&self) -> &WebSocketClientUrl {
+        &self.ws_urls[self.active_url]
+    }
+
+    /// Fail over to the next WebSocket URL, if there are any fallback URLs.
+    fn rotate_url(&mut self) {
+        if self.ws_urls.len() < 2 {
+            return;
+        }
+
+        let previous = self.ws_url().clone();
+        self.active_url = (self.active_url + 1) % self.ws_urls.len();
+
+        warn!(
+            "failing over from WebSocket endpoint {previous} to {}",
+            self.ws_url()
+        );
+
+        telemetry!(
+            active_endpoint,
+            &self.chain_id,
+            "websocket",
+            &previous.to_string(),
+            false
+        );
+        telemetry!(
+            active_endpoint,
+            &self.chain_id,
+            "websocket",
+            &self.ws_url().to_string(),
+            true
+        );
+    }
+
     /// The list of [`Query`] that this event source is subscribing for.
     pub fn queries(&self) -> &[Query] {
         &self.event_queries
@@ -172,13 +246,16 @@ impl EventSource {
         fields(chain = %self.chain_id)
     )]
     fn try_reconnect(&mut self) -> Result<()> {
-        trace!("trying to reconnect to WebSocket endpoint {}", self.ws_url);
+        trace!(
+            "trying to reconnect to WebSocket endpoint {}",
+            self.ws_url()
+        );
 
         // Try to reconnect
-        let builder = WebSocketClient::builder(self.ws_url.clone()).compat_mode(self.rpc_compat);
+        let builder = WebSocketClient::builder(self.ws_url().clone()).compat_mode(self.rpc_compat);
 
         let (mut client, driver) = self.rt.block_on(builder.build()).map_err(|_| {
-            Error::client_creation_failed(self.chain_id.clone(), self.ws_url.clone())
+            Error::client_creation_failed(self.chain_id.clone(), self.ws_url().clone())
         })?;
 
         let mut driver_handle = self.rt.spawn(run_driver(driver, self.tx_err.clone()));
@@ -188,7 +265,7 @@ impl EventSource {
         core::mem::swap(&mut self.client, &mut client);
         core::mem::swap(&mut self.driver_handle, &mut driver_handle);
 
-        trace!("reconnected to WebSocket endpoint {}", self.ws_url);
+        trace!("reconnected to WebSocket endpoint {}", self.ws_url());
 
         // Shut down previous client
         trace!("gracefully shutting down previous client",);
@@ -216,7 +293,8 @@ impl EventSource {
         self.init_subscriptions()
     }
 
-    /// Attempt to reconnect the WebSocket client using the given retry strategy.
+    /// Attempt to reconnect the WebSocket client using the given retry strategy,
+    /// failing over to the next WebSocket URL after each failed attempt.
     ///
     /// See the [`retry`](https://docs.rs/retry) crate and the
     /// [`crate::util::retry`] module for more information.
@@ -231,6 +309,7 @@ impl EventSource {
             // Try to reconnect
             if let Err(e) = self.try_reconnect() {
                 trace!("error when reconnecting: {}", e);
+                self.rotate_url();
                 return RetryResult::Retry(());
             }
 
@@ -246,11 +325,12 @@ impl EventSource {
         match result {
             Ok(()) => info!(
                 "successfully reconnected to WebSocket endpoint {}",
-                self.ws_url
+                self.ws_url()
             ),
             Err(e) => error!(
                 "failed to reconnect to {} after {} retries",
-                self.ws_url, e.tries
+                self.ws_url(),
+                e.tries
             ),
         }
     }
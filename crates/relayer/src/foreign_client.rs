@@ -14,7 +14,10 @@ use tracing::{debug, error, info, instrument, trace, warn};
 
 use flex_error::define_error;
 use ibc_relayer_types::applications::ics28_ccv::msgs::ccv_misbehaviour::MsgSubmitIcsConsumerMisbehaviour;
+use ibc_relayer_types::clients::ics08_wasm::client_state::ClientState as WasmClientState;
+use ibc_relayer_types::clients::ics08_wasm::consensus_state::ConsensusState as WasmConsensusState;
 use ibc_relayer_types::core::ics02_client::client_state::ClientState;
+use ibc_relayer_types::core::ics02_client::client_type::ClientType;
 use ibc_relayer_types::core::ics02_client::error::Error as ClientError;
 use ibc_relayer_types::core::ics02_client::events::UpdateClient;
 use ibc_relayer_types::core::ics02_client::header::{AnyHeader, Header};
@@ -26,6 +29,7 @@ use ibc_relayer_types::core::ics02_client::trust_threshold::TrustThreshold;
 use ibc_relayer_types::core::ics24_host::identifier::{ChainId, ClientId};
 use ibc_relayer_types::downcast;
 use ibc_relayer_types::events::{IbcEvent, IbcEventType, WithBlockDataType};
+use ibc_relayer_types::signer::Signer;
 use ibc_relayer_types::timestamp::{Timestamp, TimestampOverflowError};
 use ibc_relayer_types::tx_msg::Msg;
 use ibc_relayer_types::Height;
@@ -35,9 +39,10 @@ use crate::chain::handle::ChainHandle;
 use crate::chain::requests::*;
 use crate::chain::tracking::TrackedMsgs;
 use crate::client_state::AnyClientState;
-use crate::config::ChainConfig;
+use crate::config::{default, ChainConfig};
 use crate::consensus_state::AnyConsensusState;
 use crate::error::Error as RelayerError;
+use crate::event::relay::{self, RelayEvent};
 use crate::event::IbcEventWithHeight;
 use crate::misbehaviour::{AnyMisbehaviour, MisbehaviourEvidence};
 use crate::telemetry;
@@ -356,6 +361,10 @@ pub struct CreateOptions {
     pub max_clock_drift: Option<Duration>,
     pub trusting_period: Option<Duration>,
     pub trust_threshold: Option<TrustThreshold>,
+
+    /// Checksum of the Wasm light client contract to wrap the new client into,
+    /// when creating an `08-wasm` client instead of a native one.
+    pub wasm_checksum: Option<Vec<u8>>,
 }
 
 /// Captures the diagnostic of verifying whether a certain
@@ -492,8 +501,18 @@ impl<DstChain: ChainHandle, SrcChain: ChainHandle> ForeignClient<DstChain, SrcCh
         fields(client = %self)
     )]
     pub fn upgrade(&self, src_upgrade_height: Height) -> Result<Vec<IbcEvent>, ForeignClientError> {
+        // Get signer
+        let signer = self.dst_chain.get_signer().map_err(|e| {
+            ForeignClientError::client_upgrade(
+                self.id.clone(),
+                self.dst_chain.id(),
+                "failed while fetching the destination chain signer".to_string(),
+                e,
+            )
+        })?;
+
         let msgs = self
-            .build_update_client_with_trusted(src_upgrade_height, None)
+            .build_update_client_with_trusted(src_upgrade_height, None, signer.clone())
             .map_err(|_| {
                 ForeignClientError::client_upgrade_no_source(
                     self.id.clone(),
@@ -542,16 +561,6 @@ impl<DstChain: ChainHandle, SrcChain: ChainHandle> ForeignClient<DstChain, SrcCh
 
         debug!("upgraded client consensus state {:?}", consensus_state);
 
-        // Get signer
-        let signer = self.dst_chain.get_signer().map_err(|e| {
-            ForeignClientError::client_upgrade(
-                self.id.clone(),
-                self.dst_chain.id(),
-                "failed while fetching the destination chain signer".to_string(),
-                e,
-            )
-        })?;
-
         let msg_upgrade = MsgUpgradeClient {
             client_id: self.id.clone(),
             client_state: client_state.into(),
@@ -642,6 +651,7 @@ impl<DstChain: ChainHandle, SrcChain: ChainHandle> ForeignClient<DstChain, SrcCh
             )
         })?;
 
+        let wasm_checksum = options.wasm_checksum.clone();
         let settings = ClientSettings::for_create_command(options, &src_config, &dst_config);
 
         let client_state: AnyClientState = self
@@ -670,6 +680,11 @@ impl<DstChain: ChainHandle, SrcChain: ChainHandle> ForeignClient<DstChain, SrcCh
                 )
             })?;
 
+        let (client_state, consensus_state) = match wasm_checksum {
+            Some(checksum) => self.wrap_in_wasm(checksum, client_state, consensus_state)?,
+            None => (client_state, consensus_state),
+        };
+
         //TODO Get acct_prefix
         let msg = MsgCreateClient::new(client_state.into(), consensus_state.into(), signer)
             .map_err(ForeignClientError::client)?;
@@ -677,6 +692,39 @@ impl<DstChain: ChainHandle, SrcChain: ChainHandle> ForeignClient<DstChain, SrcCh
         Ok(msg)
     }
 
+    /// Wraps the Tendermint client and consensus states built by the source
+    /// chain into the states of an `08-wasm` client running the Wasm light
+    /// client contract with the given checksum.
+    fn wrap_in_wasm(
+        &self,
+        checksum: Vec<u8>,
+        client_state: AnyClientState,
+        consensus_state: AnyConsensusState,
+    ) -> Result<(AnyClientState, AnyConsensusState), ForeignClientError> {
+        match (client_state, consensus_state) {
+            (
+                AnyClientState::Tendermint(client_state),
+                AnyConsensusState::Tendermint(consensus_state),
+            ) => {
+                let client_state = WasmClientState::new(checksum, client_state)
+                    .map_err(|e| ForeignClientError::client(e.into()))?;
+
+                Ok((
+                    client_state.into(),
+                    WasmConsensusState::new(consensus_state).into(),
+                ))
+            }
+            (client_state, _) => Err(ForeignClientError::client_create(
+                self.src_chain.id(),
+                "only Tendermint clients can be wrapped into Wasm clients".to_string(),
+                RelayerError::client_type_mismatch(
+                    ClientType::Tendermint,
+                    client_state.client_type(),
+                ),
+            )),
+        }
+    }
+
     /// Returns the identifier of the newly created client.
     pub fn build_create_client_and_send(
         &self,
@@ -906,6 +954,7 @@ impl<DstChain: ChainHandle, SrcChain: ChainHandle> ForeignClient<DstChain, SrcCh
 
         let refresh_rate = match src_config {
             ChainConfig::CosmosSdk(config) => config.client_refresh_rate,
+            ChainConfig::Mock(_) | ChainConfig::SoloMachine(_) => default::client_refresh_rate(),
         };
 
         let refresh_period = client_state
@@ -932,7 +981,29 @@ impl<DstChain: ChainHandle, SrcChain: ChainHandle> ForeignClient<DstChain, SrcCh
         &self,
         target_height: Height,
     ) -> Result<Vec<Any>, ForeignClientError> {
-        self.wait_and_build_update_client_with_trusted(target_height, None)
+        self.wait_and_build_update_client_with_trusted(target_height, None, self.dst_signer()?)
+    }
+
+    /// Same as [`ForeignClient::wait_and_build_update_client`], but with the messages
+    /// signed by the given signer, eg. the wallet selected to relay packets on the
+    /// destination chain, which must sign every message of a transaction.
+    pub fn wait_and_build_update_client_with_signer(
+        &self,
+        target_height: Height,
+        signer: Signer,
+    ) -> Result<Vec<Any>, ForeignClientError> {
+        self.wait_and_build_update_client_with_trusted(target_height, None, signer)
+    }
+
+    /// The signer of the messages sent to the destination chain.
+    fn dst_signer(&self) -> Result<Signer, ForeignClientError> {
+        self.dst_chain().get_signer().map_err(|e| {
+            ForeignClientError::client_update(
+                self.dst_chain.id(),
+                "failed getting signer for dst chain".to_string(),
+                e,
+            )
+        })
     }
 
     /// Returns a trusted height that is lower than the target height, so
@@ -1136,6 +1207,7 @@ impl<DstChain: ChainHandle, SrcChain: ChainHandle> ForeignClient<DstChain, SrcCh
         &self,
         target_height: Height,
         trusted_height: Option<Height>,
+        signer: Signer,
     ) -> Result<Vec<Any>, ForeignClientError> {
         crate::time!(
             "wait_and_build_update_client_with_trusted",
@@ -1195,7 +1267,8 @@ impl<DstChain: ChainHandle, SrcChain: ChainHandle> ForeignClient<DstChain, SrcCh
             }
         }
 
-        let messages = self.build_update_client_with_trusted(target_height, trusted_height)?;
+        let messages =
+            self.build_update_client_with_trusted(target_height, trusted_height, signer)?;
 
         let encoded_messages = messages.into_iter().map(Msg::to_any).collect();
 
@@ -1212,6 +1285,7 @@ impl<DstChain: ChainHandle, SrcChain: ChainHandle> ForeignClient<DstChain, SrcCh
         &self,
         target_height: Height,
         maybe_trusted_height: Option<Height>,
+        signer: Signer,
     ) -> Result<Vec<MsgUpdateClient>, ForeignClientError> {
         // Get the latest client state on destination.
         let (client_state, _) = self.validated_client_state()?;
@@ -1268,14 +1342,6 @@ impl<DstChain: ChainHandle, SrcChain: ChainHandle> ForeignClient<DstChain, SrcCh
                 )
             })?;
 
-        let signer = self.dst_chain().get_signer().map_err(|e| {
-            ForeignClientError::client_update(
-                self.dst_chain.id(),
-                "failed getting signer for dst chain".to_string(),
-                e,
-            )
-        })?;
-
         self.wait_for_header_validation_delay(&client_state, &header)?;
 
         let mut msgs = vec![];
@@ -1287,7 +1353,7 @@ impl<DstChain: ChainHandle, SrcChain: ChainHandle> ForeignClient<DstChain, SrcCh
             );
 
             msgs.push(MsgUpdateClient {
-                header: header.into(),
+                header: client_state.wrap_header(header).into(),
                 client_id: self.id.clone(),
                 signer: signer.clone(),
             });
@@ -1300,7 +1366,7 @@ impl<DstChain: ChainHandle, SrcChain: ChainHandle> ForeignClient<DstChain, SrcCh
         );
 
         msgs.push(MsgUpdateClient {
-            header: header.into(),
+            header: client_state.wrap_header(header).into(),
             signer,
             client_id: self.id.clone(),
         });
@@ -1342,8 +1408,11 @@ impl<DstChain: ChainHandle, SrcChain: ChainHandle> ForeignClient<DstChain, SrcCh
             QueryHeight::Specific(height) => height,
         };
 
-        let new_msgs =
-            self.wait_and_build_update_client_with_trusted(target_height, trusted_height)?;
+        let new_msgs = self.wait_and_build_update_client_with_trusted(
+            target_height,
+            trusted_height,
+            self.dst_signer()?,
+        )?;
 
         if new_msgs.is_empty() {
             return Err(ForeignClientError::client_already_up_to_date(
@@ -1366,7 +1435,11 @@ impl<DstChain: ChainHandle, SrcChain: ChainHandle> ForeignClient<DstChain, SrcCh
                 )
             })?;
 
-        Ok(events.into_iter().map(|ev| ev.event).collect())
+        let events = events.into_iter().map(|ev| ev.event).collect::<Vec<_>>();
+
+        relay::publish_tx_events(&self.dst_chain.id(), &events);
+
+        Ok(events)
     }
 
     /// Attempts to update a client using header from the latest height of its source chain.
@@ -1751,14 +1824,32 @@ impl<DstChain: ChainHandle, SrcChain: ChainHandle> ForeignClient<DstChain, SrcCh
 
         let is_ccv_consumer_chain = match chain_config {
             ChainConfig::CosmosSdk(config) => config.ccv_consumer_chain,
+            ChainConfig::Mock(_) | ChainConfig::SoloMachine(_) => false,
         };
 
+        // The headers and the misbehaviour are wrapped into the messages the client expects
+        let (client_state, _) = self
+            .dst_chain()
+            .query_client_state(
+                QueryClientStateRequest {
+                    client_id: self.id.clone(),
+                    height: QueryHeight::Latest,
+                },
+                IncludeProof::No,
+            )
+            .map_err(|e| {
+                ForeignClientError::misbehaviour(
+                    format!("failed querying client state on dst chain {}", self.id),
+                    e,
+                )
+            })?;
+
         let mut msgs = vec![];
 
         for header in evidence.supporting_headers {
             msgs.push(
                 MsgUpdateClient {
-                    header: header.into(),
+                    header: client_state.wrap_header(header).into(),
                     client_id: self.id.clone(),
                     signer: signer.clone(),
                 }
@@ -1768,7 +1859,6 @@ impl<DstChain: ChainHandle, SrcChain: ChainHandle> ForeignClient<DstChain, SrcCh
 
         let tm_misbehaviour = match &evidence.misbehaviour {
             AnyMisbehaviour::Tendermint(tm_misbehaviour) => Some(tm_misbehaviour.clone()),
-            #[cfg(test)]
             _ => None,
         }
         .ok_or_else(|| {
@@ -1792,7 +1882,7 @@ impl<DstChain: ChainHandle, SrcChain: ChainHandle> ForeignClient<DstChain, SrcCh
 
         msgs.push(
             MsgSubmitMisbehaviour {
-                misbehaviour: evidence.misbehaviour.into(),
+                misbehaviour: client_state.wrap_misbehaviour(evidence.misbehaviour).into(),
                 client_id: self.id.clone(),
                 signer,
             }
@@ -1837,6 +1927,11 @@ impl<DstChain: ChainHandle, SrcChain: ChainHandle> ForeignClient<DstChain, SrcCh
                     "misbehaviour detected, sending evidence"
                 );
 
+                relay::publish(|| RelayEvent::MisbehaviourDetected {
+                    chain_id: self.dst_chain.id(),
+                    client_id: self.id.clone(),
+                });
+
                 telemetry!(
                     client_misbehaviours_submitted,
                     &self.src_chain.id(),
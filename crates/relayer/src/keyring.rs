@@ -2,21 +2,29 @@ pub mod errors;
 pub use any_signing_key_pair::AnySigningKeyPair;
 pub use ed25519_key_pair::Ed25519KeyPair;
 pub use key_type::KeyType;
+pub use remote_signer::{RemoteKey, RemoteSigner, RemoteSignerConfig};
 pub use secp256k1_key_pair::Secp256k1KeyPair;
 pub use signing_key_pair::{SigningKeyPair, SigningKeyPairSized};
 
 mod any_signing_key_pair;
 mod ed25519_key_pair;
+mod jwe;
 mod key_type;
 mod key_utils;
 mod pub_key;
+mod remote_signer;
 mod secp256k1_key_pair;
 mod signing_key_pair;
 
 use alloc::collections::btree_map::BTreeMap as HashMap;
+use core::fmt::{self, Debug};
 use std::ffi::OsStr;
-use std::fs::{self, File};
-use std::path::PathBuf;
+use std::fs::{self, File, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use base64::engine::general_purpose::STANDARD as BASE64;
+use base64::Engine;
 
 use ibc_relayer_types::core::ics24_host::identifier::ChainId;
 use serde::{Deserialize, Serialize};
@@ -26,6 +34,8 @@ use errors::Error;
 pub const KEYSTORE_DEFAULT_FOLDER: &str = ".hermes/keys/";
 pub const KEYSTORE_DISK_BACKEND: &str = "keyring-test";
 pub const KEYSTORE_FILE_EXTENSION: &str = "json";
+pub const KEYSTORE_ENCRYPTED_BACKEND: &str = "keyring-file";
+pub const KEYSTORE_ENCRYPTED_FILE_EXTENSION: &str = "info";
 
 /// JSON key seed file
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
@@ -180,10 +190,260 @@ impl<S: SigningKeyPairSized> KeyStore<S> for Test {
     }
 }
 
+/// Key store backed by files encrypted with a passphrase.
+///
+/// Each key is stored as an item of the `99designs/keyring` file backend, in the
+/// same format as the Cosmos SDK `file` keyring: a JSON item encrypted with
+/// `PBES2-HS256+A128KW` and `A256GCM`, in the JWE compact serialization.
+/// The item data holds the key pair in the Hermes JSON format.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct EncryptedFile {
+    account_prefix: String,
+    store: PathBuf,
+    #[serde(skip)]
+    passphrase: Passphrase,
+}
+
+impl EncryptedFile {
+    pub fn new(account_prefix: String, store: PathBuf, passphrase: Passphrase) -> Self {
+        Self {
+            account_prefix,
+            store,
+            passphrase,
+        }
+    }
+
+    fn key_file(&self, key_name: &str) -> PathBuf {
+        let mut key_file = self.store.join(key_name);
+        key_file.set_extension(KEYSTORE_ENCRYPTED_FILE_EXTENSION);
+        key_file
+    }
+}
+
+/// An item of the `99designs/keyring` file backend.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct EncryptedFileItem {
+    key: String,
+    /// Base64-encoded item data
+    data: String,
+    #[serde(default)]
+    label: String,
+    #[serde(default)]
+    description: String,
+    #[serde(default)]
+    keychain_not_trust_application: bool,
+    #[serde(default)]
+    keychain_not_synchronizable: bool,
+}
+
+impl<S: SigningKeyPairSized> KeyStore<S> for EncryptedFile {
+    fn get_key(&self, key_name: &str) -> Result<S, Error> {
+        let key_file = self.key_file(key_name);
+        let file_path = key_file.display().to_string();
+
+        if !key_file.as_path().exists() {
+            return Err(Error::key_file_not_found(file_path));
+        }
+
+        let token = fs::read_to_string(&key_file).map_err(|e| {
+            Error::key_file_io(file_path.clone(), "failed to read file".to_string(), e)
+        })?;
+
+        let item = jwe::decrypt(&token, self.passphrase.expose())?;
+
+        let item: EncryptedFileItem = serde_json::from_slice(&item)
+            .map_err(|e| Error::key_file_decode(file_path.clone(), e))?;
+
+        let data = BASE64
+            .decode(item.data)
+            .map_err(|e| Error::decryption(format!("invalid item data: {e}")))?;
+
+        serde_json::from_slice(&data).map_err(|e| Error::key_file_decode(file_path, e))
+    }
+
+    fn add_key(&mut self, key_name: &str, key_entry: S) -> Result<(), Error> {
+        let key_file = self.key_file(key_name);
+        let file_path = key_file.display().to_string();
+
+        let data = serde_json::to_vec(&key_entry)
+            .map_err(|e| Error::key_file_encode(file_path.clone(), e))?;
+
+        let item = EncryptedFileItem {
+            key: format!("{key_name}.{KEYSTORE_ENCRYPTED_FILE_EXTENSION}"),
+            data: BASE64.encode(data),
+            label: String::new(),
+            description: String::new(),
+            keychain_not_trust_application: false,
+            keychain_not_synchronizable: false,
+        };
+
+        let item =
+            serde_json::to_vec(&item).map_err(|e| Error::key_file_encode(file_path.clone(), e))?;
+
+        let token = jwe::encrypt(&item, self.passphrase.expose())?;
+
+        let mut file = create_private_file(&key_file).map_err(|e| {
+            Error::key_file_io(file_path.clone(), "failed to create file".to_string(), e)
+        })?;
+
+        file.write_all(token.as_bytes())
+            .map_err(|e| Error::key_file_io(file_path, "failed to write file".to_string(), e))?;
+
+        Ok(())
+    }
+
+    fn remove_key(&mut self, key_name: &str) -> Result<(), Error> {
+        let key_file = self.key_file(key_name);
+
+        fs::remove_file(&key_file)
+            .map_err(|e| Error::remove_io_fail(key_file.display().to_string(), e))?;
+
+        Ok(())
+    }
+
+    fn keys(&self) -> Result<Vec<(String, S)>, Error> {
+        let dir = fs::read_dir(&self.store).map_err(|e| {
+            Error::key_file_io(
+                self.store.display().to_string(),
+                "failed to list keys".to_string(),
+                e,
+            )
+        })?;
+
+        let ext = OsStr::new(KEYSTORE_ENCRYPTED_FILE_EXTENSION);
+
+        dir.into_iter()
+            .flatten()
+            .map(|entry| entry.path())
+            .filter(|path| path.extension() == Some(ext))
+            .flat_map(|path| path.file_stem().map(OsStr::to_owned))
+            .flat_map(|stem| stem.to_str().map(ToString::to_string))
+            .map(|name| self.get_key(&name).map(|key| (name, key)))
+            .collect()
+    }
+}
+
+/// Key store backed by a remote signer, which holds the private keys.
+///
+/// Only the public keys are known to Hermes, sign docs are sent to the signer
+/// for signing. Keys are managed by the signer and cannot be added or removed.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Remote {
+    account_prefix: String,
+    signer: RemoteSignerConfig,
+}
+
+impl Remote {
+    pub fn new(account_prefix: String, signer: RemoteSignerConfig) -> Self {
+        Self {
+            account_prefix,
+            signer,
+        }
+    }
+}
+
+impl<S: SigningKeyPairSized> KeyStore<S> for Remote {
+    fn get_key(&self, key_name: &str) -> Result<S, Error> {
+        let key = remote_signer::get_key(&self.signer, key_name)?;
+        let signer = RemoteSigner::new(self.signer.clone(), key_name.to_string());
+
+        S::from_remote_key(key, signer)
+    }
+
+    fn add_key(&mut self, _key_name: &str, _key_entry: S) -> Result<(), Error> {
+        Err(Error::remote_key_store_read_only())
+    }
+
+    fn remove_key(&mut self, _key_name: &str) -> Result<(), Error> {
+        Err(Error::remote_key_store_read_only())
+    }
+
+    fn keys(&self) -> Result<Vec<(String, S)>, Error> {
+        remote_signer::list_keys(&self.signer)?
+            .into_iter()
+            .map(|key| {
+                let signer = RemoteSigner::new(self.signer.clone(), key.name.clone());
+                let name = key.name.clone();
+
+                S::from_remote_key(key, signer).map(|key| (name, key))
+            })
+            .collect()
+    }
+}
+
+/// Creates a file which is only readable and writable by its owner.
+fn create_private_file(path: &Path) -> std::io::Result<File> {
+    let mut options = OpenOptions::new();
+    options.write(true).create(true).truncate(true);
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::OpenOptionsExt;
+        options.mode(0o600);
+    }
+
+    options.open(path)
+}
+
+/// The passphrase protecting an encrypted key store, which is never displayed.
+#[derive(Clone, Default)]
+pub struct Passphrase(String);
+
+impl Passphrase {
+    pub fn new(passphrase: String) -> Self {
+        Self(passphrase)
+    }
+
+    fn expose(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Debug for Passphrase {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Passphrase(..)")
+    }
+}
+
+/// Where to read the passphrase protecting the `File` key store from.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum KeyStorePassphrase {
+    /// Read the passphrase from the given environment variable.
+    Env(String),
+    /// Read the passphrase from the first line of the given file.
+    File(PathBuf),
+}
+
+impl KeyStorePassphrase {
+    pub fn read(&self) -> Result<Passphrase, Error> {
+        match self {
+            Self::Env(name) => std::env::var(name)
+                .map(Passphrase::new)
+                .map_err(|_| Error::passphrase_env_var(name.clone())),
+            Self::File(path) => {
+                let contents = fs::read_to_string(path).map_err(|e| {
+                    Error::key_file_io(
+                        path.display().to_string(),
+                        "failed to read passphrase file".to_string(),
+                        e,
+                    )
+                })?;
+
+                let passphrase = contents.lines().next().unwrap_or_default();
+                Ok(Passphrase::new(passphrase.to_string()))
+            }
+        }
+    }
+}
+
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum Store {
     Memory,
     Test,
+    File,
+    Remote,
 }
 
 impl Default for Store {
@@ -196,6 +456,8 @@ impl Default for Store {
 pub enum KeyRing<S> {
     Memory(Memory<S>),
     Test(Test),
+    File(EncryptedFile),
+    Remote(Remote),
 }
 
 impl<S: SigningKeyPairSized> KeyRing<S> {
@@ -204,27 +466,52 @@ impl<S: SigningKeyPairSized> KeyRing<S> {
         account_prefix: &str,
         chain_id: &ChainId,
         ks_folder: &Option<PathBuf>,
+        ks_passphrase: &Option<KeyStorePassphrase>,
+        remote_signer: &Option<RemoteSignerConfig>,
     ) -> Result<Self, Error> {
         match store {
             Store::Memory => Ok(Self::Memory(Memory::new(account_prefix.to_string()))),
 
             Store::Test => {
-                let keys_folder = disk_store_path(chain_id.as_str(), ks_folder)?;
+                let keys_folder =
+                    disk_store_path(chain_id.as_str(), ks_folder, KEYSTORE_DISK_BACKEND)?;
 
-                // Create keys folder if it does not exist
-                fs::create_dir_all(&keys_folder).map_err(|e| {
-                    Error::key_file_io(
-                        keys_folder.display().to_string(),
-                        "failed to create keys folder".to_string(),
-                        e,
-                    )
-                })?;
+                create_keys_folder(&keys_folder)?;
 
                 Ok(Self::Test(Test::new(
                     account_prefix.to_string(),
                     keys_folder,
                 )))
             }
+
+            Store::File => {
+                let passphrase = ks_passphrase
+                    .as_ref()
+                    .ok_or_else(Error::missing_passphrase)?
+                    .read()?;
+
+                let keys_folder =
+                    disk_store_path(chain_id.as_str(), ks_folder, KEYSTORE_ENCRYPTED_BACKEND)?;
+
+                create_keys_folder(&keys_folder)?;
+
+                Ok(Self::File(EncryptedFile::new(
+                    account_prefix.to_string(),
+                    keys_folder,
+                    passphrase,
+                )))
+            }
+
+            Store::Remote => {
+                let signer = remote_signer
+                    .clone()
+                    .ok_or_else(Error::missing_remote_signer)?;
+
+                Ok(Self::Remote(Remote::new(
+                    account_prefix.to_string(),
+                    signer,
+                )))
+            }
         }
     }
 
@@ -232,6 +519,8 @@ impl<S: SigningKeyPairSized> KeyRing<S> {
         match self {
             Self::Memory(m) => m.get_key(key_name),
             Self::Test(d) => d.get_key(key_name),
+            Self::File(f) => f.get_key(key_name),
+            Self::Remote(r) => r.get_key(key_name),
         }
     }
 
@@ -239,6 +528,8 @@ impl<S: SigningKeyPairSized> KeyRing<S> {
         match self {
             Self::Memory(m) => m.add_key(key_name, key_entry),
             Self::Test(d) => d.add_key(key_name, key_entry),
+            Self::File(f) => f.add_key(key_name, key_entry),
+            Self::Remote(r) => r.add_key(key_name, key_entry),
         }
     }
 
@@ -246,6 +537,8 @@ impl<S: SigningKeyPairSized> KeyRing<S> {
         match self {
             Self::Memory(m) => m.remove_key(key_name),
             Self::Test(d) => <Test as KeyStore<S>>::remove_key(d, key_name),
+            Self::File(f) => <EncryptedFile as KeyStore<S>>::remove_key(f, key_name),
+            Self::Remote(r) => <Remote as KeyStore<S>>::remove_key(r, key_name),
         }
     }
 
@@ -253,6 +546,8 @@ impl<S: SigningKeyPairSized> KeyRing<S> {
         match self {
             Self::Memory(m) => m.keys(),
             Self::Test(d) => d.keys(),
+            Self::File(f) => f.keys(),
+            Self::Remote(r) => r.keys(),
         }
     }
 
@@ -260,6 +555,8 @@ impl<S: SigningKeyPairSized> KeyRing<S> {
         match self {
             Self::Memory(m) => &m.account_prefix,
             Self::Test(d) => &d.account_prefix,
+            Self::File(f) => &f.account_prefix,
+            Self::Remote(r) => &r.account_prefix,
         }
     }
 }
@@ -270,8 +567,17 @@ impl KeyRing<Secp256k1KeyPair> {
         account_prefix: &str,
         chain_id: &ChainId,
         ks_folder: &Option<PathBuf>,
+        ks_passphrase: &Option<KeyStorePassphrase>,
+        remote_signer: &Option<RemoteSignerConfig>,
     ) -> Result<Self, Error> {
-        Self::new(store, account_prefix, chain_id, ks_folder)
+        Self::new(
+            store,
+            account_prefix,
+            chain_id,
+            ks_folder,
+            ks_passphrase,
+            remote_signer,
+        )
     }
 }
 
@@ -281,14 +587,27 @@ impl KeyRing<Ed25519KeyPair> {
         account_prefix: &str,
         chain_id: &ChainId,
         ks_folder: &Option<PathBuf>,
+        ks_passphrase: &Option<KeyStorePassphrase>,
+        remote_signer: &Option<RemoteSignerConfig>,
     ) -> Result<Self, Error> {
-        Self::new(store, account_prefix, chain_id, ks_folder)
+        Self::new(
+            store,
+            account_prefix,
+            chain_id,
+            ks_folder,
+            ks_passphrase,
+            remote_signer,
+        )
     }
 }
 
 // Why is this not a method on `ChainConfig`?
 
-fn disk_store_path(folder_name: &str, keystore_folder: &Option<PathBuf>) -> Result<PathBuf, Error> {
+fn disk_store_path(
+    folder_name: &str,
+    keystore_folder: &Option<PathBuf>,
+    backend: &str,
+) -> Result<PathBuf, Error> {
     let ks_folder = match keystore_folder {
         Some(folder) => folder.to_owned(),
         None => {
@@ -297,7 +616,271 @@ fn disk_store_path(folder_name: &str, keystore_folder: &Option<PathBuf>) -> Resu
         }
     };
 
-    let folder = ks_folder.join(folder_name).join(KEYSTORE_DISK_BACKEND);
+    let folder = ks_folder.join(folder_name).join(backend);
 
     Ok(folder)
 }
+
+/// Creates the keys folder if it does not exist
+fn create_keys_folder(keys_folder: &Path) -> Result<(), Error> {
+    fs::create_dir_all(keys_folder).map_err(|e| {
+        Error::key_file_io(
+            keys_folder.display().to_string(),
+            "failed to create keys folder".to_string(),
+            e,
+        )
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use core::str::FromStr;
+
+    use hdpath::StandardHDPath;
+
+    use crate::config::AddressType;
+
+    const MNEMONIC: &str = "abandon abandon abandon abandon abandon abandon \
+        abandon abandon abandon abandon abandon about";
+
+    #[test]
+    fn encrypted_file_store() {
+        let folder = std::env::temp_dir().join(format!("hermes-keyring-{}", std::process::id()));
+        let passphrase_file = folder.join("passphrase");
+
+        fs::create_dir_all(&folder).unwrap();
+        fs::write(&passphrase_file, "correct horse battery staple\n").unwrap();
+
+        let chain_id = ChainId::from_string("chain-a");
+        let passphrase = Some(KeyStorePassphrase::File(passphrase_file));
+
+        let mut keyring = KeyRing::new_secp256k1(
+            Store::File,
+            "cosmos",
+            &chain_id,
+            &Some(folder.clone()),
+            &passphrase,
+            &None,
+        )
+        .unwrap();
+
+        let hd_path = StandardHDPath::from_str("m/44'/118'/0'/0/0").unwrap();
+        let key_pair =
+            Secp256k1KeyPair::from_mnemonic(MNEMONIC, &hd_path, &AddressType::Cosmos, "cosmos")
+                .unwrap();
+
+        keyring.add_key("relayer", key_pair.clone()).unwrap();
+
+        // The key file does not contain the mnemonic-derived key in plaintext
+        let key_file = folder
+            .join(chain_id.as_str())
+            .join(KEYSTORE_ENCRYPTED_BACKEND)
+            .join("relayer.info");
+        let contents = fs::read_to_string(key_file).unwrap();
+        assert!(!contents.contains(&key_pair.account()));
+
+        assert_eq!(
+            keyring.get_key("relayer").unwrap().account(),
+            key_pair.account()
+        );
+
+        let keys = keyring.keys().unwrap();
+        assert_eq!(keys.len(), 1);
+        assert_eq!(keys[0].0, "relayer");
+
+        // A keyring opened with the wrong passphrase cannot read the key
+        let wrong_passphrase_file = folder.join("wrong_passphrase");
+        fs::write(&wrong_passphrase_file, "wrong").unwrap();
+
+        let wrong_keyring = KeyRing::<Secp256k1KeyPair>::new_secp256k1(
+            Store::File,
+            "cosmos",
+            &chain_id,
+            &Some(folder.clone()),
+            &Some(KeyStorePassphrase::File(wrong_passphrase_file)),
+            &None,
+        )
+        .unwrap();
+        assert!(wrong_keyring.get_key("relayer").is_err());
+
+        keyring.remove_key("relayer").unwrap();
+        assert!(keyring.get_key("relayer").is_err());
+
+        fs::remove_dir_all(&folder).unwrap();
+    }
+
+    #[test]
+    fn encrypted_file_store_requires_passphrase() {
+        let result = KeyRing::<Secp256k1KeyPair>::new_secp256k1(
+            Store::File,
+            "cosmos",
+            &ChainId::from_string("chain-a"),
+            &Some(std::env::temp_dir()),
+            &None,
+            &None,
+        );
+
+        assert!(result.is_err());
+    }
+
+    /// Serves the remote signer API, signing with the given key pairs.
+    fn mock_signer(
+        key_pair: Secp256k1KeyPair,
+        signing_key_pair: Secp256k1KeyPair,
+    ) -> RemoteSignerConfig {
+        use std::io::{BufRead, BufReader, Read};
+        use std::net::TcpListener;
+
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+
+        std::thread::spawn(move || {
+            for stream in listener.incoming() {
+                let mut stream = stream.unwrap();
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+
+                let mut request_line = String::new();
+                reader.read_line(&mut request_line).unwrap();
+
+                let mut content_length = 0;
+                loop {
+                    let mut header = String::new();
+                    reader.read_line(&mut header).unwrap();
+                    if header.trim().is_empty() {
+                        break;
+                    }
+                    if let Some((name, value)) = header.split_once(':') {
+                        if name.eq_ignore_ascii_case("content-length") {
+                            content_length = value.trim().parse().unwrap();
+                        }
+                    }
+                }
+
+                let mut body = vec![0; content_length];
+                reader.read_exact(&mut body).unwrap();
+
+                let key = serde_json::json!({
+                    "name": "relayer",
+                    "type": "local",
+                    "address": key_pair.account(),
+                    "pubkey": serde_json::json!({
+                        "@type": "/cosmos.crypto.secp256k1.PubKey",
+                        "key": BASE64.encode(key_pair.public_key.serialize()),
+                    }).to_string(),
+                });
+
+                let response = match request_line.split_whitespace().nth(1).unwrap() {
+                    "/keys" => serde_json::json!({ "keys": [key] }),
+                    "/keys/relayer" => key,
+                    "/keys/relayer/sign" => {
+                        let request: serde_json::Value = serde_json::from_slice(&body).unwrap();
+                        let sign_doc = BASE64
+                            .decode(request["sign_doc"].as_str().unwrap())
+                            .unwrap();
+                        let signature = signing_key_pair.sign(&sign_doc).unwrap();
+                        serde_json::json!({ "signature": BASE64.encode(signature) })
+                    }
+                    _ => {
+                        write!(stream, "HTTP/1.1 404 Not Found\r\nContent-Length: 0\r\nConnection: close\r\n\r\n").unwrap();
+                        continue;
+                    }
+                };
+
+                let response = response.to_string();
+                write!(
+                    stream,
+                    "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    response.len(),
+                    response
+                )
+                .unwrap();
+            }
+        });
+
+        RemoteSignerConfig {
+            url: format!("http://{addr}").parse().unwrap(),
+            timeout: core::time::Duration::from_secs(5),
+        }
+    }
+
+    fn key_pair_from_mnemonic(mnemonic: &str) -> Secp256k1KeyPair {
+        let hd_path = StandardHDPath::from_str("m/44'/118'/0'/0/0").unwrap();
+        Secp256k1KeyPair::from_mnemonic(mnemonic, &hd_path, &AddressType::Cosmos, "cosmos").unwrap()
+    }
+
+    #[test]
+    fn remote_store() {
+        let key_pair = key_pair_from_mnemonic(MNEMONIC);
+        let signer = mock_signer(key_pair.clone(), key_pair.clone());
+
+        let mut keyring = KeyRing::new_secp256k1(
+            Store::Remote,
+            "cosmos",
+            &ChainId::from_string("chain-a"),
+            &None,
+            &None,
+            &Some(signer),
+        )
+        .unwrap();
+
+        let remote_key_pair = keyring.get_key("relayer").unwrap();
+        assert_eq!(remote_key_pair.account(), key_pair.account());
+        assert_eq!(remote_key_pair.public_key, key_pair.public_key);
+
+        // Signatures are deterministic, so both key pairs produce the same one
+        let sign_doc = b"sign doc";
+        assert_eq!(
+            remote_key_pair.sign(sign_doc).unwrap(),
+            key_pair.sign(sign_doc).unwrap()
+        );
+
+        // The private key never leaves the signer
+        let serialized = serde_json::to_value(&remote_key_pair).unwrap();
+        assert!(serialized.get("private_key").is_none());
+
+        let keys = keyring.keys().unwrap();
+        assert_eq!(keys.len(), 1);
+        assert_eq!(keys[0].0, "relayer");
+
+        assert!(keyring.get_key("unknown").is_err());
+        assert!(keyring.add_key("other", key_pair).is_err());
+        assert!(keyring.remove_key("relayer").is_err());
+    }
+
+    #[test]
+    fn remote_store_rejects_signature_from_other_key() {
+        let key_pair = key_pair_from_mnemonic(MNEMONIC);
+        let other_key_pair =
+            key_pair_from_mnemonic("zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo wrong");
+        let signer = mock_signer(key_pair, other_key_pair);
+
+        let keyring = KeyRing::new_secp256k1(
+            Store::Remote,
+            "cosmos",
+            &ChainId::from_string("chain-a"),
+            &None,
+            &None,
+            &Some(signer),
+        )
+        .unwrap();
+
+        let remote_key_pair = keyring.get_key("relayer").unwrap();
+        assert!(remote_key_pair.sign(b"sign doc").is_err());
+    }
+
+    #[test]
+    fn remote_store_requires_signer() {
+        let result = KeyRing::<Secp256k1KeyPair>::new_secp256k1(
+            Store::Remote,
+            "cosmos",
+            &ChainId::from_string("chain-a"),
+            &None,
+            &None,
+            &None,
+        );
+
+        assert!(result.is_err());
+    }
+}
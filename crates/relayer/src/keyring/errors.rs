@@ -149,6 +149,39 @@ define_error! {
                 format!("Unsupported address type {} for key type {}", e.address_type, e.key_type)
             },
 
+        Encryption
+            { reason: String }
+            |e| { format!("failed to encrypt key: {}", e.reason) },
+
+        Decryption
+            { reason: String }
+            |e| { format!("failed to decrypt key: {}", e.reason) },
+
+        MissingPassphrase
+            |_| { "the `File` key store requires a passphrase, set `key_store_passphrase` in the chain configuration" },
+
+        PassphraseEnvVar
+            { name: String }
+            |e| { format!("cannot read the key store passphrase from environment variable '{}'", e.name) },
+
+        MissingRemoteSigner
+            |_| { "the `Remote` key store requires a signer, set `remote_signer` in the chain configuration" },
+
+        RemoteKeyStoreReadOnly
+            |_| { "keys of the `Remote` key store are managed by the remote signer and cannot be added or removed" },
+
+        RemoteSigner
+            { url: String, reason: String }
+            |e| { format!("remote signer at '{}' failed: {}", e.url, e.reason) },
+
+        RemoteSignerInvalidSignature
+            { key_name: String }
+            |e| { format!("remote signer returned an invalid signature for key '{}'", e.key_name) },
+
+        RemoteSignerUnsupportedKeyType
+            { key_type: KeyType }
+            |e| { format!("remote signer does not support {} keys", e.key_type) },
+
         InvalidPublicKeyLength
             {
                 got: usize,
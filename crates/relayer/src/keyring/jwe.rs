@@ -0,0 +1,248 @@
+//! Password-based JSON Web Encryption, as used by the Cosmos SDK `file` keyring backend.
+//!
+//! Payloads are encrypted with `A256GCM` under a random content encryption key,
+//! which is itself wrapped with `PBES2-HS256+A128KW`, and the result is serialized
+//! in the JWE compact serialization format.
+
+use aes_gcm::aead::{rand_core::RngCore, Aead, KeyInit, OsRng, Payload};
+use aes_gcm::{Aes256Gcm, Nonce};
+use aes_kw::KekAes128;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use serde::{Deserialize, Serialize};
+use sha2::Sha256;
+
+use super::errors::Error;
+
+const ALG: &str = "PBES2-HS256+A128KW";
+const ENC: &str = "A256GCM";
+
+/// Number of PBKDF2 iterations used when encrypting, same as the Cosmos SDK.
+const ITERATIONS: u32 = 8192;
+
+/// Bounds on the number of PBKDF2 iterations accepted when decrypting.
+const MIN_ITERATIONS: u32 = 1000;
+const MAX_ITERATIONS: u32 = 1_000_000;
+
+const SALT_LEN: usize = 12;
+const CEK_LEN: usize = 32;
+const WRAPPED_CEK_LEN: usize = CEK_LEN + 8;
+const IV_LEN: usize = 12;
+const TAG_LEN: usize = 16;
+
+#[derive(Debug, Serialize, Deserialize)]
+struct Header {
+    alg: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    created: Option<String>,
+    enc: String,
+    p2c: u32,
+    p2s: String,
+}
+
+/// Encrypts the given payload with the given password.
+pub fn encrypt(payload: &[u8], password: &str) -> Result<String, Error> {
+    let mut salt = [0u8; SALT_LEN];
+    let mut cek = [0u8; CEK_LEN];
+    let mut iv = [0u8; IV_LEN];
+
+    OsRng.fill_bytes(&mut salt);
+    OsRng.fill_bytes(&mut cek);
+    OsRng.fill_bytes(&mut iv);
+
+    let header = Header {
+        alg: ALG.to_string(),
+        created: Some(humantime::format_rfc3339_seconds(std::time::SystemTime::now()).to_string()),
+        enc: ENC.to_string(),
+        p2c: ITERATIONS,
+        p2s: URL_SAFE_NO_PAD.encode(salt),
+    };
+
+    let header = serde_json::to_vec(&header).map_err(Error::encode)?;
+    let header = URL_SAFE_NO_PAD.encode(header);
+
+    let kek = derive_kek(password, &salt, ITERATIONS);
+
+    let mut wrapped_cek = [0u8; WRAPPED_CEK_LEN];
+    KekAes128::from(kek)
+        .wrap(&cek, &mut wrapped_cek)
+        .map_err(|e| Error::encryption(e.to_string()))?;
+
+    let cipher = Aes256Gcm::new(&cek.into());
+    let mut ciphertext = cipher
+        .encrypt(
+            Nonce::from_slice(&iv),
+            Payload {
+                msg: payload,
+                aad: header.as_bytes(),
+            },
+        )
+        .map_err(|e| Error::encryption(e.to_string()))?;
+
+    let tag = ciphertext.split_off(ciphertext.len() - TAG_LEN);
+
+    Ok(format!(
+        "{}.{}.{}.{}.{}",
+        header,
+        URL_SAFE_NO_PAD.encode(wrapped_cek),
+        URL_SAFE_NO_PAD.encode(iv),
+        URL_SAFE_NO_PAD.encode(ciphertext),
+        URL_SAFE_NO_PAD.encode(tag),
+    ))
+}
+
+/// Decrypts the given JWE compact serialization with the given password.
+pub fn decrypt(token: &str, password: &str) -> Result<Vec<u8>, Error> {
+    let parts: Vec<&str> = token.trim().split('.').collect();
+
+    let [header_b64, wrapped_cek, iv, ciphertext, tag] = parts[..] else {
+        return Err(Error::decryption(
+            "invalid JWE compact serialization".to_string(),
+        ));
+    };
+
+    let header: Header = serde_json::from_slice(&decode(header_b64)?)
+        .map_err(|e| Error::decryption(format!("invalid JWE header: {e}")))?;
+
+    if header.alg != ALG || header.enc != ENC {
+        return Err(Error::decryption(format!(
+            "unsupported algorithms '{}' and '{}', expected '{}' and '{}'",
+            header.alg, header.enc, ALG, ENC
+        )));
+    }
+
+    if !(MIN_ITERATIONS..=MAX_ITERATIONS).contains(&header.p2c) {
+        return Err(Error::decryption(format!(
+            "invalid PBES2 iteration count {}",
+            header.p2c
+        )));
+    }
+
+    let kek = derive_kek(password, &decode(&header.p2s)?, header.p2c);
+
+    let wrapped_cek = decode(wrapped_cek)?;
+    if wrapped_cek.len() != WRAPPED_CEK_LEN {
+        return Err(Error::decryption(
+            "invalid encrypted key length".to_string(),
+        ));
+    }
+
+    let mut cek = [0u8; CEK_LEN];
+    KekAes128::from(kek)
+        .unwrap(&wrapped_cek, &mut cek)
+        .map_err(|_| Error::decryption("wrong passphrase".to_string()))?;
+
+    let iv = decode(iv)?;
+    if iv.len() != IV_LEN {
+        return Err(Error::decryption(
+            "invalid initialization vector length".to_string(),
+        ));
+    }
+
+    let mut msg = decode(ciphertext)?;
+    msg.extend(decode(tag)?);
+
+    let cipher = Aes256Gcm::new(&cek.into());
+    cipher
+        .decrypt(
+            Nonce::from_slice(&iv),
+            Payload {
+                msg: &msg,
+                aad: header_b64.as_bytes(),
+            },
+        )
+        .map_err(|_| Error::decryption("authentication tag mismatch".to_string()))
+}
+
+/// Derives the key encryption key from the password, as specified in RFC 7518, section 4.8.
+fn derive_kek(password: &str, salt: &[u8], iterations: u32) -> [u8; 16] {
+    let mut salt_input = Vec::with_capacity(ALG.len() + 1 + salt.len());
+    salt_input.extend_from_slice(ALG.as_bytes());
+    salt_input.push(0);
+    salt_input.extend_from_slice(salt);
+
+    let mut kek = [0u8; 16];
+    pbkdf2::pbkdf2_hmac::<Sha256>(password.as_bytes(), &salt_input, iterations, &mut kek);
+    kek
+}
+
+fn decode(input: &str) -> Result<Vec<u8>, Error> {
+    URL_SAFE_NO_PAD
+        .decode(input)
+        .map_err(|e| Error::decryption(e.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn encrypt_decrypt_roundtrip() {
+        let payload = br#"{"key":"value"}"#;
+        let token = encrypt(payload, "passphrase").unwrap();
+
+        assert_eq!(token.split('.').count(), 5);
+        assert_eq!(decrypt(&token, "passphrase").unwrap(), payload);
+    }
+
+    #[test]
+    fn decrypt_with_wrong_passphrase_fails() {
+        let token = encrypt(b"secret", "passphrase").unwrap();
+
+        assert!(decrypt(&token, "wrong").is_err());
+    }
+
+    #[test]
+    fn decrypt_tampered_token_fails() {
+        let token = encrypt(b"secret", "passphrase").unwrap();
+
+        let mut parts: Vec<String> = token.split('.').map(String::from).collect();
+        parts[3] = URL_SAFE_NO_PAD.encode(b"tampered");
+
+        assert!(decrypt(&parts.join("."), "passphrase").is_err());
+    }
+
+    #[test]
+    fn decrypt_with_other_iteration_count() {
+        // Tokens produced by other implementations may use a different number of iterations
+        let salt = [7u8; SALT_LEN];
+        let header = Header {
+            alg: ALG.to_string(),
+            created: None,
+            enc: ENC.to_string(),
+            p2c: 4096,
+            p2s: URL_SAFE_NO_PAD.encode(salt),
+        };
+        let header = URL_SAFE_NO_PAD.encode(serde_json::to_vec(&header).unwrap());
+
+        let cek = [1u8; CEK_LEN];
+        let iv = [2u8; IV_LEN];
+
+        let mut wrapped_cek = [0u8; WRAPPED_CEK_LEN];
+        KekAes128::from(derive_kek("passphrase", &salt, 4096))
+            .wrap(&cek, &mut wrapped_cek)
+            .unwrap();
+
+        let mut ciphertext = Aes256Gcm::new(&cek.into())
+            .encrypt(
+                Nonce::from_slice(&iv),
+                Payload {
+                    msg: b"payload",
+                    aad: header.as_bytes(),
+                },
+            )
+            .unwrap();
+        let tag = ciphertext.split_off(ciphertext.len() - TAG_LEN);
+
+        let token = [
+            header,
+            URL_SAFE_NO_PAD.encode(wrapped_cek),
+            URL_SAFE_NO_PAD.encode(iv),
+            URL_SAFE_NO_PAD.encode(ciphertext),
+            URL_SAFE_NO_PAD.encode(tag),
+        ]
+        .join(".");
+
+        assert_eq!(decrypt(&token, "passphrase").unwrap(), b"payload");
+    }
+}
@@ -0,0 +1,168 @@
+//! Client for an external signer, which holds private keys on behalf of Hermes.
+//!
+//! The signer is expected to expose the following HTTP endpoints:
+//!
+//! - `GET /keys`, which lists the available keys as `{ "keys": [<key>, ...] }`;
+//! - `GET /keys/<name>`, which returns a single key, in the same format as the
+//!   `keys show --output json` command of Cosmos SDK chain binaries, i.e.
+//!   `{ "name": .., "type": .., "address": .., "pubkey": .. }`;
+//! - `POST /keys/<name>/sign`, which takes `{ "sign_doc": <base64> }` and
+//!   returns `{ "signature": <base64> }`.
+//!
+//! The signature must be a 64-byte compact secp256k1 signature over the
+//! SHA-256 digest of the sign doc, or over its Keccak-256 digest for
+//! Ethermint-based chains.
+
+use core::time::Duration;
+
+use base64::engine::general_purpose::STANDARD as BASE64;
+use base64::Engine;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use tendermint_rpc::Url;
+
+use super::errors::Error;
+
+/// Configuration of the remote signer used by the `Remote` key store.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct RemoteSignerConfig {
+    /// Base URL of the signer
+    pub url: Url,
+
+    /// Timeout for requests to the signer
+    #[serde(default = "default_timeout", with = "humantime_serde")]
+    pub timeout: Duration,
+}
+
+fn default_timeout() -> Duration {
+    Duration::from_secs(10)
+}
+
+/// A key held by a remote signer.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RemoteKey {
+    pub name: String,
+    pub r#type: String,
+    pub address: String,
+    pub pubkey: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct KeysResponse {
+    keys: Vec<RemoteKey>,
+}
+
+#[derive(Debug, Serialize)]
+struct SignRequest {
+    sign_doc: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct SignResponse {
+    signature: String,
+}
+
+/// Signs messages with a single key held by a remote signer.
+#[derive(Clone, Debug)]
+pub struct RemoteSigner {
+    config: RemoteSignerConfig,
+    key_name: String,
+}
+
+impl RemoteSigner {
+    pub fn new(config: RemoteSignerConfig, key_name: String) -> Self {
+        Self { config, key_name }
+    }
+
+    pub fn key_name(&self) -> &str {
+        &self.key_name
+    }
+
+    pub fn url(&self) -> String {
+        self.config.url.to_string()
+    }
+
+    /// Returns the signature of the given sign doc, as computed by the signer.
+    pub fn sign(&self, sign_doc: &[u8]) -> Result<Vec<u8>, Error> {
+        let request = SignRequest {
+            sign_doc: BASE64.encode(sign_doc),
+        };
+
+        let response: SignResponse = post(
+            &self.config,
+            &format!("keys/{}/sign", self.key_name),
+            &request,
+        )?;
+
+        BASE64.decode(response.signature).map_err(|e| {
+            Error::remote_signer(self.url(), format!("invalid signature encoding: {e}"))
+        })
+    }
+}
+
+/// Fetches the key with the given name from the signer.
+pub fn get_key(config: &RemoteSignerConfig, key_name: &str) -> Result<RemoteKey, Error> {
+    get(config, &format!("keys/{key_name}"))
+}
+
+/// Fetches all keys available from the signer.
+pub fn list_keys(config: &RemoteSignerConfig) -> Result<Vec<RemoteKey>, Error> {
+    get::<KeysResponse>(config, "keys").map(|response| response.keys)
+}
+
+fn get<T>(config: &RemoteSignerConfig, path: &str) -> Result<T, Error>
+where
+    T: DeserializeOwned + Send,
+{
+    request(config, |client, url| client.get(url), path)
+}
+
+fn post<B, T>(config: &RemoteSignerConfig, path: &str, body: &B) -> Result<T, Error>
+where
+    B: Serialize + Sync,
+    T: DeserializeOwned + Send,
+{
+    request(config, |client, url| client.post(url).json(body), path)
+}
+
+/// Performs a request to the signer and decodes its JSON response.
+///
+/// Signing happens from within the async runtime of the chain, in which the blocking
+/// client cannot be used, so the request is performed on a separate thread.
+fn request<F, T>(config: &RemoteSignerConfig, build: F, path: &str) -> Result<T, Error>
+where
+    F: Fn(&reqwest::blocking::Client, String) -> reqwest::blocking::RequestBuilder + Sync,
+    T: DeserializeOwned + Send,
+{
+    let url = format!("{}/{}", config.url.to_string().trim_end_matches('/'), path);
+    let signer_error = |reason: String| Error::remote_signer(config.url.to_string(), reason);
+
+    std::thread::scope(|scope| {
+        scope
+            .spawn(|| {
+                let client = reqwest::blocking::Client::builder()
+                    .timeout(config.timeout)
+                    .build()
+                    .map_err(|e| signer_error(e.to_string()))?;
+
+                let response = build(&client, url)
+                    .send()
+                    .map_err(|e| signer_error(e.to_string()))?;
+
+                let status = response.status();
+                if !status.is_success() {
+                    let body = response.text().unwrap_or_default();
+                    return Err(signer_error(format!(
+                        "request to '{path}' failed with status {status}: {body}"
+                    )));
+                }
+
+                response
+                    .json()
+                    .map_err(|e| signer_error(format!("invalid response to '{path}': {e}")))
+            })
+            .join()
+            .unwrap_or_else(|_| Err(signer_error("request thread panicked".to_string())))
+    })
+}
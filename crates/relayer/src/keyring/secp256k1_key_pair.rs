@@ -9,8 +9,8 @@ use digest::Digest;
 use generic_array::{typenum::U32, GenericArray};
 use hdpath::StandardHDPath;
 use ripemd::Ripemd160;
-use secp256k1::{Message, PublicKey, Secp256k1, SecretKey};
-use serde::{Deserialize, Serialize};
+use secp256k1::{ecdsa::Signature, Message, PublicKey, Secp256k1, SecretKey};
+use serde::{Deserialize, Serialize, Serializer};
 use sha2::Sha256;
 use strum::{EnumIter, IntoEnumIterator};
 
@@ -18,6 +18,7 @@ use super::{
     errors::Error,
     key_utils::{decode_bech32, encode_bech32, keccak256_hash},
     pub_key::EncodedPubKey,
+    remote_signer::{RemoteKey, RemoteSigner},
     KeyFile, KeyType, SigningKeyPair,
 };
 use crate::config::AddressType;
@@ -134,13 +135,39 @@ fn encode_address(account_prefix: &str, address: &[u8]) -> Result<String, Error>
 #[derive(Clone, Debug, Deserialize, Serialize)]
 #[serde(try_from = "VersionedKeyPair")]
 pub struct Secp256k1KeyPair {
-    private_key: SecretKey,
+    #[serde(skip_serializing_if = "PrivateKey::is_remote")]
+    private_key: PrivateKey,
     pub public_key: PublicKey,
     address: [u8; 20],
     address_type: Secp256k1AddressType,
     account: String,
 }
 
+/// The private key of a key pair, which is either held by Hermes or by a remote signer.
+///
+/// Key pairs backed by a remote signer are serialized without their private key,
+/// and can therefore not be deserialized back.
+#[derive(Clone, Debug)]
+enum PrivateKey {
+    Local(SecretKey),
+    Remote(RemoteSigner),
+}
+
+impl PrivateKey {
+    fn is_remote(&self) -> bool {
+        matches!(self, Self::Remote(_))
+    }
+}
+
+impl Serialize for PrivateKey {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::Local(secret_key) => secret_key.serialize(serializer),
+            Self::Remote(_) => serializer.serialize_none(),
+        }
+    }
+}
+
 // The old `KeyEntry` type
 #[derive(Debug, Deserialize)]
 struct KeyPairV1 {
@@ -184,7 +211,7 @@ impl TryFrom<VersionedKeyPair> for Secp256k1KeyPair {
                     .map_err(|address_bytes| Error::invalid_address_length(address_bytes, 20))?;
                 let address_type = Secp256k1AddressType::derive(&public_key.public_key, &address)?;
                 Ok(Self {
-                    private_key: private_key.private_key,
+                    private_key: PrivateKey::Local(private_key.private_key),
                     public_key: public_key.public_key,
                     address,
                     address_type,
@@ -198,7 +225,7 @@ impl TryFrom<VersionedKeyPair> for Secp256k1KeyPair {
                 address_type,
                 account,
             }) => Ok(Self {
-                private_key,
+                private_key: PrivateKey::Local(private_key),
                 public_key,
                 address,
                 address_type,
@@ -221,7 +248,7 @@ impl Secp256k1KeyPair {
         let account = encode_address(account_prefix, &address)?;
 
         Ok(Self {
-            private_key: private_key.private_key,
+            private_key: PrivateKey::Local(private_key.private_key),
             public_key: public_key.public_key,
             address,
             address_type,
@@ -268,7 +295,7 @@ impl SigningKeyPair for Secp256k1KeyPair {
         let address_type = Secp256k1AddressType::derive(&derived_pubkey.public_key, &address)?;
 
         Ok(Self {
-            private_key: private_key.private_key,
+            private_key: PrivateKey::Local(private_key.private_key),
             public_key: derived_pubkey.public_key,
             address,
             address_type,
@@ -285,6 +312,39 @@ impl SigningKeyPair for Secp256k1KeyPair {
         Self::from_mnemonic_internal(mnemonic, hd_path, address_type.try_into()?, account_prefix)
     }
 
+    fn from_remote_key(key: RemoteKey, signer: RemoteSigner) -> Result<Self, Error> {
+        let address_bytes = decode_bech32(&key.address)?;
+
+        let encoded_key: EncodedPubKey = key.pubkey.parse()?;
+        let mut public_key_bytes = encoded_key.into_bytes();
+
+        // Keep only the compressed public key, see `from_key_file`
+        let public_key_bytes = public_key_bytes.split_off(
+            public_key_bytes
+                .len()
+                .saturating_sub(secp256k1::constants::PUBLIC_KEY_SIZE),
+        );
+        let public_key = PublicKey::from_slice(&public_key_bytes).map_err(|e| {
+            Error::remote_signer(
+                signer.url(),
+                format!("invalid public key for key '{}': {e}", key.name),
+            )
+        })?;
+
+        let address: [u8; 20] = address_bytes
+            .try_into()
+            .map_err(|address_bytes| Error::invalid_address_length(address_bytes, 20))?;
+        let address_type = Secp256k1AddressType::derive(&public_key, &address)?;
+
+        Ok(Self {
+            private_key: PrivateKey::Remote(signer),
+            public_key,
+            address,
+            address_type,
+            account: key.address,
+        })
+    }
+
     fn account(&self) -> String {
         self.account.to_owned()
     }
@@ -296,10 +356,10 @@ impl SigningKeyPair for Secp256k1KeyPair {
     // Ethermint:
     // - https://github.com/evmos/ethermint/blob/main/crypto/ethsecp256k1/ethsecp256k1.go
     // - informalsystems/hermes#2863.
-    fn sign(&self, message: &[u8]) -> Result<Vec<u8>, Error> {
+    fn sign(&self, message_bytes: &[u8]) -> Result<Vec<u8>, Error> {
         let hashed_message: GenericArray<u8, U32> = match self.address_type {
-            Secp256k1AddressType::Ethermint => keccak256_hash(message).into(),
-            Secp256k1AddressType::Cosmos => Sha256::digest(message),
+            Secp256k1AddressType::Ethermint => keccak256_hash(message_bytes).into(),
+            Secp256k1AddressType::Cosmos => Sha256::digest(message_bytes),
         };
 
         assert!(hashed_message.len() == 32);
@@ -307,10 +367,31 @@ impl SigningKeyPair for Secp256k1KeyPair {
         // SAFETY: hashed_message is 32 bytes, as expected in `Message::from_slice`.
         let message = Message::from_digest_slice(&hashed_message).unwrap();
 
-        Ok(Secp256k1::signing_only()
-            .sign_ecdsa(&message, &self.private_key)
-            .serialize_compact()
-            .to_vec())
+        match &self.private_key {
+            PrivateKey::Local(private_key) => Ok(Secp256k1::signing_only()
+                .sign_ecdsa(&message, private_key)
+                .serialize_compact()
+                .to_vec()),
+
+            PrivateKey::Remote(signer) => {
+                let invalid_signature =
+                    || Error::remote_signer_invalid_signature(signer.key_name().to_string());
+
+                let mut signature = signer.sign(message_bytes).and_then(|bytes| {
+                    Signature::from_compact(&bytes).map_err(|_| invalid_signature())
+                })?;
+
+                // Chains only accept signatures in lower-S form
+                signature.normalize_s();
+
+                // Make sure the signer used the expected key and digest
+                Secp256k1::verification_only()
+                    .verify_ecdsa(&message, &signature, &self.public_key)
+                    .map_err(|_| invalid_signature())?;
+
+                Ok(signature.serialize_compact().to_vec())
+            }
+        }
     }
 
     fn as_any(&self) -> &dyn Any {
@@ -3,7 +3,11 @@ use core::any::Any;
 use hdpath::StandardHDPath;
 use serde::{de::DeserializeOwned, Serialize};
 
-use super::{errors::Error, KeyType};
+use super::{
+    errors::Error,
+    remote_signer::{RemoteKey, RemoteSigner},
+    KeyType,
+};
 use crate::config::AddressType;
 
 pub trait SigningKeyPair {
@@ -31,6 +35,15 @@ pub trait SigningKeyPair {
     where
         Self: Sized;
 
+    /// Builds a key pair whose private key is held by the given remote signer.
+    fn from_remote_key(key: RemoteKey, signer: RemoteSigner) -> Result<Self, Error>
+    where
+        Self: Sized,
+    {
+        let _ = (key, signer);
+        Err(Error::remote_signer_unsupported_key_type(Self::KEY_TYPE))
+    }
+
     fn account(&self) -> String;
     fn sign(&self, message: &[u8]) -> Result<Vec<u8>, Error>;
 
@@ -36,12 +36,16 @@ pub mod keyring;
 pub mod light_client;
 pub mod link;
 pub mod misbehaviour;
+pub mod misbehaviour_monitor;
 pub mod object;
 pub mod path;
+pub mod profitability;
+pub mod recover_client;
 pub mod registry;
 pub mod rest;
 pub mod sdk_error;
 pub mod spawn;
+pub mod store;
 pub mod supervisor;
 pub mod telemetry;
 pub mod transfer;
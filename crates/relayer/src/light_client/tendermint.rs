@@ -1,4 +1,4 @@
-mod detector;
+pub(crate) mod detector;
 
 use std::time::Duration;
 
@@ -22,14 +22,12 @@ use tendermint_rpc as rpc;
 
 use ibc_relayer_types::clients::ics07_tendermint::header::Header as TmHeader;
 use ibc_relayer_types::clients::ics07_tendermint::misbehaviour::Misbehaviour as TmMisbehaviour;
+use ibc_relayer_types::core::ics02_client::client_type::ClientType;
 use ibc_relayer_types::core::ics02_client::events::UpdateClient;
 use ibc_relayer_types::core::ics02_client::header::AnyHeader;
 use ibc_relayer_types::core::ics24_host::identifier::ChainId;
 use ibc_relayer_types::Height as ICSHeight;
 
-#[cfg(test)]
-use ibc_relayer_types::core::ics02_client::client_type::ClientType;
-
 use crate::{
     chain::cosmos::config::CosmosSdkConfig,
     chain::cosmos::CosmosSdkChain,
@@ -146,12 +144,20 @@ impl super::LightClient<CosmosSdkChain> for LightClient {
 
         let update_header: &TmHeader = match any_header {
             AnyHeader::Tendermint(header) => Ok(header),
+
+            AnyHeader::Wasm(header) => Ok(&header.inner),
+
+            _ => Err(Error::misbehaviour(format!(
+                "header type incompatible for chain {}",
+                self.chain_id
+            ))),
         }?;
 
         let client_state = match client_state {
             AnyClientState::Tendermint(client_state) => Ok(client_state),
 
-            #[cfg(test)]
+            AnyClientState::Wasm(client_state) => Ok(&client_state.inner),
+
             _ => Err(Error::misbehaviour(format!(
                 "client type incompatible for chain {}",
                 self.chain_id
@@ -190,7 +196,8 @@ impl super::LightClient<CosmosSdkChain> for LightClient {
             self.io.rpc_client().clone(),
             target_block,
             trusted_block,
-            client_state,
+            &client_state.chain_id,
+            client_state.as_light_client_options(),
             now,
         );
 
@@ -318,7 +325,8 @@ impl LightClient {
         let client_state = match client_state {
             AnyClientState::Tendermint(client_state) => Ok(client_state),
 
-            #[cfg(test)]
+            AnyClientState::Wasm(client_state) => Ok(&client_state.inner),
+
             _ => Err(Error::client_type_mismatch(
                 ClientType::Tendermint,
                 client_state.client_type(),
@@ -15,23 +15,27 @@ use tendermint_light_client::{
 use tendermint_light_client_detector::{detect_divergence, Divergence, Provider};
 use tendermint_rpc::{Client, HttpClient};
 
-use ibc_relayer_types::clients::ics07_tendermint::client_state::ClientState;
+use tendermint_light_client::light_client::Options;
+
+use ibc_relayer_types::core::ics24_host::identifier::ChainId;
 
 use crate::{error::Error, util::block_on};
 
 type Hasher = tendermint::crypto::default::Sha256;
 
+/// Cross-check the target block against the full node reachable with the given
+/// RPC client, which acts as a witness, starting from the trusted block.
 pub fn detect(
     peer_id: PeerId,
     rpc_client: HttpClient,
     target_block: LightBlock,
     trusted_block: LightBlock,
-    client_state: &ClientState,
+    chain_id: &ChainId,
+    options: Options,
     now: Time,
 ) -> Result<Option<Divergence>, Error> {
     let primary_trace = vec![trusted_block.clone(), target_block];
-    let options = client_state.as_light_client_options();
-    let mut provider = make_provider(peer_id, rpc_client, client_state, trusted_block, now)?;
+    let mut provider = make_provider(peer_id, rpc_client, chain_id, options, trusted_block, now)?;
 
     let divergence = block_on(detect_divergence::<Hasher>(
         None,
@@ -43,25 +47,19 @@ pub fn detect(
 
     match divergence {
         Ok(None) => {
-            info!(
-                "No evidence of misbehavior detected for chain {}",
-                client_state.chain_id
-            );
+            info!("No evidence of misbehavior detected for chain {}", chain_id);
 
             Ok(None)
         }
         Ok(Some(divergence)) => {
-            info!(
-                "Evidence of misbehavior detected for chain {}",
-                client_state.chain_id
-            );
+            info!("Evidence of misbehavior detected for chain {}", chain_id);
 
             Ok(Some(divergence))
         }
         Err(e) => {
             error!(
                 "Error while detecting misbehavior for chain {}: {}",
-                client_state.chain_id, e
+                chain_id, e
             );
 
             Ok(None)
@@ -72,11 +70,11 @@ pub fn detect(
 fn make_provider(
     peer_id: PeerId,
     rpc_client: HttpClient,
-    client_state: &ClientState,
+    chain_id: &ChainId,
+    options: Options,
     trusted_block: LightBlock,
     now: Time,
 ) -> Result<Provider, Error> {
-    let options = client_state.as_light_client_options();
     let light_store = Box::new(MemoryStore::new());
 
     let builder = LightClientBuilder::custom(
@@ -95,11 +93,7 @@ fn make_provider(
         .map_err(Error::light_client_builder)?
         .build();
 
-    Ok(Provider::new(
-        client_state.chain_id.to_string(),
-        instance,
-        rpc_client,
-    ))
+    Ok(Provider::new(chain_id.to_string(), instance, rpc_client))
 }
 
 pub fn report_evidence(
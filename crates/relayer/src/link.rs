@@ -12,11 +12,12 @@ use crate::channel::{Channel, ChannelSide};
 use crate::link::error::LinkError;
 use crate::{
     chain::requests::{QueryChannelRequest, QueryHeight},
-    config::types::ics20_field_size_limit::Ics20FieldSizeLimit,
+    config::{filter::Ics20Filter, types::ics20_field_size_limit::Ics20FieldSizeLimit},
 };
 
 pub mod cli;
 pub mod error;
+pub mod inspect;
 pub mod operational_data;
 pub mod packet_events;
 
@@ -40,6 +41,7 @@ pub struct LinkParameters {
     pub max_memo_size: Ics20FieldSizeLimit,
     pub max_receiver_size: Ics20FieldSizeLimit,
     pub exclude_src_sequences: Vec<Sequence>,
+    pub ics20_filter: Ics20Filter,
 }
 
 pub struct Link<ChainA: ChainHandle, ChainB: ChainHandle> {
@@ -87,6 +89,7 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> Link<ChainA, ChainB> {
 
         if !a_channel.state_matches(&ChannelState::Open)
             && !a_channel.state_matches(&ChannelState::Closed)
+            && !a_channel.state().is_upgrading()
         {
             return Err(LinkError::invalid_channel_state(
                 a_channel_id.clone(),
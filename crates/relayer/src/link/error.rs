@@ -8,6 +8,7 @@ use crate::channel::ChannelError;
 use crate::connection::ConnectionError;
 use crate::error::Error;
 use crate::foreign_client::{ForeignClientError, HasExpiredOrFrozenError};
+use crate::store::StoreError;
 use crate::supervisor::Error as SupervisorError;
 use crate::transfer::TransferError;
 
@@ -70,6 +71,10 @@ define_error! {
         OldPacketClearingFailed
             |_| { "clearing of old packets failed" },
 
+        Store
+            [ StoreError ]
+            |_| { "error originating from the relayer state store" },
+
         Send
             { event: IbcEvent }
             |e| {
@@ -0,0 +1,227 @@
+//! Inspection of the packets a [`RelayPath`](super::RelayPath) is relaying,
+//! from outside of the packet worker which owns it.
+
+use alloc::collections::BTreeMap;
+use core::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+
+use ibc_relayer_types::core::ics04_channel::packet::{Packet, Sequence};
+use ibc_relayer_types::core::ics24_host::identifier::{ChainId, ChannelId, PortId};
+use ibc_relayer_types::events::IbcEvent;
+use ibc_relayer_types::Height;
+
+use crate::object;
+use crate::util::lock::{LockExt, RwArc};
+use crate::util::queue::Queue;
+
+use super::operational_data::OperationalData;
+use super::pending::PendingData;
+
+/// The maximum number of packets for which the last relaying error is remembered.
+const MAX_PACKET_ERRORS: usize = 1024;
+
+/// Identifies a packet by the port, channel and sequence on the chain which sent it.
+///
+/// A relay path relays both the packets sent on its source chain, and the
+/// acknowledgements of the packets sent on its destination chain, so the
+/// sequence alone is not enough to tell them apart.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct PacketKey {
+    pub port_id: PortId,
+    pub channel_id: ChannelId,
+    pub sequence: Sequence,
+}
+
+impl PacketKey {
+    pub fn new(port_id: PortId, channel_id: ChannelId, sequence: Sequence) -> Self {
+        Self {
+            port_id,
+            channel_id,
+            sequence,
+        }
+    }
+
+    fn of(packet: &Packet) -> Self {
+        Self::new(
+            packet.source_port.clone(),
+            packet.source_channel.clone(),
+            packet.sequence,
+        )
+    }
+
+    fn matches(&self, packet: &Packet) -> bool {
+        packet.sequence == self.sequence
+            && packet.source_channel == self.channel_id
+            && packet.source_port == self.port_id
+    }
+}
+
+/// The last error hit while relaying each of the packets of a relay path.
+#[derive(Clone, Default)]
+pub struct PacketErrors(RwArc<BTreeMap<PacketKey, String>>);
+
+impl PacketErrors {
+    /// Record the given error for all the packets relayed by the operational data.
+    pub fn record(&self, odata: &OperationalData, error: impl Display) {
+        let error = error.to_string();
+        let mut errors = self.0.acquire_write();
+
+        for key in packet_keys(odata) {
+            errors.insert(key, error.clone());
+        }
+
+        while errors.len() > MAX_PACKET_ERRORS {
+            errors.pop_first();
+        }
+    }
+
+    /// Forget the errors of the packets relayed by the operational data,
+    /// once its transactions are confirmed.
+    pub fn clear(&self, odata: &OperationalData) {
+        let mut errors = self.0.acquire_write();
+
+        for key in packet_keys(odata) {
+            errors.remove(&key);
+        }
+    }
+
+    pub fn get(&self, key: &PacketKey) -> Option<String> {
+        self.0.acquire_read().get(key).cloned()
+    }
+}
+
+fn packet_keys(odata: &OperationalData) -> impl Iterator<Item = PacketKey> + '_ {
+    odata
+        .batch
+        .iter()
+        .filter_map(|msg| msg.event_with_height.event.packet())
+        .map(PacketKey::of)
+}
+
+/// A message relaying a packet, which is scheduled but not submitted yet.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct QueuedPacketMsg {
+    /// The chain the message is bound for.
+    pub target_chain: ChainId,
+    /// The type of the message, eg. `MsgRecvPacket`.
+    pub msg_type: String,
+    /// The height of the proofs included in the message.
+    pub proofs_height: Height,
+}
+
+/// A transaction relaying a packet, which was submitted but not confirmed yet.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PendingPacketTx {
+    /// The chain the transaction was submitted to.
+    pub target_chain: ChainId,
+    pub tx_hashes: Vec<String>,
+    /// How long ago the transaction was submitted, in seconds.
+    pub submitted_secs_ago: u64,
+}
+
+/// Where a packet stands in the pipeline of the packet worker relaying it.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RelayerPacketStatus {
+    /// The path of the packet worker.
+    pub path: object::Packet,
+    /// The messages relaying the packet waiting in the `OperationalData` queues.
+    pub queued: Vec<QueuedPacketMsg>,
+    /// The transactions relaying the packet in the `PendingTxs` queues.
+    pub pending_txs: Vec<PendingPacketTx>,
+    /// The last error hit while relaying the packet.
+    pub last_error: Option<String>,
+}
+
+/// A handle to the queues of a relay path, which can be inspected
+/// without locking the [`Link`](crate::link::Link) owning it.
+#[derive(Clone)]
+pub struct RelayPathInspector {
+    pub(super) path: object::Packet,
+    pub(super) src_operational_data: Queue<OperationalData>,
+    pub(super) dst_operational_data: Queue<OperationalData>,
+    pub(super) pending_txs_src: Queue<PendingData>,
+    pub(super) pending_txs_dst: Queue<PendingData>,
+    pub(super) errors: PacketErrors,
+}
+
+impl RelayPathInspector {
+    /// Returns what the relay path knows of the given packet,
+    /// or `None` if the packet is neither queued, pending nor failed.
+    ///
+    /// Note that operational data being submitted at the time of the call
+    /// are neither in the queues nor pending, and are therefore not reported.
+    pub fn packet_status(&self, key: &PacketKey) -> Option<RelayerPacketStatus> {
+        let src_chain = &self.path.src_chain_id;
+        let dst_chain = &self.path.dst_chain_id;
+
+        let queued = [
+            (src_chain, &self.src_operational_data),
+            (dst_chain, &self.dst_operational_data),
+        ]
+        .into_iter()
+        .flat_map(|(target_chain, queue)| {
+            queue.clone_vec().into_iter().flat_map(move |odata| {
+                let proofs_height = odata.proofs_height;
+
+                odata
+                    .batch
+                    .into_iter()
+                    .filter(|msg| is_packet(&msg.event_with_height.event, key))
+                    .map(move |msg| QueuedPacketMsg {
+                        target_chain: target_chain.clone(),
+                        msg_type: msg_type(&msg.msg.type_url),
+                        proofs_height,
+                    })
+            })
+        })
+        .collect::<Vec<_>>();
+
+        let pending_txs = [
+            (src_chain, &self.pending_txs_src),
+            (dst_chain, &self.pending_txs_dst),
+        ]
+        .into_iter()
+        .flat_map(|(target_chain, queue)| {
+            queue
+                .clone_vec()
+                .into_iter()
+                .filter(|pending| {
+                    pending
+                        .original_od
+                        .batch
+                        .iter()
+                        .any(|msg| is_packet(&msg.event_with_height.event, key))
+                })
+                .map(move |pending| PendingPacketTx {
+                    target_chain: target_chain.clone(),
+                    tx_hashes: pending.tx_hashes.0.iter().map(|h| h.to_string()).collect(),
+                    submitted_secs_ago: pending.submit_time.elapsed().as_secs(),
+                })
+        })
+        .collect::<Vec<_>>();
+
+        let last_error = self.errors.get(key);
+
+        if queued.is_empty() && pending_txs.is_empty() && last_error.is_none() {
+            return None;
+        }
+
+        Some(RelayerPacketStatus {
+            path: self.path.clone(),
+            queued,
+            pending_txs,
+            last_error,
+        })
+    }
+}
+
+fn is_packet(event: &IbcEvent, key: &PacketKey) -> bool {
+    event.packet().map_or(false, |packet| key.matches(packet))
+}
+
+/// Returns the message type out of its type URL,
+/// eg. `MsgRecvPacket` for `/ibc.core.channel.v1.MsgRecvPacket`.
+fn msg_type(type_url: &str) -> String {
+    type_url.rsplit('.').next().unwrap_or(type_url).to_string()
+}
@@ -6,6 +6,7 @@ use ibc_proto::google::protobuf::Any;
 use tracing::{debug, info};
 
 use ibc_relayer_types::core::ics02_client::client_state::ClientState;
+use ibc_relayer_types::signer::Signer;
 use ibc_relayer_types::Height;
 
 use crate::chain::handle::ChainHandle;
@@ -17,6 +18,7 @@ use crate::chain::tracking::TrackingId;
 use crate::event::IbcEventWithHeight;
 use crate::link::error::LinkError;
 use crate::link::RelayPath;
+use crate::store::TrackedPacket;
 
 /// The chain that the events associated with a piece of [`OperationalData`] are bound for.
 #[derive(Clone, Copy, PartialEq, Eq)]
@@ -98,6 +100,9 @@ pub struct OperationalData {
     pub tracking_id: TrackingId,
     /// Stores `Some(ConnectionDelay)` if the delay is non-zero and `None` otherwise
     connection_delay: Option<ConnectionDelay>,
+    /// The signer all the messages in the batch were built with, ie. the signer of the
+    /// wallet selected to relay the packets on the target chain, if any was selected.
+    pub signer: Option<Signer>,
 }
 
 impl OperationalData {
@@ -119,6 +124,7 @@ impl OperationalData {
             target,
             connection_delay,
             tracking_id,
+            signer: None,
         }
     }
 
@@ -136,6 +142,16 @@ impl OperationalData {
         }
     }
 
+    /// Returns the packets relayed by the messages in this operational data.
+    pub fn tracked_packets(&self) -> Vec<TrackedPacket> {
+        self.batch
+            .iter()
+            .filter_map(|gm| {
+                TrackedPacket::from_event(&gm.event_with_height.event, gm.event_with_height.height)
+            })
+            .collect()
+    }
+
     /// Transforms `self` into the list of events accompanied with the tracking ID.
     pub fn into_events(self) -> TrackedEvents {
         let events = self
@@ -157,6 +173,14 @@ impl OperationalData {
         &self,
         relay_path: &RelayPath<ChainA, ChainB>,
     ) -> Result<TrackedMsgs, LinkError> {
+        // The client update messages must be built with the same signer as the
+        // messages in the batch, since a single wallet signs the transaction.
+        let signer = match (&self.signer, self.target) {
+            (Some(signer), _) => signer.clone(),
+            (None, OperationalDataTarget::Source) => relay_path.src_signer()?,
+            (None, OperationalDataTarget::Destination) => relay_path.dst_signer()?,
+        };
+
         // For zero delay we prepend the client update msgs.
         let client_update_msgs = if !self.conn_delay_needed() {
             let update_height = self.proofs_height.increment();
@@ -170,10 +194,10 @@ impl OperationalData {
             // Vector may be empty if the client already has the header for the requested height.
             match self.target {
                 OperationalDataTarget::Source => {
-                    relay_path.build_update_client_on_src(update_height)?
+                    relay_path.build_update_client_on_src(update_height, signer.clone())?
                 }
                 OperationalDataTarget::Destination => {
-                    relay_path.build_update_client_on_dst(update_height)?
+                    relay_path.build_update_client_on_dst(update_height, signer.clone())?
                 }
             }
         } else {
@@ -213,7 +237,7 @@ impl OperationalData {
             .chain(self.batch.iter().map(|gm| gm.msg.clone()))
             .collect();
 
-        let tm = TrackedMsgs::new(msgs, self.tracking_id);
+        let tm = TrackedMsgs::new(msgs, self.tracking_id).with_signer(signer);
 
         info!("assembled batch of {} message(s)", tm.messages().len());
 
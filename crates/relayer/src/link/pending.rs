@@ -1,16 +1,24 @@
 use core::iter::Iterator;
+use core::str::FromStr;
 use core::time::Duration;
 use std::time::Instant;
 
-use tracing::{debug, error, trace, trace_span};
+use tendermint::Hash;
+use tracing::{debug, error, trace, trace_span, warn};
 
 use ibc_relayer_types::core::ics24_host::identifier::{ChainId, ChannelId, PortId};
 use ibc_relayer_types::events::IbcEvent;
+use ibc_relayer_types::signer::Signer;
 
 use crate::chain::requests::{QueryTxHash, QueryTxRequest};
 use crate::chain::tracking::TrackingId;
 use crate::error::Error as RelayerError;
+use crate::event::relay;
+use crate::event::IbcEventWithHeight;
 use crate::link::{error::LinkError, RelayPath};
+use crate::object::Packet;
+use crate::profitability::{self, ChannelKey};
+use crate::store::{self, PendingTxRecord};
 use crate::telemetry;
 use crate::util::queue::Queue;
 use crate::{
@@ -39,6 +47,16 @@ impl PendingData {
     pub fn tracking_id(&self) -> TrackingId {
         self.original_od.tracking_id
     }
+
+    /// Returns the record persisting this pending data in the relayer state store.
+    fn store_record(&self, chain_id: ChainId) -> PendingTxRecord {
+        PendingTxRecord::new(
+            chain_id,
+            self.tracking_id().to_string(),
+            self.tx_hashes.0.iter().map(|h| h.to_string()).collect(),
+            self.original_od.tracked_packets(),
+        )
+    }
 }
 
 /// Stores all pending data
@@ -49,6 +67,9 @@ pub struct PendingTxs<Chain> {
     pub port_id: PortId,
     pub counterparty_chain_id: ChainId,
     pub pending_queue: Queue<PendingData>,
+    /// The packet relaying path the pending transactions belong to,
+    /// used to persist them in the relayer state store.
+    pub path: Packet,
 }
 
 impl<Chain> PendingTxs<Chain> {
@@ -57,6 +78,7 @@ impl<Chain> PendingTxs<Chain> {
         channel_id: ChannelId,
         port_id: PortId,
         counterparty_chain_id: ChainId,
+        path: Packet,
     ) -> Self {
         Self {
             chain,
@@ -64,6 +86,7 @@ impl<Chain> PendingTxs<Chain> {
             port_id,
             counterparty_chain_id,
             pending_queue: Queue::new(),
+            path,
         }
     }
 }
@@ -73,6 +96,19 @@ impl<Chain: ChainHandle> PendingTxs<Chain> {
         self.chain.id()
     }
 
+    /// The addresses of all the wallets of Hermes on the chain,
+    /// to which the ICS29 fees for relaying the packets are rewarded.
+    fn relayer_signers(&self) -> Vec<Signer> {
+        self.chain
+            .get_keys()
+            .map(|keys| {
+                keys.iter()
+                    .filter_map(|(_, key)| Signer::from_str(&key.account()).ok())
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
     /// Insert a new pending transaction to the back of the queue.
     pub fn insert_new_pending_tx(&self, r: AsyncReply, od: OperationalData) {
         let mut tx_hashes = Vec::new();
@@ -123,10 +159,110 @@ impl<Chain: ChainHandle> PendingTxs<Chain> {
             error_events,
         };
 
+        self.store_pending(&u);
         self.pending_queue.push_back(u);
     }
 
-    fn check_tx_events(&self, tx_hashes: &TxHashes) -> Result<Option<Vec<IbcEvent>>, RelayerError> {
+    /// Persists the given pending data in the relayer state store, if there is one.
+    fn store_pending(&self, pending: &PendingData) {
+        if let Some(store) = store::global() {
+            let record = pending.store_record(self.chain_id());
+
+            if let Err(e) = store.insert_pending_tx(&self.path, &record) {
+                warn!("failed to persist pending tx {}: {}", pending.tx_hashes, e);
+            }
+        }
+    }
+
+    /// Removes the given pending data from the relayer state store, if there is one.
+    /// If the transactions were committed, the packets they relay are not tracked anymore.
+    fn unstore_pending(&self, pending: &PendingData, committed: bool) {
+        if let Some(store) = store::global() {
+            let record = pending.store_record(self.chain_id());
+
+            let result = store.remove_pending_tx(&self.path, &record).and_then(|_| {
+                if committed {
+                    store.untrack_packets(&self.path, &record.packets)
+                } else {
+                    Ok(())
+                }
+            });
+
+            if let Err(e) = result {
+                warn!("failed to remove pending tx {}: {}", pending.tx_hashes, e);
+            }
+        }
+    }
+
+    /// Re-confirms the transactions submitted to this chain which were still pending
+    /// when the relayer was last stopped, as persisted in the relayer state store.
+    ///
+    /// The packets relayed by the committed transactions are not tracked anymore,
+    /// whereas the ones relayed by the other transactions remain tracked in order
+    /// to be relayed again. Either way, the pending transactions are removed from the store.
+    pub fn confirm_stored_txs(&self) -> Result<(), LinkError> {
+        let Some(store) = store::global() else {
+            return Ok(());
+        };
+
+        let chain_id = self.chain_id();
+
+        let records = store.pending_txs(&self.path).map_err(LinkError::store)?;
+
+        for record in records.into_iter().filter(|r| r.chain_id == chain_id) {
+            let tx_hashes: Result<Vec<_>, _> = record
+                .tx_hashes
+                .iter()
+                .map(|hash| Hash::from_str(hash))
+                .collect();
+
+            let committed = match tx_hashes {
+                Ok(tx_hashes) => match self.check_tx_events(&TxHashes(tx_hashes)) {
+                    Ok(events) => events.is_some(),
+                    Err(e) => {
+                        warn!(
+                            "failed to confirm stored pending txs {}: {}, will retry on next resume",
+                            record.tx_hashes.join(", "),
+                            e
+                        );
+                        continue;
+                    }
+                },
+                Err(e) => {
+                    warn!(
+                        "invalid stored pending tx hash in {}: {}",
+                        record.tx_hashes.join(", "),
+                        e
+                    );
+                    false
+                }
+            };
+
+            debug!(
+                tracking_id = %record.tracking_id,
+                tx_hashes = %record.tx_hashes.join(", "),
+                committed,
+                "re-confirmed stored pending txs",
+            );
+
+            store
+                .remove_pending_tx(&self.path, &record)
+                .map_err(LinkError::store)?;
+
+            if committed {
+                store
+                    .untrack_packets(&self.path, &record.packets)
+                    .map_err(LinkError::store)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn check_tx_events(
+        &self,
+        tx_hashes: &TxHashes,
+    ) -> Result<Option<Vec<IbcEventWithHeight>>, RelayerError> {
         let mut all_events = Vec::new();
         for hash in &tx_hashes.0 {
             let mut events = self
@@ -139,7 +275,19 @@ impl<Chain: ChainHandle> PendingTxs<Chain> {
                 all_events.append(&mut events)
             }
         }
-        Ok(Some(all_events.into_iter().map(|ev| ev.event).collect()))
+        Ok(Some(all_events))
+    }
+
+    /// Whether any of the given transactions was dropped from the mempool of the chain,
+    /// in which case it will never be committed and there is no point in waiting for it.
+    fn any_dropped(&self, tx_hashes: &TxHashes) -> bool {
+        match self.chain.query_dropped_txs(tx_hashes.0.clone()) {
+            Ok(dropped) => !dropped.is_empty(),
+            Err(e) => {
+                warn!("failed to query whether {} were dropped: {}", tx_hashes, e);
+                false
+            }
+        }
     }
 
     /// Try and process one pending transaction within the given timeout duration if one
@@ -170,6 +318,7 @@ impl<Chain: ChainHandle> PendingTxs<Chain> {
                 counterparty_chain = %self.counterparty_chain_id,
                 port = %self.port_id,
                 channel = %self.channel_id,
+                tracking_id = %pending.tracking_id(),
             );
 
             let _guard = span.enter();
@@ -185,11 +334,26 @@ impl<Chain: ChainHandle> PendingTxs<Chain> {
 
                     trace!("transaction is not yet committed: {} ", tx_hashes);
 
-                    if submit_time.elapsed() > timeout {
+                    let dropped = self.any_dropped(tx_hashes);
+
+                    if dropped || submit_time.elapsed() > timeout {
                         // The submission time for the transaction has exceeded the
-                        // timeout threshold. Returning Outcome::Timeout for the
+                        // timeout threshold, or the transaction was dropped from the
+                        // mempool of the chain. Returning Outcome::Timeout for the
                         // relayer to resubmit the transaction to the chain again.
-                        error!("timed out while confirming {}", tx_hashes);
+                        let reason = if dropped {
+                            format!("dropped from the mempool while confirming {tx_hashes}")
+                        } else {
+                            format!("timed out while confirming {tx_hashes}")
+                        };
+
+                        error!("{}", reason);
+
+                        relay_path
+                            .packet_errors
+                            .record(&pending.original_od, reason);
+
+                        self.unstore_pending(&pending, false);
 
                         match resubmit {
                             Some(f) => {
@@ -207,6 +371,7 @@ impl<Chain: ChainHandle> PendingTxs<Chain> {
                                         Ok(None)
                                     }
                                     Some(Err(e)) => {
+                                        self.store_pending(&pending);
                                         self.pending_queue.push_back(pending);
                                         Err(e)
                                     }
@@ -229,7 +394,7 @@ impl<Chain: ChainHandle> PendingTxs<Chain> {
                         Ok(None)
                     }
                 }
-                Ok(Some(mut events)) => {
+                Ok(Some(events_with_heights)) => {
                     // We get a list of events for the transaction hashes,
                     // Meaning the transaction has been committed successfully
                     // to the chain.
@@ -251,6 +416,46 @@ impl<Chain: ChainHandle> PendingTxs<Chain> {
                         &self.counterparty_chain_id
                     );
 
+                    telemetry!({
+                        relay_path
+                            .record_packet_latencies(&pending.original_od, &events_with_heights);
+                    });
+
+                    profitability::record_confirmed_txs(
+                        &ChannelKey::new(
+                            relay_path.src_chain().id(),
+                            relay_path.src_port_id().clone(),
+                            relay_path.src_channel_id().clone(),
+                            relay_path.dst_chain().id(),
+                        ),
+                        &self.chain.id(),
+                        &tx_hashes.0,
+                        &events_with_heights,
+                        || self.relayer_signers(),
+                    );
+
+                    let mut events = events_with_heights
+                        .into_iter()
+                        .map(|ev| ev.event)
+                        .collect::<Vec<_>>();
+
+                    self.unstore_pending(&pending, true);
+
+                    relay::publish_tx_confirmed(
+                        self.chain.id(),
+                        pending.tracking_id(),
+                        &tx_hashes.0,
+                    );
+                    relay::publish_tx_events(&self.chain.id(), &events);
+
+                    match events.iter().find_map(|ev| match ev {
+                        IbcEvent::ChainError(e) => Some(e),
+                        _ => None,
+                    }) {
+                        Some(e) => relay_path.packet_errors.record(&pending.original_od, e),
+                        None => relay_path.packet_errors.clear(&pending.original_od),
+                    }
+
                     // Append the events corresponding to errors from the pending tx.
                     events.extend(pending.error_events);
 
@@ -7,12 +7,11 @@ use std::time::{Duration, Instant};
 use ibc_proto::google::protobuf::Any;
 use ibc_proto::ibc::applications::transfer::v2::FungibleTokenPacketData as RawPacketData;
 use itertools::Itertools;
+use once_cell::unsync::OnceCell;
 use tracing::{debug, error, info, span, trace, warn, Level};
 
 use ibc_relayer_types::core::ics02_client::events::ClientMisbehaviour as ClientMisbehaviourEvent;
-use ibc_relayer_types::core::ics04_channel::channel::{
-    ChannelEnd, Ordering, State as ChannelState,
-};
+use ibc_relayer_types::core::ics04_channel::channel::{ChannelEnd, State as ChannelState};
 use ibc_relayer_types::core::ics04_channel::events::{SendPacket, WriteAcknowledgement};
 use ibc_relayer_types::core::ics04_channel::msgs::{
     acknowledgement::MsgAcknowledgement, chan_close_confirm::MsgChannelCloseConfirm,
@@ -44,12 +43,14 @@ use crate::chain::tracking::TrackedMsgs;
 use crate::chain::tracking::TrackingId;
 use crate::channel::error::ChannelError;
 use crate::channel::Channel;
+use crate::config::filter::Ics20Filter;
 use crate::config::types::ics20_field_size_limit::Ics20FieldSizeLimit;
 use crate::config::types::ics20_field_size_limit::ValidationResult;
 use crate::event::source::EventBatch;
 use crate::event::IbcEventWithHeight;
 use crate::foreign_client::{ForeignClient, ForeignClientError};
 use crate::link::error::{self, LinkError};
+use crate::link::inspect::{PacketErrors, RelayPathInspector};
 use crate::link::operational_data::{
     OperationalData, OperationalDataTarget, TrackedEvents, TransitMessage,
 };
@@ -61,7 +62,9 @@ use crate::link::relay_sender::{AsyncReply, SubmitReply};
 use crate::link::relay_summary::RelaySummary;
 use crate::link::LinkParameters;
 use crate::link::{pending, relay_sender};
+use crate::object;
 use crate::path::PathIdentifiers;
+use crate::store::{self, TrackedPacketKind};
 use crate::telemetry;
 use crate::util::collate::CollatedIterExt;
 use crate::util::pretty::PrettyEvents;
@@ -114,9 +117,17 @@ pub struct RelayPath<ChainA: ChainHandle, ChainB: ChainHandle> {
     pending_txs_src: PendingTxs<ChainA>,
     pending_txs_dst: PendingTxs<ChainB>,
 
+    // The last error hit while relaying each packet.
+    pub(crate) packet_errors: PacketErrors,
+
+    // The packet relaying path under which the tracked packets
+    // are persisted in the relayer state store.
+    store_path: object::Packet,
+
     pub max_memo_size: Ics20FieldSizeLimit,
     pub max_receiver_size: Ics20FieldSizeLimit,
     pub exclude_src_sequences: Vec<Sequence>,
+    pub ics20_filter: Ics20Filter,
 }
 
 impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
@@ -144,6 +155,13 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
         let src_port_id = channel.src_port_id().clone();
         let dst_port_id = channel.dst_port_id().clone();
 
+        let store_path = object::Packet {
+            dst_chain_id: dst_chain_id.clone(),
+            src_chain_id: src_chain_id.clone(),
+            src_channel_id: src_channel_id.clone(),
+            src_port_id: src_port_id.clone(),
+        };
+
         let path = PathIdentifiers {
             port_id: dst_port_id.clone(),
             channel_id: dst_channel_id.clone(),
@@ -160,13 +178,28 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
             dst_operational_data: Queue::new(),
 
             confirm_txes: with_tx_confirmation,
-            pending_txs_src: PendingTxs::new(src_chain, src_channel_id, src_port_id, dst_chain_id),
-            pending_txs_dst: PendingTxs::new(dst_chain, dst_channel_id, dst_port_id, src_chain_id),
+            pending_txs_src: PendingTxs::new(
+                src_chain,
+                src_channel_id,
+                src_port_id,
+                dst_chain_id,
+                store_path.clone(),
+            ),
+            pending_txs_dst: PendingTxs::new(
+                dst_chain,
+                dst_channel_id,
+                dst_port_id,
+                src_chain_id,
+                store_path.clone(),
+            ),
+            packet_errors: PacketErrors::default(),
+            store_path,
 
             max_memo_size: link_parameters.max_memo_size,
             max_receiver_size: link_parameters.max_receiver_size,
 
             exclude_src_sequences: link_parameters.exclude_src_sequences,
+            ics20_filter: link_parameters.ics20_filter,
         })
     }
 
@@ -214,6 +247,19 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
         &self.channel
     }
 
+    /// Returns a handle to inspect the packets this relay path is relaying,
+    /// which remains valid while the relay path is being used.
+    pub fn inspector(&self) -> RelayPathInspector {
+        RelayPathInspector {
+            path: self.store_path.clone(),
+            src_operational_data: self.src_operational_data.clone(),
+            dst_operational_data: self.dst_operational_data.clone(),
+            pending_txs_src: self.pending_txs_src.pending_queue.clone(),
+            pending_txs_dst: self.pending_txs_dst.pending_queue.clone(),
+            errors: self.packet_errors.clone(),
+        }
+    }
+
     fn src_channel(&self, height_query: QueryHeight) -> Result<ChannelEnd, LinkError> {
         self.src_chain()
             .query_channel(
@@ -242,18 +288,51 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
             .map_err(|e| LinkError::channel(ChannelError::query(self.dst_chain().id(), e)))
     }
 
-    fn src_signer(&self) -> Result<Signer, LinkError> {
+    pub(crate) fn src_signer(&self) -> Result<Signer, LinkError> {
         self.src_chain()
             .get_signer()
             .map_err(|e| LinkError::signer(self.src_chain().id(), e))
     }
 
-    fn dst_signer(&self) -> Result<Signer, LinkError> {
+    pub(crate) fn dst_signer(&self) -> Result<Signer, LinkError> {
         self.dst_chain()
             .get_signer()
             .map_err(|e| LinkError::signer(self.dst_chain().id(), e))
     }
 
+    /// The signer of the wallet selected to relay the packets of a batch on the source chain.
+    ///
+    /// The wallet is only selected once a message is built for the source chain,
+    /// and all the messages of the batch are then built with its signer.
+    fn selected_src_signer(&self, signer: &OnceCell<Signer>) -> Result<Signer, LinkError> {
+        signer
+            .get_or_try_init(|| {
+                self.src_chain()
+                    .select_signer(
+                        (self.src_port_id().clone(), self.src_channel_id().clone()),
+                        self.channel.ordering,
+                    )
+                    .map_err(|e| LinkError::signer(self.src_chain().id(), e))
+            })
+            .cloned()
+    }
+
+    /// The signer of the wallet selected to relay the packets of a batch on the destination chain.
+    ///
+    /// See [`RelayPath::selected_src_signer`].
+    fn selected_dst_signer(&self, signer: &OnceCell<Signer>) -> Result<Signer, LinkError> {
+        signer
+            .get_or_try_init(|| {
+                self.dst_chain()
+                    .select_signer(
+                        (self.dst_port_id().clone(), self.dst_channel_id().clone()),
+                        self.channel.ordering,
+                    )
+                    .map_err(|e| LinkError::signer(self.dst_chain().id(), e))
+            })
+            .cloned()
+    }
+
     pub(crate) fn src_latest_height(&self) -> Result<Height, LinkError> {
         self.src_chain()
             .query_latest_height()
@@ -320,31 +399,40 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
             .max_block_time())
     }
 
-    fn unordered_channel(&self) -> bool {
-        self.channel.ordering == Ordering::Unordered
+    fn ordered_channel(&self) -> bool {
+        self.channel.ordering.is_ordered()
     }
 
-    fn ordered_channel(&self) -> bool {
-        self.channel.ordering == Ordering::Ordered
+    fn channel_closes_on_timeout(&self) -> bool {
+        self.channel.ordering.closes_on_timeout()
     }
 
-    pub fn build_update_client_on_dst(&self, height: Height) -> Result<Vec<Any>, LinkError> {
+    pub fn build_update_client_on_dst(
+        &self,
+        height: Height,
+        signer: Signer,
+    ) -> Result<Vec<Any>, LinkError> {
         let client = self.restore_dst_client();
         client
-            .wait_and_build_update_client(height)
+            .wait_and_build_update_client_with_signer(height, signer)
             .map_err(LinkError::client)
     }
 
-    pub fn build_update_client_on_src(&self, height: Height) -> Result<Vec<Any>, LinkError> {
+    pub fn build_update_client_on_src(
+        &self,
+        height: Height,
+        signer: Signer,
+    ) -> Result<Vec<Any>, LinkError> {
         let client = self.restore_src_client();
         client
-            .wait_and_build_update_client(height)
+            .wait_and_build_update_client_with_signer(height, signer)
             .map_err(LinkError::client)
     }
 
     fn build_chan_close_confirm_from_event(
         &self,
         event: &IbcEventWithHeight,
+        dst_signer: &OnceCell<Signer>,
     ) -> Result<Option<Any>, LinkError> {
         // Build the `MsgChannelCloseConfirm` only from `Timeout` or `CloseInitChannel` event types
         if event.event.event_type() != IbcEventType::Timeout
@@ -372,7 +460,7 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
             port_id: self.dst_port_id().clone(),
             channel_id: self.dst_channel_id().clone(),
             proofs,
-            signer: self.dst_signer()?,
+            signer: self.selected_dst_signer(dst_signer)?,
             counterparty_upgrade_sequence: 0,
         };
 
@@ -562,17 +650,26 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
             self.channel.connection_delay,
         );
 
+        // The signers of the wallets selected to relay the packets on each chain
+        let src_signer = OnceCell::new();
+        let dst_signer = OnceCell::new();
+
         for event_with_height in input {
             trace!(event = %event_with_height, "processing event");
 
             if let Some(packet) = event_with_height.event.packet() {
                 // If the event is a ICS-04 packet event, and the packet contains ICS-20
                 // packet data, check that the ICS-20 fields are within the configured limits.
+                // The packets to receive must moreover pass the ICS-20 packet data filter.
+                let is_send_packet = matches!(event_with_height.event, IbcEvent::SendPacket(_));
+
                 if !check_ics20_fields_size(
                     &packet.data,
                     self.max_memo_size,
                     self.max_receiver_size,
-                ) {
+                ) || (is_send_packet
+                    && !check_ics20_packet_data(&packet.data, &self.ics20_filter))
+                {
                     telemetry!(
                         filtered_packets,
                         &self.src_chain().id(),
@@ -589,7 +686,7 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
 
             let (dst_msg, src_msg) = match &event_with_height.event {
                 IbcEvent::CloseInitChannel(_) => (
-                    self.build_chan_close_confirm_from_event(event_with_height)?,
+                    self.build_chan_close_confirm_from_event(event_with_height, &dst_signer)?,
                     None,
                 ),
                 IbcEvent::TimeoutPacket(_) => {
@@ -598,13 +695,16 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
                     // we get a timeout packet event (this happens for both unordered and ordered channels)
                     // Here we check that the channel is closed on src and send a channel close confirm
                     // to the counterparty.
-                    if self.ordered_channel()
+                    if self.channel_closes_on_timeout()
                         && self
                             .src_channel(QueryHeight::Specific(event_with_height.height))?
                             .state_matches(&ChannelState::Closed)
                     {
                         (
-                            self.build_chan_close_confirm_from_event(event_with_height)?,
+                            self.build_chan_close_confirm_from_event(
+                                event_with_height,
+                                &dst_signer,
+                            )?,
                             None,
                         )
                     } else {
@@ -621,6 +721,8 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
                             event,
                             &dst_latest_info,
                             event_with_height.height,
+                            &src_signer,
+                            &dst_signer,
                         )?
                     }
                 }
@@ -639,7 +741,11 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
                         (None, None)
                     } else {
                         (
-                            self.build_ack_from_recv_event(event, event_with_height.height)?,
+                            self.build_ack_from_recv_event(
+                                event,
+                                event_with_height.height,
+                                &dst_signer,
+                            )?,
                             None,
                         )
                     }
@@ -661,7 +767,7 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
             if let Some(msg) = src_msg {
                 // For Ordered channels a single timeout event should be sent as this closes the channel.
                 // Otherwise a multi message transaction will fail.
-                if self.unordered_channel() || src_od.batch.is_empty() {
+                if !self.channel_closes_on_timeout() || src_od.batch.is_empty() {
                     trace!(%msg.type_url, event = %event_with_height, "collected event");
 
                     src_od.batch.push(TransitMessage {
@@ -672,6 +778,9 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
             }
         }
 
+        src_od.signer = src_signer.into_inner();
+        dst_od.signer = dst_signer.into_inner();
+
         let src_od = Some(src_od).filter(|s| !s.batch.is_empty());
         let dst_od = Some(dst_od).filter(|s| !s.batch.is_empty());
 
@@ -685,7 +794,13 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
         initial_od: OperationalData,
     ) -> Result<S::Reply, LinkError> {
         // We will operate on potentially different operational data if the initial one fails.
-        let _span = span!(Level::INFO, "relay", odata = %initial_od.info()).entered();
+        let _span = span!(
+            Level::INFO,
+            "relay",
+            odata = %initial_od.info(),
+            tracking_id = %initial_od.tracking_id,
+        )
+        .entered();
 
         let mut odata = initial_od;
 
@@ -714,7 +829,9 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
 
                     return Ok(reply);
                 }
-                Err(LinkError(error::LinkErrorDetail::Send(_), _)) => {
+                Err(e @ LinkError(error::LinkErrorDetail::Send(_), _)) => {
+                    self.packet_errors.record(&odata, &e);
+
                     if i + 1 == MAX_RETRIES {
                         error!("{}/{} retries exhausted, giving up", i + 1, MAX_RETRIES)
                     } else {
@@ -729,6 +846,7 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
                 }
                 Err(e) => {
                     // Unrecoverable error, propagate up the stack
+                    self.packet_errors.record(&odata, &e);
                     return Err(e);
                 }
             }
@@ -842,9 +960,22 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
 
     fn enqueue_pending_tx(&self, reply: AsyncReply, odata: OperationalData) {
         if !self.confirm_txes {
+            // Without confirmations, there is no way to tell whether the packets
+            // were relayed, so we stop tracking them as soon as they are submitted.
+            self.untrack_packets(&odata);
             return;
         }
 
+        for response in reply.responses.iter().filter(|r| r.code.is_err()) {
+            self.packet_errors.record(
+                &odata,
+                format!(
+                    "tx {} failed with code {:?}: {}",
+                    response.hash, response.code, response.log
+                ),
+            );
+        }
+
         match odata.target {
             OperationalDataTarget::Source => {
                 self.pending_txs_src.insert_new_pending_tx(reply, odata);
@@ -1017,7 +1148,7 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
     ) -> Result<Height, LinkError> {
         info!( "sending update_client to client hosted on source chain for height {} (retries left: {})", src_chain_height, retries_left );
 
-        let dst_update = self.build_update_client_on_dst(src_chain_height)?;
+        let dst_update = self.build_update_client_on_dst(src_chain_height, self.dst_signer()?)?;
         let tm = TrackedMsgs::new(dst_update, tracking_id);
         let dst_tx_events = self
             .dst_chain()
@@ -1084,7 +1215,7 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
     ) -> Result<Height, LinkError> {
         info!("sending update_client to client hosted on source chain for height {} (retries left: {})", dst_chain_height, retries_left);
 
-        let src_update = self.build_update_client_on_src(dst_chain_height)?;
+        let src_update = self.build_update_client_on_src(dst_chain_height, self.src_signer()?)?;
         let tm = TrackedMsgs::new(src_update, tracking_id);
         let src_tx_events = self
             .src_chain()
@@ -1196,6 +1327,126 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
         Ok(())
     }
 
+    /// Schedules the relaying of the packets tracked in the relayer state store
+    /// for this path, which were not yet relayed when the relayer was last stopped.
+    ///
+    /// Packets which have been relayed in the meantime are not tracked anymore,
+    /// and the transactions which were pending are confirmed first.
+    pub fn schedule_tracked_packets(&self, tracking_id: TrackingId) -> Result<(), LinkError> {
+        let Some(store) = store::global() else {
+            return Ok(());
+        };
+
+        let _span = span!(Level::ERROR, "schedule_tracked_packets").entered();
+
+        self.pending_txs_src.confirm_stored_txs()?;
+        self.pending_txs_dst.confirm_stored_txs()?;
+
+        let tracked = store
+            .tracked_packets(&self.store_path)
+            .map_err(LinkError::store)?;
+
+        if tracked.is_empty() {
+            return Ok(());
+        }
+
+        let src_config = self.src_chain().config().map_err(LinkError::relayer)?;
+        let chunk_size = src_config.query_packets_chunk_size();
+
+        let (tracked_sends, tracked_acks): (Vec<_>, Vec<_>) = tracked
+            .into_iter()
+            .partition(|packet| packet.kind == TrackedPacketKind::Send);
+
+        if !tracked_sends.is_empty() {
+            let (unreceived, src_response_height) =
+                unreceived_packets(self.dst_chain(), self.src_chain(), &self.path_id)
+                    .map_err(LinkError::supervisor)?;
+
+            let (pending, relayed): (Vec<_>, Vec<_>) = tracked_sends
+                .into_iter()
+                .partition(|packet| unreceived.contains(&packet.sequence));
+
+            store
+                .untrack_packets(&self.store_path, &relayed)
+                .map_err(LinkError::store)?;
+
+            let sequences: Vec<Sequence> = pending.iter().map(|p| p.sequence).collect();
+
+            debug!(
+                sequences = %sequences.iter().copied().collated().format(", "),
+                "resuming relaying of tracked packets",
+            );
+
+            for events_chunk in query_packet_events_with(
+                &sequences,
+                Qualified::SmallerEqual(src_response_height),
+                self.src_chain(),
+                &self.path_id,
+                chunk_size,
+                query_send_packet_events,
+            ) {
+                self.events_to_operational_data(TrackedEvents::new(events_chunk, tracking_id))?;
+            }
+        }
+
+        let unreceived_acks = if tracked_acks.is_empty() {
+            None
+        } else {
+            unreceived_acknowledgements(self.dst_chain(), self.src_chain(), &self.path_id)
+                .map_err(LinkError::supervisor)?
+        };
+
+        if let Some((unreceived, src_response_height)) = unreceived_acks {
+            let (pending, relayed): (Vec<_>, Vec<_>) = tracked_acks
+                .into_iter()
+                .partition(|packet| unreceived.contains(&packet.sequence));
+
+            store
+                .untrack_packets(&self.store_path, &relayed)
+                .map_err(LinkError::store)?;
+
+            let sequences: Vec<Sequence> = pending.iter().map(|p| p.sequence).collect();
+
+            debug!(
+                sequences = %sequences.iter().copied().collated().format(", "),
+                "resuming relaying of tracked acknowledgements",
+            );
+
+            for events_chunk in query_packet_events_with(
+                &sequences,
+                Qualified::SmallerEqual(src_response_height),
+                self.src_chain(),
+                &self.path_id,
+                chunk_size,
+                query_write_ack_events,
+            ) {
+                self.events_to_operational_data(TrackedEvents::new(events_chunk, tracking_id))?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Records the packets relayed by the given operational data
+    /// in the relayer state store, if there is one.
+    fn track_packets(&self, od: &OperationalData) {
+        if let Some(store) = store::global() {
+            if let Err(e) = store.track_packets(&self.store_path, &od.tracked_packets()) {
+                warn!("failed to persist tracked packets: {}", e);
+            }
+        }
+    }
+
+    /// Stops tracking the packets relayed by the given operational data
+    /// in the relayer state store, if there is one.
+    fn untrack_packets(&self, od: &OperationalData) {
+        if let Some(store) = store::global() {
+            if let Err(e) = store.untrack_packets(&self.store_path, &od.tracked_packets()) {
+                warn!("failed to remove tracked packets: {}", e);
+            }
+        }
+    }
+
     /// Schedules the relaying of [`MsgAcknowledgement`] messages.
     ///
     /// The `opt_query_height` parameter allows to optionally use a specific height on the source
@@ -1259,7 +1510,12 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
         Ok(())
     }
 
-    fn build_recv_packet(&self, packet: &Packet, height: Height) -> Result<Option<Any>, LinkError> {
+    fn build_recv_packet(
+        &self,
+        packet: &Packet,
+        height: Height,
+        dst_signer: &OnceCell<Signer>,
+    ) -> Result<Option<Any>, LinkError> {
         let proofs = self
             .src_chain()
             .build_packet_proofs(
@@ -1271,7 +1527,11 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
             )
             .map_err(|e| LinkError::packet_proofs_constructor(self.src_chain().id(), e))?;
 
-        let msg = MsgRecvPacket::new(packet.clone(), proofs.clone(), self.dst_signer()?);
+        let msg = MsgRecvPacket::new(
+            packet.clone(),
+            proofs.clone(),
+            self.selected_dst_signer(dst_signer)?,
+        );
 
         trace!(packet = %packet, height = %proofs.height(), "built recv_packet msg");
 
@@ -1282,6 +1542,7 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
         &self,
         event: &WriteAcknowledgement,
         height: Height,
+        dst_signer: &OnceCell<Signer>,
     ) -> Result<Option<Any>, LinkError> {
         let packet = event.packet.clone();
 
@@ -1300,7 +1561,7 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
             packet,
             event.ack.clone().into(),
             proofs.clone(),
-            self.dst_signer()?,
+            self.selected_dst_signer(dst_signer)?,
         );
 
         trace!(packet = %msg.packet, height = %proofs.height(), "built acknowledgment msg");
@@ -1312,6 +1573,7 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
         &self,
         packet: &Packet,
         height: Height,
+        src_signer: &OnceCell<Signer>,
     ) -> Result<Option<Any>, LinkError> {
         let dst_channel_id = self.dst_channel_id();
 
@@ -1350,7 +1612,7 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
             packet.clone(),
             next_sequence_received,
             proofs.clone(),
-            self.src_signer()?,
+            self.selected_src_signer(src_signer)?,
         );
 
         trace!(packet = %msg.packet, height = %proofs.height(), "built timeout msg");
@@ -1362,6 +1624,7 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
         &self,
         packet: &Packet,
         height: Height,
+        src_signer: &OnceCell<Signer>,
     ) -> Result<Option<Any>, LinkError> {
         let dst_channel_id = self.dst_channel_id();
 
@@ -1399,7 +1662,7 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
             packet.clone(),
             next_sequence_received,
             proofs.clone(),
-            self.src_signer()?,
+            self.selected_src_signer(src_signer)?,
         );
 
         trace!(packet = %msg.packet, height = %proofs.height(), "built timeout on close msg");
@@ -1411,6 +1674,7 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
         &self,
         event: &SendPacket,
         dst_info: &ChainStatus,
+        src_signer: &OnceCell<Signer>,
     ) -> Result<Option<Any>, LinkError> {
         let packet = event.packet.clone();
 
@@ -1418,9 +1682,9 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
             .dst_channel(QueryHeight::Specific(dst_info.height))?
             .state_matches(&ChannelState::Closed)
         {
-            Ok(self.build_timeout_on_close_packet(&event.packet, dst_info.height)?)
+            Ok(self.build_timeout_on_close_packet(&event.packet, dst_info.height, src_signer)?)
         } else if packet.timed_out(&dst_info.timestamp, dst_info.height) {
-            Ok(self.build_timeout_packet(&event.packet, dst_info.height)?)
+            Ok(self.build_timeout_packet(&event.packet, dst_info.height, src_signer)?)
         } else {
             Ok(None)
         }
@@ -1431,6 +1695,8 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
         event: &SendPacket,
         dst_info: &ChainStatus,
         height: Height,
+        src_signer: &OnceCell<Signer>,
+        dst_signer: &OnceCell<Signer>,
     ) -> Result<(Option<Any>, Option<Any>), LinkError> {
         crate::time!(
             "build_recv_or_timeout_from_send_packet_event",
@@ -1440,12 +1706,15 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
             }
         );
 
-        let timeout = self.build_timeout_from_send_packet_event(event, dst_info)?;
+        let timeout = self.build_timeout_from_send_packet_event(event, dst_info, src_signer)?;
 
         if timeout.is_some() {
             Ok((None, timeout))
         } else {
-            Ok((self.build_recv_packet(&event.packet, height)?, None))
+            Ok((
+                self.build_recv_packet(&event.packet, height, dst_signer)?,
+                None,
+            ))
         }
     }
 
@@ -1546,9 +1815,9 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
         let src_od_iter = self.src_operational_data.take().into_iter();
 
         match self.execute_schedule_for_target_chain(src_od_iter, OperationalDataTarget::Source) {
-            Ok(unprocessed_src_data) => self.src_operational_data = unprocessed_src_data.into(),
+            Ok(unprocessed_src_data) => self.src_operational_data.replace(unprocessed_src_data),
             Err((unprocessed_src_data, e)) => {
-                self.src_operational_data = unprocessed_src_data.into();
+                self.src_operational_data.replace(unprocessed_src_data);
                 return Err(e);
             }
         }
@@ -1558,9 +1827,9 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
         match self
             .execute_schedule_for_target_chain(dst_od_iter, OperationalDataTarget::Destination)
         {
-            Ok(unprocessed_dst_data) => self.dst_operational_data = unprocessed_dst_data.into(),
+            Ok(unprocessed_dst_data) => self.dst_operational_data.replace(unprocessed_dst_data),
             Err((unprocessed_dst_data, e)) => {
-                self.dst_operational_data = unprocessed_dst_data.into();
+                self.dst_operational_data.replace(unprocessed_dst_data);
                 return Err(e);
             }
         }
@@ -1650,6 +1919,9 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
 
         let mut timed_out: HashMap<usize, OperationalData> = HashMap::default();
 
+        // The signer of the wallet selected to relay the timeouts on the source chain
+        let src_signer = OnceCell::new();
+
         // For each operational data targeting the destination chain...
         for (odata_pos, odata) in all_dst_odata.iter_mut().enumerate() {
             // ... check each `SendPacket` event, whether it should generate a timeout message
@@ -1665,9 +1937,11 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
                         // Catch any SendPacket event that timed-out
                         if self.send_packet_event_handled(event)? {
                             debug!(?event, "SendPacket event has already been handled");
-                        } else if let Some(new_msg) =
-                            self.build_timeout_from_send_packet_event(event, &dst_status)?
-                        {
+                        } else if let Some(new_msg) = self.build_timeout_from_send_packet_event(
+                            event,
+                            &dst_status,
+                            &src_signer,
+                        )? {
                             debug!(
                                 "found a timed-out message in the operational data: {}",
                                 odata.info(),
@@ -1721,7 +1995,9 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
         }
 
         // Schedule new operational data targeting the source chain
-        for (_, new_od) in timed_out.into_iter() {
+        for (_, mut new_od) in timed_out.into_iter() {
+            new_od.signer = src_signer.get().cloned();
+
             info!(
                 "re-scheduling from new timed-out batch of size {}",
                 new_od.batch.len()
@@ -1737,7 +2013,13 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
     /// If the relaying path has non-zero packet delays, this method also updates the client on the
     /// target chain with the appropriate headers.
     fn schedule_operational_data(&self, mut od: OperationalData) -> Result<(), LinkError> {
-        let _span = span!(Level::INFO, "schedule", odata = %od.info()).entered();
+        let _span = span!(
+            Level::INFO,
+            "schedule",
+            odata = %od.info(),
+            tracking_id = %od.tracking_id,
+        )
+        .entered();
 
         if od.batch.is_empty() {
             info!(
@@ -1772,6 +2054,8 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
 
         od.set_scheduled_time(scheduled_time);
 
+        self.track_packets(&od);
+
         match od.target {
             OperationalDataTarget::Source => self.src_operational_data.push_back(od),
             OperationalDataTarget::Destination => self.dst_operational_data.push_back(od),
@@ -1905,6 +2189,115 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
         }
     }
 
+    /// Records the end-to-end latency of the packets and acknowledgements relayed by the given
+    /// operational data, out of the events of the transactions which committed its messages.
+    ///
+    /// The latency is the difference between the time of the block which included the
+    /// SendPacket (resp. WriteAcknowledgement) event and the time of the block which
+    /// committed the RecvPacket (resp. Acknowledgement) message.
+    #[cfg(feature = "telemetry")]
+    pub(crate) fn record_packet_latencies(
+        &self,
+        odata: &OperationalData,
+        committed: &[IbcEventWithHeight],
+    ) {
+        let origin = match odata.target {
+            OperationalDataTarget::Source => OperationalDataTarget::Destination,
+            OperationalDataTarget::Destination => OperationalDataTarget::Source,
+        };
+
+        // Query the time of each block only once
+        let mut origin_times = HashMap::new();
+        let mut commit_times = HashMap::new();
+
+        for committed_event in committed {
+            let (packet, is_recv) = match &committed_event.event {
+                IbcEvent::ReceivePacket(ev) => (&ev.packet, true),
+                IbcEvent::AcknowledgePacket(ev) => (&ev.packet, false),
+                _ => continue,
+            };
+
+            let origin_event = odata
+                .batch
+                .iter()
+                .map(|msg| &msg.event_with_height)
+                .find(|ev| match &ev.event {
+                    IbcEvent::SendPacket(send) => {
+                        is_recv && send.packet.sequence == packet.sequence
+                    }
+                    IbcEvent::WriteAcknowledgement(ack) => {
+                        !is_recv && ack.packet.sequence == packet.sequence
+                    }
+                    _ => false,
+                });
+
+            let Some(origin_event) = origin_event else {
+                continue;
+            };
+
+            let sent_at = *origin_times
+                .entry(origin_event.height)
+                .or_insert_with(|| self.block_time(origin, origin_event.height));
+
+            let committed_at = *commit_times
+                .entry(committed_event.height)
+                .or_insert_with(|| self.block_time(odata.target, committed_event.height));
+
+            let Some(latency) = sent_at
+                .zip(committed_at)
+                .and_then(|(sent_at, committed_at)| committed_at.duration_since(&sent_at))
+            else {
+                continue;
+            };
+
+            // Both latencies are labelled with the end of the channel which sent the packet
+            let (chain, counterparty) = (self.src_chain().id(), self.dst_chain().id());
+            let (channel, port) = (self.src_channel_id(), self.src_port_id());
+
+            if is_recv {
+                ibc_telemetry::global().packet_recv_latency(
+                    latency,
+                    &chain,
+                    channel,
+                    port,
+                    &counterparty,
+                );
+            } else {
+                ibc_telemetry::global().packet_ack_latency(
+                    latency,
+                    &chain,
+                    channel,
+                    port,
+                    &counterparty,
+                );
+            }
+        }
+    }
+
+    /// The time of the block at the given height on the given chain,
+    /// or `None` if it could not be queried.
+    #[cfg(feature = "telemetry")]
+    fn block_time(&self, target: OperationalDataTarget, height: Height) -> Option<Timestamp> {
+        let request = QueryHostConsensusStateRequest {
+            height: QueryHeight::Specific(height),
+        };
+
+        let result = match target {
+            OperationalDataTarget::Source => self.src_chain().query_host_consensus_state(request),
+            OperationalDataTarget::Destination => {
+                self.dst_chain().query_host_consensus_state(request)
+            }
+        };
+
+        match result {
+            Ok(consensus_state) => Some(consensus_state.timestamp()),
+            Err(e) => {
+                debug!(%height, "failed to query block time for packet latency: {e}");
+                None
+            }
+        }
+    }
+
     #[cfg(feature = "telemetry")]
     fn record_cleared_send_packet(&self, event_with_height: &IbcEventWithHeight) {
         if let IbcEvent::SendPacket(send_packet_ev) = &event_with_height.event {
@@ -1977,3 +2370,26 @@ fn check_ics20_fields_size(
         }
     }
 }
+
+#[tracing::instrument(skip(data, filter))]
+fn check_ics20_packet_data(data: &[u8], filter: &Ics20Filter) -> bool {
+    if filter.is_empty() {
+        return true;
+    }
+
+    match serde_json::from_slice::<RawPacketData>(data) {
+        Ok(packet_data) => match filter.check(&packet_data) {
+            Ok(()) => true,
+            Err(field) => {
+                debug!("ICS-20 packet data rejected by the filter on its {field}, not relaying packet!");
+
+                false
+            }
+        },
+        Err(e) => {
+            trace!("failed to decode ICS20 packet data with error `{e}`");
+
+            true
+        }
+    }
+}
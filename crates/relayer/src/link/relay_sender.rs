@@ -7,6 +7,7 @@ use ibc_relayer_types::events::IbcEvent;
 
 use crate::chain::handle::ChainHandle;
 use crate::chain::tracking::TrackedMsgs;
+use crate::event::relay;
 use crate::link::error::LinkError;
 use crate::link::RelaySummary;
 use crate::util::pretty::{PrettyCode, PrettyEvents};
@@ -63,12 +64,16 @@ impl Submit for SyncSender {
 
         match ev {
             Some(ev) => Err(LinkError::send(ev.event)),
-            None => Ok(RelaySummary::from_events(
-                tx_events
+            None => {
+                let events = tx_events
                     .into_iter()
                     .map(|event_with_height| event_with_height.event)
-                    .collect(),
-            )),
+                    .collect::<Vec<_>>();
+
+                relay::publish_tx_events(&target.id(), &events);
+
+                Ok(RelaySummary::from_events(events))
+            }
         }
     }
 }
@@ -95,6 +100,8 @@ impl Submit for AsyncSender {
     type Reply = AsyncReply;
 
     fn submit(target: &impl ChainHandle, msgs: TrackedMsgs) -> Result<Self::Reply, LinkError> {
+        let tracking_id = msgs.tracking_id();
+
         let responses = target
             .send_messages_and_wait_check_tx(msgs)
             .map_err(LinkError::relayer)?;
@@ -106,6 +113,9 @@ impl Submit for AsyncSender {
         // The runtime deliberately did not catch or retry on such errors.
         info!(target_chain = %target.id(), "{}", reply);
 
+        let tx_hashes = reply.responses.iter().map(|r| r.hash).collect::<Vec<_>>();
+        relay::publish_tx_submitted(target.id(), tracking_id, &tx_hashes);
+
         Ok(reply)
     }
 }
@@ -1,9 +1,15 @@
 use serde::{Deserialize, Serialize};
 
 use ibc_proto::google::protobuf::Any;
+use ibc_relayer_types::clients::ics06_solomachine::misbehaviour::{
+    Misbehaviour as SmMisbehaviour, SOLOMACHINE_MISBEHAVIOUR_TYPE_URL,
+};
 use ibc_relayer_types::clients::ics07_tendermint::misbehaviour::{
     Misbehaviour as TmMisbehaviour, TENDERMINT_MISBEHAVIOR_TYPE_URL,
 };
+use ibc_relayer_types::clients::ics08_wasm::client_message::{
+    Misbehaviour as WasmMisbehaviour, WASM_CLIENT_MESSAGE_TYPE_URL,
+};
 use ibc_relayer_types::core::ics02_client::error::Error;
 use ibc_relayer_types::core::ics02_client::header::AnyHeader;
 use ibc_relayer_types::core::ics02_client::misbehaviour::Misbehaviour;
@@ -11,9 +17,9 @@ use ibc_relayer_types::core::ics24_host::identifier::ClientId;
 use ibc_relayer_types::Height;
 use tendermint_proto::Protobuf;
 
-#[cfg(test)]
+#[cfg(any(test, feature = "mock"))]
 use ibc_relayer_types::mock::misbehaviour::Misbehaviour as MockMisbehaviour;
-#[cfg(test)]
+#[cfg(any(test, feature = "mock"))]
 use ibc_relayer_types::mock::misbehaviour::MOCK_MISBEHAVIOUR_TYPE_URL;
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -27,7 +33,11 @@ pub struct MisbehaviourEvidence {
 pub enum AnyMisbehaviour {
     Tendermint(TmMisbehaviour),
 
-    #[cfg(test)]
+    Solomachine(SmMisbehaviour),
+
+    Wasm(WasmMisbehaviour),
+
+    #[cfg(any(test, feature = "mock"))]
     Mock(MockMisbehaviour),
 }
 
@@ -36,7 +46,11 @@ impl Misbehaviour for AnyMisbehaviour {
         match self {
             Self::Tendermint(misbehaviour) => misbehaviour.client_id(),
 
-            #[cfg(test)]
+            Self::Solomachine(misbehaviour) => misbehaviour.client_id(),
+
+            Self::Wasm(misbehaviour) => misbehaviour.client_id(),
+
+            #[cfg(any(test, feature = "mock"))]
             Self::Mock(misbehaviour) => misbehaviour.client_id(),
         }
     }
@@ -45,7 +59,11 @@ impl Misbehaviour for AnyMisbehaviour {
         match self {
             Self::Tendermint(misbehaviour) => misbehaviour.height(),
 
-            #[cfg(test)]
+            Self::Solomachine(misbehaviour) => misbehaviour.height(),
+
+            Self::Wasm(misbehaviour) => misbehaviour.height(),
+
+            #[cfg(any(test, feature = "mock"))]
             Self::Mock(misbehaviour) => misbehaviour.height(),
         }
     }
@@ -62,7 +80,15 @@ impl TryFrom<Any> for AnyMisbehaviour {
                 TmMisbehaviour::decode_vec(&raw.value).map_err(Error::decode_raw_misbehaviour)?,
             )),
 
-            #[cfg(test)]
+            SOLOMACHINE_MISBEHAVIOUR_TYPE_URL => Ok(AnyMisbehaviour::Solomachine(
+                SmMisbehaviour::decode_vec(&raw.value).map_err(Error::decode_raw_misbehaviour)?,
+            )),
+
+            WASM_CLIENT_MESSAGE_TYPE_URL => {
+                Ok(AnyMisbehaviour::Wasm(WasmMisbehaviour::try_from(raw)?))
+            }
+
+            #[cfg(any(test, feature = "mock"))]
             MOCK_MISBEHAVIOUR_TYPE_URL => Ok(AnyMisbehaviour::Mock(
                 MockMisbehaviour::decode_vec(&raw.value).map_err(Error::decode_raw_misbehaviour)?,
             )),
@@ -80,7 +106,14 @@ impl From<AnyMisbehaviour> for Any {
                 value: misbehaviour.encode_vec(),
             },
 
-            #[cfg(test)]
+            AnyMisbehaviour::Solomachine(misbehaviour) => Any {
+                type_url: SOLOMACHINE_MISBEHAVIOUR_TYPE_URL.to_string(),
+                value: misbehaviour.encode_vec(),
+            },
+
+            AnyMisbehaviour::Wasm(misbehaviour) => misbehaviour.into(),
+
+            #[cfg(any(test, feature = "mock"))]
             AnyMisbehaviour::Mock(misbehaviour) => Any {
                 type_url: MOCK_MISBEHAVIOUR_TYPE_URL.to_string(),
                 value: misbehaviour.encode_vec(),
@@ -94,7 +127,11 @@ impl core::fmt::Display for AnyMisbehaviour {
         match self {
             AnyMisbehaviour::Tendermint(tm) => write!(f, "{tm}"),
 
-            #[cfg(test)]
+            AnyMisbehaviour::Solomachine(sm) => write!(f, "{sm}"),
+
+            AnyMisbehaviour::Wasm(wasm) => write!(f, "{wasm}"),
+
+            #[cfg(any(test, feature = "mock"))]
             AnyMisbehaviour::Mock(mock) => write!(f, "{mock:?}"),
         }
     }
@@ -106,7 +143,19 @@ impl From<TmMisbehaviour> for AnyMisbehaviour {
     }
 }
 
-#[cfg(test)]
+impl From<SmMisbehaviour> for AnyMisbehaviour {
+    fn from(misbehaviour: SmMisbehaviour) -> Self {
+        Self::Solomachine(misbehaviour)
+    }
+}
+
+impl From<WasmMisbehaviour> for AnyMisbehaviour {
+    fn from(misbehaviour: WasmMisbehaviour) -> Self {
+        Self::Wasm(misbehaviour)
+    }
+}
+
+#[cfg(any(test, feature = "mock"))]
 impl From<MockMisbehaviour> for AnyMisbehaviour {
     fn from(misbehaviour: MockMisbehaviour) -> Self {
         Self::Mock(misbehaviour)
@@ -0,0 +1,352 @@
+//! Detection of light client attacks by cross-checking the headers served by the
+//! full node of a chain against the ones served by a set of witness full nodes,
+//! independently of any IBC client tracking that chain.
+
+use std::fs;
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+use tracing::{debug, error, warn};
+
+use tendermint::evidence::{ConflictingBlock, LightClientAttackEvidence};
+use tendermint::{Hash, Time};
+use tendermint_light_client::components::io::{AtHeight, Io, ProdIo};
+use tendermint_light_client::light_client::Options;
+use tendermint_light_client::verifier::types::{LightBlock, PeerId};
+use tendermint_light_client_detector::Divergence;
+use tendermint_rpc::{Client, HttpClient, Url};
+
+use ibc_relayer_types::core::ics24_host::identifier::ChainId;
+
+use crate::chain::cosmos::config::CosmosSdkConfig;
+use crate::error::Error;
+use crate::light_client::tendermint::detector;
+use crate::telemetry;
+use crate::util::block_on;
+
+/// A full node serving the headers of the monitored chain.
+struct Node {
+    url: Url,
+    peer_id: PeerId,
+    rpc_client: HttpClient,
+}
+
+impl Node {
+    fn connect(url: &Url) -> Result<Self, Error> {
+        let rpc_client = HttpClient::new(url.clone()).map_err(|e| Error::rpc(url.clone(), e))?;
+
+        let status = block_on(rpc_client.status()).map_err(|e| Error::rpc(url.clone(), e))?;
+
+        Ok(Self {
+            url: url.clone(),
+            peer_id: status.node_info.id,
+            rpc_client,
+        })
+    }
+}
+
+/// A divergence between the headers served by the primary full node of a chain
+/// and the ones served by one of its witnesses.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct DivergenceRecord {
+    pub chain_id: ChainId,
+    /// The height of the header of the primary which was cross-checked
+    pub height: u64,
+    pub primary: Url,
+    pub witness: Url,
+    pub witness_peer_id: PeerId,
+    pub detected_at: Time,
+    /// The evidence of the attack against the primary, to be reported to the witness
+    pub against_primary: LightClientAttackEvidence,
+    /// The block served by the witness which conflicts with the one of the primary
+    pub challenging_block: LightBlock,
+}
+
+impl DivergenceRecord {
+    /// The evidence of the attack with the conflicting block served by the witness,
+    /// as it would be found in the blocks of the chain once reported to the primary.
+    pub fn light_client_attack(&self) -> LightClientAttackEvidence {
+        LightClientAttackEvidence {
+            conflicting_block: ConflictingBlock {
+                signed_header: self.challenging_block.signed_header.clone(),
+                validator_set: self.challenging_block.validators.clone(),
+            },
+            ..self.against_primary.clone()
+        }
+    }
+
+    fn file_name(&self) -> String {
+        format!(
+            "{}-{}-{}.json",
+            self.chain_id, self.height, self.witness_peer_id
+        )
+    }
+}
+
+/// Cross-checks the latest header served by the primary full node of a chain,
+/// ie. the one at its `rpc_addr`, against the ones served by its `witnesses`.
+pub struct WitnessMonitor {
+    chain_id: ChainId,
+    options: Options,
+    primary: Node,
+    primary_io: ProdIo,
+    witnesses: Vec<Node>,
+    trusted_block: Option<LightBlock>,
+}
+
+impl WitnessMonitor {
+    /// Connect to the primary and to the witnesses of the given chain.
+    ///
+    /// The witnesses which cannot be reached are skipped, and an error is only
+    /// returned if none of them can be reached.
+    pub fn new(config: &CosmosSdkConfig, options: Options) -> Result<Self, Error> {
+        let primary = Node::connect(&config.rpc_addr)?;
+
+        let primary_io = ProdIo::new(
+            primary.peer_id,
+            primary.rpc_client.clone(),
+            Some(config.rpc_timeout),
+        );
+
+        let mut witnesses = Vec::with_capacity(config.witnesses.len());
+        let mut last_error = None;
+
+        for url in &config.witnesses {
+            match Node::connect(url) {
+                Ok(witness) => witnesses.push(witness),
+                Err(e) => {
+                    error!(
+                        "failed to connect to witness {url} of chain {}: {e}",
+                        config.id
+                    );
+                    last_error = Some(e);
+                }
+            }
+        }
+
+        if let (true, Some(e)) = (witnesses.is_empty(), last_error) {
+            return Err(e);
+        }
+
+        Ok(Self {
+            chain_id: config.id.clone(),
+            options,
+            primary,
+            primary_io,
+            witnesses,
+            trusted_block: None,
+        })
+    }
+
+    /// Cross-check the latest header of the primary against every witness, starting
+    /// from the header checked last time, and return the divergences detected.
+    ///
+    /// The first call only fetches the header to start from.
+    pub fn check(&mut self, now: Time) -> Result<Vec<DivergenceRecord>, Error> {
+        let target_block = self
+            .primary_io
+            .fetch_light_block(AtHeight::Highest)
+            .map_err(|e| Error::light_client_io(self.primary.url.to_string(), e))?;
+
+        let trusted_block = match self.trusted_block.replace(target_block.clone()) {
+            Some(trusted_block) if trusted_block.height() < target_block.height() => trusted_block,
+            _ => return Ok(vec![]),
+        };
+
+        let height = target_block.height().value();
+        let mut divergences = Vec::new();
+
+        for witness in &self.witnesses {
+            debug!(
+                "cross-checking header at height {height} of chain {} against witness {}",
+                self.chain_id, witness.url
+            );
+
+            telemetry!(witness_checks, &self.chain_id, &witness.url.to_string());
+
+            let divergence = detector::detect(
+                witness.peer_id,
+                witness.rpc_client.clone(),
+                target_block.clone(),
+                trusted_block.clone(),
+                &self.chain_id,
+                self.options,
+                now,
+            );
+
+            match divergence {
+                Ok(None) => {}
+                Ok(Some(Divergence {
+                    evidence,
+                    challenging_block,
+                })) => {
+                    warn!(
+                        "header at height {height} of chain {} served by {} diverges from the one served by witness {}",
+                        self.chain_id, self.primary.url, witness.url
+                    );
+
+                    telemetry!(witness_divergences, &self.chain_id, &witness.url.to_string());
+
+                    divergences.push(DivergenceRecord {
+                        chain_id: self.chain_id.clone(),
+                        height,
+                        primary: self.primary.url.clone(),
+                        witness: witness.url.clone(),
+                        witness_peer_id: witness.peer_id,
+                        detected_at: now,
+                        against_primary: evidence.against_primary,
+                        challenging_block,
+                    });
+                }
+                Err(e) => error!(
+                    "failed to cross-check header at height {height} of chain {} against witness {}: {e}",
+                    self.chain_id, witness.url
+                ),
+            }
+        }
+
+        Ok(divergences)
+    }
+
+    /// Report the evidence of the attack against the primary to the witness which detected it.
+    pub fn report_to_witness(&self, record: &DivergenceRecord) -> Result<Option<Hash>, Error> {
+        let Some(witness) = self.witnesses.iter().find(|w| w.url == record.witness) else {
+            return Ok(None);
+        };
+
+        detector::report_evidence(witness.rpc_client.clone(), record.against_primary.clone())
+            .map(Some)
+    }
+}
+
+/// A directory where the divergences detected are stored as JSON, one file per divergence.
+#[derive(Clone, Debug)]
+pub struct EvidenceArchive {
+    dir: PathBuf,
+}
+
+impl EvidenceArchive {
+    pub fn new(dir: impl Into<PathBuf>) -> Result<Self, Error> {
+        let dir = dir.into();
+
+        fs::create_dir_all(&dir).map_err(Error::io)?;
+
+        Ok(Self { dir })
+    }
+
+    /// Store the given divergence, returning the path of the file it was written to.
+    pub fn store(&self, record: &DivergenceRecord) -> Result<PathBuf, Error> {
+        let path = self.dir.join(record.file_name());
+
+        let json = serde_json::to_vec_pretty(record).map_err(|e| Error::io(e.into()))?;
+        fs::write(&path, json).map_err(Error::io)?;
+
+        Ok(path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use test_log::test;
+
+    use tendermint::block::Height as BlockHeight;
+    use tendermint::vote::Power;
+    use tendermint_testgen::light_block::TmLightBlock;
+    use tendermint_testgen::{Generator, LightBlock as TestgenLightBlock};
+
+    fn light_block(height: u64, time: i64) -> LightBlock {
+        let block: TmLightBlock = TestgenLightBlock::new_default_with_time_and_chain_id(
+            "chain-a".to_string(),
+            Time::from_unix_timestamp(time, 0).unwrap(),
+            height,
+        )
+        .generate()
+        .unwrap();
+
+        LightBlock::new(
+            block.signed_header,
+            block.validators,
+            block.next_validators,
+            block.provider,
+        )
+    }
+
+    fn record() -> DivergenceRecord {
+        let primary_block = light_block(10, 100);
+        let witness_block = light_block(10, 200);
+
+        DivergenceRecord {
+            chain_id: ChainId::from_string("chain-a"),
+            height: 10,
+            primary: "http://127.0.0.1:26657".parse().unwrap(),
+            witness: "http://127.0.0.1:26667".parse().unwrap(),
+            witness_peer_id: witness_block.provider,
+            detected_at: Time::from_unix_timestamp(300, 0).unwrap(),
+            against_primary: LightClientAttackEvidence {
+                conflicting_block: ConflictingBlock {
+                    signed_header: primary_block.signed_header,
+                    validator_set: primary_block.validators,
+                },
+                common_height: BlockHeight::from(5_u32),
+                byzantine_validators: vec![],
+                total_voting_power: Power::from(100_u32),
+                timestamp: Time::from_unix_timestamp(50, 0).unwrap(),
+            },
+            challenging_block: witness_block,
+        }
+    }
+
+    #[test]
+    fn light_client_attack_has_witness_block() {
+        let record = record();
+        let evidence = record.light_client_attack();
+
+        assert_eq!(
+            evidence.conflicting_block.signed_header,
+            record.challenging_block.signed_header
+        );
+        assert_eq!(
+            evidence.conflicting_block.validator_set,
+            record.challenging_block.validators
+        );
+        assert_ne!(
+            evidence.conflicting_block.signed_header,
+            record.against_primary.conflicting_block.signed_header
+        );
+
+        // The rest of the evidence is the one against the primary
+        assert_eq!(evidence.common_height, record.against_primary.common_height);
+        assert_eq!(
+            evidence.total_voting_power,
+            record.against_primary.total_voting_power
+        );
+        assert_eq!(evidence.timestamp, record.against_primary.timestamp);
+    }
+
+    #[test]
+    fn file_name() {
+        let record = record();
+
+        assert_eq!(
+            record.file_name(),
+            format!("chain-a-10-{}.json", record.witness_peer_id)
+        );
+    }
+
+    #[test]
+    fn archive_roundtrip() {
+        let dir = std::env::temp_dir().join(format!("hermes-evidence-{}", std::process::id()));
+        let archive = EvidenceArchive::new(&dir).unwrap();
+        let record = record();
+
+        let path = archive.store(&record).unwrap();
+        assert_eq!(path, dir.join(record.file_name()));
+
+        let stored: DivergenceRecord = serde_json::from_slice(&fs::read(&path).unwrap()).unwrap();
+        assert_eq!(stored, record);
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -8,7 +8,7 @@ use ibc_relayer_types::core::{
     ics02_client::events::UpdateClient,
     ics03_connection::events::Attributes as ConnectionAttributes,
     ics04_channel::events::{
-        Attributes, CloseInit, SendPacket, TimeoutPacket, WriteAcknowledgement,
+        Attributes, CloseInit, SendPacket, TimeoutPacket, UpgradeAttributes, WriteAcknowledgement,
     },
     ics24_host::identifier::{ChainId, ChannelId, ClientId, ConnectionId, PortId},
 };
@@ -447,6 +447,27 @@ impl Object {
         .into())
     }
 
+    /// Build the Channel object associated with the given channel upgrade event.
+    pub fn channel_from_chan_upgrade_events(
+        attributes: &UpgradeAttributes,
+        src_chain: &impl ChainHandle,
+    ) -> Result<Self, ObjectError> {
+        let channel_id = attributes.channel_id();
+        let port_id = attributes.port_id();
+
+        let dst_chain_id = channel_connection_client(src_chain, port_id, channel_id)
+            .map(|c| c.client.client_state.chain_id())
+            .map_err(ObjectError::supervisor)?;
+
+        Ok(Channel {
+            dst_chain_id,
+            src_chain_id: src_chain.id(),
+            src_channel_id: channel_id.clone(),
+            src_port_id: port_id.clone(),
+        }
+        .into())
+    }
+
     /// Build the object associated with the given [`SendPacket`] event.
     pub fn for_send_packet(
         e: &SendPacket,
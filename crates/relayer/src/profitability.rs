@@ -0,0 +1,495 @@
+//! Profitability accounting of the packets relayed by Hermes.
+//!
+//! The fees paid for the transactions submitted by Hermes are recorded when the
+//! transactions are broadcast, and attributed to the channel whose packets they
+//! relay once they are confirmed. They are matched with the ICS29 fees rewarded
+//! to Hermes for relaying the same packets, which are distributed on the chain
+//! which sent the packets once their acknowledgements or timeouts are committed.
+//!
+//! The transaction fees are paid on both ends of a channel, possibly in
+//! different denominations, so the amounts are accounted for per chain and
+//! denomination, without any conversion between them.
+//!
+//! Since the transactions are only accounted for once confirmed, the accounting
+//! is only enabled when Hermes confirms the transactions it submits, ie. when
+//! `tx_confirmation` is set in the `[mode.packets]` section of the configuration.
+
+use alloc::collections::BTreeMap;
+use core::sync::atomic::{AtomicBool, Ordering};
+use core::time::Duration;
+use std::sync::Mutex;
+
+use ibc_proto::cosmos::base::v1beta1::Coin as ProtoCoin;
+use moka::sync::Cache;
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+use tendermint::Hash as TxHash;
+
+use ibc_relayer_types::applications::ics29_fee::events::DistributionType;
+use ibc_relayer_types::applications::transfer::{Amount, RawCoin};
+use ibc_relayer_types::core::ics24_host::identifier::{ChainId, ChannelId, PortId};
+use ibc_relayer_types::events::IbcEvent;
+use ibc_relayer_types::signer::Signer;
+
+use crate::event::IbcEventWithHeight;
+use crate::telemetry;
+
+/// How long the fee of a transaction is remembered while waiting for its confirmation.
+const TX_FEE_LIFETIME: Duration = Duration::from_secs(60 * 60);
+
+/// The maximum number of transactions whose fees are remembered.
+const MAX_TX_FEES: u64 = 10_000;
+
+/// The fees paid for the transactions recently broadcast by Hermes, by hash.
+static TX_FEES: Lazy<Cache<TxHash, Vec<RawCoin>>> = Lazy::new(|| {
+    Cache::builder()
+        .time_to_live(TX_FEE_LIFETIME)
+        .max_capacity(MAX_TX_FEES)
+        .build()
+});
+
+static LEDGER: Lazy<Mutex<Ledger>> = Lazy::new(Default::default);
+
+/// Whether the transactions broadcast by Hermes are accounted for.
+static ENABLED: AtomicBool = AtomicBool::new(false);
+
+/// Identifies a channel by its end on the chain which sends the packets relayed on it,
+/// on which the ICS29 fees for relaying the packets are escrowed and distributed.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct ChannelKey {
+    pub chain_id: ChainId,
+    pub port_id: PortId,
+    pub channel_id: ChannelId,
+    pub counterparty_chain_id: ChainId,
+}
+
+impl ChannelKey {
+    pub fn new(
+        chain_id: ChainId,
+        port_id: PortId,
+        channel_id: ChannelId,
+        counterparty_chain_id: ChainId,
+    ) -> Self {
+        Self {
+            chain_id,
+            port_id,
+            channel_id,
+            counterparty_chain_id,
+        }
+    }
+}
+
+/// How profitable relaying the packets sent on a channel was, since Hermes started.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ChannelProfitability {
+    #[serde(flatten)]
+    pub channel: ChannelKey,
+    /// The number of packets received, acknowledged or timed out by Hermes.
+    pub packets_relayed: u64,
+    /// The amounts collected and paid, per chain and denomination.
+    pub balances: Vec<DenomBalance>,
+}
+
+/// The ICS29 fees collected and the transaction fees paid in a denomination of a chain.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DenomBalance {
+    pub chain_id: ChainId,
+    pub denom: String,
+    pub fees_collected: String,
+    pub gas_paid: String,
+    /// The fees collected minus the gas paid,
+    /// negative if relaying on the channel costs more than it earns.
+    pub net_profit: String,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct Balance {
+    fees_collected: Amount,
+    gas_paid: Amount,
+}
+
+impl Default for Balance {
+    fn default() -> Self {
+        Self {
+            fees_collected: Amount::from(0u64),
+            gas_paid: Amount::from(0u64),
+        }
+    }
+}
+
+impl Balance {
+    fn net_profit(&self) -> String {
+        match self.fees_collected.checked_sub(self.gas_paid) {
+            Some(profit) => profit.to_string(),
+            None => format!("-{}", self.gas_paid.0 - self.fees_collected.0),
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+struct ChannelLedger {
+    packets_relayed: u64,
+    balances: BTreeMap<(ChainId, String), Balance>,
+}
+
+#[derive(Debug, Default)]
+struct Ledger {
+    channels: BTreeMap<ChannelKey, ChannelLedger>,
+}
+
+impl Ledger {
+    fn channel(&mut self, channel: &ChannelKey) -> &mut ChannelLedger {
+        self.channels.entry(channel.clone()).or_default()
+    }
+
+    fn record_packets_relayed(&mut self, channel: &ChannelKey, count: u64) {
+        self.channel(channel).packets_relayed += count;
+    }
+
+    fn record_gas_paid(&mut self, channel: &ChannelKey, fee_chain_id: &ChainId, fee: &RawCoin) {
+        let balance = self
+            .channel(channel)
+            .balances
+            .entry((fee_chain_id.clone(), fee.denom.clone()))
+            .or_default();
+
+        balance.gas_paid = balance.gas_paid + fee.amount;
+
+        telemetry!(
+            channel_gas_paid,
+            &channel.chain_id,
+            &channel.channel_id,
+            &channel.port_id,
+            &channel.counterparty_chain_id,
+            fee_chain_id,
+            fee
+        );
+
+        telemetry!(observe_net_profit(
+            channel,
+            fee_chain_id,
+            &fee.denom,
+            balance
+        ));
+    }
+
+    fn record_fees_collected(&mut self, channel: &ChannelKey, fee: &RawCoin) {
+        let balance = self
+            .channel(channel)
+            .balances
+            .entry((channel.chain_id.clone(), fee.denom.clone()))
+            .or_default();
+
+        balance.fees_collected = balance.fees_collected + fee.amount;
+
+        telemetry!(
+            channel_fees_collected,
+            &channel.chain_id,
+            &channel.channel_id,
+            &channel.port_id,
+            &channel.counterparty_chain_id,
+            fee
+        );
+
+        telemetry!(observe_net_profit(
+            channel,
+            &channel.chain_id,
+            &fee.denom,
+            balance
+        ));
+    }
+
+    fn report(&self, chain_id: Option<&ChainId>) -> Vec<ChannelProfitability> {
+        self.channels
+            .iter()
+            .filter(|(channel, _)| {
+                chain_id.map_or(true, |chain_id| {
+                    &channel.chain_id == chain_id || &channel.counterparty_chain_id == chain_id
+                })
+            })
+            .map(|(channel, ledger)| ChannelProfitability {
+                channel: channel.clone(),
+                packets_relayed: ledger.packets_relayed,
+                balances: ledger
+                    .balances
+                    .iter()
+                    .map(|((chain_id, denom), balance)| DenomBalance {
+                        chain_id: chain_id.clone(),
+                        denom: denom.clone(),
+                        fees_collected: balance.fees_collected.to_string(),
+                        gas_paid: balance.gas_paid.to_string(),
+                        net_profit: balance.net_profit(),
+                    })
+                    .collect(),
+            })
+            .collect()
+    }
+}
+
+#[cfg(feature = "telemetry")]
+fn observe_net_profit(
+    channel: &ChannelKey,
+    fee_chain_id: &ChainId,
+    denom: &str,
+    balance: &Balance,
+) {
+    let net_profit = balance.net_profit().parse().unwrap_or_default();
+
+    ibc_telemetry::global().channel_net_profit(
+        &channel.chain_id,
+        &channel.channel_id,
+        &channel.port_id,
+        &channel.counterparty_chain_id,
+        fee_chain_id,
+        denom,
+        net_profit,
+    );
+}
+
+/// Enable the accounting of the transactions broadcast by Hermes,
+/// which must only be done if they are confirmed.
+pub fn enable() {
+    ENABLED.store(true, Ordering::Relaxed);
+}
+
+/// Remember the fee paid for a transaction broadcast by Hermes,
+/// until the transaction is confirmed and accounted for.
+///
+/// Does nothing unless the accounting is [enabled](enable), since the
+/// fees of the transactions which are never confirmed would pile up.
+pub fn record_tx_fee(tx_hash: TxHash, fee: &[ProtoCoin]) {
+    if !ENABLED.load(Ordering::Relaxed) {
+        return;
+    }
+
+    let fee = fee
+        .iter()
+        .cloned()
+        .filter_map(|coin| RawCoin::try_from(coin).ok())
+        .collect();
+
+    TX_FEES.insert(tx_hash, fee);
+}
+
+/// Account for the confirmed transactions, submitted to the chain `chain_id`,
+/// which relayed the packets sent on the given channel, or their acknowledgements
+/// or timeouts.
+///
+/// The fees of the transactions are attributed to the channel as a whole, including
+/// the fees of the client updates submitted along with the packets. The ICS29 fees
+/// distributed by the transactions are only accounted for when they are rewarded
+/// to one of the addresses returned by `relayers`, ie. the addresses of the wallets
+/// of Hermes on the chain, which is only called if there are any.
+pub fn record_confirmed_txs(
+    channel: &ChannelKey,
+    chain_id: &ChainId,
+    tx_hashes: &[TxHash],
+    events: &[IbcEventWithHeight],
+    relayers: impl FnOnce() -> Vec<Signer>,
+) {
+    let fees = tx_hashes
+        .iter()
+        .filter_map(|tx_hash| TX_FEES.remove(tx_hash))
+        .flatten()
+        .collect::<Vec<_>>();
+
+    let packets_relayed = events
+        .iter()
+        .filter(|ev| {
+            matches!(
+                ev.event,
+                IbcEvent::ReceivePacket(_)
+                    | IbcEvent::AcknowledgePacket(_)
+                    | IbcEvent::TimeoutPacket(_)
+                    | IbcEvent::TimeoutOnClosePacket(_)
+            )
+        })
+        .count() as u64;
+
+    let rewards = events
+        .iter()
+        .filter_map(|ev| match &ev.event {
+            IbcEvent::DistributeFeePacket(dist)
+                if dist.distribution_type == DistributionType::Reward =>
+            {
+                Some(dist)
+            }
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+
+    let relayers = if rewards.is_empty() {
+        vec![]
+    } else {
+        relayers()
+    };
+
+    let mut ledger = LEDGER.lock().unwrap();
+
+    ledger.record_packets_relayed(channel, packets_relayed);
+
+    for fee in &fees {
+        ledger.record_gas_paid(channel, chain_id, fee);
+    }
+
+    for dist in rewards
+        .into_iter()
+        .filter(|dist| relayers.contains(&dist.receiver))
+    {
+        ledger.record_fees_collected(channel, &dist.fee);
+    }
+}
+
+/// Returns how profitable relaying was on each channel since Hermes started,
+/// optionally restricted to the channels with an end on the given chain.
+pub fn report(chain_id: Option<&ChainId>) -> Vec<ChannelProfitability> {
+    LEDGER.lock().unwrap().report(chain_id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use core::str::FromStr;
+
+    use test_log::test;
+
+    use ibc_relayer_types::applications::ics29_fee::events::DistributeFeePacket;
+    use ibc_relayer_types::core::ics04_channel::events::AcknowledgePacket;
+    use ibc_relayer_types::core::ics04_channel::packet::Packet;
+    use ibc_relayer_types::Height;
+
+    fn channel(chain_id: &str, counterparty_chain_id: &str) -> ChannelKey {
+        ChannelKey::new(
+            ChainId::from_string(chain_id),
+            PortId::transfer(),
+            ChannelId::new(0),
+            ChainId::from_string(counterparty_chain_id),
+        )
+    }
+
+    fn coin(amount: u64, denom: &str) -> RawCoin {
+        RawCoin::new(denom.to_string(), amount)
+    }
+
+    fn proto_coin(amount: u64, denom: &str) -> ProtoCoin {
+        ProtoCoin {
+            denom: denom.to_string(),
+            amount: amount.to_string(),
+        }
+    }
+
+    fn balance(chain_id: &str, denom: &str, fees: &str, gas: &str, net: &str) -> DenomBalance {
+        DenomBalance {
+            chain_id: ChainId::from_string(chain_id),
+            denom: denom.to_string(),
+            fees_collected: fees.to_string(),
+            gas_paid: gas.to_string(),
+            net_profit: net.to_string(),
+        }
+    }
+
+    fn event(event: IbcEvent) -> IbcEventWithHeight {
+        IbcEventWithHeight::new(event, Height::new(0, 10).unwrap())
+    }
+
+    fn reward(receiver: &str, amount: u64) -> IbcEvent {
+        IbcEvent::DistributeFeePacket(DistributeFeePacket {
+            receiver: Signer::from_str(receiver).unwrap(),
+            fee: coin(amount, "stake"),
+            distribution_type: DistributionType::Reward,
+        })
+    }
+
+    #[test]
+    fn net_profit_per_chain_and_denom() {
+        let mut ledger = Ledger::default();
+
+        let chain_a = ChainId::from_string("chain_a");
+        let chain_b = ChainId::from_string("chain_b");
+        let channel = channel("chain_a", "chain_b");
+
+        ledger.record_packets_relayed(&channel, 2);
+        ledger.record_fees_collected(&channel, &coin(500, "stake"));
+        ledger.record_gas_paid(&channel, &chain_a, &coin(100, "stake"));
+        ledger.record_gas_paid(&channel, &chain_a, &coin(200, "stake"));
+        ledger.record_gas_paid(&channel, &chain_b, &coin(150, "stake"));
+
+        assert_eq!(
+            ledger.report(None),
+            vec![ChannelProfitability {
+                channel,
+                packets_relayed: 2,
+                balances: vec![
+                    balance("chain_a", "stake", "500", "300", "200"),
+                    balance("chain_b", "stake", "0", "150", "-150"),
+                ],
+            }]
+        );
+    }
+
+    #[test]
+    fn report_for_chain() {
+        let mut ledger = Ledger::default();
+
+        ledger.record_packets_relayed(&channel("chain_a", "chain_b"), 1);
+        ledger.record_packets_relayed(&channel("chain_b", "chain_c"), 1);
+        ledger.record_packets_relayed(&channel("chain_c", "chain_d"), 1);
+
+        let report = ledger.report(Some(&ChainId::from_string("chain_b")));
+
+        assert_eq!(
+            report
+                .into_iter()
+                .map(|profitability| profitability.channel)
+                .collect::<Vec<_>>(),
+            vec![channel("chain_a", "chain_b"), channel("chain_b", "chain_c")]
+        );
+    }
+
+    #[test]
+    fn confirmed_ack_tx() {
+        let chain_id = ChainId::from_string("profitability_src");
+        let channel = channel("profitability_src", "profitability_dst");
+
+        let relayer = "cosmos1relayer";
+        let other_wallet = "cosmos1otherwallet";
+        let tx_hash = TxHash::Sha256([42; 32]);
+
+        enable();
+        record_tx_fee(tx_hash, &[proto_coin(120, "stake")]);
+
+        let events = [
+            event(IbcEvent::AcknowledgePacket(AcknowledgePacket {
+                packet: Packet::default(),
+            })),
+            event(reward("cosmos1forward", 300)),
+            event(reward(relayer, 200)),
+            event(reward(other_wallet, 50)),
+            event(IbcEvent::DistributeFeePacket(DistributeFeePacket {
+                receiver: Signer::from_str("cosmos1sender").unwrap(),
+                fee: coin(100, "stake"),
+                distribution_type: DistributionType::Refund,
+            })),
+        ];
+
+        record_confirmed_txs(&channel, &chain_id, &[tx_hash], &events, || {
+            vec![
+                Signer::from_str(relayer).unwrap(),
+                Signer::from_str(other_wallet).unwrap(),
+            ]
+        });
+
+        // The fee of the transaction is only accounted for once
+        record_confirmed_txs(&channel, &chain_id, &[tx_hash], &[], Vec::new);
+
+        assert_eq!(
+            report(Some(&chain_id)),
+            vec![ChannelProfitability {
+                channel,
+                packets_relayed: 1,
+                balances: vec![balance("profitability_src", "stake", "250", "120", "130")],
+            }]
+        );
+    }
+}
@@ -0,0 +1,326 @@
+//! Recovery of expired or frozen clients, by substituting them with
+//! a fresh client through a governance proposal.
+
+use core::time::Duration;
+use std::thread;
+
+use flex_error::define_error;
+use serde::Serialize;
+use tracing::{info, warn};
+
+use tendermint::Hash as TxHash;
+
+use ibc_proto::cosmos::base::v1beta1::Coin;
+use ibc_proto::google::protobuf::Any;
+use ibc_proto::ibc::core::client::v1::{ClientUpdateProposal, MsgRecoverClient};
+use ibc_relayer_types::clients::ics07_tendermint::client_state::ClientState as TmClientState;
+use ibc_relayer_types::core::ics24_host::identifier::{ChainId, ClientId};
+use ibc_relayer_types::{downcast, Height};
+
+use crate::chain::handle::ChainHandle;
+use crate::chain::requests::{IncludeProof, QueryClientStateRequest, QueryHeight};
+use crate::chain::tracking::TrackedMsgs;
+use crate::client_state::AnyClientState;
+use crate::error::Error;
+use crate::foreign_client::{extract_client_id, CreateOptions, ForeignClient, ForeignClientError};
+use crate::upgrade_chain::{
+    build_legacy_submit_proposal, build_submit_proposal, requires_legacy_upgrade_proposal,
+};
+
+/// How many times the substitute client is updated, waiting for
+/// it to get past the latest height of the client to recover.
+const MAX_SUBSTITUTE_UPDATES: u64 = 5;
+
+/// How long to wait for the reference chain to produce new blocks
+/// before updating the substitute client again.
+const SUBSTITUTE_UPDATE_DELAY: Duration = Duration::from_secs(5);
+
+define_error! {
+    RecoverClientError {
+        Query
+            [ Error ]
+            |_| { "error during a query" },
+
+        Key
+            [ Error ]
+            |_| { "key error" },
+
+        Submit
+            { chain_id: ChainId }
+            [ Error ]
+            |e| {
+                format!("failed while submitting the client recovery proposal to chain {0}", e.chain_id)
+            },
+
+        CreateSubstitute
+            [ ForeignClientError ]
+            |_| { "failed to create the substitute client" },
+
+        UpdateSubstitute
+            [ ForeignClientError ]
+            |_| { "failed to update the substitute client" },
+
+        TendermintOnly
+            |_| { "only Tendermint clients can be recovered" },
+
+        ClientActive
+            { client_id: ClientId }
+            |e| {
+                format!("client {} is neither expired nor frozen, there is no need to recover it", e.client_id)
+            },
+
+        SubstituteInactive
+            { client_id: ClientId }
+            |e| {
+                format!("substitute client {} is expired or frozen", e.client_id)
+            },
+
+        SubstituteBehind
+            {
+                client_id: ClientId,
+                height: Height,
+                subject_height: Height,
+            }
+            |e| {
+                format!("substitute client {} at height {} is not past the height {} of the client to recover",
+                    e.client_id, e.height, e.subject_height)
+            },
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct RecoverClientOptions {
+    /// The expired or frozen client to recover.
+    pub subject_client_id: ClientId,
+    /// The client to substitute it with, or `None` to create a new one.
+    pub substitute_client_id: Option<ClientId>,
+    pub amount: u64,
+    pub denom: String,
+    pub gov_account: String,
+}
+
+/// The outcome of a successful client recovery.
+#[derive(Clone, Debug, Serialize)]
+pub struct RecoveredClient {
+    pub subject_client_id: ClientId,
+    pub substitute_client_id: ClientId,
+    pub proposal_tx_hash: TxHash,
+}
+
+/// Submit a governance proposal to recover an expired or frozen client
+/// hosted on `host_chain`, by substituting it with a healthy client.
+///
+/// If no substitute client is given, a new one is created with the same
+/// parameters as the client to recover. The substitute client is then updated
+/// until it is past the latest height of the client to recover, as required
+/// for the proposal to pass.
+pub fn recover_client(
+    host_chain: impl ChainHandle, // the chain hosting the client to recover
+    reference_chain: impl ChainHandle, // the chain tracked by the client to recover
+    opts: &RecoverClientOptions,
+) -> Result<RecoveredClient, RecoverClientError> {
+    let subject = ForeignClient::restore(
+        opts.subject_client_id.clone(),
+        host_chain.clone(),
+        reference_chain.clone(),
+    );
+
+    if !subject.is_expired_or_frozen() {
+        return Err(RecoverClientError::client_active(
+            opts.subject_client_id.clone(),
+        ));
+    }
+
+    let substitute_client_id = match &opts.substitute_client_id {
+        Some(client_id) => client_id.clone(),
+        None => create_substitute_client(
+            host_chain.clone(),
+            reference_chain.clone(),
+            &opts.subject_client_id,
+        )?,
+    };
+
+    wait_for_substitute_client(
+        host_chain.clone(),
+        reference_chain,
+        &opts.subject_client_id,
+        &substitute_client_id,
+    )?;
+
+    let proposal_tx_hash =
+        build_and_send_recover_client_proposal(host_chain, &substitute_client_id, opts)?;
+
+    Ok(RecoveredClient {
+        subject_client_id: opts.subject_client_id.clone(),
+        substitute_client_id,
+        proposal_tx_hash,
+    })
+}
+
+/// Create a client on `host_chain` tracking `reference_chain`, with the same
+/// trusting period, maximum clock drift and trust threshold as the given client,
+/// so that it can substitute it.
+pub fn create_substitute_client(
+    host_chain: impl ChainHandle,
+    reference_chain: impl ChainHandle,
+    subject_client_id: &ClientId,
+) -> Result<ClientId, RecoverClientError> {
+    let subject_state = query_tendermint_client_state(&host_chain, subject_client_id)?;
+
+    let options = CreateOptions {
+        max_clock_drift: Some(subject_state.max_clock_drift),
+        trusting_period: Some(subject_state.trusting_period),
+        trust_threshold: Some(subject_state.trust_threshold),
+        wasm_checksum: None,
+    };
+
+    let client = ForeignClient::restore(ClientId::default(), host_chain, reference_chain);
+
+    let event = client
+        .build_create_client_and_send(options)
+        .map_err(RecoverClientError::create_substitute)?;
+
+    let client_id = extract_client_id(&event.event)
+        .map_err(RecoverClientError::create_substitute)?
+        .clone();
+
+    info!("created substitute client {client_id} for client {subject_client_id}");
+
+    Ok(client_id)
+}
+
+/// Wait for the substitute client to be past the latest height of the
+/// client to recover, updating it to the latest height of the reference chain.
+pub fn wait_for_substitute_client(
+    host_chain: impl ChainHandle,
+    reference_chain: impl ChainHandle,
+    subject_client_id: &ClientId,
+    substitute_client_id: &ClientId,
+) -> Result<(), RecoverClientError> {
+    let subject_height =
+        query_tendermint_client_state(&host_chain, subject_client_id)?.latest_height();
+
+    let substitute = ForeignClient::restore(
+        substitute_client_id.clone(),
+        host_chain.clone(),
+        reference_chain,
+    );
+
+    if substitute.is_expired_or_frozen() {
+        return Err(RecoverClientError::substitute_inactive(
+            substitute_client_id.clone(),
+        ));
+    }
+
+    let mut updates = 0;
+
+    loop {
+        let height =
+            query_tendermint_client_state(&host_chain, substitute_client_id)?.latest_height();
+
+        if height > subject_height {
+            return Ok(());
+        }
+
+        if updates == MAX_SUBSTITUTE_UPDATES {
+            return Err(RecoverClientError::substitute_behind(
+                substitute_client_id.clone(),
+                height,
+                subject_height,
+            ));
+        }
+
+        if updates > 0 {
+            thread::sleep(SUBSTITUTE_UPDATE_DELAY);
+        }
+
+        warn!(
+            "substitute client {substitute_client_id} at height {height} is not past \
+            the height {subject_height} of client {subject_client_id}, updating it"
+        );
+
+        substitute
+            .build_latest_update_client_and_send()
+            .map_err(RecoverClientError::update_substitute)?;
+
+        updates += 1;
+    }
+}
+
+/// Submit the proposal substituting the client to recover with the given client.
+///
+/// Chains with ibc-go v8.0.0 or higher recover clients with a `MsgRecoverClient`,
+/// while the older ones rely on the deprecated `ClientUpdateProposal`.
+pub fn build_and_send_recover_client_proposal(
+    host_chain: impl ChainHandle,
+    substitute_client_id: &ClientId,
+    opts: &RecoverClientOptions,
+) -> Result<TxHash, RecoverClientError> {
+    let proposer = host_chain.get_signer().map_err(RecoverClientError::key)?;
+
+    let deposit = Coin {
+        denom: opts.denom.clone(),
+        amount: opts.amount.to_string(),
+    };
+
+    let title = format!("Recover client {}", opts.subject_client_id);
+    let summary = format!(
+        "Substitute the expired or frozen client {} with client {}",
+        opts.subject_client_id, substitute_client_id
+    );
+
+    let msg = if requires_legacy_upgrade_proposal(host_chain.clone()) {
+        let proposal = ClientUpdateProposal {
+            title,
+            description: summary,
+            subject_client_id: opts.subject_client_id.to_string(),
+            substitute_client_id: substitute_client_id.to_string(),
+        };
+
+        let content = Any {
+            type_url: "/ibc.core.client.v1.ClientUpdateProposal".to_string(),
+            value: prost::Message::encode_to_vec(&proposal),
+        };
+
+        build_legacy_submit_proposal(content, deposit, &proposer)
+    } else {
+        let recover = MsgRecoverClient {
+            subject_client_id: opts.subject_client_id.to_string(),
+            substitute_client_id: substitute_client_id.to_string(),
+            signer: opts.gov_account.clone(),
+        };
+
+        let message = Any {
+            type_url: "/ibc.core.client.v1.MsgRecoverClient".to_string(),
+            value: prost::Message::encode_to_vec(&recover),
+        };
+
+        build_submit_proposal(vec![message], deposit, &proposer, &title, &summary)
+    };
+
+    // As for chain upgrades, no IBC event is emitted by the transaction
+    // which could be used to confirm it, so only wait for the check tx.
+    let responses = host_chain
+        .send_messages_and_wait_check_tx(TrackedMsgs::new_single(msg, "recover client"))
+        .map_err(|e| RecoverClientError::submit(host_chain.id(), e))?;
+
+    Ok(responses[0].hash)
+}
+
+fn query_tendermint_client_state(
+    host_chain: &impl ChainHandle,
+    client_id: &ClientId,
+) -> Result<TmClientState, RecoverClientError> {
+    let (client_state, _) = host_chain
+        .query_client_state(
+            QueryClientStateRequest {
+                client_id: client_id.clone(),
+                height: QueryHeight::Latest,
+            },
+            IncludeProof::No,
+        )
+        .map_err(RecoverClientError::query)?;
+
+    downcast!(client_state => AnyClientState::Tendermint)
+        .ok_or_else(RecoverClientError::tendermint_only)
+}
@@ -41,6 +41,14 @@ impl<Chain: ChainHandle> Registry<Chain> {
         }
     }
 
+    /// Replace the configuration used to spawn new chain runtimes,
+    /// eg. after a chain was added to the relayer at runtime.
+    ///
+    /// Runtimes which are already running are left untouched.
+    pub fn set_config(&mut self, config: Config) {
+        self.config = config;
+    }
+
     /// Return the size of the registry, i.e., the number of distinct chain runtimes.
     pub fn size(&self) -> usize {
         self.handles.len()
@@ -1,9 +1,16 @@
 use crossbeam_channel::TryRecvError;
-use ibc_relayer_types::core::ics24_host::identifier::ChainId;
+use ibc_relayer_types::core::{
+    ics04_channel::packet::Sequence,
+    ics24_host::identifier::{ChainId, ChannelId, PortId},
+};
 use tracing::{error, trace};
 
 use crate::{
-    config::Config,
+    chain::counterparty::PacketTrace,
+    config::{filter::PacketFilter, ChainConfig, Config},
+    event::relay,
+    object::Object,
+    profitability,
     rest::request::ReplySender,
     rest::request::{Request, VersionInfo},
     supervisor::dump_state::SupervisorState,
@@ -26,13 +33,21 @@ pub const VER: &str = env!(
 pub type Receiver = crossbeam_channel::Receiver<Request>;
 
 // TODO: Unify this enum with `SupervisorCmd`
-//  We won't unify yet as it is possible we will never implement
-//  REST API `/chain` adding endpoint; instead of `/chain` we might
-//  implement `/reload` for supporting a broader range of functionality
-//  e.g., adjusting chain config, removing chains, etc.
 pub enum Command {
     DumpState(ReplySender<SupervisorState>),
     ClearPackets(Option<ChainId>, ReplySender<()>),
+    AddChain(Box<ChainConfig>, ReplySender<()>),
+    RemoveChain(ChainId, ReplySender<()>),
+    UpdatePacketFilter(ChainId, PacketFilter, ReplySender<()>),
+    PauseWorker(Object, ReplySender<()>),
+    ResumeWorker(Object, ReplySender<()>),
+    PacketTrace(
+        ChainId,
+        PortId,
+        ChannelId,
+        Sequence,
+        ReplySender<PacketTrace>,
+    ),
 }
 
 /// Process incoming REST requests.
@@ -58,6 +73,14 @@ pub fn process_incoming_requests(config: &Config, channel: &Receiver) -> Option<
                     .unwrap_or_else(|e| error!("error replying to a REST request {}", e));
             }
 
+            Request::SubscribeEvents { reply_to } => {
+                trace!("SubscribeEvents");
+
+                reply_to
+                    .send(Ok(relay::subscribe()))
+                    .unwrap_or_else(|e| error!("error replying to a REST request {}", e));
+            }
+
             Request::GetChains { reply_to } => {
                 trace!("GetChains");
 
@@ -90,6 +113,69 @@ pub fn process_incoming_requests(config: &Config, channel: &Receiver) -> Option<
 
                 return Some(Command::ClearPackets(chain_id, reply_to));
             }
+
+            Request::AddChain {
+                chain_config,
+                reply_to,
+            } => {
+                trace!("AddChain {}", chain_config.id());
+
+                return Some(Command::AddChain(chain_config, reply_to));
+            }
+
+            Request::RemoveChain { chain_id, reply_to } => {
+                trace!("RemoveChain {}", chain_id);
+
+                return Some(Command::RemoveChain(chain_id, reply_to));
+            }
+
+            Request::UpdatePacketFilter {
+                chain_id,
+                packet_filter,
+                reply_to,
+            } => {
+                trace!("UpdatePacketFilter {}", chain_id);
+
+                return Some(Command::UpdatePacketFilter(
+                    chain_id,
+                    packet_filter,
+                    reply_to,
+                ));
+            }
+
+            Request::PauseWorker { object, reply_to } => {
+                trace!("PauseWorker {}", object.short_name());
+
+                return Some(Command::PauseWorker(object, reply_to));
+            }
+
+            Request::ResumeWorker { object, reply_to } => {
+                trace!("ResumeWorker {}", object.short_name());
+
+                return Some(Command::ResumeWorker(object, reply_to));
+            }
+
+            Request::PacketTrace {
+                chain_id,
+                port_id,
+                channel_id,
+                sequence,
+                reply_to,
+            } => {
+                trace!("PacketTrace {chain_id}/{port_id}/{channel_id}/{sequence}");
+
+                return Some(Command::PacketTrace(
+                    chain_id, port_id, channel_id, sequence, reply_to,
+                ));
+            }
+
+            Request::FeeProfitability { chain_id, reply_to } => {
+                trace!("FeeProfitability {:?}", chain_id);
+
+                reply_to
+                    .send(Ok(profitability::report(chain_id.as_ref())))
+                    .unwrap_or_else(|e| error!("error replying to a REST request {}", e));
+            }
         },
         Err(e) => {
             if !matches!(e, TryRecvError::Empty) {
@@ -23,6 +23,18 @@ pub enum RestApiError {
     #[error("failed while parsing the request body into a chain configuration: {0}")]
     InvalidChainConfig(String),
 
+    #[error("chain {0} is already configured")]
+    ChainAlreadyExists(ChainId),
+
+    #[error("failed to start chain {0}: {1}")]
+    ChainStart(ChainId, String),
+
+    #[error("no worker is running for object: {0}")]
+    WorkerNotFound(String),
+
+    #[error("failed to trace packet {0}: {1}")]
+    PacketTrace(String, String),
+
     #[error("not implemented")]
     Unimplemented,
 }
@@ -36,6 +48,10 @@ impl RestApiError {
             RestApiError::ChainConfigNotFound(_) => "ChainConfigNotFound",
             RestApiError::InvalidChainId(_, _) => "InvalidChainId",
             RestApiError::InvalidChainConfig(_) => "InvalidChainConfig",
+            RestApiError::ChainAlreadyExists(_) => "ChainAlreadyExists",
+            RestApiError::ChainStart(_, _) => "ChainStart",
+            RestApiError::WorkerNotFound(_) => "WorkerNotFound",
+            RestApiError::PacketTrace(_, _) => "PacketTrace",
             RestApiError::Unimplemented => "Unimplemented",
         }
     }
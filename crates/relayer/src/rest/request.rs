@@ -1,8 +1,19 @@
 use serde::{Deserialize, Serialize};
 
-use ibc_relayer_types::core::ics24_host::identifier::ChainId;
+use ibc_relayer_types::core::{
+    ics04_channel::packet::Sequence,
+    ics24_host::identifier::{ChainId, ChannelId, PortId},
+};
 
-use crate::{config::ChainConfig, rest::RestApiError, supervisor::dump_state::SupervisorState};
+use crate::{
+    chain::counterparty::PacketTrace,
+    config::{filter::PacketFilter, ChainConfig},
+    event::{bus::BoundedSubscription, relay::RelayEvent},
+    object::Object,
+    profitability::ChannelProfitability,
+    rest::RestApiError,
+    supervisor::dump_state::SupervisorState,
+};
 
 pub type ReplySender<T> = crossbeam_channel::Sender<Result<T, RestApiError>>;
 pub type ReplyReceiver<T> = crossbeam_channel::Receiver<Result<T, RestApiError>>;
@@ -28,6 +39,10 @@ pub enum Request {
         reply_to: ReplySender<SupervisorState>,
     },
 
+    SubscribeEvents {
+        reply_to: ReplySender<BoundedSubscription<RelayEvent>>,
+    },
+
     GetChains {
         reply_to: ReplySender<Vec<ChainId>>,
     },
@@ -41,4 +56,43 @@ pub enum Request {
         chain_id: Option<ChainId>,
         reply_to: ReplySender<()>,
     },
+
+    AddChain {
+        chain_config: Box<ChainConfig>,
+        reply_to: ReplySender<()>,
+    },
+
+    RemoveChain {
+        chain_id: ChainId,
+        reply_to: ReplySender<()>,
+    },
+
+    UpdatePacketFilter {
+        chain_id: ChainId,
+        packet_filter: PacketFilter,
+        reply_to: ReplySender<()>,
+    },
+
+    PauseWorker {
+        object: Object,
+        reply_to: ReplySender<()>,
+    },
+
+    ResumeWorker {
+        object: Object,
+        reply_to: ReplySender<()>,
+    },
+
+    PacketTrace {
+        chain_id: ChainId,
+        port_id: PortId,
+        channel_id: ChannelId,
+        sequence: Sequence,
+        reply_to: ReplySender<PacketTrace>,
+    },
+
+    FeeProfitability {
+        chain_id: Option<ChainId>,
+        reply_to: ReplySender<Vec<ChannelProfitability>>,
+    },
 }
@@ -34,6 +34,10 @@ define_error! {
             { code: u32, amount: u64 }
             |e| { format_args!("the issue might have been caused by the configured max gas which binds the gas used. Please check the Hermes config.toml and increase the configured `max_gas`. Curerent value is `{}`", e.amount) },
 
+        OutOfGasModelled
+            { code: u32, amount: u64 }
+            |e| { format_args!("the gas amount `{}` was estimated from the gas used by past transactions, which will be simulated again from now on. If the issue persists, please check the `gas_model` in the Hermes config.toml", e.amount) },
+
         InsufficientFee
             { code: u32 }
             |_| { "the price configuration for this chain may be too low! please check the `gas_price.price` Hermes config.toml".to_string() },
@@ -200,6 +204,7 @@ pub fn sdk_error_from_tx_sync_error_code(code: u32, estimated_gas: EstimatedGas)
         11 => match estimated_gas {
             EstimatedGas::Default(amount) => SdkError::out_of_gas_default(code, amount),
             EstimatedGas::Simulated(amount) => SdkError::out_of_gas_simulated(code, amount),
+            EstimatedGas::Modelled(amount) => SdkError::out_of_gas_modelled(code, amount),
         },
         13 => SdkError::insufficient_fee(code),
         _ => SdkError::unknown_tx_sync(code),
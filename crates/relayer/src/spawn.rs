@@ -5,8 +5,13 @@ use tokio::runtime::Runtime as TokioRuntime;
 
 use ibc_relayer_types::core::ics24_host::identifier::ChainId;
 
+#[cfg(any(test, feature = "mock"))]
+use crate::chain::mock::MockChain;
 use crate::{
-    chain::{cosmos::CosmosSdkChain, handle::ChainHandle, runtime::ChainRuntime},
+    chain::{
+        cosmos::CosmosSdkChain, handle::ChainHandle, runtime::ChainRuntime,
+        solomachine::SoloMachine,
+    },
     config::{ChainConfig, Config},
     error::Error as RelayerError,
 };
@@ -25,6 +30,12 @@ define_error! {
             | e | {
                 format_args!("missing chain config for '{}' in configuration file", e.chain_id)
             },
+
+        MockChainDisabled
+            { chain_id: ChainId }
+            | e | {
+                format_args!("cannot spawn mock chain '{}': the relayer was built without the `mock` feature", e.chain_id)
+            },
     }
 }
 
@@ -82,6 +93,11 @@ pub fn spawn_chain_runtime_with_config<Handle: ChainHandle>(
 ) -> Result<Handle, SpawnError> {
     let handle = match config {
         ChainConfig::CosmosSdk(_) => ChainRuntime::<CosmosSdkChain>::spawn(config, rt),
+        #[cfg(any(test, feature = "mock"))]
+        ChainConfig::Mock(_) => ChainRuntime::<MockChain>::spawn(config, rt),
+        #[cfg(not(any(test, feature = "mock")))]
+        ChainConfig::Mock(config) => return Err(SpawnError::mock_chain_disabled(config.id)),
+        ChainConfig::SoloMachine(_) => ChainRuntime::<SoloMachine>::spawn(config, rt),
     }
     .map_err(SpawnError::relayer)?;
 
@@ -0,0 +1,580 @@
+//! Optional on-disk store for the relayer state.
+//!
+//! For each packet relaying path, the store persists the packets which are
+//! being relayed and the hashes of the transactions pending confirmation.
+//! It also records the last height processed for each chain.
+//! On startup, the supervisor uses this state to resume relaying from where
+//! it left off, instead of clearing all the pending packets.
+//!
+//! The on-disk backend is only available if the `relay-store` feature is enabled.
+
+use alloc::collections::BTreeMap;
+use std::collections::HashMap;
+use std::path::Path;
+use std::sync::{Arc, Mutex, RwLock};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use flex_error::{define_error, TraceError};
+use once_cell::sync::OnceCell;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+
+use ibc_relayer_types::core::ics04_channel::packet::Sequence;
+use ibc_relayer_types::core::ics24_host::identifier::ChainId;
+use ibc_relayer_types::events::IbcEvent;
+use ibc_relayer_types::Height;
+
+use crate::config::StoreConfig;
+use crate::object::Packet;
+
+define_error! {
+    StoreError {
+        Disabled
+            |_| { "the relayer state store is not available, Hermes must be built with the `relay-store` feature" },
+
+        Backend
+            { reason: String }
+            |e| { format_args!("relayer state store backend error: {}", e.reason) },
+
+        Encode
+            [ TraceError<serde_json::Error> ]
+            |_| { "failed to encode relayer state store record" },
+
+        Decode
+            { key: String }
+            [ TraceError<serde_json::Error> ]
+            |e| { format_args!("failed to decode relayer state store record '{}'", e.key) },
+    }
+}
+
+static GLOBAL: OnceCell<RelayStore> = OnceCell::new();
+
+/// Opens the store described by the given configuration, if it is enabled,
+/// and installs it as the global relayer state store.
+///
+/// The store is opened only once per process, subsequent calls return
+/// the store opened by the first call.
+pub fn init(config: &StoreConfig) -> Result<Option<&'static RelayStore>, StoreError> {
+    if !config.enabled {
+        return Ok(None);
+    }
+
+    GLOBAL
+        .get_or_try_init(|| RelayStore::open(&config.path))
+        .map(Some)
+}
+
+/// Returns the global relayer state store, if one has been initialized.
+pub fn global() -> Option<&'static RelayStore> {
+    GLOBAL.get()
+}
+
+/// The kind of event which caused the relayer to track a packet.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum TrackedPacketKind {
+    /// A packet sent on the source chain, to be received on the destination chain.
+    Send,
+    /// A packet acknowledgement written on the source chain, to be relayed back.
+    WriteAck,
+}
+
+impl TrackedPacketKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Send => "send",
+            Self::WriteAck => "write_ack",
+        }
+    }
+}
+
+/// A packet tracked by the relayer until the transaction relaying it is confirmed.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TrackedPacket {
+    pub kind: TrackedPacketKind,
+    pub sequence: Sequence,
+    pub height: Height,
+}
+
+impl TrackedPacket {
+    /// Returns the packet to track for the given event, if the event is either
+    /// a `SendPacket` or a `WriteAcknowledgement` event.
+    pub fn from_event(event: &IbcEvent, height: Height) -> Option<Self> {
+        let kind = match event {
+            IbcEvent::SendPacket(_) => TrackedPacketKind::Send,
+            IbcEvent::WriteAcknowledgement(_) => TrackedPacketKind::WriteAck,
+            _ => return None,
+        };
+
+        Some(Self {
+            kind,
+            sequence: event.packet()?.sequence,
+            height,
+        })
+    }
+}
+
+/// A batch of transactions submitted by the relayer and not yet confirmed.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PendingTxRecord {
+    /// Identifier of the chain the transactions were submitted to.
+    pub chain_id: ChainId,
+    pub tracking_id: String,
+    pub tx_hashes: Vec<String>,
+    pub packets: Vec<TrackedPacket>,
+    /// Submission time, in seconds since the UNIX epoch.
+    pub submitted_at: u64,
+}
+
+impl PendingTxRecord {
+    pub fn new(
+        chain_id: ChainId,
+        tracking_id: String,
+        tx_hashes: Vec<String>,
+        packets: Vec<TrackedPacket>,
+    ) -> Self {
+        let submitted_at = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or_default();
+
+        Self {
+            chain_id,
+            tracking_id,
+            tx_hashes,
+            packets,
+            submitted_at,
+        }
+    }
+}
+
+#[derive(Clone)]
+enum Backend {
+    #[cfg(feature = "relay-store")]
+    Sled(sled::Db),
+    Memory(Arc<Mutex<BTreeMap<String, Vec<u8>>>>),
+}
+
+impl Backend {
+    fn insert(&self, key: &str, value: Vec<u8>) -> Result<(), StoreError> {
+        match self {
+            #[cfg(feature = "relay-store")]
+            Self::Sled(db) => db
+                .insert(key, value)
+                .map(|_| ())
+                .map_err(|e| StoreError::backend(e.to_string())),
+            Self::Memory(map) => {
+                map.lock().unwrap().insert(key.to_string(), value);
+                Ok(())
+            }
+        }
+    }
+
+    fn get(&self, key: &str) -> Result<Option<Vec<u8>>, StoreError> {
+        match self {
+            #[cfg(feature = "relay-store")]
+            Self::Sled(db) => db
+                .get(key)
+                .map(|value| value.map(|v| v.to_vec()))
+                .map_err(|e| StoreError::backend(e.to_string())),
+            Self::Memory(map) => Ok(map.lock().unwrap().get(key).cloned()),
+        }
+    }
+
+    fn remove(&self, key: &str) -> Result<(), StoreError> {
+        match self {
+            #[cfg(feature = "relay-store")]
+            Self::Sled(db) => db
+                .remove(key)
+                .map(|_| ())
+                .map_err(|e| StoreError::backend(e.to_string())),
+            Self::Memory(map) => {
+                map.lock().unwrap().remove(key);
+                Ok(())
+            }
+        }
+    }
+
+    /// Returns all the entries whose key starts with the given prefix, ordered by key.
+    fn scan_prefix(&self, prefix: &str) -> Result<Vec<(String, Vec<u8>)>, StoreError> {
+        match self {
+            #[cfg(feature = "relay-store")]
+            Self::Sled(db) => db
+                .scan_prefix(prefix)
+                .map(|entry| {
+                    let (key, value) = entry.map_err(|e| StoreError::backend(e.to_string()))?;
+                    Ok((String::from_utf8_lossy(&key).into_owned(), value.to_vec()))
+                })
+                .collect(),
+            Self::Memory(map) => Ok(map
+                .lock()
+                .unwrap()
+                .range(prefix.to_string()..)
+                .take_while(|(key, _)| key.starts_with(prefix))
+                .map(|(key, value)| (key.clone(), value.clone()))
+                .collect()),
+        }
+    }
+}
+
+/// Handle to the relayer state store.
+#[derive(Clone)]
+pub struct RelayStore {
+    backend: Backend,
+    /// Heights from which the event sources of the resumed chains replay blocks.
+    resume_heights: Arc<RwLock<HashMap<ChainId, Height>>>,
+}
+
+impl RelayStore {
+    fn new(backend: Backend) -> Self {
+        Self {
+            backend,
+            resume_heights: Default::default(),
+        }
+    }
+
+    /// Opens the on-disk store at the given path, creating it if necessary.
+    #[cfg(feature = "relay-store")]
+    pub fn open(path: &Path) -> Result<Self, StoreError> {
+        let path = expand_home(path);
+        let db = sled::open(&path).map_err(|e| StoreError::backend(e.to_string()))?;
+
+        tracing::info!("opened relayer state store at '{}'", path.display());
+
+        Ok(Self::new(Backend::Sled(db)))
+    }
+
+    /// Opens the on-disk store at the given path, creating it if necessary.
+    #[cfg(not(feature = "relay-store"))]
+    pub fn open(_path: &Path) -> Result<Self, StoreError> {
+        Err(StoreError::disabled())
+    }
+
+    /// Creates a store which only lives in memory, mostly useful for testing.
+    pub fn in_memory() -> Self {
+        Self::new(Backend::Memory(Default::default()))
+    }
+
+    pub fn last_processed_height(&self, chain_id: &ChainId) -> Result<Option<Height>, StoreError> {
+        self.get(&height_key(chain_id))
+    }
+
+    pub fn set_last_processed_height(
+        &self,
+        chain_id: &ChainId,
+        height: Height,
+    ) -> Result<(), StoreError> {
+        self.put(&height_key(chain_id), &height)
+    }
+
+    /// Records the given packets as being relayed on the given path.
+    pub fn track_packets(
+        &self,
+        path: &Packet,
+        packets: &[TrackedPacket],
+    ) -> Result<(), StoreError> {
+        if packets.is_empty() {
+            return Ok(());
+        }
+
+        self.put(&format!("path/{}", path_key(path)), path)?;
+
+        for packet in packets {
+            self.put(&packet_key(path, packet), packet)?;
+        }
+
+        Ok(())
+    }
+
+    /// Stops tracking the given packets, once they have been relayed.
+    pub fn untrack_packets(
+        &self,
+        path: &Packet,
+        packets: &[TrackedPacket],
+    ) -> Result<(), StoreError> {
+        for packet in packets {
+            self.backend.remove(&packet_key(path, packet))?;
+        }
+
+        Ok(())
+    }
+
+    pub fn tracked_packets(&self, path: &Packet) -> Result<Vec<TrackedPacket>, StoreError> {
+        self.scan(&format!("packet/{}/", path_key(path)))
+    }
+
+    /// Returns the paths with at least one tracked packet.
+    pub fn tracked_paths(&self) -> Result<Vec<Packet>, StoreError> {
+        let mut paths = Vec::new();
+
+        for path in self.scan::<Packet>("path/")? {
+            if !self.tracked_packets(&path)?.is_empty() {
+                paths.push(path);
+            }
+        }
+
+        Ok(paths)
+    }
+
+    pub fn insert_pending_tx(
+        &self,
+        path: &Packet,
+        record: &PendingTxRecord,
+    ) -> Result<(), StoreError> {
+        match pending_tx_key(path, record) {
+            Some(key) => self.put(&key, record),
+            None => Ok(()),
+        }
+    }
+
+    pub fn remove_pending_tx(
+        &self,
+        path: &Packet,
+        record: &PendingTxRecord,
+    ) -> Result<(), StoreError> {
+        match pending_tx_key(path, record) {
+            Some(key) => self.backend.remove(&key),
+            None => Ok(()),
+        }
+    }
+
+    pub fn pending_txs(&self, path: &Packet) -> Result<Vec<PendingTxRecord>, StoreError> {
+        self.scan(&format!("pending/{}/", path_key(path)))
+    }
+
+    /// Marks the given chain as resumed, with its event source replaying
+    /// the blocks following the given height.
+    pub fn set_resume_height(&self, chain_id: &ChainId, height: Height) {
+        self.resume_heights
+            .write()
+            .unwrap()
+            .insert(chain_id.clone(), height);
+    }
+
+    pub fn resume_height(&self, chain_id: &ChainId) -> Option<Height> {
+        self.resume_heights.read().unwrap().get(chain_id).copied()
+    }
+
+    pub fn is_resumed(&self, chain_id: &ChainId) -> bool {
+        self.resume_height(chain_id).is_some()
+    }
+
+    fn put<T: Serialize>(&self, key: &str, value: &T) -> Result<(), StoreError> {
+        let bytes = serde_json::to_vec(value).map_err(StoreError::encode)?;
+        self.backend.insert(key, bytes)
+    }
+
+    fn get<T: DeserializeOwned>(&self, key: &str) -> Result<Option<T>, StoreError> {
+        self.backend
+            .get(key)?
+            .map(|bytes| {
+                serde_json::from_slice(&bytes).map_err(|e| StoreError::decode(key.to_string(), e))
+            })
+            .transpose()
+    }
+
+    fn scan<T: DeserializeOwned>(&self, prefix: &str) -> Result<Vec<T>, StoreError> {
+        self.backend
+            .scan_prefix(prefix)?
+            .into_iter()
+            .map(|(key, bytes)| {
+                serde_json::from_slice(&bytes).map_err(|e| StoreError::decode(key, e))
+            })
+            .collect()
+    }
+}
+
+fn height_key(chain_id: &ChainId) -> String {
+    format!("height/{chain_id}")
+}
+
+fn path_key(path: &Packet) -> String {
+    format!(
+        "{}/{}/{}/{}",
+        path.src_chain_id, path.src_port_id, path.src_channel_id, path.dst_chain_id
+    )
+}
+
+fn packet_key(path: &Packet, packet: &TrackedPacket) -> String {
+    // Zero-pad the sequence so that the packets are ordered by sequence
+    format!(
+        "packet/{}/{}/{:020}",
+        path_key(path),
+        packet.kind.as_str(),
+        u64::from(packet.sequence)
+    )
+}
+
+fn pending_tx_key(path: &Packet, record: &PendingTxRecord) -> Option<String> {
+    record
+        .tx_hashes
+        .first()
+        .map(|hash| format!("pending/{}/{}", path_key(path), hash))
+}
+
+/// Expands a leading `~` in the given path to the home directory of the current user.
+#[cfg(feature = "relay-store")]
+fn expand_home(path: &Path) -> std::path::PathBuf {
+    match (path.strip_prefix("~"), dirs_next::home_dir()) {
+        (Ok(rest), Some(home)) => home.join(rest),
+        _ => path.to_path_buf(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use core::str::FromStr;
+
+    use ibc_relayer_types::core::ics24_host::identifier::{ChannelId, PortId};
+
+    fn path(channel: &str) -> Packet {
+        Packet {
+            dst_chain_id: ChainId::from_string("chain-b"),
+            src_chain_id: ChainId::from_string("chain-a"),
+            src_channel_id: ChannelId::from_str(channel).unwrap(),
+            src_port_id: PortId::transfer(),
+        }
+    }
+
+    fn packet(kind: TrackedPacketKind, sequence: u64) -> TrackedPacket {
+        TrackedPacket {
+            kind,
+            sequence: sequence.into(),
+            height: Height::new(0, 10).unwrap(),
+        }
+    }
+
+    #[test]
+    fn last_processed_height() {
+        let store = RelayStore::in_memory();
+        let chain_id = ChainId::from_string("chain-a");
+
+        assert_eq!(store.last_processed_height(&chain_id).unwrap(), None);
+
+        let height = Height::new(1, 42).unwrap();
+        store.set_last_processed_height(&chain_id, height).unwrap();
+
+        assert_eq!(
+            store.last_processed_height(&chain_id).unwrap(),
+            Some(height)
+        );
+    }
+
+    #[test]
+    fn track_and_untrack_packets() {
+        let store = RelayStore::in_memory();
+        let path_0 = path("channel-0");
+        let path_1 = path("channel-1");
+
+        let packets = vec![
+            packet(TrackedPacketKind::Send, 2),
+            packet(TrackedPacketKind::Send, 10),
+            packet(TrackedPacketKind::WriteAck, 1),
+        ];
+
+        store.track_packets(&path_0, &packets).unwrap();
+        store
+            .track_packets(&path_1, &[packet(TrackedPacketKind::Send, 1)])
+            .unwrap();
+
+        // Tracked packets are ordered by kind, then by sequence
+        assert_eq!(
+            store.tracked_packets(&path_0).unwrap(),
+            vec![packets[0].clone(), packets[1].clone(), packets[2].clone()]
+        );
+        assert_eq!(store.tracked_paths().unwrap(), vec![path_0.clone(), path_1]);
+
+        store.untrack_packets(&path_0, &packets[..2]).unwrap();
+        assert_eq!(
+            store.tracked_packets(&path_0).unwrap(),
+            vec![packets[2].clone()]
+        );
+
+        store.untrack_packets(&path_0, &packets[2..]).unwrap();
+        assert!(store.tracked_packets(&path_0).unwrap().is_empty());
+        assert!(!store.tracked_paths().unwrap().contains(&path_0));
+    }
+
+    #[test]
+    fn insert_and_remove_pending_txs() {
+        let store = RelayStore::in_memory();
+        let path = path("channel-0");
+
+        let record = PendingTxRecord::new(
+            ChainId::from_string("chain-b"),
+            "tracking-id".to_string(),
+            vec!["ABCDEF".to_string()],
+            vec![packet(TrackedPacketKind::Send, 1)],
+        );
+
+        store.insert_pending_tx(&path, &record).unwrap();
+        assert_eq!(store.pending_txs(&path).unwrap(), vec![record.clone()]);
+
+        store.remove_pending_tx(&path, &record).unwrap();
+        assert!(store.pending_txs(&path).unwrap().is_empty());
+    }
+
+    #[test]
+    fn pending_txs_without_hashes_are_not_stored() {
+        let store = RelayStore::in_memory();
+        let path = path("channel-0");
+
+        let record = PendingTxRecord::new(
+            ChainId::from_string("chain-b"),
+            "tracking-id".to_string(),
+            vec![],
+            vec![],
+        );
+
+        store.insert_pending_tx(&path, &record).unwrap();
+        assert!(store.pending_txs(&path).unwrap().is_empty());
+    }
+
+    #[test]
+    fn resume_heights() {
+        let store = RelayStore::in_memory();
+        let chain_id = ChainId::from_string("chain-a");
+
+        assert!(!store.is_resumed(&chain_id));
+
+        let height = Height::new(0, 7).unwrap();
+        store.set_resume_height(&chain_id, height);
+
+        assert!(store.is_resumed(&chain_id));
+        assert_eq!(store.resume_height(&chain_id), Some(height));
+    }
+
+    #[cfg(feature = "relay-store")]
+    #[test]
+    fn on_disk_store_persists_state() {
+        let dir = std::env::temp_dir().join(format!("hermes-store-{}", std::process::id()));
+        let chain_id = ChainId::from_string("chain-a");
+        let height = Height::new(0, 42).unwrap();
+        let packets = vec![packet(TrackedPacketKind::Send, 1)];
+
+        {
+            let store = RelayStore::open(&dir).unwrap();
+            store.set_last_processed_height(&chain_id, height).unwrap();
+            store.track_packets(&path("channel-0"), &packets).unwrap();
+        }
+
+        // sled releases the lock on the database asynchronously once it is dropped
+        let store = (0..50)
+            .find_map(|_| {
+                RelayStore::open(&dir)
+                    .map_err(|_| std::thread::sleep(core::time::Duration::from_millis(100)))
+                    .ok()
+            })
+            .expect("failed to reopen the store");
+
+        assert_eq!(
+            store.last_processed_height(&chain_id).unwrap(),
+            Some(height)
+        );
+        assert_eq!(store.tracked_packets(&path("channel-0")).unwrap(), packets);
+
+        drop(store);
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -5,30 +5,44 @@ use core::ops::Deref;
 use core::time::Duration;
 use std::sync::RwLock;
 
-use crossbeam_channel::{unbounded, Receiver, Sender};
+use crossbeam_channel::{unbounded, Receiver, Sender, TryRecvError};
 use itertools::Itertools;
 use tracing::{debug, error, error_span, info, instrument, trace, warn};
 
 use ibc_relayer_types::{
-    core::ics24_host::identifier::{ChainId, ChannelId, PortId},
+    core::{
+        ics04_channel::packet::Sequence,
+        ics24_host::identifier::{ChainId, ChannelId, PortId},
+    },
     events::IbcEvent,
     Height,
 };
 
 use crate::{
-    chain::{endpoint::HealthCheck, handle::ChainHandle, tracking::TrackingId},
-    config::Config,
+    chain::{
+        counterparty::{self, channel_connection_client_no_checks, PacketTrace},
+        endpoint::HealthCheck,
+        handle::ChainHandle,
+        tracking::TrackingId,
+    },
+    config::{
+        filter::{FeePolicy, Ics20Filter, PacketFilter},
+        ChainConfig, Config, Diagnostic, EventSourceMode,
+    },
     event::{
         source::{self, Error as EventError, ErrorDetail as EventErrorDetail, EventBatch},
         IbcEventWithHeight,
     },
-    object::Object,
+    link::inspect::PacketKey,
+    object::{self, Object},
+    profitability,
     registry::{Registry, SharedRegistry},
-    rest,
+    rest::{self, RestApiError},
+    store,
     supervisor::scan::ScanMode,
     telemetry,
     util::{
-        lock::LockExt,
+        lock::{LockExt, RwArc},
         task::{spawn_background_task, Next, TaskError, TaskHandle},
     },
     worker::WorkerMap,
@@ -49,11 +63,20 @@ pub mod spawn;
 pub mod cmd;
 use cmd::SupervisorCmd;
 
-use self::{scan::ChainScanner, spawn::SpawnContext};
+pub mod reload;
+
+use self::{
+    scan::{ChainScanner, ChainsScan},
+    spawn::SpawnContext,
+};
 
 type ArcBatch = Arc<source::Result<EventBatch>>;
 type Subscription = Receiver<ArcBatch>;
 
+/// The batch workers of the chains started after the supervisor was spawned,
+/// eg. through the REST API or upon a configuration reload.
+type BatchTasks = RwArc<HashMap<ChainId, TaskHandle>>;
+
 /**
     A wrapper around the SupervisorCmd sender so that we can
     send stop signal to the supervisor before stopping the
@@ -131,18 +154,27 @@ impl SupervisorHandle {
 
         Ok(state)
     }
+
+    /// Ask the supervisor to apply the changes between its running configuration and the given one
+    pub fn update_config(&self, config: Config) -> Result<(), Error> {
+        self.sender
+            .send(SupervisorCmd::UpdateConfig(Box::new(config)))
+            .map_err(|_| Error::handle_send())
+    }
 }
 
 /// Whether the supervisor should scan the chains for clients, connections, and channels.
 /// The supervisor should scan if any of the following conditions are met:
-/// - the clear_on_start option is enabled
+/// - the clear_on_start option is enabled, and not all chains were resumed from the relayer state store
 /// - the client refresh or misbehavior workers are enabled
 /// - the channel workers are enabled
 /// - the connection workers are enabled
 /// - the full_scan option is enabled
 fn should_scan(config: &Config, options: &SupervisorOptions) -> bool {
     options.force_full_scan
-        || (config.mode.packets.enabled && config.mode.packets.clear_on_start)
+        || (config.mode.packets.enabled
+            && config.mode.packets.clear_on_start
+            && !all_chains_resumed(config))
         || config.mode.connections.enabled
         || config.mode.channels.enabled
         || (config.mode.clients.enabled
@@ -160,6 +192,9 @@ pub fn spawn_supervisor_tasks<Chain: ChainHandle>(
         health_check(&config, &mut registry.write());
     }
 
+    // If the relayer state store is enabled, resume the chains from their last processed height
+    resume_from_store(&config, &mut registry.write());
+
     // If telemetry is enabled, for each chain register the relayer's address
     // in the list of visible fee addresses.
     if config.telemetry.enabled {
@@ -170,6 +205,20 @@ pub fn spawn_supervisor_tasks<Chain: ChainHandle>(
         }
     }
 
+    // The fees paid and collected on each channel can only be
+    // accounted for when the transactions are confirmed.
+    if config.mode.packets.enabled {
+        if config.mode.packets.tx_confirmation {
+            profitability::enable();
+        } else if config.rest.enabled || config.telemetry.enabled {
+            warn!(
+                "transaction confirmation is disabled, the fees paid and collected on each \
+                 channel will not be accounted for; set `tx_confirmation = true` in the \
+                 `[mode.packets]` section of the configuration to account for them"
+            );
+        }
+    }
+
     let workers = Arc::new(RwLock::new(WorkerMap::new()));
     let client_state_filter = Arc::new(RwLock::new(FilterPolicy::default()));
 
@@ -194,23 +243,49 @@ pub fn spawn_supervisor_tasks<Chain: ChainHandle>(
             .spawn_workers(scan);
     }
 
+    spawn_tracked_packet_workers(
+        &config,
+        &mut registry.write(),
+        &mut client_state_filter.acquire_write(),
+        &mut workers.acquire_write(),
+    );
+
     let subscriptions = init_subscriptions(&config, &mut registry.write())?;
 
+    // From now on, the configuration may be updated at runtime through the REST API
+    let config = Arc::new(RwLock::new(config));
+
     let batch_tasks = spawn_batch_workers(
         &config,
         registry.clone(),
-        client_state_filter,
+        client_state_filter.clone(),
         workers.clone(),
         subscriptions,
     );
 
-    let cmd_task = spawn_cmd_worker(registry.clone(), workers.clone(), cmd_rx);
+    let dynamic_batch_tasks = Arc::new(RwLock::new(HashMap::new()));
+
+    let cmd_task = spawn_cmd_worker(
+        config.clone(),
+        registry.clone(),
+        client_state_filter.clone(),
+        workers.clone(),
+        dynamic_batch_tasks.clone(),
+        cmd_rx,
+    );
 
     let mut tasks = vec![cmd_task];
     tasks.extend(batch_tasks);
 
     if let Some(rest_rx) = rest_rx {
-        let rest_task = spawn_rest_worker(config, registry, workers.clone(), rest_rx);
+        let rest_task = spawn_rest_worker(
+            config,
+            registry,
+            client_state_filter,
+            workers.clone(),
+            dynamic_batch_tasks,
+            rest_rx,
+        );
         tasks.push(rest_task);
     }
 
@@ -221,7 +296,7 @@ pub fn spawn_supervisor_tasks<Chain: ChainHandle>(
 }
 
 fn spawn_batch_workers<Chain: ChainHandle>(
-    config: &Config,
+    config: &RwArc<Config>,
     registry: SharedRegistry<Chain>,
     client_state_filter: Arc<RwLock<FilterPolicy>>,
     workers: Arc<RwLock<WorkerMap>>,
@@ -230,18 +305,42 @@ fn spawn_batch_workers<Chain: ChainHandle>(
     let mut handles = Vec::with_capacity(subscriptions.len());
 
     for (chain, subscription) in subscriptions {
-        let config = config.clone();
-        let registry = registry.clone();
-        let client_state_filter = client_state_filter.clone();
-        let workers = workers.clone();
-
-        let handle = spawn_background_task(
-            error_span!("worker.batch", chain = %chain.id()),
-            Some(Duration::from_millis(5)),
-            move || -> Result<Next, TaskError<Infallible>> {
-                if let Ok(batch) = subscription.try_recv() {
+        let handle = spawn_batch_worker(
+            config.clone(),
+            registry.clone(),
+            client_state_filter.clone(),
+            workers.clone(),
+            chain,
+            subscription,
+        );
+
+        handles.push(handle);
+    }
+
+    handles
+}
+
+/// Spawn a background task which processes the batches of events
+/// received from the given chain.
+///
+/// The task stops once the subscription gets disconnected,
+/// eg. after the chain runtime was shut down.
+fn spawn_batch_worker<Chain: ChainHandle>(
+    config: RwArc<Config>,
+    registry: SharedRegistry<Chain>,
+    client_state_filter: Arc<RwLock<FilterPolicy>>,
+    workers: Arc<RwLock<WorkerMap>>,
+    chain: Chain,
+    subscription: Subscription,
+) -> TaskHandle {
+    spawn_background_task(
+        error_span!("worker.batch", chain = %chain.id()),
+        Some(Duration::from_millis(5)),
+        move || -> Result<Next, TaskError<Infallible>> {
+            match subscription.try_recv() {
+                Ok(batch) => {
                     handle_batch(
-                        &config,
+                        &config.acquire_read(),
                         &mut registry.write(),
                         &mut client_state_filter.acquire_write(),
                         &mut workers.acquire_write(),
@@ -249,20 +348,25 @@ fn spawn_batch_workers<Chain: ChainHandle>(
                         batch,
                     );
                 }
+                Err(TryRecvError::Disconnected) => {
+                    debug!("event subscription was closed, stopping batch worker");
 
-                Ok(Next::Continue)
-            },
-        );
-
-        handles.push(handle);
-    }
+                    return Ok(Next::Abort);
+                }
+                Err(TryRecvError::Empty) => {}
+            }
 
-    handles
+            Ok(Next::Continue)
+        },
+    )
 }
 
 pub fn spawn_cmd_worker<Chain: ChainHandle>(
+    config: RwArc<Config>,
     registry: SharedRegistry<Chain>,
+    client_state_filter: Arc<RwLock<FilterPolicy>>,
     workers: Arc<RwLock<WorkerMap>>,
+    batch_tasks: BatchTasks,
     cmd_rx: Receiver<SupervisorCmd>,
 ) -> TaskHandle {
     spawn_background_task(
@@ -274,6 +378,16 @@ pub fn spawn_cmd_worker<Chain: ChainHandle>(
                     SupervisorCmd::DumpState(reply_to) => {
                         dump_state(&registry.read(), &workers.acquire_read(), reply_to);
                     }
+                    SupervisorCmd::UpdateConfig(new_config) => {
+                        reload::update_config(
+                            &config,
+                            &registry,
+                            &client_state_filter,
+                            &workers,
+                            &batch_tasks,
+                            *new_config,
+                        );
+                    }
                 }
             }
 
@@ -283,16 +397,25 @@ pub fn spawn_cmd_worker<Chain: ChainHandle>(
 }
 
 pub fn spawn_rest_worker<Chain: ChainHandle>(
-    config: Config,
+    config: RwArc<Config>,
     registry: SharedRegistry<Chain>,
+    client_state_filter: Arc<RwLock<FilterPolicy>>,
     workers: Arc<RwLock<WorkerMap>>,
+    batch_tasks: BatchTasks,
     rest_rx: rest::Receiver,
 ) -> TaskHandle {
     spawn_background_task(
         error_span!("rest"),
         Some(Duration::from_millis(500)),
         move || -> Result<Next, TaskError<Infallible>> {
-            handle_rest_requests(&config, &registry.read(), &workers.acquire_read(), &rest_rx);
+            handle_rest_requests(
+                &config,
+                &registry,
+                &client_state_filter,
+                &workers,
+                &batch_tasks,
+                &rest_rx,
+            );
 
             Ok(Next::Continue)
         },
@@ -539,6 +662,26 @@ pub fn collect_events(
                     || Object::client_from_chan_open_events(&attributes, src_chain).ok(),
                 );
             }
+            IbcEvent::UpgradeInitChannel(..)
+            | IbcEvent::UpgradeTryChannel(..)
+            | IbcEvent::UpgradeAckChannel(..)
+            | IbcEvent::UpgradeConfirmChannel(..)
+            | IbcEvent::UpgradeOpenChannel(..) => {
+                collect_event(
+                    &mut collected,
+                    event_with_height.clone(),
+                    mode.channels.enabled,
+                    || {
+                        event_with_height
+                            .event
+                            .clone()
+                            .channel_upgrade_attributes()
+                            .and_then(|attr| {
+                                Object::channel_from_chan_upgrade_events(&attr, src_chain).ok()
+                            })
+                    },
+                );
+            }
             IbcEvent::SendPacket(ref packet) => {
                 collect_event(
                     &mut collected,
@@ -612,6 +755,147 @@ fn chain_scanner<'a, Chain: ChainHandle>(
     ChainScanner::new(config, registry, client_state_filter, full_scan)
 }
 
+/// Whether all the chains have been resumed from the relayer state store.
+fn all_chains_resumed(config: &Config) -> bool {
+    store::global().map_or(false, |store| {
+        config
+            .chains
+            .iter()
+            .all(|chain| store.is_resumed(chain.id()))
+    })
+}
+
+/// Open the relayer state store, if enabled, and mark as resumed the chains
+/// whose event source can replay the blocks following their last processed height.
+fn resume_from_store<Chain: ChainHandle>(config: &Config, registry: &mut Registry<Chain>) {
+    let store = match store::init(&config.store) {
+        Ok(Some(store)) => store,
+        Ok(None) => return,
+        Err(e) => {
+            error!("failed to open the relayer state store, relaying will not be resumed: {e}");
+            return;
+        }
+    };
+
+    for chain_config in &config.chains {
+        let id = chain_config.id();
+        let _span = error_span!("resume", chain = %id).entered();
+
+        // Only the pull event source can replay past blocks
+        let can_replay = match chain_config {
+            ChainConfig::CosmosSdk(config) => {
+                matches!(config.event_source, EventSourceMode::Pull { .. })
+            }
+            ChainConfig::Mock(_) | ChainConfig::SoloMachine(_) => false,
+        };
+
+        if !can_replay {
+            debug!("not resuming chain, its event source cannot replay past blocks");
+            continue;
+        }
+
+        let last_height = match store.last_processed_height(id) {
+            Ok(Some(height)) => height,
+            Ok(None) => continue,
+            Err(e) => {
+                warn!("failed to read last processed height: {e}");
+                continue;
+            }
+        };
+
+        let latest_height = match registry.get_or_spawn(id) {
+            Ok(chain) => chain.query_latest_height(),
+            Err(e) => {
+                warn!("failed to spawn chain runtime: {e}");
+                continue;
+            }
+        };
+
+        let latest_height = match latest_height {
+            Ok(height) => height,
+            Err(e) => {
+                warn!("failed to query latest height: {e}");
+                continue;
+            }
+        };
+
+        let missed_blocks = latest_height
+            .revision_height()
+            .saturating_sub(last_height.revision_height());
+
+        if latest_height.revision_number() != last_height.revision_number()
+            || missed_blocks > config.store.max_replay_blocks
+        {
+            info!(
+                %last_height, %latest_height,
+                "too many blocks to replay since last processed height, not resuming chain"
+            );
+            continue;
+        }
+
+        info!(%last_height, "resuming chain from last processed height");
+
+        store.set_resume_height(id, last_height);
+    }
+}
+
+/// Spawn the packet workers for the paths with packets tracked in the relayer
+/// state store, provided their source chain has been resumed.
+fn spawn_tracked_packet_workers<Chain: ChainHandle>(
+    config: &Config,
+    registry: &mut Registry<Chain>,
+    client_state_filter: &mut FilterPolicy,
+    workers: &mut WorkerMap,
+) {
+    let Some(store) = store::global() else {
+        return;
+    };
+
+    if !config.mode.packets.enabled {
+        return;
+    }
+
+    let paths = match store.tracked_paths() {
+        Ok(paths) => paths,
+        Err(e) => {
+            warn!("failed to read tracked paths from the relayer state store: {e}");
+            return;
+        }
+    };
+
+    for path in paths {
+        if !store.is_resumed(&path.src_chain_id) {
+            continue;
+        }
+
+        let object = Object::Packet(path);
+
+        if !relay_on_object(
+            config,
+            registry,
+            client_state_filter,
+            object.src_chain_id(),
+            &object,
+        ) {
+            continue;
+        }
+
+        let chains = registry
+            .get_or_spawn(object.src_chain_id())
+            .and_then(|src| Ok((src, registry.get_or_spawn(object.dst_chain_id())?)));
+
+        match chains {
+            Ok((src, dst)) => {
+                workers.get_or_spawn(object, src, dst, config);
+            }
+            Err(e) => warn!(
+                "failed to spawn worker for tracked packets of '{}': {e}",
+                object.short_name()
+            ),
+        }
+    }
+}
+
 /// Perform a health check on all connected chains
 fn health_check<Chain: ChainHandle>(config: &Config, registry: &mut Registry<Chain>) {
     use HealthCheck::*;
@@ -702,41 +986,57 @@ fn state<Chain: ChainHandle>(registry: &Registry<Chain>, workers: &WorkerMap) ->
 }
 
 fn handle_rest_requests<Chain: ChainHandle>(
-    config: &Config,
-    registry: &Registry<Chain>,
-    workers: &WorkerMap,
+    config: &RwArc<Config>,
+    registry: &SharedRegistry<Chain>,
+    client_state_filter: &Arc<RwLock<FilterPolicy>>,
+    workers: &Arc<RwLock<WorkerMap>>,
+    batch_tasks: &BatchTasks,
     rest_rx: &rest::Receiver,
 ) {
-    if let Some(cmd) = rest::process_incoming_requests(config, rest_rx) {
-        handle_rest_cmd(registry, workers, cmd);
+    let cmd = rest::process_incoming_requests(&config.acquire_read(), rest_rx);
+
+    if let Some(cmd) = cmd {
+        handle_rest_cmd(
+            config,
+            registry,
+            client_state_filter,
+            workers,
+            batch_tasks,
+            cmd,
+        );
     }
 }
 
 #[instrument(name = "supervisor.handle_rest_cmd", level = "error", skip_all)]
 fn handle_rest_cmd<Chain: ChainHandle>(
-    registry: &Registry<Chain>,
-    workers: &WorkerMap,
+    config: &RwArc<Config>,
+    registry: &SharedRegistry<Chain>,
+    client_state_filter: &Arc<RwLock<FilterPolicy>>,
+    workers: &Arc<RwLock<WorkerMap>>,
+    batch_tasks: &BatchTasks,
     cmd: rest::Command,
 ) {
     match cmd {
         rest::Command::DumpState(reply) => {
-            let state = state(registry, workers);
+            let state = state(&registry.read(), &workers.acquire_read());
             reply
                 .send(Ok(state))
                 .unwrap_or_else(|e| error!("error replying to a REST request {e}"));
         }
 
         rest::Command::ClearPackets(chain_id, reply) => {
+            let workers = workers.acquire_read();
+
             if let Some(chain_id) = chain_id {
                 info!("clearing packets for chain {chain_id} after REST request");
 
-                clear_pending_packets(workers, &chain_id)
+                clear_pending_packets(&workers, &chain_id)
                     .unwrap_or_else(|e| error!("error clearing packets for chain {chain_id}: {e}"));
             } else {
-                for chain_id in registry.chains().map(|c| c.id()) {
+                for chain_id in registry.read().chains().map(|c| c.id()) {
                     info!("clearing packets for chain {chain_id} after REST request");
 
-                    clear_pending_packets(workers, &chain_id).unwrap_or_else(|e| {
+                    clear_pending_packets(&workers, &chain_id).unwrap_or_else(|e| {
                         error!("error clearing packets for chain {chain_id}: {e}")
                     });
                 }
@@ -746,9 +1046,415 @@ fn handle_rest_cmd<Chain: ChainHandle>(
                 .send(Ok(()))
                 .unwrap_or_else(|e| error!("error replying to a REST request {e}"));
         }
+
+        rest::Command::AddChain(chain_config, reply) => {
+            let chain_id = chain_config.id().clone();
+            info!("adding chain {chain_id} after REST request");
+
+            let result = add_chain(
+                config,
+                registry,
+                client_state_filter,
+                workers,
+                batch_tasks,
+                *chain_config,
+            );
+
+            if let Err(e) = &result {
+                error!("error adding chain {chain_id}: {e}");
+            }
+
+            reply
+                .send(result)
+                .unwrap_or_else(|e| error!("error replying to a REST request {e}"));
+        }
+
+        rest::Command::RemoveChain(chain_id, reply) => {
+            info!("removing chain {chain_id} after REST request");
+
+            let result = remove_chain(config, registry, workers, batch_tasks, &chain_id);
+
+            reply
+                .send(result)
+                .unwrap_or_else(|e| error!("error replying to a REST request {e}"));
+        }
+
+        rest::Command::UpdatePacketFilter(chain_id, packet_filter, reply) => {
+            info!("updating packet filter of chain {chain_id} after REST request");
+
+            let result = update_packet_filter(config, registry, workers, &chain_id, packet_filter);
+
+            reply
+                .send(result)
+                .unwrap_or_else(|e| error!("error replying to a REST request {e}"));
+        }
+
+        rest::Command::PauseWorker(object, reply) => {
+            info!("pausing worker {} after REST request", object.short_name());
+
+            let result = if workers.acquire_write().pause(&object) {
+                Ok(())
+            } else {
+                Err(RestApiError::WorkerNotFound(object.short_name()))
+            };
+
+            reply
+                .send(result)
+                .unwrap_or_else(|e| error!("error replying to a REST request {e}"));
+        }
+
+        rest::Command::ResumeWorker(object, reply) => {
+            info!("resuming worker {} after REST request", object.short_name());
+
+            let result = if workers.acquire_write().resume(&object) {
+                Ok(())
+            } else {
+                Err(RestApiError::WorkerNotFound(object.short_name()))
+            };
+
+            reply
+                .send(result)
+                .unwrap_or_else(|e| error!("error replying to a REST request {e}"));
+        }
+
+        rest::Command::PacketTrace(chain_id, port_id, channel_id, sequence, reply) => {
+            debug!(
+                "tracing packet {chain_id}/{port_id}/{channel_id}/{sequence} after REST request"
+            );
+
+            let result = packet_trace(
+                registry,
+                workers,
+                &chain_id,
+                &port_id,
+                &channel_id,
+                sequence,
+            )
+            .map_err(|e| {
+                RestApiError::PacketTrace(
+                    format!("{chain_id}/{port_id}/{channel_id}/{sequence}"),
+                    e.to_string(),
+                )
+            });
+
+            reply
+                .send(result)
+                .unwrap_or_else(|e| error!("error replying to a REST request {e}"));
+        }
     }
 }
 
+/// Add the given chain to the configuration, spawn its runtime,
+/// and start relaying on it.
+#[instrument(
+    name = "supervisor.add_chain",
+    level = "error",
+    skip_all,
+    fields(chain = %chain_config.id())
+)]
+fn add_chain<Chain: ChainHandle>(
+    config: &RwArc<Config>,
+    registry: &SharedRegistry<Chain>,
+    client_state_filter: &Arc<RwLock<FilterPolicy>>,
+    workers: &Arc<RwLock<WorkerMap>>,
+    batch_tasks: &BatchTasks,
+    chain_config: ChainConfig,
+) -> Result<(), RestApiError> {
+    let chain_id = chain_config.id().clone();
+
+    let old_config = config.acquire_read().clone();
+
+    if old_config.find_chain(&chain_id).is_some() {
+        return Err(RestApiError::ChainAlreadyExists(chain_id));
+    }
+
+    let mut new_config = old_config.clone();
+    new_config.chains.push(chain_config);
+
+    match new_config.validate_config() {
+        Ok(()) => {}
+        Err(Diagnostic::Warning(e)) => {
+            warn!("configuration of chain {chain_id} is valid, with warning: {e}")
+        }
+        Err(Diagnostic::Error(e)) => {
+            return Err(RestApiError::InvalidChainConfig(e.to_string()));
+        }
+    }
+
+    *config.acquire_write() = new_config.clone();
+    registry.write().set_config(new_config);
+
+    let started = start_chain(
+        config,
+        registry,
+        client_state_filter,
+        workers,
+        batch_tasks,
+        &chain_id,
+        true,
+    );
+
+    if let Err(e) = started {
+        *config.acquire_write() = old_config.clone();
+        registry.write().set_config(old_config);
+
+        return Err(RestApiError::ChainStart(chain_id, e.to_string()));
+    }
+
+    Ok(())
+}
+
+/// Stop relaying on the given chain, shut down its runtime,
+/// and remove it from the configuration.
+#[instrument(
+    name = "supervisor.remove_chain",
+    level = "error",
+    skip_all,
+    fields(chain = %chain_id)
+)]
+fn remove_chain<Chain: ChainHandle>(
+    config: &RwArc<Config>,
+    registry: &SharedRegistry<Chain>,
+    workers: &Arc<RwLock<WorkerMap>>,
+    batch_tasks: &BatchTasks,
+    chain_id: &ChainId,
+) -> Result<(), RestApiError> {
+    let new_config = {
+        let mut config = config.acquire_write();
+
+        if config.find_chain(chain_id).is_none() {
+            return Err(RestApiError::ChainConfigNotFound(chain_id.clone()));
+        }
+
+        config.chains.retain(|c| c.id() != chain_id);
+        config.clone()
+    };
+
+    stop_chain(registry, workers, batch_tasks, chain_id);
+
+    registry.write().set_config(new_config);
+
+    Ok(())
+}
+
+/// Replace the packet filter of the given chain, and apply it to its workers.
+///
+/// See [`refilter_workers`].
+#[instrument(
+    name = "supervisor.update_packet_filter",
+    level = "error",
+    skip_all,
+    fields(chain = %chain_id)
+)]
+fn update_packet_filter<Chain: ChainHandle>(
+    config: &RwArc<Config>,
+    registry: &SharedRegistry<Chain>,
+    workers: &Arc<RwLock<WorkerMap>>,
+    chain_id: &ChainId,
+    packet_filter: PacketFilter,
+) -> Result<(), RestApiError> {
+    let (old_config, new_config) = {
+        let mut config = config.acquire_write();
+        let old_config = config.clone();
+
+        config
+            .find_chain_mut(chain_id)
+            .ok_or_else(|| RestApiError::ChainConfigNotFound(chain_id.clone()))?
+            .set_packet_filter(packet_filter);
+
+        (old_config, config.clone())
+    };
+
+    registry.write().set_config(new_config.clone());
+
+    refilter_workers(&old_config, &new_config, registry, workers, chain_id);
+
+    Ok(())
+}
+
+/// Spawn the runtime of the given chain, which must already be part of the
+/// configuration of the registry, along with the batch worker processing its events.
+///
+/// If `scan` is set, the chain is also scanned for clients, connections and channels,
+/// and workers are spawned for the objects found.
+fn start_chain<Chain: ChainHandle>(
+    config: &RwArc<Config>,
+    registry: &SharedRegistry<Chain>,
+    client_state_filter: &Arc<RwLock<FilterPolicy>>,
+    workers: &Arc<RwLock<WorkerMap>>,
+    batch_tasks: &BatchTasks,
+    chain_id: &ChainId,
+    scan: bool,
+) -> Result<(), Error> {
+    let chain = registry.get_or_spawn(chain_id).map_err(Error::spawn)?;
+
+    let subscription = match chain.subscribe() {
+        Ok(subscription) => subscription,
+        Err(e) => {
+            registry.shutdown(chain_id);
+            return Err(Error::relayer(e));
+        }
+    };
+
+    if scan {
+        let config = config.acquire_read().clone();
+        scan_chain(&config, registry, client_state_filter, workers, chain_id);
+    }
+
+    let batch_task = spawn_batch_worker(
+        config.clone(),
+        registry.clone(),
+        client_state_filter.clone(),
+        workers.clone(),
+        chain,
+        subscription,
+    );
+
+    batch_tasks
+        .acquire_write()
+        .insert(chain_id.clone(), batch_task);
+
+    Ok(())
+}
+
+/// Shut down the workers relaying to and from the given chain, as well as its runtime.
+///
+/// The batch worker of the chain stops once its event subscription gets disconnected.
+fn stop_chain<Chain: ChainHandle>(
+    registry: &SharedRegistry<Chain>,
+    workers: &Arc<RwLock<WorkerMap>>,
+    batch_tasks: &BatchTasks,
+    chain_id: &ChainId,
+) {
+    {
+        let mut workers = workers.acquire_write();
+
+        for object in workers.objects_for_chain(chain_id) {
+            workers.shutdown_worker(&object);
+        }
+    }
+
+    registry.shutdown(chain_id);
+
+    let batch_task = batch_tasks.acquire_write().remove(chain_id);
+    drop(batch_task);
+}
+
+/// Scan the given chain for clients, connections and channels,
+/// and spawn the workers for the objects found.
+fn scan_chain<Chain: ChainHandle>(
+    config: &Config,
+    registry: &SharedRegistry<Chain>,
+    client_state_filter: &Arc<RwLock<FilterPolicy>>,
+    workers: &Arc<RwLock<WorkerMap>>,
+    chain_id: &ChainId,
+) {
+    let Some(chain_config) = config.find_chain(chain_id) else {
+        return;
+    };
+
+    let scan = chain_scanner(
+        config,
+        &mut registry.write(),
+        &mut client_state_filter.acquire_write(),
+        ScanMode::Auto,
+    )
+    .scan_chain(chain_config);
+
+    let scan = ChainsScan { chains: vec![scan] };
+
+    info!("scanned chain:");
+    info!("{}", scan);
+
+    spawn_context(config, &mut registry.write(), &mut workers.acquire_write()).spawn_workers(scan);
+}
+
+/// Apply the new packet filter of the given chain to its workers.
+///
+/// The packet and channel workers for the channels which are not allowed anymore
+/// are shut down. Since packet workers capture the fee policy, the ICS-20 filter and
+/// the excluded sequences of their channel when they are spawned, the packet workers
+/// for the channels whose settings changed are respawned with the new ones.
+///
+/// Workers for newly allowed channels are spawned upon the next event on these channels.
+fn refilter_workers<Chain: ChainHandle>(
+    old_config: &Config,
+    new_config: &Config,
+    registry: &SharedRegistry<Chain>,
+    workers: &Arc<RwLock<WorkerMap>>,
+    chain_id: &ChainId,
+) {
+    let objects = workers.acquire_read().objects_for_chain(chain_id);
+
+    for object in objects {
+        let (port_id, channel_id) = match &object {
+            Object::Packet(p) if &p.src_chain_id == chain_id => (&p.src_port_id, &p.src_channel_id),
+            Object::Channel(c) if &c.src_chain_id == chain_id => {
+                (&c.src_port_id, &c.src_channel_id)
+            }
+            _ => continue,
+        };
+
+        if !is_channel_allowed(new_config, chain_id, port_id, channel_id) {
+            debug!(
+                "shutting down worker for {} as its channel is no longer allowed",
+                object.short_name()
+            );
+
+            workers.acquire_write().shutdown_worker(&object);
+            continue;
+        }
+
+        let Object::Packet(path) = &object else {
+            continue;
+        };
+
+        if packet_settings(old_config, chain_id, port_id, channel_id)
+            == packet_settings(new_config, chain_id, port_id, channel_id)
+        {
+            continue;
+        }
+
+        debug!(
+            "respawning worker for {} as the settings of its channel changed",
+            object.short_name()
+        );
+
+        // Get the chain handles before locking the workers, as the other users of both do
+        let chains = registry
+            .get_or_spawn(&path.src_chain_id)
+            .and_then(|src| Ok((src, registry.get_or_spawn(&path.dst_chain_id)?)));
+
+        let mut workers = workers.acquire_write();
+        workers.shutdown_worker(&object);
+
+        match chains {
+            Ok((src, dst)) => {
+                workers.spawn(src, dst, &object, new_config);
+            }
+            Err(e) => error!("failed to respawn worker for {}: {e}", object.short_name()),
+        }
+    }
+}
+
+/// The settings of the packet worker for the given channel of the given chain,
+/// which are captured by the worker when it is spawned.
+fn packet_settings(
+    config: &Config,
+    chain_id: &ChainId,
+    port_id: &PortId,
+    channel_id: &ChannelId,
+) -> Option<(Vec<Sequence>, Option<FeePolicy>, Ics20Filter)> {
+    let chain_config = config.find_chain(chain_id)?;
+    let packet_filter = chain_config.packet_filter();
+
+    Some((
+        chain_config.excluded_sequences(channel_id).to_vec(),
+        packet_filter.fee_policy(port_id, channel_id).cloned(),
+        packet_filter.ics20_filter(channel_id),
+    ))
+}
+
 #[instrument(
     name = "supervisor.clear_pending_packets",
     level = "error",
@@ -763,6 +1469,66 @@ fn clear_pending_packets(workers: &WorkerMap, chain_id: &ChainId) -> Result<(),
     Ok(())
 }
 
+/// Trace the lifecycle of the given packet on both ends of its channel,
+/// along with what the packet workers relaying it know of it.
+fn packet_trace<Chain: ChainHandle>(
+    registry: &SharedRegistry<Chain>,
+    workers: &Arc<RwLock<WorkerMap>>,
+    chain_id: &ChainId,
+    port_id: &PortId,
+    channel_id: &ChannelId,
+    sequence: Sequence,
+) -> Result<PacketTrace, Error> {
+    let chain = registry.get_or_spawn(chain_id).map_err(Error::spawn)?;
+
+    let channel_connection_client =
+        channel_connection_client_no_checks(&chain, port_id, channel_id)?;
+
+    let counterparty_chain_id = channel_connection_client.client.client_state.chain_id();
+    let counterparty_chain = registry
+        .get_or_spawn(&counterparty_chain_id)
+        .map_err(Error::spawn)?;
+
+    let mut trace = counterparty::packet_trace(
+        &chain,
+        &counterparty_chain,
+        &channel_connection_client.channel,
+        sequence,
+    )?;
+
+    let key = PacketKey::new(port_id.clone(), channel_id.clone(), sequence);
+
+    // Packets are relayed by the worker of the source channel,
+    // and their acknowledgements by the worker of the destination channel.
+    let paths = [
+        object::Packet {
+            dst_chain_id: counterparty_chain_id.clone(),
+            src_chain_id: chain_id.clone(),
+            src_channel_id: channel_id.clone(),
+            src_port_id: port_id.clone(),
+        },
+        object::Packet {
+            dst_chain_id: chain_id.clone(),
+            src_chain_id: counterparty_chain_id,
+            src_channel_id: trace.counterparty_channel_id.clone(),
+            src_port_id: trace.counterparty_port_id.clone(),
+        },
+    ];
+
+    let workers = workers.acquire_read();
+
+    let statuses = paths
+        .into_iter()
+        .filter_map(|path| workers.get(&Object::Packet(path)))
+        .filter_map(|worker| worker.inspector())
+        .filter_map(|inspector| inspector.packet_status(&key))
+        .collect();
+
+    trace.relayer = Some(statuses);
+
+    Ok(trace)
+}
+
 /// Process a batch of events received from a chain.
 #[instrument(
     name = "supervisor.process_batch",
@@ -834,6 +1600,12 @@ fn process_batch<Chain: ChainHandle>(
         );
     }
 
+    if let Some(store) = store::global() {
+        if let Err(e) = store.set_last_processed_height(&batch.chain_id, batch.height) {
+            warn!("failed to persist last processed height: {}", e);
+        }
+    }
+
     Ok(())
 }
 
@@ -1,8 +1,10 @@
 use crossbeam_channel::Sender;
 
 use super::dump_state::SupervisorState;
+use crate::config::Config;
 
 #[derive(Clone, Debug)]
 pub enum SupervisorCmd {
     DumpState(Sender<SupervisorState>),
+    UpdateConfig(Box<Config>),
 }
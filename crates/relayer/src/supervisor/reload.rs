@@ -0,0 +1,410 @@
+//! Reloading of the configuration of a running supervisor.
+//!
+//! Only the parts of the configuration which changed are applied: chains are
+//! started, stopped or restarted, gas settings are swapped inside the chain
+//! runtimes, and the workers are re-filtered or respawned with the new packet filter.
+
+use alloc::collections::btree_map::BTreeMap as HashMap;
+use core::fmt::{Display, Error as FmtError, Formatter};
+use std::sync::{Arc, RwLock};
+
+use tracing::{error, info, instrument, warn};
+
+use ibc_relayer_types::core::ics24_host::identifier::ChainId;
+
+use crate::{
+    chain::handle::ChainHandle,
+    config::{ChainConfig, Config, Diagnostic},
+    registry::SharedRegistry,
+    util::{
+        diff::{gdiff, Change},
+        lock::{LockExt, RwArc},
+    },
+    worker::WorkerMap,
+};
+
+use super::{
+    client_state_filter::FilterPolicy, refilter_workers, scan_chain, start_chain, stop_chain,
+    BatchTasks, Error,
+};
+
+/// How the configuration of a chain changed.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ChainChange {
+    /// The chain was added to the configuration.
+    Added,
+
+    /// The chain was removed from the configuration.
+    Removed,
+
+    /// Settings which require restarting the chain runtime changed.
+    Restarted,
+
+    /// Only the gas settings and/or the packet filter changed,
+    /// which can be updated without restarting the chain runtime.
+    Updated {
+        gas_settings: bool,
+        packet_filter: bool,
+    },
+}
+
+impl ChainChange {
+    fn between(prev: &ChainConfig, next: &ChainConfig) -> Self {
+        let mut patched = prev.clone();
+
+        patched.set_gas_settings(next);
+        let gas_settings = patched != *prev;
+
+        patched.set_packet_filter(next.packet_filter().clone());
+
+        if patched != *next {
+            return Self::Restarted;
+        }
+
+        Self::Updated {
+            gas_settings,
+            packet_filter: prev.packet_filter() != next.packet_filter(),
+        }
+    }
+}
+
+impl Display for ChainChange {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), FmtError> {
+        match self {
+            Self::Added => write!(f, "added"),
+            Self::Removed => write!(f, "removed"),
+            Self::Restarted => write!(f, "restarted"),
+            Self::Updated {
+                gas_settings,
+                packet_filter,
+            } => match (gas_settings, packet_filter) {
+                (true, true) => write!(f, "updated gas settings and packet filter"),
+                (true, false) => write!(f, "updated gas settings"),
+                (false, true) => write!(f, "updated packet filter"),
+                (false, false) => write!(f, "unchanged"),
+            },
+        }
+    }
+}
+
+/// The changes between the running configuration and a new one.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ConfigChanges {
+    /// The changes to the configuration of the chains.
+    pub chains: Vec<(ChainId, ChainChange)>,
+
+    /// Whether the `[mode]` section changed, in which case all the workers are respawned.
+    pub mode: bool,
+
+    /// The sections which changed but cannot be reloaded without restarting Hermes.
+    pub unsupported: Vec<&'static str>,
+}
+
+impl ConfigChanges {
+    pub fn new(prev: &Config, next: &Config) -> Self {
+        let prev_chains: HashMap<&ChainId, &ChainConfig> =
+            prev.chains.iter().map(|c| (c.id(), c)).collect();
+
+        let next_chains: HashMap<&ChainId, &ChainConfig> =
+            next.chains.iter().map(|c| (c.id(), c)).collect();
+
+        let mut chains = gdiff(&prev_chains, &next_chains, |a, b| a == b)
+            .into_iter()
+            .map(|change| match change {
+                Change::Added(id) => ((*id).clone(), ChainChange::Added),
+                Change::Removed(id) => ((*id).clone(), ChainChange::Removed),
+                Change::Updated(id) => (
+                    (*id).clone(),
+                    ChainChange::between(prev_chains[id], next_chains[id]),
+                ),
+            })
+            .collect::<Vec<_>>();
+
+        chains.sort_by(|a, b| a.0.cmp(&b.0));
+
+        let mut unsupported = Vec::new();
+
+        if prev.global != next.global {
+            unsupported.push("global");
+        }
+        if prev.rest != next.rest {
+            unsupported.push("rest");
+        }
+        if prev.telemetry != next.telemetry {
+            unsupported.push("telemetry");
+        }
+        if prev.tracing_server != next.tracing_server {
+            unsupported.push("tracing_server");
+        }
+        if prev.store != next.store {
+            unsupported.push("store");
+        }
+
+        Self {
+            chains,
+            mode: prev.mode != next.mode,
+            unsupported,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.chains.is_empty() && !self.mode && self.unsupported.is_empty()
+    }
+}
+
+/// Apply the changes between the running configuration and the given one.
+///
+/// The running configuration is left untouched if the new one is invalid.
+#[instrument(name = "supervisor.update_config", level = "error", skip_all)]
+pub(super) fn update_config<Chain: ChainHandle>(
+    config: &RwArc<Config>,
+    registry: &SharedRegistry<Chain>,
+    client_state_filter: &Arc<RwLock<FilterPolicy>>,
+    workers: &Arc<RwLock<WorkerMap>>,
+    batch_tasks: &BatchTasks,
+    mut new_config: Config,
+) {
+    match new_config.validate_config() {
+        Ok(()) => {}
+        Err(Diagnostic::Warning(e)) => warn!("relayer may be misconfigured: {e}"),
+        Err(Diagnostic::Error(e)) => {
+            error!("ignoring invalid configuration: {e}");
+            return;
+        }
+    }
+
+    let old_config = config.acquire_read().clone();
+    let changes = ConfigChanges::new(&old_config, &new_config);
+
+    if changes.is_empty() {
+        info!("configuration is unchanged");
+        return;
+    }
+
+    for section in &changes.unsupported {
+        warn!("changes to the `[{section}]` section require a restart to take effect");
+    }
+
+    // Keep running with the sections which cannot be reloaded
+    new_config.global = old_config.global.clone();
+    new_config.rest = old_config.rest.clone();
+    new_config.telemetry = old_config.telemetry.clone();
+    new_config.tracing_server = old_config.tracing_server.clone();
+    new_config.store = old_config.store.clone();
+
+    for (chain_id, change) in &changes.chains {
+        if matches!(change, ChainChange::Removed | ChainChange::Restarted) {
+            stop_chain(registry, workers, batch_tasks, chain_id);
+        }
+    }
+
+    *config.acquire_write() = new_config.clone();
+    registry.write().set_config(new_config.clone());
+
+    for (chain_id, change) in &changes.chains {
+        info!(chain = %chain_id, "{change}");
+
+        match change {
+            ChainChange::Added | ChainChange::Restarted => {
+                // When the mode changed, all the chains are scanned below
+                let scan = !changes.mode;
+
+                let started = start_chain(
+                    config,
+                    registry,
+                    client_state_filter,
+                    workers,
+                    batch_tasks,
+                    chain_id,
+                    scan,
+                );
+
+                if let Err(e) = started {
+                    error!(chain = %chain_id, "failed to start chain: {e}");
+                }
+            }
+
+            ChainChange::Updated {
+                gas_settings,
+                packet_filter,
+            } => {
+                if *gas_settings {
+                    update_gas_settings(registry, &new_config, chain_id).unwrap_or_else(
+                        |e| error!(chain = %chain_id, "failed to update gas settings: {e}"),
+                    );
+                }
+
+                if *packet_filter {
+                    refilter_workers(&old_config, &new_config, registry, workers, chain_id);
+                }
+            }
+
+            ChainChange::Removed => {}
+        }
+    }
+
+    if changes.mode {
+        info!("operation mode changed, respawning all workers");
+
+        {
+            let mut workers = workers.acquire_write();
+
+            let objects = workers
+                .handles()
+                .map(|handle| handle.object().clone())
+                .collect::<Vec<_>>();
+
+            for object in objects {
+                workers.shutdown_worker(&object);
+            }
+        }
+
+        for chain_config in &new_config.chains {
+            scan_chain(
+                &new_config,
+                registry,
+                client_state_filter,
+                workers,
+                chain_config.id(),
+            );
+        }
+    }
+
+    info!("configuration reloaded");
+}
+
+fn update_gas_settings<Chain: ChainHandle>(
+    registry: &SharedRegistry<Chain>,
+    config: &Config,
+    chain_id: &ChainId,
+) -> Result<(), Error> {
+    let Some(chain_config) = config.find_chain(chain_id) else {
+        return Ok(());
+    };
+
+    registry
+        .get_or_spawn(chain_id)
+        .map_err(Error::spawn)?
+        .update_gas_settings(chain_config.clone())
+        .map_err(Error::relayer)
+}
+
+#[cfg(test)]
+mod tests {
+    use core::time::Duration;
+
+    use test_log::test;
+
+    use super::*;
+    use crate::config::{load, GasPrice};
+
+    fn config() -> Config {
+        let path = concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/tests/config/fixtures/relayer_conf_example.toml"
+        );
+
+        load(path).expect("could not parse config")
+    }
+
+    fn chain_id(id: &str) -> ChainId {
+        ChainId::from_string(id)
+    }
+
+    #[test]
+    fn unchanged_config() {
+        let changes = ConfigChanges::new(&config(), &config());
+
+        assert!(changes.is_empty());
+    }
+
+    #[test]
+    fn added_and_removed_chains() {
+        let prev = config();
+
+        let mut next = config();
+        let mut chain = next.chains.remove(0);
+        if let ChainConfig::CosmosSdk(chain) = &mut chain {
+            chain.id = chain_id("chain_C");
+        }
+        next.chains.push(chain);
+
+        let changes = ConfigChanges::new(&prev, &next);
+
+        assert_eq!(
+            changes.chains,
+            vec![
+                (chain_id("chain_A"), ChainChange::Removed),
+                (chain_id("chain_C"), ChainChange::Added),
+            ]
+        );
+    }
+
+    #[test]
+    fn updated_gas_settings_and_packet_filter() {
+        let prev = config();
+
+        let mut next = config();
+        if let ChainConfig::CosmosSdk(chain) = &mut next.chains[0] {
+            chain.gas_price = GasPrice::new(0.1, "stake".to_string());
+        }
+        // Chain B has no packet filter, so give it the one of chain A
+        let packet_filter = next.chains[0].packet_filter().clone();
+        next.chains[1].set_packet_filter(packet_filter);
+
+        let changes = ConfigChanges::new(&prev, &next);
+
+        assert_eq!(
+            changes.chains,
+            vec![
+                (
+                    chain_id("chain_A"),
+                    ChainChange::Updated {
+                        gas_settings: true,
+                        packet_filter: false
+                    }
+                ),
+                (
+                    chain_id("chain_B"),
+                    ChainChange::Updated {
+                        gas_settings: false,
+                        packet_filter: true
+                    }
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn restarted_chain() {
+        let prev = config();
+
+        let mut next = config();
+        if let ChainConfig::CosmosSdk(chain) = &mut next.chains[0] {
+            chain.gas_price = GasPrice::new(0.1, "stake".to_string());
+            chain.rpc_timeout = Duration::from_secs(42);
+        }
+
+        let changes = ConfigChanges::new(&prev, &next);
+
+        assert_eq!(
+            changes.chains,
+            vec![(chain_id("chain_A"), ChainChange::Restarted)]
+        );
+    }
+
+    #[test]
+    fn mode_and_unsupported_sections() {
+        let prev = config();
+
+        let mut next = config();
+        next.mode.packets.clear_interval += 1;
+        next.rest.port += 1;
+
+        let changes = ConfigChanges::new(&prev, &next);
+
+        assert!(changes.chains.is_empty());
+        assert!(changes.mode);
+        assert_eq!(changes.unsupported, vec!["rest"]);
+    }
+}
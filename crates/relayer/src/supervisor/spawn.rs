@@ -2,11 +2,15 @@ use tracing::{error, info};
 
 use ibc_relayer_types::core::{
     ics03_connection::connection::IdentifiedConnectionEnd,
-    ics04_channel::channel::State as ChannelState,
+    ics04_channel::channel::{IdentifiedChannelEnd, State as ChannelState},
 };
 
 use crate::{
-    chain::{counterparty::connection_state_on_destination, handle::ChainHandle},
+    chain::{
+        counterparty::connection_state_on_destination,
+        handle::ChainHandle,
+        requests::{IncludeProof, QueryHeight, QueryUpgradeRequest},
+    },
     client_state::IdentifiedAnyClientState,
     config::Config,
     object::{Channel, Client, Connection, Object, Packet, Wallet},
@@ -244,9 +248,42 @@ impl<'a, Chain: ChainHandle> SpawnContext<'a, Chain> {
             chan_state_dst
         );
 
+        // Determine if an upgrade handshake is in progress, i.e. if either channel end
+        // is flushing, or if one end has initialised an upgrade the other has not picked up yet.
+        // The upgrade sequences of the ends cannot tell the latter, since they still differ
+        // once the upgrade is cancelled, but the upgrade is then no longer stored.
+        let upgrade_handshake = chan_state_src.is_upgrading()
+            || chan_state_dst.is_upgrading()
+            || has_upgrade(&chain, &channel_scan.channel)
+            || channel_scan
+                .counterparty
+                .as_ref()
+                .map_or(false, |c| has_upgrade(&counterparty_chain, c));
+
+        if mode.channels.enabled && upgrade_handshake {
+            // create worker for the upgrade handshake that will advance the counterparty state
+            let channel_object = Object::Channel(Channel {
+                dst_chain_id: counterparty_chain.id(),
+                src_chain_id: chain.id(),
+                src_channel_id: channel_scan.channel.channel_id.clone(),
+                src_port_id: channel_scan.channel.port_id.clone(),
+            });
+
+            self.workers
+                .spawn(
+                    chain.clone(),
+                    counterparty_chain.clone(),
+                    &channel_object,
+                    self.config,
+                )
+                .then(|| info!("spawned channel worker: {}", channel_object.short_name()));
+        }
+
         if (mode.clients.enabled || mode.packets.enabled)
-            && chan_state_src.is_open()
-            && (chan_state_dst.is_open() || chan_state_dst.is_closed())
+            && (chan_state_src.is_open() || chan_state_src.is_upgrading())
+            && (chan_state_dst.is_open()
+                || chan_state_dst.is_closed()
+                || chan_state_dst.is_upgrading())
         {
             if mode.clients.enabled {
                 // Spawn the client worker
@@ -344,3 +381,23 @@ impl<'a, Chain: ChainHandle> SpawnContext<'a, Chain> {
         }
     }
 }
+
+/// Whether an upgrade is stored for the given channel end, ie. whether an upgrade
+/// was initialised on it and has neither completed nor been cancelled yet.
+fn has_upgrade<Chain: ChainHandle>(chain: &Chain, channel: &IdentifiedChannelEnd) -> bool {
+    // A channel which was never upgraded has no upgrade to query for
+    if channel.channel_end.upgrade_sequence == 0 {
+        return false;
+    }
+
+    chain
+        .query_upgrade(
+            QueryUpgradeRequest {
+                port_id: channel.port_id.clone(),
+                channel_id: channel.channel_id.clone(),
+                height: QueryHeight::Latest,
+            },
+            IncludeProof::No,
+        )
+        .is_ok()
+}
@@ -8,6 +8,7 @@ use flex_error::define_error;
 
 use tendermint::Hash as TxHash;
 
+use ibc_proto::cosmos::base::v1beta1::Coin;
 use ibc_proto::cosmos::gov::v1::MsgSubmitProposal;
 use ibc_proto::cosmos::gov::v1beta1::MsgSubmitProposal as LegacyMsgSubmitProposal;
 use ibc_proto::cosmos::upgrade::v1beta1::Plan;
@@ -16,6 +17,7 @@ use ibc_proto::ibc::core::client::v1::{MsgIbcSoftwareUpgrade, UpgradeProposal};
 use ibc_relayer_types::clients::ics07_tendermint::client_state::UpgradeOptions;
 use ibc_relayer_types::core::ics02_client::client_state::ClientState;
 use ibc_relayer_types::core::ics24_host::identifier::{ChainId, ClientId};
+use ibc_relayer_types::signer::Signer;
 use ibc_relayer_types::{downcast, Height};
 use tracing::warn;
 
@@ -201,18 +203,7 @@ fn build_legacy_upgrade_proposal(
         amount: opts.amount.to_string(),
     };
 
-    let msg = LegacyMsgSubmitProposal {
-        content: Some(any_proposal),
-        initial_deposit: vec![coins],
-        proposer: proposer.to_string(),
-    };
-
-    let mut buf_msg = Vec::new();
-    prost::Message::encode(&msg, &mut buf_msg).unwrap();
-    Ok(Any {
-        type_url: "/cosmos.gov.v1beta1.MsgSubmitProposal".to_string(),
-        value: buf_msg,
-    })
+    Ok(build_legacy_submit_proposal(any_proposal, coins, &proposer))
 }
 
 /// Since ibc-go version to v8.x.x `MsgIbcSoftwareUpgrade` is used to upgrade a chain
@@ -289,21 +280,60 @@ fn build_upgrade_proposal(
         amount: opts.amount.to_string(),
     };
 
+    Ok(build_submit_proposal(
+        vec![any_proposal],
+        coins,
+        &proposer,
+        "proposal 0",
+        "upgrade the chain software and unbonding period",
+    ))
+}
+
+/// Wrap the content of a legacy proposal into a `MsgSubmitProposal`
+/// of the `gov/v1beta1` module, with the given initial deposit.
+pub(crate) fn build_legacy_submit_proposal(
+    content: Any,
+    initial_deposit: Coin,
+    proposer: &Signer,
+) -> Any {
+    let msg = LegacyMsgSubmitProposal {
+        content: Some(content),
+        initial_deposit: vec![initial_deposit],
+        proposer: proposer.to_string(),
+    };
+
+    let mut buf_msg = Vec::new();
+    prost::Message::encode(&msg, &mut buf_msg).unwrap();
+    Any {
+        type_url: "/cosmos.gov.v1beta1.MsgSubmitProposal".to_string(),
+        value: buf_msg,
+    }
+}
+
+/// Wrap the messages to execute if a proposal passes into a `MsgSubmitProposal`
+/// of the `gov/v1` module, with the given initial deposit.
+pub(crate) fn build_submit_proposal(
+    messages: Vec<Any>,
+    initial_deposit: Coin,
+    proposer: &Signer,
+    title: &str,
+    summary: &str,
+) -> Any {
     let msg = MsgSubmitProposal {
-        messages: vec![any_proposal],
-        initial_deposit: vec![coins],
+        messages,
+        initial_deposit: vec![initial_deposit],
         proposer: proposer.to_string(),
         metadata: "".to_string(),
-        title: "proposal 0".to_string(),
-        summary: "upgrade the chain software and unbonding period".to_string(),
+        title: title.to_string(),
+        summary: summary.to_string(),
     };
 
     let mut buf_msg = Vec::new();
     prost::Message::encode(&msg, &mut buf_msg).unwrap();
-    Ok(Any {
+    Any {
         type_url: "/cosmos.gov.v1.MsgSubmitProposal".to_string(),
         value: buf_msg,
-    })
+    }
 }
 
 enum Proposal {
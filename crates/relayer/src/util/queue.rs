@@ -58,6 +58,14 @@ impl<T: Clone> Queue<T> {
     }
 }
 
+/// Cloning a `Queue` returns another handle to the same underlying queue,
+/// so that it can be inspected from another thread.
+impl<T> Clone for Queue<T> {
+    fn clone(&self) -> Self {
+        Queue(self.0.clone())
+    }
+}
+
 impl<T> Default for Queue<T> {
     fn default() -> Self {
         Self::new()
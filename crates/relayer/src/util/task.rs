@@ -18,10 +18,15 @@ use crate::util::lock::LockExt;
 
    Otherwise, when the `TaskHandle` is dropped, it will stop the background
    task and wait for the background task to terminate before returning.
+
+   The background task can also be temporarily suspended by calling
+   [`pause`](TaskHandle::pause), and later continued with
+   [`resume`](TaskHandle::resume).
 */
 pub struct TaskHandle {
     shutdown_sender: Sender<()>,
     stopped: Arc<RwLock<bool>>,
+    paused: Arc<RwLock<bool>>,
     join_handle: DropJoinHandle,
 }
 
@@ -52,6 +57,10 @@ pub enum TaskError<E> {
     Fatal(E),
 }
 
+/// How long a paused task without an interval pause sleeps
+/// before checking again whether it was resumed.
+const PAUSED_POLL_INTERVAL: Duration = Duration::from_millis(500);
+
 pub enum Next {
     Continue,
     Abort,
@@ -95,6 +104,9 @@ pub fn spawn_background_task<E: Display>(
     let stopped = Arc::new(RwLock::new(false));
     let write_stopped = stopped.clone();
 
+    let paused = Arc::new(RwLock::new(false));
+    let read_paused = paused.clone();
+
     let (shutdown_sender, receiver) = bounded(1);
 
     let join_handle = thread::spawn(move || {
@@ -104,6 +116,10 @@ pub fn spawn_background_task<E: Display>(
                 Ok(()) => {
                     break;
                 }
+                _ if *read_paused.acquire_read() => {
+                    thread::sleep(interval_pause.unwrap_or(PAUSED_POLL_INTERVAL));
+                    continue;
+                }
                 _ => match step_runner() {
                     Ok(Next::Continue) => {}
                     Ok(Next::Abort) => {
@@ -132,6 +148,7 @@ pub fn spawn_background_task<E: Display>(
     TaskHandle {
         shutdown_sender,
         stopped,
+        paused,
         join_handle: DropJoinHandle(Some(join_handle)),
     }
 }
@@ -179,6 +196,29 @@ impl TaskHandle {
     pub fn is_stopped(&self) -> bool {
         *self.stopped.acquire_read()
     }
+
+    /**
+       Suspend the background task: the step runner will not be called
+       again until the task is resumed, but the task can still be shut down.
+    */
+    pub fn pause(&self) {
+        *self.paused.acquire_write() = true;
+    }
+
+    /**
+       Resume a background task previously suspended with
+       [`pause`](TaskHandle::pause).
+    */
+    pub fn resume(&self) {
+        *self.paused.acquire_write() = false;
+    }
+
+    /**
+       Check whether a background task is currently paused.
+    */
+    pub fn is_paused(&self) -> bool {
+        *self.paused.acquire_read()
+    }
 }
 
 impl Drop for DropJoinHandle {
@@ -61,6 +61,7 @@ pub fn spawn_worker_tasks<ChainA: ChainHandle, ChainB: ChainHandle>(
     config: &Config,
 ) -> WorkerHandle {
     let mut task_handles = Vec::new();
+    let mut inspector = None;
 
     let (cmd_tx, data) = match &object {
         Object::Client(client) => {
@@ -116,6 +117,15 @@ pub fn spawn_worker_tasks<ChainA: ChainHandle, ChainB: ChainHandle>(
                 .unwrap_or_default()
                 .to_vec();
 
+            let ics20_filter = config
+                .find_chain(&chains.a.id())
+                .map(|chain_config| {
+                    chain_config
+                        .packet_filter()
+                        .ics20_filter(&path.src_channel_id)
+                })
+                .unwrap_or_default();
+
             let packets_config = config.mode.packets;
             let link_res = Link::new_from_opts(
                 chains.a.clone(),
@@ -126,6 +136,7 @@ pub fn spawn_worker_tasks<ChainA: ChainHandle, ChainB: ChainHandle>(
                     max_memo_size: packets_config.ics20_max_memo_size,
                     max_receiver_size: packets_config.ics20_max_receiver_size,
                     exclude_src_sequences,
+                    ics20_filter,
                 },
                 packets_config.tx_confirmation,
                 packets_config.auto_register_counterparty_payee,
@@ -134,10 +145,16 @@ pub fn spawn_worker_tasks<ChainA: ChainHandle, ChainB: ChainHandle>(
             match link_res {
                 Ok(link) => {
                     let channel_ordering = link.a_to_b.channel().ordering;
+                    // Chains resumed from the relayer state store do not need
+                    // to clear the packets on start, the tracked ones are relayed instead
+                    let resumed = crate::store::global()
+                        .map_or(false, |store| store.is_resumed(&chains.a.id()));
+
                     let should_clear_on_start =
-                        should_clear_on_start(&packets_config, channel_ordering);
+                        should_clear_on_start(&packets_config, channel_ordering, resumed);
 
                     let (cmd_tx, cmd_rx) = crossbeam_channel::unbounded();
+                    inspector = Some(link.a_to_b.inspector());
                     let link = Arc::new(Mutex::new(link));
 
                     let src_chain_config = config
@@ -153,10 +170,7 @@ pub fn spawn_worker_tasks<ChainA: ChainHandle, ChainB: ChainHandle>(
 
                             let fee_filter = chain_config
                                 .packet_filter()
-                                .min_fees
-                                .iter()
-                                .find(|(channel, _)| channel.matches(&path.src_channel_id))
-                                .map(|(_, filter)| filter)
+                                .fee_policy(&path.src_port_id, &path.src_channel_id)
                                 .cloned();
 
                             (fee_filter, chain_clear_interval)
@@ -222,13 +236,17 @@ pub fn spawn_worker_tasks<ChainA: ChainHandle, ChainB: ChainHandle>(
         }
     };
 
-    WorkerHandle::new(id, object, data, cmd_tx, task_handles)
+    WorkerHandle::new(id, object, data, cmd_tx, task_handles, inspector)
 }
 
-fn should_clear_on_start(config: &crate::config::Packets, channel_ordering: Ordering) -> bool {
+fn should_clear_on_start(
+    config: &crate::config::Packets,
+    channel_ordering: Ordering,
+    resumed: bool,
+) -> bool {
     if config.force_disable_clear_on_start {
         false
     } else {
-        config.clear_on_start || channel_ordering == Ordering::Ordered
+        (config.clear_on_start && !resumed) || channel_ordering.is_ordered()
     }
 }
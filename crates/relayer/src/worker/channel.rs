@@ -49,16 +49,42 @@ pub fn spawn_channel_worker<ChainA: ChainHandle, ChainB: ChainHandle>(
 
                         complete_handshake_on_new_block = false;
                         if let Some(event_with_height) = last_event {
+                            let is_upgrade_event = event_with_height
+                                .event
+                                .clone()
+                                .channel_upgrade_attributes()
+                                .is_some();
+
+                            // Upgrade events do not carry the connection identifier,
+                            // hence the channel is restored from the chain state instead.
+                            // The following steps may only become possible once the
+                            // in-flight packets are flushed, so keep checking on new blocks.
+                            complete_handshake_on_new_block = is_upgrade_event;
+
                             retry_with_index(
                                 channel_handshake_retry::default_strategy(max_block_times),
-                                |index| match RelayChannel::restore_from_event(
-                                    chains.a.clone(),
-                                    chains.b.clone(),
-                                    event_with_height.event.clone(),
-                                ) {
-                                    Ok(mut handshake_channel) => handshake_channel
-                                        .step_event(&event_with_height.event, index),
-                                    Err(_) => RetryResult::Retry(index),
+                                |index| {
+                                    let restored = if is_upgrade_event {
+                                        RelayChannel::restore_from_state(
+                                            chains.a.clone(),
+                                            chains.b.clone(),
+                                            channel.clone(),
+                                            event_with_height.height,
+                                        )
+                                        .map(|(handshake_channel, _)| handshake_channel)
+                                    } else {
+                                        RelayChannel::restore_from_event(
+                                            chains.a.clone(),
+                                            chains.b.clone(),
+                                            event_with_height.event.clone(),
+                                        )
+                                    };
+
+                                    match restored {
+                                        Ok(mut handshake_channel) => handshake_channel
+                                            .step_event(&event_with_height.event, index),
+                                        Err(_) => RetryResult::Retry(index),
+                                    }
                                 },
                             )
                             .map_err(|e| TaskError::Fatal(RunError::retry(e)))
@@ -87,6 +113,9 @@ pub fn spawn_channel_worker<ChainA: ChainHandle, ChainB: ChainHandle>(
                                 height,
                             ) {
                                 Ok((mut handshake_channel, state)) => {
+                                    // Keep driving an upgrade whose next step depends
+                                    // on in-flight packets being flushed.
+                                    complete_handshake_on_new_block = state.is_upgrading();
                                     handshake_channel.step_state(state, index)
                                 }
                                 Err(_) => RetryResult::Retry(index),
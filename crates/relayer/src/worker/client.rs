@@ -8,6 +8,8 @@ use tracing::{debug, debug_span, error_span, trace, warn};
 use ibc_relayer_types::core::ics02_client::events::UpdateClient;
 use ibc_relayer_types::events::IbcEvent;
 
+use crate::event::relay::{self, RelayEvent};
+use crate::object::Client;
 use crate::util::retry::clamp_total;
 use crate::util::task::{spawn_background_task, Next, TaskError, TaskHandle};
 use crate::{
@@ -53,7 +55,19 @@ pub fn spawn_refresh_client<ChainA: ChainHandle, ChainB: ChainHandle>(
 
                 // If `client.refresh()` failed and the retry mechanism
                 // exceeded the maximum delay, return a fatal error.
-                Err(e) => Err(TaskError::Fatal(e)),
+                Err(e) => {
+                    relay::publish(|| RelayEvent::WorkerError {
+                        worker: Client {
+                            dst_chain_id: client.dst_chain.id(),
+                            dst_client_id: client.id.clone(),
+                            src_chain_id: client.src_chain.id(),
+                        }
+                        .short_name(),
+                        error: e.to_string(),
+                    });
+
+                    Err(TaskError::Fatal(e))
+                }
             }
         },
     ))
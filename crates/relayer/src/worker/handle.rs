@@ -13,6 +13,7 @@ use ibc_relayer_types::{
 
 use crate::chain::tracking::TrackingId;
 use crate::event::IbcEventWithHeight;
+use crate::link::inspect::RelayPathInspector;
 use crate::util::lock::{LockExt, RwArc};
 use crate::util::task::TaskHandle;
 use crate::{event::source::EventBatch, object::Object};
@@ -31,6 +32,7 @@ pub struct WorkerHandle {
     data: Option<WorkerData>,
     tx: RwArc<Option<Sender<WorkerCmd>>>,
     task_handles: Vec<TaskHandle>,
+    inspector: Option<RelayPathInspector>,
 }
 
 impl WorkerHandle {
@@ -40,6 +42,7 @@ impl WorkerHandle {
         data: Option<WorkerData>,
         tx: Option<Sender<WorkerCmd>>,
         task_handles: Vec<TaskHandle>,
+        inspector: Option<RelayPathInspector>,
     ) -> Self {
         Self {
             id,
@@ -47,6 +50,7 @@ impl WorkerHandle {
             data,
             tx: <RwArc<_>>::new_lock(tx),
             task_handles,
+            inspector,
         }
     }
 
@@ -82,7 +86,14 @@ impl WorkerHandle {
     }
 
     /// Send a batch of [`NewBlock`] event to the worker.
+    ///
+    /// The event is dropped if the worker is paused, since the worker would
+    /// otherwise accumulate one such command for every block while paused.
     pub fn send_new_block(&self, height: Height, new_block: NewBlock) {
+        if self.is_paused() {
+            return;
+        }
+
         self.try_send_command(WorkerCmd::NewBlock { height, new_block });
     }
 
@@ -91,6 +102,35 @@ impl WorkerHandle {
         self.try_send_command(WorkerCmd::ClearPendingPackets);
     }
 
+    /// Pause all worker tasks.
+    ///
+    /// The commands sent to the worker while it is paused are
+    /// queued and only processed once the worker is resumed.
+    pub fn pause(&self) {
+        for task in self.task_handles.iter() {
+            task.pause()
+        }
+    }
+
+    /// Resume all worker tasks.
+    ///
+    /// Packet workers also clear the pending packets, to catch up
+    /// with the blocks committed while they were paused.
+    pub fn resume(&self) {
+        for task in self.task_handles.iter() {
+            task.resume()
+        }
+
+        if matches!(self.object, Object::Packet(_)) {
+            self.clear_pending_packets();
+        }
+    }
+
+    /// Whether the worker tasks are paused.
+    pub fn is_paused(&self) -> bool {
+        self.task_handles.iter().any(|t| t.is_paused())
+    }
+
     /// Shutdown all worker tasks without waiting for them to terminate.
     pub fn shutdown(&self) {
         for task in self.task_handles.iter() {
@@ -152,6 +192,11 @@ impl WorkerHandle {
     pub fn data(&self) -> Option<&WorkerData> {
         self.data.as_ref()
     }
+
+    /// Get a handle to inspect the packets relayed by a packet worker.
+    pub fn inspector(&self) -> Option<&RelayPathInspector> {
+        self.inspector.as_ref()
+    }
 }
 
 // Drop handle to send shutdown signals to background tasks in parallel
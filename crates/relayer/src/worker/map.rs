@@ -1,4 +1,5 @@
 use alloc::collections::btree_map::BTreeMap as HashMap;
+use alloc::collections::btree_set::BTreeSet;
 use core::mem;
 
 use ibc_relayer_types::core::ics02_client::events::NewBlock;
@@ -19,6 +20,7 @@ use super::{spawn_worker_tasks, WorkerHandle, WorkerId};
 #[derive(Debug)]
 pub struct WorkerMap {
     workers: HashMap<Object, WorkerHandle>,
+    paused: BTreeSet<Object>,
     latest_worker_id: WorkerId,
 }
 
@@ -26,6 +28,7 @@ impl Default for WorkerMap {
     fn default() -> Self {
         Self {
             workers: HashMap::new(),
+            paused: BTreeSet::new(),
             latest_worker_id: WorkerId::new(0),
         }
     }
@@ -44,6 +47,11 @@ impl WorkerMap {
         self.workers.contains_key(object)
     }
 
+    /// Returns the [`WorkerHandle`] associated with the given [`Object`], if any.
+    pub fn get(&self, object: &Object) -> Option<&WorkerHandle> {
+        self.workers.get(object)
+    }
+
     /// Remove the [`WorkerHandle`] associated with the given [`Object`] from
     /// the map and wait for its thread to terminate.
     pub fn remove_stopped(&mut self, id: WorkerId, object: Object) -> bool {
@@ -162,6 +170,9 @@ impl WorkerMap {
     }
 
     /// Force spawn a worker for the given [`Object`].
+    ///
+    /// The worker starts out paused if its object was paused,
+    /// so that the pause survives the worker being respawned.
     fn spawn_worker<Chain: ChainHandle>(
         &mut self,
         src: Chain,
@@ -171,12 +182,49 @@ impl WorkerMap {
     ) -> WorkerHandle {
         telemetry!(worker, metric_type(object), 1);
 
-        spawn_worker_tasks(
+        let worker = spawn_worker_tasks(
             ChainHandlePair { a: src, b: dst },
             self.next_worker_id(),
             object.clone(),
             config,
-        )
+        );
+
+        if self.paused.contains(object) {
+            debug!("re-applying pause to worker {}", object.short_name());
+            worker.pause();
+        }
+
+        worker
+    }
+
+    /// Pause the worker for the given [`Object`], and keep it paused
+    /// whenever it gets respawned until [`WorkerMap::resume`] is called.
+    ///
+    /// Returns `false` if there is no worker for this object.
+    pub fn pause(&mut self, object: &Object) -> bool {
+        match self.workers.get(object) {
+            Some(worker) => {
+                worker.pause();
+                self.paused.insert(object.clone());
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Resume the worker for the given [`Object`].
+    ///
+    /// Returns `false` if there is no worker for this object.
+    pub fn resume(&mut self, object: &Object) -> bool {
+        self.paused.remove(object);
+
+        match self.workers.get(object) {
+            Some(worker) => {
+                worker.resume();
+                true
+            }
+            None => false,
+        }
     }
 
     /// Compute the next worker id
@@ -1,9 +1,3 @@
-#[cfg(feature = "telemetry")]
-use {
-    ibc_relayer_types::core::ics24_host::identifier::ChannelId,
-    ibc_relayer_types::core::ics24_host::identifier::PortId,
-};
-
 use core::time::Duration;
 use std::borrow::BorrowMut;
 use std::sync::{Arc, Mutex};
@@ -11,27 +5,33 @@ use std::sync::{Arc, Mutex};
 use crossbeam_channel::Receiver;
 use itertools::Itertools;
 use moka::sync::Cache;
+use once_cell::unsync::OnceCell;
 use tracing::{debug, error, error_span, info, trace, warn};
 
 use ibc_proto::ibc::apps::fee::v1::{IdentifiedPacketFees, QueryIncentivizedPacketRequest};
 use ibc_proto::ibc::core::channel::v1::PacketId;
 use ibc_relayer_types::applications::ics29_fee::events::IncentivizedPacket;
 use ibc_relayer_types::applications::transfer::{Amount, Coin, RawCoin};
-use ibc_relayer_types::core::ics04_channel::channel::Ordering;
 use ibc_relayer_types::core::ics04_channel::events::WriteAcknowledgement;
+use ibc_relayer_types::core::ics04_channel::msgs;
 use ibc_relayer_types::core::ics04_channel::packet::Sequence;
+use ibc_relayer_types::core::ics24_host::identifier::{ChannelId, PortId};
 use ibc_relayer_types::events::{IbcEvent, IbcEventType};
 use ibc_relayer_types::Height;
 
+use crate::chain::endpoint::ChainStatus;
 use crate::chain::handle::ChainHandle;
 use crate::chain::requests::QueryHeight;
+use crate::chain::tracking::TrackingId;
 use crate::config::filter::FeePolicy;
+use crate::event::relay::{self, RelayEvent};
 use crate::event::source::EventBatch;
 use crate::event::IbcEventWithHeight;
 use crate::foreign_client::HasExpiredOrFrozenError;
 use crate::link::Resubmit;
 use crate::link::{error::LinkError, Link};
 use crate::object::Packet;
+use crate::store;
 use crate::telemetry;
 use crate::util::lock::{LockExt, RwArc};
 use crate::util::task::{spawn_background_task, Next, TaskError, TaskHandle};
@@ -46,7 +46,9 @@ const INCENTIVIZED_CACHE_MAX_CAPACITY: u64 = 1000;
 // packet cmd worker.
 const IDLE_TIMEOUT_BLOCKS: u64 = 100;
 
-fn handle_link_error_in_task(e: LinkError) -> TaskError<RunError> {
+fn handle_link_error_in_task(path: &Packet, e: LinkError) -> TaskError<RunError> {
+    publish_worker_error(path, &e);
+
     if e.is_expired_or_frozen_error() {
         // If the client is expired or frozen, terminate the packet worker
         // as there is no point of relaying further packets.
@@ -56,6 +58,13 @@ fn handle_link_error_in_task(e: LinkError) -> TaskError<RunError> {
     }
 }
 
+fn publish_worker_error(path: &Packet, e: &LinkError) {
+    relay::publish(|| RelayEvent::WorkerError {
+        worker: path.short_name(),
+        error: e.to_string(),
+    });
+}
+
 /// Spawns a packet worker task in the background that handles the work of
 /// processing pending txs between `ChainA` and `ChainB`.
 pub fn spawn_packet_worker<ChainA: ChainHandle, ChainB: ChainHandle>(
@@ -108,10 +117,21 @@ pub fn spawn_packet_cmd_worker<ChainA: ChainHandle, ChainB: ChainHandle>(
 
     let mut idle_worker_timer = 0;
 
+    // If the source chain was resumed from the relayer state store,
+    // relay the packets which were tracked when the relayer was last stopped.
+    let mut should_resume_tracked =
+        store::global().map_or(false, |store| store.is_resumed(&path.src_chain_id));
+
     spawn_background_task(span, Some(Duration::from_millis(200)), move || {
         if let Ok(cmd) = cmd_rx.try_recv() {
             let is_new_batch = cmd.is_ibc_events();
 
+            if should_resume_tracked {
+                should_resume_tracked = false;
+
+                handle_resume_tracked_packets(&mut link.lock().unwrap(), clear_interval, &path)?;
+            }
+
             // Try to clear pending packets. At different levels down in `handle_packet_cmd` there
             // are retries mechanisms for MAX_RETRIES (current value hardcoded at 5).
             // If clearing fails after all these retries with ignorable error the task continues
@@ -171,6 +191,15 @@ pub fn spawn_incentivized_packet_cmd_worker<ChainA: ChainHandle, ChainB: ChainHa
             .build(),
     );
 
+    // This Cache will store the `ack_fee` queried for the packets of the WriteAcknowledgement
+    // events, or `None` if the packet is not incentivized, so that it is queried once per packet.
+    let incentivized_ack_cache: RwArc<Cache<Sequence, Option<Vec<RawCoin>>>> = RwArc::new_lock(
+        moka::sync::Cache::builder()
+            .time_to_live(INCENTIVIZED_CACHE_TTL)
+            .max_capacity(INCENTIVIZED_CACHE_MAX_CAPACITY)
+            .build(),
+    );
+
     spawn_background_task(span, Some(Duration::from_millis(200)), move || {
         if let Ok(cmd) = cmd_rx.try_recv() {
             handle_incentivized_packet_cmd(
@@ -178,6 +207,7 @@ pub fn spawn_incentivized_packet_cmd_worker<ChainA: ChainHandle, ChainB: ChainHa
                 &path,
                 cmd,
                 &incentivized_recv_cache,
+                &incentivized_ack_cache,
                 &fee_filter,
             )?;
         }
@@ -207,7 +237,7 @@ fn handle_packet_cmd<ChainA: ChainHandle, ChainB: ChainHandle>(
 ) -> Result<(), TaskError<RunError>> {
     // Handle packet clearing which is triggered from a command
     let (do_clear, maybe_height) = match &cmd {
-        WorkerCmd::IbcEvents { batch } if link.a_to_b.channel().ordering == Ordering::Ordered => {
+        WorkerCmd::IbcEvents { batch } if link.a_to_b.channel().ordering.is_ordered() => {
             let lowest_sequence = lowest_sequence(&batch.events);
 
             let next_sequence = query_next_sequence_receive(
@@ -281,6 +311,7 @@ fn handle_incentivized_packet_cmd<ChainA: ChainHandle, ChainB: ChainHandle>(
     path: &Packet,
     cmd: WorkerCmd,
     incentivized_recv_cache: &RwArc<Cache<Sequence, IncentivizedPacket>>,
+    incentivized_ack_cache: &RwArc<Cache<Sequence, Option<Vec<RawCoin>>>>,
     fee_filter: &FeePolicy,
 ) -> Result<(), TaskError<RunError>> {
     // Handle command-specific task
@@ -293,31 +324,38 @@ fn handle_incentivized_packet_cmd<ChainA: ChainHandle, ChainB: ChainHandle>(
                     .acquire_write()
                     .insert(packet.sequence, packet.clone());
             }
-            // It is not authorized to filter WriteAcknowledgement at the moment.
-            // This is because in order to filter them the worker would need to
-            // query the IncentivizedPacket events seen by the worker handling packets
-            // from ChainB to ChainA or it would need to query ChainA for incentivized
-            // packets.
-            // In addition if the WriteAcknowledgment are not relayed, no fees will be paid.
-            //IbcEvent::WriteAcknowledgement(ack) => get_incentivized_for_write_acknowledgement(link, ack, event.height.revision_height(), incentivized_ack_cache.clone()),
         }
-        filter_batch(batch.borrow_mut(), incentivized_recv_cache, fee_filter);
+        filter_batch(
+            link,
+            batch.borrow_mut(),
+            incentivized_recv_cache,
+            incentivized_ack_cache,
+            fee_filter,
+        );
         handle_update_schedule(link, 0, path, batch)
     } else {
         Ok(())
     }
 }
 
-/// It is only possible to filter incentivized packets using the `recv_fee` as criteria.
-/// This method is a helper which can be used if filtering using `ack_fee` is added.
-/// It queries the chain for incentivized packet information regarding the `WriteAcknowledgement`,
-/// and stores this information in a Read/Write Cache.
-fn _get_incentivized_for_write_acknowledgement<ChainA: ChainHandle, ChainB: ChainHandle>(
-    link: &mut Link<ChainA, ChainB>,
-    ack: WriteAcknowledgement,
-    height: u64,
-    incentivized_ack_cache: RwArc<Cache<Sequence, IdentifiedPacketFees>>,
-) {
+/// The packets acknowledged by this worker were sent by the counterparty chain,
+/// which is where their fees are escrowed.
+/// Queries the latest state of the counterparty chain for the `ack_fee`
+/// of the packet of the `WriteAcknowledgement`.
+///
+/// The result of a successful query is cached per sequence, so that the
+/// fees of a packet are only queried once.
+fn get_incentivized_for_write_acknowledgement<ChainA: ChainHandle, ChainB: ChainHandle>(
+    link: &Link<ChainA, ChainB>,
+    ack: &WriteAcknowledgement,
+    incentivized_ack_cache: &RwArc<Cache<Sequence, Option<Vec<RawCoin>>>>,
+) -> Option<Vec<RawCoin>> {
+    let sequence = ack.packet.sequence;
+
+    if let Some(fees) = incentivized_ack_cache.acquire_read().get(&sequence) {
+        return fees;
+    }
+
     let dst_chain = link.a_to_b.dst_chain();
 
     // Build PacketId required for the QueryIncentivizedPacketRequest
@@ -329,23 +367,26 @@ fn _get_incentivized_for_write_acknowledgement<ChainA: ChainHandle, ChainB: Chai
 
     let request = QueryIncentivizedPacketRequest {
         packet_id: Some(packet_id),
-        query_height: height,
+        query_height: 0,
     };
 
     match dst_chain.query_incentivized_packet(request) {
-        Ok(ev) => {
-            if let Some(identifier_packet_fees) = ev.incentivized_packet {
-                if let Some(packet) = &identifier_packet_fees.packet_id {
-                    incentivized_ack_cache
-                        .acquire_write()
-                        .insert(packet.sequence.into(), identifier_packet_fees);
-                }
-            }
+        Ok(response) => {
+            let fees = response
+                .incentivized_packet
+                .map(|packet_fees| retrieve_all_ack_fees_from_packet_fees(&packet_fees));
+
+            incentivized_ack_cache
+                .acquire_write()
+                .insert(sequence, fees.clone());
+
+            fees
         }
         // If the query failed it could mean that the packet is not incentivized.
         // The error is logged as debug.
         Err(e) => {
             debug!("Query for incentivized packet failed: {e}");
+            None
         }
     }
 }
@@ -353,34 +394,145 @@ fn _get_incentivized_for_write_acknowledgement<ChainA: ChainHandle, ChainB: Chai
 /// Using the configured FeesFilter and observed/queried information for
 /// incentivized packets, determine if the SendPacket and WriteAcknowledgement events
 /// should be relayed or not.
-fn filter_batch(
+///
+/// A `SendPacket` event for a packet which has already timed out on the destination
+/// chain is filtered on the `timeout_fee`, since relaying it means timing it out.
+///
+/// A `SendPacket` event for a packet which is not incentivized is only filtered out
+/// if the policy filters packets on their `recv_fee` or on the fee of the transaction.
+fn filter_batch<ChainA: ChainHandle, ChainB: ChainHandle>(
+    link: &Link<ChainA, ChainB>,
     batch: &mut EventBatch,
     incentivized_recv_cache: &RwArc<Cache<Sequence, IncentivizedPacket>>,
+    incentivized_ack_cache: &RwArc<Cache<Sequence, Option<Vec<RawCoin>>>>,
     fee_filter: &FeePolicy,
 ) {
+    let relay_path = &link.a_to_b;
+
+    let dst_status = OnceCell::new();
+    let timeout_tx_fee = OnceCell::new();
+    let recv_tx_fee = OnceCell::new();
+    let ack_tx_fee = OnceCell::new();
+
     batch.events.retain(|e| match &e.event {
         IbcEvent::SendPacket(packet) => incentivized_recv_cache
             .acquire_read()
             .get(&packet.packet.sequence)
-            .map_or(false, |incentivized_event| {
-                let grouped_amounts =
-                    retrieve_all_fees_from_incentivized_packet(incentivized_event);
-
-                fee_filter.should_relay(IbcEventType::SendPacket, &grouped_amounts)
+            .map_or(!fee_filter.filters_recvs(), |incentivized_event| {
+                let timed_out = dst_status
+                    .get_or_init(|| relay_path.dst_chain().query_application_status().ok())
+                    .as_ref()
+                    .map_or(false, |status: &ChainStatus| {
+                        packet.packet.timed_out(&status.timestamp, status.height)
+                    });
+
+                if timed_out {
+                    let fees = group_fees_by_denom(&incentivized_event.total_timeout_fee);
+
+                    fee_filter.should_relay(IbcEventType::Timeout, &fees)
+                        && covers_tx_fee(
+                            fee_filter,
+                            &fees,
+                            &timeout_tx_fee,
+                            relay_path.src_chain(),
+                            msgs::timeout::TYPE_URL,
+                        )
+                } else {
+                    let fees = retrieve_all_fees_from_incentivized_packet(incentivized_event);
+
+                    fee_filter.should_relay(IbcEventType::SendPacket, &fees)
+                        && covers_tx_fee(
+                            fee_filter,
+                            &fees,
+                            &recv_tx_fee,
+                            relay_path.dst_chain(),
+                            msgs::recv_packet::TYPE_URL,
+                        )
+                }
             }),
+        IbcEvent::WriteAcknowledgement(_) if !fee_filter.filters_acks() => true,
+        IbcEvent::WriteAcknowledgement(ack) => {
+            // Acknowledgements of packets which are not incentivized are always relayed
+            let ack_fees =
+                get_incentivized_for_write_acknowledgement(link, ack, incentivized_ack_cache);
+
+            ack_fees.map_or(true, |fees| {
+                fee_filter.should_relay(IbcEventType::WriteAck, &fees)
+                    && covers_tx_fee(
+                        fee_filter,
+                        &fees,
+                        &ack_tx_fee,
+                        relay_path.dst_chain(),
+                        msgs::acknowledgement::TYPE_URL,
+                    )
+            })
+        }
         _ => true,
     });
 }
 
+/// Whether the fees cover the fee of the transaction relaying the packet on the given chain
+/// with a message of the given type, if the policy requires it.
+///
+/// The fee of the transaction is estimated once per batch and type of message,
+/// so that it follows the gas used by the transactions and the gas price of the chain.
+fn covers_tx_fee<Chain: ChainHandle>(
+    fee_filter: &FeePolicy,
+    fees: &[RawCoin],
+    tx_fee: &OnceCell<Option<RawCoin>>,
+    chain: &Chain,
+    msg_type: &str,
+) -> bool {
+    if !fee_filter.has_gas_margin() {
+        return true;
+    }
+
+    // Do not filter the packet if the fee of the transaction cannot be estimated
+    tx_fee
+        .get_or_init(|| estimate_tx_fee(chain, msg_type))
+        .as_ref()
+        .map_or(true, |tx_fee| fee_filter.covers_tx_fee(fees, tx_fee))
+}
+
+/// Estimate the fee of a transaction with a single message of the given type on the given chain.
+fn estimate_tx_fee<Chain: ChainHandle>(chain: &Chain, msg_type: &str) -> Option<RawCoin> {
+    chain
+        .estimate_tx_fee(msg_type.to_string())
+        .unwrap_or_else(|e| {
+            warn!(
+                chain = %chain.id(),
+                "failed to estimate the fee of a transaction, will not filter on gas: {e}"
+            );
+            None
+        })
+}
+
 /// Multiple fees with different denoms can be specified as rewards,
 /// in an `IncentivizedPacket`. This method extract all and groups all
 /// the fees with the same denom.
 fn retrieve_all_fees_from_incentivized_packet(
     incentivized_packet: IncentivizedPacket,
 ) -> Vec<RawCoin> {
-    incentivized_packet
-        .total_recv_fee
+    group_fees_by_denom(&incentivized_packet.total_recv_fee)
+}
+
+/// Extracts the `ack_fee` of all the fees paid for a packet,
+/// grouped by denom.
+fn retrieve_all_ack_fees_from_packet_fees(packet_fees: &IdentifiedPacketFees) -> Vec<RawCoin> {
+    let ack_fees: Vec<RawCoin> = packet_fees
+        .packet_fees
         .iter()
+        .filter_map(|packet_fee| packet_fee.fee.as_ref())
+        .flat_map(|fee| fee.ack_fee.iter())
+        .filter_map(|coin| RawCoin::try_from(coin.clone()).ok())
+        .collect();
+
+    group_fees_by_denom(&ack_fees)
+}
+
+fn group_fees_by_denom(fees: &[RawCoin]) -> Vec<RawCoin> {
+    fees.iter()
+        .sorted_by(|a, b| a.denom.cmp(&b.denom))
         .group_by(|a| &a.denom)
         .into_iter()
         .map(|(key, group)| {
@@ -408,7 +560,7 @@ fn handle_update_schedule<ChainA: ChainHandle, ChainB: ChainHandle>(
 ) -> Result<(), TaskError<RunError>> {
     link.a_to_b
         .update_schedule(batch)
-        .map_err(handle_link_error_in_task)?;
+        .map_err(|e| handle_link_error_in_task(path, e))?;
 
     handle_execute_schedule(link, path, Resubmit::from_clear_interval(clear_interval))
 }
@@ -421,21 +573,37 @@ fn handle_clear_packet<ChainA: ChainHandle, ChainB: ChainHandle>(
 ) -> Result<(), TaskError<RunError>> {
     link.a_to_b
         .schedule_packet_clearing(height)
-        .map_err(handle_link_error_in_task)?;
+        .map_err(|e| handle_link_error_in_task(path, e))?;
+
+    handle_execute_schedule(link, path, Resubmit::from_clear_interval(clear_interval))
+}
+
+fn handle_resume_tracked_packets<ChainA: ChainHandle, ChainB: ChainHandle>(
+    link: &mut Link<ChainA, ChainB>,
+    clear_interval: u64,
+    path: &Packet,
+) -> Result<(), TaskError<RunError>> {
+    info!("resuming relaying of tracked packets");
+
+    link.a_to_b
+        .schedule_tracked_packets(TrackingId::new_packet_clearing())
+        .map_err(|e| handle_link_error_in_task(path, e))?;
 
     handle_execute_schedule(link, path, Resubmit::from_clear_interval(clear_interval))
 }
 
 fn handle_execute_schedule<ChainA: ChainHandle, ChainB: ChainHandle>(
     link: &mut Link<ChainA, ChainB>,
-    _path: &Packet,
+    path: &Packet,
     resubmit: Resubmit,
 ) -> Result<(), TaskError<RunError>> {
     link.a_to_b
         .refresh_schedule()
-        .map_err(handle_link_error_in_task)?;
+        .map_err(|e| handle_link_error_in_task(path, e))?;
 
     link.a_to_b.execute_schedule().map_err(|e| {
+        publish_worker_error(path, &e);
+
         if e.is_expired_or_frozen_error() {
             TaskError::Fatal(RunError::link(e))
         } else {
@@ -450,7 +618,7 @@ fn handle_execute_schedule<ChainA: ChainHandle, ChainB: ChainHandle>(
         trace!("produced relay summary: {:?}", summary);
 
         telemetry!(packet_metrics(
-            _path,
+            path,
             &summary,
             &link.a_to_b.path_id.counterparty_channel_id,
             &link.a_to_b.path_id.counterparty_port_id
@@ -1,51 +1,195 @@
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
-use tracing::{error_span, trace, warn};
+use tracing::{debug, error_span, trace, warn};
 
 use crate::{
-    chain::handle::ChainHandle,
+    chain::{handle::ChainHandle, requests::QueryGrantsRequest},
+    config::ChainConfig,
+    keyring::AnySigningKeyPair,
     telemetry,
     util::task::{spawn_background_task, Next, TaskError, TaskHandle},
 };
 
+/// How often the fee grants given to the wallets are checked
+const FEE_GRANTS_CHECK_INTERVAL: Duration = Duration::from_secs(60);
+
 pub fn spawn_wallet_worker<Chain: ChainHandle>(chain: Chain) -> TaskHandle {
     let span = error_span!("wallet", chain = %chain.id());
 
+    let mut last_grants_check: Option<Instant> = None;
+
     spawn_background_task(span, Some(Duration::from_secs(5)), move || {
-        let key = chain.get_key().map_err(|e| {
-            TaskError::Fatal(format!("failed to get key in use by the relayer: {e}"))
+        let keys = chain.get_keys().map_err(|e| {
+            TaskError::Fatal(format!("failed to get the keys in use by the relayer: {e}"))
         })?;
 
-        let balance = chain.query_balance(None, None).map_err(|e| {
-            TaskError::Ignore(format!("failed to query balance for the account: {e}"))
-        })?;
+        if last_grants_check.map_or(true, |last| last.elapsed() >= FEE_GRANTS_CHECK_INTERVAL) {
+            last_grants_check = Some(Instant::now());
+            check_fee_grants(&chain, &keys);
+        }
 
-        match balance.amount.parse::<f64>() {
-            Ok(amount) => {
-                telemetry!(
-                    wallet_balance,
-                    &chain.id(),
-                    &key.account(),
-                    amount,
-                    &balance.denom,
+        for (key_name, key) in keys {
+            let balance = match chain.query_balance(Some(key_name.clone()), None) {
+                Ok(balance) => balance,
+                Err(e) => {
+                    warn!("failed to query balance for the account of key '{key_name}': {e}");
+                    continue;
+                }
+            };
+
+            match balance.amount.parse::<f64>() {
+                Ok(amount) => {
+                    telemetry!(
+                        wallet_balance,
+                        &chain.id(),
+                        &key.account(),
+                        amount,
+                        &balance.denom,
+                    );
+                    trace!(%amount, denom = %balance.denom, account = %key.account(), "wallet balance");
+                    telemetry!(
+                        update_period_fees,
+                        &chain.id(),
+                        &key.account(),
+                        &balance.denom
+                    );
+                }
+                Err(e) => {
+                    warn!(
+                        %balance.amount, denom = %balance.denom, account = %key.account(),
+                        "unable to parse the wallet balance into a f64, the balance will therefore not be reported to telemetry. Reason: {}", e
+                    );
+                }
+            }
+        }
+
+        Ok(Next::Continue)
+    })
+}
+
+/// Checks that each wallet has been given a fee allowance by the fee granter of the chain,
+/// and that the primary wallet is authorized to renew them, warning about grants which are
+/// missing or about to expire.
+fn check_fee_grants<Chain: ChainHandle>(chain: &Chain, keys: &[(String, AnySigningKeyPair)]) {
+    let config = match chain.config() {
+        Ok(ChainConfig::CosmosSdk(config)) => config,
+        Ok(ChainConfig::Mock(_) | ChainConfig::SoloMachine(_)) => return,
+        Err(e) => {
+            warn!("failed to get the chain configuration: {e}");
+            return;
+        }
+    };
+
+    let Some(granter) = config.fee_granter.filter(|granter| !granter.is_empty()) else {
+        return;
+    };
+
+    for (index, (key_name, key)) in keys.iter().enumerate() {
+        let grantee = key.account();
+
+        if grantee == granter {
+            continue;
+        }
+
+        let grants = match chain.query_grants(QueryGrantsRequest {
+            granter: granter.clone(),
+            grantee: grantee.clone(),
+        }) {
+            Ok(grants) => grants,
+            Err(e) => {
+                warn!("failed to query the fee grants of the account of key '{key_name}': {e}");
+                continue;
+            }
+        };
+
+        match grants.fee_allowance {
+            Some(allowance) => check_expiration(
+                chain,
+                &granter,
+                &grantee,
+                "allowance",
+                allowance.expiration,
+                config.fee_grant_expiry_warning,
+            ),
+            None => {
+                warn!(
+                    %granter, %grantee,
+                    "the account of key '{key_name}' has no fee allowance, \
+                    its transactions will fail until one is granted with `hermes keys grant`"
                 );
-                trace!(%amount, denom = %balance.denom, account = %key.account(), "wallet balance");
                 telemetry!(
-                    update_period_fees,
+                    fee_grant_expiration,
                     &chain.id(),
-                    &key.account(),
-                    &balance.denom
+                    &granter,
+                    &grantee,
+                    "allowance",
+                    0
                 );
             }
-            Err(e) => {
-                warn!(
-                    %balance.amount, denom = %balance.denom, account = %key.account(),
-                    "unable to parse the wallet balance into a f64, the balance will therefore not be reported to telemetry. Reason: {}", e
+        }
+
+        // Only the primary wallet renews the allowances on behalf of the granter
+        if index != 0 {
+            continue;
+        }
+
+        match grants.authorization {
+            Some(authorization) => check_expiration(
+                chain,
+                &granter,
+                &grantee,
+                "authorization",
+                authorization.expiration,
+                config.fee_grant_expiry_warning,
+            ),
+            None => {
+                debug!(
+                    %granter, %grantee,
+                    "the account of key '{key_name}' is not authorized to renew the fee allowances"
+                );
+                telemetry!(
+                    fee_grant_expiration,
+                    &chain.id(),
+                    &granter,
+                    &grantee,
+                    "authorization",
+                    0
                 );
             }
         }
-        Ok(Next::Continue)
-    })
+    }
+}
+
+fn check_expiration<Chain: ChainHandle>(
+    chain: &Chain,
+    granter: &str,
+    grantee: &str,
+    kind: &str,
+    expiration: Option<tendermint::Time>,
+    warning_period: Duration,
+) {
+    let Some(expiration) = expiration else {
+        trace!(%granter, %grantee, "fee grant {kind} does not expire");
+        return;
+    };
+
+    telemetry!(
+        fee_grant_expiration,
+        &chain.id(),
+        granter,
+        grantee,
+        kind,
+        expiration.unix_timestamp().max(0) as u64,
+    );
+
+    match expiration.duration_since(tendermint::Time::now()) {
+        Err(_) => warn!(%granter, %grantee, "fee grant {kind} expired at {expiration}"),
+        Ok(remaining) if remaining <= warning_period => warn!(
+            %granter, %grantee,
+            "fee grant {kind} expires at {expiration}, renew it with `hermes keys grant`"
+        ),
+        Ok(_) => trace!(%granter, %grantee, "fee grant {kind} expires at {expiration}"),
+    }
 }
 
 #[cfg(test)]
@@ -1,5 +1,7 @@
 pub mod broadcast_error;
 pub mod encoder;
+#[cfg(feature = "otlp")]
+pub mod otlp;
 mod path_identifier;
 pub mod server;
 pub mod state;
@@ -50,6 +52,30 @@ pub fn init(
     GLOBAL_STATE.get().unwrap()
 }
 
+/// Same as [`init`], but the metrics are also pushed to an
+/// OpenTelemetry collector over OTLP.
+#[cfg(feature = "otlp")]
+pub fn init_with_otlp(
+    tx_latency_submitted_range: Range<u64>,
+    tx_latency_submitted_buckets: u64,
+    tx_latency_confirmed_range: Range<u64>,
+    tx_latency_confirmed_buckets: u64,
+    settings: &otlp::OtlpSettings,
+) -> Result<&'static Arc<TelemetryState>, opentelemetry::metrics::MetricsError> {
+    let new_state = TelemetryState::new_with_otlp(
+        tx_latency_submitted_range,
+        tx_latency_submitted_buckets,
+        tx_latency_confirmed_range,
+        tx_latency_confirmed_buckets,
+        settings,
+    )?;
+    match GLOBAL_STATE.set(Arc::new(new_state)) {
+        Ok(_) => debug!("initialised telemetry global state with OTLP export"),
+        Err(_) => debug!("telemetry global state was already set"),
+    }
+    Ok(GLOBAL_STATE.get().unwrap())
+}
+
 pub fn global() -> &'static Arc<TelemetryState> {
     match GLOBAL_STATE.get() {
         Some(state) => state,
@@ -0,0 +1,111 @@
+//! Export of traces and metrics to an OpenTelemetry collector over OTLP (gRPC).
+//!
+//! The exporters run on a dedicated Tokio runtime, so that they can be
+//! installed from synchronous code, eg. before Hermes starts any runtime.
+
+use core::time::Duration;
+
+use once_cell::sync::OnceCell;
+use opentelemetry::metrics::MetricsError;
+use opentelemetry::sdk::export::metrics::aggregation;
+use opentelemetry::sdk::metrics::controllers::BasicController;
+use opentelemetry::sdk::{trace, Resource};
+use opentelemetry::trace::TraceError;
+use opentelemetry::{runtime, KeyValue};
+use opentelemetry_otlp::WithExportConfig;
+use tokio::runtime::Runtime;
+use tracing::Subscriber;
+use tracing_opentelemetry::OpenTelemetryLayer;
+use tracing_subscriber::registry::LookupSpan;
+
+use crate::state::CustomAggregatorSelector;
+
+/// The maximum time to wait for the collector to acknowledge an export.
+const EXPORT_TIMEOUT: Duration = Duration::from_secs(10);
+
+static RUNTIME: OnceCell<Runtime> = OnceCell::new();
+
+/// Where and how often to export traces and metrics.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct OtlpSettings {
+    /// The gRPC endpoint of the collector, eg. `http://127.0.0.1:4317`.
+    pub endpoint: String,
+    /// The `service.name` reported to the collector.
+    pub service_name: String,
+    /// How often the metrics are exported.
+    pub export_interval: Duration,
+}
+
+fn runtime() -> &'static Runtime {
+    RUNTIME.get_or_init(|| {
+        tokio::runtime::Builder::new_multi_thread()
+            .worker_threads(1)
+            .thread_name("otlp-exporter")
+            .enable_all()
+            .build()
+            .expect("failed to build the OTLP exporter runtime")
+    })
+}
+
+fn resource(settings: &OtlpSettings) -> Resource {
+    Resource::new([KeyValue::new("service.name", settings.service_name.clone())])
+}
+
+fn exporter(settings: &OtlpSettings) -> opentelemetry_otlp::TonicExporterBuilder {
+    opentelemetry_otlp::new_exporter()
+        .tonic()
+        .with_endpoint(settings.endpoint.clone())
+        .with_timeout(EXPORT_TIMEOUT)
+}
+
+/// Install a global tracer exporting spans in batches to the collector.
+pub fn tracer(settings: &OtlpSettings) -> Result<trace::Tracer, TraceError> {
+    let _guard = runtime().enter();
+
+    opentelemetry_otlp::new_pipeline()
+        .tracing()
+        .with_exporter(exporter(settings))
+        .with_trace_config(trace::config().with_resource(resource(settings)))
+        .install_batch(runtime::Tokio)
+}
+
+/// Build a [`tracing`] layer exporting the spans to the collector.
+///
+/// The fields of the spans, eg. the `tracking_id` of the operational data
+/// being relayed, are exported as span attributes.
+pub fn layer<S>(settings: &OtlpSettings) -> Result<OpenTelemetryLayer<S, trace::Tracer>, TraceError>
+where
+    S: Subscriber + for<'span> LookupSpan<'span>,
+{
+    let tracer = tracer(settings)?;
+
+    Ok(tracing_opentelemetry::layer().with_tracer(tracer))
+}
+
+/// Build and start a metrics controller which pushes the metrics
+/// to the collector at the configured interval.
+///
+/// The controller is shared with the Prometheus exporter, so that
+/// both export the same metrics.
+pub(crate) fn metrics_controller(
+    aggregator_selector: CustomAggregatorSelector,
+    settings: &OtlpSettings,
+) -> Result<BasicController, MetricsError> {
+    let _guard = runtime().enter();
+
+    opentelemetry_otlp::new_pipeline()
+        .metrics(
+            aggregator_selector,
+            aggregation::cumulative_temporality_selector(),
+            runtime::Tokio,
+        )
+        .with_exporter(exporter(settings))
+        .with_period(settings.export_interval)
+        .with_resource(resource(settings))
+        .build()
+}
+
+/// Flush the spans which were not exported yet, and shut down the tracer.
+pub fn shutdown() {
+    opentelemetry::global::shutdown_tracer_provider();
+}
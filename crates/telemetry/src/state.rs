@@ -28,6 +28,15 @@ const EMPTY_BACKLOG_SYMBOL: u64 = 0;
 const BACKLOG_CAPACITY: usize = 1000;
 const BACKLOG_RESET_THRESHOLD: usize = 900;
 
+/// Buckets of the `packet_*_latency` histograms, in milliseconds.
+const PACKET_LATENCY_BUCKETS: [f64; 11] = [
+    1000.0, 2500.0, 5000.0, 10000.0, 15000.0, 20000.0, 30000.0, 60000.0, 120000.0, 300000.0,
+    600000.0,
+];
+
+/// Buckets of the `gas_estimate_error` histogram, in percent.
+const GAS_ESTIMATE_ERROR_BUCKETS: [f64; 8] = [1.0, 2.0, 5.0, 10.0, 20.0, 30.0, 50.0, 100.0];
+
 const QUERY_TYPES_CACHE: [&str; 4] = [
     "query_latest_height",
     "query_client_state",
@@ -135,6 +144,9 @@ pub struct TelemetryState {
     /// The balance of each wallet Hermes uses per chain
     wallet_balance: ObservableGauge<f64>,
 
+    /// The expiration time of the fee grants given to each wallet Hermes uses per chain
+    fee_grant_expiration: ObservableGauge<u64>,
+
     /// Indicates the latency for all transactions submitted to a specific chain,
     /// i.e. the difference between the moment when Hermes received a batch of events
     /// until the corresponding transaction(s) were submitted. Milliseconds.
@@ -149,6 +161,16 @@ pub struct TelemetryState {
     /// Used for computing the `tx_latency` metric.
     in_flight_events: moka::sync::Cache<String, Instant>,
 
+    /// Indicates the end-to-end latency for relaying a packet, i.e. the difference between
+    /// the time of the block which included the SendPacket event and the time of the block
+    /// which committed the corresponding RecvPacket message. Milliseconds.
+    packet_recv_latency: ObservableGauge<u64>,
+
+    /// Indicates the end-to-end latency for relaying an acknowledgement, i.e. the difference
+    /// between the time of the block which included the WriteAcknowledgement event and the
+    /// time of the block which committed the corresponding Acknowledgement message. Milliseconds.
+    packet_ack_latency: ObservableGauge<u64>,
+
     /// Number of SendPacket events received
     send_packet_events: Counter<u64>,
 
@@ -177,6 +199,12 @@ pub struct TelemetryState {
     /// Records the length of the backlog, i.e., how many packets are pending.
     backlog_size: ObservableGauge<u64>,
 
+    /// Records the local timestamp at which the oldest pending SendPacket event was observed,
+    /// such that the age of the oldest unrelayed packet is the time elapsed since then.
+    /// The timestamp is the time passed since the unix epoch in seconds.
+    /// The value is 0 if all the SendPacket events were relayed.
+    backlog_oldest_timestamp: ObservableGauge<u64>,
+
     /// Stores the backlogs for all the paths the relayer is active on.
     /// This is a map of multiple inner backlogs, one inner backlog per path.
     ///
@@ -198,6 +226,15 @@ pub struct TelemetryState {
     /// Sum of rewarded fees over the past FEE_LIFETIME seconds
     period_fees: ObservableGauge<u64>,
 
+    /// Total amount of ICS29 fees rewarded to Hermes for relaying the packets sent on a channel
+    channel_fees_collected: Counter<u64>,
+
+    /// Total amount of transaction fees paid by Hermes for relaying the packets sent on a channel
+    channel_gas_paid: Counter<u64>,
+
+    /// ICS29 fees collected minus the transaction fees paid for relaying the packets sent on a channel
+    channel_net_profit: ObservableGauge<f64>,
+
     /// Number of errors observed by Hermes when broadcasting a Tx
     broadcast_errors: Counter<u64>,
 
@@ -213,8 +250,27 @@ pub struct TelemetryState {
     /// The EIP-1559 base fee successfully queried
     dynamic_gas_queried_success_fees: ObservableGauge<f64>,
 
-    /// Number of ICS-20 packets filtered because the memo and/or the receiver fields were exceeding the configured limits
+    /// Number of ICS-20 packets filtered because the memo and/or the receiver fields were exceeding the configured limits,
+    /// or because their data did not pass the configured ICS-20 packet filter
     filtered_packets: Counter<u64>,
+
+    /// Number of headers cross-checked against each witness of a chain
+    witness_checks: Counter<u64>,
+
+    /// Number of divergences detected between the headers of a chain and those of its witnesses
+    witness_divergences: Counter<u64>,
+
+    /// Whether an endpoint of a chain is the one in use (1) or not (0), per kind of endpoint
+    active_endpoint: ObservableGauge<u64>,
+
+    /// Number of pipelined Txs dropped from the mempool before being committed
+    dropped_txs: Counter<u64>,
+
+    /// Number of Txs whose gas was estimated, per source of the estimate
+    gas_estimates: Counter<u64>,
+
+    /// The relative difference between the gas estimated for a Tx and the gas it actually used. Percent.
+    gas_estimate_error: ObservableGauge<u64>,
 }
 
 impl TelemetryState {
@@ -238,6 +294,35 @@ impl TelemetryState {
         ))
         .build();
 
+        Self::with_controller(controller)
+    }
+
+    /// Same as [`TelemetryState::new`], but the metrics are also pushed
+    /// to an OpenTelemetry collector over OTLP.
+    #[cfg(feature = "otlp")]
+    pub fn new_with_otlp(
+        tx_latency_submitted_range: Range<u64>,
+        tx_latency_submitted_buckets: u64,
+        tx_latency_confirmed_range: Range<u64>,
+        tx_latency_confirmed_buckets: u64,
+        settings: &crate::otlp::OtlpSettings,
+    ) -> Result<Self, opentelemetry::metrics::MetricsError> {
+        let controller = crate::otlp::metrics_controller(
+            CustomAggregatorSelector::new(
+                tx_latency_submitted_range,
+                tx_latency_submitted_buckets,
+                tx_latency_confirmed_range,
+                tx_latency_confirmed_buckets,
+            ),
+            settings,
+        )?;
+
+        Ok(Self::with_controller(controller))
+    }
+
+    fn with_controller(
+        controller: opentelemetry::sdk::metrics::controllers::BasicController,
+    ) -> Self {
         let exporter = opentelemetry_prometheus::ExporterBuilder::new(controller).init();
 
         let meter = global::meter("hermes");
@@ -312,6 +397,11 @@ impl TelemetryState {
                 .with_description("The balance of each wallet Hermes uses per chain. Please note that when converting the balance to f64 a loss in precision might be introduced in the displayed value")
                 .init(),
 
+            fee_grant_expiration: meter
+                .u64_observable_gauge("fee_grant_expiration")
+                .with_description("The UNIX timestamp at which the fee grants given to each wallet Hermes uses per chain expire, 0 if the grant is missing")
+                .init(),
+
             send_packet_events: meter
                 .u64_counter("send_packet_events")
                 .with_description("Number of SendPacket events received")
@@ -353,6 +443,22 @@ impl TelemetryState {
                     until the corresponding transaction(s) were confirmed. Milliseconds.")
                 .init(),
 
+            packet_recv_latency: meter
+                .u64_observable_gauge("packet_recv_latency")
+                .with_unit(Unit::new("milliseconds"))
+                .with_description("The end-to-end latency for relaying a packet, \
+                    i.e. the difference between the time of the block which included the SendPacket event \
+                    and the time of the block which committed the corresponding RecvPacket message. Milliseconds.")
+                .init(),
+
+            packet_ack_latency: meter
+                .u64_observable_gauge("packet_ack_latency")
+                .with_unit(Unit::new("milliseconds"))
+                .with_description("The end-to-end latency for relaying an acknowledgement, \
+                    i.e. the difference between the time of the block which included the WriteAcknowledgement event \
+                    and the time of the block which committed the corresponding Acknowledgement message. Milliseconds.")
+                .init(),
+
             in_flight_events: moka::sync::Cache::builder()
                 .time_to_live(Duration::from_secs(60 * 60)) // Remove entries after 1 hour
                 .time_to_idle(Duration::from_secs(30 * 60)) // Remove entries if they have been idle for 30 minutes
@@ -376,6 +482,12 @@ impl TelemetryState {
                 .with_description("Total number of SendPacket events in the backlog")
                 .init(),
 
+            backlog_oldest_timestamp: meter
+                .u64_observable_gauge("backlog_oldest_timestamp")
+                .with_unit(Unit::new("seconds"))
+                .with_description("Local timestamp at which the oldest SendPacket event in the backlog was observed")
+                .init(),
+
             fee_amounts: meter
                 .u64_counter("ics29_fee_amounts")
                 .with_description("Total amount received from ICS29 fees")
@@ -390,6 +502,21 @@ impl TelemetryState {
                 .with_description("Amount of ICS29 fees rewarded over the past 7 days")
                 .init(),
 
+            channel_fees_collected: meter
+                .u64_counter("channel_fees_collected")
+                .with_description("Total amount of ICS29 fees rewarded to Hermes for relaying the packets sent on a channel")
+                .init(),
+
+            channel_gas_paid: meter
+                .u64_counter("channel_gas_paid")
+                .with_description("Total amount of transaction fees paid by Hermes for relaying the packets sent on a channel")
+                .init(),
+
+            channel_net_profit: meter
+                .f64_observable_gauge("channel_net_profit")
+                .with_description("ICS29 fees collected minus the transaction fees paid for relaying the packets sent on a channel")
+                .init(),
+
             broadcast_errors: meter
                 .u64_counter("broadcast_errors")
                 .with_description(
@@ -421,7 +548,38 @@ impl TelemetryState {
 
             filtered_packets: meter
                 .u64_counter("filtered_packets")
-                .with_description("Number of ICS-20 packets filtered because the memo and/or the receiver fields were exceeding the configured limits")
+                .with_description("Number of ICS-20 packets filtered because the memo and/or the receiver fields were exceeding the configured limits, or because their data did not pass the configured ICS-20 packet filter")
+                .init(),
+
+            witness_checks: meter
+                .u64_counter("witness_checks")
+                .with_description("Number of headers cross-checked against each witness of a chain")
+                .init(),
+
+            witness_divergences: meter
+                .u64_counter("witness_divergences")
+                .with_description("Number of divergences detected between the headers of a chain and those of its witnesses")
+                .init(),
+
+            active_endpoint: meter
+                .u64_observable_gauge("active_endpoint")
+                .with_description("Whether an endpoint of a chain is the one in use (1) or not (0), per kind of endpoint")
+                .init(),
+
+            dropped_txs: meter
+                .u64_counter("dropped_txs")
+                .with_description("Number of pipelined Txs dropped from the mempool before being committed")
+                .init(),
+
+            gas_estimates: meter
+                .u64_counter("gas_estimates")
+                .with_description("Number of Txs whose gas was estimated, per source of the estimate")
+                .init(),
+
+            gas_estimate_error: meter
+                .u64_observable_gauge("gas_estimate_error")
+                .with_unit(Unit::new("percent"))
+                .with_description("The relative difference between the gas estimated for a Tx and the gas it actually used. Percent.")
                 .init(),
         }
     }
@@ -501,6 +659,7 @@ impl TelemetryState {
         self.backlog_oldest_sequence.observe(&cx, 0, labels);
         self.backlog_latest_update_timestamp.observe(&cx, 0, labels);
         self.backlog_size.observe(&cx, 0, labels);
+        self.backlog_oldest_timestamp.observe(&cx, 0, labels);
     }
 
     pub fn init_per_client(
@@ -762,6 +921,29 @@ impl TelemetryState {
         self.wallet_balance.observe(&cx, amount, labels);
     }
 
+    /// The UNIX timestamp at which a grant given by the fee granter to a wallet expires,
+    /// per chain, granter, grantee and kind of grant, i.e. `allowance` or `authorization`.
+    /// A value of 0 indicates that the grant is missing.
+    pub fn fee_grant_expiration(
+        &self,
+        chain_id: &ChainId,
+        granter: &str,
+        grantee: &str,
+        kind: &str,
+        expiration: u64,
+    ) {
+        let cx = Context::current();
+
+        let labels = &[
+            KeyValue::new("chain", chain_id.to_string()),
+            KeyValue::new("granter", granter.to_string()),
+            KeyValue::new("grantee", grantee.to_string()),
+            KeyValue::new("kind", kind.to_string()),
+        ];
+
+        self.fee_grant_expiration.observe(&cx, expiration, labels);
+    }
+
     pub fn received_event_batch(&self, tracking_id: impl ToString) {
         self.in_flight_events
             .insert(tracking_id.to_string(), Instant::now());
@@ -827,6 +1009,55 @@ impl TelemetryState {
         }
     }
 
+    /// Record the end-to-end latency for relaying a packet, from the time of the block
+    /// which included the SendPacket event on the given chain, to the time of the block
+    /// which committed the RecvPacket message on the counterparty chain.
+    pub fn packet_recv_latency(
+        &self,
+        latency: Duration,
+        chain_id: &ChainId,
+        channel_id: &ChannelId,
+        port_id: &PortId,
+        counterparty_chain_id: &ChainId,
+    ) {
+        let cx = Context::current();
+
+        let labels = &[
+            KeyValue::new("chain", chain_id.to_string()),
+            KeyValue::new("counterparty", counterparty_chain_id.to_string()),
+            KeyValue::new("channel", channel_id.to_string()),
+            KeyValue::new("port", port_id.to_string()),
+        ];
+
+        self.packet_recv_latency
+            .observe(&cx, latency.as_millis() as u64, labels);
+    }
+
+    /// Record the end-to-end latency for relaying the acknowledgement of a packet sent on
+    /// the given chain, from the time of the block which included the WriteAcknowledgement
+    /// event on the counterparty chain, to the time of the block which committed the
+    /// Acknowledgement message on the given chain.
+    pub fn packet_ack_latency(
+        &self,
+        latency: Duration,
+        chain_id: &ChainId,
+        channel_id: &ChannelId,
+        port_id: &PortId,
+        counterparty_chain_id: &ChainId,
+    ) {
+        let cx = Context::current();
+
+        let labels = &[
+            KeyValue::new("chain", chain_id.to_string()),
+            KeyValue::new("counterparty", counterparty_chain_id.to_string()),
+            KeyValue::new("channel", channel_id.to_string()),
+            KeyValue::new("port", port_id.to_string()),
+        ];
+
+        self.packet_ack_latency
+            .observe(&cx, latency.as_millis() as u64, labels);
+    }
+
     pub fn send_packet_events(
         &self,
         _seq_nr: u64,
@@ -964,7 +1195,8 @@ impl TelemetryState {
         };
 
         // Update the backlog with the incoming data and retrieve the oldest values
-        let (oldest_sn, total) = if let Some(path_backlog) = self.backlogs.get(&path_uid) {
+        let (oldest_sn, oldest_ts, total) = if let Some(path_backlog) = self.backlogs.get(&path_uid)
+        {
             // Avoid having the inner backlog map growing more than a given threshold, by removing
             // the oldest sequence number entry.
             if path_backlog.len() > BACKLOG_RESET_THRESHOLD {
@@ -976,11 +1208,16 @@ impl TelemetryState {
 
             // Return the oldest event information to be recorded in telemetry
             if let Some(min) = path_backlog.iter().map(|v| *v.key()).min() {
-                (min, path_backlog.len() as u64)
+                let oldest_ts = oldest_timestamp(&path_backlog);
+                (min, oldest_ts, path_backlog.len() as u64)
             } else {
                 // We just inserted a new key/value, so this else branch is unlikely to activate,
                 // but it can happen in case of concurrent updates to the backlog.
-                (EMPTY_BACKLOG_SYMBOL, EMPTY_BACKLOG_SYMBOL)
+                (
+                    EMPTY_BACKLOG_SYMBOL,
+                    EMPTY_BACKLOG_SYMBOL,
+                    EMPTY_BACKLOG_SYMBOL,
+                )
             }
         } else {
             // If there is no inner backlog for this path, create a new map to store it.
@@ -990,7 +1227,7 @@ impl TelemetryState {
             self.backlogs.insert(path_uid, new_path_backlog);
 
             // Return the current event information to be recorded in telemetry
-            (seq_nr, 1)
+            (seq_nr, timestamp, 1)
         };
 
         // Update metrics to reflect the new state of the backlog
@@ -998,6 +1235,8 @@ impl TelemetryState {
         self.backlog_latest_update_timestamp
             .observe(&cx, timestamp, labels);
         self.backlog_size.observe(&cx, total, labels);
+        self.backlog_oldest_timestamp
+            .observe(&cx, oldest_ts, labels);
     }
 
     /// Inserts in the backlog a new event for the given sequence number.
@@ -1086,11 +1325,18 @@ impl TelemetryState {
                     self.backlog_oldest_sequence.observe(&cx, min_key, labels);
                     self.backlog_size
                         .observe(&cx, path_backlog.len() as u64, labels);
+                    self.backlog_oldest_timestamp.observe(
+                        &cx,
+                        oldest_timestamp(&path_backlog),
+                        labels,
+                    );
                 } else {
                     // No minimum found, update the metrics to reflect an empty backlog
                     self.backlog_oldest_sequence
                         .observe(&cx, EMPTY_BACKLOG_SYMBOL, labels);
                     self.backlog_size.observe(&cx, EMPTY_BACKLOG_SYMBOL, labels);
+                    self.backlog_oldest_timestamp
+                        .observe(&cx, EMPTY_BACKLOG_SYMBOL, labels);
                 }
             }
         }
@@ -1149,6 +1395,85 @@ impl TelemetryState {
         self.period_fees.observe(&cx, sum, labels);
     }
 
+    /// Record the ICS29 fees rewarded to Hermes for relaying a packet sent on the given chain,
+    /// which are paid on that chain.
+    pub fn channel_fees_collected(
+        &self,
+        chain_id: &ChainId,
+        channel_id: &ChannelId,
+        port_id: &PortId,
+        counterparty_chain_id: &ChainId,
+        fee: &Coin<String>,
+    ) {
+        let cx = Context::current();
+
+        let labels = &[
+            KeyValue::new("chain", chain_id.to_string()),
+            KeyValue::new("counterparty", counterparty_chain_id.to_string()),
+            KeyValue::new("channel", channel_id.to_string()),
+            KeyValue::new("port", port_id.to_string()),
+            KeyValue::new("fee_chain", chain_id.to_string()),
+            KeyValue::new("denom", fee.denom.to_string()),
+        ];
+
+        self.channel_fees_collected
+            .add(&cx, fee.amount.0.as_u64(), labels);
+    }
+
+    /// Record the transaction fees paid on `fee_chain_id` by Hermes for relaying
+    /// packets sent on the given chain, or their acknowledgements or timeouts.
+    pub fn channel_gas_paid(
+        &self,
+        chain_id: &ChainId,
+        channel_id: &ChannelId,
+        port_id: &PortId,
+        counterparty_chain_id: &ChainId,
+        fee_chain_id: &ChainId,
+        fee: &Coin<String>,
+    ) {
+        let cx = Context::current();
+
+        let labels = &[
+            KeyValue::new("chain", chain_id.to_string()),
+            KeyValue::new("counterparty", counterparty_chain_id.to_string()),
+            KeyValue::new("channel", channel_id.to_string()),
+            KeyValue::new("port", port_id.to_string()),
+            KeyValue::new("fee_chain", fee_chain_id.to_string()),
+            KeyValue::new("denom", fee.denom.to_string()),
+        ];
+
+        self.channel_gas_paid
+            .add(&cx, fee.amount.0.as_u64(), labels);
+    }
+
+    /// The ICS29 fees collected minus the transaction fees paid, in the given denomination
+    /// on `fee_chain_id`, for relaying the packets sent on the given chain.
+    /// Negative if relaying on the channel costs more than it earns.
+    #[allow(clippy::too_many_arguments)]
+    pub fn channel_net_profit(
+        &self,
+        chain_id: &ChainId,
+        channel_id: &ChannelId,
+        port_id: &PortId,
+        counterparty_chain_id: &ChainId,
+        fee_chain_id: &ChainId,
+        denom: &str,
+        net_profit: f64,
+    ) {
+        let cx = Context::current();
+
+        let labels = &[
+            KeyValue::new("chain", chain_id.to_string()),
+            KeyValue::new("counterparty", counterparty_chain_id.to_string()),
+            KeyValue::new("channel", channel_id.to_string()),
+            KeyValue::new("port", port_id.to_string()),
+            KeyValue::new("fee_chain", fee_chain_id.to_string()),
+            KeyValue::new("denom", denom.to_string()),
+        ];
+
+        self.channel_net_profit.observe(&cx, net_profit, labels);
+    }
+
     // Add an address to the list of addresses which will record
     // the rewarded fees from ICS29.
     pub fn add_visible_fee_address(&self, address: String) {
@@ -1209,7 +1534,8 @@ impl TelemetryState {
             .observe(&cx, amount, labels);
     }
 
-    /// Increment number of packets filtered because the memo field is too big
+    /// Increment number of packets filtered because the memo field is too big,
+    /// or because their data did not pass the ICS-20 packet filter
     #[allow(clippy::too_many_arguments)]
     pub fn filtered_packets(
         &self,
@@ -1236,6 +1562,92 @@ impl TelemetryState {
             self.filtered_packets.add(&cx, count, labels);
         }
     }
+
+    /// Increment the number of headers cross-checked against the given witness
+    pub fn witness_checks(&self, chain_id: &ChainId, witness: &str) {
+        let cx = Context::current();
+
+        let labels = &[
+            KeyValue::new("chain", chain_id.to_string()),
+            KeyValue::new("witness", witness.to_string()),
+        ];
+
+        self.witness_checks.add(&cx, 1, labels);
+    }
+
+    /// Increment the number of divergences detected against the given witness
+    pub fn witness_divergences(&self, chain_id: &ChainId, witness: &str) {
+        let cx = Context::current();
+
+        let labels = &[
+            KeyValue::new("chain", chain_id.to_string()),
+            KeyValue::new("witness", witness.to_string()),
+        ];
+
+        self.witness_divergences.add(&cx, 1, labels);
+    }
+
+    /// Record whether the given endpoint of a chain is the one in use,
+    /// where `kind` is either `rpc`, `grpc` or `websocket`.
+    pub fn active_endpoint(&self, chain_id: &ChainId, kind: &str, url: &str, active: bool) {
+        let cx = Context::current();
+
+        let labels = &[
+            KeyValue::new("chain", chain_id.to_string()),
+            KeyValue::new("kind", kind.to_string()),
+            KeyValue::new("url", url.to_string()),
+        ];
+
+        self.active_endpoint.observe(&cx, u64::from(active), labels);
+    }
+
+    /// Add to the number of pipelined Txs broadcast by the given account which were
+    /// dropped from the mempool, eg. evicted or invalidated by a failed CheckTx.
+    pub fn dropped_txs(&self, chain_id: &ChainId, address: &String, count: u64) {
+        let cx = Context::current();
+
+        let labels = &[
+            KeyValue::new("chain", chain_id.to_string()),
+            KeyValue::new("account", address.to_string()),
+        ];
+
+        self.dropped_txs.add(&cx, count, labels);
+    }
+
+    /// Count a Tx whose gas was estimated from the given source,
+    /// ie. `simulation`, `model` or `default`.
+    pub fn gas_estimates(&self, chain_id: &ChainId, source: &str) {
+        let cx = Context::current();
+
+        let labels = &[
+            KeyValue::new("chain", chain_id.to_string()),
+            KeyValue::new("source", source.to_string()),
+        ];
+
+        self.gas_estimates.add(&cx, 1, labels);
+    }
+
+    /// Record the relative difference, in percent, between the gas estimated
+    /// for a Tx from the given source and the gas it actually used.
+    pub fn gas_estimate_error(&self, chain_id: &ChainId, source: &str, error: u64) {
+        let cx = Context::current();
+
+        let labels = &[
+            KeyValue::new("chain", chain_id.to_string()),
+            KeyValue::new("source", source.to_string()),
+        ];
+
+        self.gas_estimate_error.observe(&cx, error, labels);
+    }
+}
+
+/// The timestamp at which the oldest SendPacket event in the given backlog was observed.
+fn oldest_timestamp(backlog: &DashMap<u64, u64>) -> u64 {
+    backlog
+        .iter()
+        .map(|v| *v.value())
+        .min()
+        .unwrap_or(EMPTY_BACKLOG_SYMBOL)
 }
 
 use std::sync::Arc;
@@ -1247,7 +1659,7 @@ use opentelemetry::sdk::metrics::aggregators::{histogram, last_value, sum};
 use opentelemetry::sdk::metrics::sdk_api::Descriptor;
 
 #[derive(Debug)]
-struct CustomAggregatorSelector {
+pub(crate) struct CustomAggregatorSelector {
     tx_latency_submitted_range: Range<u64>,
     tx_latency_submitted_buckets: u64,
     tx_latency_confirmed_range: Range<u64>,
@@ -1297,14 +1709,19 @@ impl AggregatorSelector for CustomAggregatorSelector {
     fn aggregator_for(&self, descriptor: &Descriptor) -> Option<Arc<dyn Aggregator + Send + Sync>> {
         match descriptor.name() {
             "wallet_balance" => Some(Arc::new(last_value())),
+            "fee_grant_expiration" => Some(Arc::new(last_value())),
             "backlog_oldest_sequence" => Some(Arc::new(last_value())),
             "backlog_latest_update_timestamp" => Some(Arc::new(last_value())),
             "backlog_size" => Some(Arc::new(last_value())),
+            "backlog_oldest_timestamp" => Some(Arc::new(last_value())),
             // Prometheus' supports only collector for histogram, sum, and last value aggregators.
             // https://docs.rs/opentelemetry-prometheus/0.10.0/src/opentelemetry_prometheus/lib.rs.html#411-418
             // TODO: Once quantile sketches are supported, replace histograms with that.
             "tx_latency_submitted" => Some(Arc::new(histogram(&self.get_submitted_range()))),
             "tx_latency_confirmed" => Some(Arc::new(histogram(&self.get_confirmed_range()))),
+            "packet_recv_latency" => Some(Arc::new(histogram(&PACKET_LATENCY_BUCKETS))),
+            "packet_ack_latency" => Some(Arc::new(histogram(&PACKET_LATENCY_BUCKETS))),
+            "gas_estimate_error" => Some(Arc::new(histogram(&GAS_ESTIMATE_ERROR_BUCKETS))),
             "dynamic_gas_queried_fees" => Some(Arc::new(histogram(&[
                 0.0025, 0.005, 0.01, 0.05, 0.1, 0.5, 1.0, 5.0,
             ]))),
@@ -1315,6 +1732,8 @@ impl AggregatorSelector for CustomAggregatorSelector {
                 0.0025, 0.005, 0.01, 0.05, 0.1, 0.5, 1.0, 5.0,
             ]))),
             "ics29_period_fees" => Some(Arc::new(last_value())),
+            "channel_net_profit" => Some(Arc::new(last_value())),
+            "active_endpoint" => Some(Arc::new(last_value())),
             _ => Some(Arc::new(sum())),
         }
     }
@@ -1477,6 +1896,196 @@ mod tests {
             assert_metric_value(backlog_oldest_sequence.get_metric(), 0),
             "expected backlog_oldest_sequence to be 0"
         );
+        let backlog_oldest_timestamp = metrics
+            .iter()
+            .find(|&metric| metric.get_name() == "backlog_oldest_timestamp")
+            .unwrap();
+        assert!(
+            assert_metric_value(backlog_oldest_timestamp.get_metric(), 0),
+            "expected backlog_oldest_timestamp to be 0"
+        );
+    }
+
+    #[test]
+    fn backlog_oldest_timestamp() {
+        let state = TelemetryState::new(
+            Range {
+                start: 0,
+                end: 5000,
+            },
+            5,
+            Range {
+                start: 0,
+                end: 5000,
+            },
+            5,
+        );
+
+        let chain_id = ChainId::from_string("chain-test");
+        let counterparty_chain_id = ChainId::from_string("counterpartychain-test");
+        let channel_id = ChannelId::new(0);
+        let port_id = PortId::transfer();
+
+        state.backlog_insert(1, &chain_id, &channel_id, &port_id, &counterparty_chain_id);
+        state.backlog_insert(2, &chain_id, &channel_id, &port_id, &counterparty_chain_id);
+        state.backlog_remove(1, &chain_id, &channel_id, &port_id, &counterparty_chain_id);
+
+        let metrics = state.exporter.registry().gather().clone();
+        let backlog_oldest_timestamp = metrics
+            .iter()
+            .find(|&metric| metric.get_name() == "backlog_oldest_timestamp")
+            .unwrap();
+        assert!(
+            backlog_oldest_timestamp
+                .get_metric()
+                .iter()
+                .all(|m| m.get_gauge().get_value() > 0.0),
+            "expected backlog_oldest_timestamp to be set"
+        );
+    }
+
+    #[test]
+    fn packet_latency() {
+        let state = TelemetryState::new(
+            Range {
+                start: 0,
+                end: 5000,
+            },
+            5,
+            Range {
+                start: 0,
+                end: 5000,
+            },
+            5,
+        );
+
+        let chain_id = ChainId::from_string("chain-test");
+        let counterparty_chain_id = ChainId::from_string("counterpartychain-test");
+        let channel_id = ChannelId::new(0);
+        let port_id = PortId::transfer();
+
+        state.packet_recv_latency(
+            Duration::from_secs(3),
+            &chain_id,
+            &channel_id,
+            &port_id,
+            &counterparty_chain_id,
+        );
+        state.packet_recv_latency(
+            Duration::from_secs(7),
+            &chain_id,
+            &channel_id,
+            &port_id,
+            &counterparty_chain_id,
+        );
+        state.packet_ack_latency(
+            Duration::from_secs(4),
+            &chain_id,
+            &channel_id,
+            &port_id,
+            &counterparty_chain_id,
+        );
+
+        let metrics = state.exporter.registry().gather().clone();
+
+        let packet_recv_latency = metrics
+            .iter()
+            .find(|&metric| metric.get_name() == "packet_recv_latency")
+            .unwrap();
+        let histogram = packet_recv_latency.get_metric()[0].get_histogram();
+        assert_eq!(histogram.get_sample_count(), 2);
+        assert_eq!(histogram.get_sample_sum(), 10000.0);
+
+        let packet_ack_latency = metrics
+            .iter()
+            .find(|&metric| metric.get_name() == "packet_ack_latency")
+            .unwrap();
+        let histogram = packet_ack_latency.get_metric()[0].get_histogram();
+        assert_eq!(histogram.get_sample_count(), 1);
+        assert_eq!(histogram.get_sample_sum(), 4000.0);
+    }
+
+    #[test]
+    fn channel_profitability() {
+        let state = TelemetryState::new(
+            Range {
+                start: 0,
+                end: 5000,
+            },
+            5,
+            Range {
+                start: 0,
+                end: 5000,
+            },
+            5,
+        );
+
+        let chain_id = ChainId::from_string("chain-test");
+        let counterparty_chain_id = ChainId::from_string("counterpartychain-test");
+        let channel_id = ChannelId::new(0);
+        let port_id = PortId::transfer();
+
+        let coin = |amount: u64| Coin {
+            denom: "stake".to_string(),
+            amount: amount.into(),
+        };
+
+        state.channel_fees_collected(
+            &chain_id,
+            &channel_id,
+            &port_id,
+            &counterparty_chain_id,
+            &coin(300),
+        );
+        state.channel_gas_paid(
+            &chain_id,
+            &channel_id,
+            &port_id,
+            &counterparty_chain_id,
+            &chain_id,
+            &coin(100),
+        );
+        state.channel_gas_paid(
+            &chain_id,
+            &channel_id,
+            &port_id,
+            &counterparty_chain_id,
+            &chain_id,
+            &coin(250),
+        );
+        state.channel_net_profit(
+            &chain_id,
+            &channel_id,
+            &port_id,
+            &counterparty_chain_id,
+            &chain_id,
+            "stake",
+            -50.0,
+        );
+
+        let metrics = state.exporter.registry().gather().clone();
+
+        let counter_value = |name: &str| {
+            metrics
+                .iter()
+                .find(|&metric| metric.get_name().starts_with(name))
+                .unwrap()
+                .get_metric()[0]
+                .get_counter()
+                .get_value()
+        };
+
+        assert_eq!(counter_value("channel_fees_collected"), 300.0);
+        assert_eq!(counter_value("channel_gas_paid"), 350.0);
+
+        let channel_net_profit = metrics
+            .iter()
+            .find(|&metric| metric.get_name() == "channel_net_profit")
+            .unwrap();
+        assert_eq!(
+            channel_net_profit.get_metric()[0].get_gauge().get_value(),
+            -50.0
+        );
     }
 
     fn assert_metric_value(metric: &[Metric], expected: u64) -> bool {
@@ -0,0 +1,154 @@
+//! Export of traces and metrics to a local stub of an OpenTelemetry collector.
+
+#![cfg(feature = "otlp")]
+
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use opentelemetry_proto::tonic::collector::metrics::v1::{
+    metrics_service_server::{MetricsService, MetricsServiceServer},
+    ExportMetricsServiceRequest, ExportMetricsServiceResponse,
+};
+use opentelemetry_proto::tonic::collector::trace::v1::{
+    trace_service_server::{TraceService, TraceServiceServer},
+    ExportTraceServiceRequest, ExportTraceServiceResponse,
+};
+use tokio::runtime::Runtime;
+use tonic::transport::Server;
+use tonic::{Request, Response, Status};
+use tracing_subscriber::layer::SubscriberExt;
+
+use ibc_telemetry::otlp::{self, OtlpSettings};
+use ibc_telemetry::state::WorkerType;
+use ibc_telemetry::TelemetryState;
+
+/// A span received by the collector, with the keys of its attributes.
+type ExportedSpan = (String, Vec<String>);
+
+/// Records the names of the spans and metrics it receives.
+#[derive(Clone, Default)]
+struct Collector {
+    spans: Arc<Mutex<Vec<ExportedSpan>>>,
+    metrics: Arc<Mutex<Vec<String>>>,
+}
+
+#[tonic::async_trait]
+impl TraceService for Collector {
+    async fn export(
+        &self,
+        request: Request<ExportTraceServiceRequest>,
+    ) -> Result<Response<ExportTraceServiceResponse>, Status> {
+        let spans = request
+            .into_inner()
+            .resource_spans
+            .into_iter()
+            .flat_map(|resource| resource.scope_spans)
+            .flat_map(|scope| scope.spans)
+            .map(|span| {
+                let keys = span.attributes.into_iter().map(|kv| kv.key).collect();
+                (span.name, keys)
+            });
+
+        self.spans.lock().unwrap().extend(spans);
+
+        Ok(Response::new(ExportTraceServiceResponse::default()))
+    }
+}
+
+#[tonic::async_trait]
+impl MetricsService for Collector {
+    async fn export(
+        &self,
+        request: Request<ExportMetricsServiceRequest>,
+    ) -> Result<Response<ExportMetricsServiceResponse>, Status> {
+        let metrics = request
+            .into_inner()
+            .resource_metrics
+            .into_iter()
+            .flat_map(|resource| resource.scope_metrics)
+            .flat_map(|scope| scope.metrics)
+            .map(|metric| metric.name);
+
+        self.metrics.lock().unwrap().extend(metrics);
+
+        Ok(Response::new(ExportMetricsServiceResponse::default()))
+    }
+}
+
+/// Spawn the collector on the given port, on a runtime of its own.
+fn spawn_collector(port: u16) -> (Runtime, Collector) {
+    let collector = Collector::default();
+    let rt = Runtime::new().unwrap();
+
+    rt.spawn(
+        Server::builder()
+            .add_service(TraceServiceServer::new(collector.clone()))
+            .add_service(MetricsServiceServer::new(collector.clone()))
+            .serve(([127, 0, 0, 1], port).into()),
+    );
+
+    (rt, collector)
+}
+
+/// Wait until the condition holds, or panic after a few seconds.
+fn wait_until(what: &str, condition: impl Fn() -> bool) {
+    let start = Instant::now();
+
+    while !condition() {
+        if start.elapsed() > Duration::from_secs(10) {
+            panic!("timed out waiting for {what}");
+        }
+
+        thread::sleep(Duration::from_millis(50));
+    }
+}
+
+#[test]
+fn export_traces_and_metrics() {
+    let port = 14317;
+    let (_rt, collector) = spawn_collector(port);
+
+    let settings = OtlpSettings {
+        endpoint: format!("http://127.0.0.1:{port}"),
+        service_name: "hermes-test".to_string(),
+        export_interval: Duration::from_millis(100),
+    };
+
+    // Traces
+    let subscriber = tracing_subscriber::registry().with(otlp::layer(&settings).unwrap());
+
+    tracing::subscriber::with_default(subscriber, || {
+        let _span = tracing::error_span!("relay", tracking_id = "test-tracking-id").entered();
+    });
+
+    otlp::shutdown();
+
+    wait_until("the relay span", || {
+        collector
+            .spans
+            .lock()
+            .unwrap()
+            .iter()
+            .any(|(name, keys)| name == "relay" && keys.iter().any(|k| k == "tracking_id"))
+    });
+
+    // Metrics
+    let state = TelemetryState::new_with_otlp(500..10000, 10, 1000..20000, 10, &settings).unwrap();
+    state.worker(WorkerType::Client, 1);
+
+    wait_until("the workers metric", || {
+        collector
+            .metrics
+            .lock()
+            .unwrap()
+            .iter()
+            .any(|name| name == "workers")
+    });
+
+    // The metrics are still exposed to Prometheus
+    assert!(state
+        .gather()
+        .iter()
+        .any(|family| family.get_name() == "workers"));
+}
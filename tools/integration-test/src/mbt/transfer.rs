@@ -162,6 +162,7 @@ impl TestOverrides for IbcTransferMBT {
                 ChainConfig::CosmosSdk(chain_config) => {
                     chain_config.trusting_period = Some(CLIENT_EXPIRY);
                 }
+                ChainConfig::Mock(_) | ChainConfig::SoloMachine(_) => {}
             }
         }
     }
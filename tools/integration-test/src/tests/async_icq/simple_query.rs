@@ -143,6 +143,7 @@ fn assert_eventual_async_icq_success<ChainA: ChainHandle, ChainB: ChainHandle>(
 ) -> Result<(), Error> {
     let rpc_addr = match relayer.config.chains.first().unwrap() {
         ChainConfig::CosmosSdk(c) => c.rpc_addr.clone(),
+        ChainConfig::Mock(_) | ChainConfig::SoloMachine(_) => unreachable!("unexpected chain type"),
     };
 
     let mut rpc_client = HttpClient::new(rpc_addr).unwrap();
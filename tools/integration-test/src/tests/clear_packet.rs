@@ -326,6 +326,7 @@ impl TestOverrides for ClearPacketOverrideTest {
             match chain_config {
                 // Use a small clear interval in the chain configurations to override the global high interval
                 ChainConfig::CosmosSdk(chain_config) => chain_config.clear_interval = Some(10),
+                ChainConfig::Mock(_) | ChainConfig::SoloMachine(_) => {}
             }
         }
     }
@@ -488,6 +489,7 @@ impl BinaryChannelTest for ClearPacketSequencesTest {
             max_memo_size: packet_config.ics20_max_memo_size,
             max_receiver_size: packet_config.ics20_max_receiver_size,
             exclude_src_sequences: vec![],
+            ics20_filter: Default::default(),
         };
 
         let rev_opts = LinkParameters {
@@ -496,6 +498,7 @@ impl BinaryChannelTest for ClearPacketSequencesTest {
             max_memo_size: packet_config.ics20_max_memo_size,
             max_receiver_size: packet_config.ics20_max_receiver_size,
             exclude_src_sequences: vec![],
+            ics20_filter: Default::default(),
         };
 
         // Clear all even packets
@@ -32,6 +32,7 @@ impl TestOverrides for ClientDefaultsTest {
             max_clock_drift: Some(Duration::from_secs(3)),
             trusting_period: Some(Duration::from_secs(60)),
             trust_threshold: Some(TrustThreshold::new(13, 23).unwrap()),
+            wasm_checksum: None,
         }
     }
 
@@ -40,6 +41,7 @@ impl TestOverrides for ClientDefaultsTest {
             max_clock_drift: Some(Duration::from_secs(6)),
             trusting_period: Some(Duration::from_secs(60)),
             trust_threshold: Some(TrustThreshold::TWO_THIRDS),
+            wasm_checksum: None,
         }
     }
 }
@@ -104,6 +106,7 @@ impl TestOverrides for ClientFailsTest {
             max_clock_drift: Some(Duration::from_secs(3)),
             trusting_period: Some(Duration::from_secs(60)),
             trust_threshold: Some(TrustThreshold::new(13, 23).unwrap()),
+            wasm_checksum: None,
         }
     }
 
@@ -112,6 +115,7 @@ impl TestOverrides for ClientFailsTest {
             max_clock_drift: Some(Duration::from_secs(6)),
             trusting_period: Some(Duration::from_secs(60)),
             trust_threshold: Some(TrustThreshold::TWO_THIRDS),
+            wasm_checksum: None,
         }
     }
 }
@@ -128,11 +132,15 @@ impl BinaryChainTest for ClientFailsTest {
             chains,
             |config| {
                 {
-                    let ChainConfig::CosmosSdk(config_chain_a) = &mut config.chains[0];
+                    let ChainConfig::CosmosSdk(config_chain_a) = &mut config.chains[0] else {
+                        unreachable!("unexpected chain type")
+                    };
                     config_chain_a.gas_multiplier = Some(GasMultiplier::unsafe_new(0.8));
                 }
 
-                let ChainConfig::CosmosSdk(config_chain_b) = &mut config.chains[1];
+                let ChainConfig::CosmosSdk(config_chain_b) = &mut config.chains[1] else {
+                    unreachable!("unexpected chain type")
+                };
                 config_chain_b.gas_multiplier = Some(GasMultiplier::unsafe_new(0.8));
             },
             config,
@@ -34,6 +34,7 @@ impl TestOverrides for ClientDefaultsTest {
                 chain_config_a.trusting_period = Some(Duration::from_secs(120_000));
                 chain_config_a.trust_threshold = TrustThreshold::new(13, 23).unwrap();
             }
+            ChainConfig::Mock(_) | ChainConfig::SoloMachine(_) => {}
         }
 
         match &mut config.chains[1] {
@@ -43,6 +44,7 @@ impl TestOverrides for ClientDefaultsTest {
                 chain_config_b.trusting_period = Some(Duration::from_secs(340_000));
                 chain_config_b.trust_threshold = TrustThreshold::TWO_THIRDS;
             }
+            ChainConfig::Mock(_) | ChainConfig::SoloMachine(_) => {}
         }
     }
 }
@@ -75,6 +77,7 @@ impl TestOverrides for ClientOptionsTest {
             max_clock_drift: Some(Duration::from_secs(3)),
             trusting_period: Some(Duration::from_secs(120_000)),
             trust_threshold: Some(TrustThreshold::new(13, 23).unwrap()),
+            wasm_checksum: None,
         }
     }
 
@@ -83,6 +86,7 @@ impl TestOverrides for ClientOptionsTest {
             max_clock_drift: Some(Duration::from_secs(6)),
             trusting_period: Some(Duration::from_secs(340_000)),
             trust_threshold: Some(TrustThreshold::TWO_THIRDS),
+            wasm_checksum: None,
         }
     }
 }
@@ -52,6 +52,7 @@ impl TestOverrides for DynamicGasTest {
                     GasPrice::new(0.1, chain_config_a.gas_price.denom.clone());
                 chain_config_a.dynamic_gas_price = DynamicGasPrice::unsafe_new(false, 1.1, 0.6);
             }
+            ChainConfig::Mock(_) | ChainConfig::SoloMachine(_) => {}
         }
 
         match &mut config.chains[1] {
@@ -61,6 +62,7 @@ impl TestOverrides for DynamicGasTest {
                 chain_config_b.dynamic_gas_price =
                     DynamicGasPrice::unsafe_new(self.dynamic_gas_enabled, 1.1, 0.6);
             }
+            ChainConfig::Mock(_) | ChainConfig::SoloMachine(_) => {}
         }
     }
 
@@ -23,14 +23,17 @@ impl TestOverrides for FilterIncentivizedFeesRelayerTest {
         config.mode.packets.auto_register_counterparty_payee = true;
         let recv_fee = MinFee::new(50, Some("samoleans".to_owned()));
         let fees_filters = FeePolicy::new(vec![recv_fee]);
-        let min_fees =
-            HashMap::from([(FilterPattern::Wildcard("*".parse().unwrap()), fees_filters)]);
+        let min_fees = HashMap::from([(
+            FilterPattern::Wildcard("*".parse().unwrap()).into(),
+            fees_filters,
+        )]);
         let packet_filter = PacketFilter::new(ChannelPolicy::default(), min_fees);
         for chain_config in config.chains.iter_mut() {
             match chain_config {
                 ChainConfig::CosmosSdk(chain_config) => {
                     chain_config.packet_filter = packet_filter.clone();
                 }
+                ChainConfig::Mock(_) | ChainConfig::SoloMachine(_) => {}
             }
         }
     }
@@ -174,7 +177,7 @@ impl TestOverrides for FilterByChannelIncentivizedFeesRelayerTest {
         let recv_fee = MinFee::new(50, Some("samoleans".to_owned()));
         let fees_filters = FeePolicy::new(vec![recv_fee]);
         let min_fees = HashMap::from([(
-            FilterPattern::Wildcard("other-channel*".parse().unwrap()),
+            FilterPattern::Wildcard("other-channel*".parse().unwrap()).into(),
             fees_filters,
         )]);
         let packet_filter = PacketFilter::new(ChannelPolicy::default(), min_fees);
@@ -183,6 +186,7 @@ impl TestOverrides for FilterByChannelIncentivizedFeesRelayerTest {
                 ChainConfig::CosmosSdk(chain_config) => {
                     chain_config.packet_filter = packet_filter.clone();
                 }
+                ChainConfig::Mock(_) | ChainConfig::SoloMachine(_) => {}
             }
         }
     }
@@ -82,6 +82,9 @@ impl BinaryChannelTest for FeeGrantTest {
             .ok_or_else(|| eyre!("chain configuration is empty"))?
         {
             ChainConfig::CosmosSdk(chain_config) => chain_config.gas_price.denom.clone(),
+            ChainConfig::Mock(_) | ChainConfig::SoloMachine(_) => {
+                unreachable!("unexpected chain type")
+            }
         };
 
         let gas_denom: MonoTagged<ChainA, Denom> = MonoTagged::new(Denom::Base(gas_denom_str));
@@ -107,6 +110,7 @@ impl BinaryChannelTest for FeeGrantTest {
                         ChainConfig::CosmosSdk(c) => {
                             c.fee_granter = Some("user2".to_owned());
                         }
+                        ChainConfig::Mock(_) | ChainConfig::SoloMachine(_) => {}
                     }
                 }
             });
@@ -225,6 +229,9 @@ impl BinaryChannelTest for NoFeeGrantTest {
             .ok_or_else(|| eyre!("chain configuration is empty"))?
         {
             ChainConfig::CosmosSdk(chain_config) => chain_config.gas_price.denom.clone(),
+            ChainConfig::Mock(_) | ChainConfig::SoloMachine(_) => {
+                unreachable!("unexpected chain type")
+            }
         };
 
         let gas_denom: MonoTagged<ChainA, Denom> = MonoTagged::new(Denom::Base(gas_denom_str));
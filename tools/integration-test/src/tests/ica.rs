@@ -71,6 +71,7 @@ impl TestOverrides for IcaFilterTestAllow {
                 ChainConfig::CosmosSdk(chain_config) => {
                     chain_config.packet_filter = self.packet_filter.clone();
                 }
+                ChainConfig::Mock(_) | ChainConfig::SoloMachine(_) => {}
             }
         }
     }
@@ -202,6 +203,7 @@ impl TestOverrides for IcaFilterTestDeny {
                             FilterPattern::Wildcard("*".parse().unwrap()),
                         )]));
                 }
+                ChainConfig::Mock(_) | ChainConfig::SoloMachine(_) => {}
             }
         }
     }
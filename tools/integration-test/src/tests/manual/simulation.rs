@@ -33,6 +33,7 @@ impl TestOverrides for SimulationTest {
                 ChainConfig::CosmosSdk(chain_config) => {
                     chain_config.max_msg_num = MaxMsgNum::new(MAX_MSGS).unwrap();
                 }
+                ChainConfig::Mock(_) | ChainConfig::SoloMachine(_) => {}
             }
         }
     }
@@ -54,6 +54,7 @@ impl TestOverrides for OrderedChannelClearTest {
                 ChainConfig::CosmosSdk(chain_config) => {
                     chain_config.sequential_batch_tx = self.sequential_batch_tx;
                 }
+                ChainConfig::Mock(_) | ChainConfig::SoloMachine(_) => {}
             }
         }
 
@@ -62,6 +63,7 @@ impl TestOverrides for OrderedChannelClearTest {
             ChainConfig::CosmosSdk(chain_config) => {
                 chain_config.sequential_batch_tx = self.sequential_batch_tx;
             }
+            ChainConfig::Mock(_) | ChainConfig::SoloMachine(_) => {}
         }
     }
 
@@ -122,6 +124,7 @@ impl BinaryChannelTest for OrderedChannelClearTest {
             max_memo_size: packet_config.ics20_max_memo_size,
             max_receiver_size: packet_config.ics20_max_receiver_size,
             exclude_src_sequences: vec![],
+            ics20_filter: Default::default(),
         };
 
         let chain_a_link = Link::new_from_opts(
@@ -138,6 +141,7 @@ impl BinaryChannelTest for OrderedChannelClearTest {
             max_memo_size: packet_config.ics20_max_memo_size,
             max_receiver_size: packet_config.ics20_max_receiver_size,
             exclude_src_sequences: vec![],
+            ics20_filter: Default::default(),
         };
 
         let chain_b_link = Link::new_from_opts(
@@ -198,6 +202,7 @@ impl TestOverrides for OrderedChannelClearEqualCLITest {
                     chain_config.sequential_batch_tx = true;
                     chain_config.max_msg_num = MaxMsgNum::new(3).unwrap();
                 }
+                ChainConfig::Mock(_) | ChainConfig::SoloMachine(_) => {}
             }
         }
 
@@ -207,6 +212,7 @@ impl TestOverrides for OrderedChannelClearEqualCLITest {
                 chain_config.sequential_batch_tx = true;
                 chain_config.max_msg_num = MaxMsgNum::new(3).unwrap();
             }
+            ChainConfig::Mock(_) | ChainConfig::SoloMachine(_) => {}
         }
     }
 
@@ -275,6 +281,7 @@ impl BinaryChannelTest for OrderedChannelClearEqualCLITest {
             max_memo_size: packet_config.ics20_max_memo_size,
             max_receiver_size: packet_config.ics20_max_receiver_size,
             exclude_src_sequences: vec![],
+            ics20_filter: Default::default(),
         };
 
         let chain_a_link = Link::new_from_opts(
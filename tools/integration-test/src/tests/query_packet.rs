@@ -63,6 +63,7 @@ impl BinaryChannelTest for QueryPacketPendingTest {
             max_memo_size: packet_config.ics20_max_memo_size,
             max_receiver_size: packet_config.ics20_max_receiver_size,
             exclude_src_sequences: vec![],
+            ics20_filter: Default::default(),
         };
 
         let rev_opts = LinkParameters {
@@ -71,6 +72,7 @@ impl BinaryChannelTest for QueryPacketPendingTest {
             max_memo_size: packet_config.ics20_max_memo_size,
             max_receiver_size: packet_config.ics20_max_receiver_size,
             exclude_src_sequences: vec![],
+            ics20_filter: Default::default(),
         };
 
         let link = Link::new_from_opts(
@@ -54,6 +54,7 @@ impl TestOverrides for FilterClearOnStartTest {
             ChainConfig::CosmosSdk(chain_config) => {
                 chain_config.excluded_sequences = excluded_sequences;
             }
+            ChainConfig::Mock(_) | ChainConfig::SoloMachine(_) => {}
         }
         config.mode.channels.enabled = true;
 
@@ -91,6 +92,7 @@ impl TestOverrides for FilterClearIntervalTest {
             ChainConfig::CosmosSdk(chain_config) => {
                 chain_config.excluded_sequences = excluded_sequences;
             }
+            ChainConfig::Mock(_) | ChainConfig::SoloMachine(_) => {}
         }
         config.mode.channels.enabled = true;
 
@@ -250,6 +252,7 @@ impl TestOverrides for StandardRelayingNoFilterTest {
             ChainConfig::CosmosSdk(chain_config) => {
                 chain_config.excluded_sequences = excluded_sequences;
             }
+            ChainConfig::Mock(_) | ChainConfig::SoloMachine(_) => {}
         }
         config.mode.packets.clear_on_start = true;
         config.mode.packets.clear_interval = 0;
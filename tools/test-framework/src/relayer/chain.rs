@@ -27,7 +27,7 @@ use tracing::Span;
 use ibc_proto::ibc::apps::fee::v1::{
     QueryIncentivizedPacketRequest, QueryIncentivizedPacketResponse,
 };
-use ibc_relayer::account::Balance;
+use ibc_relayer::account::{Balance, Grants};
 use ibc_relayer::chain::client::ClientSettings;
 use ibc_relayer::chain::endpoint::{ChainStatus, HealthCheck};
 use ibc_relayer::chain::handle::{ChainHandle, ChainRequest, Subscription};
@@ -43,14 +43,16 @@ use ibc_relayer::event::IbcEventWithHeight;
 use ibc_relayer::keyring::AnySigningKeyPair;
 use ibc_relayer::misbehaviour::MisbehaviourEvidence;
 use ibc_relayer_types::applications::ics31_icq::response::CrossChainQueryResponse;
+use ibc_relayer_types::applications::transfer::RawCoin;
 use ibc_relayer_types::core::ics02_client::events::UpdateClient;
 use ibc_relayer_types::core::ics02_client::header::AnyHeader;
 use ibc_relayer_types::core::ics03_connection::connection::ConnectionEnd;
 use ibc_relayer_types::core::ics03_connection::connection::IdentifiedConnectionEnd;
 use ibc_relayer_types::core::ics03_connection::version::Version;
 use ibc_relayer_types::core::ics04_channel::channel::ChannelEnd;
-use ibc_relayer_types::core::ics04_channel::channel::IdentifiedChannelEnd;
+use ibc_relayer_types::core::ics04_channel::channel::{IdentifiedChannelEnd, Ordering};
 use ibc_relayer_types::core::ics04_channel::packet::{PacketMsgType, Sequence};
+use ibc_relayer_types::core::ics04_channel::upgrade::Upgrade;
 use ibc_relayer_types::core::ics23_commitment::commitment::CommitmentPrefix;
 use ibc_relayer_types::core::ics23_commitment::merkle::MerkleProof;
 use ibc_relayer_types::core::ics24_host::identifier::ChainId;
@@ -118,10 +120,30 @@ where
         self.value().get_key()
     }
 
+    fn get_keys(&self) -> Result<Vec<(String, AnySigningKeyPair)>, Error> {
+        self.value().get_keys()
+    }
+
+    fn select_signer(
+        &self,
+        channel: (PortId, ChannelId),
+        ordering: Ordering,
+    ) -> Result<Signer, Error> {
+        self.value().select_signer(channel, ordering)
+    }
+
     fn add_key(&self, key_name: String, key: AnySigningKeyPair) -> Result<(), Error> {
         self.value().add_key(key_name, key)
     }
 
+    fn update_gas_settings(&self, config: ChainConfig) -> Result<(), Error> {
+        self.value().update_gas_settings(config)
+    }
+
+    fn estimate_tx_fee(&self, msg_type: String) -> Result<Option<RawCoin>, Error> {
+        self.value().estimate_tx_fee(msg_type)
+    }
+
     fn version_specs(&self) -> Result<Specs, Error> {
         self.value().version_specs()
     }
@@ -239,6 +261,14 @@ where
         self.value().query_channel(request, include_proof)
     }
 
+    fn query_upgrade(
+        &self,
+        request: QueryUpgradeRequest,
+        include_proof: IncludeProof,
+    ) -> Result<(Upgrade, Option<MerkleProof>), Error> {
+        self.value().query_upgrade(request, include_proof)
+    }
+
     fn query_channel_client_state(
         &self,
         request: QueryChannelClientStateRequest,
@@ -435,4 +465,15 @@ where
     fn query_consumer_chains(&self) -> Result<Vec<(ChainId, ClientId)>, Error> {
         self.value().query_consumer_chains()
     }
+
+    fn query_grants(&self, request: QueryGrantsRequest) -> Result<Grants, Error> {
+        self.value().query_grants(request)
+    }
+
+    fn query_dropped_txs(
+        &self,
+        tx_hashes: Vec<tendermint::Hash>,
+    ) -> Result<Vec<tendermint::Hash>, Error> {
+        self.value().query_dropped_txs(tx_hashes)
+    }
 }
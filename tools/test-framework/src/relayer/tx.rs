@@ -8,6 +8,7 @@ use ibc_relayer::chain::cosmos::gas::calculate_fee;
 use ibc_relayer::chain::cosmos::types::config::TxConfig;
 use ibc_relayer::chain::cosmos::types::gas::GasConfig;
 use ibc_relayer::config::dynamic_gas::DynamicGasPrice;
+use ibc_relayer::config::gas_model::GasModel;
 use ibc_relayer::config::{AddressType, GasPrice};
 use ibc_relayer_types::core::ics24_host::identifier::ChainId;
 use tendermint_rpc::Url;
@@ -49,6 +50,7 @@ pub fn gas_config_for_test(native_token: String) -> GasConfig {
             multiplier: 1.0,
             max: 0.6,
         },
+        gas_model: GasModel::disabled(),
     }
 }
 
@@ -66,6 +68,7 @@ pub fn new_tx_config_for_test(
     let max_msg_num = Default::default();
     let max_tx_size = Default::default();
     let extension_options = Default::default();
+    let tx_pipeline = Default::default();
 
     Ok(TxConfig {
         chain_id,
@@ -77,5 +80,6 @@ pub fn new_tx_config_for_test(
         max_msg_num,
         max_tx_size,
         extension_options,
+        tx_pipeline,
     })
 }
@@ -7,10 +7,13 @@ use core::time::Duration;
 use eyre::eyre;
 use eyre::Report as Error;
 use ibc_relayer::chain::cosmos::config::CosmosSdkConfig;
+use ibc_relayer::chain::cosmos::wallets::WalletAssignment;
 use ibc_relayer::config;
 use ibc_relayer::config::compat_mode::CompatMode;
 use ibc_relayer::config::dynamic_gas::DynamicGasPrice;
+use ibc_relayer::config::gas_model::GasModel;
 use ibc_relayer::config::gas_multiplier::GasMultiplier;
+use ibc_relayer::config::tx_pipeline::TxPipeline;
 use ibc_relayer::keyring::Store;
 use ibc_relayer_types::core::ics24_host::identifier::ChainId;
 use std::collections::BTreeMap;
@@ -166,18 +169,26 @@ impl FullNode {
             },
             rpc_timeout: config::default::rpc_timeout(),
             trusted_node: false,
+            witnesses: Vec::new(),
             genesis_restart: None,
             account_prefix: self.chain_driver.account_prefix.clone(),
             key_name: self.wallets.relayer.id.0.clone(),
+            extra_key_names: Vec::new(),
+            wallet_assignment: WalletAssignment::default(),
             key_store_type: Store::Test,
             key_store_folder: Some(hermes_keystore_dir.into()),
+            key_store_passphrase: None,
+            remote_signer: None,
             store_prefix: "ibc".to_string(),
             default_gas: None,
             max_gas: Some(3000000),
             gas_adjustment: None,
             gas_multiplier: Some(GasMultiplier::unsafe_new(1.5)),
             dynamic_gas_price: DynamicGasPrice::default(),
+            gas_model: GasModel::default(),
+            tx_pipeline: TxPipeline::default(),
             fee_granter: None,
+            fee_grant_expiry_warning: config::default::fee_grant_expiry_warning(),
             max_msg_num: Default::default(),
             max_tx_size: Default::default(),
             max_grpc_decoding_size: config::default::max_grpc_decoding_size(),
@@ -199,6 +210,7 @@ impl FullNode {
             compat_mode,
             clear_interval: None,
             excluded_sequences: BTreeMap::new(),
+            fallback_endpoints: Vec::new(),
         }))
     }
 